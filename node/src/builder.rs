@@ -26,7 +26,7 @@ use std::future::Future;
 
 pub use manta_primitives::types::{AccountId, Balance, Block, Hash, Header, Index as Nonce};
 use polkadot_service::CollatorPair;
-use session_key_primitives::AuraId;
+use session_key_primitives::{AuraId, NimbusId};
 use std::sync::Arc;
 
 use sc_consensus::LongestChain;
@@ -137,8 +137,14 @@ where
         }
     };
 
+    let client_for_vrf = client.clone();
+    let keystore_for_vrf = keystore.clone();
+    let vrf_digests_provider = move |nimbus_id: NimbusId, parent: Hash| {
+        crate::vrf::vrf_pre_digest(&*client_for_vrf, &keystore_for_vrf, nimbus_id, parent)
+    };
+
     Ok(NimbusConsensus::build(BuildNimbusConsensusParams {
-        additional_digests_provider: (),
+        additional_digests_provider: vrf_digests_provider,
         para_id: id,
         proposer_factory,
         block_import: client.clone(),
@@ -189,6 +195,8 @@ where
         );
 
     let client_set_aside_for_cidp = client.clone();
+    let client_for_vrf = client.clone();
+    let keystore_for_vrf = keystore_container.keystore();
 
     let consensus = run_manual_seal(ManualSealParams {
         block_import: InstantFinalizeBlockImport::new(client.clone()),
@@ -200,7 +208,9 @@ where
         consensus_data_provider: Some(Box::new(NimbusManualSealConsensusDataProvider {
             keystore: keystore_container.keystore(),
             client,
-            additional_digests_provider: (),
+            additional_digests_provider: move |nimbus_id: NimbusId, parent: Hash| {
+                crate::vrf::vrf_pre_digest(&*client_for_vrf, &keystore_for_vrf, nimbus_id, parent)
+            },
             _phantom: Default::default(),
         })),
         create_inherent_data_providers: move |block: Hash, ()| {
@@ -20,8 +20,8 @@ use super::*;
 use crate::command::CALAMARI_PARACHAIN_ID;
 #[allow(unused_imports)]
 use calamari_runtime::{
-    currency::KMA, opaque::SessionKeys, CouncilConfig, DemocracyConfig, GenesisConfig,
-    LotteryConfig, ParachainStakingConfig, TechnicalCommitteeConfig,
+    currency::KMA, opaque::SessionKeys, AssetLotteryConfig, CouncilConfig, DemocracyConfig,
+    GenesisConfig, LotteryConfig, ParachainStakingConfig, TechnicalCommitteeConfig,
 };
 use session_key_primitives::util::unchecked_account_id;
 /// Calamari Protocol Identifier
@@ -239,6 +239,14 @@ fn calamari_dev_genesis(
             min_withdraw: 5_000 * KMA,
             gas_reserve: 10_000 * KMA,
             farming_pool_params: Default::default(),
+            phantom: Default::default(),
+        },
+        asset_lottery: AssetLotteryConfig {
+            min_deposit: calamari_runtime::AssetLotteryMinDeposit::get(),
+            min_withdraw: calamari_runtime::AssetLotteryMinWithdraw::get(),
+            gas_reserve: calamari_runtime::AssetLotteryGasReserve::get(),
+            farming_pool_params: Default::default(),
+            phantom: Default::default(),
         },
     }
 }
@@ -19,8 +19,8 @@
 use super::*;
 use crate::command::MANTA_PARACHAIN_ID;
 use manta_runtime::{
-    opaque::SessionKeys, staking::NORMAL_COLLATOR_MINIMUM_STAKE, CouncilConfig, DemocracyConfig,
-    GenesisConfig, LotteryConfig, ParachainStakingConfig, PolkadotXcmConfig,
+    opaque::SessionKeys, staking::NORMAL_COLLATOR_MINIMUM_STAKE, AssetLotteryConfig, CouncilConfig,
+    DemocracyConfig, GenesisConfig, LotteryConfig, ParachainStakingConfig, PolkadotXcmConfig,
     TechnicalCommitteeConfig,
 };
 use sc_service::config::MultiaddrWithPeerId;
@@ -131,6 +131,14 @@ fn manta_devnet_genesis(genesis_collators: Vec<Collator>) -> GenesisConfig {
             min_withdraw: 10 * MANTA,
             gas_reserve: 1_000 * MANTA,
             farming_pool_params: Default::default(),
+            phantom: Default::default(),
+        },
+        asset_lottery: AssetLotteryConfig {
+            min_deposit: manta_runtime::AssetLotteryMinDeposit::get(),
+            min_withdraw: manta_runtime::AssetLotteryMinWithdraw::get(),
+            gas_reserve: manta_runtime::AssetLotteryGasReserve::get(),
+            farming_pool_params: Default::default(),
+            phantom: Default::default(),
         },
         parachain_info: manta_runtime::ParachainInfoConfig {
             parachain_id: MANTA_PARACHAIN_ID.into(),
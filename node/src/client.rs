@@ -38,7 +38,9 @@ where
 
 /// Extend RuntimeApi trait bound for Nimbus
 pub trait RuntimeApiNimbus:
-    cumulus_primitives_core::CollectCollationInfo<Block> + nimbus_primitives::NimbusApi<Block>
+    cumulus_primitives_core::CollectCollationInfo<Block>
+    + nimbus_primitives::NimbusApi<Block>
+    + session_key_primitives::vrf::VrfApi<Block>
 {
 }
 
@@ -57,6 +59,8 @@ where
 }
 
 impl<Api> RuntimeApiNimbus for Api where
-    Api: cumulus_primitives_core::CollectCollationInfo<Block> + nimbus_primitives::NimbusApi<Block>
+    Api: cumulus_primitives_core::CollectCollationInfo<Block>
+        + nimbus_primitives::NimbusApi<Block>
+        + session_key_primitives::vrf::VrfApi<Block>
 {
 }
@@ -29,3 +29,4 @@ pub mod command;
 mod instant_finalize;
 pub mod rpc;
 pub mod service;
+pub mod vrf;
@@ -32,6 +32,10 @@ use pallet_manta_sbt::{
     rpc::{SBTPull, SBTPullApiServer},
     runtime::SBTPullLedgerDiffApi,
 };
+use pallet_parachain_staking::{
+    rpc::{ParachainStaking, ParachainStakingRpcServer},
+    runtime::ParachainStakingApi,
+};
 
 use zenlink_protocol::AssetId as ZenlinkAssetId;
 use zenlink_protocol_rpc::{ZenlinkProtocol, ZenlinkProtocolApiServer};
@@ -53,6 +57,7 @@ where
     C::Api: PullLedgerDiffApi<Block>,
     C::Api: SBTPullLedgerDiffApi<Block>,
     C::Api: LotteryApi<Block>,
+    C::Api: ParachainStakingApi<Block, AccountId, Balance>,
     C::Api: FarmingRuntimeApi<Block, AccountId, CalamariAssetId, PoolId>,
     C::Api: ZenlinkProtocolRuntimeApi<Block, AccountId, ZenlinkAssetId>,
     P: TransactionPool + Sync + Send + 'static,
@@ -93,6 +98,10 @@ where
         .merge(FarmingRpc::new(client.clone()).into_rpc())
         .map_err(|e| sc_service::Error::Other(e.to_string()))?;
 
+    module
+        .merge(ParachainStaking::new(client.clone()).into_rpc())
+        .map_err(|e| sc_service::Error::Other(e.to_string()))?;
+
     module
         .merge(Lottery::new(client).into_rpc())
         .map_err(|e| sc_service::Error::Other(e.to_string()))?;
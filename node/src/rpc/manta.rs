@@ -28,6 +28,10 @@ use pallet_manta_sbt::{
     rpc::{SBTPull, SBTPullApiServer},
     runtime::SBTPullLedgerDiffApi,
 };
+use pallet_parachain_staking::{
+    rpc::{ParachainStaking, ParachainStakingRpcServer},
+    runtime::ParachainStakingApi,
+};
 use zenlink_protocol::AssetId as ZenlinkAssetId;
 use zenlink_protocol_rpc::{ZenlinkProtocol, ZenlinkProtocolApiServer};
 use zenlink_protocol_runtime_api::ZenlinkProtocolApi as ZenlinkProtocolRuntimeApi;
@@ -47,6 +51,7 @@ where
     C::Api: BlockBuilder<Block>,
     C::Api: SBTPullLedgerDiffApi<Block>,
     C::Api: LotteryApi<Block>,
+    C::Api: ParachainStakingApi<Block, AccountId, Balance>,
     C::Api: FarmingRuntimeApi<Block, AccountId, MantaAssetId, PoolId>,
     C::Api: ZenlinkProtocolRuntimeApi<Block, AccountId, ZenlinkAssetId>,
     P: TransactionPool + Sync + Send + 'static,
@@ -82,6 +87,10 @@ where
         .merge(FarmingRpc::new(client.clone()).into_rpc())
         .map_err(|e| sc_service::Error::Other(e.to_string()))?;
 
+    module
+        .merge(ParachainStaking::new(client.clone()).into_rpc())
+        .map_err(|e| sc_service::Error::Other(e.to_string()))?;
+
     module
         .merge(Lottery::new(client).into_rpc())
         .map_err(|e| sc_service::Error::Other(e.to_string()))?;
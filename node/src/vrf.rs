@@ -0,0 +1,69 @@
+// Copyright 2020-2024 Manta Network.
+// This file is part of Manta.
+//
+// Manta is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// Manta is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Manta.  If not, see <http://www.gnu.org/licenses/>.
+
+//! VRF Pre-Runtime Digest of the Block Author
+
+use codec::Encode;
+use manta_primitives::types::{Block, Hash};
+use session_key_primitives::{
+    vrf::{make_vrf_transcript, PreDigest, VrfApi, VRF_ENGINE_ID, VRF_KEY_ID},
+    NimbusId,
+};
+use sp_api::ProvideRuntimeApi;
+use sp_keystore::{Keystore, KeystorePtr};
+use sp_runtime::DigestItem;
+
+/// Signs the VRF output of the `parent` block with the VRF session key registered for `nimbus_id`
+/// and returns the VRF pre-runtime digest of the new block.
+///
+/// Returns `None` if the runtime does not expect a VRF digest yet or if the VRF key is not in the
+/// `keystore`, in which case the runtime rejects the block once the VRF digest is enforced.
+pub fn vrf_pre_digest<C>(
+    client: &C,
+    keystore: &KeystorePtr,
+    nimbus_id: NimbusId,
+    parent: Hash,
+) -> Option<DigestItem>
+where
+    C: ProvideRuntimeApi<Block>,
+    C::Api: VrfApi<Block>,
+{
+    let runtime_api = client.runtime_api();
+    let last_vrf_output = runtime_api.get_last_vrf_output(parent).ok()??;
+    let key = match runtime_api.vrf_key_lookup(parent, nimbus_id).ok()? {
+        Some(key) => key,
+        None => {
+            log::warn!("Block author has no VRF session key, authoring without VRF digest");
+            return None;
+        }
+    };
+    let transcript = make_vrf_transcript(last_vrf_output);
+    let signature = match keystore
+        .sr25519_vrf_sign(VRF_KEY_ID, key.as_ref(), &transcript)
+        .ok()?
+    {
+        Some(signature) => signature,
+        None => {
+            log::warn!("VRF session key is not in the keystore, authoring without VRF digest");
+            return None;
+        }
+    };
+    let pre_digest = PreDigest {
+        vrf_output: signature.output,
+        vrf_proof: signature.proof,
+    };
+    Some(DigestItem::PreRuntime(VRF_ENGINE_ID, pre_digest.encode()))
+}
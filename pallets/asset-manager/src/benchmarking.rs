@@ -17,8 +17,10 @@
 #![cfg(feature = "runtime-benchmarks")]
 
 use crate::{Call, Config, Pallet};
-use frame_benchmarking::{benchmarks, impl_benchmark_test_suite, vec, whitelisted_caller};
-use frame_support::traits::Get;
+use frame_benchmarking::{
+    benchmarks, impl_benchmark_test_suite, vec, whitelisted_caller, BenchmarkError,
+};
+use frame_support::traits::{EnsureOrigin, Get, Hooks};
 use frame_system::{EventRecord, RawOrigin};
 use manta_primitives::{
     assets::{AssetConfig, AssetRegistryMetadata, FungibleLedger, TestingDefault, UnitsPerSecond},
@@ -33,6 +35,30 @@ fn assert_last_event<T: Config>(generic_event: <T as Config>::RuntimeEvent) {
     assert_eq!(event, &system_event);
 }
 
+/// Returns the origin and para id of a sibling parachain whose sovereign account is funded, along
+/// with the metadata and units-per-second of an asset proposal within the configured bounds.
+#[allow(clippy::type_complexity)]
+fn sibling_proposal<T: Config>(
+) -> Result<(T::RuntimeOrigin, u32, AssetRegistryMetadata<Balance>, u128), BenchmarkError> {
+    let origin =
+        T::SiblingAssetOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+    let (para_id, depositor) = T::SiblingAssetOrigin::ensure_origin(origin.clone())
+        .map_err(|_| BenchmarkError::Weightless)?;
+    let _ = <T::AssetConfig as AssetConfig<T>>::FungibleLedger::deposit_minting(
+        <T::AssetConfig as AssetConfig<T>>::NativeAssetId::get(),
+        &depositor,
+        1_000_000_000_000_000_000_000u128,
+    );
+    let mut metadata = AssetRegistryMetadata::<Balance>::testing_default();
+    metadata.min_balance = T::ProposedMinBalanceBounds::get().0;
+    Ok((
+        origin,
+        para_id,
+        metadata,
+        T::ProposedUnitsPerSecondBounds::get().0,
+    ))
+}
+
 benchmarks! {
     where_clause { where T::Location: From<MultiLocation>, <T as Config>::AssetId: From<u32> }
 
@@ -170,6 +196,8 @@ benchmarks! {
     verify {
         assert_eq!(Pallet::<T>::asset_id_pair_to_lp((current_asset_id, current_asset_id + 1.into())), Some(current_asset_id + 2.into()));
         assert_eq!(Pallet::<T>::lp_to_asset_id_pair(current_asset_id + 2.into()), Some((current_asset_id, current_asset_id + 1.into())));
+        assert!(crate::AssetIdToLp::<T>::contains_key(current_asset_id, current_asset_id + 2.into()));
+        assert!(crate::AssetIdToLp::<T>::contains_key(current_asset_id + 1.into(), current_asset_id + 2.into()));
     }
 
     permissionless_register_asset {
@@ -181,6 +209,104 @@ benchmarks! {
             1_000_000_000_000_000_000_000u128
         );
     }: _(RawOrigin::Signed(caller), vec![].try_into().unwrap(), vec![].try_into().unwrap(), 12, 1_000_000_000_000_000)
+
+    propose_asset {
+        let (origin, para_id, metadata, units_per_second) = sibling_proposal::<T>()?;
+        let location = T::Location::from(MultiLocation::new(1, X2(Parachain(para_id), GeneralIndex(0))));
+    }: _<T::RuntimeOrigin>(origin, location.clone(), metadata, units_per_second)
+    verify {
+        assert!(Pallet::<T>::pending_asset(location).is_some());
+    }
+
+    veto_pending_asset {
+        let (origin, para_id, metadata, units_per_second) = sibling_proposal::<T>()?;
+        let location = T::Location::from(MultiLocation::new(1, X2(Parachain(para_id), GeneralIndex(0))));
+        Pallet::<T>::propose_asset(origin, location.clone(), metadata, units_per_second)?;
+        let veto_origin = T::VetoOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+    }: _<T::RuntimeOrigin>(veto_origin, location.clone())
+    verify {
+        assert_last_event::<T>(crate::Event::PendingAssetVetoed { location }.into());
+    }
+
+    activate_pending_assets {
+        let n in 1 .. T::MaxActivationsPerBlock::get();
+        let (origin, para_id, metadata, units_per_second) = sibling_proposal::<T>()?;
+        for i in 0..n {
+            let location = T::Location::from(MultiLocation::new(1, X2(Parachain(para_id), GeneralIndex(i.into()))));
+            Pallet::<T>::propose_asset(origin.clone(), location, metadata.clone(), units_per_second)?;
+        }
+        let activates_at = frame_system::Pallet::<T>::block_number() + T::AssetChallengePeriod::get();
+        let next_asset_id = crate::NextAssetId::<T>::get();
+    }: {
+        Pallet::<T>::on_initialize(activates_at);
+    }
+    verify {
+        assert_eq!(crate::NextAssetId::<T>::get(), next_asset_id + n.into());
+    }
+
+    set_dynamic_pricing {
+        let location = T::Location::default();
+        let metadata = AssetRegistryMetadata::<Balance>::testing_default();
+        let asset_id = crate::NextAssetId::<T>::get();
+        Pallet::<T>::register_asset(RawOrigin::Root.into(), location, metadata)?;
+    }: _(RawOrigin::Root, asset_id, Some((1, u128::MAX)))
+    verify {
+        assert!(Pallet::<T>::dynamic_pricing(asset_id).is_some());
+    }
+
+    update_dynamic_prices {
+        let n in 1 .. T::MaxDynamicPricedAssets::get();
+        for i in 0..n {
+            let location: MultiLocation = MultiLocation::new(0, X1(GeneralIndex(i.into())));
+            let metadata = AssetRegistryMetadata::<Balance>::testing_default();
+            let asset_id = crate::NextAssetId::<T>::get();
+            Pallet::<T>::register_asset(RawOrigin::Root.into(), location.into(), metadata)?;
+            Pallet::<T>::set_dynamic_pricing(RawOrigin::Root.into(), asset_id, Some((1, u128::MAX)))?;
+        }
+        let now = frame_system::Pallet::<T>::block_number() + T::SpotPriceAveragingPeriod::get();
+    }: {
+        Pallet::<T>::update_dynamic_prices(now);
+    }
+    verify {
+        assert_eq!(crate::DynamicPricing::<T>::count(), n);
+    }
+
+    freeze_asset {
+        let location = T::Location::from(MultiLocation::new(1, X1(Parachain(1000))));
+        let metadata = AssetRegistryMetadata::<Balance>::testing_default();
+        let asset_id = crate::NextAssetId::<T>::get();
+        Pallet::<T>::register_asset(RawOrigin::Root.into(), location, metadata)?;
+    }: _(RawOrigin::Root, asset_id)
+    verify {
+        assert!(crate::FrozenAssets::<T>::contains_key(asset_id));
+    }
+
+    unfreeze_asset {
+        let location = T::Location::from(MultiLocation::new(1, X1(Parachain(1000))));
+        let metadata = AssetRegistryMetadata::<Balance>::testing_default();
+        let asset_id = crate::NextAssetId::<T>::get();
+        Pallet::<T>::register_asset(RawOrigin::Root.into(), location, metadata)?;
+        Pallet::<T>::freeze_asset(RawOrigin::Root.into(), asset_id)?;
+    }: _(RawOrigin::Root, asset_id)
+    verify {
+        assert!(!crate::FrozenAssets::<T>::contains_key(asset_id));
+    }
+
+    deregister_asset {
+        let reserve_chain = MultiLocation::new(1, X1(Parachain(1000)));
+        let location = T::Location::from(MultiLocation::new(1, X2(Parachain(1000), GeneralIndex(0))));
+        let metadata = AssetRegistryMetadata::<Balance>::testing_default();
+        let asset_id = crate::NextAssetId::<T>::get();
+        Pallet::<T>::register_asset(RawOrigin::Root.into(), location.clone(), metadata)?;
+        Pallet::<T>::set_units_per_second(RawOrigin::Root.into(), asset_id, 1)?;
+        Pallet::<T>::set_dynamic_pricing(RawOrigin::Root.into(), asset_id, Some((1, u128::MAX)))?;
+        Pallet::<T>::set_min_xcm_fee(RawOrigin::Root.into(), reserve_chain.into(), 1)?;
+        Pallet::<T>::update_outgoing_filtered_assets(RawOrigin::Root.into(), location.clone(), true)?;
+        Pallet::<T>::freeze_asset(RawOrigin::Root.into(), asset_id)?;
+    }: _(RawOrigin::Root, asset_id)
+    verify {
+        assert_last_event::<T>(crate::Event::AssetDeregistered { asset_id, location: Some(location) }.into());
+    }
 }
 
 impl_benchmark_test_suite!(Pallet, crate::mock::new_test_ext(), crate::mock::Runtime);
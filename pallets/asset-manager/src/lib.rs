@@ -29,6 +29,7 @@
 #[cfg(feature = "runtime-benchmarks")]
 mod benchmarking;
 pub mod migrations;
+pub mod pricing;
 pub mod weights;
 
 #[cfg(test)]
@@ -39,13 +40,15 @@ mod tests;
 
 pub use crate::weights::WeightInfo;
 pub use pallet::*;
+pub use pricing::{DynamicPrice, NativePriceProvider};
 
 /// Asset Manager Pallet
 #[frame_support::pallet]
 pub mod pallet {
-    use crate::weights::WeightInfo;
+    use crate::{weights::WeightInfo, DynamicPrice, NativePriceProvider};
     use frame_support::{
         pallet_prelude::*,
+        storage::with_storage_layer,
         traits::{
             tokens::{ExistenceRequirement, Provenance},
             Contains, StorageVersion,
@@ -72,7 +75,7 @@ pub mod pallet {
     use xcm::latest::prelude::*;
 
     /// Storage Version
-    pub const STORAGE_VERSION: StorageVersion = StorageVersion::new(2);
+    pub const STORAGE_VERSION: StorageVersion = StorageVersion::new(3);
 
     /// Used to set the minimum balance for permissionless assets.
     pub const POSSIBLE_ACCOUNTS_PER_ASSET: Balance = 10_000_000_000;
@@ -135,6 +138,74 @@ pub mod pallet {
 
         /// Cost of registering a permissionless asset in native token
         type PermissionlessAssetRegistryCost: Get<Balance>;
+
+        /// The origin of a sibling parachain proposing one of its assets, resolving to its para id
+        /// and sovereign account.
+        type SiblingAssetOrigin: EnsureOrigin<
+            Self::RuntimeOrigin,
+            Success = (ParaId, Self::AccountId),
+        >;
+
+        /// The origin which may veto an asset proposed by a sibling parachain.
+        type VetoOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+        /// Number of blocks a proposed asset stays pending before it is registered.
+        #[pallet::constant]
+        type AssetChallengePeriod: Get<BlockNumberFor<Self>>;
+
+        /// Max number of proposed assets activating in the same block.
+        #[pallet::constant]
+        type MaxActivationsPerBlock: Get<u32>;
+
+        /// Deposit in native token taken from the proposing sibling parachain, returned once the
+        /// challenge period ends and kept if the asset is vetoed.
+        #[pallet::constant]
+        type AssetProposalDeposit: Get<Balance>;
+
+        /// Lower and upper bounds of the min balance of a proposed asset.
+        #[pallet::constant]
+        type ProposedMinBalanceBounds: Get<(Balance, Balance)>;
+
+        /// Lower and upper bounds of the units-per-second of a proposed asset.
+        #[pallet::constant]
+        type ProposedUnitsPerSecondBounds: Get<(u128, u128)>;
+
+        /// DEX pricing assets against the native token.
+        type NativePrice: NativePriceProvider<Self::AssetId>;
+
+        /// Units-per-second charged in the native token, which dynamic prices are derived from.
+        #[pallet::constant]
+        type NativeUnitsPerSecond: Get<u128>;
+
+        /// Number of blocks over which the time-weighted average of the spot DEX price is taken.
+        #[pallet::constant]
+        type SpotPriceAveragingPeriod: Get<BlockNumberFor<Self>>;
+
+        /// Max number of assets with dynamic pricing.
+        #[pallet::constant]
+        type MaxDynamicPricedAssets: Get<u32>;
+    }
+
+    /// An asset proposed by a sibling parachain, waiting out the challenge period.
+    #[derive(Clone, Debug, Decode, Encode, Eq, PartialEq, TypeInfo)]
+    pub struct PendingAsset<AccountId, BlockNumber> {
+        /// Para id of the proposing sibling parachain
+        pub para_id: ParaId,
+
+        /// Sovereign account of the proposing sibling parachain
+        pub depositor: AccountId,
+
+        /// Deposit taken from the depositor
+        pub deposit: Balance,
+
+        /// Metadata to register the asset with
+        pub metadata: AssetRegistryMetadata<Balance>,
+
+        /// Units-per-second to set for the asset
+        pub units_per_second: u128,
+
+        /// Block at which the asset is registered
+        pub activates_at: BlockNumber,
     }
 
     /// Asset Manager Pallet
@@ -168,7 +239,9 @@ pub mod pallet {
 
         #[inline]
         fn asset_id(location: &Self::Location) -> Option<Self::AssetId> {
+            // Frozen assets cannot be received anymore.
             LocationAssetId::<T>::get(location)
+                .filter(|asset_id| !FrozenAssets::<T>::contains_key(asset_id))
         }
     }
 
@@ -194,7 +267,9 @@ pub mod pallet {
     {
         #[inline]
         fn units_per_second(id: &Self::AssetId) -> Option<u128> {
-            UnitsPerSecond::<T>::get(id)
+            DynamicPricing::<T>::get(id)
+                .and_then(|pricing| pricing.units_per_second)
+                .or_else(|| UnitsPerSecond::<T>::get(id))
         }
     }
 
@@ -331,6 +406,87 @@ pub mod pallet {
             /// Metadata Registered to Asset Manager
             metadata: AssetRegistryMetadata<Balance>,
         },
+
+        /// A sibling parachain proposed one of its assets
+        AssetProposed {
+            /// Para id of the proposing sibling parachain
+            para_id: ParaId,
+
+            /// Location of the proposed Asset
+            location: T::Location,
+
+            /// Metadata of the proposed Asset
+            metadata: AssetRegistryMetadata<Balance>,
+
+            /// Units-per-second of the proposed Asset
+            units_per_second: u128,
+
+            /// Block at which the Asset is registered unless vetoed
+            activates_at: BlockNumberFor<T>,
+        },
+
+        /// A pending asset was vetoed
+        PendingAssetVetoed {
+            /// Location of the vetoed Asset
+            location: T::Location,
+        },
+
+        /// A pending asset could not be registered at the end of its challenge period
+        PendingAssetActivationFailed {
+            /// Location of the pending Asset
+            location: T::Location,
+
+            /// Reason the registration failed
+            error: DispatchError,
+        },
+
+        /// Dynamic pricing was enabled or its bounds updated for an asset
+        DynamicPricingSet {
+            /// Asset Id of the Asset
+            asset_id: T::AssetId,
+
+            /// Lower bound of the derived units-per-second
+            min_units_per_second: u128,
+
+            /// Upper bound of the derived units-per-second
+            max_units_per_second: u128,
+        },
+
+        /// Dynamic pricing was disabled for an asset
+        DynamicPricingRemoved {
+            /// Asset Id of the Asset
+            asset_id: T::AssetId,
+        },
+
+        /// The units-per-second of an asset was derived from its time-weighted average DEX price
+        DynamicUnitsPerSecondUpdated {
+            /// Asset Id of the updated Asset
+            asset_id: T::AssetId,
+
+            /// Derived units-per-second for the Asset
+            units_per_second: u128,
+        },
+
+        /// An asset was frozen ahead of its deregistration
+        AssetFrozen {
+            /// Asset Id of the frozen Asset
+            asset_id: T::AssetId,
+        },
+
+        /// An asset was unfrozen
+        AssetUnfrozen {
+            /// Asset Id of the unfrozen Asset
+            asset_id: T::AssetId,
+        },
+
+        /// An asset was deregistered and destroyed
+        AssetDeregistered {
+            /// Asset Id of the deregistered Asset
+            asset_id: T::AssetId,
+
+            /// Location of the deregistered Asset, if it had any
+            location: Option<T::Location>,
+        },
     }
 
     /// Asset Manager Error
@@ -374,6 +530,48 @@ pub mod pallet {
 
         /// Decimals cannot be set to zero
         DecimalIsZero,
+
+        /// The proposed location does not belong to the proposing sibling parachain.
+        LocationNotFromOrigin,
+
+        /// An asset with this location is already pending.
+        AssetAlreadyPending,
+
+        /// There is no pending asset with this location.
+        PendingAssetNotExist,
+
+        /// Too many assets already activate in the same block.
+        TooManyPendingAssets,
+
+        /// The min balance of the proposed asset is out of bounds.
+        MinBalanceOutOfBounds,
+
+        /// The units-per-second of the proposed asset is out of bounds.
+        UnitsPerSecondOutOfBounds,
+
+        /// The lower bound of the units-per-second is above its upper bound.
+        InvalidPriceBounds,
+
+        /// Too many assets already have dynamic pricing.
+        TooManyDynamicPricedAssets,
+
+        /// The asset does not have dynamic pricing.
+        DynamicPricingNotSet,
+
+        /// Cannot Freeze Native Asset
+        CannotFreezeNativeAsset,
+
+        /// The asset is frozen ahead of its deregistration.
+        AssetFrozen,
+
+        /// The asset is not frozen.
+        AssetNotFrozen,
+
+        /// The asset still has some supply.
+        AssetSupplyNotZero,
+
+        /// The asset is one of the assets of a registered LP asset.
+        AssetInLpPair,
     }
 
     /// [`AssetId`](AssetConfig::AssetId) to [`MultiLocation`] Map
@@ -438,6 +636,81 @@ pub mod pallet {
     pub(super) type LpToAssetIdPair<T: Config> =
         StorageMap<_, Blake2_128Concat, T::AssetId, (T::AssetId, T::AssetId)>;
 
+    /// Asset id to the LP asset ids of the asset pairs it belongs to.
+    #[pallet::storage]
+    pub(super) type AssetIdToLp<T: Config> =
+        StorageDoubleMap<_, Blake2_128Concat, T::AssetId, Blake2_128Concat, T::AssetId, ()>;
+
+    /// Assets proposed by sibling parachains, by location.
+    #[pallet::storage]
+    #[pallet::getter(fn pending_asset)]
+    pub type PendingAssets<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::Location, PendingAsset<T::AccountId, BlockNumberFor<T>>>;
+
+    /// Locations of the pending assets activating at each block.
+    #[pallet::storage]
+    pub type PendingActivations<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        BlockNumberFor<T>,
+        BoundedVec<T::Location, T::MaxActivationsPerBlock>,
+        ValueQuery,
+    >;
+
+    /// Dynamic pricing state of the assets whose units-per-second track their DEX price.
+    #[pallet::storage]
+    #[pallet::getter(fn dynamic_pricing)]
+    pub type DynamicPricing<T: Config> =
+        CountedStorageMap<_, Blake2_128Concat, T::AssetId, DynamicPrice<BlockNumberFor<T>>>;
+
+    /// Assets frozen ahead of their deregistration, which cannot be transferred in or out by XCM
+    /// nor made private anymore.
+    #[pallet::storage]
+    pub type FrozenAssets<T: Config> = StorageMap<_, Blake2_128Concat, T::AssetId, ()>;
+
+    #[pallet::hooks]
+    impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+        fn on_initialize(n: BlockNumberFor<T>) -> Weight {
+            let mut weight = T::DbWeight::get().reads(1);
+            if !DynamicPricing::<T>::count().is_zero() {
+                weight.saturating_accrue(T::WeightInfo::update_dynamic_prices(
+                    Self::update_dynamic_prices(n),
+                ));
+            }
+
+            let locations = PendingActivations::<T>::take(n);
+            if locations.is_empty() {
+                return weight.saturating_add(T::DbWeight::get().reads(1));
+            }
+            let count = locations.len() as u32;
+            for location in locations {
+                // A vetoed asset may have been proposed again, activating at a later block.
+                let pending = match PendingAssets::<T>::get(&location) {
+                    Some(pending) if pending.activates_at == n => pending,
+                    _ => continue,
+                };
+                PendingAssets::<T>::remove(&location);
+                // The deposit is returned whether or not the asset could be registered.
+                let _ = <T::AssetConfig as AssetConfig<T>>::FungibleLedger::transfer(
+                    <T::AssetConfig as AssetConfig<T>>::NativeAssetId::get(),
+                    &Self::account_id(),
+                    &pending.depositor,
+                    pending.deposit,
+                    ExistenceRequirement::AllowDeath,
+                );
+                if let Err(error) = with_storage_layer(|| {
+                    Self::do_activate_pending_asset(location.clone(), pending)
+                }) {
+                    Self::deposit_event(Event::<T>::PendingAssetActivationFailed {
+                        location,
+                        error,
+                    });
+                }
+            }
+            weight.saturating_add(T::WeightInfo::activate_pending_assets(count))
+        }
+    }
+
     #[pallet::call]
     impl<T: Config> Pallet<T> {
         /// Register a new asset in the asset manager.
@@ -495,6 +768,10 @@ pub mod pallet {
                 AssetIdLocation::<T>::contains_key(asset_id),
                 Error::<T>::UpdateNonExistentAsset
             );
+            ensure!(
+                !FrozenAssets::<T>::contains_key(asset_id),
+                Error::<T>::AssetFrozen
+            );
             ensure!(
                 !LocationAssetId::<T>::contains_key(&location),
                 Error::<T>::LocationAlreadyExists
@@ -512,17 +789,7 @@ pub mod pallet {
             if let Some(old_para_id) =
                 Self::para_id_from_multilocation(old_location.into().as_ref())
             {
-                if AllowedDestParaIds::<T>::get(old_para_id) <= Some(<AssetCount as One>::one()) {
-                    AllowedDestParaIds::<T>::remove(old_para_id);
-                } else {
-                    AllowedDestParaIds::<T>::try_mutate(old_para_id, |cnt| -> DispatchResult {
-                        let new_cnt = cnt
-                            .map(|c| c - <AssetCount as One>::one())
-                            .ok_or(Error::<T>::UpdateParaIdError)?;
-                        *cnt = Some(new_cnt);
-                        Ok(())
-                    })?;
-                }
+                Self::decrease_count_of_associated_assets(*old_para_id);
             }
 
             // If it's a new para id, which will be inserted with AssetCount as 1.
@@ -560,6 +827,10 @@ pub mod pallet {
                 AssetIdLocation::<T>::contains_key(asset_id),
                 Error::<T>::UpdateNonExistentAsset
             );
+            ensure!(
+                !FrozenAssets::<T>::contains_key(asset_id),
+                Error::<T>::AssetFrozen
+            );
             <T::AssetConfig as AssetConfig<T>>::AssetRegistry::update_asset_metadata(
                 &asset_id,
                 metadata.clone().into(),
@@ -616,6 +887,10 @@ pub mod pallet {
                 AssetIdLocation::<T>::contains_key(asset_id),
                 Error::<T>::UpdateNonExistentAsset
             );
+            ensure!(
+                !FrozenAssets::<T>::contains_key(asset_id),
+                Error::<T>::AssetFrozen
+            );
             <T::AssetConfig as AssetConfig<T>>::FungibleLedger::deposit_minting_with_check(
                 asset_id,
                 &beneficiary,
@@ -703,6 +978,11 @@ pub mod pallet {
                     && AssetIdMetadata::<T>::contains_key(asset_1),
                 Error::<T>::AssetIdNotExist
             );
+            ensure!(
+                !FrozenAssets::<T>::contains_key(asset_0)
+                    && !FrozenAssets::<T>::contains_key(asset_1),
+                Error::<T>::AssetFrozen
+            );
 
             let (asset_id0, asset_id1) = Self::sort_asset_id(asset_0, asset_1);
             ensure!(
@@ -714,6 +994,8 @@ pub mod pallet {
 
             AssetIdPairToLp::<T>::insert((asset_id0, asset_id1), asset_id);
             LpToAssetIdPair::<T>::insert(asset_id, (asset_id0, asset_id1));
+            AssetIdToLp::<T>::insert(asset_id0, asset_id, ());
+            AssetIdToLp::<T>::insert(asset_id1, asset_id, ());
 
             Self::deposit_event(Event::<T>::LPAssetRegistered {
                 asset_id0,
@@ -794,6 +1076,298 @@ pub mod pallet {
             });
             Ok(())
         }
+
+        /// Propose an asset of the calling sibling parachain, registered once the challenge
+        /// period ends unless vetoed. A proposed asset is never sufficient, and the
+        /// `AssetProposalDeposit` is taken from the sovereign account of the parachain.
+        ///
+        /// * `origin`: Caller of this extrinsic, the access control is specified by `SiblingAssetOrigin`.
+        /// * `location`: Location of the asset, which must be on the calling parachain.
+        /// * `metadata`: Asset metadata, whose min balance must be within `ProposedMinBalanceBounds`.
+        /// * `units_per_second`: units per second for the asset, within `ProposedUnitsPerSecondBounds`.
+        #[pallet::call_index(9)]
+        #[pallet::weight(T::WeightInfo::propose_asset())]
+        #[transactional]
+        pub fn propose_asset(
+            origin: OriginFor<T>,
+            location: T::Location,
+            metadata: AssetRegistryMetadata<Balance>,
+            #[pallet::compact] units_per_second: u128,
+        ) -> DispatchResult {
+            let (para_id, depositor) = T::SiblingAssetOrigin::ensure_origin(origin)?;
+            let multilocation: Option<MultiLocation> = location.clone().into();
+            ensure!(
+                multilocation.map(|l| l.parents) == Some(1)
+                    && Self::para_id_from_multilocation(multilocation.as_ref()) == Some(&para_id),
+                Error::<T>::LocationNotFromOrigin
+            );
+            ensure!(
+                !LocationAssetId::<T>::contains_key(&location),
+                Error::<T>::LocationAlreadyExists
+            );
+            ensure!(
+                !PendingAssets::<T>::contains_key(&location),
+                Error::<T>::AssetAlreadyPending
+            );
+            let (min_balance_lower, min_balance_upper) = T::ProposedMinBalanceBounds::get();
+            ensure!(
+                (min_balance_lower..=min_balance_upper).contains(metadata.min_balance()),
+                Error::<T>::MinBalanceOutOfBounds
+            );
+            let (units_per_second_lower, units_per_second_upper) =
+                T::ProposedUnitsPerSecondBounds::get();
+            ensure!(
+                (units_per_second_lower..=units_per_second_upper).contains(&units_per_second),
+                Error::<T>::UnitsPerSecondOutOfBounds
+            );
+            let metadata = AssetRegistryMetadata {
+                is_sufficient: false,
+                ..metadata
+            };
+
+            let deposit = T::AssetProposalDeposit::get();
+            <T::AssetConfig as AssetConfig<T>>::FungibleLedger::transfer(
+                <T::AssetConfig as AssetConfig<T>>::NativeAssetId::get(),
+                &depositor,
+                &Self::account_id(),
+                deposit,
+                ExistenceRequirement::AllowDeath,
+            )
+            .map_err(|_| Error::<T>::NotEnoughNativeFunds)?;
+
+            let activates_at =
+                frame_system::Pallet::<T>::block_number() + T::AssetChallengePeriod::get();
+            PendingActivations::<T>::try_append(activates_at, location.clone())
+                .map_err(|_| Error::<T>::TooManyPendingAssets)?;
+            PendingAssets::<T>::insert(
+                &location,
+                PendingAsset {
+                    para_id,
+                    depositor,
+                    deposit,
+                    metadata: metadata.clone(),
+                    units_per_second,
+                    activates_at,
+                },
+            );
+
+            Self::deposit_event(Event::<T>::AssetProposed {
+                para_id,
+                location,
+                metadata,
+                units_per_second,
+                activates_at,
+            });
+            Ok(())
+        }
+
+        /// Veto a pending asset, which will not be registered. The deposit of the proposing
+        /// parachain is kept.
+        ///
+        /// * `origin`: Caller of this extrinsic, the access control is specified by `VetoOrigin`.
+        /// * `location`: Location of the pending asset.
+        #[pallet::call_index(10)]
+        #[pallet::weight(T::WeightInfo::veto_pending_asset())]
+        #[transactional]
+        pub fn veto_pending_asset(origin: OriginFor<T>, location: T::Location) -> DispatchResult {
+            T::VetoOrigin::ensure_origin(origin)?;
+            ensure!(
+                PendingAssets::<T>::contains_key(&location),
+                Error::<T>::PendingAssetNotExist
+            );
+            // The location left in `PendingActivations` is skipped once its block comes.
+            PendingAssets::<T>::remove(&location);
+            Self::deposit_event(Event::<T>::PendingAssetVetoed { location });
+            Ok(())
+        }
+
+        /// Enable, update or disable dynamic pricing of an asset, whose units-per-second is then
+        /// derived from its time-weighted average DEX price against the native token.
+        ///
+        /// * `origin`: Caller of this extrinsic, the access control is specified by `ModifierOrigin`.
+        /// * `asset_id`: AssetId to be updated.
+        /// * `bounds`: Lower and upper bounds of the derived units-per-second, `None` to disable
+        ///  dynamic pricing and use the units-per-second set by `set_units_per_second`.
+        #[pallet::call_index(11)]
+        #[pallet::weight(T::WeightInfo::set_dynamic_pricing())]
+        #[transactional]
+        pub fn set_dynamic_pricing(
+            origin: OriginFor<T>,
+            #[pallet::compact] asset_id: T::AssetId,
+            bounds: Option<(u128, u128)>,
+        ) -> DispatchResult {
+            T::ModifierOrigin::ensure_origin(origin)?;
+            let (min_units_per_second, max_units_per_second) = match bounds {
+                Some(bounds) => bounds,
+                None => {
+                    ensure!(
+                        DynamicPricing::<T>::contains_key(asset_id),
+                        Error::<T>::DynamicPricingNotSet
+                    );
+                    DynamicPricing::<T>::remove(asset_id);
+                    Self::deposit_event(Event::<T>::DynamicPricingRemoved { asset_id });
+                    return Ok(());
+                }
+            };
+            ensure!(
+                AssetIdLocation::<T>::contains_key(asset_id),
+                Error::<T>::UpdateNonExistentAsset
+            );
+            ensure!(
+                min_units_per_second <= max_units_per_second,
+                Error::<T>::InvalidPriceBounds
+            );
+
+            let pricing = match DynamicPricing::<T>::get(asset_id) {
+                Some(mut pricing) => {
+                    pricing.min_units_per_second = min_units_per_second;
+                    pricing.max_units_per_second = max_units_per_second;
+                    pricing.units_per_second = pricing.units_per_second.map(|u| pricing.clamp(u));
+                    pricing
+                }
+                None => {
+                    ensure!(
+                        DynamicPricing::<T>::count() < T::MaxDynamicPricedAssets::get(),
+                        Error::<T>::TooManyDynamicPricedAssets
+                    );
+                    DynamicPrice::new(
+                        min_units_per_second,
+                        max_units_per_second,
+                        frame_system::Pallet::<T>::block_number(),
+                    )
+                }
+            };
+            DynamicPricing::<T>::insert(asset_id, pricing);
+            Self::deposit_event(Event::<T>::DynamicPricingSet {
+                asset_id,
+                min_units_per_second,
+                max_units_per_second,
+            });
+            Ok(())
+        }
+
+        /// Freeze an asset ahead of its deregistration. A frozen asset cannot be transferred in or
+        /// out by XCM nor made private, and its metadata is frozen.
+        ///
+        /// * `origin`: Caller of this extrinsic, the access control is specified by `SuspenderOrigin`.
+        /// * `asset_id`: AssetId to be frozen.
+        #[pallet::call_index(12)]
+        #[pallet::weight(T::WeightInfo::freeze_asset())]
+        #[transactional]
+        pub fn freeze_asset(
+            origin: OriginFor<T>,
+            #[pallet::compact] asset_id: T::AssetId,
+        ) -> DispatchResult {
+            T::SuspenderOrigin::ensure_origin(origin)?;
+            ensure!(
+                asset_id != <T::AssetConfig as AssetConfig<T>>::NativeAssetId::get(),
+                Error::<T>::CannotFreezeNativeAsset
+            );
+            let metadata =
+                AssetIdMetadata::<T>::get(asset_id).ok_or(Error::<T>::UpdateNonExistentAsset)?;
+            ensure!(
+                !FrozenAssets::<T>::contains_key(asset_id),
+                Error::<T>::AssetFrozen
+            );
+
+            let mut metadata: AssetStorageMetadata = metadata.into();
+            metadata.is_frozen = true;
+            <T::AssetConfig as AssetConfig<T>>::AssetRegistry::update_asset_metadata(
+                &asset_id, metadata,
+            )?;
+            FrozenAssets::<T>::insert(asset_id, ());
+            Self::deposit_event(Event::<T>::AssetFrozen { asset_id });
+            Ok(())
+        }
+
+        /// Unfreeze an asset, restoring its registered metadata.
+        ///
+        /// * `origin`: Caller of this extrinsic, the access control is specified by `ModifierOrigin`.
+        /// * `asset_id`: AssetId to be unfrozen.
+        #[pallet::call_index(13)]
+        #[pallet::weight(T::WeightInfo::unfreeze_asset())]
+        #[transactional]
+        pub fn unfreeze_asset(
+            origin: OriginFor<T>,
+            #[pallet::compact] asset_id: T::AssetId,
+        ) -> DispatchResult {
+            T::ModifierOrigin::ensure_origin(origin)?;
+            ensure!(
+                FrozenAssets::<T>::contains_key(asset_id),
+                Error::<T>::AssetNotFrozen
+            );
+            let metadata =
+                AssetIdMetadata::<T>::get(asset_id).ok_or(Error::<T>::UpdateNonExistentAsset)?;
+
+            <T::AssetConfig as AssetConfig<T>>::AssetRegistry::update_asset_metadata(
+                &asset_id,
+                metadata.into(),
+            )?;
+            FrozenAssets::<T>::remove(asset_id);
+            Self::deposit_event(Event::<T>::AssetUnfrozen { asset_id });
+            Ok(())
+        }
+
+        /// Deregister a frozen asset without any supply left, destroying it and removing all of
+        /// its XCM configuration.
+        ///
+        /// An LP asset is deregistered along with its asset pair, while an asset of a registered
+        /// LP asset cannot be deregistered before it.
+        ///
+        /// * `origin`: Caller of this extrinsic, the access control is specified by `ModifierOrigin`.
+        /// * `asset_id`: AssetId to be deregistered.
+        #[pallet::call_index(14)]
+        #[pallet::weight(T::WeightInfo::deregister_asset())]
+        #[transactional]
+        pub fn deregister_asset(
+            origin: OriginFor<T>,
+            #[pallet::compact] asset_id: T::AssetId,
+        ) -> DispatchResult {
+            T::ModifierOrigin::ensure_origin(origin)?;
+            ensure!(
+                FrozenAssets::<T>::contains_key(asset_id),
+                Error::<T>::AssetNotFrozen
+            );
+            ensure!(
+                <T::AssetConfig as AssetConfig<T>>::FungibleLedger::supply(asset_id).is_zero(),
+                Error::<T>::AssetSupplyNotZero
+            );
+            ensure!(
+                AssetIdToLp::<T>::iter_key_prefix(asset_id).next().is_none(),
+                Error::<T>::AssetInLpPair
+            );
+
+            <T::AssetConfig as AssetConfig<T>>::AssetRegistry::destroy_asset(&asset_id)?;
+            if let Some((asset_id0, asset_id1)) = LpToAssetIdPair::<T>::take(asset_id) {
+                AssetIdPairToLp::<T>::remove((asset_id0, asset_id1));
+                AssetIdToLp::<T>::remove(asset_id0, asset_id);
+                AssetIdToLp::<T>::remove(asset_id1, asset_id);
+            }
+            AssetIdMetadata::<T>::remove(asset_id);
+            UnitsPerSecond::<T>::remove(asset_id);
+            DynamicPricing::<T>::remove(asset_id);
+            FrozenAssets::<T>::remove(asset_id);
+
+            let location = AssetIdLocation::<T>::take(asset_id);
+            if let Some(location) = &location {
+                LocationAssetId::<T>::remove(location);
+                let multilocation: Option<MultiLocation> = location.clone().into();
+                FilteredOutgoingAssetLocations::<T>::remove(multilocation);
+                if let Some(para_id) = Self::para_id_from_multilocation(multilocation.as_ref()) {
+                    // The min xcm fee of the reserve chain is only needed while some of its
+                    // assets are registered.
+                    if Self::decrease_count_of_associated_assets(*para_id) {
+                        MinXcmFee::<T>::remove(T::Location::from(MultiLocation::new(
+                            1,
+                            X1(Parachain(*para_id)),
+                        )));
+                    }
+                }
+            }
+
+            Self::deposit_event(Event::<T>::AssetDeregistered { asset_id, location });
+            Ok(())
+        }
     }
 
     impl<T> Pallet<T>
@@ -827,6 +1401,27 @@ pub mod pallet {
             Ok(asset_id)
         }
 
+        /// Registers a pending asset whose challenge period ended.
+        fn do_activate_pending_asset(
+            location: T::Location,
+            pending: PendingAsset<T::AccountId, BlockNumberFor<T>>,
+        ) -> DispatchResult {
+            let asset_id = Self::do_register_asset(Some(&location), &pending.metadata)?;
+            Self::increase_count_of_associated_assets(pending.para_id)?;
+            UnitsPerSecond::<T>::insert(asset_id, pending.units_per_second);
+
+            Self::deposit_event(Event::<T>::AssetRegistered {
+                asset_id,
+                location,
+                metadata: pending.metadata,
+            });
+            Self::deposit_event(Event::<T>::UnitsPerSecondUpdated {
+                asset_id,
+                units_per_second: pending.units_per_second,
+            });
+            Ok(())
+        }
+
         /// Returns and increments the [`NextAssetId`] by one. Fails if it hits the upper limit of `PermissionlessStartId`
         #[inline]
         pub(super) fn next_asset_id_and_increment() -> Result<T::AssetId, DispatchError> {
@@ -899,8 +1494,30 @@ pub mod pallet {
             }
         }
 
+        /// Decreases the count of associated assets for the para id, which is removed once it has
+        /// no asset left. Returns `true` if the para id was removed.
+        pub fn decrease_count_of_associated_assets(para_id: ParaId) -> bool {
+            AllowedDestParaIds::<T>::mutate_exists(para_id, |count| match count {
+                Some(count) if *count > <AssetCount as One>::one() => {
+                    *count -= <AssetCount as One>::one();
+                    false
+                }
+                Some(_) => {
+                    *count = None;
+                    true
+                }
+                None => false,
+            })
+        }
+
+        /// Returns `true` if the asset at `asset_location` must not be transferred out of the
+        /// chain, either because its location is filtered or because the asset is frozen.
         pub fn check_outgoing_assets_filter(asset_location: &Option<MultiLocation>) -> bool {
             FilteredOutgoingAssetLocations::<T>::contains_key(asset_location)
+                || asset_location.map_or(false, |location| {
+                    LocationAssetId::<T>::get(T::Location::from(location))
+                        .map_or(false, FrozenAssets::<T>::contains_key)
+                })
         }
 
         /// Sorted the assets pair
@@ -939,6 +1556,19 @@ pub mod pallet {
         }
     }
 
+    /// Assets which are not frozen ahead of their deregistration.
+    pub struct ActiveAssets<T>(PhantomData<T>);
+
+    impl<T> Contains<T::AssetId> for ActiveAssets<T>
+    where
+        T: Config,
+    {
+        #[inline]
+        fn contains(asset_id: &T::AssetId) -> bool {
+            !FrozenAssets::<T>::contains_key(asset_id)
+        }
+    }
+
     /// Get min-xcm-fee for reserve chain by multilocation.
     impl<T> GetByKey<MultiLocation, Option<u128>> for Pallet<T>
     where
@@ -15,9 +15,11 @@
 // along with Manta.  If not, see <http://www.gnu.org/licenses/>.
 
 //! Do storage migration for AllowedDestParaIds which records the count of associated assets for
-//! each para id.
+//! each para id, and for AssetIdToLp which indexes the LP assets of each asset.
 
 use super::*;
+#[cfg(feature = "try-runtime")]
+use codec::{Decode, Encode};
 use core::marker::PhantomData;
 use frame_support::{
     dispatch::GetStorageVersion,
@@ -102,3 +104,63 @@ where
         }
     }
 }
+
+/// Storage migration to index the existing LP assets by
+/// their asset pair in the new AssetIdToLp storage item
+pub struct AssetIdToLpMigration<T>(PhantomData<T>);
+
+impl<T> OnRuntimeUpgrade for AssetIdToLpMigration<T>
+where
+    T: GetStorageVersion + Config + PalletInfoAccess,
+{
+    fn on_runtime_upgrade() -> Weight {
+        let storage_version = <T as GetStorageVersion>::on_chain_storage_version();
+        if storage_version == 2 {
+            log::info!(target: "asset-manager", "Start to execute storage migration for asset-manager.");
+            let mut reads: u64 = 1;
+            let mut writes: u64 = 0;
+            LpToAssetIdPair::<T>::iter().for_each(|(lp_asset_id, (asset_id0, asset_id1))| {
+                reads += 1;
+                AssetIdToLp::<T>::insert(asset_id0, lp_asset_id, ());
+                AssetIdToLp::<T>::insert(asset_id1, lp_asset_id, ());
+                writes += 2;
+            });
+            // Update storage version.
+            StorageVersion::new(3u16).put::<T>();
+            writes += 1;
+            T::DbWeight::get()
+                .reads(reads)
+                .saturating_add(T::DbWeight::get().writes(writes))
+        } else {
+            log::info!("✅ no migration for asset-manager.");
+            // only 1 read
+            T::DbWeight::get().reads(1)
+        }
+    }
+
+    #[cfg(feature = "try-runtime")]
+    fn pre_upgrade() -> Result<Vec<u8>, DispatchError> {
+        let lp_assets = LpToAssetIdPair::<T>::iter_keys().count() as u32;
+        Ok(lp_assets.encode())
+    }
+
+    #[cfg(feature = "try-runtime")]
+    fn post_upgrade(state: Vec<u8>) -> Result<(), DispatchError> {
+        let storage_version = <T as GetStorageVersion>::on_chain_storage_version();
+        if storage_version < 3 {
+            return Err(DispatchError::Other(
+                "Storage version is < 3, the migration wasn't executed.",
+            ));
+        }
+        let lp_assets = u32::decode(&mut &state[..])
+            .map_err(|_| DispatchError::Other("Failed to decode the pre-upgrade state."))?;
+        if AssetIdToLp::<T>::iter_keys().count() as u32 == lp_assets.saturating_mul(2) {
+            log::info!("✅ Storage migration for asset-manager has been executed successfully.");
+            Ok(())
+        } else {
+            Err(DispatchError::Other(
+                "Failed to executed storage migration for asset-manager.",
+            ))
+        }
+    }
+}
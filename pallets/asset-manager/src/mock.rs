@@ -25,7 +25,7 @@ use frame_support::{
     construct_runtime,
     pallet_prelude::DispatchResult,
     parameter_types,
-    traits::{AsEnsureOriginWithArg, ConstU128, ConstU32},
+    traits::{fungibles, AsEnsureOriginWithArg, ConstU128, ConstU32, EnsureOrigin, Get},
     PalletId,
 };
 use frame_system as system;
@@ -179,6 +179,14 @@ impl AssetRegistry for MantaAssetRegistry {
             metadata.is_frozen,
         )
     }
+
+    fn destroy_asset(asset_id: &CalamariAssetId) -> DispatchResult {
+        let limit = <Runtime as pallet_assets::Config>::RemoveItemsLimit::get();
+        <Assets as fungibles::Destroy<AccountId>>::start_destroy(*asset_id, None)?;
+        <Assets as fungibles::Destroy<AccountId>>::destroy_accounts(*asset_id, limit)?;
+        <Assets as fungibles::Destroy<AccountId>>::destroy_approvals(*asset_id, limit)?;
+        <Assets as fungibles::Destroy<AccountId>>::finish_destroy(*asset_id)
+    }
 }
 
 parameter_types! {
@@ -220,6 +228,46 @@ impl AssetConfig<Runtime> for MantaAssetConfig {
     type FungibleLedger = NativeAndNonNative<Runtime, MantaAssetConfig, Balances, Assets>;
 }
 
+pub const SIBLING_PARA_ID: u32 = 2000;
+pub const SIBLING_ACCOUNT: AccountId = AccountId::new([20u8; 32]);
+
+/// Stands in for the origin of an XCM `Transact` sent by sibling parachain [`SIBLING_PARA_ID`].
+pub struct EnsureSibling;
+impl EnsureOrigin<RuntimeOrigin> for EnsureSibling {
+    type Success = (u32, AccountId);
+
+    fn try_origin(o: RuntimeOrigin) -> Result<Self::Success, RuntimeOrigin> {
+        match o.clone().into() {
+            Ok(system::RawOrigin::Signed(who)) if who == SIBLING_ACCOUNT => {
+                Ok((SIBLING_PARA_ID, SIBLING_ACCOUNT))
+            }
+            _ => Err(o),
+        }
+    }
+
+    #[cfg(feature = "runtime-benchmarks")]
+    fn try_successful_origin() -> Result<RuntimeOrigin, ()> {
+        Ok(RuntimeOrigin::signed(SIBLING_ACCOUNT))
+    }
+}
+
+parameter_types! {
+    /// Reserves of every asset and of the native token in their DEX pool.
+    pub static PoolReserves: Option<(Balance, Balance)> = None;
+}
+
+pub struct MockNativePrice;
+impl pallet_asset_manager::NativePriceProvider<CalamariAssetId> for MockNativePrice {
+    fn reserves(_: &CalamariAssetId) -> Option<(Balance, Balance)> {
+        PoolReserves::get()
+    }
+}
+
+parameter_types! {
+    pub const ProposedMinBalanceBounds: (Balance, Balance) = (1, 1_000);
+    pub const ProposedUnitsPerSecondBounds: (u128, u128) = (1, 1_000_000);
+}
+
 impl pallet_asset_manager::Config for Runtime {
     type RuntimeEvent = RuntimeEvent;
     type AssetId = CalamariAssetId;
@@ -232,6 +280,17 @@ impl pallet_asset_manager::Config for Runtime {
     type TokenNameMaxLen = ConstU32<100>;
     type TokenSymbolMaxLen = ConstU32<100>;
     type PermissionlessAssetRegistryCost = ConstU128<1000>;
+    type SiblingAssetOrigin = EnsureSibling;
+    type VetoOrigin = EnsureRoot<AccountId>;
+    type AssetChallengePeriod = ConstU32<10>;
+    type MaxActivationsPerBlock = ConstU32<2>;
+    type AssetProposalDeposit = ConstU128<100>;
+    type ProposedMinBalanceBounds = ProposedMinBalanceBounds;
+    type ProposedUnitsPerSecondBounds = ProposedUnitsPerSecondBounds;
+    type NativePrice = MockNativePrice;
+    type NativeUnitsPerSecond = ConstU128<1_000_000>;
+    type SpotPriceAveragingPeriod = ConstU32<4>;
+    type MaxDynamicPricedAssets = ConstU32<2>;
     type WeightInfo = ();
 }
 
@@ -0,0 +1,148 @@
+// Copyright 2020-2024 Manta Network.
+// This file is part of Manta.
+//
+// Manta is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// Manta is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Manta.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Dynamic XCM fee pricing.
+//!
+//! Assets with dynamic pricing enabled have their units-per-second derived from a time-weighted
+//! average (TWAP) of their spot DEX price against the native token over `SpotPriceAveragingPeriod`
+//! blocks, clamped to the bounds set by governance. The spot price is observed once per block, and
+//! each observation is accumulated multiplied by the number of blocks it lasted until the next one.
+//! Blocks in which the pool had no price are left out of the average. A pool held off its price
+//! still moves the average in proportion to the blocks it was held for, which the governance bounds
+//! limit. The stored [`UnitsPerSecond`] is used until a first average is available, or whenever a
+//! whole period passes without any price.
+
+use crate::*;
+use codec::{Decode, Encode};
+use frame_support::pallet_prelude::Get;
+use frame_system::pallet_prelude::BlockNumberFor;
+use manta_primitives::types::Balance;
+use scale_info::TypeInfo;
+use sp_runtime::{
+    traits::{CheckedDiv, SaturatedConversion, Saturating, Zero},
+    FixedPointNumber, FixedU128, RuntimeDebug,
+};
+
+/// A DEX quoting assets against the native token.
+pub trait NativePriceProvider<AssetId> {
+    /// Returns the reserves of `asset_id` and of the native token in their pool, in this order.
+    fn reserves(asset_id: &AssetId) -> Option<(Balance, Balance)>;
+}
+
+impl<AssetId> NativePriceProvider<AssetId> for () {
+    fn reserves(_: &AssetId) -> Option<(Balance, Balance)> {
+        None
+    }
+}
+
+/// Dynamic Pricing State of an Asset
+#[derive(Clone, Decode, Encode, Eq, PartialEq, RuntimeDebug, TypeInfo)]
+pub struct DynamicPrice<BlockNumber> {
+    /// Lower bound of the derived units-per-second
+    pub min_units_per_second: u128,
+
+    /// Upper bound of the derived units-per-second
+    pub max_units_per_second: u128,
+
+    /// Block at which the current averaging period started
+    pub period_start: BlockNumber,
+
+    /// Block of the last spot price observation
+    pub last_observation: BlockNumber,
+
+    /// Spot price observed at `last_observation`, in asset units per native unit
+    pub last_price: Option<FixedU128>,
+
+    /// Sum of the spot prices observed in the current period, each multiplied by the number of
+    /// blocks it lasted
+    pub price_cumulative: FixedU128,
+
+    /// Number of blocks of the current period with an observed price
+    pub priced_blocks: u32,
+
+    /// Units-per-second derived over the last period, if any price was sampled in it
+    pub units_per_second: Option<u128>,
+}
+
+impl<BlockNumber> DynamicPrice<BlockNumber>
+where
+    BlockNumber: Copy,
+{
+    /// Builds a new pricing state with an averaging period starting at `now`.
+    #[inline]
+    pub fn new(min_units_per_second: u128, max_units_per_second: u128, now: BlockNumber) -> Self {
+        Self {
+            min_units_per_second,
+            max_units_per_second,
+            period_start: now,
+            last_observation: now,
+            last_price: None,
+            price_cumulative: Zero::zero(),
+            priced_blocks: 0,
+            units_per_second: None,
+        }
+    }
+
+    /// Clamps `units_per_second` to the bounds of `self`.
+    #[inline]
+    pub fn clamp(&self, units_per_second: u128) -> u128 {
+        units_per_second.clamp(self.min_units_per_second, self.max_units_per_second)
+    }
+}
+
+impl<T: Config> Pallet<T> {
+    /// Observes the spot price of every dynamically priced asset, accumulating the previous
+    /// observation over the blocks it lasted, and derives its units-per-second from the
+    /// time-weighted average at the end of each averaging period. Returns the number of assets
+    /// observed.
+    pub(crate) fn update_dynamic_prices(now: BlockNumberFor<T>) -> u32 {
+        let mut count = 0;
+        DynamicPricing::<T>::translate(|asset_id, mut pricing: DynamicPrice<_>| {
+            count += 1;
+            if let Some(price) = pricing.last_price {
+                let elapsed: u32 = now
+                    .saturating_sub(pricing.last_observation)
+                    .saturated_into();
+                pricing.price_cumulative = pricing.price_cumulative.saturating_add(
+                    price.saturating_mul(FixedU128::saturating_from_integer(elapsed)),
+                );
+                pricing.priced_blocks = pricing.priced_blocks.saturating_add(elapsed);
+            }
+            pricing.last_price = T::NativePrice::reserves(&asset_id)
+                .and_then(|(asset, native)| FixedU128::checked_from_rational(asset, native));
+            pricing.last_observation = now;
+            if now.saturating_sub(pricing.period_start) >= T::SpotPriceAveragingPeriod::get() {
+                pricing.units_per_second = pricing
+                    .price_cumulative
+                    .checked_div(&FixedU128::saturating_from_integer(pricing.priced_blocks))
+                    .map(|average| {
+                        pricing.clamp(average.saturating_mul_int(T::NativeUnitsPerSecond::get()))
+                    });
+                if let Some(units_per_second) = pricing.units_per_second {
+                    Self::deposit_event(Event::<T>::DynamicUnitsPerSecondUpdated {
+                        asset_id,
+                        units_per_second,
+                    });
+                }
+                pricing.period_start = now;
+                pricing.price_cumulative = Zero::zero();
+                pricing.priced_blocks = 0;
+            }
+            Some(pricing)
+        });
+        count
+    }
+}
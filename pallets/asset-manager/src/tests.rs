@@ -17,15 +17,22 @@
 //! unit tests for asset-manager
 
 use crate::{
-    mock::*, AssetIdLocation, AssetIdMetadata, AssetIdPairToLp, Error, LocationAssetId,
-    LpToAssetIdPair, NextAssetId, UnitsPerSecond,
+    mock::*, ActiveAssets, AssetIdLocation, AssetIdMetadata, AssetIdPairToLp, AssetIdToLp, Error,
+    LocationAssetId, LpToAssetIdPair, NextAssetId, UnitsPerSecond,
 };
 use frame_support::{
     assert_noop, assert_ok,
-    traits::{fungibles::metadata::Inspect, Contains},
+    traits::{
+        fungibles::{self, metadata::Inspect},
+        tokens::ExistenceRequirement,
+        Contains, Hooks,
+    },
 };
 use manta_primitives::{
-    assets::{AssetConfig, AssetLocation, AssetRegistryMetadata, FungibleLedger},
+    assets::{
+        AssetConfig, AssetIdLocationMap, AssetLocation, AssetRegistryMetadata, FungibleLedger,
+        UnitsPerSecond as _,
+    },
     types::Balance,
 };
 use orml_traits::GetByKey;
@@ -883,3 +890,589 @@ fn counters_test() {
         );
     });
 }
+
+fn sibling_asset_location(index: u128) -> AssetLocation {
+    AssetLocation(VersionedMultiLocation::V3(MultiLocation::new(
+        1,
+        X2(Parachain(SIBLING_PARA_ID), GeneralIndex(index)),
+    )))
+}
+
+const SIBLING_FUNDS: Balance = 1_000_000;
+
+fn fund_sibling_account() {
+    assert_ok!(
+        <MantaAssetConfig as AssetConfig<Runtime>>::FungibleLedger::deposit_minting(
+            <MantaAssetConfig as AssetConfig<Runtime>>::NativeAssetId::get(),
+            &SIBLING_ACCOUNT,
+            SIBLING_FUNDS,
+        )
+    );
+}
+
+#[test]
+fn propose_asset_works() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        fund_sibling_account();
+        let deposit: Balance = <Runtime as crate::pallet::Config>::AssetProposalDeposit::get();
+        let metadata = create_asset_metadata("Sibling", "SBL", 12, 1u128, false, true);
+        // A proposed asset is never sufficient.
+        let registered_metadata = create_asset_metadata("Sibling", "SBL", 12, 1u128, false, false);
+        let location = sibling_asset_location(0);
+
+        assert_noop!(
+            AssetManager::propose_asset(
+                RuntimeOrigin::signed([0u8; 32].into()),
+                location.clone(),
+                metadata.clone(),
+                1_000
+            ),
+            BadOrigin
+        );
+        // Only locations on the proposing parachain itself are accepted.
+        for foreign in [
+            MultiLocation::new(1, X2(Parachain(2001), GeneralIndex(0))),
+            MultiLocation::new(0, X2(Parachain(SIBLING_PARA_ID), GeneralIndex(0))),
+            MultiLocation::parent(),
+        ] {
+            assert_noop!(
+                AssetManager::propose_asset(
+                    RuntimeOrigin::signed(SIBLING_ACCOUNT),
+                    AssetLocation(VersionedMultiLocation::V3(foreign)),
+                    metadata.clone(),
+                    1_000
+                ),
+                Error::<Runtime>::LocationNotFromOrigin
+            );
+        }
+
+        assert_ok!(AssetManager::propose_asset(
+            RuntimeOrigin::signed(SIBLING_ACCOUNT),
+            location.clone(),
+            metadata.clone(),
+            1_000
+        ));
+        System::assert_last_event(RuntimeEvent::AssetManager(crate::Event::AssetProposed {
+            para_id: SIBLING_PARA_ID,
+            location: location.clone(),
+            metadata: registered_metadata.clone(),
+            units_per_second: 1_000,
+            activates_at: 11,
+        }));
+        assert_eq!(
+            Balances::free_balance(&SIBLING_ACCOUNT),
+            SIBLING_FUNDS - deposit
+        );
+        assert_noop!(
+            AssetManager::propose_asset(
+                RuntimeOrigin::signed(SIBLING_ACCOUNT),
+                location.clone(),
+                metadata.clone(),
+                1_000
+            ),
+            Error::<Runtime>::AssetAlreadyPending
+        );
+
+        // Nothing is registered before the challenge period ends.
+        let asset_id = NextAssetId::<Runtime>::get();
+        AssetManager::on_initialize(10);
+        assert_eq!(LocationAssetId::<Runtime>::get(&location), None);
+
+        AssetManager::on_initialize(11);
+        assert_eq!(LocationAssetId::<Runtime>::get(&location), Some(asset_id));
+        assert_eq!(
+            AssetIdMetadata::<Runtime>::get(asset_id),
+            Some(registered_metadata.clone())
+        );
+        assert_eq!(UnitsPerSecond::<Runtime>::get(asset_id), Some(1_000));
+        assert_eq!(AssetManager::get_para_id(SIBLING_PARA_ID), Some(1));
+        assert_eq!(AssetManager::pending_asset(&location), None);
+        assert_eq!(Balances::free_balance(&SIBLING_ACCOUNT), SIBLING_FUNDS);
+        System::assert_has_event(RuntimeEvent::AssetManager(crate::Event::AssetRegistered {
+            asset_id,
+            location: location.clone(),
+            metadata: registered_metadata,
+        }));
+
+        assert_noop!(
+            AssetManager::propose_asset(
+                RuntimeOrigin::signed(SIBLING_ACCOUNT),
+                location,
+                metadata,
+                1_000
+            ),
+            Error::<Runtime>::LocationAlreadyExists
+        );
+    });
+}
+
+#[test]
+fn veto_pending_asset_works() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        fund_sibling_account();
+        let deposit: Balance = <Runtime as crate::pallet::Config>::AssetProposalDeposit::get();
+        let metadata = create_asset_metadata("Sibling", "SBL", 12, 1u128, false, true);
+        let location = sibling_asset_location(0);
+        assert_ok!(AssetManager::propose_asset(
+            RuntimeOrigin::signed(SIBLING_ACCOUNT),
+            location.clone(),
+            metadata.clone(),
+            1_000
+        ));
+
+        assert_noop!(
+            AssetManager::veto_pending_asset(
+                RuntimeOrigin::signed(SIBLING_ACCOUNT),
+                location.clone()
+            ),
+            BadOrigin
+        );
+        assert_ok!(AssetManager::veto_pending_asset(
+            RuntimeOrigin::root(),
+            location.clone()
+        ));
+        assert_noop!(
+            AssetManager::veto_pending_asset(RuntimeOrigin::root(), location.clone()),
+            Error::<Runtime>::PendingAssetNotExist
+        );
+        // The deposit of a vetoed asset is kept.
+        assert_eq!(
+            Balances::free_balance(&SIBLING_ACCOUNT),
+            SIBLING_FUNDS - deposit
+        );
+
+        // Proposed again, the asset only activates at the end of its new challenge period.
+        System::set_block_number(5);
+        assert_ok!(AssetManager::propose_asset(
+            RuntimeOrigin::signed(SIBLING_ACCOUNT),
+            location.clone(),
+            metadata,
+            1_000
+        ));
+        let asset_id = NextAssetId::<Runtime>::get();
+        AssetManager::on_initialize(11);
+        assert_eq!(LocationAssetId::<Runtime>::get(&location), None);
+        assert!(AssetManager::pending_asset(&location).is_some());
+        AssetManager::on_initialize(15);
+        assert_eq!(LocationAssetId::<Runtime>::get(&location), Some(asset_id));
+    });
+}
+
+#[test]
+fn pending_assets_per_block_are_bounded() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        fund_sibling_account();
+        let metadata = create_asset_metadata("Sibling", "SBL", 12, 1u128, false, true);
+        for index in 0..2 {
+            assert_ok!(AssetManager::propose_asset(
+                RuntimeOrigin::signed(SIBLING_ACCOUNT),
+                sibling_asset_location(index),
+                metadata.clone(),
+                1_000
+            ));
+        }
+        assert_noop!(
+            AssetManager::propose_asset(
+                RuntimeOrigin::signed(SIBLING_ACCOUNT),
+                sibling_asset_location(2),
+                metadata.clone(),
+                1_000
+            ),
+            Error::<Runtime>::TooManyPendingAssets
+        );
+
+        System::set_block_number(2);
+        assert_ok!(AssetManager::propose_asset(
+            RuntimeOrigin::signed(SIBLING_ACCOUNT),
+            sibling_asset_location(2),
+            metadata,
+            1_000
+        ));
+    });
+}
+
+#[test]
+fn failed_activation_is_reported() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        fund_sibling_account();
+        let metadata = create_asset_metadata("Sibling", "SBL", 12, 1u128, false, true);
+        let location = sibling_asset_location(0);
+        assert_ok!(AssetManager::propose_asset(
+            RuntimeOrigin::signed(SIBLING_ACCOUNT),
+            location.clone(),
+            metadata.clone(),
+            1_000
+        ));
+        // Governance registers the location first.
+        assert_ok!(AssetManager::register_asset(
+            RuntimeOrigin::root(),
+            location.clone(),
+            metadata
+        ));
+        let next_asset_id = NextAssetId::<Runtime>::get();
+
+        AssetManager::on_initialize(11);
+        assert_eq!(NextAssetId::<Runtime>::get(), next_asset_id);
+        assert_eq!(AssetManager::get_para_id(SIBLING_PARA_ID), Some(1));
+        assert_eq!(AssetManager::pending_asset(&location), None);
+        assert_eq!(Balances::free_balance(&SIBLING_ACCOUNT), SIBLING_FUNDS);
+        System::assert_last_event(RuntimeEvent::AssetManager(
+            crate::Event::PendingAssetActivationFailed {
+                location,
+                error: Error::<Runtime>::LocationAlreadyExists.into(),
+            },
+        ));
+    });
+}
+
+#[test]
+fn propose_asset_checks_bounds_and_deposit() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        let location = sibling_asset_location(0);
+        for (min_balance, units_per_second, error) in [
+            (0, 1_000, Error::<Runtime>::MinBalanceOutOfBounds),
+            (1_001, 1_000, Error::<Runtime>::MinBalanceOutOfBounds),
+            (1, 0, Error::<Runtime>::UnitsPerSecondOutOfBounds),
+            (1, 1_000_001, Error::<Runtime>::UnitsPerSecondOutOfBounds),
+            // The sovereign account cannot pay the deposit.
+            (1, 1_000, Error::<Runtime>::NotEnoughNativeFunds),
+        ] {
+            assert_noop!(
+                AssetManager::propose_asset(
+                    RuntimeOrigin::signed(SIBLING_ACCOUNT),
+                    location.clone(),
+                    create_asset_metadata("Sibling", "SBL", 12, min_balance, false, false),
+                    units_per_second
+                ),
+                error
+            );
+        }
+
+        fund_sibling_account();
+        assert_ok!(AssetManager::propose_asset(
+            RuntimeOrigin::signed(SIBLING_ACCOUNT),
+            location,
+            create_asset_metadata("Sibling", "SBL", 12, 1_000, false, false),
+            1_000_000
+        ));
+    });
+}
+
+#[test]
+fn dynamic_pricing_works() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        let metadata = create_asset_metadata("Kusama", "KSM", 12, 1u128, false, true);
+        let location = AssetLocation(VersionedMultiLocation::V3(MultiLocation::parent()));
+        let asset_id = NextAssetId::<Runtime>::get();
+        assert_ok!(AssetManager::register_asset(
+            RuntimeOrigin::root(),
+            location,
+            metadata
+        ));
+        assert_ok!(AssetManager::set_units_per_second(
+            RuntimeOrigin::root(),
+            asset_id,
+            100
+        ));
+
+        assert_noop!(
+            AssetManager::set_dynamic_pricing(
+                RuntimeOrigin::signed([0u8; 32].into()),
+                asset_id,
+                Some((1, 10_000_000))
+            ),
+            BadOrigin
+        );
+        assert_noop!(
+            AssetManager::set_dynamic_pricing(RuntimeOrigin::root(), 100, Some((1, 10_000_000))),
+            Error::<Runtime>::UpdateNonExistentAsset
+        );
+        assert_noop!(
+            AssetManager::set_dynamic_pricing(RuntimeOrigin::root(), asset_id, Some((2, 1))),
+            Error::<Runtime>::InvalidPriceBounds
+        );
+        assert_noop!(
+            AssetManager::set_dynamic_pricing(RuntimeOrigin::root(), asset_id, None),
+            Error::<Runtime>::DynamicPricingNotSet
+        );
+        assert_ok!(AssetManager::set_dynamic_pricing(
+            RuntimeOrigin::root(),
+            asset_id,
+            Some((1, 10_000_000))
+        ));
+
+        // The stored value is used until a first average is available.
+        PoolReserves::set(Some((2_000, 1_000)));
+        AssetManager::on_initialize(2);
+        assert_eq!(AssetManager::units_per_second(&asset_id), Some(100));
+
+        // A price of 2 lasting two blocks and a price of 5 lasting one, times 1_000_000 for the
+        // native token, rather than the plain average of the two observed prices.
+        PoolReserves::set(Some((5_000, 1_000)));
+        AssetManager::on_initialize(4);
+        assert_eq!(AssetManager::units_per_second(&asset_id), Some(100));
+        AssetManager::on_initialize(5);
+        assert_eq!(AssetManager::units_per_second(&asset_id), Some(3_000_000));
+        System::assert_last_event(RuntimeEvent::AssetManager(
+            crate::Event::DynamicUnitsPerSecondUpdated {
+                asset_id,
+                units_per_second: 3_000_000,
+            },
+        ));
+
+        // Updating the bounds clamps the derived value right away.
+        assert_ok!(AssetManager::set_dynamic_pricing(
+            RuntimeOrigin::root(),
+            asset_id,
+            Some((1, 2_500_000))
+        ));
+        assert_eq!(AssetManager::units_per_second(&asset_id), Some(2_500_000));
+
+        // The last price still counts for the block it lasted into the next period.
+        PoolReserves::set(None);
+        for n in 6..10 {
+            AssetManager::on_initialize(n);
+        }
+        assert_eq!(AssetManager::units_per_second(&asset_id), Some(2_500_000));
+
+        // Without any price over a whole period, the stored value is used again.
+        for n in 10..14 {
+            AssetManager::on_initialize(n);
+        }
+        assert_eq!(AssetManager::units_per_second(&asset_id), Some(100));
+
+        PoolReserves::set(Some((4_000, 1_000)));
+        for n in 14..18 {
+            AssetManager::on_initialize(n);
+        }
+        assert_eq!(AssetManager::units_per_second(&asset_id), Some(2_500_000));
+        assert_ok!(AssetManager::set_dynamic_pricing(
+            RuntimeOrigin::root(),
+            asset_id,
+            None
+        ));
+        assert_eq!(AssetManager::units_per_second(&asset_id), Some(100));
+    });
+}
+
+#[test]
+fn dynamic_priced_assets_are_bounded() {
+    new_test_ext().execute_with(|| {
+        let metadata = create_asset_metadata("Sibling", "SBL", 12, 1u128, false, true);
+        let asset_id = NextAssetId::<Runtime>::get();
+        for index in 0..3 {
+            assert_ok!(AssetManager::register_asset(
+                RuntimeOrigin::root(),
+                sibling_asset_location(index),
+                metadata.clone()
+            ));
+        }
+        for id in asset_id..asset_id + 2 {
+            assert_ok!(AssetManager::set_dynamic_pricing(
+                RuntimeOrigin::root(),
+                id,
+                Some((1, 10))
+            ));
+        }
+        // Updating the bounds of an asset with dynamic pricing is still possible.
+        assert_ok!(AssetManager::set_dynamic_pricing(
+            RuntimeOrigin::root(),
+            asset_id,
+            Some((1, 20))
+        ));
+        assert_noop!(
+            AssetManager::set_dynamic_pricing(RuntimeOrigin::root(), asset_id + 2, Some((1, 10))),
+            Error::<Runtime>::TooManyDynamicPricedAssets
+        );
+    });
+}
+
+#[test]
+fn freeze_and_deregister_asset_works() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        let metadata = create_asset_metadata("Sibling", "SBL", 12, 1u128, false, true);
+        let location = sibling_asset_location(0);
+        let multilocation: Option<MultiLocation> = location.clone().into();
+        let reserve_chain: AssetLocation = AssetLocation(VersionedMultiLocation::V3(
+            MultiLocation::new(1, X1(Parachain(SIBLING_PARA_ID))),
+        ));
+        let asset_id = NextAssetId::<Runtime>::get();
+        assert_ok!(AssetManager::register_asset(
+            RuntimeOrigin::root(),
+            location.clone(),
+            metadata.clone()
+        ));
+        assert_ok!(AssetManager::set_units_per_second(
+            RuntimeOrigin::root(),
+            asset_id,
+            1_000
+        ));
+        assert_ok!(AssetManager::set_dynamic_pricing(
+            RuntimeOrigin::root(),
+            asset_id,
+            Some((1, 1_000))
+        ));
+        assert_ok!(AssetManager::set_min_xcm_fee(
+            RuntimeOrigin::root(),
+            reserve_chain.clone(),
+            100
+        ));
+        assert_ok!(AssetManager::mint_asset(
+            RuntimeOrigin::root(),
+            asset_id,
+            ALICE,
+            1_000
+        ));
+
+        assert_noop!(
+            AssetManager::deregister_asset(RuntimeOrigin::root(), asset_id),
+            Error::<Runtime>::AssetNotFrozen
+        );
+        assert_noop!(
+            AssetManager::freeze_asset(RuntimeOrigin::signed(ALICE), asset_id),
+            BadOrigin
+        );
+        assert_noop!(
+            AssetManager::freeze_asset(
+                RuntimeOrigin::root(),
+                <MantaAssetConfig as AssetConfig<Runtime>>::NativeAssetId::get()
+            ),
+            Error::<Runtime>::CannotFreezeNativeAsset
+        );
+        assert_ok!(AssetManager::freeze_asset(RuntimeOrigin::root(), asset_id));
+        System::assert_last_event(RuntimeEvent::AssetManager(crate::Event::AssetFrozen {
+            asset_id,
+        }));
+        assert_noop!(
+            AssetManager::freeze_asset(RuntimeOrigin::root(), asset_id),
+            Error::<Runtime>::AssetFrozen
+        );
+
+        // The asset cannot move in or out of the chain, nor be made private anymore.
+        assert_eq!(AssetIdMetadata::<Runtime>::get(asset_id), Some(metadata));
+        assert_eq!(AssetManager::asset_id(&location), None);
+        assert!(AssetManager::check_outgoing_assets_filter(&multilocation));
+        assert!(!ActiveAssets::<Runtime>::contains(&asset_id));
+        assert_noop!(
+            AssetManager::mint_asset(RuntimeOrigin::root(), asset_id, ALICE, 1),
+            Error::<Runtime>::AssetFrozen
+        );
+
+        assert_noop!(
+            AssetManager::deregister_asset(RuntimeOrigin::root(), asset_id),
+            Error::<Runtime>::AssetSupplyNotZero
+        );
+        assert_ok!(
+            <MantaAssetConfig as AssetConfig<Runtime>>::FungibleLedger::withdraw_burning(
+                asset_id,
+                &ALICE,
+                1_000,
+                ExistenceRequirement::AllowDeath
+            )
+        );
+        assert_ok!(AssetManager::deregister_asset(
+            RuntimeOrigin::root(),
+            asset_id
+        ));
+        System::assert_last_event(RuntimeEvent::AssetManager(
+            crate::Event::AssetDeregistered {
+                asset_id,
+                location: Some(location.clone()),
+            },
+        ));
+        assert!(!<Assets as fungibles::Inspect<sp_runtime::AccountId32>>::asset_exists(asset_id));
+        assert_eq!(AssetIdLocation::<Runtime>::get(asset_id), None);
+        assert_eq!(LocationAssetId::<Runtime>::get(&location), None);
+        assert_eq!(AssetIdMetadata::<Runtime>::get(asset_id), None);
+        assert_eq!(UnitsPerSecond::<Runtime>::get(asset_id), None);
+        assert_eq!(AssetManager::dynamic_pricing(asset_id), None);
+        assert_eq!(AssetManager::get_para_id(SIBLING_PARA_ID), None);
+        assert_eq!(AssetManager::get_min_xcm_fee(reserve_chain), None);
+        assert!(ActiveAssets::<Runtime>::contains(&asset_id));
+    });
+}
+
+#[test]
+fn unfreeze_asset_works() {
+    new_test_ext().execute_with(|| {
+        let (metadata, location) = create_asset_and_location("Asset8");
+        let asset_id = NextAssetId::<Runtime>::get();
+        assert_ok!(AssetManager::register_asset(
+            RuntimeOrigin::root(),
+            location.clone(),
+            metadata
+        ));
+        assert_noop!(
+            AssetManager::unfreeze_asset(RuntimeOrigin::root(), asset_id),
+            Error::<Runtime>::AssetNotFrozen
+        );
+        assert_ok!(AssetManager::freeze_asset(RuntimeOrigin::root(), asset_id));
+        assert_eq!(AssetManager::asset_id(&location), None);
+
+        assert_ok!(AssetManager::unfreeze_asset(
+            RuntimeOrigin::root(),
+            asset_id
+        ));
+        assert_eq!(AssetManager::asset_id(&location), Some(asset_id));
+        assert!(!AssetManager::check_outgoing_assets_filter(
+            &location.into()
+        ));
+    });
+}
+
+#[test]
+fn deregister_lp_asset_works() {
+    new_test_ext().execute_with(|| {
+        let (metadata8, location8) = create_asset_and_location("Asset8");
+        let (metadata9, location9) = create_asset_and_location("Asset9");
+        assert_ok!(AssetManager::register_asset(
+            RuntimeOrigin::root(),
+            location8,
+            metadata8
+        ));
+        assert_ok!(AssetManager::register_asset(
+            RuntimeOrigin::root(),
+            location9,
+            metadata9
+        ));
+        let lp_metadata = create_asset_metadata("LP10", "LP10", 12, 1u128, false, false);
+        assert_ok!(AssetManager::register_lp_asset(
+            RuntimeOrigin::root(),
+            8,
+            9,
+            lp_metadata
+        ));
+        assert_eq!(AssetManager::get_para_id(2015), Some(2));
+        assert!(AssetIdToLp::<Runtime>::contains_key(8, 10));
+        assert!(AssetIdToLp::<Runtime>::contains_key(9, 10));
+
+        // An asset of a registered LP asset goes after it.
+        assert_ok!(AssetManager::freeze_asset(RuntimeOrigin::root(), 8));
+        assert_noop!(
+            AssetManager::deregister_asset(RuntimeOrigin::root(), 8),
+            Error::<Runtime>::AssetInLpPair
+        );
+
+        assert_ok!(AssetManager::freeze_asset(RuntimeOrigin::root(), 10));
+        assert_ok!(AssetManager::deregister_asset(RuntimeOrigin::root(), 10));
+        System::assert_last_event(RuntimeEvent::AssetManager(
+            crate::Event::AssetDeregistered {
+                asset_id: 10,
+                location: None,
+            },
+        ));
+        assert_eq!(AssetIdPairToLp::<Runtime>::get((8, 9)), None);
+        assert_eq!(LpToAssetIdPair::<Runtime>::get(10), None);
+        assert_eq!(AssetIdToLp::<Runtime>::iter().count(), 0);
+
+        assert_ok!(AssetManager::deregister_asset(RuntimeOrigin::root(), 8));
+        assert_eq!(AssetManager::get_para_id(2015), Some(1));
+    });
+}
@@ -180,6 +180,52 @@ benchmarks! {
         assert_last_event::<T>(Event::NewEvictionTolerance(percentage).into());
     }
 
+    set_eviction_window {
+        let sessions = T::MaxEvictionWindow::get();
+        let origin =
+            T::UpdateOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+    }: {
+        assert_ok!(
+            <CollatorSelection<T>>::set_eviction_window(origin, sessions)
+        );
+    }
+    verify {
+        assert_last_event::<T>(Event::NewEvictionWindow(sessions).into());
+    }
+
+    whitelist_collator {
+        <CandidacyBond<T>>::put(T::Currency::minimum_balance());
+        <DesiredCandidates<T>>::put(T::MaxCandidates::get());
+        register_validators::<T>(T::MaxCandidates::get());
+        register_candidates::<T>(T::MaxCandidates::get());
+
+        // worst case: the collator is the last candidate in the list
+        let collator = <Candidates<T>>::get().last().unwrap().who.clone();
+        let origin =
+            T::UpdateOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+    }: {
+        assert_ok!(
+            <CollatorSelection<T>>::whitelist_collator(origin, collator.clone(), 10u32)
+        );
+    }
+    verify {
+        assert_last_event::<T>(Event::CollatorWhitelisted(collator, 10u32).into());
+    }
+
+    remove_whitelisted_collator {
+        let collator: T::AccountId = account("candidate", 0, SEED);
+        <EvictionWhitelist<T>>::insert(&collator, 10u32);
+        let origin =
+            T::UpdateOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+    }: {
+        assert_ok!(
+            <CollatorSelection<T>>::remove_whitelisted_collator(origin, collator.clone())
+        );
+    }
+    verify {
+        assert_last_event::<T>(Event::CollatorWhitelistRemoved(collator).into());
+    }
+
     // worse case is when we have all the max-candidate slots filled except one, and we fill that
     // one.
     register_as_candidate {
@@ -316,9 +362,18 @@ benchmarks! {
         let underperformers = &candidates[0..candidates.len()-1];
         let top_performer = &candidates[candidates.len()-1];
 
-        // worst case: everyone but one collator underperforms and must be removed
+        // worst case: everyone but one collator underperforms throughout the longest window and must be removed
+        let window = T::MaxEvictionWindow::get();
+        <EvictionWindow<T>>::put(window);
+        let underperformance = SessionPerformance { blocks: zero_block, threshold: new_block };
         for up in underperformers{
             <BlocksPerCollatorThisSession<T>>::insert(up.who.clone(), zero_block);
+            <PerformanceHistory<T>>::insert(
+                up.who.clone(),
+                sp_std::iter::repeat(underperformance.clone())
+                    .take(window.saturating_sub(1) as usize)
+                    .collect::<Vec<_>>(),
+            );
         }
         <BlocksPerCollatorThisSession<T>>::insert(top_performer.who.clone(), new_block);
 
@@ -46,6 +46,14 @@
 //! The current implementation resolves congestion of [`Candidates`] in a first-come-first-serve
 //! manner.
 //!
+//! ### Eviction
+//!
+//! At the end of every session each candidate's authored blocks are compared against a threshold
+//! derived from [`EvictionBaseline`] and [`EvictionTolerance`] and appended to its
+//! [`PerformanceHistory`]. A candidate is evicted once it fell short of the threshold in each of the
+//! last [`EvictionWindow`] sessions it was active in. Governance can exempt a candidate for a number
+//! of sessions through the [`EvictionWhitelist`], e.g. during announced maintenance.
+//!
 //! ### Rewards
 //!
 //! The Collator Selection pallet maintains an on-chain account (the "Pot"). In each block, the
@@ -166,6 +174,10 @@ pub mod pallet {
         /// If the pallet that implements this trait depends on an inherent, that inherent **must**
         /// be included before this one.
         type CanAuthor: CanAuthor<Self::AccountId>;
+
+        /// Maximum number of sessions of collator performance kept to judge eviction.
+        #[pallet::constant]
+        type MaxEvictionWindow: Get<u32>;
     }
 
     /// Basic information about a collation candidate.
@@ -177,6 +189,22 @@ pub mod pallet {
         pub deposit: Balance,
     }
 
+    /// Performance of a collator in a single session, as used to judge eviction.
+    #[derive(PartialEq, Eq, Clone, Encode, Decode, RuntimeDebug, scale_info::TypeInfo)]
+    pub struct SessionPerformance {
+        /// Blocks authored by the collator in the session.
+        pub blocks: BlockCount,
+        /// Blocks a collator had to author in the session to not be underperforming.
+        pub threshold: BlockCount,
+    }
+
+    impl SessionPerformance {
+        /// Whether the collator authored fewer blocks than required in this session.
+        pub fn is_underperforming(&self) -> bool {
+            self.blocks < self.threshold
+        }
+    }
+
     #[pallet::pallet]
     #[pallet::storage_version(STORAGE_VERSION)]
     #[pallet::without_storage_info]
@@ -193,7 +221,7 @@ pub mod pallet {
     pub type Candidates<T: Config> =
         StorageValue<_, Vec<CandidateInfo<T::AccountId, BalanceOf<T>>>, ValueQuery>;
 
-    pub(super) type BlockCount = u32;
+    pub type BlockCount = u32;
     #[pallet::type_value]
     pub(super) fn StartingBlockCount() -> BlockCount {
         Zero::zero()
@@ -214,6 +242,28 @@ pub mod pallet {
     #[pallet::getter(fn eviction_tolerance)]
     pub type EvictionTolerance<T: Config> = StorageValue<_, Percent, ValueQuery>;
 
+    #[pallet::type_value]
+    pub(super) fn DefaultEvictionWindow() -> u32 {
+        One::one()
+    }
+
+    /// Number of consecutive sessions a collator must underperform in before it is evicted
+    #[pallet::storage]
+    #[pallet::getter(fn eviction_window)]
+    pub type EvictionWindow<T: Config> = StorageValue<_, u32, ValueQuery, DefaultEvictionWindow>;
+
+    /// Performance of each candidate over its last [`EvictionWindow`] active sessions, oldest first
+    #[pallet::storage]
+    #[pallet::getter(fn performance_history)]
+    pub type PerformanceHistory<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AccountId, Vec<SessionPerformance>, ValueQuery>;
+
+    /// Candidates exempt from eviction and the number of sessions their exemption still lasts
+    #[pallet::storage]
+    #[pallet::getter(fn eviction_whitelist)]
+    pub type EvictionWhitelist<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AccountId, u32, OptionQuery>;
+
     /// Desired number of candidates.
     ///
     /// This should ideally always be less than [`Config::MaxCandidates`] for weights to be correct.
@@ -294,6 +344,13 @@ pub mod pallet {
         CandidateRemoved(T::AccountId),
         NewEvictionBaseline(Percent),
         NewEvictionTolerance(Percent),
+        NewEvictionWindow(u32),
+        /// A candidate was evicted for underperforming in every session of the eviction window.
+        CollatorEvicted(T::AccountId, Vec<SessionPerformance>),
+        /// A candidate is exempt from eviction for the given number of sessions.
+        CollatorWhitelisted(T::AccountId, u32),
+        /// A candidate's exemption from eviction was revoked or ran out.
+        CollatorWhitelistRemoved(T::AccountId),
     }
 
     // Errors inform users that something went wrong.
@@ -317,6 +374,12 @@ pub mod pallet {
         ValidatorNotRegistered,
         /// Removing invulnerable collators is not allowed
         NotAllowRemoveInvulnerable,
+        /// Eviction window must be between one session and `MaxEvictionWindow`
+        InvalidEvictionWindow,
+        /// Whitelisting a collator requires at least one session
+        InvalidWhitelistDuration,
+        /// Collator is not whitelisted
+        NotWhitelisted,
     }
 
     #[pallet::hooks]
@@ -539,6 +602,67 @@ pub mod pallet {
             Self::deposit_event(Event::NewEvictionTolerance(percentage));
             Ok(().into())
         }
+
+        /// Set the number of consecutive sessions a collator must underperform in to be evicted
+        ///
+        /// `sessions`: length of the performance window, at most `MaxEvictionWindow`
+        #[pallet::call_index(9)]
+        #[pallet::weight(T::WeightInfo::set_eviction_window())]
+        pub fn set_eviction_window(
+            origin: OriginFor<T>,
+            sessions: u32,
+        ) -> DispatchResultWithPostInfo {
+            T::UpdateOrigin::ensure_origin(origin)?;
+            ensure!(
+                !sessions.is_zero() && sessions <= T::MaxEvictionWindow::get(),
+                Error::<T>::InvalidEvictionWindow
+            );
+            <EvictionWindow<T>>::put(sessions);
+            Self::deposit_event(Event::NewEvictionWindow(sessions));
+            Ok(().into())
+        }
+
+        /// Exempt a candidate from eviction, e.g. during announced maintenance
+        ///
+        /// Sessions in which the candidate is whitelisted don't count towards its performance history.
+        ///
+        /// - `collator`: candidate to exempt
+        /// - `sessions`: number of sessions the exemption lasts, replacing any previous one
+        #[pallet::call_index(10)]
+        #[pallet::weight(T::WeightInfo::whitelist_collator())]
+        pub fn whitelist_collator(
+            origin: OriginFor<T>,
+            collator: T::AccountId,
+            sessions: u32,
+        ) -> DispatchResultWithPostInfo {
+            T::UpdateOrigin::ensure_origin(origin)?;
+            ensure!(!sessions.is_zero(), Error::<T>::InvalidWhitelistDuration);
+            ensure!(
+                Self::candidates().iter().any(|c| c.who == collator),
+                Error::<T>::NotCandidate
+            );
+            <EvictionWhitelist<T>>::insert(&collator, sessions);
+            Self::deposit_event(Event::CollatorWhitelisted(collator, sessions));
+            Ok(().into())
+        }
+
+        /// Revoke a candidate's exemption from eviction before it runs out
+        ///
+        /// - `collator`: whitelisted candidate
+        #[pallet::call_index(11)]
+        #[pallet::weight(T::WeightInfo::remove_whitelisted_collator())]
+        pub fn remove_whitelisted_collator(
+            origin: OriginFor<T>,
+            collator: T::AccountId,
+        ) -> DispatchResultWithPostInfo {
+            T::UpdateOrigin::ensure_origin(origin)?;
+            ensure!(
+                <EvictionWhitelist<T>>::take(&collator).is_some(),
+                Error::<T>::NotWhitelisted
+            );
+            Self::deposit_event(Event::CollatorWhitelistRemoved(collator));
+            Ok(().into())
+        }
     }
 
     impl<T: Config> Pallet<T> {
@@ -559,6 +683,8 @@ pub mod pallet {
                     candidates.remove(index);
                     Ok(candidates.len())
                 })?;
+            <PerformanceHistory<T>>::remove(who);
+            <EvictionWhitelist<T>>::remove(who);
             Self::deposit_event(Event::CandidateRemoved(who.clone()));
             Ok(current_count)
         }
@@ -572,7 +698,8 @@ pub mod pallet {
             collators
         }
 
-        /// Removes collators with unsatisfactory performance
+        /// Records this session's performance of all candidates and removes the ones that
+        /// underperformed in every session of the [`EvictionWindow`]
         /// Returns the removed AccountIds
         pub fn evict_bad_collators(
             candidates: Vec<CandidateInfo<T::AccountId, BalanceOf<T>>>,
@@ -621,31 +748,63 @@ pub mod pallet {
                 evict_below_blocks
             );
 
-            // 5. Walk the percentile slice, call try_remove_candidate if a collator is under threshold
-            let kick_candidates = &collator_perf_this_session[..index_at_ordinal_rank]; // ordinal-rank exclusive, the collator at percentile is safe
-            let mut removed_account_ids: Vec<T::AccountId> =
-                Vec::with_capacity(kick_candidates.len());
-            kick_candidates.iter().for_each(|(acc_id, my_blocks_this_session)| {
-                if *my_blocks_this_session < evict_below_blocks {
-                    // If our validator is not also a candidate we're invulnerable or already kicked
-                    if candidates.iter().any(|x| x.who == *acc_id) {
-                        #[allow(clippy::bind_instead_of_map)] Self::try_remove_candidate(acc_id)
-                            .and_then(|_| {
-                                removed_account_ids.push(acc_id.clone());
-                                log::info!("Removed collator of account {:?} as it only produced {} blocks this session which is below acceptable threshold of {}", &acc_id, my_blocks_this_session,evict_below_blocks);
-                                Ok(())
-                            })
-                            .unwrap_or_else(|why| {
-                                log::warn!("Failed to remove candidate due to underperformance {:?}", why);
-                                debug_assert!(false, "failed to remove candidate {why:?}");
-                            });
+            // 5. Record the session for every active candidate, evict those underperforming throughout the eviction window
+            let eviction_window = Self::eviction_window() as usize;
+            let mut removed_account_ids: Vec<T::AccountId> = Vec::new();
+            for (acc_id, my_blocks_this_session) in collator_perf_this_session {
+                // If our validator is not also a candidate we're invulnerable or already kicked
+                if !candidates.iter().any(|x| x.who == acc_id) {
+                    continue;
+                }
+                // Sessions spent whitelisted, e.g. for maintenance, don't count towards the history
+                if <EvictionWhitelist<T>>::contains_key(&acc_id) {
+                    continue;
+                }
+                let mut history = Self::performance_history(&acc_id);
+                history.push(SessionPerformance {
+                    blocks: my_blocks_this_session,
+                    threshold: evict_below_blocks,
+                });
+                if history.len() > eviction_window {
+                    history.drain(..history.len() - eviction_window);
+                }
+                if history.len() < eviction_window
+                    || !history.iter().all(SessionPerformance::is_underperforming)
+                {
+                    <PerformanceHistory<T>>::insert(&acc_id, history);
+                    continue;
+                }
+                match Self::try_remove_candidate(&acc_id) {
+                    Ok(_) => {
+                        log::info!("Removed collator of account {:?} as it produced fewer blocks than the acceptable threshold in each of the last {} sessions: {:?}", &acc_id, eviction_window, history);
+                        Self::deposit_event(Event::CollatorEvicted(acc_id.clone(), history));
+                        removed_account_ids.push(acc_id);
+                    }
+                    Err(why) => {
+                        log::warn!(
+                            "Failed to remove candidate due to underperformance {:?}",
+                            why
+                        );
+                        debug_assert!(false, "failed to remove candidate {why:?}");
                     }
                 }
-            });
-            removed_account_ids.shrink_to_fit();
+            }
             removed_account_ids
         }
 
+        /// Counts down the sessions left on every eviction exemption, dropping the ones that ran out
+        pub fn age_eviction_whitelist() {
+            <EvictionWhitelist<T>>::translate(|collator, sessions_left: u32| {
+                let sessions_left = sessions_left.saturating_sub(One::one());
+                if sessions_left.is_zero() {
+                    Self::deposit_event(Event::CollatorWhitelistRemoved(collator));
+                    None
+                } else {
+                    Some(sessions_left)
+                }
+            });
+        }
+
         /// Reset the performance map to the currently active validators at 0 blocks
         pub fn reset_collator_performance() {
             let validators = T::ValidatorRegistration::validators();
@@ -665,6 +824,34 @@ pub mod pallet {
                 <BlocksPerCollatorThisSession<T>>::insert(account_id.clone(), 0u32);
             }
         }
+
+        /// Removes all remaining candidates, returning their deposits, and clears every storage item
+        /// of this pallet. Used once the collator set is managed elsewhere.
+        /// Returns the weight consumed
+        pub fn drain_storage() -> Weight {
+            let candidates = Self::candidates();
+            for candidate in candidates.iter() {
+                let _ = Self::try_remove_candidate(&candidate.who);
+            }
+            let mut removed = <BlocksPerCollatorThisSession<T>>::clear(u32::MAX, None).unique;
+            removed = removed.saturating_add(<PerformanceHistory<T>>::clear(u32::MAX, None).unique);
+            removed = removed.saturating_add(<EvictionWhitelist<T>>::clear(u32::MAX, None).unique);
+            <Candidates<T>>::kill();
+            <Invulnerables<T>>::kill();
+            <DesiredCandidates<T>>::kill();
+            <CandidacyBond<T>>::kill();
+            <EvictionBaseline<T>>::kill();
+            <EvictionTolerance<T>>::kill();
+            <EvictionWindow<T>>::kill();
+            let candidates_len = candidates.len() as u64;
+            T::DbWeight::get().reads_writes(
+                candidates_len.saturating_add(1),
+                candidates_len
+                    .saturating_mul(2)
+                    .saturating_add(removed as u64)
+                    .saturating_add(7),
+            )
+        }
     }
 
     /// Checks if a provided NimbusId SessionKey has an associated AccountId
@@ -763,10 +950,12 @@ pub mod pallet {
             let _success = T::Currency::transfer(&pot, &author, reward, KeepAlive);
             debug_assert!(_success.is_ok());
 
-            // increment blocks this node authored
-            <BlocksPerCollatorThisSession<T>>::mutate(&author, |blocks| {
-                *blocks = blocks.saturating_add(One::one());
-            });
+            // increment blocks this node authored, performance is only needed to evict candidates
+            if !Self::candidates().is_empty() {
+                <BlocksPerCollatorThisSession<T>>::mutate(&author, |blocks| {
+                    *blocks = blocks.saturating_add(One::one());
+                });
+            }
 
             frame_system::Pallet::<T>::register_extra_weight_unchecked(
                 T::WeightInfo::note_author(),
@@ -803,6 +992,7 @@ pub mod pallet {
                 DispatchClass::Mandatory,
             );
 
+            Self::age_eviction_whitelist();
             Self::reset_collator_performance(); // Reset performance map for the now starting session's active validator set
             Some(result)
         }
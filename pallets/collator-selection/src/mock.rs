@@ -213,6 +213,7 @@ impl Config for Test {
     type ValidatorRegistration = IsRegistered;
     type WeightInfo = ();
     type CanAuthor = ();
+    type MaxEvictionWindow = ConstU32<4>;
 }
 
 pub fn new_test_ext() -> sp_io::TestExternalities {
@@ -17,7 +17,7 @@
 use crate as collator_selection;
 use crate::{
     mock::*, BlocksPerCollatorThisSession, CandidateInfo, Error, EvictionBaseline,
-    EvictionTolerance,
+    EvictionTolerance, SessionPerformance,
 };
 use frame_support::{
     assert_noop, assert_ok,
@@ -225,6 +225,103 @@ fn set_eviction_tolerance() {
         );
     });
 }
+
+#[test]
+fn set_eviction_window() {
+    new_test_ext().execute_with(|| {
+        // given
+        assert_eq!(CollatorSelection::eviction_window(), 1);
+
+        // can set
+        assert_ok!(CollatorSelection::set_eviction_window(
+            RuntimeOrigin::signed(RootAccount::get()),
+            4
+        ));
+        assert_eq!(CollatorSelection::eviction_window(), 4);
+
+        // must span at least one and at most MaxEvictionWindow sessions
+        assert_noop!(
+            CollatorSelection::set_eviction_window(RuntimeOrigin::signed(RootAccount::get()), 0),
+            Error::<Test>::InvalidEvictionWindow
+        );
+        assert_noop!(
+            CollatorSelection::set_eviction_window(RuntimeOrigin::signed(RootAccount::get()), 5),
+            Error::<Test>::InvalidEvictionWindow
+        );
+
+        // rejects bad origin.
+        assert_noop!(
+            CollatorSelection::set_eviction_window(RuntimeOrigin::signed(1), 2),
+            BadOrigin
+        );
+    });
+}
+
+#[test]
+fn whitelist_collator() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        setup_3_candidates();
+
+        // only candidates can be whitelisted, for at least one session
+        assert_noop!(
+            CollatorSelection::whitelist_collator(
+                RuntimeOrigin::signed(RootAccount::get()),
+                ALICE,
+                2
+            ),
+            Error::<Test>::NotCandidate
+        );
+        assert_noop!(
+            CollatorSelection::whitelist_collator(
+                RuntimeOrigin::signed(RootAccount::get()),
+                EVE,
+                0
+            ),
+            Error::<Test>::InvalidWhitelistDuration
+        );
+        assert_noop!(
+            CollatorSelection::whitelist_collator(RuntimeOrigin::signed(EVE), EVE, 2),
+            BadOrigin
+        );
+
+        assert_ok!(CollatorSelection::whitelist_collator(
+            RuntimeOrigin::signed(RootAccount::get()),
+            EVE,
+            2
+        ));
+        System::assert_last_event(RuntimeEvent::CollatorSelection(
+            collator_selection::Event::CollatorWhitelisted(EVE, 2),
+        ));
+        assert_eq!(CollatorSelection::eviction_whitelist(EVE), Some(2));
+
+        assert_ok!(CollatorSelection::remove_whitelisted_collator(
+            RuntimeOrigin::signed(RootAccount::get()),
+            EVE
+        ));
+        System::assert_last_event(RuntimeEvent::CollatorSelection(
+            collator_selection::Event::CollatorWhitelistRemoved(EVE),
+        ));
+        assert_eq!(CollatorSelection::eviction_whitelist(EVE), None);
+        assert_noop!(
+            CollatorSelection::remove_whitelisted_collator(
+                RuntimeOrigin::signed(RootAccount::get()),
+                EVE
+            ),
+            Error::<Test>::NotWhitelisted
+        );
+
+        // leaving candidates drops the whitelisting
+        assert_ok!(CollatorSelection::whitelist_collator(
+            RuntimeOrigin::signed(RootAccount::get()),
+            EVE,
+            2
+        ));
+        assert_ok!(CollatorSelection::leave_intent(RuntimeOrigin::signed(EVE)));
+        assert_eq!(CollatorSelection::eviction_whitelist(EVE), None);
+    });
+}
+
 #[test]
 fn cannot_register_candidate_if_too_many() {
     new_test_ext().execute_with(|| {
@@ -398,6 +495,37 @@ fn authorship_event_handler() {
     });
 }
 
+#[test]
+fn drain_storage() {
+    new_test_ext().execute_with(|| {
+        setup_3_candidates();
+        assert_ok!(CollatorSelection::whitelist_collator(
+            RuntimeOrigin::signed(RootAccount::get()),
+            EVE,
+            2
+        ));
+        // triggers `note_author`
+        Authorship::on_initialize(1);
+        assert_eq!(BlocksPerCollatorThisSession::<Test>::get(DAVE), 1);
+        assert_eq!(Balances::reserved_balance(CHAD), 10);
+
+        CollatorSelection::drain_storage();
+        assert!(CollatorSelection::candidates().is_empty());
+        assert!(CollatorSelection::invulnerables().is_empty());
+        assert_eq!(CollatorSelection::desired_candidates(), 0);
+        assert_eq!(CollatorSelection::candidacy_bond(), 0);
+        assert_eq!(CollatorSelection::eviction_whitelist(EVE), None);
+        assert!(!BlocksPerCollatorThisSession::<Test>::contains_key(DAVE));
+        // deposits are returned
+        assert_eq!(Balances::reserved_balance(CHAD), 0);
+        assert_eq!(Balances::reserved_balance(EVE), 0);
+
+        // authored blocks are not tracked without candidates
+        Authorship::on_initialize(2);
+        assert!(!BlocksPerCollatorThisSession::<Test>::contains_key(DAVE));
+    });
+}
+
 #[test]
 fn fees_edgecases() {
     new_test_ext().execute_with(|| {
@@ -738,6 +866,117 @@ fn manta_dont_kick_collator_at_tolerance() {
         assert_eq!(candidate_ids(), vec![CHAD, DAVE, EVE]);
     })
 }
+#[test]
+fn manta_kick_only_sustained_underperformance() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        setup_3_candidates();
+        assert_ok!(CollatorSelection::set_eviction_window(
+            RuntimeOrigin::signed(RootAccount::get()),
+            3
+        ));
+        let end_session = |eve_blocks: u32| {
+            for collator in [ALICE, BOB, CHAD, DAVE] {
+                BlocksPerCollatorThisSession::<Test>::insert(collator, 10);
+            }
+            BlocksPerCollatorThisSession::<Test>::insert(EVE, eve_blocks);
+            CollatorSelection::evict_bad_collators(CollatorSelection::candidates())
+        };
+        // 80th percentile = 10, EVE underperforms *below* 9
+        let underperformed = SessionPerformance {
+            blocks: 0,
+            threshold: 9,
+        };
+        let performed = SessionPerformance {
+            blocks: 10,
+            threshold: 9,
+        };
+
+        // underperforming for less than the window is tolerated
+        assert!(end_session(0).is_empty());
+        assert!(end_session(0).is_empty());
+        assert_eq!(
+            CollatorSelection::performance_history(EVE),
+            vec![underperformed.clone(), underperformed.clone()]
+        );
+        // recovering restarts the window
+        assert!(end_session(10).is_empty());
+        assert!(end_session(0).is_empty());
+        assert!(end_session(0).is_empty());
+        assert_eq!(
+            CollatorSelection::performance_history(EVE),
+            vec![
+                performed.clone(),
+                underperformed.clone(),
+                underperformed.clone()
+            ]
+        );
+        // history is capped at the window
+        assert_eq!(
+            CollatorSelection::performance_history(DAVE),
+            vec![performed.clone(), performed.clone(), performed]
+        );
+        // invulnerables are not tracked
+        assert!(CollatorSelection::performance_history(ALICE).is_empty());
+
+        // underperforming throughout the window gets EVE evicted
+        assert_eq!(end_session(0), vec![EVE]);
+        assert_eq!(candidate_ids(), vec![CHAD, DAVE]);
+        System::assert_last_event(RuntimeEvent::CollatorSelection(
+            collator_selection::Event::CollatorEvicted(
+                EVE,
+                vec![
+                    underperformed.clone(),
+                    underperformed.clone(),
+                    underperformed,
+                ],
+            ),
+        ));
+        assert!(CollatorSelection::performance_history(EVE).is_empty());
+    });
+}
+
+#[test]
+fn manta_dont_kick_whitelisted_collator() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        setup_3_candidates();
+        initialize_to_block(20);
+        assert_eq!(Session::validators(), vec![ALICE, BOB, CHAD, DAVE, EVE]);
+        assert_ok!(CollatorSelection::whitelist_collator(
+            RuntimeOrigin::signed(RootAccount::get()),
+            EVE,
+            2
+        ));
+
+        // EVE is down for maintenance during two sessions
+        initialize_to_block(29);
+        set_all_validator_perf_to(10);
+        BlocksPerCollatorThisSession::<Test>::insert(EVE, 0);
+        initialize_to_block(30);
+        assert_eq!(candidate_ids(), vec![CHAD, DAVE, EVE]);
+        assert_eq!(CollatorSelection::eviction_whitelist(EVE), Some(1));
+        initialize_to_block(39);
+        set_all_validator_perf_to(10);
+        BlocksPerCollatorThisSession::<Test>::insert(EVE, 0);
+        initialize_to_block(40);
+        assert_eq!(candidate_ids(), vec![CHAD, DAVE, EVE]);
+        System::assert_has_event(RuntimeEvent::CollatorSelection(
+            collator_selection::Event::CollatorWhitelistRemoved(EVE),
+        ));
+        assert_eq!(CollatorSelection::eviction_whitelist(EVE), None);
+        // whitelisted sessions don't count towards the performance history
+        assert!(CollatorSelection::performance_history(EVE).is_empty());
+
+        // once the whitelisting ran out EVE is judged again
+        initialize_to_block(49);
+        set_all_validator_perf_to(10);
+        BlocksPerCollatorThisSession::<Test>::insert(EVE, 0);
+        initialize_to_block(50);
+        assert_eq!(candidate_ids(), vec![CHAD, DAVE]);
+    });
+}
+
 #[test]
 #[should_panic = "duplicate invulnerables in genesis."]
 fn cannot_set_genesis_value_twice() {
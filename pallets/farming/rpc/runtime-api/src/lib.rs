@@ -17,11 +17,14 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 
 use codec::Codec;
-use manta_primitives::types::Balance;
+use manta_primitives::types::{Balance, BlockNumber};
+pub use pallet_farming::{PoolState, PoolSummary, UserPosition};
 use sp_api::decl_runtime_apis;
+use sp_runtime::FixedU128;
 use sp_std::vec::Vec;
 
 decl_runtime_apis! {
+    /// Version 2 adds the pool runway, pool listing, APR and user position queries.
     pub trait FarmingRuntimeApi<AccountId, CurrencyId, PoolId> where
         AccountId: Codec,
         PoolId: Codec,
@@ -36,5 +39,31 @@ decl_runtime_apis! {
             who: AccountId,
             pid: PoolId,
         ) -> Vec<(CurrencyId, Balance)>;
+
+        /// The number of blocks the pool keeps accruing rewards for, `None` if the pool does not
+        /// exist or neither its schedule nor its charged rewards bound it.
+        #[api_version(2)]
+        fn get_pool_runway(pid: PoolId) -> Option<BlockNumber>;
+
+        /// All farming pools with their rewards accrued up to the current block.
+        #[api_version(2)]
+        fn get_pools() -> Vec<PoolSummary<Balance, CurrencyId, BlockNumber>>;
+
+        /// The estimated yearly return of the pool, `None` if the pool is not accruing rewards,
+        /// nothing is staked or a price is missing.
+        ///
+        /// `prices` are the prices of one unit of the smallest denomination of the staked and
+        /// reward tokens in a common quote currency.
+        #[api_version(2)]
+        fn get_pool_apr(
+            pid: PoolId,
+            prices: Vec<(CurrencyId, FixedU128)>,
+        ) -> Option<FixedU128>;
+
+        /// The pools `who` has a share in.
+        #[api_version(2)]
+        fn get_user_positions(
+            who: AccountId,
+        ) -> Vec<UserPosition<Balance, CurrencyId, BlockNumber>>;
     }
 }
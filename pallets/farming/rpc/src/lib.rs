@@ -22,17 +22,20 @@ use jsonrpsee::{
     proc_macros::rpc,
     types::error::{CallError, ErrorCode, ErrorObject},
 };
-use manta_primitives::types::Balance;
-pub use pallet_farming_rpc_runtime_api::{self as runtime_api, FarmingRuntimeApi};
-use sp_api::ProvideRuntimeApi;
+use manta_primitives::types::{Balance, BlockNumber};
+pub use pallet_farming_rpc_runtime_api::{
+    self as runtime_api, FarmingRuntimeApi, PoolSummary, UserPosition,
+};
+use sp_api::{ApiExt, ProvideRuntimeApi};
 use sp_blockchain::HeaderBackend;
 use sp_rpc::number::NumberOrHex;
-use sp_runtime::traits::Block as BlockT;
+use sp_runtime::{traits::Block as BlockT, FixedU128};
 
 #[rpc(client, server)]
 pub trait FarmingRpcApi<BlockHash, AccountId, CurrencyId, PoolId>
 where
     AccountId: Send + Sync + 'static,
+    CurrencyId: Send + Sync + 'static,
     PoolId: Send + Sync + 'static,
 {
     /// rpc method for getting farming rewards
@@ -52,6 +55,39 @@ where
         pid: PoolId,
         at: Option<BlockHash>,
     ) -> RpcResult<Vec<(CurrencyId, NumberOrHex)>>;
+
+    /// rpc method for getting the remaining reward blocks of a farming pool
+    #[method(name = "farming_getPoolRunway")]
+    async fn get_pool_runway(
+        &self,
+        pid: PoolId,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Option<BlockNumber>>;
+
+    /// rpc method for listing all farming pools
+    #[method(name = "farming_getPools")]
+    async fn get_pools(
+        &self,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Vec<PoolSummary<Balance, CurrencyId, BlockNumber>>>;
+
+    /// rpc method for estimating the yearly return of a farming pool, given the prices of one
+    /// unit of the smallest denomination of its tokens in a common quote currency
+    #[method(name = "farming_getPoolApr")]
+    async fn get_pool_apr(
+        &self,
+        pid: PoolId,
+        prices: Vec<(CurrencyId, FixedU128)>,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Option<FixedU128>>;
+
+    /// rpc method for listing the farming pools an account has a share in
+    #[method(name = "farming_getUserPositions")]
+    async fn get_user_positions(
+        &self,
+        who: AccountId,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Vec<UserPosition<Balance, CurrencyId, BlockNumber>>>;
 }
 
 #[derive(Clone, Debug)]
@@ -69,6 +105,52 @@ impl<C, Block> FarmingRpc<C, Block> {
     }
 }
 
+/// Version of the farming runtime api adding the pool runway, pool listing, APR and user
+/// position queries
+const POOL_QUERIES_API_VERSION: u32 = 2;
+
+impl<C, Block> FarmingRpc<C, Block>
+where
+    Block: BlockT,
+    C: ProvideRuntimeApi<Block>,
+{
+    /// Fails unless the runtime at `at` implements `version` of the farming runtime api.
+    fn ensure_api_version<AccountId, CurrencyId, PoolId>(
+        &self,
+        at: <Block as BlockT>::Hash,
+        version: u32,
+    ) -> RpcResult<()>
+    where
+        C::Api: FarmingRuntimeApi<Block, AccountId, CurrencyId, PoolId>,
+        AccountId: Codec,
+        CurrencyId: Codec,
+        PoolId: Codec,
+    {
+        let api_version = self
+            .client
+            .runtime_api()
+            .api_version::<dyn FarmingRuntimeApi<Block, AccountId, CurrencyId, PoolId>>(at)
+            .map_err(|e| {
+                CallError::Custom(ErrorObject::owned(
+                    ErrorCode::InternalError.code(),
+                    "Failed to get the farming runtime api version.",
+                    Some(format!("{:?}", e)),
+                ))
+            })
+            .map_err(jsonrpsee::core::Error::Call)?;
+        match api_version {
+            Some(api_version) if api_version >= version => Ok(()),
+            _ => Err(jsonrpsee::core::Error::Call(CallError::Custom(
+                ErrorObject::owned(
+                    ErrorCode::MethodNotFound.code(),
+                    "The runtime does not support this farming query.",
+                    Some(format!("{:?}", api_version)),
+                ),
+            ))),
+        }
+    }
+}
+
 #[async_trait]
 impl<C, Block, AccountId, CurrencyId, PoolId>
     FarmingRpcApiServer<<Block as BlockT>::Hash, AccountId, CurrencyId, PoolId>
@@ -78,7 +160,7 @@ where
     C: Send + Sync + 'static + ProvideRuntimeApi<Block> + HeaderBackend<Block>,
     C::Api: FarmingRuntimeApi<Block, AccountId, CurrencyId, PoolId>,
     AccountId: Codec + Send + Sync + 'static,
-    CurrencyId: Codec,
+    CurrencyId: Codec + Send + Sync + 'static,
     PoolId: Codec + Send + Sync + 'static,
 {
     async fn get_farming_rewards(
@@ -130,4 +212,84 @@ where
         }
         .map_err(jsonrpsee::core::Error::Call)
     }
+
+    async fn get_pool_runway(
+        &self,
+        pid: PoolId,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Option<BlockNumber>> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+        self.ensure_api_version::<AccountId, CurrencyId, PoolId>(at, POOL_QUERIES_API_VERSION)?;
+
+        api.get_pool_runway(at, pid)
+            .map_err(|e| {
+                CallError::Custom(ErrorObject::owned(
+                    ErrorCode::InternalError.code(),
+                    "Failed to get pool runway.",
+                    Some(format!("{:?}", e)),
+                ))
+            })
+            .map_err(jsonrpsee::core::Error::Call)
+    }
+
+    async fn get_pools(
+        &self,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Vec<PoolSummary<Balance, CurrencyId, BlockNumber>>> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+        self.ensure_api_version::<AccountId, CurrencyId, PoolId>(at, POOL_QUERIES_API_VERSION)?;
+
+        api.get_pools(at)
+            .map_err(|e| {
+                CallError::Custom(ErrorObject::owned(
+                    ErrorCode::InternalError.code(),
+                    "Failed to get farming pools.",
+                    Some(format!("{:?}", e)),
+                ))
+            })
+            .map_err(jsonrpsee::core::Error::Call)
+    }
+
+    async fn get_pool_apr(
+        &self,
+        pid: PoolId,
+        prices: Vec<(CurrencyId, FixedU128)>,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Option<FixedU128>> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+        self.ensure_api_version::<AccountId, CurrencyId, PoolId>(at, POOL_QUERIES_API_VERSION)?;
+
+        api.get_pool_apr(at, pid, prices)
+            .map_err(|e| {
+                CallError::Custom(ErrorObject::owned(
+                    ErrorCode::InternalError.code(),
+                    "Failed to get pool APR.",
+                    Some(format!("{:?}", e)),
+                ))
+            })
+            .map_err(jsonrpsee::core::Error::Call)
+    }
+
+    async fn get_user_positions(
+        &self,
+        who: AccountId,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Vec<UserPosition<Balance, CurrencyId, BlockNumber>>> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+        self.ensure_api_version::<AccountId, CurrencyId, PoolId>(at, POOL_QUERIES_API_VERSION)?;
+
+        api.get_user_positions(at, who)
+            .map_err(|e| {
+                CallError::Custom(ErrorObject::owned(
+                    ErrorCode::InternalError.code(),
+                    "Failed to get user positions.",
+                    Some(format!("{:?}", e)),
+                ))
+            })
+            .map_err(jsonrpsee::core::Error::Call)
+    }
 }
@@ -31,8 +31,6 @@ pub const INITIAL_VALUE: u128 = 1_000_000_000_000_000_000_000u128;
 
 benchmarks! {
     where_clause { where T: pallet_assets::Config + pallet_asset_manager::Config, <T as pallet_asset_manager::Config>::AssetId: From<u32> }
-    on_initialize {}:{Farming::<T>::on_initialize(T::BlockNumber::from(10u32));}
-
     create_farming_pool {
         let ksm_asset_id = CurrencyIdOf::<T>::unique_saturated_from(8u128);
         let token_amount = BalanceOf::<T>::unique_saturated_from(1000u128);
@@ -180,7 +178,6 @@ benchmarks! {
         assert_ok!(Farming::<T>::charge(RawOrigin::Signed(caller.clone()).into(), 0, charge_rewards));
         assert_ok!(Farming::<T>::deposit(RawOrigin::Signed(caller.clone()).into(), 0, token_amount, None));
         System::<T>::set_block_number(System::<T>::block_number() + BlockNumberFor::<T>::from(10u32));
-        Farming::<T>::on_initialize(BlockNumberFor::<T>::from(0u32));
     }: _(RawOrigin::Signed(caller.clone()), 0)
 
     gauge_withdraw {
@@ -215,4 +212,232 @@ benchmarks! {
         assert_ok!(Farming::<T>::charge(RawOrigin::Signed(caller.clone()).into(), 0, charge_rewards));
         assert_ok!(Farming::<T>::deposit(RawOrigin::Signed(caller.clone()).into(), 0, token_amount, Some((BalanceOf::<T>::unique_saturated_from(100u128), BlockNumberFor::<T>::from(100u32)))));
     }: _(RawOrigin::Signed(caller.clone()), 0)
+
+    create_incentive_pool {
+        let location = T::Location::default();
+        let metadata = AssetRegistryMetadata::<Balance>::testing_default();
+        let asset_0 = AssetManager::<T>::do_register_asset(Some(&location), &metadata)?;
+        let asset_1 = AssetManager::<T>::do_register_asset(None, &metadata)?;
+        let lp_asset_id = AssetManager::<T>::next_asset_id();
+        AssetManager::<T>::register_lp_asset(RawOrigin::Root.into(), asset_0, asset_1, metadata)?;
+
+        let lp_token = CurrencyIdOf::<T>::unique_saturated_from(lp_asset_id.saturated_into::<u128>());
+        let caller: T::AccountId = whitelisted_caller();
+        let token_amount = BalanceOf::<T>::unique_saturated_from(1000u128);
+        // reward tokens from the KSM asset id on, up to the bound
+        let basic_rewards = BoundedVec::truncate_from(
+            (0..T::MaxBasicRewards::get())
+                .map(|i| (CurrencyIdOf::<T>::unique_saturated_from(8u128 + i as u128), token_amount))
+                .collect::<Vec<_>>(),
+        );
+        T::Currency::make_free_balance_be(&caller, T::PoolCreationDeposit::get().saturating_mul(10u32.into()));
+    }: _(RawOrigin::Signed(caller.clone()),
+        lp_token,
+        basic_rewards,
+        BalanceOf::<T>::unique_saturated_from(0u128),
+        BlockNumberFor::<T>::from(0u32),
+        BlockNumberFor::<T>::from(7u32),
+        BlockNumberFor::<T>::from(6u32),
+        5,
+        None
+    )
+
+    reclaim_rewards {
+        let location = T::Location::default();
+        let metadata = AssetRegistryMetadata::<Balance>::testing_default();
+        let asset_0 = AssetManager::<T>::do_register_asset(Some(&location), &metadata)?;
+        let asset_1 = AssetManager::<T>::do_register_asset(None, &metadata)?;
+        let lp_asset_id = AssetManager::<T>::next_asset_id();
+        AssetManager::<T>::register_lp_asset(RawOrigin::Root.into(), asset_0, asset_1, metadata)?;
+
+        let lp_token = CurrencyIdOf::<T>::unique_saturated_from(lp_asset_id.saturated_into::<u128>());
+        let ksm_asset_id = CurrencyIdOf::<T>::unique_saturated_from(8u128);
+        let caller: T::AccountId = whitelisted_caller();
+        let token_amount = BalanceOf::<T>::unique_saturated_from(1000u128);
+        let basic_rewards = BoundedVec::truncate_from(vec![(ksm_asset_id, token_amount)]);
+        T::Currency::make_free_balance_be(&caller, T::PoolCreationDeposit::get().saturating_mul(10u32.into()));
+        assert_ok!(Farming::<T>::create_incentive_pool(
+            RawOrigin::Signed(caller.clone()).into(),
+            lp_token,
+            basic_rewards,
+            BalanceOf::<T>::unique_saturated_from(0u128),
+            BlockNumberFor::<T>::from(0u32),
+            BlockNumberFor::<T>::from(7u32),
+            BlockNumberFor::<T>::from(6u32),
+            5,
+            None,
+        ));
+
+        let _ = <T::AssetConfig as AssetConfig<T>>::FungibleLedger::deposit_minting(
+            8.into(),
+            &caller,
+            INITIAL_VALUE,
+        );
+        let charge_rewards = vec![(ksm_asset_id, BalanceOf::<T>::unique_saturated_from(300000u128))];
+        assert_ok!(Farming::<T>::charge(RawOrigin::Signed(caller.clone()).into(), 0, charge_rewards));
+        System::<T>::set_block_number(System::<T>::block_number() + BlockNumberFor::<T>::from(1u32));
+        assert_ok!(Farming::<T>::close_pool(RawOrigin::Root.into(), 0));
+    }: _(RawOrigin::Signed(caller.clone()), 0)
+
+    ve_lock {
+        let caller: T::AccountId = whitelisted_caller();
+        let lock_amount = T::PoolCreationDeposit::get();
+        T::Currency::make_free_balance_be(&caller, lock_amount.saturating_mul(10u32.into()));
+        assert_ok!(Farming::<T>::ve_lock(RawOrigin::Signed(caller.clone()).into(), lock_amount, 1));
+    }: _(RawOrigin::Signed(caller.clone()), lock_amount, T::MaxLockEpochs::get())
+
+    ve_unlock {
+        let caller: T::AccountId = whitelisted_caller();
+        let lock_amount = T::PoolCreationDeposit::get();
+        T::Currency::make_free_balance_be(&caller, lock_amount.saturating_mul(10u32.into()));
+        assert_ok!(Farming::<T>::ve_lock(RawOrigin::Signed(caller.clone()).into(), lock_amount, 1));
+        System::<T>::set_block_number(System::<T>::block_number() + T::VeEpochLength::get());
+    }: _(RawOrigin::Signed(caller.clone()))
+
+    update_boost {
+        let location = T::Location::default();
+        let metadata = AssetRegistryMetadata::<Balance>::testing_default();
+        AssetManager::<T>::do_register_asset(Some(&location), &metadata)?;
+
+        let ksm_asset_id = CurrencyIdOf::<T>::unique_saturated_from(8u128);
+        let caller: T::AccountId = whitelisted_caller();
+        let token_amount = BalanceOf::<T>::unique_saturated_from(1000u128);
+        let tokens_proportion = vec![(ksm_asset_id, Perbill::from_percent(100))];
+        let basic_rewards = vec![(ksm_asset_id, token_amount)];
+        assert_ok!(Farming::<T>::create_farming_pool(
+            RawOrigin::Root.into(),
+            tokens_proportion,
+            basic_rewards,
+            None,
+            BalanceOf::<T>::unique_saturated_from(0u128),
+            BlockNumberFor::<T>::from(0u32),
+            BlockNumberFor::<T>::from(7u32),
+            BlockNumberFor::<T>::from(6u32),
+            5,
+        ));
+
+        let _ = <T::AssetConfig as AssetConfig<T>>::FungibleLedger::deposit_minting(
+            8.into(),
+            &caller,
+            INITIAL_VALUE,
+        );
+        let charge_rewards = vec![(ksm_asset_id, BalanceOf::<T>::unique_saturated_from(300000u128))];
+        assert_ok!(Farming::<T>::charge(RawOrigin::Signed(caller.clone()).into(), 0, charge_rewards));
+        assert_ok!(Farming::<T>::deposit(RawOrigin::Signed(caller.clone()).into(), 0, token_amount, None));
+        let lock_amount = T::PoolCreationDeposit::get();
+        T::Currency::make_free_balance_be(&caller, lock_amount.saturating_mul(10u32.into()));
+        assert_ok!(Farming::<T>::ve_lock(RawOrigin::Signed(caller.clone()).into(), lock_amount, T::MaxLockEpochs::get()));
+    }: _(RawOrigin::Signed(caller.clone()), caller.clone(), 0)
+
+    vote {
+        let v in 1 .. T::MaxVotes::get();
+        let ksm_asset_id = CurrencyIdOf::<T>::unique_saturated_from(8u128);
+        let caller: T::AccountId = whitelisted_caller();
+        let token_amount = BalanceOf::<T>::unique_saturated_from(1000u128);
+        for _ in 0..v {
+            assert_ok!(Farming::<T>::create_farming_pool(
+                RawOrigin::Root.into(),
+                vec![(ksm_asset_id, Perbill::from_percent(100))],
+                vec![(ksm_asset_id, token_amount)],
+                None,
+                BalanceOf::<T>::unique_saturated_from(0u128),
+                BlockNumberFor::<T>::from(0u32),
+                BlockNumberFor::<T>::from(7u32),
+                BlockNumberFor::<T>::from(6u32),
+                5,
+            ));
+        }
+        let lock_amount = T::PoolCreationDeposit::get();
+        T::Currency::make_free_balance_be(&caller, lock_amount.saturating_mul(10u32.into()));
+        assert_ok!(Farming::<T>::ve_lock(RawOrigin::Signed(caller.clone()).into(), lock_amount, T::MaxLockEpochs::get()));
+        let votes: BoundedVec<_, T::MaxVotes> = BoundedVec::truncate_from(
+            (0..v).map(|pid| (pid as PoolId, Percent::from_rational(1, v))).collect(),
+        );
+    }: _(RawOrigin::Signed(caller.clone()), votes)
+
+    set_vote_rewards {
+        let n in 0 .. T::MaxBasicRewards::get();
+        let token_amount = BalanceOf::<T>::unique_saturated_from(1000u128);
+        let rewards: BoundedVec<_, T::MaxBasicRewards> = BoundedVec::truncate_from(
+            (0..n)
+                .map(|i| (CurrencyIdOf::<T>::unique_saturated_from(8u128 + i as u128), token_amount))
+                .collect(),
+        );
+    }: _(RawOrigin::Root, rewards)
+    verify {
+        assert_eq!(Farming::<T>::vote_rewards().len(), n as usize);
+    }
+
+    distribute_vote_rewards {
+        let location = T::Location::default();
+        let metadata = AssetRegistryMetadata::<Balance>::testing_default();
+        AssetManager::<T>::do_register_asset(Some(&location), &metadata)?;
+
+        let ksm_asset_id = CurrencyIdOf::<T>::unique_saturated_from(8u128);
+        let caller: T::AccountId = whitelisted_caller();
+        let token_amount = BalanceOf::<T>::unique_saturated_from(1000u128);
+        let tokens_proportion = vec![(ksm_asset_id, Perbill::from_percent(100))];
+        let basic_rewards = vec![(ksm_asset_id, token_amount)];
+        assert_ok!(Farming::<T>::create_farming_pool(
+            RawOrigin::Root.into(),
+            tokens_proportion,
+            basic_rewards,
+            None,
+            BalanceOf::<T>::unique_saturated_from(0u128),
+            BlockNumberFor::<T>::from(0u32),
+            BlockNumberFor::<T>::from(7u32),
+            BlockNumberFor::<T>::from(6u32),
+            5,
+        ));
+
+        let _ = <T::AssetConfig as AssetConfig<T>>::FungibleLedger::deposit_minting(
+            8.into(),
+            &caller,
+            INITIAL_VALUE,
+        );
+        let charge_rewards = vec![(ksm_asset_id, BalanceOf::<T>::unique_saturated_from(300000u128))];
+        assert_ok!(Farming::<T>::charge(RawOrigin::Signed(caller.clone()).into(), 0, charge_rewards));
+        assert_ok!(Farming::<T>::deposit(RawOrigin::Signed(caller.clone()).into(), 0, token_amount, None));
+        let lock_amount = T::PoolCreationDeposit::get();
+        T::Currency::make_free_balance_be(&caller, lock_amount.saturating_mul(10u32.into()));
+        assert_ok!(Farming::<T>::ve_lock(RawOrigin::Signed(caller.clone()).into(), lock_amount, T::MaxLockEpochs::get()));
+        let _ = <T::AssetConfig as AssetConfig<T>>::FungibleLedger::deposit_minting(
+            8.into(),
+            &T::TreasuryAccount::get(),
+            INITIAL_VALUE,
+        );
+        assert_ok!(Farming::<T>::set_vote_rewards(RawOrigin::Root.into(), BoundedVec::truncate_from(vec![(ksm_asset_id, token_amount)])));
+        let epoch = Farming::<T>::current_epoch();
+        assert_ok!(Farming::<T>::vote(RawOrigin::Signed(caller.clone()).into(), BoundedVec::truncate_from(vec![(0, Percent::from_percent(100))])));
+        System::<T>::set_block_number(System::<T>::block_number() + T::VeEpochLength::get());
+    }: _(RawOrigin::Signed(caller.clone()), epoch, 0)
+
+    set_reward_schedule {
+        let n in 0 .. T::MaxRewardSteps::get();
+        let ksm_asset_id = CurrencyIdOf::<T>::unique_saturated_from(8u128);
+        let token_amount = BalanceOf::<T>::unique_saturated_from(1000u128);
+        assert_ok!(Farming::<T>::create_farming_pool(
+            RawOrigin::Root.into(),
+            vec![(ksm_asset_id, Perbill::from_percent(100))],
+            vec![(ksm_asset_id, token_amount)],
+            None,
+            BalanceOf::<T>::unique_saturated_from(0u128),
+            BlockNumberFor::<T>::from(0u32),
+            BlockNumberFor::<T>::from(7u32),
+            BlockNumberFor::<T>::from(6u32),
+            5,
+        ));
+        let basic_rewards: BoundedVec<_, T::MaxBasicRewards> = BoundedVec::truncate_from(
+            (0..T::MaxBasicRewards::get())
+                .map(|i| (CurrencyIdOf::<T>::unique_saturated_from(8u128 + i as u128), token_amount))
+                .collect(),
+        );
+        let now = System::<T>::block_number();
+        let reward_steps: BoundedVec<_, T::MaxRewardSteps> = BoundedVec::truncate_from(
+            (1..=n).map(|step| (now + step.into(), basic_rewards.clone())).collect(),
+        );
+    }: _(RawOrigin::Root, 0, None, reward_steps)
+    verify {
+        assert_eq!(Farming::<T>::pool_infos(0).unwrap().reward_steps.len() as u32, n);
+    }
 }
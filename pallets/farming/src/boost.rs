@@ -0,0 +1,380 @@
+// Copyright 2020-2024 Manta Network.
+// This file is part of Manta.
+//
+// Manta is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// Manta is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Manta.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Vote-escrowed native token locks.
+//!
+//! Native tokens locked for up to `MaxLockEpochs` give voting power which decays linearly until
+//! the lock expires. The voting power boosts the share of its owner in farming pools, and is
+//! voted to split the `VoteRewards` of each epoch across pools.
+
+use codec::HasCompact;
+use frame_support::{
+    pallet_prelude::*,
+    traits::{Currency, LockIdentifier, LockableCurrency, WithdrawReasons},
+};
+use scale_info::TypeInfo;
+use sp_runtime::{
+    traits::{Saturating, Zero},
+    Perbill, Percent, RuntimeDebug, SaturatedConversion,
+};
+use sp_std::prelude::*;
+
+use crate::*;
+
+pub const VE_LOCK_ID: LockIdentifier = *b"farm/ve_";
+
+/// Native tokens locked for voting power.
+#[derive(Clone, Default, Encode, Decode, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct VeLock<BalanceOf: HasCompact> {
+    pub amount: BalanceOf,
+    /// The epoch the lock expires at, its voting power decays linearly until then
+    pub end_epoch: u32,
+}
+
+/// The total of the unexpired locks, giving the total voting power of an epoch.
+#[derive(Clone, Default, Encode, Decode, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct VeSupplyInfo<BalanceOf: HasCompact> {
+    /// The epoch up to which expired locks have been removed
+    pub epoch: u32,
+    /// Total amount of the unexpired locks
+    pub amount: BalanceOf,
+    /// Sum of the amount of each unexpired lock multiplied by its `end_epoch`
+    pub weighted_end: BalanceOf,
+}
+
+/// The votes cast in an epoch along with the rewards they split.
+#[derive(Clone, Default, Encode, Decode, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct EpochVoteInfo<BalanceOf: HasCompact, CurrencyIdOf: Ord, DepositBalanceOf> {
+    pub total_votes: DepositBalanceOf,
+    /// `VoteRewards` when the first vote of the epoch was cast
+    pub rewards: BTreeMap<CurrencyIdOf, BalanceOf>,
+}
+
+impl<T: Config> Pallet<T> {
+    /// Returns the current voting epoch.
+    pub fn current_epoch() -> u32 {
+        (frame_system::Pallet::<T>::block_number() / T::VeEpochLength::get()).saturated_into()
+    }
+
+    /// Returns the voting power of `who` in `epoch`.
+    pub fn ve_power(who: &T::AccountId, epoch: u32) -> DepositBalanceOf<T> {
+        Self::ve_locks(who).map_or_else(Zero::zero, |lock| {
+            lock.amount
+                .saturating_mul(lock.end_epoch.saturating_sub(epoch).into())
+                / T::MaxLockEpochs::get().into()
+        })
+    }
+
+    /// Returns the total voting power in `epoch`, which must not be before the current epoch.
+    pub fn total_ve_power(epoch: u32) -> DepositBalanceOf<T> {
+        let mut supply = Self::ve_supply();
+        Self::advance_ve_supply(&mut supply, epoch, VeExpiries::<T>::get);
+        supply
+            .weighted_end
+            .saturating_sub(supply.amount.saturating_mul(epoch.into()))
+            / T::MaxLockEpochs::get().into()
+    }
+
+    /// Removes the locks expired by `epoch` from the supply and stores it.
+    fn checkpoint_ve_supply(epoch: u32) -> VeSupplyInfoOf<T> {
+        let mut supply = Self::ve_supply();
+        Self::advance_ve_supply(&mut supply, epoch, VeExpiries::<T>::take);
+        VeSupply::<T>::put(&supply);
+        supply
+    }
+
+    fn advance_ve_supply(
+        supply: &mut VeSupplyInfoOf<T>,
+        epoch: u32,
+        mut expiries: impl FnMut(u32) -> Option<(DepositBalanceOf<T>, DepositBalanceOf<T>)>,
+    ) {
+        // No lock is pending expiry without any locked amount
+        if supply.amount.is_zero() {
+            supply.epoch = supply.epoch.max(epoch);
+        }
+        while supply.epoch < epoch {
+            supply.epoch += 1;
+            if let Some((amount, weighted_end)) = expiries(supply.epoch) {
+                supply.amount = supply.amount.saturating_sub(amount);
+                supply.weighted_end = supply.weighted_end.saturating_sub(weighted_end);
+            }
+        }
+    }
+
+    /// Adds the lock to the supply, or removes it if `add` is false.
+    fn update_ve_supply(supply: &mut VeSupplyInfoOf<T>, lock: &VeLockOf<T>, add: bool) {
+        let weighted_end = lock.amount.saturating_mul(lock.end_epoch.into());
+        VeExpiries::<T>::mutate_exists(lock.end_epoch, |expiry| {
+            let (amount, total_weighted_end) = expiry.get_or_insert_with(Default::default);
+            if add {
+                *amount = amount.saturating_add(lock.amount);
+                *total_weighted_end = total_weighted_end.saturating_add(weighted_end);
+            } else {
+                *amount = amount.saturating_sub(lock.amount);
+                *total_weighted_end = total_weighted_end.saturating_sub(weighted_end);
+            }
+            if amount.is_zero() {
+                *expiry = None;
+            }
+        });
+        if add {
+            supply.amount = supply.amount.saturating_add(lock.amount);
+            supply.weighted_end = supply.weighted_end.saturating_add(weighted_end);
+        } else {
+            supply.amount = supply.amount.saturating_sub(lock.amount);
+            supply.weighted_end = supply.weighted_end.saturating_sub(weighted_end);
+        }
+    }
+
+    /// Locks `amount` more native tokens of `who` and extends their lock to expire no earlier
+    /// than `lock_epochs` from now.
+    pub(crate) fn do_ve_lock(
+        who: &T::AccountId,
+        amount: DepositBalanceOf<T>,
+        lock_epochs: u32,
+    ) -> Result<VeLockOf<T>, DispatchError> {
+        ensure!(
+            lock_epochs <= T::MaxLockEpochs::get(),
+            Error::<T>::InvalidLockDuration
+        );
+        let epoch = Self::current_epoch();
+        let mut supply = Self::checkpoint_ve_supply(epoch);
+        let mut lock = Self::ve_locks(who).unwrap_or_default();
+        if !lock.amount.is_zero() {
+            ensure!(lock.end_epoch > epoch, Error::<T>::VeLockExpired);
+            Self::update_ve_supply(&mut supply, &lock, false);
+        }
+
+        lock.amount = lock.amount.saturating_add(amount);
+        lock.end_epoch = lock.end_epoch.max(epoch.saturating_add(lock_epochs));
+        ensure!(
+            !lock.amount.is_zero() && lock.end_epoch > epoch,
+            Error::<T>::InvalidLockDuration
+        );
+        ensure!(
+            T::Currency::free_balance(who) >= lock.amount,
+            Error::<T>::NotEnoughBalance
+        );
+        T::Currency::set_lock(VE_LOCK_ID, who, lock.amount, WithdrawReasons::all());
+        Self::update_ve_supply(&mut supply, &lock, true);
+        VeSupply::<T>::put(supply);
+        VeLocks::<T>::insert(who, &lock);
+        Ok(lock)
+    }
+
+    /// Releases the expired lock of `who`.
+    pub(crate) fn do_ve_unlock(who: &T::AccountId) -> Result<VeLockOf<T>, DispatchError> {
+        let lock = Self::ve_locks(who).ok_or(Error::<T>::VeLockNotExist)?;
+        let epoch = Self::current_epoch();
+        ensure!(lock.end_epoch <= epoch, Error::<T>::VeLockNotExpired);
+
+        // The expired lock is dropped from the supply by the checkpoint
+        Self::checkpoint_ve_supply(epoch);
+        T::Currency::remove_lock(VE_LOCK_ID, who);
+        VeLocks::<T>::remove(who);
+        Ok(lock)
+    }
+
+    /// Returns the boost share of `who` in the pool for their current voting power.
+    ///
+    /// The staked share is boosted by up to `MaxBoost`, reached once the fraction of the total
+    /// voting power owned by `who` is at least their fraction of the staked shares of the pool.
+    pub fn boost_share_of(
+        who: &T::AccountId,
+        pool_id: PoolId,
+    ) -> Result<BalanceOf<T>, DispatchError> {
+        let pool_info = Self::pool_infos(pool_id).ok_or(Error::<T>::PoolDoesNotExist)?;
+        let staked = Self::shares_and_withdrawn_rewards(pool_id, who).map_or_else(
+            Zero::zero,
+            |share_info| {
+                share_info
+                    .share
+                    .saturating_sub(Self::boost_shares(pool_id, who))
+            },
+        );
+        let pool_staked = pool_info
+            .total_shares
+            .saturating_sub(Self::pool_boost_shares(pool_id));
+        if staked.is_zero() || !matches!(pool_info.state, PoolState::Charged | PoolState::Ongoing) {
+            return Ok(Zero::zero());
+        }
+
+        let epoch = Self::current_epoch();
+        let total_power = Self::total_ve_power(epoch).saturated_into::<u128>();
+        let power = Self::ve_power(who, epoch).saturated_into::<u128>();
+        if power.is_zero() || total_power.is_zero() {
+            return Ok(Zero::zero());
+        }
+        let power_fraction = Perbill::from_rational(power, total_power);
+        if power_fraction.is_zero() {
+            return Ok(Zero::zero());
+        }
+        let staked_fraction = Perbill::from_rational(
+            staked.saturated_into::<u128>(),
+            pool_staked.saturated_into::<u128>(),
+        );
+        let boost_ratio = if power_fraction >= staked_fraction {
+            Perbill::one()
+        } else {
+            Perbill::from_rational(power_fraction.deconstruct(), staked_fraction.deconstruct())
+        };
+        Ok(T::MaxBoost::get() * (boost_ratio * staked))
+    }
+
+    /// Updates the boost share of `who` in the pool to follow their current voting power.
+    pub fn update_boost_share(who: &T::AccountId, pool_id: PoolId) -> DispatchResult {
+        let boost_share = Self::boost_share_of(who, pool_id)?;
+        Self::set_boost_share(who, pool_id, boost_share)
+    }
+
+    /// Sets the boost share of `who` in the pool, which earns rewards like the staked share
+    /// without being unstaked.
+    pub(crate) fn set_boost_share(
+        who: &T::AccountId,
+        pool_id: PoolId,
+        boost_share: BalanceOf<T>,
+    ) -> DispatchResult {
+        let old_boost_share = Self::boost_shares(pool_id, who);
+        if boost_share > old_boost_share {
+            let mut pool_info = Self::updated_pool_info(pool_id)?;
+            Self::add_share(who, pool_id, &mut pool_info, boost_share - old_boost_share);
+        } else if boost_share < old_boost_share {
+            Self::remove_boost_share(who, pool_id, old_boost_share - boost_share)?;
+        } else {
+            return Ok(());
+        }
+
+        PoolBoostShares::<T>::mutate(pool_id, |total| {
+            *total = total
+                .saturating_add(boost_share)
+                .saturating_sub(old_boost_share)
+        });
+        if boost_share.is_zero() {
+            BoostShares::<T>::remove(pool_id, who);
+        } else {
+            BoostShares::<T>::insert(pool_id, who, boost_share);
+        }
+
+        Self::deposit_event(Event::BoostUpdated {
+            who: who.clone(),
+            pid: pool_id,
+            boost_share,
+        });
+        Ok(())
+    }
+
+    /// Casts the voting power of `who` for the pools, replacing their votes of this epoch.
+    pub(crate) fn do_vote(
+        who: &T::AccountId,
+        votes: BoundedVec<(PoolId, Percent), T::MaxVotes>,
+    ) -> Result<(u32, Vec<(PoolId, DepositBalanceOf<T>)>), DispatchError> {
+        ensure!(
+            votes
+                .iter()
+                .map(|(_, proportion)| proportion.deconstruct() as u32)
+                .sum::<u32>()
+                <= 100,
+            Error::<T>::InvalidVotes
+        );
+        let epoch = Self::current_epoch();
+        let power = Self::ve_power(who, epoch);
+        ensure!(!power.is_zero(), Error::<T>::NoVotingPower);
+
+        let mut epoch_votes = Self::epoch_votes(epoch).unwrap_or_else(|| EpochVoteInfo {
+            total_votes: Zero::zero(),
+            rewards: Self::vote_rewards(),
+        });
+        if let Some((voted_epoch, old_votes)) = UserVotes::<T>::get(who) {
+            if voted_epoch == epoch {
+                old_votes.into_iter().for_each(|(pool_id, weight)| {
+                    PoolVotes::<T>::mutate(epoch, pool_id, |pool_votes| {
+                        *pool_votes = pool_votes.saturating_sub(weight)
+                    });
+                    epoch_votes.total_votes = epoch_votes.total_votes.saturating_sub(weight);
+                });
+            }
+        }
+
+        let votes = votes
+            .into_iter()
+            .map(|(pool_id, proportion)| {
+                ensure!(
+                    PoolInfos::<T>::contains_key(pool_id),
+                    Error::<T>::PoolDoesNotExist
+                );
+                let weight = proportion * power;
+                PoolVotes::<T>::mutate(epoch, pool_id, |pool_votes| {
+                    *pool_votes = pool_votes.saturating_add(weight)
+                });
+                epoch_votes.total_votes = epoch_votes.total_votes.saturating_add(weight);
+                Ok((pool_id, weight))
+            })
+            .collect::<Result<Vec<_>, DispatchError>>()?;
+
+        EpochVotes::<T>::insert(epoch, epoch_votes);
+        UserVotes::<T>::insert(who, (epoch, votes.clone()));
+        Ok((epoch, votes))
+    }
+
+    /// Pays the share of the pool in the rewards of an ended epoch to its stakers.
+    pub(crate) fn do_distribute_vote_rewards(
+        epoch: u32,
+        pool_id: PoolId,
+    ) -> Result<RewardOf<T>, DispatchError> {
+        ensure!(epoch < Self::current_epoch(), Error::<T>::EpochNotEnded);
+        let pool_votes = PoolVotes::<T>::take(epoch, pool_id);
+        let epoch_votes = Self::epoch_votes(epoch).ok_or(Error::<T>::NoVotes)?;
+        ensure!(!pool_votes.is_zero(), Error::<T>::NoVotes);
+
+        let mut pool_info = Self::updated_pool_info(pool_id)?;
+        ensure!(
+            pool_info.state == PoolState::Ongoing && !pool_info.total_shares.is_zero(),
+            Error::<T>::InvalidPoolState
+        );
+        let vote_proportion = Perbill::from_rational(
+            pool_votes.saturated_into::<u128>(),
+            epoch_votes.total_votes.saturated_into::<u128>(),
+        );
+        let rewards: RewardOf<T> = epoch_votes
+            .rewards
+            .into_iter()
+            .map(|(reward_currency, reward_amount)| {
+                (reward_currency, vote_proportion * reward_amount)
+            })
+            .filter(|(_, reward_amount)| !reward_amount.is_zero())
+            .collect();
+        rewards
+            .iter()
+            .try_for_each(|(reward_currency, reward_amount)| -> DispatchResult {
+                T::MultiCurrency::transfer(
+                    *reward_currency,
+                    &T::TreasuryAccount::get(),
+                    &pool_info.reward_issuer,
+                    *reward_amount,
+                )?;
+                pool_info
+                    .rewards
+                    .entry(*reward_currency)
+                    .and_modify(|(total_reward, _)| {
+                        *total_reward = total_reward.saturating_add(*reward_amount)
+                    })
+                    .or_insert((*reward_amount, Zero::zero()));
+                Ok(())
+            })?;
+        PoolInfos::<T>::insert(pool_id, &pool_info);
+        Ok(rewards)
+    }
+}
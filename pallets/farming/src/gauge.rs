@@ -72,6 +72,8 @@ pub struct GaugePoolInfo<BalanceOf: HasCompact, CurrencyIdOf: Ord, AccountIdOf,
     pub total_time_factor: u128,
     pub gauge_state: GaugeState,
     pub gauge_last_block: BlockNumberFor,
+    /// The block number up to which `rewards` have been accrued
+    pub last_update_block: BlockNumberFor,
 }
 
 #[derive(Encode, Decode, Copy, Clone, Eq, PartialEq, RuntimeDebug, TypeInfo)]
@@ -106,8 +108,9 @@ where
             max_block,
             gauge_amount: Default::default(),
             total_time_factor: Default::default(),
-            gauge_last_block: current_block_number,
+            gauge_last_block: current_block_number.clone(),
             gauge_state: GaugeState::Bonded,
+            last_update_block: current_block_number,
         }
     }
 }
@@ -155,6 +158,10 @@ where
     ) -> DispatchResult {
         GaugePoolInfos::<T>::mutate(gid, |gauge_pool_info_old| -> DispatchResult {
             if let Some(mut gauge_pool_info) = gauge_pool_info_old.take() {
+                Self::accrue_gauge_rewards(
+                    &mut gauge_pool_info,
+                    frame_system::Pallet::<T>::block_number(),
+                );
                 let mut gauge_info =
                     GaugeInfos::<T>::get(gid, who).unwrap_or_else(|| GaugeInfo::new(who.clone()));
 
@@ -233,8 +240,7 @@ where
             return Ok(());
         }
         let current_block_number: BlockNumberFor<T> = frame_system::Pallet::<T>::block_number();
-        let mut gauge_pool_info =
-            GaugePoolInfos::<T>::get(gid).ok_or(Error::<T>::GaugePoolNotExist)?;
+        let mut gauge_pool_info = Self::updated_gauge_pool_info(gid)?;
         let pool_info =
             PoolInfos::<T>::get(gauge_pool_info.pool_id).ok_or(Error::<T>::PoolDoesNotExist)?;
         GaugeInfos::<T>::mutate_exists(gid, who, |maybe_gauge_info| -> DispatchResult {
@@ -312,14 +318,13 @@ where
         who: &T::AccountId,
         pool_id: PoolId,
     ) -> Result<RewardOf<T>, DispatchError> {
-        let pool_info = PoolInfos::<T>::get(pool_id).ok_or(Error::<T>::PoolDoesNotExist)?;
+        let pool_info = Self::updated_pool_info(pool_id)?;
         let mut result_vec = Vec::<(CurrencyIdOf<T>, BalanceOf<T>)>::new();
 
         match pool_info.gauge {
             None => (),
             Some(gid) => {
-                let gauge_pool_info =
-                    GaugePoolInfos::<T>::get(gid).ok_or(Error::<T>::GaugePoolNotExist)?;
+                let gauge_pool_info = Self::updated_gauge_pool_info(gid)?;
                 let gauge_info =
                     GaugeInfos::<T>::get(gid, who).ok_or(Error::<T>::GaugeInfoNotExist)?;
                 let (gauge_rate, _) = Self::get_gauge_rate(&gauge_pool_info, &gauge_info)?;
@@ -16,27 +16,38 @@
 
 #![cfg_attr(not(feature = "std"), no_std)]
 
-use frame_support::{pallet_prelude::*, PalletId};
+use frame_support::{
+    pallet_prelude::*,
+    traits::{Currency, LockableCurrency, ReservableCurrency},
+    PalletId,
+};
 use frame_system::pallet_prelude::*;
-use manta_primitives::types::PoolId;
+use manta_primitives::{assets::AssetIdLpMap, types::PoolId};
 use orml_traits::{arithmetic::CheckedAdd, MultiCurrency};
 use sp_core::U256;
 use sp_runtime::{
-    traits::{AccountIdConversion, AtLeast32BitUnsigned, One, Saturating, Zero},
-    ArithmeticError, Perbill, SaturatedConversion,
+    traits::{
+        AccountIdConversion, AtLeast32BitUnsigned, Bounded, CheckedDiv, One, Saturating, Zero,
+    },
+    ArithmeticError, FixedPointNumber, FixedU128, Perbill, Percent, SaturatedConversion,
 };
 use sp_std::{borrow::ToOwned, collections::btree_map::BTreeMap, vec::Vec};
 
 #[cfg(feature = "runtime-benchmarks")]
 mod benchmarking;
+pub mod boost;
 pub mod gauge;
+pub mod liquidity;
+pub mod migrations;
 #[cfg(test)]
 mod mock;
 pub mod rewards;
 #[cfg(test)]
 mod tests;
 pub mod weights;
+pub use boost::*;
 pub use gauge::*;
+pub use liquidity::*;
 pub use pallet::*;
 pub use rewards::*;
 pub use weights::WeightInfo;
@@ -53,6 +64,10 @@ pub type CurrencyIdOf<T> = <<T as Config>::MultiCurrency as MultiCurrency<
 type BalanceOf<T: Config> =
     <<T as Config>::MultiCurrency as MultiCurrency<AccountIdOf<T>>>::Balance;
 
+#[allow(type_alias_bounds)]
+type DepositBalanceOf<T: Config> =
+    <<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
+
 #[allow(type_alias_bounds)]
 type GaugeInitType<T: Config> = (
     CurrencyIdOf<T>,
@@ -68,6 +83,21 @@ type PoolInfoOf<T: Config> =
 type GaugePoolInfoOf<T: Config> =
     GaugePoolInfo<BalanceOf<T>, CurrencyIdOf<T>, AccountIdOf<T>, BlockNumberFor<T>>;
 
+#[allow(type_alias_bounds)]
+pub type PoolSummaryOf<T: Config> = PoolSummary<BalanceOf<T>, CurrencyIdOf<T>, BlockNumberFor<T>>;
+
+#[allow(type_alias_bounds)]
+pub type UserPositionOf<T: Config> = UserPosition<BalanceOf<T>, CurrencyIdOf<T>, BlockNumberFor<T>>;
+
+#[allow(type_alias_bounds)]
+type VeLockOf<T: Config> = VeLock<DepositBalanceOf<T>>;
+
+#[allow(type_alias_bounds)]
+type VeSupplyInfoOf<T: Config> = VeSupplyInfo<DepositBalanceOf<T>>;
+
+#[allow(type_alias_bounds)]
+type EpochVoteInfoOf<T: Config> = EpochVoteInfo<BalanceOf<T>, CurrencyIdOf<T>, DepositBalanceOf<T>>;
+
 #[allow(type_alias_bounds)]
 type GaugeInfoOf<T> = GaugeInfo<BalanceOf<T>, BlockNumberFor<T>, AccountIdOf<T>>;
 
@@ -82,7 +112,10 @@ type RewardOf<T> = Vec<(CurrencyIdOf<T>, BalanceOf<T>)>;
 pub mod pallet {
     use super::*;
 
+    const STORAGE_VERSION: StorageVersion = StorageVersion::new(2);
+
     #[pallet::pallet]
+    #[pallet::storage_version(STORAGE_VERSION)]
     #[pallet::without_storage_info]
     pub struct Pallet<T>(_);
 
@@ -119,6 +152,53 @@ pub mod pallet {
 
         #[pallet::constant]
         type RewardIssuer: Get<PalletId>;
+
+        /// Currency in which the incentive pool creation deposit is reserved and vote-escrow
+        /// locks are made
+        type Currency: ReservableCurrency<Self::AccountId>
+            + LockableCurrency<Self::AccountId, Moment = BlockNumberFor<Self>>;
+
+        /// Deposit reserved from the creator of an incentive pool until the pool is killed
+        #[pallet::constant]
+        type PoolCreationDeposit: Get<DepositBalanceOf<Self>>;
+
+        /// Registered LP assets, the only tokens incentive pools can stake
+        type LpAssets: AssetIdLpMap<AssetId = Self::CurrencyId>;
+
+        /// The maximum number of reward tokens of an incentive pool
+        #[pallet::constant]
+        type MaxBasicRewards: Get<u32>;
+
+        /// The maximum number of reward steps which can be scheduled for a pool
+        #[pallet::constant]
+        type MaxRewardSteps: Get<u32>;
+
+        /// The longest withdraw limit time an incentive pool can be created with
+        #[pallet::constant]
+        type MaxWithdrawLimitTime: Get<BlockNumberFor<Self>>;
+
+        /// The longest claim limit time an incentive pool can be created with
+        #[pallet::constant]
+        type MaxClaimLimitTime: Get<BlockNumberFor<Self>>;
+
+        /// The number of blocks of a voting epoch
+        #[pallet::constant]
+        type VeEpochLength: Get<BlockNumberFor<Self>>;
+
+        /// The maximum number of epochs native tokens can be locked for, giving full voting power
+        #[pallet::constant]
+        type MaxLockEpochs: Get<u32>;
+
+        /// The extra share weight a staked share earns at full boost
+        #[pallet::constant]
+        type MaxBoost: Get<Perbill>;
+
+        /// The maximum number of pools an account can vote for in an epoch
+        #[pallet::constant]
+        type MaxVotes: Get<u32>;
+
+        /// The DEX providing the liquidity of the LP tokens staked in farming pools
+        type Liquidity: LiquidityProvider<Self::AccountId, Self::CurrencyId, BalanceOf<Self>>;
     }
 
     #[pallet::event]
@@ -182,6 +262,59 @@ pub mod pallet {
         RetireLimitSet {
             limit: u32,
         },
+        RewardScheduleSet {
+            pid: PoolId,
+            end_block: Option<BlockNumberFor<T>>,
+        },
+        IncentivePoolCreated {
+            pid: PoolId,
+            creator: AccountIdOf<T>,
+            lp_token: CurrencyIdOf<T>,
+        },
+        RewardsReclaimed {
+            who: AccountIdOf<T>,
+            pid: PoolId,
+            rewards: Vec<(CurrencyIdOf<T>, BalanceOf<T>)>,
+        },
+        VeLocked {
+            who: AccountIdOf<T>,
+            amount: DepositBalanceOf<T>,
+            end_epoch: u32,
+        },
+        VeUnlocked {
+            who: AccountIdOf<T>,
+            amount: DepositBalanceOf<T>,
+        },
+        BoostUpdated {
+            who: AccountIdOf<T>,
+            pid: PoolId,
+            boost_share: BalanceOf<T>,
+        },
+        Voted {
+            who: AccountIdOf<T>,
+            epoch: u32,
+            votes: Vec<(PoolId, DepositBalanceOf<T>)>,
+        },
+        VoteRewardsSet {
+            rewards: Vec<(CurrencyIdOf<T>, BalanceOf<T>)>,
+        },
+        VoteRewardsDistributed {
+            epoch: u32,
+            pid: PoolId,
+            rewards: Vec<(CurrencyIdOf<T>, BalanceOf<T>)>,
+        },
+        LiquidityDeposited {
+            who: AccountIdOf<T>,
+            pid: PoolId,
+            lp_token: CurrencyIdOf<T>,
+            liquidity: BalanceOf<T>,
+        },
+        LiquidityWithdrawn {
+            who: AccountIdOf<T>,
+            pid: PoolId,
+            lp_token: CurrencyIdOf<T>,
+            liquidity: BalanceOf<T>,
+        },
     }
 
     #[pallet::error]
@@ -210,6 +343,34 @@ pub mod pallet {
         CanNotDeposit,
         /// The retire limit number is not set
         RetireLimitNotSet,
+        /// Staking token is not a registered LP asset
+        NotLpAsset,
+        /// Only the creator of the incentive pool can do this
+        NotPoolCreator,
+        /// The lock duration exceeds `MaxLockEpochs` or the lock would be empty
+        InvalidLockDuration,
+        /// Not enough free balance to lock
+        NotEnoughBalance,
+        /// The vote-escrow lock has expired and must be unlocked first
+        VeLockExpired,
+        /// The vote-escrow lock does not exist
+        VeLockNotExist,
+        /// The vote-escrow lock has not expired yet
+        VeLockNotExpired,
+        /// No voting power in the current epoch
+        NoVotingPower,
+        /// The votes add up to more than 100%
+        InvalidVotes,
+        /// The epoch has not ended yet
+        EpochNotEnded,
+        /// No votes for the pool in the epoch
+        NoVotes,
+        /// The pool does not stake only the LP token of the pair
+        NotLiquidityPool,
+        /// No LP tokens were minted or unstaked
+        NoLiquidity,
+        /// A reward currency of the pool has not been charged
+        RewardBudgetNotFound,
     }
 
     /// The next farming pool id.
@@ -258,56 +419,92 @@ pub mod pallet {
     pub type SharesAndWithdrawnRewards<T: Config> =
         StorageDoubleMap<_, Twox64Concat, PoolId, Twox64Concat, T::AccountId, ShareInfoOf<T>>;
 
-    #[pallet::hooks]
-    impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
-        fn on_initialize(n: BlockNumberFor<T>) -> Weight {
-            PoolInfos::<T>::iter().for_each(|(pool_id, mut pool_info)| match pool_info.state {
-                PoolState::Ongoing => {
-                    pool_info.basic_rewards.clone().iter().for_each(
-                        |(reward_currency_id, reward_amount)| {
-                            pool_info
-                                .rewards
-                                .entry(*reward_currency_id)
-                                .and_modify(|(total_reward, _)| {
-                                    *total_reward = total_reward.saturating_add(*reward_amount);
-                                })
-                                .or_insert((*reward_amount, Zero::zero()));
-                        },
-                    );
-                    PoolInfos::<T>::insert(pool_id, &pool_info);
-                }
-                PoolState::Charged => {
-                    if n >= pool_info.after_block_to_start
-                        && pool_info.total_shares >= pool_info.min_deposit_to_start
-                    {
-                        pool_info.block_startup = Some(n);
-                        pool_info.state = PoolState::Ongoing;
-                        PoolInfos::<T>::insert(pool_id, &pool_info);
-                    }
-                }
-                _ => (),
-            });
+    /// Record the creator and the reserved deposit of permissionless incentive pools.
+    ///
+    /// map PoolId => (AccountId, Deposit)
+    #[pallet::storage]
+    #[pallet::getter(fn pool_creators)]
+    pub type PoolCreators<T: Config> =
+        StorageMap<_, Twox64Concat, PoolId, (T::AccountId, DepositBalanceOf<T>)>;
 
-            GaugePoolInfos::<T>::iter().for_each(|(pool_id, mut gauge_pool_info)| {
-                if gauge_pool_info.gauge_state == GaugeState::Bonded {
-                    gauge_pool_info.gauge_basic_rewards.clone().iter().for_each(
-                        |(reward_currency_id, reward_amount)| {
-                            gauge_pool_info
-                                .rewards
-                                .entry(*reward_currency_id)
-                                .and_modify(|(total_reward, _, _)| {
-                                    *total_reward = total_reward.saturating_add(*reward_amount);
-                                })
-                                .or_insert((*reward_amount, Zero::zero(), Zero::zero()));
-                        },
-                    );
-                    GaugePoolInfos::<T>::insert(pool_id, &gauge_pool_info);
-                }
-            });
+    /// Record the vote-escrow lock of native tokens of `AccountId`.
+    ///
+    /// map AccountId => VeLock
+    #[pallet::storage]
+    #[pallet::getter(fn ve_locks)]
+    pub type VeLocks<T: Config> = StorageMap<_, Twox64Concat, T::AccountId, VeLockOf<T>>;
 
-            T::WeightInfo::on_initialize()
-        }
-    }
+    /// The total of the unexpired vote-escrow locks.
+    #[pallet::storage]
+    #[pallet::getter(fn ve_supply)]
+    pub type VeSupply<T: Config> = StorageValue<_, VeSupplyInfoOf<T>, ValueQuery>;
+
+    /// Record the total amount and weighted end of the locks expiring at an epoch.
+    ///
+    /// map Epoch => (Amount, WeightedEnd)
+    #[pallet::storage]
+    #[pallet::getter(fn ve_expiries)]
+    pub type VeExpiries<T: Config> =
+        StorageMap<_, Twox64Concat, u32, (DepositBalanceOf<T>, DepositBalanceOf<T>)>;
+
+    /// Record the part of the share of `AccountId` under `PoolId` given by its voting power.
+    ///
+    /// double_map (PoolId, AccountId) => BoostShare
+    #[pallet::storage]
+    #[pallet::getter(fn boost_shares)]
+    pub type BoostShares<T: Config> = StorageDoubleMap<
+        _,
+        Twox64Concat,
+        PoolId,
+        Twox64Concat,
+        T::AccountId,
+        BalanceOf<T>,
+        ValueQuery,
+    >;
+
+    /// Record the total boost shares of a pool.
+    ///
+    /// map PoolId => BoostShare
+    #[pallet::storage]
+    #[pallet::getter(fn pool_boost_shares)]
+    pub type PoolBoostShares<T: Config> =
+        StorageMap<_, Twox64Concat, PoolId, BalanceOf<T>, ValueQuery>;
+
+    /// The rewards split across pools following the votes of each epoch.
+    #[pallet::storage]
+    #[pallet::getter(fn vote_rewards)]
+    pub type VoteRewards<T: Config> =
+        StorageValue<_, BTreeMap<CurrencyIdOf<T>, BalanceOf<T>>, ValueQuery>;
+
+    /// Record the total votes and the rewards of an epoch.
+    ///
+    /// map Epoch => EpochVoteInfo
+    #[pallet::storage]
+    #[pallet::getter(fn epoch_votes)]
+    pub type EpochVotes<T: Config> = StorageMap<_, Twox64Concat, u32, EpochVoteInfoOf<T>>;
+
+    /// Record the votes for `PoolId` in an epoch, removed once the rewards are distributed.
+    ///
+    /// double_map (Epoch, PoolId) => Votes
+    #[pallet::storage]
+    #[pallet::getter(fn pool_votes)]
+    pub type PoolVotes<T: Config> = StorageDoubleMap<
+        _,
+        Twox64Concat,
+        u32,
+        Twox64Concat,
+        PoolId,
+        DepositBalanceOf<T>,
+        ValueQuery,
+    >;
+
+    /// Record the latest votes of `AccountId` along with their epoch.
+    ///
+    /// map AccountId => (Epoch, Vec<(PoolId, Votes)>)
+    #[pallet::storage]
+    #[pallet::getter(fn user_votes)]
+    pub type UserVotes<T: Config> =
+        StorageMap<_, Twox64Concat, T::AccountId, (u32, Vec<(PoolId, DepositBalanceOf<T>)>)>;
 
     #[pallet::call]
     impl<T: Config> Pallet<T>
@@ -356,6 +553,7 @@ pub mod pallet {
                 withdraw_limit_time,
                 claim_limit_time,
                 withdraw_limit_count,
+                frame_system::Pallet::<T>::block_number(),
             );
 
             if let Some((gauge_token, max_block, gauge_basic_rewards)) = gauge_init {
@@ -396,7 +594,10 @@ pub mod pallet {
         ) -> DispatchResult {
             let exchanger = ensure_signed(origin)?;
 
-            let mut pool_info = Self::pool_infos(pool_id).ok_or(Error::<T>::PoolDoesNotExist)?;
+            if let Some((creator, _)) = Self::pool_creators(pool_id) {
+                ensure!(creator == exchanger, Error::<T>::NotPoolCreator);
+            }
+            let mut pool_info = Self::updated_pool_info(pool_id)?;
             rewards
                 .iter()
                 .try_for_each(|(reward_currency, reward)| -> DispatchResult {
@@ -405,8 +606,15 @@ pub mod pallet {
                         &exchanger,
                         &pool_info.reward_issuer,
                         *reward,
-                    )
+                    )?;
+                    pool_info
+                        .reward_budget
+                        .entry(*reward_currency)
+                        .and_modify(|budget| *budget = budget.saturating_add(*reward))
+                        .or_insert(*reward);
+                    Ok(())
                 })?;
+            Self::ensure_reward_budget(&pool_info)?;
             pool_info.state = PoolState::Charged;
             PoolInfos::<T>::insert(pool_id, pool_info);
 
@@ -452,7 +660,7 @@ pub mod pallet {
         pub fn claim(origin: OriginFor<T>, pool_id: PoolId) -> DispatchResult {
             let exchanger = ensure_signed(origin)?;
 
-            let pool_info = Self::pool_infos(pool_id).ok_or(Error::<T>::PoolDoesNotExist)?;
+            let pool_info = Self::updated_pool_info(pool_id)?;
             ensure!(
                 PoolState::state_valid(Action::Claim, pool_info.state),
                 Error::<T>::InvalidPoolState
@@ -493,7 +701,7 @@ pub mod pallet {
         pub fn close_pool(origin: OriginFor<T>, pool_id: PoolId) -> DispatchResult {
             T::ControlOrigin::ensure_origin(origin)?;
 
-            let mut pool_info = Self::pool_infos(pool_id).ok_or(Error::<T>::PoolDoesNotExist)?;
+            let mut pool_info = Self::updated_pool_info(pool_id)?;
             ensure!(
                 PoolState::state_valid(Action::ClosePool, pool_info.state),
                 Error::<T>::InvalidPoolState
@@ -522,7 +730,7 @@ pub mod pallet {
         #[pallet::call_index(8)]
         #[pallet::weight(T::DbWeight::get().write)]
         pub fn retire_pool(origin: OriginFor<T>, pool_id: PoolId) -> DispatchResult {
-            T::ControlOrigin::ensure_origin(origin)?;
+            Self::ensure_control_or_creator(origin, pool_id)?;
 
             let mut pool_info = Self::pool_infos(pool_id).ok_or(Error::<T>::PoolDoesNotExist)?;
             ensure!(
@@ -541,6 +749,7 @@ pub mod pallet {
                     break;
                 }
                 let who = share_info.who;
+                Self::set_boost_share(&who, pool_id, Zero::zero())?;
                 Self::remove_share(&who, pool_id, None, withdraw_limit_time)?;
                 Self::claim_rewards(&who, pool_id)?;
                 if let Some(ref gid) = pool_info.gauge {
@@ -551,8 +760,7 @@ pub mod pallet {
 
             if all_retired {
                 if let Some(ref gid) = pool_info.gauge {
-                    let mut gauge_pool_info =
-                        Self::gauge_pool_infos(gid).ok_or(Error::<T>::GaugePoolNotExist)?;
+                    let mut gauge_pool_info = Self::updated_gauge_pool_info(*gid)?;
                     gauge_pool_info.gauge_state = GaugeState::Unbond;
                     GaugePoolInfos::<T>::insert(gid, gauge_pool_info);
                 }
@@ -626,6 +834,7 @@ pub mod pallet {
             pool_info.rewards = BTreeMap::new();
             pool_info.state = PoolState::UnCharged;
             pool_info.block_startup = None;
+            pool_info.last_update_block = frame_system::Pallet::<T>::block_number();
             PoolInfos::<T>::insert(pool_id, &pool_info);
 
             Self::deposit_event(Event::FarmingPoolReset { pid: pool_id });
@@ -635,15 +844,22 @@ pub mod pallet {
         #[pallet::call_index(10)]
         #[pallet::weight(T::DbWeight::get().write)]
         pub fn kill_pool(origin: OriginFor<T>, pool_id: PoolId) -> DispatchResult {
-            T::ControlOrigin::ensure_origin(origin)?;
+            Self::ensure_control_or_creator(origin, pool_id)?;
 
-            let pool_info = Self::pool_infos(pool_id).ok_or(Error::<T>::PoolDoesNotExist)?;
+            let mut pool_info = Self::pool_infos(pool_id).ok_or(Error::<T>::PoolDoesNotExist)?;
             ensure!(
                 PoolState::state_valid(Action::KillPool, pool_info.state),
                 Error::<T>::InvalidPoolState
             );
+            if let Some((creator, deposit)) = PoolCreators::<T>::take(pool_id) {
+                Self::transfer_reward_budget(&mut pool_info, &creator)?;
+                T::Currency::unreserve(&creator, deposit);
+            }
             #[allow(deprecated)]
             SharesAndWithdrawnRewards::<T>::remove_prefix(pool_id, None);
+            #[allow(deprecated)]
+            BoostShares::<T>::remove_prefix(pool_id, None);
+            PoolBoostShares::<T>::remove(pool_id);
             PoolInfos::<T>::remove(pool_id);
 
             Self::deposit_event(Event::FarmingPoolKilled { pid: pool_id });
@@ -663,7 +879,7 @@ pub mod pallet {
         ) -> DispatchResult {
             T::ControlOrigin::ensure_origin(origin)?;
 
-            let mut pool_info = Self::pool_infos(pool_id).ok_or(Error::<T>::PoolDoesNotExist)?;
+            let mut pool_info = Self::updated_pool_info(pool_id)?;
             ensure!(
                 PoolState::state_valid(Action::EditPool, pool_info.state),
                 Error::<T>::InvalidPoolState
@@ -689,16 +905,14 @@ pub mod pallet {
                         .into_iter()
                         .map(|(k, v)| (k, v))
                         .collect();
-                GaugePoolInfos::<T>::mutate(
-                    pool_info.gauge.ok_or(Error::<T>::GaugePoolNotExist)?,
-                    |gauge_pool_info_old| {
-                        if let Some(mut gauge_pool_info) = gauge_pool_info_old.take() {
-                            gauge_pool_info.gauge_basic_rewards = gauge_basic_rewards_map;
-                            *gauge_pool_info_old = Some(gauge_pool_info);
-                        }
-                    },
-                );
+                let gid = pool_info.gauge.ok_or(Error::<T>::GaugePoolNotExist)?;
+                let mut gauge_pool_info = Self::updated_gauge_pool_info(gid)?;
+                gauge_pool_info.gauge_basic_rewards = gauge_basic_rewards_map;
+                GaugePoolInfos::<T>::insert(gid, gauge_pool_info);
             };
+            if matches!(pool_info.state, PoolState::Charged | PoolState::Ongoing) {
+                Self::ensure_reward_budget(&pool_info)?;
+            }
             PoolInfos::<T>::insert(pool_id, &pool_info);
 
             Self::deposit_event(Event::FarmingPoolEdited { pid: pool_id });
@@ -770,10 +984,399 @@ pub mod pallet {
             }
             Ok(())
         }
+
+        /// `ControlOrigin` set the end block and the step changes of `basic_rewards`.
+        ///
+        /// Rewards stop accruing after `end_block`, then the pool is closed. Each step replaces
+        /// `basic_rewards` from its block on, previously scheduled steps are discarded.
+        #[pallet::call_index(14)]
+        #[pallet::weight(T::WeightInfo::set_reward_schedule(reward_steps.len() as u32))]
+        pub fn set_reward_schedule(
+            origin: OriginFor<T>,
+            pool_id: PoolId,
+            end_block: Option<BlockNumberFor<T>>,
+            reward_steps: BoundedVec<
+                (
+                    BlockNumberFor<T>,
+                    BoundedVec<(CurrencyIdOf<T>, BalanceOf<T>), T::MaxBasicRewards>,
+                ),
+                T::MaxRewardSteps,
+            >,
+        ) -> DispatchResult {
+            T::ControlOrigin::ensure_origin(origin)?;
+
+            let mut pool_info = Self::updated_pool_info(pool_id)?;
+            ensure!(
+                PoolState::state_valid(Action::EditPool, pool_info.state),
+                Error::<T>::InvalidPoolState
+            );
+
+            let current_block_number: BlockNumberFor<T> = frame_system::Pallet::<T>::block_number();
+            let last_block = end_block.unwrap_or_else(Bounded::max_value);
+            ensure!(
+                last_block > current_block_number,
+                Error::<T>::InvalidPoolParameter
+            );
+            let mut reward_steps_map = BTreeMap::new();
+            for (step_block, basic_rewards) in reward_steps {
+                ensure!(
+                    step_block > current_block_number && step_block <= last_block,
+                    Error::<T>::InvalidPoolParameter
+                );
+                let basic_rewards_map: BTreeMap<CurrencyIdOf<T>, BalanceOf<T>> =
+                    basic_rewards.into_iter().collect();
+                reward_steps_map.insert(step_block, basic_rewards_map);
+            }
+            pool_info.end_block = end_block;
+            pool_info.reward_steps = reward_steps_map;
+            if matches!(pool_info.state, PoolState::Charged | PoolState::Ongoing) {
+                Self::ensure_reward_budget(&pool_info)?;
+            }
+            PoolInfos::<T>::insert(pool_id, &pool_info);
+
+            Self::deposit_event(Event::RewardScheduleSet {
+                pid: pool_id,
+                end_block,
+            });
+            Ok(())
+        }
+
+        /// Anyone can create an incentive pool staking a registered LP token, reserving
+        /// `PoolCreationDeposit` until the pool is killed.
+        ///
+        /// Only the creator can `charge` the pool, and `retire_pool` or `kill_pool` it besides
+        /// `ControlOrigin`. At least one withdrawal must be allowed and the withdraw and claim
+        /// limit times are capped by `MaxWithdrawLimitTime` and `MaxClaimLimitTime`.
+        #[pallet::call_index(15)]
+        #[pallet::weight(T::WeightInfo::create_incentive_pool())]
+        pub fn create_incentive_pool(
+            origin: OriginFor<T>,
+            lp_token: CurrencyIdOf<T>,
+            basic_rewards: BoundedVec<(CurrencyIdOf<T>, BalanceOf<T>), T::MaxBasicRewards>,
+            #[pallet::compact] min_deposit_to_start: BalanceOf<T>,
+            #[pallet::compact] after_block_to_start: BlockNumberFor<T>,
+            #[pallet::compact] withdraw_limit_time: BlockNumberFor<T>,
+            #[pallet::compact] claim_limit_time: BlockNumberFor<T>,
+            #[pallet::compact] withdraw_limit_count: u8,
+            end_block: Option<BlockNumberFor<T>>,
+        ) -> DispatchResult {
+            let creator = ensure_signed(origin)?;
+
+            ensure!(
+                T::LpAssets::lp_asset_pool(&lp_token).is_some(),
+                Error::<T>::NotLpAsset
+            );
+            let current_block_number: BlockNumberFor<T> = frame_system::Pallet::<T>::block_number();
+            ensure!(
+                end_block.map_or(true, |end_block| end_block > current_block_number),
+                Error::<T>::InvalidPoolParameter
+            );
+            // deposits could never be withdrawn or stay locked for too long
+            ensure!(
+                withdraw_limit_count >= 1
+                    && withdraw_limit_time <= T::MaxWithdrawLimitTime::get()
+                    && claim_limit_time <= T::MaxClaimLimitTime::get(),
+                Error::<T>::InvalidPoolParameter
+            );
+
+            let pool_id = Self::pool_next_id();
+            let keeper = T::Keeper::get().into_sub_account_truncating(pool_id);
+            let reward_issuer = T::RewardIssuer::get().into_sub_account_truncating(pool_id);
+            let basic_token = (lp_token, Perbill::from_percent(100));
+            let tokens_proportion_map: BTreeMap<CurrencyIdOf<T>, Perbill> =
+                [basic_token].into_iter().collect();
+            let basic_rewards_map: BTreeMap<CurrencyIdOf<T>, BalanceOf<T>> =
+                basic_rewards.into_iter().collect();
+
+            let mut pool_info = PoolInfo::new(
+                keeper,
+                reward_issuer,
+                tokens_proportion_map,
+                basic_token,
+                basic_rewards_map,
+                None,
+                min_deposit_to_start,
+                after_block_to_start,
+                withdraw_limit_time,
+                claim_limit_time,
+                withdraw_limit_count,
+                current_block_number,
+            );
+            pool_info.end_block = end_block;
+
+            let deposit = T::PoolCreationDeposit::get();
+            T::Currency::reserve(&creator, deposit)?;
+            PoolInfos::<T>::insert(pool_id, &pool_info);
+            PoolCreators::<T>::insert(pool_id, (creator.clone(), deposit));
+            PoolNextId::<T>::mutate(|id| -> DispatchResult {
+                *id = id
+                    .checked_add(One::one())
+                    .ok_or(ArithmeticError::Overflow)?;
+                Ok(())
+            })?;
+
+            Self::deposit_event(Event::IncentivePoolCreated {
+                pid: pool_id,
+                creator,
+                lp_token,
+            });
+            Ok(())
+        }
+
+        /// The creator of an incentive pool reclaims the charged rewards which were not
+        /// accrued once the pool has ended.
+        #[pallet::call_index(16)]
+        #[pallet::weight(T::WeightInfo::reclaim_rewards())]
+        pub fn reclaim_rewards(origin: OriginFor<T>, pool_id: PoolId) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            let (creator, _) = Self::pool_creators(pool_id).ok_or(Error::<T>::NotPoolCreator)?;
+            ensure!(creator == who, Error::<T>::NotPoolCreator);
+            let mut pool_info = Self::updated_pool_info(pool_id)?;
+            ensure!(
+                PoolState::state_valid(Action::ReclaimRewards, pool_info.state),
+                Error::<T>::InvalidPoolState
+            );
+
+            let rewards = Self::transfer_reward_budget(&mut pool_info, &who)?;
+            PoolInfos::<T>::insert(pool_id, &pool_info);
+
+            Self::deposit_event(Event::RewardsReclaimed {
+                who,
+                pid: pool_id,
+                rewards,
+            });
+            Ok(())
+        }
+
+        /// Locks `amount` more native tokens for voting power and extends the lock to expire no
+        /// earlier than `lock_epochs` from now.
+        #[pallet::call_index(17)]
+        #[pallet::weight(T::WeightInfo::ve_lock())]
+        pub fn ve_lock(
+            origin: OriginFor<T>,
+            #[pallet::compact] amount: DepositBalanceOf<T>,
+            lock_epochs: u32,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            let lock = Self::do_ve_lock(&who, amount, lock_epochs)?;
+
+            Self::deposit_event(Event::VeLocked {
+                who,
+                amount: lock.amount,
+                end_epoch: lock.end_epoch,
+            });
+            Ok(())
+        }
+
+        /// Unlocks the native tokens of an expired vote-escrow lock.
+        #[pallet::call_index(18)]
+        #[pallet::weight(T::WeightInfo::ve_unlock())]
+        pub fn ve_unlock(origin: OriginFor<T>) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            let lock = Self::do_ve_unlock(&who)?;
+
+            Self::deposit_event(Event::VeUnlocked {
+                who,
+                amount: lock.amount,
+            });
+            Ok(())
+        }
+
+        /// Updates the boost share of `who` in the pool to their current voting power, which
+        /// anyone can do to take back the boost of a decayed or expired lock.
+        #[pallet::call_index(19)]
+        #[pallet::weight(T::WeightInfo::update_boost())]
+        pub fn update_boost(
+            origin: OriginFor<T>,
+            who: AccountIdOf<T>,
+            pool_id: PoolId,
+        ) -> DispatchResult {
+            ensure_signed(origin)?;
+
+            Self::update_boost_share(&who, pool_id)
+        }
+
+        /// Votes for pools with proportions of the current voting power, replacing the votes
+        /// already cast in this epoch.
+        #[pallet::call_index(20)]
+        #[pallet::weight(T::WeightInfo::vote(votes.len() as u32))]
+        pub fn vote(
+            origin: OriginFor<T>,
+            votes: BoundedVec<(PoolId, Percent), T::MaxVotes>,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            let (epoch, votes) = Self::do_vote(&who, votes)?;
+
+            Self::deposit_event(Event::Voted { who, epoch, votes });
+            Ok(())
+        }
+
+        /// `ControlOrigin` sets the rewards paid by the treasury and split across pools
+        /// following the votes of each epoch.
+        #[pallet::call_index(21)]
+        #[pallet::weight(T::WeightInfo::set_vote_rewards(rewards.len() as u32))]
+        pub fn set_vote_rewards(
+            origin: OriginFor<T>,
+            rewards: BoundedVec<(CurrencyIdOf<T>, BalanceOf<T>), T::MaxBasicRewards>,
+        ) -> DispatchResult {
+            T::ControlOrigin::ensure_origin(origin)?;
+
+            VoteRewards::<T>::put(rewards.iter().copied().collect::<BTreeMap<_, _>>());
+
+            Self::deposit_event(Event::VoteRewardsSet {
+                rewards: rewards.into_inner(),
+            });
+            Ok(())
+        }
+
+        /// Pays the share of the pool in the vote rewards of an ended epoch to its stakers.
+        #[pallet::call_index(22)]
+        #[pallet::weight(T::WeightInfo::distribute_vote_rewards())]
+        pub fn distribute_vote_rewards(
+            origin: OriginFor<T>,
+            epoch: u32,
+            pool_id: PoolId,
+        ) -> DispatchResult {
+            ensure_signed(origin)?;
+
+            let rewards = Self::do_distribute_vote_rewards(epoch, pool_id)?;
+
+            Self::deposit_event(Event::VoteRewardsDistributed {
+                epoch,
+                pid: pool_id,
+                rewards,
+            });
+            Ok(())
+        }
+
+        /// Adds liquidity of the pair and deposits the minted LP tokens into the pool, which
+        /// must stake only the LP token of the pair.
+        ///
+        /// Fails if less than `amount_0_min` or `amount_1_min` would be added.
+        #[pallet::call_index(23)]
+        #[pallet::weight(T::WeightInfo::deposit().saturating_add(T::Liquidity::add_liquidity_weight()))]
+        pub fn add_liquidity_and_deposit(
+            origin: OriginFor<T>,
+            pool_id: PoolId,
+            asset_0: CurrencyIdOf<T>,
+            asset_1: CurrencyIdOf<T>,
+            #[pallet::compact] amount_0_desired: BalanceOf<T>,
+            #[pallet::compact] amount_1_desired: BalanceOf<T>,
+            #[pallet::compact] amount_0_min: BalanceOf<T>,
+            #[pallet::compact] amount_1_min: BalanceOf<T>,
+            gauge_info: Option<(BalanceOf<T>, BlockNumberFor<T>)>,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            let (lp_token, liquidity) = Self::do_add_liquidity_and_deposit(
+                &who,
+                pool_id,
+                asset_0,
+                asset_1,
+                amount_0_desired,
+                amount_1_desired,
+                amount_0_min,
+                amount_1_min,
+                gauge_info,
+            )?;
+
+            Self::deposit_event(Event::LiquidityDeposited {
+                who,
+                pid: pool_id,
+                lp_token,
+                liquidity,
+            });
+            Ok(())
+        }
+
+        /// Withdraws from the pool, unstakes and removes the liquidity of the LP tokens
+        /// unstaked, which requires the withdrawal to be unlocked right away.
+        ///
+        /// Fails if less than `amount_0_min` or `amount_1_min` would be returned.
+        #[pallet::call_index(24)]
+        #[pallet::weight(T::WeightInfo::withdraw()
+            .saturating_add(T::WeightInfo::claim())
+            .saturating_add(T::Liquidity::remove_liquidity_weight()))]
+        pub fn withdraw_and_remove_liquidity(
+            origin: OriginFor<T>,
+            pool_id: PoolId,
+            asset_0: CurrencyIdOf<T>,
+            asset_1: CurrencyIdOf<T>,
+            remove_value: Option<BalanceOf<T>>,
+            #[pallet::compact] amount_0_min: BalanceOf<T>,
+            #[pallet::compact] amount_1_min: BalanceOf<T>,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            let (lp_token, liquidity) = Self::do_withdraw_and_remove_liquidity(
+                &who,
+                pool_id,
+                asset_0,
+                asset_1,
+                remove_value,
+                amount_0_min,
+                amount_1_min,
+            )?;
+
+            Self::deposit_event(Event::LiquidityWithdrawn {
+                who,
+                pid: pool_id,
+                lp_token,
+                liquidity,
+            });
+            Ok(())
+        }
     }
 }
 
 impl<T: Config> Pallet<T> {
+    /// Ensures `origin` is `ControlOrigin` or the signed creator of the incentive pool.
+    fn ensure_control_or_creator(origin: OriginFor<T>, pool_id: PoolId) -> DispatchResult {
+        if let Err(origin) = T::ControlOrigin::try_origin(origin) {
+            let who = ensure_signed(origin)?;
+            ensure!(
+                Self::pool_creators(pool_id).map_or(false, |(creator, _)| creator == who),
+                Error::<T>::NotPoolCreator
+            );
+        }
+        Ok(())
+    }
+
+    /// Ensures every currency the pool rewards now or in a reward step has been charged, so that
+    /// accrual never meets a reward without a `reward_budget` entry.
+    fn ensure_reward_budget(pool_info: &PoolInfoOf<T>) -> DispatchResult {
+        let charged = sp_std::iter::once(&pool_info.basic_rewards)
+            .chain(pool_info.reward_steps.values())
+            .flat_map(|basic_rewards| basic_rewards.iter())
+            .filter(|(_, reward_amount)| !reward_amount.is_zero())
+            .all(|(reward_currency_id, _)| {
+                pool_info.reward_budget.contains_key(reward_currency_id)
+            });
+        ensure!(charged, Error::<T>::RewardBudgetNotFound);
+        Ok(())
+    }
+
+    /// Transfers the charged rewards which were not accrued from the reward issuer to `who`.
+    fn transfer_reward_budget(
+        pool_info: &mut PoolInfoOf<T>,
+        who: &T::AccountId,
+    ) -> Result<RewardOf<T>, DispatchError> {
+        let rewards: RewardOf<T> = sp_std::mem::take(&mut pool_info.reward_budget)
+            .into_iter()
+            .filter(|(_, budget)| !budget.is_zero())
+            .collect();
+        rewards
+            .iter()
+            .try_for_each(|(reward_currency, budget)| -> DispatchResult {
+                T::MultiCurrency::transfer(*reward_currency, &pool_info.reward_issuer, who, *budget)
+            })?;
+        Ok(rewards)
+    }
+
     fn farming_token_transfer(
         reward_currency: &CurrencyIdOf<T>,
         reward_to_withdraw: BalanceOf<T>,
@@ -797,13 +1400,209 @@ impl<T: Config> Pallet<T> {
         T::MultiCurrency::transfer(*reward_currency, from, &account_to_send, reward_to_withdraw)
     }
 
+    /// Returns the pool info with rewards accrued up to the current block.
+    ///
+    /// The result is not written back, callers that mutate the pool must store it themselves.
+    pub fn updated_pool_info(pool_id: PoolId) -> Result<PoolInfoOf<T>, DispatchError> {
+        let mut pool_info = Self::pool_infos(pool_id).ok_or(Error::<T>::PoolDoesNotExist)?;
+        Self::accrue_pool_rewards(&mut pool_info, frame_system::Pallet::<T>::block_number())?;
+        Ok(pool_info)
+    }
+
+    /// Returns the gauge pool info with rewards accrued up to the current block.
+    pub fn updated_gauge_pool_info(gid: PoolId) -> Result<GaugePoolInfoOf<T>, DispatchError> {
+        let mut gauge_pool_info =
+            Self::gauge_pool_infos(gid).ok_or(Error::<T>::GaugePoolNotExist)?;
+        Self::accrue_gauge_rewards(
+            &mut gauge_pool_info,
+            frame_system::Pallet::<T>::block_number(),
+        );
+        Ok(gauge_pool_info)
+    }
+
+    /// Accrues `basic_rewards` for every block between `last_update_block` and `n`.
+    ///
+    /// A charged pool is started at the first block after its last update that satisfies
+    /// `after_block_to_start`, provided it already holds `min_deposit_to_start` shares.
+    pub(crate) fn accrue_pool_rewards(
+        pool_info: &mut PoolInfoOf<T>,
+        n: BlockNumberFor<T>,
+    ) -> DispatchResult {
+        Self::accrue_pool_rewards_until(pool_info, n)?;
+        pool_info.last_update_block = pool_info.last_update_block.max(n);
+        Ok(())
+    }
+
+    /// Accrues the pool rewards up to `n` following `reward_steps`, and closes the pool once
+    /// `end_block` is passed or `reward_budget` cannot pay for another block.
+    ///
+    /// `last_update_block` is left at the last rewarded block when the pool is closed. Fails with
+    /// `RewardBudgetNotFound` if a reward currency has never been charged.
+    fn accrue_pool_rewards_until(
+        pool_info: &mut PoolInfoOf<T>,
+        n: BlockNumberFor<T>,
+    ) -> DispatchResult {
+        if pool_info.state == PoolState::Charged
+            && pool_info.total_shares >= pool_info.min_deposit_to_start
+        {
+            let startup = pool_info
+                .after_block_to_start
+                .max(pool_info.last_update_block.saturating_add(One::one()));
+            if startup <= n {
+                pool_info.block_startup = Some(startup);
+                pool_info.state = PoolState::Ongoing;
+                pool_info.last_update_block = startup;
+            }
+        }
+        if pool_info.state == PoolState::Charged
+            && pool_info
+                .end_block
+                .map_or(false, |end_block| end_block <= n)
+        {
+            pool_info.state = PoolState::Dead;
+        }
+
+        while pool_info.state == PoolState::Ongoing {
+            let next_block = pool_info.last_update_block.saturating_add(One::one());
+            while let Some(step_block) = pool_info.reward_steps.keys().next().copied() {
+                if step_block > next_block {
+                    break;
+                }
+                if let Some(basic_rewards) = pool_info.reward_steps.remove(&step_block) {
+                    pool_info.basic_rewards = basic_rewards;
+                }
+            }
+            if pool_info
+                .end_block
+                .map_or(false, |end_block| pool_info.last_update_block >= end_block)
+            {
+                pool_info.state = PoolState::Dead;
+                break;
+            }
+
+            // accrue until the next step, the end of the schedule or `n`, whichever comes first
+            let mut until = n;
+            if let Some(end_block) = pool_info.end_block {
+                until = until.min(end_block);
+            }
+            if let Some(step_block) = pool_info.reward_steps.keys().next() {
+                until = until.min(step_block.saturating_sub(One::one()));
+            }
+            if until <= pool_info.last_update_block {
+                break;
+            }
+            let scheduled_blocks = until - pool_info.last_update_block;
+            let blocks = Self::funded_blocks(pool_info)?
+                .map_or(scheduled_blocks, |funded| funded.min(scheduled_blocks));
+
+            let blocks_balance = Self::blocks_as_balance(blocks);
+            pool_info.basic_rewards.clone().iter().for_each(
+                |(reward_currency_id, reward_amount)| {
+                    let accrued = reward_amount.saturating_mul(blocks_balance);
+                    pool_info
+                        .rewards
+                        .entry(*reward_currency_id)
+                        .and_modify(|(total_reward, _)| {
+                            *total_reward = total_reward.saturating_add(accrued);
+                        })
+                        .or_insert((accrued, Zero::zero()));
+                    if let Some(budget) = pool_info.reward_budget.get_mut(reward_currency_id) {
+                        *budget = budget.saturating_sub(accrued);
+                    }
+                },
+            );
+            pool_info.last_update_block = pool_info.last_update_block.saturating_add(blocks);
+
+            if blocks < scheduled_blocks {
+                pool_info.state = PoolState::Dead;
+            }
+        }
+        Ok(())
+    }
+
+    /// The number of blocks `reward_budget` can pay `basic_rewards` for, `None` if unlimited.
+    ///
+    /// A reward currency without a `reward_budget` entry was never charged and is an error.
+    fn funded_blocks(
+        pool_info: &PoolInfoOf<T>,
+    ) -> Result<Option<BlockNumberFor<T>>, DispatchError> {
+        let funded_blocks = pool_info
+            .basic_rewards
+            .iter()
+            .filter(|(_, reward_amount)| !reward_amount.is_zero())
+            .map(|(reward_currency_id, reward_amount)| {
+                let budget = pool_info
+                    .reward_budget
+                    .get(reward_currency_id)
+                    .ok_or(Error::<T>::RewardBudgetNotFound)?;
+                Ok((*budget / *reward_amount)
+                    .saturated_into::<u128>()
+                    .saturated_into())
+            })
+            .collect::<Result<Vec<BlockNumberFor<T>>, DispatchError>>()?;
+        Ok(funded_blocks.into_iter().min())
+    }
+
+    /// Returns the number of blocks from now on the pool keeps accruing rewards, assuming it is
+    /// running, or `None` if neither its schedule nor its budget bound it.
+    pub fn pool_runway(pool_id: PoolId) -> Result<Option<BlockNumberFor<T>>, DispatchError> {
+        let mut pool_info = Self::updated_pool_info(pool_id)?;
+        if !matches!(pool_info.state, PoolState::Charged | PoolState::Ongoing) {
+            return Ok(Some(Zero::zero()));
+        }
+
+        let current_block_number: BlockNumberFor<T> = frame_system::Pallet::<T>::block_number();
+        pool_info.state = PoolState::Ongoing;
+        pool_info.last_update_block = current_block_number;
+        Self::accrue_pool_rewards_until(&mut pool_info, Bounded::max_value())?;
+        if pool_info.state == PoolState::Dead {
+            Ok(Some(
+                pool_info
+                    .last_update_block
+                    .saturating_sub(current_block_number),
+            ))
+        } else {
+            Ok(None)
+        }
+    }
+
+    /// Accrues `gauge_basic_rewards` for every block between `last_update_block` and `n` while
+    /// the gauge pool is bonded.
+    pub(crate) fn accrue_gauge_rewards(
+        gauge_pool_info: &mut GaugePoolInfoOf<T>,
+        n: BlockNumberFor<T>,
+    ) {
+        if gauge_pool_info.gauge_state == GaugeState::Bonded
+            && n > gauge_pool_info.last_update_block
+        {
+            let blocks = Self::blocks_as_balance(n - gauge_pool_info.last_update_block);
+            gauge_pool_info.gauge_basic_rewards.clone().iter().for_each(
+                |(reward_currency_id, reward_amount)| {
+                    let accrued = reward_amount.saturating_mul(blocks);
+                    gauge_pool_info
+                        .rewards
+                        .entry(*reward_currency_id)
+                        .and_modify(|(total_reward, _, _)| {
+                            *total_reward = total_reward.saturating_add(accrued);
+                        })
+                        .or_insert((accrued, Zero::zero(), Zero::zero()));
+                },
+            );
+        }
+        gauge_pool_info.last_update_block = gauge_pool_info.last_update_block.max(n);
+    }
+
+    fn blocks_as_balance(blocks: BlockNumberFor<T>) -> BalanceOf<T> {
+        blocks.saturated_into::<u128>().saturated_into()
+    }
+
     pub fn get_farming_rewards(
         who: &T::AccountId,
         pool_id: PoolId,
     ) -> Result<RewardOf<T>, DispatchError> {
         let share_info = SharesAndWithdrawnRewards::<T>::get(pool_id, who)
             .ok_or(Error::<T>::ShareInfoNotExists)?;
-        let pool_info = PoolInfos::<T>::get(pool_id).ok_or(Error::<T>::PoolDoesNotExist)?;
+        let pool_info = Self::updated_pool_info(pool_id)?;
         let total_shares = pool_info.total_shares;
         let mut result_vec = Vec::<(CurrencyIdOf<T>, BalanceOf<T>)>::new();
 
@@ -828,6 +1627,95 @@ impl<T: Config> Pallet<T> {
         Ok(result_vec)
     }
 
+    /// Lists every farming pool with its rewards accrued up to the current block.
+    pub fn get_pool_summaries() -> Vec<PoolSummaryOf<T>> {
+        let mut pool_ids: Vec<PoolId> = PoolInfos::<T>::iter_keys().collect();
+        pool_ids.sort();
+        pool_ids
+            .into_iter()
+            .filter_map(|pool_id| {
+                let pool_info = Self::updated_pool_info(pool_id).ok()?;
+                Some(PoolSummary {
+                    pid: pool_id,
+                    state: pool_info.state,
+                    tokens_proportion: pool_info.tokens_proportion.into_iter().collect(),
+                    total_shares: pool_info.total_shares,
+                    basic_rewards: pool_info.basic_rewards.into_iter().collect(),
+                    gauge: pool_info.gauge,
+                    block_startup: pool_info.block_startup,
+                    end_block: pool_info.end_block,
+                })
+            })
+            .collect()
+    }
+
+    /// Estimates the yearly return of the pool at its current `basic_rewards`, excluding the
+    /// gauge rewards.
+    ///
+    /// `prices` are the prices of one unit of the smallest denomination of the staked and
+    /// reward tokens in a common quote currency. Returns `None` if the pool is not accruing
+    /// rewards, nothing is staked or a price is missing.
+    pub fn get_pool_apr(
+        pool_id: PoolId,
+        prices: &BTreeMap<CurrencyIdOf<T>, FixedU128>,
+        blocks_per_year: BlockNumberFor<T>,
+    ) -> Option<FixedU128> {
+        let pool_info = Self::updated_pool_info(pool_id).ok()?;
+        if !matches!(pool_info.state, PoolState::Charged | PoolState::Ongoing)
+            || pool_info.total_shares.is_zero()
+        {
+            return None;
+        }
+        // Amounts are scaled down by `FixedU128::DIV` to not saturate, which leaves the ratio as is
+        let to_fixed = |amount: u128| FixedU128::from_inner(amount);
+
+        // The value staked for one share, see `deposit_farming`
+        let basic_proportion = FixedU128::from(pool_info.basic_token.1);
+        let share_value = pool_info.tokens_proportion.iter().try_fold(
+            FixedU128::zero(),
+            |value, (token, proportion)| {
+                let amount = FixedU128::from(*proportion).checked_div(&basic_proportion)?;
+                Some(value.saturating_add(amount.saturating_mul(*prices.get(token)?)))
+            },
+        )?;
+        let staked_value =
+            share_value.saturating_mul(to_fixed(pool_info.total_shares.saturated_into::<u128>()));
+
+        let rewards_value = pool_info.basic_rewards.iter().try_fold(
+            FixedU128::zero(),
+            |value, (reward_currency, reward_amount)| {
+                let reward_value = to_fixed((*reward_amount).saturated_into::<u128>())
+                    .saturating_mul(*prices.get(reward_currency)?);
+                Some(value.saturating_add(reward_value))
+            },
+        )?;
+        rewards_value
+            .saturating_mul(FixedU128::saturating_from_integer(
+                blocks_per_year.saturated_into::<u128>(),
+            ))
+            .checked_div(&staked_value)
+    }
+
+    /// Lists the pools `who` has a share in, along with the rewards they can claim and the
+    /// shares they are waiting to unstake.
+    pub fn get_user_positions(who: &T::AccountId) -> Vec<UserPositionOf<T>> {
+        let mut pool_ids: Vec<PoolId> = PoolInfos::<T>::iter_keys().collect();
+        pool_ids.sort();
+        pool_ids
+            .into_iter()
+            .filter_map(|pool_id| {
+                let share_info = SharesAndWithdrawnRewards::<T>::get(pool_id, who)?;
+                Some(UserPosition {
+                    pid: pool_id,
+                    share: share_info.share,
+                    boost_share: Self::boost_shares(pool_id, who),
+                    pending_rewards: Self::get_farming_rewards(who, pool_id).unwrap_or_default(),
+                    withdraw_list: share_info.withdraw_list,
+                })
+            })
+            .collect()
+    }
+
     fn get_reward_amount(
         share_info: &ShareInfoOf<T>,
         total_reward: &BalanceOf<T>,
@@ -872,7 +1760,7 @@ impl<T: Config> Pallet<T> {
         add_value: BalanceOf<T>,
         gauge_info: Option<(BalanceOf<T>, BlockNumberFor<T>)>,
     ) -> DispatchResult {
-        let mut pool_info = Self::pool_infos(pool_id).ok_or(Error::<T>::PoolDoesNotExist)?;
+        let mut pool_info = Self::updated_pool_info(pool_id)?;
         ensure!(
             PoolState::state_valid(Action::Deposit, pool_info.state),
             Error::<T>::InvalidPoolState
@@ -900,6 +1788,7 @@ impl<T: Config> Pallet<T> {
             },
         )?;
         Self::add_share(&exchanger, pool_id, &mut pool_info, add_value);
+        Self::update_boost_share(&exchanger, pool_id)?;
 
         if let Some((gauge_value, gauge_block)) = gauge_info {
             Self::gauge_add(
@@ -935,7 +1824,7 @@ impl<T: Config> Pallet<T> {
         pool_id: PoolId,
         remove_value: Option<BalanceOf<T>>,
     ) -> DispatchResult {
-        let pool_info = Self::pool_infos(pool_id).ok_or(Error::<T>::PoolDoesNotExist)?;
+        let pool_info = Self::updated_pool_info(pool_id)?;
         ensure!(
             PoolState::state_valid(Action::Withdraw, pool_info.state),
             Error::<T>::InvalidPoolState
@@ -948,12 +1837,15 @@ impl<T: Config> Pallet<T> {
             Error::<T>::WithdrawLimitCountExceeded
         );
 
+        // The boost share is not staked, it is dropped before and set again after withdrawing
+        Self::set_boost_share(&exchanger, pool_id, Zero::zero())?;
         Self::remove_share(
             &exchanger,
             pool_id,
             remove_value,
             pool_info.withdraw_limit_time,
         )?;
+        Self::update_boost_share(&exchanger, pool_id)?;
 
         Self::deposit_event(Event::Withdrawn {
             who: exchanger,
@@ -0,0 +1,173 @@
+// Copyright 2020-2024 Manta Network.
+// This file is part of Manta.
+//
+// Manta is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// Manta is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Manta.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Staking straight from DEX liquidity.
+//!
+//! Pools staking only the LP token of a pair can be deposited into by adding liquidity to the
+//! pair, and withdrawn from by removing the unstaked liquidity, each in a single call.
+
+use frame_support::{dispatch::DispatchResult, pallet_prelude::*};
+use sp_runtime::traits::{Saturating, Zero};
+
+use crate::*;
+
+/// A DEX holding the liquidity of the pairs whose LP tokens are staked in farming pools.
+pub trait LiquidityProvider<AccountId, CurrencyId, Balance> {
+    /// Adds liquidity of the pair from `who`, who receives the minted LP tokens.
+    ///
+    /// Fails if less than `amount_0_min` or `amount_1_min` would be added.
+    fn add_liquidity(
+        who: &AccountId,
+        asset_0: CurrencyId,
+        asset_1: CurrencyId,
+        amount_0_desired: Balance,
+        amount_1_desired: Balance,
+        amount_0_min: Balance,
+        amount_1_min: Balance,
+    ) -> DispatchResult;
+
+    /// Burns `liquidity` LP tokens of `who` and returns them the assets of the pair.
+    ///
+    /// Fails if less than `amount_0_min` or `amount_1_min` would be returned.
+    fn remove_liquidity(
+        who: &AccountId,
+        asset_0: CurrencyId,
+        asset_1: CurrencyId,
+        liquidity: Balance,
+        amount_0_min: Balance,
+        amount_1_min: Balance,
+    ) -> DispatchResult;
+
+    /// The weight of `add_liquidity`.
+    fn add_liquidity_weight() -> Weight;
+
+    /// The weight of `remove_liquidity`.
+    fn remove_liquidity_weight() -> Weight;
+}
+
+impl<AccountId, CurrencyId, Balance> LiquidityProvider<AccountId, CurrencyId, Balance> for () {
+    fn add_liquidity(
+        _: &AccountId,
+        _: CurrencyId,
+        _: CurrencyId,
+        _: Balance,
+        _: Balance,
+        _: Balance,
+        _: Balance,
+    ) -> DispatchResult {
+        Err(DispatchError::Unavailable)
+    }
+
+    fn remove_liquidity(
+        _: &AccountId,
+        _: CurrencyId,
+        _: CurrencyId,
+        _: Balance,
+        _: Balance,
+        _: Balance,
+    ) -> DispatchResult {
+        Err(DispatchError::Unavailable)
+    }
+
+    fn add_liquidity_weight() -> Weight {
+        Weight::zero()
+    }
+
+    fn remove_liquidity_weight() -> Weight {
+        Weight::zero()
+    }
+}
+
+impl<T: Config> Pallet<T> {
+    /// Returns the LP token of the pair, which must be the only token staked by the pool.
+    fn liquidity_pool_token(
+        pool_id: PoolId,
+        asset_0: CurrencyIdOf<T>,
+        asset_1: CurrencyIdOf<T>,
+    ) -> Result<CurrencyIdOf<T>, DispatchError> {
+        let lp_token =
+            T::LpAssets::lp_asset_id(&asset_0, &asset_1).ok_or(Error::<T>::NotLpAsset)?;
+        let pool_info = Self::pool_infos(pool_id).ok_or(Error::<T>::PoolDoesNotExist)?;
+        ensure!(
+            pool_info.tokens_proportion.len() == 1
+                && pool_info.tokens_proportion.contains_key(&lp_token),
+            Error::<T>::NotLiquidityPool
+        );
+        Ok(lp_token)
+    }
+
+    /// Adds liquidity of the pair and deposits all the minted LP tokens into the pool.
+    #[allow(clippy::too_many_arguments)]
+    pub(crate) fn do_add_liquidity_and_deposit(
+        who: &T::AccountId,
+        pool_id: PoolId,
+        asset_0: CurrencyIdOf<T>,
+        asset_1: CurrencyIdOf<T>,
+        amount_0_desired: BalanceOf<T>,
+        amount_1_desired: BalanceOf<T>,
+        amount_0_min: BalanceOf<T>,
+        amount_1_min: BalanceOf<T>,
+        gauge_info: Option<(BalanceOf<T>, BlockNumberFor<T>)>,
+    ) -> Result<(CurrencyIdOf<T>, BalanceOf<T>), DispatchError> {
+        let lp_token = Self::liquidity_pool_token(pool_id, asset_0, asset_1)?;
+
+        let lp_before = T::MultiCurrency::free_balance(lp_token, who);
+        T::Liquidity::add_liquidity(
+            who,
+            asset_0,
+            asset_1,
+            amount_0_desired,
+            amount_1_desired,
+            amount_0_min,
+            amount_1_min,
+        )?;
+        let liquidity = T::MultiCurrency::free_balance(lp_token, who).saturating_sub(lp_before);
+        ensure!(!liquidity.is_zero(), Error::<T>::NoLiquidity);
+
+        Self::deposit_farming(who.clone(), pool_id, liquidity, gauge_info)?;
+        Ok((lp_token, liquidity))
+    }
+
+    /// Withdraws from the pool and removes the liquidity of all the LP tokens unstaked.
+    ///
+    /// Fails if nothing is unstaked, as with a pending `withdraw_limit_time`.
+    pub(crate) fn do_withdraw_and_remove_liquidity(
+        who: &T::AccountId,
+        pool_id: PoolId,
+        asset_0: CurrencyIdOf<T>,
+        asset_1: CurrencyIdOf<T>,
+        remove_value: Option<BalanceOf<T>>,
+        amount_0_min: BalanceOf<T>,
+        amount_1_min: BalanceOf<T>,
+    ) -> Result<(CurrencyIdOf<T>, BalanceOf<T>), DispatchError> {
+        let lp_token = Self::liquidity_pool_token(pool_id, asset_0, asset_1)?;
+
+        let lp_before = T::MultiCurrency::free_balance(lp_token, who);
+        Self::withdraw_and_unstake(who.clone(), pool_id, remove_value)?;
+        let liquidity = T::MultiCurrency::free_balance(lp_token, who).saturating_sub(lp_before);
+        ensure!(!liquidity.is_zero(), Error::<T>::NoLiquidity);
+
+        T::Liquidity::remove_liquidity(
+            who,
+            asset_0,
+            asset_1,
+            liquidity,
+            amount_0_min,
+            amount_1_min,
+        )?;
+        Ok((lp_token, liquidity))
+    }
+}
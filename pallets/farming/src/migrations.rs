@@ -0,0 +1,326 @@
+// Copyright 2020-2024 Manta Network.
+// This file is part of Manta.
+//
+// Manta is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// Manta is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Manta.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Do storage migrations of the farming pools:
+//! * lazy reward accrual, which records the block up to which the `rewards` of every farming and
+//!   gauge pool have been accrued.
+//! * reward schedules, which add the end block, the reward steps and the charged reward budget.
+
+use super::*;
+use codec::HasCompact;
+use core::marker::PhantomData;
+use frame_support::{
+    dispatch::GetStorageVersion,
+    pallet_prelude::Weight,
+    traits::{Get, OnRuntimeUpgrade, StorageVersion},
+};
+use sp_runtime::DispatchError;
+use sp_std::vec::Vec;
+
+/// Farming pool info before `last_update_block` was introduced.
+#[derive(Clone, Encode, Decode, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct OldPoolInfo<BalanceOf: HasCompact, CurrencyIdOf: Ord, AccountIdOf, BlockNumberFor> {
+    pub tokens_proportion: BTreeMap<CurrencyIdOf, Perbill>,
+    pub basic_token: (CurrencyIdOf, Perbill),
+    pub total_shares: BalanceOf,
+    pub basic_rewards: BTreeMap<CurrencyIdOf, BalanceOf>,
+    pub rewards: BTreeMap<CurrencyIdOf, (BalanceOf, BalanceOf)>,
+    pub state: PoolState,
+    pub keeper: AccountIdOf,
+    pub reward_issuer: AccountIdOf,
+    pub gauge: Option<PoolId>,
+    pub block_startup: Option<BlockNumberFor>,
+    pub min_deposit_to_start: BalanceOf,
+    pub after_block_to_start: BlockNumberFor,
+    pub withdraw_limit_time: BlockNumberFor,
+    pub claim_limit_time: BlockNumberFor,
+    pub withdraw_limit_count: u8,
+}
+
+/// Farming pool info before the reward schedule was introduced.
+#[derive(Clone, Encode, Decode, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct PoolInfoV1<BalanceOf: HasCompact, CurrencyIdOf: Ord, AccountIdOf, BlockNumberFor> {
+    pub tokens_proportion: BTreeMap<CurrencyIdOf, Perbill>,
+    pub basic_token: (CurrencyIdOf, Perbill),
+    pub total_shares: BalanceOf,
+    pub basic_rewards: BTreeMap<CurrencyIdOf, BalanceOf>,
+    pub rewards: BTreeMap<CurrencyIdOf, (BalanceOf, BalanceOf)>,
+    pub state: PoolState,
+    pub keeper: AccountIdOf,
+    pub reward_issuer: AccountIdOf,
+    pub gauge: Option<PoolId>,
+    pub block_startup: Option<BlockNumberFor>,
+    pub min_deposit_to_start: BalanceOf,
+    pub after_block_to_start: BlockNumberFor,
+    pub withdraw_limit_time: BlockNumberFor,
+    pub claim_limit_time: BlockNumberFor,
+    pub withdraw_limit_count: u8,
+    pub last_update_block: BlockNumberFor,
+}
+
+/// Gauge pool info before `last_update_block` was introduced.
+#[derive(Clone, Encode, Decode, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct OldGaugePoolInfo<BalanceOf: HasCompact, CurrencyIdOf: Ord, AccountIdOf, BlockNumberFor> {
+    pub pool_id: PoolId,
+    pub token: CurrencyIdOf,
+    pub keeper: AccountIdOf,
+    pub reward_issuer: AccountIdOf,
+    pub rewards: BTreeMap<CurrencyIdOf, (BalanceOf, BalanceOf, BalanceOf)>,
+    pub gauge_basic_rewards: BTreeMap<CurrencyIdOf, BalanceOf>,
+    pub max_block: BlockNumberFor,
+    pub gauge_amount: BalanceOf,
+    pub total_time_factor: u128,
+    pub gauge_state: GaugeState,
+    pub gauge_last_block: BlockNumberFor,
+}
+
+#[allow(type_alias_bounds)]
+type OldPoolInfoOf<T: Config> =
+    OldPoolInfo<BalanceOf<T>, CurrencyIdOf<T>, AccountIdOf<T>, BlockNumberFor<T>>;
+
+#[allow(type_alias_bounds)]
+type PoolInfoV1Of<T: Config> =
+    PoolInfoV1<BalanceOf<T>, CurrencyIdOf<T>, AccountIdOf<T>, BlockNumberFor<T>>;
+
+#[allow(type_alias_bounds)]
+type OldGaugePoolInfoOf<T: Config> =
+    OldGaugePoolInfo<BalanceOf<T>, CurrencyIdOf<T>, AccountIdOf<T>, BlockNumberFor<T>>;
+
+/// Storage migration adding `last_update_block` to `PoolInfos` and `GaugePoolInfos`.
+///
+/// Before this migration `rewards` were topped up in `on_initialize`, so the stored totals
+/// already include every block up to the previous one. Accrual therefore resumes from there.
+/// The farming pools are brought to the layout of [`RewardScheduleMigration`] at the same time.
+pub struct LazyRewardAccrualMigration<T>(PhantomData<T>);
+
+impl<T> OnRuntimeUpgrade for LazyRewardAccrualMigration<T>
+where
+    T: Config,
+{
+    fn on_runtime_upgrade() -> Weight {
+        let storage_version = Pallet::<T>::on_chain_storage_version();
+        if storage_version < 1 {
+            log::info!(target: "farming", "Start to execute storage migration for farming.");
+            let mut reads: u64 = 1;
+            let mut writes: u64 = 0;
+            let last_update_block =
+                frame_system::Pallet::<T>::block_number().saturating_sub(One::one());
+
+            PoolInfos::<T>::translate::<OldPoolInfoOf<T>, _>(|_pool_id, old| {
+                reads += 1;
+                writes += 1;
+                Some(upgrade_pool_info::<T>(PoolInfoV1 {
+                    tokens_proportion: old.tokens_proportion,
+                    basic_token: old.basic_token,
+                    total_shares: old.total_shares,
+                    basic_rewards: old.basic_rewards,
+                    rewards: old.rewards,
+                    state: old.state,
+                    keeper: old.keeper,
+                    reward_issuer: old.reward_issuer,
+                    gauge: old.gauge,
+                    block_startup: old.block_startup,
+                    min_deposit_to_start: old.min_deposit_to_start,
+                    after_block_to_start: old.after_block_to_start,
+                    withdraw_limit_time: old.withdraw_limit_time,
+                    claim_limit_time: old.claim_limit_time,
+                    withdraw_limit_count: old.withdraw_limit_count,
+                    last_update_block,
+                }))
+            });
+
+            GaugePoolInfos::<T>::translate::<OldGaugePoolInfoOf<T>, _>(|_gid, old| {
+                reads += 1;
+                writes += 1;
+                Some(GaugePoolInfo {
+                    pool_id: old.pool_id,
+                    token: old.token,
+                    keeper: old.keeper,
+                    reward_issuer: old.reward_issuer,
+                    rewards: old.rewards,
+                    gauge_basic_rewards: old.gauge_basic_rewards,
+                    max_block: old.max_block,
+                    gauge_amount: old.gauge_amount,
+                    total_time_factor: old.total_time_factor,
+                    gauge_state: old.gauge_state,
+                    gauge_last_block: old.gauge_last_block,
+                    last_update_block,
+                })
+            });
+
+            // The farming pools are stored in the latest layout already.
+            StorageVersion::new(2u16).put::<Pallet<T>>();
+            writes += 1;
+            T::DbWeight::get()
+                .reads(reads)
+                .saturating_add(T::DbWeight::get().writes(writes))
+        } else {
+            log::info!("✅ no migration for farming.");
+            // only 1 read
+            T::DbWeight::get().reads(1)
+        }
+    }
+
+    #[cfg(feature = "try-runtime")]
+    fn pre_upgrade() -> Result<Vec<u8>, DispatchError> {
+        let storage_version = Pallet::<T>::on_chain_storage_version();
+        if storage_version >= 1 {
+            return Err(DispatchError::Other(
+                "Storage version is >= 1, the migration won't be executed.",
+            ));
+        }
+        let pools = (PoolInfos::<T>::iter_keys().count() as u32)
+            .saturating_add(GaugePoolInfos::<T>::iter_keys().count() as u32);
+        Ok(pools.encode())
+    }
+
+    #[cfg(feature = "try-runtime")]
+    fn post_upgrade(state: Vec<u8>) -> Result<(), DispatchError> {
+        let storage_version = Pallet::<T>::on_chain_storage_version();
+        if storage_version < 1 {
+            return Err(DispatchError::Other(
+                "Storage version is < 1, the migration wasn't executed.",
+            ));
+        }
+        let pools_before = u32::decode(&mut &state[..])
+            .map_err(|_| DispatchError::Other("Failed to decode the pre-upgrade state."))?;
+        let pools_after = (PoolInfos::<T>::iter().count() as u32)
+            .saturating_add(GaugePoolInfos::<T>::iter().count() as u32);
+        if pools_before == pools_after {
+            log::info!("✅ Storage migration for farming has been executed successfully.");
+            Ok(())
+        } else {
+            Err(DispatchError::Other(
+                "Failed to executed storage migration for farming.",
+            ))
+        }
+    }
+}
+
+/// Adds the reward schedule to a farming pool in the layout of storage version 1.
+///
+/// The pool gets neither an end block nor reward steps. Every reward currency gets an explicit
+/// `reward_budget` entry, which is what the reward issuer holds beyond the accrued but unclaimed
+/// rewards of the pool, or zero if it holds less. From then on the budget only grows by `charge`,
+/// funds sent to the reward issuer otherwise are not part of it.
+fn upgrade_pool_info<T: Config>(old: PoolInfoV1Of<T>) -> PoolInfoOf<T> {
+    let reward_budget = old
+        .basic_rewards
+        .keys()
+        .map(|reward_currency| {
+            let unclaimed = old
+                .rewards
+                .get(reward_currency)
+                .map(|(total_reward, total_withdrawn_reward)| {
+                    total_reward.saturating_sub(*total_withdrawn_reward)
+                })
+                .unwrap_or_else(Zero::zero);
+            let budget = T::MultiCurrency::free_balance(*reward_currency, &old.reward_issuer)
+                .saturating_sub(unclaimed);
+            (*reward_currency, budget)
+        })
+        .collect();
+
+    PoolInfo {
+        tokens_proportion: old.tokens_proportion,
+        basic_token: old.basic_token,
+        total_shares: old.total_shares,
+        basic_rewards: old.basic_rewards,
+        rewards: old.rewards,
+        state: old.state,
+        keeper: old.keeper,
+        reward_issuer: old.reward_issuer,
+        gauge: old.gauge,
+        block_startup: old.block_startup,
+        min_deposit_to_start: old.min_deposit_to_start,
+        after_block_to_start: old.after_block_to_start,
+        withdraw_limit_time: old.withdraw_limit_time,
+        claim_limit_time: old.claim_limit_time,
+        withdraw_limit_count: old.withdraw_limit_count,
+        last_update_block: old.last_update_block,
+        end_block: None,
+        reward_steps: BTreeMap::new(),
+        reward_budget,
+    }
+}
+
+/// Storage migration adding `end_block`, `reward_steps` and `reward_budget` to `PoolInfos`.
+pub struct RewardScheduleMigration<T>(PhantomData<T>);
+
+impl<T> OnRuntimeUpgrade for RewardScheduleMigration<T>
+where
+    T: Config,
+{
+    fn on_runtime_upgrade() -> Weight {
+        let storage_version = Pallet::<T>::on_chain_storage_version();
+        if storage_version == 1 {
+            log::info!(target: "farming", "Start to execute storage migration for farming.");
+            let mut reads: u64 = 1;
+            let mut writes: u64 = 0;
+
+            PoolInfos::<T>::translate::<PoolInfoV1Of<T>, _>(|_pool_id, old| {
+                reads += 1 + old.basic_rewards.len() as u64;
+                writes += 1;
+                Some(upgrade_pool_info::<T>(old))
+            });
+
+            // Update storage version.
+            StorageVersion::new(2u16).put::<Pallet<T>>();
+            writes += 1;
+            T::DbWeight::get()
+                .reads(reads)
+                .saturating_add(T::DbWeight::get().writes(writes))
+        } else {
+            log::info!("✅ no migration for farming.");
+            // only 1 read
+            T::DbWeight::get().reads(1)
+        }
+    }
+
+    #[cfg(feature = "try-runtime")]
+    fn pre_upgrade() -> Result<Vec<u8>, DispatchError> {
+        let pools = PoolInfos::<T>::iter_keys().count() as u32;
+        Ok(pools.encode())
+    }
+
+    #[cfg(feature = "try-runtime")]
+    fn post_upgrade(state: Vec<u8>) -> Result<(), DispatchError> {
+        let storage_version = Pallet::<T>::on_chain_storage_version();
+        if storage_version < 2 {
+            return Err(DispatchError::Other(
+                "Storage version is < 2, the migration wasn't executed.",
+            ));
+        }
+        let pools_before = u32::decode(&mut &state[..])
+            .map_err(|_| DispatchError::Other("Failed to decode the pre-upgrade state."))?;
+        let pools_after = PoolInfos::<T>::iter().count() as u32;
+        if PoolInfos::<T>::iter_values()
+            .any(|pool_info| Pallet::<T>::ensure_reward_budget(&pool_info).is_err())
+        {
+            return Err(DispatchError::Other(
+                "A farming pool has a reward currency without a reward budget.",
+            ));
+        }
+        if pools_before == pools_after {
+            log::info!("✅ Storage migration for farming has been executed successfully.");
+            Ok(())
+        } else {
+            Err(DispatchError::Other(
+                "Failed to executed storage migration for farming.",
+            ))
+        }
+    }
+}
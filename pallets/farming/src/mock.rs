@@ -21,25 +21,27 @@
 
 use frame_support::{
     dispatch::DispatchResult,
-    ord_parameter_types, parameter_types,
-    traits::{AsEnsureOriginWithArg, EitherOfDiverse, GenesisBuild},
+    ensure, ord_parameter_types, parameter_types,
+    traits::{fungibles, AsEnsureOriginWithArg, ConstU64, EitherOfDiverse, GenesisBuild, Get},
+    weights::Weight,
     PalletId,
 };
 use frame_system::{EnsureNever, EnsureRoot, EnsureSignedBy};
 use manta_primitives::{
     assets::{
-        AssetConfig, AssetIdType, AssetLocation, AssetRegistry, AssetRegistryMetadata,
-        AssetStorageMetadata, BalanceType, LocationType, NativeAndNonNative,
+        AssetConfig, AssetIdLpMap, AssetIdType, AssetLocation, AssetRegistry,
+        AssetRegistryMetadata, AssetStorageMetadata, BalanceType, LocationType, NativeAndNonNative,
     },
     constants::ASSET_MANAGER_PALLET_ID,
     currencies::Currencies,
     types::CalamariAssetId,
 };
+use orml_traits::MultiCurrency;
 use sp_core::{ConstU128, ConstU32, H256};
 use sp_runtime::{
     testing::Header,
     traits::{BlakeTwo256, IdentityLookup},
-    AccountId32,
+    AccountId32, DispatchError, Perbill,
 };
 use xcm::{
     prelude::{Parachain, X1},
@@ -219,6 +221,14 @@ impl AssetRegistry for MantaAssetRegistry {
             metadata.is_frozen,
         )
     }
+
+    fn destroy_asset(asset_id: &CalamariAssetId) -> DispatchResult {
+        let limit = <Runtime as pallet_assets::Config>::RemoveItemsLimit::get();
+        <Assets as fungibles::Destroy<AccountId>>::start_destroy(*asset_id, None)?;
+        <Assets as fungibles::Destroy<AccountId>>::destroy_accounts(*asset_id, limit)?;
+        <Assets as fungibles::Destroy<AccountId>>::destroy_approvals(*asset_id, limit)?;
+        <Assets as fungibles::Destroy<AccountId>>::finish_destroy(*asset_id)
+    }
 }
 
 parameter_types! {
@@ -261,6 +271,10 @@ impl AssetConfig<Runtime> for MantaAssetConfig {
     type FungibleLedger = NativeAndNonNative<Runtime, MantaAssetConfig, Balances, Assets>;
 }
 
+parameter_types! {
+    pub const ProposedBounds: (u128, u128) = (1, u128::MAX);
+}
+
 impl pallet_asset_manager::Config for Runtime {
     type RuntimeEvent = RuntimeEvent;
     type AssetId = CalamariAssetId;
@@ -274,12 +288,24 @@ impl pallet_asset_manager::Config for Runtime {
     type TokenNameMaxLen = ConstU32<100>;
     type TokenSymbolMaxLen = ConstU32<100>;
     type PermissionlessAssetRegistryCost = ConstU128<1000>;
+    type SiblingAssetOrigin = EnsureNever<(u32, AccountId32)>;
+    type VetoOrigin = EnsureRoot<AccountId32>;
+    type AssetChallengePeriod = ConstU64<10>;
+    type MaxActivationsPerBlock = ConstU32<10>;
+    type AssetProposalDeposit = ConstU128<1_000>;
+    type ProposedMinBalanceBounds = ProposedBounds;
+    type ProposedUnitsPerSecondBounds = ProposedBounds;
+    type NativePrice = ();
+    type NativeUnitsPerSecond = ConstU128<1_000_000_000>;
+    type SpotPriceAveragingPeriod = ConstU64<10>;
+    type MaxDynamicPricedAssets = ConstU32<10>;
 }
 
 parameter_types! {
     pub const FarmingKeeperPalletId: PalletId = PalletId(*b"bf/fmkpr");
     pub const FarmingRewardIssuerPalletId: PalletId = PalletId(*b"bf/fmrir");
     pub const TreasuryAccount: AccountId32 = TREASURY_ACCOUNT;
+    pub const FarmingMaxBoost: Perbill = Perbill::from_percent(100);
 }
 
 ord_parameter_types! {
@@ -288,6 +314,58 @@ ord_parameter_types! {
 
 type MantaCurrencies = Currencies<Runtime, MantaAssetConfig, Balances, Assets>;
 
+/// A DEX whose pairs trade 1:1, minting one LP token for each unit of both assets added.
+pub struct MockLiquidity;
+impl pallet_farming::LiquidityProvider<AccountId, CalamariAssetId, Balance> for MockLiquidity {
+    fn add_liquidity(
+        who: &AccountId,
+        asset_0: CalamariAssetId,
+        asset_1: CalamariAssetId,
+        amount_0_desired: Balance,
+        amount_1_desired: Balance,
+        amount_0_min: Balance,
+        amount_1_min: Balance,
+    ) -> DispatchResult {
+        let lp_token = AssetManager::lp_asset_id(&asset_0, &asset_1)
+            .ok_or(DispatchError::Other("pair not exists"))?;
+        let amount = amount_0_desired.min(amount_1_desired);
+        ensure!(
+            amount >= amount_0_min && amount >= amount_1_min,
+            DispatchError::Other("insufficient amount")
+        );
+        MantaCurrencies::withdraw(asset_0, who, amount)?;
+        MantaCurrencies::withdraw(asset_1, who, amount)?;
+        MantaCurrencies::deposit(lp_token, who, amount)
+    }
+
+    fn remove_liquidity(
+        who: &AccountId,
+        asset_0: CalamariAssetId,
+        asset_1: CalamariAssetId,
+        liquidity: Balance,
+        amount_0_min: Balance,
+        amount_1_min: Balance,
+    ) -> DispatchResult {
+        let lp_token = AssetManager::lp_asset_id(&asset_0, &asset_1)
+            .ok_or(DispatchError::Other("pair not exists"))?;
+        ensure!(
+            liquidity >= amount_0_min && liquidity >= amount_1_min,
+            DispatchError::Other("insufficient amount")
+        );
+        MantaCurrencies::withdraw(lp_token, who, liquidity)?;
+        MantaCurrencies::deposit(asset_0, who, liquidity)?;
+        MantaCurrencies::deposit(asset_1, who, liquidity)
+    }
+
+    fn add_liquidity_weight() -> Weight {
+        Weight::zero()
+    }
+
+    fn remove_liquidity_weight() -> Weight {
+        Weight::zero()
+    }
+}
+
 impl pallet_farming::Config for Runtime {
     type RuntimeEvent = RuntimeEvent;
     type CurrencyId = CalamariAssetId;
@@ -296,6 +374,18 @@ impl pallet_farming::Config for Runtime {
     type TreasuryAccount = TreasuryAccount;
     type Keeper = FarmingKeeperPalletId;
     type RewardIssuer = FarmingRewardIssuerPalletId;
+    type Currency = Balances;
+    type PoolCreationDeposit = ConstU128<1000>;
+    type LpAssets = AssetManager;
+    type MaxBasicRewards = ConstU32<4>;
+    type MaxRewardSteps = ConstU32<4>;
+    type MaxWithdrawLimitTime = ConstU64<100>;
+    type MaxClaimLimitTime = ConstU64<100>;
+    type VeEpochLength = ConstU64<10>;
+    type MaxLockEpochs = ConstU32<10>;
+    type MaxBoost = FarmingMaxBoost;
+    type MaxVotes = ConstU32<4>;
+    type Liquidity = MockLiquidity;
     type WeightInfo = ();
 }
 
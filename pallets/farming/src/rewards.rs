@@ -17,6 +17,8 @@
 use crate::*;
 use codec::HasCompact;
 use scale_info::TypeInfo;
+#[cfg(feature = "std")]
+use serde::{Deserialize, Serialize};
 use sp_runtime::{
     traits::{Saturating, Zero},
     RuntimeDebug,
@@ -51,7 +53,7 @@ where
 
 /// The Reward Pool Info.
 #[derive(Clone, Encode, Decode, PartialEq, Eq, RuntimeDebug, TypeInfo)]
-pub struct PoolInfo<BalanceOf: HasCompact, CurrencyIdOf: Ord, AccountIdOf, BlockNumberFor> {
+pub struct PoolInfo<BalanceOf: HasCompact, CurrencyIdOf: Ord, AccountIdOf, BlockNumberFor: Ord> {
     pub tokens_proportion: BTreeMap<CurrencyIdOf, Perbill>,
     pub basic_token: (CurrencyIdOf, Perbill),
     /// Total shares amount
@@ -75,6 +77,14 @@ pub struct PoolInfo<BalanceOf: HasCompact, CurrencyIdOf: Ord, AccountIdOf, Block
     pub claim_limit_time: BlockNumberFor,
     /// The withdraw limit length
     pub withdraw_limit_count: u8,
+    /// The block number up to which `rewards` have been accrued
+    pub last_update_block: BlockNumberFor,
+    /// The last block accruing rewards, the pool is closed after it
+    pub end_block: Option<BlockNumberFor>,
+    /// Scheduled changes of `basic_rewards`, keyed by the first block accruing the new rewards
+    pub reward_steps: BTreeMap<BlockNumberFor, BTreeMap<CurrencyIdOf, BalanceOf>>,
+    /// Charged rewards which have not been accrued yet, the pool is closed once they run out
+    pub reward_budget: BTreeMap<CurrencyIdOf, BalanceOf>,
 }
 
 impl<BalanceOf, CurrencyIdOf, AccountIdOf, BlockNumberFor>
@@ -82,6 +92,7 @@ impl<BalanceOf, CurrencyIdOf, AccountIdOf, BlockNumberFor>
 where
     BalanceOf: Default + HasCompact,
     CurrencyIdOf: Ord,
+    BlockNumberFor: Ord,
 {
     #[allow(clippy::too_many_arguments)]
     pub fn new(
@@ -96,6 +107,7 @@ where
         withdraw_limit_time: BlockNumberFor,
         claim_limit_time: BlockNumberFor,
         withdraw_limit_count: u8,
+        current_block_number: BlockNumberFor,
     ) -> Self {
         Self {
             tokens_proportion,
@@ -113,10 +125,47 @@ where
             withdraw_limit_time,
             claim_limit_time,
             withdraw_limit_count,
+            last_update_block: current_block_number,
+            end_block: None,
+            reward_steps: BTreeMap::new(),
+            reward_budget: BTreeMap::new(),
         }
     }
 }
 
+/// A farming pool as listed by the farming RPC.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Clone, Encode, Decode, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct PoolSummary<BalanceOf, CurrencyIdOf, BlockNumberFor> {
+    pub pid: PoolId,
+    pub state: PoolState,
+    pub tokens_proportion: Vec<(CurrencyIdOf, Perbill)>,
+    /// Total shares amount
+    pub total_shares: BalanceOf,
+    /// Rewards accrued per block
+    pub basic_rewards: Vec<(CurrencyIdOf, BalanceOf)>,
+    /// Gauge pool id
+    pub gauge: Option<PoolId>,
+    pub block_startup: Option<BlockNumberFor>,
+    pub end_block: Option<BlockNumberFor>,
+}
+
+/// The share of an account in a farming pool as listed by the farming RPC.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Clone, Encode, Decode, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct UserPosition<BalanceOf, CurrencyIdOf, BlockNumberFor> {
+    pub pid: PoolId,
+    /// The share including `boost_share`
+    pub share: BalanceOf,
+    /// The share given by voting power
+    pub boost_share: BalanceOf,
+    /// Rewards which can be claimed
+    pub pending_rewards: Vec<(CurrencyIdOf, BalanceOf)>,
+    /// Withdrawn shares along with the block they can be unstaked at
+    pub withdraw_list: Vec<(BlockNumberFor, BalanceOf)>,
+}
+
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 #[derive(Encode, Decode, Copy, Clone, Eq, PartialEq, RuntimeDebug, TypeInfo)]
 pub enum PoolState {
     UnCharged,
@@ -136,6 +185,7 @@ pub enum Action {
     ResetPool,
     KillPool,
     EditPool,
+    ReclaimRewards,
 }
 
 impl PoolState {
@@ -158,6 +208,7 @@ impl PoolState {
                     || state == PoolState::Charged
                     || state == PoolState::UnCharged
             }
+            Action::ReclaimRewards => state == PoolState::Dead || state == PoolState::Retired,
         }
     }
 }
@@ -245,47 +296,10 @@ impl<T: Config> Pallet<T> {
                     return Ok(());
                 }
 
-                PoolInfos::<T>::mutate(pool, |maybe_pool_info| -> DispatchResult {
-                    let pool_info = maybe_pool_info
-                        .as_mut()
-                        .ok_or(Error::<T>::PoolDoesNotExist)?;
-                    pool_info.total_shares = pool_info.total_shares.saturating_sub(remove_amount);
-
-                    // update withdrawn rewards for each reward currency
-                    share_info.withdrawn_rewards.iter_mut().try_for_each(
-                        |(reward_currency, withdrawn_reward)| -> DispatchResult {
-                            let withdrawn_amount = Self::get_reward_inflation(
-                                remove_amount,
-                                withdrawn_reward,
-                                share_info.share,
-                            );
-                            if withdrawn_amount.is_zero() {
-                                return Ok(());
-                            }
-
-                            if let Some((total_reward, total_withdrawn_reward)) =
-                                pool_info.rewards.get_mut(reward_currency)
-                            {
-                                *total_reward = total_reward.saturating_sub(withdrawn_amount);
-                                *total_withdrawn_reward =
-                                    total_withdrawn_reward.saturating_sub(withdrawn_amount);
-
-                                // remove if all reward is withdrawn
-                                if total_reward.is_zero() {
-                                    pool_info.rewards.remove(reward_currency);
-                                }
-                            }
-                            *withdrawn_reward = withdrawn_reward.saturating_sub(withdrawn_amount);
-                            Ok(())
-                        },
-                    )?;
-                    Ok(())
-                })?;
-
+                Self::reduce_share(pool, &mut share_info, remove_amount)?;
                 share_info
                     .withdraw_list
                     .push((n + withdraw_limit_time, remove_amount));
-                share_info.share = share_info.share.saturating_sub(remove_amount);
                 *share_info_old = Some(share_info);
             }
             Ok(())
@@ -293,6 +307,69 @@ impl<T: Config> Pallet<T> {
         Ok(())
     }
 
+    /// Removes `remove_amount` of the share of `who` without unstaking anything, after claiming
+    /// the rewards of the removed share.
+    pub(crate) fn remove_boost_share(
+        who: &T::AccountId,
+        pool: PoolId,
+        remove_amount: BalanceOf<T>,
+    ) -> DispatchResult {
+        Self::claim_rewards(who, pool)?;
+        SharesAndWithdrawnRewards::<T>::try_mutate(pool, who, |maybe_share_info| {
+            let share_info = maybe_share_info
+                .as_mut()
+                .ok_or(Error::<T>::ShareInfoNotExists)?;
+            Self::reduce_share(pool, share_info, remove_amount)
+        })
+    }
+
+    /// Decreases the share along with the pool total shares, deducting the withdrawn rewards of
+    /// the removed share from the pool rewards.
+    fn reduce_share(
+        pool: PoolId,
+        share_info: &mut ShareInfoOf<T>,
+        remove_amount: BalanceOf<T>,
+    ) -> DispatchResult {
+        PoolInfos::<T>::mutate(pool, |maybe_pool_info| -> DispatchResult {
+            let pool_info = maybe_pool_info
+                .as_mut()
+                .ok_or(Error::<T>::PoolDoesNotExist)?;
+            pool_info.total_shares = pool_info.total_shares.saturating_sub(remove_amount);
+
+            // update withdrawn rewards for each reward currency
+            share_info.withdrawn_rewards.iter_mut().try_for_each(
+                |(reward_currency, withdrawn_reward)| -> DispatchResult {
+                    let withdrawn_amount = Self::get_reward_inflation(
+                        remove_amount,
+                        withdrawn_reward,
+                        share_info.share,
+                    );
+                    if withdrawn_amount.is_zero() {
+                        return Ok(());
+                    }
+
+                    if let Some((total_reward, total_withdrawn_reward)) =
+                        pool_info.rewards.get_mut(reward_currency)
+                    {
+                        *total_reward = total_reward.saturating_sub(withdrawn_amount);
+                        *total_withdrawn_reward =
+                            total_withdrawn_reward.saturating_sub(withdrawn_amount);
+
+                        // remove if all reward is withdrawn
+                        if total_reward.is_zero() {
+                            pool_info.rewards.remove(reward_currency);
+                        }
+                    }
+                    *withdrawn_reward = withdrawn_reward.saturating_sub(withdrawn_amount);
+                    Ok(())
+                },
+            )?;
+            Ok(())
+        })?;
+        share_info.share = share_info.share.saturating_sub(remove_amount);
+        Ok(())
+    }
+
     pub fn claim_rewards(who: &T::AccountId, pool: PoolId) -> DispatchResult {
         SharesAndWithdrawnRewards::<T>::mutate_exists(
             pool,
@@ -308,6 +385,7 @@ impl<T: Config> Pallet<T> {
                         let pool_info = maybe_pool_info
                             .as_mut()
                             .ok_or(Error::<T>::PoolDoesNotExist)?;
+                        Self::accrue_pool_rewards(pool_info, n)?;
 
                         let total_shares = pool_info.total_shares;
                         pool_info.rewards.iter_mut().try_for_each(
@@ -16,11 +16,55 @@
 
 #![cfg(test)]
 
-use frame_support::{assert_err, assert_noop, assert_ok};
-use manta_primitives::types::CalamariAssetId;
+use frame_support::{
+    assert_err, assert_noop, assert_ok,
+    storage::unhashed,
+    traits::{GetStorageVersion, OnRuntimeUpgrade, StorageVersion},
+};
+use manta_primitives::{
+    assets::{AssetLocation, AssetRegistryMetadata, TestingDefault},
+    types::CalamariAssetId,
+};
 use sp_runtime::traits::AccountIdConversion;
+use xcm::{
+    prelude::{Parachain, X1},
+    v3::MultiLocation,
+    VersionedMultiLocation,
+};
+
+use crate::{
+    migrations::{PoolInfoV1, RewardScheduleMigration},
+    mock::*,
+    *,
+};
+
+/// Moves to the next block, which accrues one more block of rewards in every ongoing pool.
+fn next_block() {
+    System::set_block_number(System::block_number() + 1);
+}
 
-use crate::{mock::*, *};
+/// Registers the LP token of KMA and a sibling asset, returning its asset id.
+fn register_lp_token() -> CalamariAssetId {
+    let location = AssetLocation(VersionedMultiLocation::V3(MultiLocation::new(
+        1,
+        X1(Parachain(2000)),
+    )));
+    let metadata = AssetRegistryMetadata::<Balance>::testing_default();
+    let asset_id = AssetManager::next_asset_id();
+    assert_ok!(AssetManager::register_asset(
+        RuntimeOrigin::root(),
+        location,
+        metadata.clone()
+    ));
+    let lp_token = AssetManager::next_asset_id();
+    assert_ok!(AssetManager::register_lp_asset(
+        RuntimeOrigin::root(),
+        KMA,
+        asset_id,
+        metadata
+    ));
+    lp_token
+}
 
 fn init_gauge_900() -> (PoolId, BalanceOf<Runtime>) {
     let tokens_proportion = vec![(KSM, Perbill::from_percent(100))];
@@ -268,8 +312,8 @@ fn precondition_check_should_work() {
             );
 
             // Pool state is Ongoing
-            Farming::on_initialize(System::block_number() + 10);
-            let pool1: PoolInfoOf<Runtime> = Farming::pool_infos(pool_id0).unwrap();
+            next_block();
+            let pool1: PoolInfoOf<Runtime> = Farming::updated_pool_info(pool_id0).unwrap();
             assert_eq!(pool1.state, PoolState::Ongoing);
 
             assert_noop!(
@@ -424,12 +468,13 @@ fn no_gauge_farming_pool_should_work() {
                 Error::<Runtime>::InvalidPoolState
             );
 
-            // OnInitialize hook change the pool state and also pool rewards
-            Farming::on_initialize(System::block_number() + 3);
-            Farming::on_initialize(0);
-            pool1 = Farming::pool_infos(pool_id).unwrap();
+            // The pool starts on the next block and accrues rewards for every block after it
+            next_block();
+            next_block();
+            pool1 = Farming::updated_pool_info(pool_id).unwrap();
             assert_eq!(pool1.total_shares, deposit_amount);
             assert_eq!(pool1.state, PoolState::Ongoing);
+            assert_eq!(pool1.block_startup, Some(4));
             assert_eq!(pool1.claim_limit_time, 6);
             assert_eq!(pool1.rewards.get(&KSM).unwrap(), &(reward_amount, 0));
 
@@ -452,107 +497,83 @@ fn no_gauge_farming_pool_should_work() {
                 Error::<Runtime>::CanNotClaim
             );
 
-            // Claim success, user get reward.
+            // Claim success, user get the rewards of 7 blocks since startup.
             System::set_block_number(System::block_number() + 6);
             assert_ok!(Farming::claim(RuntimeOrigin::signed(ALICE), pool_id));
             assert_eq!(
                 Assets::balance(KSM, &ALICE),
-                alice_init_balance - deposit_amount + reward_amount
+                alice_init_balance - deposit_amount + reward_amount * 7
             );
             assert_eq!(
                 Assets::balance(KSM, &pool1.reward_issuer),
-                total_rewards - reward_amount
+                total_rewards - reward_amount * 7
             );
 
             // Claim operation update pool info's rewards and also share info's withdrawn_rewards
             pool1 = Farming::pool_infos(pool_id).unwrap();
             assert_eq!(
                 pool1.rewards.get(&KSM).unwrap(),
-                &(reward_amount, reward_amount)
+                &(reward_amount * 7, reward_amount * 7)
             );
+            assert_eq!(pool1.last_update_block, 11);
             reward = SharesAndWithdrawnRewards::<Runtime>::get(pool_id, &ALICE).unwrap();
-            assert_eq!(reward.withdrawn_rewards.get(&KSM).unwrap(), &reward_amount);
+            assert_eq!(
+                reward.withdrawn_rewards.get(&KSM).unwrap(),
+                &(reward_amount * 7)
+            );
             // The withdraw list of user share info is still empty.
             assert!(reward.withdraw_list.is_empty());
 
-            // Claim without new block.
-            for i in 0..5 {
-                System::set_block_number(System::block_number() + 100);
-                assert_ok!(Farming::claim(RuntimeOrigin::signed(ALICE), pool_id));
-
-                reward = SharesAndWithdrawnRewards::<Runtime>::get(pool_id, &ALICE).unwrap();
-                assert_eq!(reward.withdrawn_rewards.get(&KSM).unwrap(), &reward_amount);
-                assert_eq!(reward.claim_last_block, 109 + i * 100);
-                assert_eq!(reward.share, deposit_amount);
-                assert!(reward.withdraw_list.is_empty());
-
-                pool1 = Farming::pool_infos(pool_id).unwrap();
-                assert_eq!(
-                    pool1.rewards.get(&KSM).unwrap(),
-                    &(reward_amount, reward_amount)
-                );
-                assert_eq!(pool1.total_shares, deposit_amount);
-
-                assert_eq!(
-                    Assets::balance(KSM, &ALICE),
-                    alice_init_balance - deposit_amount + reward_amount
-                );
-                assert_eq!(
-                    Assets::balance(KSM, &pool1.reward_issuer),
-                    total_rewards - reward_amount
-                );
-                assert_eq!(Assets::balance(KSM, &pool1.keeper), deposit_amount);
-            }
-            assert_eq!(
-                Assets::balance(KSM, &pool1.reward_issuer),
-                total_rewards - reward_amount
+            // Claim again in the same block, no new rewards.
+            assert_err!(
+                Farming::claim(RuntimeOrigin::signed(ALICE), pool_id),
+                Error::<Runtime>::CanNotClaim
             );
-            assert_eq!(Assets::balance(KSM, &pool1.keeper), deposit_amount);
-            // Claim with new block
+
+            // Claim with new blocks
             for i in 1..5 {
                 System::set_block_number(System::block_number() + 6);
-                Farming::on_initialize(System::block_number() + 3);
                 assert_ok!(Farming::claim(RuntimeOrigin::signed(ALICE), pool_id));
 
                 reward = SharesAndWithdrawnRewards::<Runtime>::get(pool_id, &ALICE).unwrap();
                 assert_eq!(
                     reward.withdrawn_rewards.get(&KSM).unwrap(),
-                    &(reward_amount * (i + 1))
+                    &(reward_amount * (7 + i * 6))
                 );
-                assert_eq!(reward.claim_last_block as u128, 509 + i * 6);
+                assert_eq!(reward.claim_last_block as u128, 11 + i * 6);
                 assert_eq!(reward.share, deposit_amount);
                 assert!(reward.withdraw_list.is_empty());
 
                 pool1 = Farming::pool_infos(pool_id).unwrap();
                 assert_eq!(
                     pool1.rewards.get(&KSM).unwrap(),
-                    &(reward_amount * (i + 1), reward_amount * (i + 1))
+                    &(reward_amount * (7 + i * 6), reward_amount * (7 + i * 6))
                 );
                 assert_eq!(pool1.total_shares, deposit_amount);
 
                 assert_eq!(
                     Assets::balance(KSM, &ALICE),
-                    alice_init_balance - deposit_amount + reward_amount * (i + 1)
+                    alice_init_balance - deposit_amount + reward_amount * (7 + i * 6)
                 );
                 assert_eq!(
                     Assets::balance(KSM, &pool1.reward_issuer),
-                    total_rewards - reward_amount * (i + 1)
+                    total_rewards - reward_amount * (7 + i * 6)
                 );
                 // Because withdraw_list of user share is empty, keeper not return token to user.
                 assert_eq!(Assets::balance(KSM, &pool1.keeper), deposit_amount);
             }
             assert_eq!(
                 Assets::balance(KSM, &ALICE),
-                alice_init_balance - deposit_amount + reward_amount * 5
+                alice_init_balance - deposit_amount + reward_amount * 31
             );
             assert_eq!(
                 Assets::balance(KSM, &pool1.reward_issuer),
-                total_rewards - reward_amount * 5
+                total_rewards - reward_amount * 31
             );
             assert_eq!(Assets::balance(KSM, &pool1.keeper), deposit_amount);
             assert_eq!(
                 pool1.rewards.get(&KSM).unwrap(),
-                &(reward_amount * 5, reward_amount * 5)
+                &(reward_amount * 31, reward_amount * 31)
             );
 
             // Withdraw failed because of share info not exist.
@@ -567,11 +588,11 @@ fn no_gauge_farming_pool_should_work() {
             assert!(reward.withdraw_list.is_empty());
 
             let share_reward = reward.withdrawn_rewards.get(&KSM).unwrap();
-            assert_eq!(share_reward, &(reward_amount * 5));
+            assert_eq!(share_reward, &(reward_amount * 31));
             let (total_reward, total_withdrawn_reward) = pool1.rewards.get(&KSM).unwrap();
             assert_eq!(
                 pool1.rewards.get(&KSM).unwrap(),
-                &(reward_amount * 5, reward_amount * 5)
+                &(reward_amount * 31, reward_amount * 31)
             );
 
             let reward_amount1 = Farming::get_reward_amount(
@@ -582,10 +603,10 @@ fn no_gauge_farming_pool_should_work() {
                 &KSM,
             )
             .unwrap();
-            assert_eq!(reward_amount1, (reward_amount * 5, 0));
+            assert_eq!(reward_amount1, (reward_amount * 31, 0));
             let reward_inflation =
                 Farming::get_reward_inflation(reward.share, total_reward, pool1.total_shares);
-            assert_eq!(reward_inflation, reward_amount * 5);
+            assert_eq!(reward_inflation, reward_amount * 31);
 
             let reward_inflation = Farming::get_reward_inflation(800, share_reward, reward.share);
             assert_eq!(reward_inflation, share_reward * 8 / 10);
@@ -595,10 +616,10 @@ fn no_gauge_farming_pool_should_work() {
             assert_eq!(reward_inflation, share_reward / 10);
 
             // Withdraw partial tokens
-            assert_eq!(System::block_number(), 533);
+            assert_eq!(System::block_number(), 35);
             assert_eq!(
                 Assets::balance(KSM, &ALICE),
-                alice_init_balance - deposit_amount + reward_amount * 5
+                alice_init_balance - deposit_amount + reward_amount * 31
             );
             assert_ok!(Farming::withdraw(
                 RuntimeOrigin::signed(ALICE),
@@ -610,11 +631,11 @@ fn no_gauge_farming_pool_should_work() {
             // So both user and reward issuer account balance not change.
             assert_eq!(
                 Assets::balance(KSM, &ALICE),
-                alice_init_balance - deposit_amount + reward_amount * 5
+                alice_init_balance - deposit_amount + reward_amount * 31
             );
             assert_eq!(
                 Assets::balance(KSM, &pool1.reward_issuer),
-                total_rewards - reward_amount * 5
+                total_rewards - reward_amount * 31
             );
             assert_eq!(Assets::balance(KSM, &pool1.keeper), deposit_amount);
 
@@ -625,16 +646,18 @@ fn no_gauge_farming_pool_should_work() {
             pool1 = Farming::pool_infos(pool_id).unwrap();
 
             assert_eq!(pool1.total_shares, 200);
-            assert_eq!(reward.withdraw_list, vec![(533 + withdraw_limit_time, 800)]);
+            assert_eq!(reward.withdraw_list, vec![(35 + withdraw_limit_time, 800)]);
             assert_eq!(reward.share, 200);
-            assert_eq!(reward.withdrawn_rewards.get(&KSM).unwrap(), &reward_amount);
+            assert_eq!(
+                reward.withdrawn_rewards.get(&KSM).unwrap(),
+                &(reward_amount * 31 / 5)
+            );
             assert_eq!(
                 pool1.rewards.get(&KSM).unwrap(),
-                &(reward_amount, reward_amount)
+                &(reward_amount * 31 / 5, reward_amount * 31 / 5)
             );
 
             System::set_block_number(System::block_number() + 6);
-            Farming::on_initialize(System::block_number() + 3);
 
             // Withdraw rest all of share
             assert_ok!(Farming::withdraw(
@@ -642,12 +665,16 @@ fn no_gauge_farming_pool_should_work() {
                 pool_id,
                 Some(300)
             ));
+            assert_eq!(
+                Assets::balance(KSM, &ALICE),
+                alice_init_balance - deposit_amount + reward_amount * 37
+            );
             reward = SharesAndWithdrawnRewards::<Runtime>::get(pool_id, &ALICE).unwrap();
             pool1 = Farming::pool_infos(pool_id).unwrap();
             assert_eq!(pool1.total_shares, 0);
             assert!(pool1.rewards.is_empty());
             assert_eq!(reward.share, 0);
-            assert_eq!(reward.withdraw_list, vec![(540, 800), (546, 200)]);
+            assert_eq!(reward.withdraw_list, vec![(42, 800), (48, 200)]);
             assert_eq!(reward.withdrawn_rewards.get(&KSM).unwrap(), &0);
         })
 }
@@ -706,7 +733,6 @@ fn staking_minimum_kma(stake_token: CalamariAssetId, reward_token: CalamariAsset
             assert_eq!(Balances::free_balance(&CHARLIE), 0);
             assert_eq!(Balances::free_balance(&pool1.keeper), charlie_init_kma);
 
-            Farming::on_initialize(System::block_number() + 10);
             System::set_block_number(System::block_number() + 10);
             assert_eq!(Balances::free_balance(&CHARLIE), 0);
             assert_ok!(Farming::claim(RuntimeOrigin::signed(CHARLIE), pool_id));
@@ -785,7 +811,6 @@ fn staking_all_kma(stake_token: CalamariAssetId, reward_token: CalamariAssetId)
             assert_eq!(Balances::free_balance(&ALICE), 0);
             assert_eq!(Balances::free_balance(&pool1.keeper), alice_init_kma);
 
-            Farming::on_initialize(System::block_number() + 10);
             System::set_block_number(System::block_number() + 10);
             assert_eq!(Balances::free_balance(&ALICE), 0);
             assert_ok!(Farming::claim(RuntimeOrigin::signed(ALICE), pool_id));
@@ -911,10 +936,10 @@ fn staking_case(stake_token: CalamariAssetId, reward_token: CalamariAssetId) {
             assert_eq!(pool1.state, PoolState::Charged);
             assert!(pool1.rewards.is_empty());
 
-            // OnInitialize hook change the pool state and also pool rewards
-            Farming::on_initialize(System::block_number() + 3);
-            Farming::on_initialize(0);
-            pool1 = Farming::pool_infos(pool_id).unwrap();
+            // The pool starts on the next block and accrues rewards for every block after it
+            next_block();
+            next_block();
+            pool1 = Farming::updated_pool_info(pool_id).unwrap();
             assert_eq!(pool1.total_shares, deposit_amount);
             assert_eq!(pool1.state, PoolState::Ongoing);
             assert_eq!(pool1.claim_limit_time, 6);
@@ -930,28 +955,28 @@ fn staking_case(stake_token: CalamariAssetId, reward_token: CalamariAssetId) {
             assert!(reward.withdraw_list.is_empty());
             assert_eq!(reward.claim_last_block, 3);
 
-            // Claim success, user get reward.
+            // Claim success, user get the rewards of 7 blocks since startup.
             System::set_block_number(System::block_number() + 6);
             assert_ok!(Farming::claim(RuntimeOrigin::signed(ALICE), pool_id));
             if reward_token == KSM {
                 // Stake KMA, reward KSM
                 assert_eq!(
                     Assets::balance(reward_token, &ALICE),
-                    alice_init_ksm + reward_amount
+                    alice_init_ksm + reward_amount * 7
                 );
                 assert_eq!(
                     Assets::balance(reward_token, &pool1.reward_issuer),
-                    total_rewards - reward_amount
+                    total_rewards - reward_amount * 7
                 );
             } else {
                 // Stake KSM, reward KMA
                 assert_eq!(
                     Balances::free_balance(&ALICE),
-                    alice_init_kma + reward_amount
+                    alice_init_kma + reward_amount * 7
                 );
                 assert_eq!(
                     Balances::free_balance(&pool1.reward_issuer),
-                    total_rewards - reward_amount
+                    total_rewards - reward_amount * 7
                 );
             }
 
@@ -959,97 +984,56 @@ fn staking_case(stake_token: CalamariAssetId, reward_token: CalamariAssetId) {
             pool1 = Farming::pool_infos(pool_id).unwrap();
             assert_eq!(
                 pool1.rewards.get(&reward_token).unwrap(),
-                &(reward_amount, reward_amount)
+                &(reward_amount * 7, reward_amount * 7)
             );
             reward = SharesAndWithdrawnRewards::<Runtime>::get(pool_id, &ALICE).unwrap();
             assert_eq!(
                 reward.withdrawn_rewards.get(&reward_token).unwrap(),
-                &reward_amount
+                &(reward_amount * 7)
             );
             // The withdraw list of user share info is still empty.
             assert!(reward.withdraw_list.is_empty());
 
-            // Claim without new block.
-            for i in 0..5 {
-                System::set_block_number(System::block_number() + 100);
-                assert_ok!(Farming::claim(RuntimeOrigin::signed(ALICE), pool_id));
-
-                reward = SharesAndWithdrawnRewards::<Runtime>::get(pool_id, &ALICE).unwrap();
-                assert_eq!(
-                    reward.withdrawn_rewards.get(&reward_token).unwrap(),
-                    &reward_amount
-                );
-                assert_eq!(reward.claim_last_block, 109 + i * 100);
-                assert_eq!(reward.share, deposit_amount);
-                assert!(reward.withdraw_list.is_empty());
-
-                pool1 = Farming::pool_infos(pool_id).unwrap();
-                assert_eq!(
-                    pool1.rewards.get(&reward_token).unwrap(),
-                    &(reward_amount, reward_amount)
-                );
-                assert_eq!(pool1.total_shares, deposit_amount);
-            }
-            if reward_token == KSM {
-                // Stake KMA, reward KSM
-                assert_eq!(
-                    Assets::balance(reward_token, &pool1.reward_issuer),
-                    total_rewards - reward_amount
-                );
-            } else {
-                // Stake KSM, reward KMA
-                assert_eq!(
-                    Balances::free_balance(&pool1.reward_issuer),
-                    total_rewards - reward_amount
-                );
-            }
-            if stake_token == KSM {
-                assert_eq!(Assets::balance(stake_token, &pool1.keeper), deposit_amount);
-            } else {
-                assert_eq!(Balances::free_balance(&pool1.keeper), deposit_amount);
-            }
-
-            // Claim with new block
+            // Claim with new blocks
             for i in 1..5 {
                 System::set_block_number(System::block_number() + 6);
-                Farming::on_initialize(System::block_number() + 3);
                 assert_ok!(Farming::claim(RuntimeOrigin::signed(ALICE), pool_id));
 
                 reward = SharesAndWithdrawnRewards::<Runtime>::get(pool_id, &ALICE).unwrap();
                 assert_eq!(
                     reward.withdrawn_rewards.get(&reward_token).unwrap(),
-                    &(reward_amount * (i + 1))
+                    &(reward_amount * (7 + i * 6))
                 );
-                assert_eq!(reward.claim_last_block as u128, 509 + i * 6);
+                assert_eq!(reward.claim_last_block as u128, 11 + i * 6);
                 assert_eq!(reward.share, deposit_amount);
                 assert!(reward.withdraw_list.is_empty());
 
                 pool1 = Farming::pool_infos(pool_id).unwrap();
                 assert_eq!(
                     pool1.rewards.get(&reward_token).unwrap(),
-                    &(reward_amount * (i + 1), reward_amount * (i + 1))
+                    &(reward_amount * (7 + i * 6), reward_amount * (7 + i * 6))
                 );
                 assert_eq!(pool1.total_shares, deposit_amount);
             }
             if reward_token == KSM {
-                // Stake KMA, reward another 4 times KSM
+                // Stake KMA, reward another 24 blocks of KSM
                 assert_eq!(
                     Assets::balance(reward_token, &ALICE),
-                    alice_init_ksm + reward_amount * 5
+                    alice_init_ksm + reward_amount * 31
                 );
                 assert_eq!(
                     Assets::balance(reward_token, &pool1.reward_issuer),
-                    total_rewards - reward_amount * 5
+                    total_rewards - reward_amount * 31
                 );
             } else {
-                // Stake KSM, reward another 4 times KMA
+                // Stake KSM, reward another 24 blocks of KMA
                 assert_eq!(
                     Balances::free_balance(&ALICE),
-                    alice_init_kma + reward_amount * 5
+                    alice_init_kma + reward_amount * 31
                 );
                 assert_eq!(
                     Balances::free_balance(&pool1.reward_issuer),
-                    total_rewards - reward_amount * 5
+                    total_rewards - reward_amount * 31
                 );
             }
             if stake_token == KSM {
@@ -1059,7 +1043,7 @@ fn staking_case(stake_token: CalamariAssetId, reward_token: CalamariAssetId) {
             }
             assert_eq!(
                 pool1.rewards.get(&reward_token).unwrap(),
-                &(reward_amount * 5, reward_amount * 5)
+                &(reward_amount * 31, reward_amount * 31)
             );
 
             // Withdraw failed because of share info not exist.
@@ -1074,11 +1058,11 @@ fn staking_case(stake_token: CalamariAssetId, reward_token: CalamariAssetId) {
             assert!(reward.withdraw_list.is_empty());
 
             let share_reward = reward.withdrawn_rewards.get(&reward_token).unwrap();
-            assert_eq!(share_reward, &(reward_amount * 5));
+            assert_eq!(share_reward, &(reward_amount * 31));
             let (total_reward, total_withdrawn_reward) = pool1.rewards.get(&reward_token).unwrap();
             assert_eq!(
                 pool1.rewards.get(&reward_token).unwrap(),
-                &(reward_amount * 5, reward_amount * 5)
+                &(reward_amount * 31, reward_amount * 31)
             );
 
             let reward_amount1 = Farming::get_reward_amount(
@@ -1089,10 +1073,10 @@ fn staking_case(stake_token: CalamariAssetId, reward_token: CalamariAssetId) {
                 &reward_token,
             )
             .unwrap();
-            assert_eq!(reward_amount1, (reward_amount * 5, 0));
+            assert_eq!(reward_amount1, (reward_amount * 31, 0));
             let reward_inflation =
                 Farming::get_reward_inflation(reward.share, total_reward, pool1.total_shares);
-            assert_eq!(reward_inflation, reward_amount * 5);
+            assert_eq!(reward_inflation, reward_amount * 31);
 
             let reward_inflation = Farming::get_reward_inflation(800, share_reward, reward.share);
             assert_eq!(reward_inflation, share_reward * 8 / 10);
@@ -1102,7 +1086,7 @@ fn staking_case(stake_token: CalamariAssetId, reward_token: CalamariAssetId) {
             assert_eq!(reward_inflation, share_reward / 10);
 
             // Withdraw partial tokens
-            assert_eq!(System::block_number(), 533);
+            assert_eq!(System::block_number(), 35);
             assert_ok!(Farming::withdraw(
                 RuntimeOrigin::signed(ALICE),
                 pool_id,
@@ -1114,21 +1098,21 @@ fn staking_case(stake_token: CalamariAssetId, reward_token: CalamariAssetId) {
                 // Stake KMA, reward KSM
                 assert_eq!(
                     Assets::balance(reward_token, &ALICE),
-                    alice_init_ksm + reward_amount * 5
+                    alice_init_ksm + reward_amount * 31
                 );
                 assert_eq!(
                     Assets::balance(reward_token, &pool1.reward_issuer),
-                    total_rewards - reward_amount * 5
+                    total_rewards - reward_amount * 31
                 );
             } else {
                 // Stake KSM, reward KMA
                 assert_eq!(
                     Balances::free_balance(&ALICE),
-                    alice_init_kma + reward_amount * 5
+                    alice_init_kma + reward_amount * 31
                 );
                 assert_eq!(
                     Balances::free_balance(&pool1.reward_issuer),
-                    total_rewards - reward_amount * 5
+                    total_rewards - reward_amount * 31
                 );
             }
             if stake_token == KSM {
@@ -1144,19 +1128,18 @@ fn staking_case(stake_token: CalamariAssetId, reward_token: CalamariAssetId) {
             pool1 = Farming::pool_infos(pool_id).unwrap();
 
             assert_eq!(pool1.total_shares, 200);
-            assert_eq!(reward.withdraw_list, vec![(533 + withdraw_limit_time, 800)]);
+            assert_eq!(reward.withdraw_list, vec![(35 + withdraw_limit_time, 800)]);
             assert_eq!(reward.share, 200);
             assert_eq!(
                 reward.withdrawn_rewards.get(&reward_token).unwrap(),
-                &reward_amount
+                &(reward_amount * 31 / 5)
             );
             assert_eq!(
                 pool1.rewards.get(&reward_token).unwrap(),
-                &(reward_amount, reward_amount)
+                &(reward_amount * 31 / 5, reward_amount * 31 / 5)
             );
 
             System::set_block_number(System::block_number() + 6);
-            Farming::on_initialize(System::block_number() + 3);
 
             // Withdraw rest all of share
             assert_ok!(Farming::withdraw(
@@ -1169,27 +1152,27 @@ fn staking_case(stake_token: CalamariAssetId, reward_token: CalamariAssetId) {
             assert_eq!(pool1.total_shares, 0);
             assert!(pool1.rewards.is_empty());
             assert_eq!(reward.share, 0);
-            assert_eq!(reward.withdraw_list, vec![(540, 800), (546, 200)]);
+            assert_eq!(reward.withdraw_list, vec![(42, 800), (48, 200)]);
             assert_eq!(reward.withdrawn_rewards.get(&reward_token).unwrap(), &0);
             if reward_token == KSM {
                 // Stake KMA, reward KSM
                 assert_eq!(
                     Assets::balance(reward_token, &ALICE),
-                    alice_init_ksm + reward_amount * 6
+                    alice_init_ksm + reward_amount * 37
                 );
                 assert_eq!(
                     Assets::balance(reward_token, &pool1.reward_issuer),
-                    total_rewards - reward_amount * 6
+                    total_rewards - reward_amount * 37
                 );
             } else {
                 // Stake KSM, reward KMA
                 assert_eq!(
                     Balances::free_balance(&ALICE),
-                    alice_init_kma + reward_amount * 6
+                    alice_init_kma + reward_amount * 37
                 );
                 assert_eq!(
                     Balances::free_balance(&pool1.reward_issuer),
-                    total_rewards - reward_amount * 6
+                    total_rewards - reward_amount * 37
                 );
             }
             if stake_token == KSM {
@@ -1307,10 +1290,10 @@ fn gauge_farming_pool_should_work() {
             assert_eq!(pool1.min_deposit_to_start, 2);
             assert_eq!(pool1.state, PoolState::Charged);
 
-            // OnInitialize hook change the pool state
-            Farming::on_initialize(System::block_number() + 3);
-            Farming::on_initialize(0);
-            pool1 = Farming::pool_infos(pool_id).unwrap();
+            // The pool starts on the next block
+            next_block();
+            next_block();
+            pool1 = Farming::updated_pool_info(pool_id).unwrap();
             assert_eq!(pool1.total_shares, 1000);
             assert_eq!(pool1.min_deposit_to_start, 2);
             assert_eq!(pool1.state, PoolState::Ongoing);
@@ -1322,19 +1305,19 @@ fn gauge_farming_pool_should_work() {
             );
             System::set_block_number(System::block_number() + 6);
             assert_ok!(Farming::claim(RuntimeOrigin::signed(ALICE), pool_id));
-            assert_eq!(Assets::balance(KSM, &ALICE), 3008);
+            assert_eq!(Assets::balance(KSM, &ALICE), 9692);
 
             System::set_block_number(System::block_number() + 100);
             assert_ok!(Farming::claim(RuntimeOrigin::signed(ALICE), pool_id));
-            assert_eq!(Assets::balance(KSM, &ALICE), 4698);
+            assert_eq!(Assets::balance(KSM, &ALICE), 200971);
 
-            // Withdraw part tokens
+            // Withdraw part tokens, no new rewards in the same block
             assert_ok!(Farming::withdraw(
                 RuntimeOrigin::signed(ALICE),
                 pool_id,
                 Some(800)
             ));
-            assert_eq!(Assets::balance(KSM, &ALICE), 4698);
+            assert_eq!(Assets::balance(KSM, &ALICE), 200971);
 
             // Claim again
             System::set_block_number(System::block_number() + 6);
@@ -1343,10 +1326,10 @@ fn gauge_farming_pool_should_work() {
                 Farming::claim(RuntimeOrigin::signed(ALICE), pool_id),
                 Error::<Runtime>::CanNotClaim
             );
-            assert_eq!(Assets::balance(KSM, &ALICE), 4698);
+            assert_eq!(Assets::balance(KSM, &ALICE), 206971);
             System::set_block_number(System::block_number() + 6);
             assert_ok!(Farming::claim(RuntimeOrigin::signed(ALICE), pool_id));
-            assert_eq!(Assets::balance(KSM, &ALICE), 5498);
+            assert_eq!(Assets::balance(KSM, &ALICE), 213771);
         })
 }
 
@@ -1428,59 +1411,70 @@ fn deposit_should_work(use_gauge: bool) {
     if use_gauge {
         let mut gauge_basic_rewards = BTreeMap::<CurrencyIdOf<Runtime>, BalanceOf<Runtime>>::new();
         gauge_basic_rewards.entry(KSM).or_insert(tokens);
+        // The gauge pool is bonded since creation, both gauge deposits accrued its rewards.
+        let mut gauge_rewards = BTreeMap::<
+            CurrencyIdOf<Runtime>,
+            (BalanceOf<Runtime>, BalanceOf<Runtime>, BalanceOf<Runtime>),
+        >::new();
+        gauge_rewards.entry(KSM).or_insert((2000, 0, 0));
         let gauge_pool_info = GaugePoolInfo {
             pool_id,
             token: KSM,
             keeper,
             reward_issuer,
-            rewards: BTreeMap::<
-                CurrencyIdOf<Runtime>,
-                (BalanceOf<Runtime>, BalanceOf<Runtime>, BalanceOf<Runtime>),
-            >::new(),
+            rewards: gauge_rewards,
             gauge_basic_rewards,
             max_block: 1000,
             gauge_amount: 200,
             total_time_factor: 39900,
             gauge_last_block: System::block_number(),
             gauge_state: GaugeState::Bonded,
+            last_update_block: System::block_number(),
         };
         assert_eq!(Farming::gauge_pool_infos(0), Some(gauge_pool_info));
     } else {
         assert_eq!(Farming::gauge_pool_infos(0), None);
     }
 
-    Farming::on_initialize(0);
-    assert_eq!(Farming::pool_infos(0).unwrap().state, PoolState::Ongoing);
-    assert!(Farming::pool_infos(0).unwrap().rewards.is_empty());
-    if use_gauge {
-        assert_eq!(
-            Farming::gauge_pool_infos(0).unwrap().rewards.get(&KSM),
-            Some(&(1000, 0, 0))
-        );
-    }
+    // The pool started on the first block after it was charged.
+    let pool_info = Farming::updated_pool_info(0).unwrap();
+    assert_eq!(pool_info.state, PoolState::Ongoing);
+    assert_eq!(pool_info.block_startup, Some(1));
+    // Without gauge, the second deposit settled one block of rewards and got its share inflation.
+    let (total_reward, total_withdrawn_reward) = if use_gauge { (1000, 0) } else { (1050, 50) };
+    assert_eq!(
+        pool_info.rewards.get(&KSM),
+        Some(&(total_reward, total_withdrawn_reward))
+    );
 
-    Farming::on_initialize(0);
+    next_block();
     assert_eq!(
-        Farming::pool_infos(0).unwrap().rewards.get(&KSM),
-        Some(&(1000, 0))
+        Farming::updated_pool_info(0).unwrap().rewards.get(&KSM),
+        Some(&(total_reward + 1000, total_withdrawn_reward))
     );
     if use_gauge {
         assert_eq!(
-            Farming::gauge_pool_infos(0).unwrap().rewards.get(&KSM),
-            Some(&(2000, 0, 0))
+            Farming::updated_gauge_pool_info(0)
+                .unwrap()
+                .rewards
+                .get(&KSM),
+            Some(&(3000, 0, 0))
         );
     }
 
-    System::set_block_number(System::block_number() + 1000);
-    Farming::on_initialize(0);
+    // Skipped blocks accrue as well, without any storage write in between.
+    System::set_block_number(System::block_number() + 10);
     assert_eq!(
-        Farming::pool_infos(0).unwrap().rewards.get(&KSM),
-        Some(&(2000, 0))
+        Farming::updated_pool_info(0).unwrap().rewards.get(&KSM),
+        Some(&(total_reward + 11_000, total_withdrawn_reward))
     );
     if use_gauge {
         assert_eq!(
-            Farming::gauge_pool_infos(0).unwrap().rewards.get(&KSM),
-            Some(&(3000, 0, 0))
+            Farming::updated_gauge_pool_info(0)
+                .unwrap()
+                .rewards
+                .get(&KSM),
+            Some(&(13_000, 0, 0))
         );
     }
 }
@@ -1517,8 +1511,8 @@ fn withdraw_should_work(use_gauge: bool) {
     assert_eq!(Assets::balance(KSM, &reward_issuer), 100_000);
     assert_eq!(Assets::balance(KSM, &token_keeper), 1_000);
 
-    Farming::on_initialize(0);
-    Farming::on_initialize(0);
+    next_block();
+    next_block();
     System::set_block_number(System::block_number() + 1);
 
     let reward = SharesAndWithdrawnRewards::<Runtime>::get(pool_id, &ALICE).unwrap();
@@ -1533,9 +1527,9 @@ fn withdraw_should_work(use_gauge: bool) {
     ));
 
     // withdraw contains claim reward operation
-    // reward issuer transfer 1000 reward token to user
-    assert_eq!(Assets::balance(KSM, &reward_issuer), 99_000);
-    assert_eq!(Assets::balance(KSM, &ALICE), 3_000);
+    // reward issuer transfer 2 blocks of reward token to user
+    assert_eq!(Assets::balance(KSM, &reward_issuer), 98_000);
+    assert_eq!(Assets::balance(KSM, &ALICE), 4_000);
 
     let reward = SharesAndWithdrawnRewards::<Runtime>::get(pool_id, &ALICE).unwrap();
     assert_eq!(reward.withdraw_list.len(), 1);
@@ -1547,12 +1541,12 @@ fn withdraw_should_work(use_gauge: bool) {
 
     // Alice claim reward manually, but due to no new block, so no reward
     assert_ok!(Farming::claim(RuntimeOrigin::signed(ALICE), pool_id));
-    assert_eq!(Assets::balance(KSM, &ALICE), 3_000);
-    assert_eq!(Assets::balance(KSM, &reward_issuer), 99_000);
+    assert_eq!(Assets::balance(KSM, &ALICE), 4_000);
+    assert_eq!(Assets::balance(KSM, &reward_issuer), 98_000);
     assert_eq!(Assets::balance(KSM, &token_keeper), 1_000);
 
     // Bob deposit
-    System::set_block_number(System::block_number() + 100);
+    System::set_block_number(System::block_number() + 10);
     if use_gauge {
         assert_ok!(Farming::deposit(
             RuntimeOrigin::signed(BOB),
@@ -1577,10 +1571,10 @@ fn withdraw_should_work(use_gauge: bool) {
     }
 
     // Alice claim again, because new block produces, so has reward now
-    Farming::on_initialize(0);
+    next_block();
     assert_ok!(Farming::claim(RuntimeOrigin::signed(ALICE), pool_id));
-    assert_eq!(Assets::balance(KSM, &ALICE), 3_966);
-    assert_eq!(Assets::balance(KSM, &reward_issuer), 98_834);
+    assert_eq!(Assets::balance(KSM, &ALICE), 14_966);
+    assert_eq!(Assets::balance(KSM, &reward_issuer), 87_834);
 
     // withdraw
     assert_ok!(Farming::withdraw(
@@ -1588,8 +1582,8 @@ fn withdraw_should_work(use_gauge: bool) {
         pool_id,
         Some(200)
     ));
-    assert_eq!(Assets::balance(KSM, &ALICE), 3_966);
-    assert_eq!(Assets::balance(KSM, &reward_issuer), 98_834);
+    assert_eq!(Assets::balance(KSM, &ALICE), 14_966);
+    assert_eq!(Assets::balance(KSM, &reward_issuer), 87_834);
     if use_gauge {
         assert_eq!(Assets::balance(KSM, &token_keeper), 1_300);
     } else {
@@ -1598,13 +1592,13 @@ fn withdraw_should_work(use_gauge: bool) {
 
     // `withdraw_claim` operation will transfer back user stake token
     // User unStake 200 KSM, so keeper transfer back 200 KSM to user.
-    System::set_block_number(System::block_number() + 100);
+    System::set_block_number(System::block_number() + 10);
     assert_ok!(Farming::withdraw_claim(
         RuntimeOrigin::signed(ALICE),
         pool_id
     ));
-    assert_eq!(Assets::balance(KSM, &ALICE), 4_166);
-    assert_eq!(Assets::balance(KSM, &reward_issuer), 98_834);
+    assert_eq!(Assets::balance(KSM, &ALICE), 15_166);
+    assert_eq!(Assets::balance(KSM, &reward_issuer), 87_834);
     if use_gauge {
         assert_eq!(Assets::balance(KSM, &token_keeper), 1_100);
     } else {
@@ -1615,8 +1609,8 @@ fn withdraw_should_work(use_gauge: bool) {
     let reward = SharesAndWithdrawnRewards::<Runtime>::get(pool_id, &ALICE).unwrap();
     assert!(reward.withdraw_list.is_empty());
     assert_ok!(Farming::claim(RuntimeOrigin::signed(ALICE), pool_id));
-    assert_eq!(Assets::balance(KSM, &ALICE), 4_166);
-    assert_eq!(Assets::balance(KSM, &reward_issuer), 98_834);
+    assert_eq!(Assets::balance(KSM, &ALICE), 15_166);
+    assert_eq!(Assets::balance(KSM, &reward_issuer), 87_834);
     if use_gauge {
         assert_eq!(Assets::balance(KSM, &token_keeper), 1_100);
     } else {
@@ -1647,32 +1641,32 @@ fn claim() {
                 Error::<Runtime>::InvalidPoolState
             );
 
-            System::set_block_number(System::block_number() + 100);
-            Farming::on_initialize(0);
+            // The pool started at block 1, so 9 blocks of rewards by block 10
+            System::set_block_number(System::block_number() + 10);
             assert_ok!(Farming::claim(RuntimeOrigin::signed(ALICE), pool_id));
-            assert_eq!(Assets::balance(KSM, &ALICE), 2000);
+            assert_eq!(Assets::balance(KSM, &ALICE), 11_000);
             assert_eq!(Assets::balance(KSM, &keeper), 1000);
-            assert_eq!(Assets::balance(KSM, &reward_issuer), 100_000);
+            assert_eq!(Assets::balance(KSM, &reward_issuer), 91_000);
 
-            Farming::on_initialize(0);
+            next_block();
             assert_ok!(Farming::withdraw_claim(
                 RuntimeOrigin::signed(ALICE),
                 pool_id
             ));
-            assert_eq!(Assets::balance(KSM, &ALICE), 2000);
+            assert_eq!(Assets::balance(KSM, &ALICE), 11_000);
 
             assert_ok!(Farming::claim(RuntimeOrigin::signed(ALICE), pool_id));
-            assert_eq!(Assets::balance(KSM, &ALICE), 3000);
+            assert_eq!(Assets::balance(KSM, &ALICE), 12_000);
             assert_eq!(Assets::balance(KSM, &keeper), 1000);
-            assert_eq!(Assets::balance(KSM, &reward_issuer), 99_000);
+            assert_eq!(Assets::balance(KSM, &reward_issuer), 90_000);
 
-            Farming::on_initialize(0);
+            next_block();
             assert_ok!(Farming::close_pool(RuntimeOrigin::signed(ALICE), pool_id));
 
             assert_ok!(Farming::retire_pool(RuntimeOrigin::signed(ALICE), pool_id));
-            assert_eq!(Assets::balance(KSM, &ALICE), 5000); // 3000 + 1000 + 1000
+            assert_eq!(Assets::balance(KSM, &ALICE), 14_000); // 12000 + 1000 + 1000
             assert_eq!(Assets::balance(KSM, &keeper), 0);
-            assert_eq!(Assets::balance(KSM, &reward_issuer), 98_000);
+            assert_eq!(Assets::balance(KSM, &reward_issuer), 89_000);
         });
 }
 
@@ -1687,14 +1681,11 @@ fn gauge() {
             if let Some(gauge_pool_infos) = Farming::gauge_pool_infos(0) {
                 assert!(gauge_pool_infos.rewards.is_empty());
             };
-            Farming::on_initialize(0);
-            System::set_block_number(System::block_number() + 1);
-            Farming::on_initialize(0);
+            System::set_block_number(System::block_number() + 2);
             assert_ok!(Farming::claim(RuntimeOrigin::signed(ALICE), pool_id));
-            assert_eq!(Assets::balance(KSM, &ALICE), 2918);
+            assert_eq!(Assets::balance(KSM, &ALICE), 2936);
 
-            Farming::on_initialize(0);
-            System::set_block_number(System::block_number() + 10);
+            System::set_block_number(System::block_number() + 11);
             assert_noop!(
                 Farming::deposit(
                     RuntimeOrigin::signed(ALICE),
@@ -1710,11 +1701,11 @@ fn gauge() {
                 tokens,
                 Some((100, 100))
             ));
-            assert_eq!(Assets::balance(KSM, &ALICE), 1818);
+            assert_eq!(Assets::balance(KSM, &ALICE), 1836);
 
             System::set_block_number(System::block_number() + 20);
             assert_ok!(Farming::claim(RuntimeOrigin::signed(ALICE), pool_id));
-            assert_eq!(Assets::balance(KSM, &ALICE), 3163);
+            assert_eq!(Assets::balance(KSM, &ALICE), 36745);
 
             assert_ok!(Farming::deposit(
                 RuntimeOrigin::signed(BOB),
@@ -1724,9 +1715,9 @@ fn gauge() {
             ));
             assert_eq!(Assets::balance(KSM, &BOB), 9699990);
 
-            System::set_block_number(System::block_number() + 200);
+            System::set_block_number(System::block_number() + 100);
             assert_ok!(Farming::claim(RuntimeOrigin::signed(ALICE), pool_id));
-            assert_eq!(Assets::balance(KSM, &ALICE), 5383);
+            assert_eq!(Assets::balance(KSM, &ALICE), 195771);
             assert_eq!(Assets::balance(KSM, &BOB), 9699990);
             assert_ok!(Farming::deposit(
                 RuntimeOrigin::signed(BOB),
@@ -1735,7 +1726,7 @@ fn gauge() {
                 Some((100, 100))
             ));
 
-            System::set_block_number(System::block_number() + 200);
+            System::set_block_number(System::block_number() + 100);
             assert_noop!(
                 Farming::force_gauge_claim(RuntimeOrigin::signed(ALICE), pool_id),
                 Error::<Runtime>::RetireLimitNotSet
@@ -1745,7 +1736,7 @@ fn gauge() {
                 RuntimeOrigin::signed(ALICE),
                 pool_id
             ));
-            assert_eq!(Assets::balance(KSM, &BOB), 9699991);
+            assert_eq!(Assets::balance(KSM, &BOB), 9700516);
 
             assert_ok!(Farming::deposit(
                 RuntimeOrigin::signed(ALICE),
@@ -1753,7 +1744,7 @@ fn gauge() {
                 tokens,
                 Some((100, 1))
             ));
-            System::set_block_number(System::block_number() + 200);
+            System::set_block_number(System::block_number() + 2);
             assert_noop!(
                 Farming::deposit(
                     RuntimeOrigin::signed(ALICE),
@@ -1777,20 +1768,18 @@ fn gauge_withdraw() {
             if let Some(gauge_pool_infos) = Farming::gauge_pool_infos(0) {
                 assert_eq!(gauge_pool_infos.gauge_amount, 100)
             };
-            Farming::on_initialize(0);
-            System::set_block_number(System::block_number() + 1);
-            Farming::on_initialize(0);
+            System::set_block_number(System::block_number() + 2);
             assert_ok!(Farming::gauge_withdraw(
                 RuntimeOrigin::signed(ALICE),
                 pool_id
             ));
-            assert_eq!(Assets::balance(KSM, &ALICE), 1918);
-            System::set_block_number(System::block_number() + 1000);
+            assert_eq!(Assets::balance(KSM, &ALICE), 1936);
+            System::set_block_number(System::block_number() + 100);
             assert_ok!(Farming::gauge_withdraw(
                 RuntimeOrigin::signed(ALICE),
                 pool_id
             ));
-            assert_eq!(Assets::balance(KSM, &ALICE), 3782);
+            assert_eq!(Assets::balance(KSM, &ALICE), 91965);
             if let Some(gauge_pool_infos) = Farming::gauge_pool_infos(0) {
                 assert_eq!(gauge_pool_infos.gauge_amount, 0)
             };
@@ -1804,8 +1793,7 @@ fn pool_admin_operation_should_work() {
         .build()
         .execute_with(|| {
             let (pool_id, tokens) = init_gauge_1000();
-            Farming::on_initialize(0);
-            System::set_block_number(System::block_number() + 1);
+            System::set_block_number(System::block_number() + 2);
             assert_ok!(Farming::deposit(
                 RuntimeOrigin::signed(ALICE),
                 pool_id,
@@ -1905,7 +1893,7 @@ fn pool_admin_operation_should_work() {
             assert_eq!(pool.state, PoolState::Retired);
 
             // claim all rewards automatically to user
-            assert_eq!(Assets::balance(KSM, &ALICE), 3000);
+            assert_eq!(Assets::balance(KSM, &ALICE), 5000);
             assert_eq!(Farming::shares_and_withdrawn_rewards(pool_id, &ALICE), None);
 
             // Pool is retired, not allow to retire again, deposit, withdraw, claim, close
@@ -1944,11 +1932,9 @@ fn reset() {
         .execute_with(|| {
             let (pool_id, _tokens) = init_gauge_900();
             assert_eq!(Assets::balance(KSM, &ALICE), 1900);
-            Farming::on_initialize(0);
-            System::set_block_number(System::block_number() + 1);
-            Farming::on_initialize(0);
+            System::set_block_number(System::block_number() + 2);
             assert_ok!(Farming::claim(RuntimeOrigin::signed(ALICE), pool_id));
-            assert_eq!(Assets::balance(KSM, &ALICE), 2918);
+            assert_eq!(Assets::balance(KSM, &ALICE), 2936);
             assert_ok!(Farming::close_pool(RuntimeOrigin::signed(ALICE), pool_id));
             assert_ok!(Farming::set_retire_limit(RuntimeOrigin::signed(ALICE), 10));
             assert_ok!(Farming::retire_pool(RuntimeOrigin::signed(ALICE), pool_id));
@@ -1991,6 +1977,11 @@ fn reset() {
                 withdraw_limit_time: Default::default(),
                 claim_limit_time: Default::default(),
                 withdraw_limit_count: 5,
+                last_update_block: System::block_number(),
+                end_block: None,
+                reward_steps: BTreeMap::new(),
+                // One block of rewards was accrued before the pool was closed
+                reward_budget: BTreeMap::from([(KSM, 299_000)]),
             };
             assert_eq!(Farming::pool_infos(0), Some(pool_infos));
             let gauge_pool_info = GaugePoolInfo {
@@ -2008,9 +1999,10 @@ fn reset() {
                 total_time_factor: 0,
                 gauge_last_block: System::block_number(),
                 gauge_state: GaugeState::Bonded,
+                last_update_block: System::block_number(),
             };
             assert_eq!(Farming::gauge_pool_infos(1), Some(gauge_pool_info));
-            assert_eq!(Assets::balance(KSM, &ALICE), 3918);
+            assert_eq!(Assets::balance(KSM, &ALICE), 3936);
             let charge_rewards = vec![(KSM, 300000)];
             assert_ok!(Farming::charge(
                 RuntimeOrigin::signed(BOB),
@@ -2023,10 +2015,906 @@ fn reset() {
                 1,
                 Some((100, 100))
             ));
-            assert_eq!(Assets::balance(KSM, &ALICE), 3817);
-            Farming::on_initialize(0);
-            System::set_block_number(System::block_number() + 20);
+            assert_eq!(Assets::balance(KSM, &ALICE), 3835);
+            System::set_block_number(System::block_number() + 21);
+            assert_ok!(Farming::claim(RuntimeOrigin::signed(ALICE), pool_id));
+            assert_eq!(Assets::balance(KSM, &ALICE), 28245);
+        })
+}
+
+#[test]
+fn reward_schedule_should_close_pool() {
+    ExtBuilder::default()
+        .one_hundred_for_alice_n_bob()
+        .build()
+        .execute_with(|| {
+            let (pool_id, tokens) = init_no_gauge();
+            assert_eq!(Farming::pool_runway(pool_id), Ok(Some(100)));
+
+            assert_noop!(
+                Farming::set_reward_schedule(
+                    RuntimeOrigin::signed(ALICE),
+                    pool_id,
+                    Some(0),
+                    BoundedVec::default()
+                ),
+                Error::<Runtime>::InvalidPoolParameter
+            );
+            assert_noop!(
+                Farming::set_reward_schedule(
+                    RuntimeOrigin::signed(ALICE),
+                    pool_id,
+                    Some(10),
+                    BoundedVec::truncate_from(vec![(
+                        11,
+                        BoundedVec::truncate_from(vec![(KSM, 2000)])
+                    )])
+                ),
+                Error::<Runtime>::InvalidPoolParameter
+            );
+            assert_ok!(Farming::set_reward_schedule(
+                RuntimeOrigin::signed(ALICE),
+                pool_id,
+                Some(10),
+                BoundedVec::truncate_from(vec![(6, BoundedVec::truncate_from(vec![(KSM, 2000)]))])
+            ));
+
+            // The pool started at block 1, blocks 2 to 5 accrue 1000 and blocks 6 to 10 accrue 2000
+            System::set_block_number(5);
+            assert_eq!(Farming::pool_runway(pool_id), Ok(Some(5)));
+
+            System::set_block_number(20);
+            let pool_info = Farming::updated_pool_info(pool_id).unwrap();
+            assert_eq!(pool_info.state, PoolState::Dead);
+            assert_eq!(pool_info.basic_rewards, BTreeMap::from([(KSM, 2000)]));
+            assert!(pool_info.reward_steps.is_empty());
+            assert_eq!(pool_info.rewards.get(&KSM), Some(&(14_000, 0)));
+            assert_eq!(pool_info.reward_budget.get(&KSM), Some(&86_000));
+            assert_eq!(Farming::pool_runway(pool_id), Ok(Some(0)));
+
+            assert_noop!(
+                Farming::deposit(RuntimeOrigin::signed(ALICE), pool_id, tokens, None),
+                Error::<Runtime>::InvalidPoolState
+            );
+            assert_ok!(Farming::claim(RuntimeOrigin::signed(ALICE), pool_id));
+            assert_eq!(Assets::balance(KSM, &ALICE), 16_000);
+        })
+}
+
+#[test]
+fn charged_rewards_should_bound_pool() {
+    ExtBuilder::default()
+        .one_hundred_for_alice_n_bob()
+        .build()
+        .execute_with(|| {
+            assert_ok!(Farming::create_farming_pool(
+                RuntimeOrigin::signed(ALICE),
+                vec![(KSM, Perbill::from_percent(100))],
+                vec![(KSM, 1000)],
+                None,
+                0, // min_deposit_to_start
+                0, // after_block_to_start
+                0, // withdraw_limit_time
+                0, // claim_limit_time
+                1  // withdraw_limit_count
+            ));
+            let pool_id = 0;
+            assert_ok!(Farming::charge(
+                RuntimeOrigin::signed(BOB),
+                pool_id,
+                vec![(KSM, 5500)]
+            ));
+            assert_ok!(Farming::deposit(
+                RuntimeOrigin::signed(ALICE),
+                pool_id,
+                1000,
+                None
+            ));
+
+            // The pool started at block 1, the charged rewards pay for blocks 2 to 6
+            System::set_block_number(3);
+            assert_eq!(Farming::pool_runway(pool_id), Ok(Some(3)));
+
+            System::set_block_number(10);
+            let pool_info = Farming::updated_pool_info(pool_id).unwrap();
+            assert_eq!(pool_info.state, PoolState::Dead);
+            assert_eq!(pool_info.rewards.get(&KSM), Some(&(5000, 0)));
+            assert_eq!(pool_info.reward_budget.get(&KSM), Some(&500));
+            assert_eq!(Farming::pool_runway(pool_id), Ok(Some(0)));
+
+            // Charging the pool again restarts it from the next block
+            assert_ok!(Farming::charge(
+                RuntimeOrigin::signed(BOB),
+                pool_id,
+                vec![(KSM, 1000)]
+            ));
+            System::set_block_number(13);
+            let pool_info = Farming::updated_pool_info(pool_id).unwrap();
+            assert_eq!(pool_info.state, PoolState::Dead);
+            assert_eq!(pool_info.block_startup, Some(11));
+            assert_eq!(pool_info.rewards.get(&KSM), Some(&(6000, 0)));
+
+            assert_ok!(Farming::claim(RuntimeOrigin::signed(ALICE), pool_id));
+            assert_eq!(Assets::balance(KSM, &ALICE), 8000);
+        })
+}
+
+#[test]
+fn charge_should_fund_every_reward_currency() {
+    ExtBuilder::default()
+        .one_hundred_for_alice_n_bob()
+        .build()
+        .execute_with(|| {
+            assert_ok!(Farming::create_farming_pool(
+                RuntimeOrigin::signed(ALICE),
+                vec![(KSM, Perbill::from_percent(100))],
+                vec![(KSM, 1000), (KMA, 500)],
+                None,
+                0, // min_deposit_to_start
+                0, // after_block_to_start
+                0, // withdraw_limit_time
+                0, // claim_limit_time
+                1  // withdraw_limit_count
+            ));
+            let pool_id = 0;
+            assert_noop!(
+                Farming::charge(RuntimeOrigin::signed(BOB), pool_id, vec![(KSM, 5000)]),
+                Error::<Runtime>::RewardBudgetNotFound
+            );
+            assert_ok!(Farming::charge(
+                RuntimeOrigin::signed(BOB),
+                pool_id,
+                vec![(KSM, 5000), (KMA, 5000)]
+            ));
+            assert_ok!(Farming::deposit(
+                RuntimeOrigin::signed(ALICE),
+                pool_id,
+                1000,
+                None
+            ));
+
+            // A reward currency without a budget is an error rather than an empty budget
+            PoolInfos::<Runtime>::mutate(pool_id, |maybe_pool_info| {
+                maybe_pool_info.as_mut().unwrap().reward_budget.remove(&KMA);
+            });
+            System::set_block_number(3);
+            assert_eq!(
+                Farming::updated_pool_info(pool_id),
+                Err(Error::<Runtime>::RewardBudgetNotFound.into())
+            );
+        })
+}
+
+#[test]
+fn reward_schedule_migration_should_budget_partly_funded_issuer() {
+    ExtBuilder::default()
+        .one_hundred_for_alice_n_bob()
+        .build()
+        .execute_with(|| {
+            let pool_id = 0;
+            let keeper: AccountId =
+                <Runtime as Config>::Keeper::get().into_sub_account_truncating(pool_id);
+            let reward_issuer: AccountId =
+                <Runtime as Config>::RewardIssuer::get().into_sub_account_truncating(pool_id);
+            // The issuer holds 3000 KSM beyond the unclaimed rewards, but not all unclaimed KMA
+            assert_ok!(<Runtime as Config>::MultiCurrency::transfer(
+                KSM,
+                &BOB,
+                &reward_issuer,
+                5000
+            ));
+            assert_ok!(<Runtime as Config>::MultiCurrency::transfer(
+                KMA,
+                &BOB,
+                &reward_issuer,
+                500
+            ));
+            let old = PoolInfoV1 {
+                tokens_proportion: BTreeMap::from([(KSM, Perbill::from_percent(100))]),
+                basic_token: (KSM, Perbill::from_percent(100)),
+                total_shares: 1000,
+                basic_rewards: BTreeMap::from([(KSM, 1000), (KMA, 500)]),
+                rewards: BTreeMap::from([(KSM, (3000, 1000)), (KMA, (1000, 0))]),
+                state: PoolState::Ongoing,
+                keeper,
+                reward_issuer: reward_issuer.clone(),
+                gauge: None,
+                block_startup: Some(1),
+                min_deposit_to_start: 0,
+                after_block_to_start: 0,
+                withdraw_limit_time: 0,
+                claim_limit_time: 0,
+                withdraw_limit_count: 1,
+                last_update_block: 1,
+            };
+            unhashed::put(&PoolInfos::<Runtime>::hashed_key_for(pool_id), &old);
+            StorageVersion::new(1).put::<Farming>();
+
+            RewardScheduleMigration::<Runtime>::on_runtime_upgrade();
+            assert_eq!(Farming::on_chain_storage_version(), StorageVersion::new(2));
+            let pool_info = Farming::pool_infos(pool_id).unwrap();
+            assert_eq!(
+                pool_info.reward_budget,
+                BTreeMap::from([(KSM, 3000), (KMA, 0)])
+            );
+
+            // Funds sent to the issuer after the upgrade are not charged, so KMA runs out
+            assert_ok!(<Runtime as Config>::MultiCurrency::transfer(
+                KMA,
+                &BOB,
+                &reward_issuer,
+                10_000
+            ));
+            System::set_block_number(2);
+            let pool_info = Farming::updated_pool_info(pool_id).unwrap();
+            assert_eq!(pool_info.state, PoolState::Dead);
+            assert_eq!(pool_info.rewards.get(&KSM), Some(&(3000, 1000)));
+
+            // Charging KMA restarts the pool until the migrated KSM budget runs out
+            assert_ok!(Farming::charge(
+                RuntimeOrigin::signed(BOB),
+                pool_id,
+                vec![(KMA, 5000)]
+            ));
+            assert_eq!(Farming::pool_runway(pool_id), Ok(Some(3)));
+        })
+}
+
+#[test]
+fn incentive_pool_should_work() {
+    ExtBuilder::default()
+        .one_hundred_for_alice_n_bob()
+        .build()
+        .execute_with(|| {
+            let lp_token = register_lp_token();
+            assert_noop!(
+                Farming::create_incentive_pool(
+                    RuntimeOrigin::signed(BOB),
+                    KSM,
+                    BoundedVec::truncate_from(vec![(KSM, 1000)]),
+                    0, // min_deposit_to_start
+                    0, // after_block_to_start
+                    0, // withdraw_limit_time
+                    0, // claim_limit_time
+                    5, // withdraw_limit_count
+                    Some(10)
+                ),
+                Error::<Runtime>::NotLpAsset
+            );
+            assert_noop!(
+                Farming::create_incentive_pool(
+                    RuntimeOrigin::signed(BOB),
+                    lp_token,
+                    BoundedVec::truncate_from(vec![(KSM, 1000)]),
+                    0, // min_deposit_to_start
+                    0, // after_block_to_start
+                    0, // withdraw_limit_time
+                    0, // claim_limit_time
+                    5, // withdraw_limit_count
+                    Some(0)
+                ),
+                Error::<Runtime>::InvalidPoolParameter
+            );
+            assert_ok!(Farming::create_incentive_pool(
+                RuntimeOrigin::signed(BOB),
+                lp_token,
+                BoundedVec::truncate_from(vec![(KSM, 1000)]),
+                0, // min_deposit_to_start
+                0, // after_block_to_start
+                0, // withdraw_limit_time
+                0, // claim_limit_time
+                5, // withdraw_limit_count
+                Some(10)
+            ));
+            let pool_id = 0;
+            assert_eq!(Farming::pool_creators(pool_id), Some((BOB, 1000)));
+            assert_eq!(Balances::reserved_balance(&BOB), 1000);
+            let pool_info = Farming::pool_infos(pool_id).unwrap();
+            assert_eq!(pool_info.state, PoolState::UnCharged);
+            assert_eq!(pool_info.end_block, Some(10));
+            assert_eq!(
+                pool_info.tokens_proportion,
+                BTreeMap::from([(lp_token, Perbill::from_percent(100))])
+            );
+
+            assert_noop!(
+                Farming::charge(RuntimeOrigin::signed(CHARLIE), pool_id, vec![(KSM, 1000)]),
+                Error::<Runtime>::NotPoolCreator
+            );
+            assert_ok!(Farming::charge(
+                RuntimeOrigin::signed(BOB),
+                pool_id,
+                vec![(KSM, 100_000)]
+            ));
+            assert_ok!(<Runtime as Config>::MultiCurrency::deposit(
+                lp_token, &ALICE, 1000
+            ));
+            assert_ok!(Farming::deposit(
+                RuntimeOrigin::signed(ALICE),
+                pool_id,
+                1000,
+                None
+            ));
+            assert_eq!(Assets::balance(lp_token, &ALICE), 0);
+            assert_noop!(
+                Farming::reclaim_rewards(RuntimeOrigin::signed(BOB), pool_id),
+                Error::<Runtime>::InvalidPoolState
+            );
+
+            // The pool started at block 1 and blocks 2 to 10 accrue 1000
+            System::set_block_number(20);
+            assert_noop!(
+                Farming::reclaim_rewards(RuntimeOrigin::signed(CHARLIE), pool_id),
+                Error::<Runtime>::NotPoolCreator
+            );
+            assert_ok!(Farming::reclaim_rewards(
+                RuntimeOrigin::signed(BOB),
+                pool_id
+            ));
+            assert_eq!(Assets::balance(KSM, &BOB), 9_991_000);
+            let pool_info = Farming::pool_infos(pool_id).unwrap();
+            assert_eq!(pool_info.state, PoolState::Dead);
+            assert_eq!(pool_info.rewards.get(&KSM), Some(&(9000, 0)));
+            assert!(pool_info.reward_budget.is_empty());
+
+            assert_ok!(Farming::claim(RuntimeOrigin::signed(ALICE), pool_id));
+            assert_eq!(Assets::balance(KSM, &ALICE), 12_000);
+
+            assert_ok!(Farming::set_retire_limit(RuntimeOrigin::signed(ALICE), 10));
+            assert_noop!(
+                Farming::retire_pool(RuntimeOrigin::signed(CHARLIE), pool_id),
+                Error::<Runtime>::NotPoolCreator
+            );
+            assert_ok!(Farming::retire_pool(RuntimeOrigin::signed(BOB), pool_id));
+            assert_eq!(Assets::balance(lp_token, &ALICE), 1000);
+            assert_noop!(
+                Farming::kill_pool(RuntimeOrigin::signed(CHARLIE), pool_id),
+                Error::<Runtime>::NotPoolCreator
+            );
+            assert_ok!(Farming::kill_pool(RuntimeOrigin::signed(BOB), pool_id));
+            assert_eq!(Farming::pool_creators(pool_id), None);
+            assert_eq!(Farming::pool_infos(pool_id), None);
+            assert_eq!(Balances::reserved_balance(&BOB), 0);
+        })
+}
+
+#[test]
+fn create_incentive_pool_checks_limits() {
+    ExtBuilder::default()
+        .one_hundred_for_alice_n_bob()
+        .build()
+        .execute_with(|| {
+            let lp_token = register_lp_token();
+            let create = |withdraw_limit_time, claim_limit_time, withdraw_limit_count| {
+                Farming::create_incentive_pool(
+                    RuntimeOrigin::signed(BOB),
+                    lp_token,
+                    BoundedVec::truncate_from(vec![(KSM, 1000)]),
+                    0, // min_deposit_to_start
+                    0, // after_block_to_start
+                    withdraw_limit_time,
+                    claim_limit_time,
+                    withdraw_limit_count,
+                    None,
+                )
+            };
+            // no withdrawal would ever be allowed
+            assert_noop!(create(0, 0, 0), Error::<Runtime>::InvalidPoolParameter);
+            // longer than `MaxWithdrawLimitTime`
+            assert_noop!(create(101, 0, 1), Error::<Runtime>::InvalidPoolParameter);
+            // longer than `MaxClaimLimitTime`
+            assert_noop!(create(0, 101, 1), Error::<Runtime>::InvalidPoolParameter);
+            // more reward tokens than `MaxBasicRewards` do not decode
+            let basic_rewards: Vec<(CalamariAssetId, Balance)> =
+                (0..5).map(|i| (i, 1000)).collect();
+            assert!(BoundedVec::<
+                (CurrencyIdOf<Runtime>, Balance),
+                <Runtime as Config>::MaxBasicRewards,
+            >::decode(&mut &basic_rewards.encode()[..])
+            .is_err());
+
+            assert_ok!(create(100, 100, 1));
+            assert_eq!(Farming::pool_infos(0).unwrap().withdraw_limit_count, 1);
+        });
+}
+
+#[test]
+fn rpc_views_should_work() {
+    ExtBuilder::default()
+        .one_hundred_for_alice_n_bob()
+        .build()
+        .execute_with(|| {
+            let (pool_id, tokens) = init_no_gauge();
+            assert_eq!(
+                Farming::get_pool_summaries(),
+                vec![PoolSummary {
+                    pid: pool_id,
+                    state: PoolState::Charged,
+                    tokens_proportion: vec![(KSM, Perbill::from_percent(100))],
+                    total_shares: tokens,
+                    basic_rewards: vec![(KSM, 1000)],
+                    gauge: None,
+                    block_startup: None,
+                    end_block: None,
+                }]
+            );
+
+            // 1000 KSM rewarded per block for 1000 KSM staked
+            let prices = BTreeMap::from([(KSM, FixedU128::from_u32(1))]);
+            assert_eq!(
+                Farming::get_pool_apr(pool_id, &prices, 100),
+                Some(FixedU128::from_u32(100))
+            );
+            assert_eq!(Farming::get_pool_apr(pool_id, &BTreeMap::new(), 100), None);
+            assert_eq!(Farming::get_pool_apr(1, &prices, 100), None);
+
+            System::set_block_number(2);
+            assert_eq!(
+                Farming::get_user_positions(&ALICE),
+                vec![UserPosition {
+                    pid: pool_id,
+                    share: tokens,
+                    boost_share: 0,
+                    pending_rewards: vec![(KSM, 1000)],
+                    withdraw_list: vec![],
+                }]
+            );
+            assert_ok!(Farming::withdraw(
+                RuntimeOrigin::signed(ALICE),
+                pool_id,
+                Some(400)
+            ));
+            assert_eq!(
+                Farming::get_user_positions(&ALICE),
+                vec![UserPosition {
+                    pid: pool_id,
+                    share: 600,
+                    boost_share: 0,
+                    pending_rewards: vec![],
+                    withdraw_list: vec![(12, 400)],
+                }]
+            );
+            assert!(Farming::get_user_positions(&BOB).is_empty());
+        })
+}
+
+#[test]
+fn ve_lock_should_work() {
+    ExtBuilder::default()
+        .one_hundred_for_alice_n_bob()
+        .build()
+        .execute_with(|| {
+            assert_noop!(
+                Farming::ve_lock(RuntimeOrigin::signed(ALICE), 1000, 11),
+                Error::<Runtime>::InvalidLockDuration
+            );
+            assert_noop!(
+                Farming::ve_lock(RuntimeOrigin::signed(ALICE), 0, 5),
+                Error::<Runtime>::InvalidLockDuration
+            );
+            assert_noop!(
+                Farming::ve_lock(RuntimeOrigin::signed(ALICE), 5000, 5),
+                Error::<Runtime>::NotEnoughBalance
+            );
+            assert_ok!(Farming::ve_lock(RuntimeOrigin::signed(ALICE), 1000, 5));
+            assert_eq!(
+                Farming::ve_locks(&ALICE),
+                Some(VeLock {
+                    amount: 1000,
+                    end_epoch: 5
+                })
+            );
+            assert_eq!(Balances::usable_balance(&ALICE), 2000);
+            assert_eq!(Farming::ve_power(&ALICE, 0), 500);
+            assert_eq!(Farming::total_ve_power(0), 500);
+
+            assert_noop!(
+                Farming::ve_unlock(RuntimeOrigin::signed(BOB)),
+                Error::<Runtime>::VeLockNotExist
+            );
+            assert_noop!(
+                Farming::ve_unlock(RuntimeOrigin::signed(ALICE)),
+                Error::<Runtime>::VeLockNotExpired
+            );
+            assert_ok!(Farming::ve_lock(RuntimeOrigin::signed(BOB), 1000, 10));
+            assert_eq!(Farming::total_ve_power(0), 1500);
+
+            // The voting power decays linearly until the end of the lock
+            System::set_block_number(20);
+            assert_eq!(Farming::current_epoch(), 2);
+            assert_eq!(Farming::ve_power(&ALICE, 2), 300);
+            assert_eq!(Farming::ve_power(&BOB, 2), 800);
+            assert_eq!(Farming::total_ve_power(2), 1100);
+
+            assert_ok!(Farming::ve_lock(RuntimeOrigin::signed(ALICE), 500, 8));
+            assert_eq!(
+                Farming::ve_locks(&ALICE),
+                Some(VeLock {
+                    amount: 1500,
+                    end_epoch: 10
+                })
+            );
+            assert_eq!(Farming::ve_power(&ALICE, 2), 1200);
+            assert_eq!(Farming::total_ve_power(2), 2000);
+
+            System::set_block_number(100);
+            assert_eq!(Farming::total_ve_power(10), 0);
+            assert_ok!(Farming::ve_unlock(RuntimeOrigin::signed(ALICE)));
+            assert_eq!(Farming::ve_locks(&ALICE), None);
+            assert_eq!(Balances::usable_balance(&ALICE), 3000);
+            assert_eq!(Farming::ve_supply().amount, 0);
+            assert_noop!(
+                Farming::ve_lock(RuntimeOrigin::signed(BOB), 1000, 5),
+                Error::<Runtime>::VeLockExpired
+            );
+        })
+}
+
+#[test]
+fn boost_should_work() {
+    ExtBuilder::default()
+        .one_hundred_for_alice_n_bob()
+        .build()
+        .execute_with(|| {
+            let (pool_id, tokens) = init_no_gauge();
+            assert_ok!(Farming::deposit(
+                RuntimeOrigin::signed(BOB),
+                pool_id,
+                tokens,
+                None
+            ));
+            assert_ok!(Farming::update_boost(
+                RuntimeOrigin::signed(CHARLIE),
+                ALICE,
+                pool_id
+            ));
+            assert_eq!(Farming::boost_shares(pool_id, &ALICE), 0);
+
+            // ALICE owns all the voting power, more than her half of the staked shares
+            assert_ok!(Farming::ve_lock(RuntimeOrigin::signed(ALICE), 1000, 10));
+            assert_ok!(Farming::update_boost(
+                RuntimeOrigin::signed(CHARLIE),
+                ALICE,
+                pool_id
+            ));
+            assert_eq!(Farming::boost_shares(pool_id, &ALICE), 1000);
+            assert_eq!(Farming::pool_boost_shares(pool_id), 1000);
+            assert_eq!(
+                Farming::shares_and_withdrawn_rewards(pool_id, &ALICE)
+                    .unwrap()
+                    .share,
+                2000
+            );
+            assert_eq!(Farming::pool_infos(pool_id).unwrap().total_shares, 3000);
+
+            // Blocks 2 to 10 accrue 9000 split 2:1
+            System::set_block_number(10);
             assert_ok!(Farming::claim(RuntimeOrigin::signed(ALICE), pool_id));
-            assert_eq!(Assets::balance(KSM, &ALICE), 4017);
+            assert_ok!(Farming::claim(RuntimeOrigin::signed(BOB), pool_id));
+            assert_eq!(Assets::balance(KSM, &ALICE), 8000);
+            assert_eq!(Assets::balance(KSM, &BOB), 9_902_000);
+
+            // ALICE keeps 9% of the voting power against 50% of the staked shares
+            assert_ok!(Farming::ve_lock(RuntimeOrigin::signed(BOB), 9100, 10));
+            assert_eq!(Farming::total_ve_power(1), 10_000);
+            assert_ok!(Farming::update_boost(
+                RuntimeOrigin::signed(BOB),
+                ALICE,
+                pool_id
+            ));
+            assert_eq!(Farming::boost_shares(pool_id, &ALICE), 180);
+            assert_ok!(Farming::update_boost(
+                RuntimeOrigin::signed(BOB),
+                BOB,
+                pool_id
+            ));
+            assert_eq!(Farming::boost_shares(pool_id, &BOB), 1000);
+            assert_eq!(Farming::pool_boost_shares(pool_id), 1180);
+            assert_eq!(Farming::pool_infos(pool_id).unwrap().total_shares, 3180);
+
+            // Withdrawing all the staked share drops the boost share
+            assert_ok!(Farming::withdraw(
+                RuntimeOrigin::signed(ALICE),
+                pool_id,
+                None
+            ));
+            assert_eq!(Farming::boost_shares(pool_id, &ALICE), 0);
+            assert_eq!(Farming::pool_boost_shares(pool_id), 1000);
+            assert_eq!(Farming::pool_infos(pool_id).unwrap().total_shares, 2000);
+        })
+}
+
+#[test]
+fn vote_rewards_should_work() {
+    ExtBuilder::default()
+        .one_hundred_for_alice_n_bob()
+        .build()
+        .execute_with(|| {
+            let (pool_id, tokens) = init_no_gauge();
+            let pool_id_1 = 1;
+            assert_ok!(Farming::create_farming_pool(
+                RuntimeOrigin::signed(ALICE),
+                vec![(KSM, Perbill::from_percent(100))],
+                vec![(KSM, 1000)],
+                None,
+                0,  // min_deposit_to_start
+                0,  // after_block_to_start
+                10, // withdraw_limit_time
+                0,  // claim_limit_time
+                1   // withdraw_limit_count
+            ));
+            assert_ok!(Farming::charge(
+                RuntimeOrigin::signed(BOB),
+                pool_id_1,
+                vec![(KSM, 100000)]
+            ));
+            assert_ok!(Farming::deposit(
+                RuntimeOrigin::signed(BOB),
+                pool_id_1,
+                tokens,
+                None
+            ));
+
+            assert_noop!(
+                Farming::vote(
+                    RuntimeOrigin::signed(ALICE),
+                    BoundedVec::truncate_from(vec![(pool_id, Percent::from_percent(100))])
+                ),
+                Error::<Runtime>::NoVotingPower
+            );
+            assert_ok!(Farming::ve_lock(RuntimeOrigin::signed(ALICE), 1000, 10));
+            assert_ok!(Farming::ve_lock(RuntimeOrigin::signed(BOB), 3000, 10));
+            assert_noop!(
+                Farming::vote(
+                    RuntimeOrigin::signed(ALICE),
+                    BoundedVec::truncate_from(vec![
+                        (pool_id, Percent::from_percent(60)),
+                        (pool_id_1, Percent::from_percent(50))
+                    ])
+                ),
+                Error::<Runtime>::InvalidVotes
+            );
+            assert_noop!(
+                Farming::vote(
+                    RuntimeOrigin::signed(ALICE),
+                    BoundedVec::truncate_from(vec![(5, Percent::from_percent(100))])
+                ),
+                Error::<Runtime>::PoolDoesNotExist
+            );
+
+            assert_noop!(
+                Farming::set_vote_rewards(
+                    RuntimeOrigin::signed(BOB),
+                    BoundedVec::truncate_from(vec![(KSM, 4000)])
+                ),
+                DispatchError::BadOrigin
+            );
+            assert_ok!(Farming::set_vote_rewards(
+                RuntimeOrigin::signed(ALICE),
+                BoundedVec::truncate_from(vec![(KSM, 4000)])
+            ));
+            assert_ok!(Farming::vote(
+                RuntimeOrigin::signed(ALICE),
+                BoundedVec::truncate_from(vec![
+                    (pool_id, Percent::from_percent(50)),
+                    (pool_id_1, Percent::from_percent(50))
+                ])
+            ));
+            assert_eq!(Farming::pool_votes(0, pool_id_1), 500);
+
+            // Voting again in the same epoch replaces the votes
+            assert_ok!(Farming::vote(
+                RuntimeOrigin::signed(ALICE),
+                BoundedVec::truncate_from(vec![(pool_id, Percent::from_percent(100))])
+            ));
+            assert_ok!(Farming::vote(
+                RuntimeOrigin::signed(BOB),
+                BoundedVec::truncate_from(vec![(pool_id_1, Percent::from_percent(100))])
+            ));
+            assert_eq!(Farming::pool_votes(0, pool_id), 1000);
+            assert_eq!(Farming::pool_votes(0, pool_id_1), 3000);
+            assert_eq!(
+                Farming::user_votes(&ALICE),
+                Some((0, vec![(pool_id, 1000)]))
+            );
+
+            // The rewards of the epoch are fixed by its first vote
+            assert_ok!(Farming::set_vote_rewards(
+                RuntimeOrigin::signed(ALICE),
+                BoundedVec::truncate_from(vec![(KSM, 8000)])
+            ));
+            assert_eq!(
+                Farming::epoch_votes(0),
+                Some(EpochVoteInfo {
+                    total_votes: 4000,
+                    rewards: BTreeMap::from([(KSM, 4000)])
+                })
+            );
+
+            assert_noop!(
+                Farming::distribute_vote_rewards(RuntimeOrigin::signed(CHARLIE), 0, pool_id),
+                Error::<Runtime>::EpochNotEnded
+            );
+            System::set_block_number(10);
+            assert_ok!(<Runtime as Config>::MultiCurrency::deposit(
+                KSM,
+                &TREASURY_ACCOUNT,
+                10_000
+            ));
+            assert_ok!(Farming::distribute_vote_rewards(
+                RuntimeOrigin::signed(CHARLIE),
+                0,
+                pool_id
+            ));
+            assert_noop!(
+                Farming::distribute_vote_rewards(RuntimeOrigin::signed(CHARLIE), 0, pool_id),
+                Error::<Runtime>::NoVotes
+            );
+            assert_ok!(Farming::distribute_vote_rewards(
+                RuntimeOrigin::signed(CHARLIE),
+                0,
+                pool_id_1
+            ));
+            assert_eq!(Assets::balance(KSM, &TREASURY_ACCOUNT), 6000);
+
+            // Blocks 2 to 10 accrue 9000 on top of the 1000 vote rewards
+            assert_eq!(
+                Farming::pool_infos(pool_id).unwrap().rewards.get(&KSM),
+                Some(&(10_000, 0))
+            );
+            assert_ok!(Farming::claim(RuntimeOrigin::signed(ALICE), pool_id));
+            assert_eq!(Assets::balance(KSM, &ALICE), 12_000);
+        })
+}
+
+#[test]
+fn liquidity_should_work() {
+    ExtBuilder::default()
+        .one_hundred_for_alice_n_bob()
+        .build()
+        .execute_with(|| {
+            let lp_token = register_lp_token();
+            let asset_1 = 9;
+            assert_ok!(<Runtime as Config>::MultiCurrency::deposit(
+                asset_1, &ALICE, 2000
+            ));
+            for (token, withdraw_limit_time) in [(lp_token, 0), (lp_token, 10), (KSM, 0)] {
+                assert_ok!(Farming::create_farming_pool(
+                    RuntimeOrigin::signed(ALICE),
+                    vec![(token, Perbill::from_percent(100))],
+                    vec![(KSM, 1000)],
+                    None,
+                    0,                   // min_deposit_to_start
+                    0,                   // after_block_to_start
+                    withdraw_limit_time, // withdraw_limit_time
+                    0,                   // claim_limit_time
+                    5                    // withdraw_limit_count
+                ));
+            }
+            for pool_id in 0..3 {
+                assert_ok!(Farming::charge(
+                    RuntimeOrigin::signed(BOB),
+                    pool_id,
+                    vec![(KSM, 100000)]
+                ));
+            }
+
+            assert_noop!(
+                Farming::add_liquidity_and_deposit(
+                    RuntimeOrigin::signed(ALICE),
+                    0,
+                    KMA,
+                    KSM,
+                    1000,
+                    1000,
+                    0,
+                    0,
+                    None
+                ),
+                Error::<Runtime>::NotLpAsset
+            );
+            assert_noop!(
+                Farming::add_liquidity_and_deposit(
+                    RuntimeOrigin::signed(ALICE),
+                    2,
+                    KMA,
+                    asset_1,
+                    1000,
+                    1000,
+                    0,
+                    0,
+                    None
+                ),
+                Error::<Runtime>::NotLiquidityPool
+            );
+            assert_noop!(
+                Farming::add_liquidity_and_deposit(
+                    RuntimeOrigin::signed(ALICE),
+                    0,
+                    KMA,
+                    asset_1,
+                    1000,
+                    800,
+                    900,
+                    0,
+                    None
+                ),
+                DispatchError::Other("insufficient amount")
+            );
+            assert_ok!(Farming::add_liquidity_and_deposit(
+                RuntimeOrigin::signed(ALICE),
+                0,
+                KMA,
+                asset_1,
+                1000,
+                1200,
+                900,
+                900,
+                None
+            ));
+            assert_eq!(Balances::free_balance(&ALICE), 2000);
+            assert_eq!(Assets::balance(asset_1, &ALICE), 1000);
+            assert_eq!(Assets::balance(lp_token, &ALICE), 0);
+            assert_eq!(
+                Farming::shares_and_withdrawn_rewards(0, &ALICE)
+                    .unwrap()
+                    .share,
+                1000
+            );
+
+            assert_noop!(
+                Farming::withdraw_and_remove_liquidity(
+                    RuntimeOrigin::signed(ALICE),
+                    0,
+                    KMA,
+                    asset_1,
+                    Some(400),
+                    500,
+                    0
+                ),
+                DispatchError::Other("insufficient amount")
+            );
+            assert_ok!(Farming::withdraw_and_remove_liquidity(
+                RuntimeOrigin::signed(ALICE),
+                0,
+                KMA,
+                asset_1,
+                Some(400),
+                400,
+                400
+            ));
+            assert_eq!(Balances::free_balance(&ALICE), 2400);
+            assert_eq!(Assets::balance(asset_1, &ALICE), 1400);
+            assert_eq!(
+                Farming::shares_and_withdrawn_rewards(0, &ALICE)
+                    .unwrap()
+                    .share,
+                600
+            );
+
+            // The withdrawal of the second pool is locked for 10 blocks
+            assert_ok!(Farming::add_liquidity_and_deposit(
+                RuntimeOrigin::signed(ALICE),
+                1,
+                KMA,
+                asset_1,
+                500,
+                500,
+                500,
+                500,
+                None
+            ));
+            assert_noop!(
+                Farming::withdraw_and_remove_liquidity(
+                    RuntimeOrigin::signed(ALICE),
+                    1,
+                    KMA,
+                    asset_1,
+                    None,
+                    0,
+                    0
+                ),
+                Error::<Runtime>::NoLiquidity
+            );
         })
 }
@@ -62,7 +62,10 @@ use core::marker::PhantomData;
 use errors::{ReceiverLedgerError, SenderLedgerError, TransferLedgerError};
 use frame_support::{
     pallet_prelude::*,
-    traits::tokens::{ExistenceRequirement, Provenance},
+    traits::{
+        tokens::{ExistenceRequirement, Provenance},
+        Contains,
+    },
     transactional, PalletId,
 };
 use frame_system::pallet_prelude::*;
@@ -143,6 +146,9 @@ pub mod pallet {
 
         /// Pallet ID
         type PalletId: Get<PalletId>;
+
+        /// Assets which may be transformed from public to private.
+        type PrivateAssetFilter: Contains<StandardAssetId>;
     }
 
     /// Fungible Ledger Implementation for [`Config`]
@@ -226,6 +232,12 @@ pub mod pallet {
                     Error::<T>::ZeroTransfer
                 );
             }
+            if let Some(asset_id) = post.asset_id.and_then(id_from_field) {
+                ensure!(
+                    T::PrivateAssetFilter::contains(&asset_id),
+                    Error::<T>::AssetNotAllowed
+                );
+            }
             Self::post_transaction(None, vec![origin], vec![], post)
         }
 
@@ -387,6 +399,11 @@ pub mod pallet {
         /// The transfer had an invalid shape.
         InvalidShape,
 
+        /// Asset Not Allowed
+        ///
+        /// The asset of the transfer cannot be transformed from public to private.
+        AssetNotAllowed,
+
         /// Invalid Authorization Signature
         InvalidAuthorizationSignature,
 
@@ -17,7 +17,7 @@
 use frame_support::{
     pallet_prelude::DispatchResult,
     parameter_types,
-    traits::{AsEnsureOriginWithArg, ConstU128, ConstU32, IsInVec},
+    traits::{fungibles, AsEnsureOriginWithArg, ConstU128, ConstU32, Get, IsInVec},
     PalletId,
 };
 use frame_system::{EnsureNever, EnsureRoot};
@@ -207,6 +207,14 @@ impl AssetRegistry for MantaAssetRegistry {
             metadata.is_frozen,
         )
     }
+
+    fn destroy_asset(asset_id: &StandardAssetId) -> DispatchResult {
+        let limit = <Test as pallet_assets::Config>::RemoveItemsLimit::get();
+        <Assets as fungibles::Destroy<AccountId32>>::start_destroy(*asset_id, None)?;
+        <Assets as fungibles::Destroy<AccountId32>>::destroy_accounts(*asset_id, limit)?;
+        <Assets as fungibles::Destroy<AccountId32>>::destroy_approvals(*asset_id, limit)?;
+        <Assets as fungibles::Destroy<AccountId32>>::finish_destroy(*asset_id)
+    }
 }
 
 parameter_types! {
@@ -249,6 +257,10 @@ impl AssetConfig<Test> for MantaAssetConfig {
     type FungibleLedger = NativeAndNonNative<Test, MantaAssetConfig, Balances, Assets>;
 }
 
+parameter_types! {
+    pub const ProposedBounds: (u128, u128) = (1, u128::MAX);
+}
+
 impl pallet_asset_manager::Config for Test {
     type RuntimeEvent = RuntimeEvent;
     type AssetId = StandardAssetId;
@@ -262,6 +274,17 @@ impl pallet_asset_manager::Config for Test {
     type TokenNameMaxLen = ConstU32<100>;
     type TokenSymbolMaxLen = ConstU32<100>;
     type PermissionlessAssetRegistryCost = ConstU128<1000>;
+    type SiblingAssetOrigin = EnsureNever<(u32, AccountId32)>;
+    type VetoOrigin = EnsureRoot<AccountId32>;
+    type AssetChallengePeriod = ConstU32<10>;
+    type MaxActivationsPerBlock = ConstU32<10>;
+    type AssetProposalDeposit = ConstU128<1_000>;
+    type ProposedMinBalanceBounds = ProposedBounds;
+    type ProposedUnitsPerSecondBounds = ProposedBounds;
+    type NativePrice = ();
+    type NativeUnitsPerSecond = ConstU128<1_000_000_000>;
+    type SpotPriceAveragingPeriod = ConstU32<10>;
+    type MaxDynamicPricedAssets = ConstU32<10>;
 }
 
 parameter_types! {
@@ -273,6 +296,7 @@ impl crate::Config for Test {
     type WeightInfo = crate::weights::SubstrateWeight<Self>;
     type PalletId = MantaPayPalletId;
     type AssetConfig = MantaAssetConfig;
+    type PrivateAssetFilter = frame_support::traits::Everything;
 }
 
 parameter_types! {
@@ -19,8 +19,8 @@
 use frame_support::{
     parameter_types,
     traits::{
-        AsEnsureOriginWithArg, ConstU128, ConstU16, ConstU32, ConstU64, Everything, GenesisBuild,
-        IsInVec,
+        fungibles, AsEnsureOriginWithArg, ConstU128, ConstU16, ConstU32, ConstU64, Everything,
+        GenesisBuild, Get, IsInVec,
     },
     weights::RuntimeDbWeight,
     PalletId,
@@ -236,6 +236,14 @@ impl AssetRegistry for MantaAssetRegistry {
             metadata.is_frozen,
         )
     }
+
+    fn destroy_asset(asset_id: &StandardAssetId) -> DispatchResult {
+        let limit = <Test as pallet_assets::Config>::RemoveItemsLimit::get();
+        <Assets as fungibles::Destroy<AccountId32>>::start_destroy(*asset_id, None)?;
+        <Assets as fungibles::Destroy<AccountId32>>::destroy_accounts(*asset_id, limit)?;
+        <Assets as fungibles::Destroy<AccountId32>>::destroy_approvals(*asset_id, limit)?;
+        <Assets as fungibles::Destroy<AccountId32>>::finish_destroy(*asset_id)
+    }
 }
 
 parameter_types! {
@@ -278,6 +286,10 @@ impl AssetConfig<Test> for MantaAssetConfig {
     type FungibleLedger = NativeAndNonNative<Test, MantaAssetConfig, Balances, Assets>;
 }
 
+parameter_types! {
+    pub const ProposedBounds: (u128, u128) = (1, u128::MAX);
+}
+
 impl pallet_asset_manager::Config for Test {
     type RuntimeEvent = RuntimeEvent;
     type AssetId = StandardAssetId;
@@ -290,6 +302,17 @@ impl pallet_asset_manager::Config for Test {
     type TokenNameMaxLen = ConstU32<100>;
     type TokenSymbolMaxLen = ConstU32<100>;
     type PermissionlessAssetRegistryCost = ConstU128<1000>;
+    type SiblingAssetOrigin = EnsureNever<(u32, AccountId32)>;
+    type VetoOrigin = EnsureRoot<AccountId32>;
+    type AssetChallengePeriod = ConstU32<10>;
+    type MaxActivationsPerBlock = ConstU32<10>;
+    type AssetProposalDeposit = ConstU128<1_000>;
+    type ProposedMinBalanceBounds = ProposedBounds;
+    type ProposedUnitsPerSecondBounds = ProposedBounds;
+    type NativePrice = ();
+    type NativeUnitsPerSecond = ConstU128<1_000_000_000>;
+    type SpotPriceAveragingPeriod = ConstU32<10>;
+    type MaxDynamicPricedAssets = ConstU32<10>;
     type WeightInfo = ();
 }
 
@@ -302,6 +325,7 @@ impl pallet_manta_pay::Config for Test {
     type WeightInfo = pallet_manta_pay::weights::SubstrateWeight<Test>;
     type PalletId = MantaPayPalletId;
     type AssetConfig = MantaAssetConfig;
+    type PrivateAssetFilter = Everything;
 }
 
 parameter_types! {
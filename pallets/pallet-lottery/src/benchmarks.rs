@@ -17,10 +17,14 @@
 #![cfg(feature = "runtime-benchmarks")]
 
 //! Benchmarking
-use crate::{Call, Config, Pallet, Request};
-use frame_benchmarking::{benchmarks, impl_benchmark_test_suite, Zero};
+use crate::{
+    Call, Config, DepositStrategyKind, Pallet, PotBacking, Request, StakingStrategyConfig,
+    WithdrawStrategyKind,
+};
+use frame_benchmarking::{account, benchmarks_instance_pallet, impl_benchmark_test_suite, Zero};
 use frame_support::{
     assert_ok,
+    pallet_prelude::BoundedVec,
     traits::{Currency, EstimateCallFee, Get, OnFinalize, OnInitialize},
 };
 use frame_system::RawOrigin;
@@ -28,13 +32,14 @@ use pallet_parachain_staking::{
     benchmarks::{create_funded_collator, create_funded_user, parachain_staking_on_finalize},
     BalanceOf, Pallet as Staking,
 };
-use sp_runtime::Saturating;
+use sp_runtime::{Perbill, Saturating};
+use sp_std::prelude::*;
 
 const MAX_COLLATOR_COUNT: u32 = 63;
 const USER_SEED: u32 = 696969;
 
 /// Run to end block and author
-fn roll_rounds_and_author<T: Config>(rounds: u32) {
+fn roll_rounds_and_author<T: pallet_parachain_staking::Config>(rounds: u32) {
     let total_rounds = rounds + 1u32;
     let round_length: T::BlockNumber = Staking::<T>::round().length.into();
     let mut now = <frame_system::Pallet<T>>::block_number() + 1u32.into();
@@ -53,14 +58,14 @@ fn roll_rounds_and_author<T: Config>(rounds: u32) {
     }
 }
 
-fn fund_lottery_account<T: Config>(bal: BalanceOf<T>) {
+fn fund_lottery_account<T: Config<I>, I: 'static>(bal: BalanceOf<T>) {
     <T as pallet_parachain_staking::Config>::Currency::deposit_creating(
-        &Pallet::<T>::account_id(),
+        &Pallet::<T, I>::account_id(),
         bal,
     );
 }
 
-fn register_collators<T: Config>(number: u32) {
+fn register_collators<T: pallet_parachain_staking::Config>(number: u32) {
     let original_collator_count = Staking::<T>::candidate_pool().len() as u32;
     let mut collator_seed: u32 = 444;
     for _ in 0..number {
@@ -75,41 +80,41 @@ fn register_collators<T: Config>(number: u32) {
     }
 }
 
-fn deposit_prior_users<T: Config>(number: u32, amount: BalanceOf<T>) {
+fn deposit_prior_users<T: Config<I>, I: 'static>(number: u32, amount: BalanceOf<T>) {
     for user in 0..number {
         <frame_system::Pallet<T>>::set_block_number(user.into());
         let (depositor, _) = create_funded_user::<T>("depositor", USER_SEED - 1 - user, amount);
-        assert_ok!(Pallet::<T>::deposit(
+        assert_ok!(Pallet::<T, I>::deposit(
             RawOrigin::Signed(depositor).into(),
             amount
         ));
     }
 }
 
-benchmarks! {
+benchmarks_instance_pallet! {
     // USER DISPATCHABLES
 
     deposit {
         let x in 0u32..1_000u32; // other users that have already deposited to the lottery previously
         let y in 0..MAX_COLLATOR_COUNT; // registered collators
 
-        fund_lottery_account::<T>(Pallet::<T>::gas_reserve());
+        fund_lottery_account::<T, I>(Pallet::<T, I>::gas_reserve());
         let min_delegator_bond = <<T as pallet_parachain_staking::Config>::MinDelegatorStk as Get<BalanceOf<T>>>::get();
         let original_collator_count = Staking::<T>::candidate_pool().len() as u32;
         let deposit_amount: BalanceOf<T> = min_delegator_bond * 10_000u32.into();
         // fill collators
         register_collators::<T>(y);
         assert_eq!(Staking::<T>::candidate_pool().len() as u32, original_collator_count + y);
-        assert_eq!(Pallet::<T>::total_pot(), Zero::zero());
+        assert_eq!(Pallet::<T, I>::total_pot(), Zero::zero());
 
         let original_staked_amount = Staking::<T>::total();
-        deposit_prior_users::<T>(x,deposit_amount);
+        deposit_prior_users::<T, I>(x,deposit_amount);
 
         let (caller, _) = create_funded_user::<T>("caller", USER_SEED, deposit_amount);
     }: _(RawOrigin::Signed(caller.clone()), deposit_amount)
     verify {
-        assert_eq!(Pallet::<T>::active_balance_per_user(caller), deposit_amount);
-        assert_eq!(Pallet::<T>::total_pot(), deposit_amount.saturating_mul((x+1).into()));
+        assert_eq!(Pallet::<T, I>::active_balance_per_user(caller), deposit_amount);
+        assert_eq!(Pallet::<T, I>::total_pot(), deposit_amount.saturating_mul((x+1).into()));
         assert_eq!(Staking::<T>::total(), original_staked_amount + deposit_amount.saturating_mul((x+1).into()));
     }
 
@@ -117,7 +122,7 @@ benchmarks! {
         let x in 0..1_000; // other users that have already deposited to the lottery previously
         let y in 0..MAX_COLLATOR_COUNT; // registered collators
 
-        fund_lottery_account::<T>(Pallet::<T>::gas_reserve());
+        fund_lottery_account::<T, I>(Pallet::<T, I>::gas_reserve());
         let min_delegator_bond = <<T as pallet_parachain_staking::Config>::MinDelegatorStk as Get<BalanceOf<T>>>::get();
         let original_collator_count = Staking::<T>::candidate_pool().len() as u32;
         let deposit_amount: BalanceOf<T> = min_delegator_bond * 10_000u32.into();
@@ -126,30 +131,56 @@ benchmarks! {
         assert_eq!(Staking::<T>::candidate_pool().len() as u32, original_collator_count + y);
 
         let original_staked_amount = Staking::<T>::total();
-        deposit_prior_users::<T>(x,deposit_amount);
+        deposit_prior_users::<T, I>(x,deposit_amount);
 
         let (caller, _) = create_funded_user::<T>("caller", USER_SEED, deposit_amount);
-        assert_ok!(Pallet::<T>::deposit(RawOrigin::Signed(caller.clone()).into(), deposit_amount));
-        assert_eq!(Pallet::<T>::active_balance_per_user(caller.clone()), deposit_amount);
+        assert_ok!(Pallet::<T, I>::deposit(RawOrigin::Signed(caller.clone()).into(), deposit_amount));
+        assert_eq!(Pallet::<T, I>::active_balance_per_user(caller.clone()), deposit_amount);
     }: _(RawOrigin::Signed(caller.clone()), deposit_amount)
     verify {
-        assert!(Pallet::<T>::active_balance_per_user(caller.clone()).is_zero());
+        assert!(Pallet::<T, I>::active_balance_per_user(caller.clone()).is_zero());
         let now = <frame_system::Pallet<T>>::block_number();
         let should_be_request = Request {
             user: caller.clone(),
             block: now,
             balance: deposit_amount,
         };
-        let mut request_queue = Pallet::<T>::withdrawal_request_queue();
+        let mut request_queue = Pallet::<T, I>::withdrawal_request_queue();
         assert_eq!(request_queue.len(),1usize);
         assert_eq!(request_queue.pop().unwrap(), should_be_request);
     }
 
+    instant_withdraw {
+        let x in 0..1_000; // other users that have already deposited to the lottery previously
+        let y in 0..MAX_COLLATOR_COUNT; // registered collators
+
+        fund_lottery_account::<T, I>(Pallet::<T, I>::gas_reserve());
+        let min_delegator_bond = <<T as pallet_parachain_staking::Config>::MinDelegatorStk as Get<BalanceOf<T>>>::get();
+        let original_collator_count = Staking::<T>::candidate_pool().len() as u32;
+        let deposit_amount: BalanceOf<T> = min_delegator_bond * 10_000u32.into();
+        // fill collators
+        register_collators::<T>(y);
+        assert_eq!(Staking::<T>::candidate_pool().len() as u32, original_collator_count + y);
+
+        deposit_prior_users::<T, I>(x,deposit_amount);
+
+        // keep the caller's deposit in the liquidity buffer
+        assert_ok!(Pallet::<T, I>::set_liquidity_buffer_params(RawOrigin::Root.into(), deposit_amount, <T as Config<I>>::MaxInstantWithdrawFee::get()));
+        let (caller, _) = create_funded_user::<T>("caller", USER_SEED, deposit_amount);
+        assert_ok!(Pallet::<T, I>::deposit(RawOrigin::Signed(caller.clone()).into(), deposit_amount));
+        assert_eq!(Pallet::<T, I>::liquidity_buffer(), deposit_amount);
+    }: _(RawOrigin::Signed(caller.clone()), deposit_amount)
+    verify {
+        assert!(Pallet::<T, I>::active_balance_per_user(caller.clone()).is_zero());
+        assert!(Pallet::<T, I>::liquidity_buffer().is_zero());
+        assert!(Pallet::<T, I>::withdrawal_request_queue().is_empty());
+    }
+
     claim_my_winnings {
         let y in 0..MAX_COLLATOR_COUNT; // registered collators
 
         // NOTE: We fund 2x gas reserve to have 1x gas reserve to pay out as winnings
-        fund_lottery_account::<T>(Pallet::<T>::gas_reserve().saturating_add(Pallet::<T>::gas_reserve()));
+        fund_lottery_account::<T, I>(Pallet::<T, I>::gas_reserve().saturating_add(Pallet::<T, I>::gas_reserve()));
 
         let min_delegator_bond = <<T as pallet_parachain_staking::Config>::MinDelegatorStk as Get<BalanceOf<T>>>::get();
         let original_collator_count = Staking::<T>::candidate_pool().len() as u32;
@@ -159,39 +190,39 @@ benchmarks! {
         assert_eq!(Staking::<T>::candidate_pool().len() as u32, original_collator_count + y);
 
         let (caller, _) = create_funded_user::<T>("caller", USER_SEED, deposit_amount);
-        assert_ok!(Pallet::<T>::deposit(RawOrigin::Signed(caller.clone()).into(), deposit_amount));
-        assert_eq!(Pallet::<T>::active_balance_per_user(caller.clone()), deposit_amount);
+        assert_ok!(Pallet::<T, I>::deposit(RawOrigin::Signed(caller.clone()).into(), deposit_amount));
+        assert_eq!(Pallet::<T, I>::active_balance_per_user(caller.clone()), deposit_amount);
         roll_rounds_and_author::<T>(2);
-        assert_ok!(Pallet::<T>::draw_lottery(RawOrigin::Root.into()));
+        assert_ok!(Pallet::<T, I>::draw_lottery(RawOrigin::Root.into()));
         // should have won now
-        let unclaimed_winnings = Pallet::<T>::total_unclaimed_winnings();
+        let unclaimed_winnings = Pallet::<T, I>::total_unclaimed_winnings();
         let account_balance_before = <T as pallet_parachain_staking::Config>::Currency::free_balance(&caller);
-        let fee_estimate  = T::EstimateCallFee::estimate_call_fee(&Call::<T>::claim_my_winnings {  }, None.into());
+        let fee_estimate  = T::EstimateCallFee::estimate_call_fee(&Call::<T, I>::claim_my_winnings {  }, None.into());
         assert!(!unclaimed_winnings.is_zero());
-        assert_eq!(unclaimed_winnings,Pallet::<T>::unclaimed_winnings_by_account(caller.clone()).unwrap());
+        assert_eq!(unclaimed_winnings,Pallet::<T, I>::unclaimed_winnings_by_account(caller.clone()).unwrap());
     }: _(RawOrigin::Signed(caller.clone()))
     verify {
-        assert!(Pallet::<T>::total_unclaimed_winnings().is_zero());
+        assert!(Pallet::<T, I>::total_unclaimed_winnings().is_zero());
         let account_balance_after = <T as pallet_parachain_staking::Config>::Currency::free_balance(&caller);
-        assert!(Pallet::<T>::unclaimed_winnings_by_account(caller.clone()).is_none());
+        assert!(Pallet::<T, I>::unclaimed_winnings_by_account(caller.clone()).is_none());
         assert!(account_balance_after >= account_balance_before + unclaimed_winnings - fee_estimate);
         assert!(account_balance_after <= account_balance_before + unclaimed_winnings);
     }
 
     // ROOT DISPATCHABLES
     start_lottery {
-                fund_lottery_account::<T>(Pallet::<T>::gas_reserve());
+                fund_lottery_account::<T, I>(Pallet::<T, I>::gas_reserve());
     }: _(RawOrigin::Root)
     verify {
-        assert!(Pallet::<T>::next_drawing_at().is_some());
+        assert!(Pallet::<T, I>::next_drawing_at().is_some());
     }
 
     stop_lottery {
-                fund_lottery_account::<T>(Pallet::<T>::gas_reserve());
-        assert_ok!(Pallet::<T>::start_lottery(RawOrigin::Root.into()));
+                fund_lottery_account::<T, I>(Pallet::<T, I>::gas_reserve());
+        assert_ok!(Pallet::<T, I>::start_lottery(RawOrigin::Root.into()));
     }: _(RawOrigin::Root)
     verify {
-        assert!(Pallet::<T>::next_drawing_at().is_none());
+        assert!(Pallet::<T, I>::next_drawing_at().is_none());
     }
 
     draw_lottery {
@@ -199,7 +230,7 @@ benchmarks! {
         let y in 0..MAX_COLLATOR_COUNT; // registered collators
 
         // NOTE: We fund 2x gas reserve to have 1x gas reserve to pay out as winnings
-        fund_lottery_account::<T>(Pallet::<T>::gas_reserve().saturating_add(Pallet::<T>::gas_reserve()));
+        fund_lottery_account::<T, I>(Pallet::<T, I>::gas_reserve().saturating_add(Pallet::<T, I>::gas_reserve()));
 
         let min_delegator_bond = <<T as pallet_parachain_staking::Config>::MinDelegatorStk as Get<BalanceOf<T>>>::get();
         let original_collator_count = Staking::<T>::candidate_pool().len() as u32;
@@ -208,16 +239,16 @@ benchmarks! {
         register_collators::<T>(y);
         assert_eq!(Staking::<T>::candidate_pool().len() as u32, original_collator_count + y);
 
-        deposit_prior_users::<T>(x,deposit_amount);
+        deposit_prior_users::<T, I>(x,deposit_amount);
 
         let (caller, _) = create_funded_user::<T>("caller", USER_SEED, deposit_amount);
-        assert_ok!(Pallet::<T>::deposit(RawOrigin::Signed(caller.clone()).into(), deposit_amount));
-        assert_eq!(Pallet::<T>::active_balance_per_user(&caller), deposit_amount);
+        assert_ok!(Pallet::<T, I>::deposit(RawOrigin::Signed(caller.clone()).into(), deposit_amount));
+        assert_eq!(Pallet::<T, I>::active_balance_per_user(&caller), deposit_amount);
         // roll_rounds_and_author::<T>(2);
     }: _(RawOrigin::Root)
     verify {
         // someone should have won now
-        let unclaimed_winnings = Pallet::<T>::total_unclaimed_winnings();
+        let unclaimed_winnings = Pallet::<T, I>::total_unclaimed_winnings();
         assert!(!unclaimed_winnings.is_zero());
     }
 
@@ -226,7 +257,7 @@ benchmarks! {
     verify {
     }
     set_min_deposit {
-        assert_ok!(Pallet::<T>::set_min_withdraw(RawOrigin::Root.into(),u32::MAX.into()));
+        assert_ok!(Pallet::<T, I>::set_min_withdraw(RawOrigin::Root.into(),u32::MAX.into()));
     }: _(RawOrigin::Root,u32::MAX.into())
     verify {
     }
@@ -238,6 +269,53 @@ benchmarks! {
     }: _(RawOrigin::Root,u32::MAX.into())
     verify {
     }
+    set_staking_strategy {
+        let x in 0..<T as Config<I>>::MaxCollatorBlocklistLen::get(); // blocklisted collators
+
+        let strategy = StakingStrategyConfig {
+            deposit_strategies: BoundedVec::truncate_from(vec![
+                DepositStrategyKind::ReactivateBottomCollators,
+                DepositStrategyKind::SplitToUnderallocatedCollators,
+                DepositStrategyKind::SplitApyWeighted,
+                DepositStrategyKind::StakeToRandomCollator,
+            ]),
+            withdraw_strategies: BoundedVec::truncate_from(vec![
+                WithdrawStrategyKind::UnstakeBlocklistedCollators,
+                WithdrawStrategyKind::UnstakeInactiveCollators,
+                WithdrawStrategyKind::UnstakeLeastApyCollators,
+            ]),
+            max_stake_per_collator: Some(Pallet::<T, I>::min_deposit()),
+            collator_blocklist: BoundedVec::truncate_from((0..x).map(|i| account("collator", i, USER_SEED)).collect()),
+        };
+    }: _(RawOrigin::Root, strategy.clone())
+    verify {
+        assert_eq!(Pallet::<T, I>::staking_strategy(), strategy);
+    }
+    set_pot_backing {
+        // a farming backing is the worst case, its pool has to be looked up and checked
+        let currency_id = <T as pallet_farming::Config>::CurrencyId::default();
+        let pool_id = pallet_farming::Pallet::<T>::pool_next_id();
+        assert_ok!(pallet_farming::Pallet::<T>::create_farming_pool(
+            RawOrigin::Root.into(),
+            vec![(currency_id, Perbill::one())],
+            vec![(currency_id, 1_000u32.into())],
+            None,
+            Zero::zero(),
+            Zero::zero(),
+            Zero::zero(),
+            Zero::zero(),
+            5,
+        ));
+        let backing = PotBacking::Farming { pool_id, deposit: currency_id, prize: currency_id };
+    }: _(RawOrigin::Root, backing)
+    verify {
+        assert_eq!(Pallet::<T, I>::pot_backing(), backing);
+    }
+    set_liquidity_buffer_params {
+    }: _(RawOrigin::Root,u32::MAX.into(),<T as Config<I>>::MaxInstantWithdrawFee::get())
+    verify {
+        assert_eq!(Pallet::<T, I>::liquidity_buffer_target(), u32::MAX.into());
+    }
     // rebalance_stake {
     // }: _()
     // verify {
@@ -295,6 +373,24 @@ mod tests {
         });
     }
     #[test]
+    fn bench_instant_withdraw() {
+        new_test_ext().execute_with(|| {
+            assert_ok!(Pallet::<Test>::test_benchmark_instant_withdraw());
+        });
+    }
+    #[test]
+    fn bench_set_staking_strategy() {
+        new_test_ext().execute_with(|| {
+            assert_ok!(Pallet::<Test>::test_benchmark_set_staking_strategy());
+        });
+    }
+    #[test]
+    fn bench_set_pot_backing() {
+        new_test_ext().execute_with(|| {
+            assert_ok!(Pallet::<Test>::test_benchmark_set_pot_backing());
+        });
+    }
+    #[test]
     fn bench_claim_my_winnings() {
         new_test_ext().execute_with(|| {
             assert_ok!(Pallet::<Test>::test_benchmark_claim_my_winnings());
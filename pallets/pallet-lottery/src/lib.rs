@@ -23,6 +23,10 @@
 //! accrued excess funds.
 //! Funds withdrawn from the the lottery are subject to a timelock determined by parachain-staking before they can be claimed.
 //!
+//! The pallet is instanceable. Every instance has its own pot account, drawing interval and settings, and its pot can be
+//! backed by an [`orml_traits::MultiCurrency`] asset or a [`pallet_farming`] pool instead of staking, see [`PotBacking`].
+//! Deposits of asset backed pots are not timelocked and withdrawals are paid out immediately.
+//!
 //! ### Lottery Rules
 //! 1. A drawing is scheduled to happen every [`Config::DrawingInterval`] blocks.
 //! 2. A designated manager can start & stop the drawings as well as rebalance the stake to improve the yield generated through staking
@@ -46,6 +50,7 @@
 //! ### User Dispatchable Functions
 //! * [`Call::deposit`]: Allows any user to deposit tokens into the lottery
 //! * [`Call::request_withdraw`]: Allows any user to request return of their deposited tokens to own wallet
+//! * [`Call::instant_withdraw`]: Allows any user to withdraw deposited tokens immediately from the liquidity buffer for a fee
 //! * [`Call::claim_my_winnings`]: Allows any user to transfer any accrued winnings into their wallet
 //!
 //! ### Manager Dispatchable Functions
@@ -55,6 +60,8 @@
 //! * [`Call::process_matured_withdrawals`]: Immediately transfer funds of all matured withdrawals to their respective owner's wallets
 //! * [`Call::liquidate_lottery`]: Unstakes all lottery funds and schedules [`Call::process_matured_withdrawals`] after the timelock period
 //! * [`Call::rebalance_stake`]: Immediately unstakes overweight collators (with low APY) for later restaking into underweight collators (with high APY)
+//! * [`Call::set_staking_strategy`]: Selects order and parameters of the strategies used to distribute the pot over collators
+//! * [`Call::set_pot_backing`]: Selects where deposits are kept and what asset prizes are paid in, only allowed while the pot is empty
 //!
 //! ### Important state queries callable via RPC
 //! * [`Pallet::next_drawing_at`]: Block number where the next drawing will happen
@@ -72,6 +79,7 @@
 
 #![cfg_attr(not(feature = "std"), no_std)]
 
+pub mod migrations;
 mod staking;
 
 #[cfg(feature = "rpc")]
@@ -106,34 +114,39 @@ pub mod pallet {
     use frame_system::{pallet_prelude::*, RawOrigin};
     use manta_primitives::types::PoolId;
     use orml_traits::MultiCurrency;
-    use pallet_parachain_staking::BalanceOf;
+    use pallet_farming::WeightInfo as FarmingWeightInfo;
+    use pallet_parachain_staking::{BalanceOf, WeightInfo as StakingWeightInfo};
     #[cfg(feature = "std")]
     use serde::{Deserialize, Serialize};
     use sp_arithmetic::traits::SaturatedConversion;
     use sp_core::U256;
     use sp_runtime::{
         traits::{AccountIdConversion, CheckedAdd, CheckedSub, Dispatchable, Saturating, Zero},
-        ArithmeticError, DispatchResult,
+        ArithmeticError, DispatchResult, Perbill,
     };
-    use sp_std::prelude::*;
+    use sp_std::{collections::btree_set::BTreeSet, fmt::Debug, prelude::*};
 
     const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
 
-    pub type CallOf<T> = <T as Config>::RuntimeCall;
+    pub type CallOf<T, I = ()> = <T as Config<I>>::RuntimeCall;
+    pub type AssetBalanceOf<T> = <<T as pallet_farming::Config>::MultiCurrency as MultiCurrency<
+        <T as frame_system::Config>::AccountId,
+    >>::Balance;
 
     #[pallet::config]
-    pub trait Config:
+    pub trait Config<I: 'static = ()>:
         frame_system::Config + pallet_parachain_staking::Config + pallet_farming::Config
     {
         /// The aggregated `RuntimeCall` type.
         type RuntimeCall: Parameter
             + Dispatchable<RuntimeOrigin = Self::RuntimeOrigin>
-            + From<Call<Self>>;
-        type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+            + From<Call<Self, I>>;
+        type RuntimeEvent: From<Event<Self, I>>
+            + IsType<<Self as frame_system::Config>::RuntimeEvent>;
         /// The Scheduler.
         type Scheduler: ScheduleNamed<
             Self::BlockNumber,
-            CallOf<Self>,
+            CallOf<Self, I>,
             Self::PalletsOrigin,
             Hash = Self::Hash,
         >;
@@ -143,14 +156,16 @@ pub mod pallet {
                 <<Self as pallet_farming::Config>::MultiCurrency as MultiCurrency<
                     <Self as frame_system::Config>::AccountId,
                 >>::Balance,
-            > + From<BalanceOf<Self>>;
+            > + From<BalanceOf<Self>>
+            + From<AssetBalanceOf<Self>>
+            + Into<BalanceOf<Self>>;
         /// Randomness source to use for determining lottery winner
         type RandomnessSource: Randomness<Self::Hash, Self::BlockNumber>;
         /// Something that can estimate the cost of sending an extrinsic
         type EstimateCallFee: frame_support::traits::EstimateCallFee<
                 pallet_parachain_staking::Call<Self>,
                 BalanceOf<Self>,
-            > + frame_support::traits::EstimateCallFee<Call<Self>, BalanceOf<Self>>;
+            > + frame_support::traits::EstimateCallFee<Call<Self, I>, BalanceOf<Self>>;
         /// Origin that can manage lottery parameters and start/stop drawings
         type ManageOrigin: EnsureOrigin<Self::RuntimeOrigin>;
         /// Overarching type of all pallets origins.
@@ -169,6 +184,18 @@ pub mod pallet {
         /// Time in blocks until a collator is done unstaking
         #[pallet::constant]
         type UnstakeLockTime: Get<Self::BlockNumber>; // XXX: could maybe alculate this from staking LeaveDelayRounds * DefaultBlocksPerRound
+        /// Highest [`InstantWithdrawFee`] that can be set with [`Call::set_liquidity_buffer_params`]
+        #[pallet::constant]
+        type MaxInstantWithdrawFee: Get<Perbill>;
+        /// Maximum number of deposit strategies in a [`StakingStrategyConfig`]
+        #[pallet::constant]
+        type MaxDepositStrategies: Get<u32>;
+        /// Maximum number of withdraw strategies in a [`StakingStrategyConfig`]
+        #[pallet::constant]
+        type MaxWithdrawStrategies: Get<u32>;
+        /// Maximum number of collators that can be put on the staking blocklist
+        #[pallet::constant]
+        type MaxCollatorBlocklistLen: Get<u32>;
         /// Weight information for extrinsics in this pallet.
         type WeightInfo: WeightInfo;
     }
@@ -176,7 +203,7 @@ pub mod pallet {
     #[pallet::pallet]
     #[pallet::storage_version(STORAGE_VERSION)]
     #[pallet::without_storage_info]
-    pub struct Pallet<T>(PhantomData<T>);
+    pub struct Pallet<T, I = ()>(PhantomData<(T, I)>);
 
     // Configurable (constant) storage items
 
@@ -184,15 +211,31 @@ pub mod pallet {
     /// This must be initialized at genesis, otherwise the pallet will run out of gas at the first drawing
     #[pallet::storage]
     #[pallet::getter(fn gas_reserve)]
-    pub(super) type GasReserve<T: Config> = StorageValue<_, BalanceOf<T>, ValueQuery>;
+    pub(super) type GasReserve<T: Config<I>, I: 'static = ()> =
+        StorageValue<_, BalanceOf<T>, ValueQuery>;
 
     #[pallet::storage]
     #[pallet::getter(fn min_deposit)]
-    pub(super) type MinDeposit<T: Config> = StorageValue<_, BalanceOf<T>, ValueQuery>;
+    pub(super) type MinDeposit<T: Config<I>, I: 'static = ()> =
+        StorageValue<_, BalanceOf<T>, ValueQuery>;
 
     #[pallet::storage]
     #[pallet::getter(fn min_withdraw)]
-    pub(super) type MinWithdraw<T: Config> = StorageValue<_, BalanceOf<T>, ValueQuery>;
+    pub(super) type MinWithdraw<T: Config<I>, I: 'static = ()> =
+        StorageValue<_, BalanceOf<T>, ValueQuery>;
+
+    /// Amount of deposited funds to keep unstaked in the pot to serve [`Call::instant_withdraw`]
+    /// Zero disables the liquidity buffer
+    #[pallet::storage]
+    #[pallet::getter(fn liquidity_buffer_target)]
+    pub(super) type LiquidityBufferTarget<T: Config<I>, I: 'static = ()> =
+        StorageValue<_, BalanceOf<T>, ValueQuery>;
+
+    /// Share of an instant withdrawal that is kept by the pot and added to the prize pool
+    #[pallet::storage]
+    #[pallet::getter(fn instant_withdraw_fee)]
+    pub(super) type InstantWithdrawFee<T: Config<I>, I: 'static = ()> =
+        StorageValue<_, Perbill, ValueQuery>;
 
     // Dynamic Storage Items
 
@@ -201,31 +244,34 @@ pub mod pallet {
     /// Decremented on withdrawal to user wallet in [`Call::process_matured_withdrawals`]
     #[pallet::storage]
     #[pallet::getter(fn sum_of_deposits)]
-    pub(super) type SumOfDeposits<T: Config> = StorageValue<_, BalanceOf<T>, ValueQuery>;
+    pub(super) type SumOfDeposits<T: Config<I>, I: 'static = ()> =
+        StorageValue<_, BalanceOf<T>, ValueQuery>;
 
     /// Total number of token eligible to win in the current drawing cycle
     /// Incremented on [`Call::deposit`]
     /// Decremented on [`Call::request_withdraw`]
     #[pallet::storage]
     #[pallet::getter(fn total_pot)]
-    pub(super) type TotalPot<T: Config> = StorageValue<_, BalanceOf<T>, ValueQuery>;
+    pub(super) type TotalPot<T: Config<I>, I: 'static = ()> =
+        StorageValue<_, BalanceOf<T>, ValueQuery>;
 
     #[pallet::storage]
     #[pallet::getter(fn total_users)]
-    pub(super) type TotalUsers<T: Config> = StorageValue<_, u32, ValueQuery>;
+    pub(super) type TotalUsers<T: Config<I>, I: 'static = ()> = StorageValue<_, u32, ValueQuery>;
 
     #[pallet::storage]
     #[pallet::getter(fn is_rebalancing)]
-    pub(super) type RebalanceInProgress<T: Config> = StorageValue<_, bool, ValueQuery>;
+    pub(super) type RebalanceInProgress<T: Config<I>, I: 'static = ()> =
+        StorageValue<_, bool, ValueQuery>;
 
     #[pallet::storage]
     #[pallet::getter(fn active_balance_per_user)]
-    pub(super) type ActiveBalancePerUser<T: Config> =
+    pub(super) type ActiveBalancePerUser<T: Config<I>, I: 'static = ()> =
         StorageMap<_, Blake2_128Concat, T::AccountId, BalanceOf<T>, ValueQuery>;
 
     #[pallet::storage]
     #[pallet::getter(fn unclaimed_winnings_by_account)]
-    pub(super) type UnclaimedWinningsByAccount<T: Config> =
+    pub(super) type UnclaimedWinningsByAccount<T: Config<I>, I: 'static = ()> =
         StorageMap<_, Blake2_128Concat, T::AccountId, BalanceOf<T>, OptionQuery>;
 
     /// Free balance in the pallet that belongs to a previous lottery winner
@@ -233,14 +279,24 @@ pub mod pallet {
     /// Decremented on transfer of winnings to ower wallet in [`Call::claim_my_winnings`]
     #[pallet::storage]
     #[pallet::getter(fn total_unclaimed_winnings)]
-    pub(super) type TotalUnclaimedWinnings<T: Config> = StorageValue<_, BalanceOf<T>, ValueQuery>;
+    pub(super) type TotalUnclaimedWinnings<T: Config<I>, I: 'static = ()> =
+        StorageValue<_, BalanceOf<T>, ValueQuery>;
 
     /// Free balance in the pallet that was unstaked from a collator and is needed for future withdrawal requests
     /// Incremented on successful unstaking of a collator
     /// Decremented on transfer of funds to withdrawer and on restaking of funds a collator
     #[pallet::storage]
     #[pallet::getter(fn unlocked_unstaking_funds)]
-    pub(super) type UnlockedUnstakingFunds<T: Config> = StorageValue<_, BalanceOf<T>, ValueQuery>;
+    pub(super) type UnlockedUnstakingFunds<T: Config<I>, I: 'static = ()> =
+        StorageValue<_, BalanceOf<T>, ValueQuery>;
+
+    /// Deposited funds held unstaked in the pallet to serve instant withdrawals
+    /// Incremented on [`Call::deposit`] and on restaking in [`Call::process_matured_withdrawals`] while below [`LiquidityBufferTarget`]
+    /// Decremented on [`Call::instant_withdraw`]
+    #[pallet::storage]
+    #[pallet::getter(fn liquidity_buffer)]
+    pub(super) type LiquidityBuffer<T: Config<I>, I: 'static = ()> =
+        StorageValue<_, BalanceOf<T>, ValueQuery>;
 
     #[derive(Clone, Encode, Decode, TypeInfo)]
     pub(super) struct UnstakingCollator<AccountId, BlockNumber> {
@@ -249,7 +305,7 @@ pub mod pallet {
     }
 
     #[pallet::storage]
-    pub(super) type UnstakingCollators<T: Config> =
+    pub(super) type UnstakingCollators<T: Config<I>, I: 'static = ()> =
         StorageValue<_, Vec<UnstakingCollator<T::AccountId, T::BlockNumber>>, ValueQuery>;
 
     /// This is balance unstaked from a collator that is not needed to service user's withdrawal requests
@@ -257,7 +313,8 @@ pub mod pallet {
     /// Decremented on [`Call::request_withdraw`] (no collator unstake) and [`Call::rebalance_stake`] (restaking of surplus funds)
     #[pallet::storage]
     #[pallet::getter(fn surplus_unstaking_balance)]
-    pub(super) type SurplusUnstakingBalance<T: Config> = StorageValue<_, BalanceOf<T>, ValueQuery>;
+    pub(super) type SurplusUnstakingBalance<T: Config<I>, I: 'static = ()> =
+        StorageValue<_, BalanceOf<T>, ValueQuery>;
 
     #[derive(Clone, Debug, Eq, PartialEq, Encode, Decode, TypeInfo)]
     pub struct Request<AccountId, BlockNumber, Balance> {
@@ -268,14 +325,14 @@ pub mod pallet {
 
     #[pallet::storage]
     #[pallet::getter(fn withdrawal_request_queue)]
-    pub(super) type WithdrawalRequestQueue<T: Config> =
+    pub(super) type WithdrawalRequestQueue<T: Config<I>, I: 'static = ()> =
         StorageValue<_, Vec<Request<T::AccountId, T::BlockNumber, BalanceOf<T>>>, ValueQuery>;
 
     /// Incremented whenever delegating tokens to a collator
     /// Collators are removed from here when their funds are unlocked in [`Call::finish_unstaking_collators`]
     #[pallet::storage]
     #[pallet::getter(fn staked_collators)]
-    pub(super) type StakedCollators<T: Config> =
+    pub(super) type StakedCollators<T: Config<I>, I: 'static = ()> =
         StorageMap<_, Blake2_128Concat, T::AccountId, BalanceOf<T>, ValueQuery>;
 
     #[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
@@ -291,19 +348,148 @@ pub mod pallet {
 
     /// Boolean for the minting of a farming token on `deposit` call
     #[pallet::storage]
-    pub(super) type FarmingParameters<T: Config> = StorageValue<_, FarmingParamsOf<T>, ValueQuery>;
+    pub(super) type FarmingParameters<T: Config<I>, I: 'static = ()> =
+        StorageValue<_, FarmingParamsOf<T>, ValueQuery>;
+
+    /// Strategies used to assign deposited funds to collators, in the order they are tried
+    #[derive(Clone, Copy, Debug, Eq, PartialEq, Ord, PartialOrd, Encode, Decode, TypeInfo)]
+    pub enum DepositStrategyKind {
+        /// Top up collators we're staked to that dropped out of their top delegations
+        ReactivateBottomCollators,
+        /// Bring collators with below-median stake up towards the median
+        SplitToUnderallocatedCollators,
+        /// Spread the deposit over all eligible collators weighted by their estimated APY
+        SplitApyWeighted,
+        /// Stake everything with a random eligible collator
+        StakeToRandomCollator,
+    }
+
+    /// Strategies used to select collators to unstake from, in the order they are tried
+    #[derive(Clone, Copy, Debug, Eq, PartialEq, Ord, PartialOrd, Encode, Decode, TypeInfo)]
+    pub enum WithdrawStrategyKind {
+        /// Unstake collators on the [`StakingStrategyConfig::collator_blocklist`]
+        UnstakeBlocklistedCollators,
+        /// Unstake collators that are not selected or did not produce blocks last round
+        UnstakeInactiveCollators,
+        /// Unstake collators with the highest total stake ( i.e. lowest APY ) first
+        UnstakeLeastApyCollators,
+    }
+
+    /// Governance-tunable parameters controlling how the pot is distributed over collators
+    #[derive(
+        CloneNoBound, EqNoBound, PartialEqNoBound, RuntimeDebugNoBound, Encode, Decode, TypeInfo,
+    )]
+    #[scale_info(skip_type_params(MaxDepositStrategies, MaxWithdrawStrategies, MaxBlocklistLen))]
+    pub struct StakingStrategyConfig<
+        AccountId,
+        Balance,
+        MaxDepositStrategies,
+        MaxWithdrawStrategies,
+        MaxBlocklistLen,
+    >
+    where
+        AccountId: Clone + Debug + Eq,
+        Balance: Clone + Debug + Eq,
+        MaxDepositStrategies: Get<u32>,
+        MaxWithdrawStrategies: Get<u32>,
+        MaxBlocklistLen: Get<u32>,
+    {
+        /// Deposit strategies, tried in order until the full deposit is assigned
+        pub deposit_strategies: BoundedVec<DepositStrategyKind, MaxDepositStrategies>,
+        /// Withdraw strategies, tried in order until the full withdrawal is covered
+        pub withdraw_strategies: BoundedVec<WithdrawStrategyKind, MaxWithdrawStrategies>,
+        /// Upper bound of the pallet's stake with any single collator, if any
+        pub max_stake_per_collator: Option<Balance>,
+        /// Collators the pallet must never stake to
+        pub collator_blocklist: BoundedVec<AccountId, MaxBlocklistLen>,
+    }
+
+    impl<AccountId, Balance, MaxDepositStrategies, MaxWithdrawStrategies, MaxBlocklistLen> Default
+        for StakingStrategyConfig<
+            AccountId,
+            Balance,
+            MaxDepositStrategies,
+            MaxWithdrawStrategies,
+            MaxBlocklistLen,
+        >
+    where
+        AccountId: Clone + Debug + Eq,
+        Balance: Clone + Debug + Eq,
+        MaxDepositStrategies: Get<u32>,
+        MaxWithdrawStrategies: Get<u32>,
+        MaxBlocklistLen: Get<u32>,
+    {
+        fn default() -> Self {
+            Self {
+                deposit_strategies: BoundedVec::truncate_from(vec![
+                    DepositStrategyKind::ReactivateBottomCollators,
+                    DepositStrategyKind::SplitToUnderallocatedCollators,
+                    DepositStrategyKind::StakeToRandomCollator,
+                ]),
+                withdraw_strategies: BoundedVec::truncate_from(vec![
+                    WithdrawStrategyKind::UnstakeInactiveCollators,
+                    WithdrawStrategyKind::UnstakeLeastApyCollators,
+                ]),
+                max_stake_per_collator: None,
+                collator_blocklist: BoundedVec::default(),
+            }
+        }
+    }
+
+    pub type StakingStrategyConfigOf<T, I = ()> = StakingStrategyConfig<
+        <T as frame_system::Config>::AccountId,
+        BalanceOf<T>,
+        <T as Config<I>>::MaxDepositStrategies,
+        <T as Config<I>>::MaxWithdrawStrategies,
+        <T as Config<I>>::MaxCollatorBlocklistLen,
+    >;
+
+    /// Ordering and parameters of the staking strategies, set by [`Call::set_staking_strategy`]
+    #[pallet::storage]
+    #[pallet::getter(fn staking_strategy)]
+    pub(super) type StakingStrategy<T: Config<I>, I: 'static = ()> =
+        StorageValue<_, StakingStrategyConfigOf<T, I>, ValueQuery>;
+
+    /// Where the deposits of a lottery instance are kept and where its prizes come from
+    #[derive(Clone, Copy, Debug, Default, Eq, PartialEq, Encode, Decode, TypeInfo)]
+    pub enum PotBacking<CurrencyId> {
+        /// Native token deposits are staked with [`pallet_parachain_staking`] collators, staking rewards are the prize
+        #[default]
+        Staking,
+        /// `deposit` asset is held by the pot, any `prize` asset sent to the pot on top of the deposits is the prize
+        Asset {
+            deposit: CurrencyId,
+            prize: CurrencyId,
+        },
+        /// `deposit` asset is staked by the pot into farming pool `pool_id`, farming rewards paid in `prize` are the prize
+        Farming {
+            pool_id: PoolId,
+            deposit: CurrencyId,
+            prize: CurrencyId,
+        },
+    }
+
+    pub type PotBackingOf<T> = PotBacking<<T as pallet_farming::Config>::CurrencyId>;
+
+    /// Backing of this lottery's pot, set by [`Call::set_pot_backing`]
+    #[pallet::storage]
+    #[pallet::getter(fn pot_backing)]
+    pub(super) type Backing<T: Config<I>, I: 'static = ()> =
+        StorageValue<_, PotBackingOf<T>, ValueQuery>;
 
     #[pallet::genesis_config]
-    pub struct GenesisConfig<T: Config> {
+    pub struct GenesisConfig<T: Config<I>, I: 'static = ()> {
         /// amount of token to keep in the pot for paying gas fees
         pub gas_reserve: BalanceOf<T>,
         pub min_deposit: BalanceOf<T>,
         pub min_withdraw: BalanceOf<T>,
         pub farming_pool_params: FarmingParamsOf<T>,
+        #[serde(skip)]
+        pub phantom: PhantomData<I>,
     }
 
     #[cfg(feature = "std")]
-    impl<T: Config> Default for GenesisConfig<T> {
+    impl<T: Config<I>, I: 'static> Default for GenesisConfig<T, I> {
         fn default() -> Self {
             Self {
                 min_deposit: 1u32.into(),
@@ -315,24 +501,25 @@ pub mod pallet {
                     pool_id: 0,
                     currency_id: <T as pallet_farming::Config>::CurrencyId::default(),
                 },
+                phantom: PhantomData,
             }
         }
     }
 
     #[pallet::genesis_build]
-    impl<T: Config> GenesisBuild<T> for GenesisConfig<T> {
+    impl<T: Config<I>, I: 'static> GenesisBuild<T, I> for GenesisConfig<T, I> {
         #[inline]
         fn build(&self) {
-            GasReserve::<T>::set(self.gas_reserve);
-            MinDeposit::<T>::set(self.min_deposit);
-            MinWithdraw::<T>::set(self.min_withdraw);
-            FarmingParameters::<T>::set(self.farming_pool_params);
+            GasReserve::<T, I>::set(self.gas_reserve);
+            MinDeposit::<T, I>::set(self.min_deposit);
+            MinWithdraw::<T, I>::set(self.min_withdraw);
+            FarmingParameters::<T, I>::set(self.farming_pool_params);
         }
     }
 
     #[pallet::event]
     #[pallet::generate_deposit(fn deposit_event)]
-    pub enum Event<T: Config> {
+    pub enum Event<T: Config<I>, I: 'static = ()> {
         LotteryStarted,
         LotteryStopped,
         LotteryWinner {
@@ -355,10 +542,22 @@ pub mod pallet {
             account: T::AccountId,
             amount: BalanceOf<T>,
         },
+        InstantWithdrawn {
+            account: T::AccountId,
+            amount: BalanceOf<T>,
+            fee: BalanceOf<T>,
+        },
+        PotBackingSet {
+            backing: PotBackingOf<T>,
+        },
+        LiquidityBufferParamsSet {
+            target: BalanceOf<T>,
+            instant_withdraw_fee: Perbill,
+        },
     }
 
     #[pallet::error]
-    pub enum Error<T> {
+    pub enum Error<T, I = ()> {
         /// Lottery has not been started
         LotteryNotStarted,
         /// Lottery has already been started
@@ -404,33 +603,40 @@ pub mod pallet {
         CouldNotSchedule,
         /// Fatal: Functionality not yet supported
         NotImplemented,
+        /// The pot backing can only be changed while no deposits or winnings are in the pot
+        PotNotEmpty,
+        /// Farming pool does not exist, does not stake exactly the deposit asset or locks withdrawals
+        InvalidFarmingPool,
+        /// Instant withdraw fee is above [`Config::MaxInstantWithdrawFee`]
+        InstantWithdrawFeeTooHigh,
     }
 
     #[pallet::call]
-    impl<T: Config> Pallet<T> {
+    impl<T: Config<I>, I: 'static> Pallet<T, I> {
         /// Allows any user to deposit tokens into the lottery
         ///
         /// # Arguments
         ///
         /// * `amount` - The amount of tokens to be deposited.
         #[pallet::call_index(0)]
-        #[pallet::weight(<T as Config>::WeightInfo::deposit(Pallet::<T>::total_users(), pallet_parachain_staking::Pallet::<T>::selected_candidates().len() as u32))]
+        #[pallet::weight(Pallet::<T, I>::deposit_weight())]
         pub fn deposit(origin: OriginFor<T>, amount: BalanceOf<T>) -> DispatchResult {
             let caller_account = ensure_signed(origin)?;
             ensure!(
                 amount >= Self::min_deposit(),
-                Error::<T>::DepositBelowMinAmount
+                Error::<T, I>::DepositBelowMinAmount
             );
             ensure!(
                 Self::not_in_drawing_freezeout(),
-                Error::<T>::TooCloseToDrawing
+                Error::<T, I>::TooCloseToDrawing
             );
+            let backing = Self::pot_backing();
             ensure! { // Sanity check: make sure we dont accept deposits that will fail in staking
-                Self::min_deposit() >= <T as pallet_parachain_staking::Config>::MinDelegation::get(),
-                Error::<T>::PalletMisconfigured
+                backing != PotBacking::Staking || Self::min_deposit() >= <T as pallet_parachain_staking::Config>::MinDelegation::get(),
+                Error::<T, I>::PalletMisconfigured
             };
 
-            let farming_params = FarmingParameters::<T>::get();
+            let farming_params = FarmingParameters::<T, I>::get();
             if farming_params.mint_farming_token {
                 // mint JUMBO token and put it in farming pool
                 let convert_amount: T::BalanceConversion = amount.into();
@@ -447,30 +653,66 @@ pub mod pallet {
                 )?;
             }
 
-            // Transfer funds to pot
-            <T as pallet_parachain_staking::Config>::Currency::transfer(
-                &caller_account,
-                &Self::account_id(),
-                amount,
-                KeepAlive,
-            )?;
+            match backing {
+                PotBacking::Staking => {
+                    // Transfer funds to pot
+                    <T as pallet_parachain_staking::Config>::Currency::transfer(
+                        &caller_account,
+                        &Self::account_id(),
+                        amount,
+                        KeepAlive,
+                    )?;
 
-            // Attempt to stake them
-            let collator_balance_pairs = Self::calculate_deposit_distribution(amount);
-            ensure!(
-                !collator_balance_pairs.is_empty(),
-                Error::<T>::NoCollatorForDeposit
-            );
-            for (some_collator, balance) in collator_balance_pairs {
-                // TODO: What if the `balance` is below `MinDelegation`a on a new collator? this will fail
-                Self::do_stake_one_collator(some_collator, balance)?;
+                    // Keep what the liquidity buffer is missing unstaked and attempt to stake the rest
+                    let to_buffer = Self::amount_to_buffer(amount);
+                    let to_stake = amount.saturating_sub(to_buffer);
+                    if !to_stake.is_zero() {
+                        let collator_balance_pairs = Self::calculate_deposit_distribution(to_stake);
+                        ensure!(
+                            !collator_balance_pairs.is_empty(),
+                            Error::<T, I>::NoCollatorForDeposit
+                        );
+                        for (some_collator, balance) in collator_balance_pairs {
+                            // TODO: What if the `balance` is below `MinDelegation`a on a new collator? this will fail
+                            Self::do_stake_one_collator(some_collator, balance)?;
+                        }
+                    }
+                    LiquidityBuffer::<T, I>::mutate(|buffer| *buffer += to_buffer);
+                }
+                PotBacking::Asset { deposit, .. } => {
+                    <T as pallet_farming::Config>::MultiCurrency::transfer(
+                        deposit,
+                        &caller_account,
+                        &Self::account_id(),
+                        Self::to_asset_balance(amount),
+                    )?;
+                }
+                PotBacking::Farming {
+                    pool_id, deposit, ..
+                } => {
+                    let asset_amount = Self::to_asset_balance(amount);
+                    <T as pallet_farming::Config>::MultiCurrency::transfer(
+                        deposit,
+                        &caller_account,
+                        &Self::account_id(),
+                        asset_amount,
+                    )?;
+                    pallet_farming::Pallet::<T>::deposit_farming(
+                        Self::account_id(),
+                        pool_id,
+                        asset_amount,
+                        None,
+                    )?;
+                }
             }
 
             // Add to active funds
-            ActiveBalancePerUser::<T>::mutate(caller_account.clone(), |balance| *balance += amount);
-            TotalPot::<T>::mutate(|balance| *balance += amount);
-            TotalUsers::<T>::mutate(|users| *users += 1);
-            SumOfDeposits::<T>::mutate(|balance| *balance += amount);
+            ActiveBalancePerUser::<T, I>::mutate(caller_account.clone(), |balance| {
+                *balance += amount
+            });
+            TotalPot::<T, I>::mutate(|balance| *balance += amount);
+            TotalUsers::<T, I>::mutate(|users| *users += 1);
+            SumOfDeposits::<T, I>::mutate(|balance| *balance += amount);
             Self::deposit_event(Event::Deposited {
                 account: caller_account,
                 amount,
@@ -502,129 +744,107 @@ pub mod pallet {
         /// * The user has no or not enough active funds
         /// * There are any arithmetic underflows
         #[pallet::call_index(1)]
-        #[pallet::weight(<T as Config>::WeightInfo::request_withdraw(Pallet::<T>::total_users(), pallet_parachain_staking::Pallet::<T>::selected_candidates().len() as u32))]
+        #[pallet::weight(Pallet::<T, I>::request_withdraw_weight())]
         pub fn request_withdraw(origin: OriginFor<T>, amount: BalanceOf<T>) -> DispatchResult {
             let caller = ensure_signed(origin)?;
 
             ensure!(
                 amount >= Self::min_withdraw(),
-                Error::<T>::WithdrawBelowMinAmount
+                Error::<T, I>::WithdrawBelowMinAmount
             );
             ensure!(
                 Self::not_in_drawing_freezeout(),
-                Error::<T>::TooCloseToDrawing
+                Error::<T, I>::TooCloseToDrawing
             );
 
-            let farming_params = FarmingParameters::<T>::get();
-            if farming_params.destroy_farming_token {
-                let convert_amount: T::BalanceConversion = amount.into();
-                pallet_farming::Pallet::<T>::withdraw_and_unstake(
-                    caller.clone(),
-                    farming_params.pool_id,
-                    Some(convert_amount.into()),
-                )?;
-                <T as pallet_farming::Config>::MultiCurrency::withdraw(
-                    farming_params.currency_id,
-                    &caller,
-                    convert_amount.into(),
-                )?;
+            Self::do_deactivate_funds(&caller, amount)?;
+            if Self::pot_backing() != PotBacking::Staking {
+                // asset deposits are not timelocked and are paid out right away
+                return Self::do_withdraw_asset(caller, amount);
             }
+            Self::do_request_withdraw(caller, amount)
+        }
 
-            let now = <frame_system::Pallet<T>>::block_number();
-            log::debug!("Requesting withdraw of {:?} tokens", amount);
-            // Ensure user has enough funds active and mark them as offboarding (remove from `ActiveBalancePerUser`)
-            ActiveBalancePerUser::<T>::try_mutate_exists(caller.clone(), |maybe_balance| {
-                match maybe_balance {
-                    None => Err(Error::<T>::NoDepositForAccount),
-                    Some(balance) => {
-                        // Withdraw only what's active
-                        ensure!(*balance >= amount, Error::<T>::WithdrawAboveDeposit);
-                        // Mark funds as offboarding
-                        WithdrawalRequestQueue::<T>::mutate(|withdraw_vec| {
-                            withdraw_vec.push(Request {
-                                user: caller.clone(),
-                                block: now,
-                                balance: amount,
-                            })
-                        });
-                        // store reduced balance
-                        *maybe_balance = match balance
-                            .checked_sub(&amount)
-                            .ok_or(Error::<T>::ArithmeticUnderflow)?
-                        {
-                            new_balance if new_balance.is_zero() => {
-                                // remove user if this was his last remaining funds
-                                TotalUsers::<T>::try_mutate(|users| {
-                                    *users = (*users)
-                                        .checked_sub(1u32)
-                                        .ok_or(Error::<T>::ArithmeticUnderflow)?;
-                                    Ok(())
-                                })?;
-                                None
-                            }
-                            new_balance => Some(new_balance),
-                        };
-                        TotalPot::<T>::try_mutate(|pot| {
-                            *pot = (*pot)
-                                .checked_sub(&amount)
-                                .ok_or(Error::<T>::ArithmeticUnderflow)?;
-                            Ok(())
-                        })?;
-                        Ok(())
-                    }
-                }
-            })?;
+        /// Withdraws `amount` of the caller's active funds immediately from the liquidity buffer
+        ///
+        /// The pot keeps [`InstantWithdrawFee`] of `amount` which is added to the prize pool.
+        /// If the buffer does not hold enough funds, this falls back to a fee-free [`Call::request_withdraw`]
+        ///
+        /// # Arguments
+        ///
+        /// * `amount` - the amount of funds to withdraw
+        ///
+        /// # Errors
+        ///
+        /// Returns an error if:
+        /// * `amount` is below the minimum withdraw amount
+        /// * `amount` is larger than the user's total deposit
+        /// * It is too close to the drawing
+        /// * The user has no or not enough active funds
+        #[pallet::call_index(14)]
+        #[pallet::weight(Pallet::<T, I>::request_withdraw_weight().max(<T as Config<I>>::WeightInfo::instant_withdraw(Pallet::<T, I>::total_users(), pallet_parachain_staking::Pallet::<T>::selected_candidates().len() as u32)))]
+        pub fn instant_withdraw(
+            origin: OriginFor<T>,
+            amount: BalanceOf<T>,
+        ) -> DispatchResultWithPostInfo {
+            let caller = ensure_signed(origin)?;
+            let instant_withdraw_weight = <T as Config<I>>::WeightInfo::instant_withdraw(
+                Self::total_users(),
+                pallet_parachain_staking::Pallet::<T>::selected_candidates().len() as u32,
+            );
 
-            // Unstaking workflow
-            // 1. See if this withdrawal can be serviced with left-over balance from an already unstaking collator, if so deduct remaining balance and schedule the request
-            // 2. If it can't, find the collator with the smallest delegation that is able to handle this withdrawal request and fully unstake it
-            // 3. Add balance overshoot to "remaining balance" to handle further requests from
+            ensure!(
+                amount >= Self::min_withdraw(),
+                Error::<T, I>::WithdrawBelowMinAmount
+            );
+            ensure!(
+                Self::not_in_drawing_freezeout(),
+                Error::<T, I>::TooCloseToDrawing
+            );
 
-            // If the withdrawal fits in the currently unstaking funds, do nothing else
-            SurplusUnstakingBalance::<T>::try_mutate(|remaining_balance| {
-                match (*remaining_balance).checked_sub(&amount){
-                    Some(subtracted) => {
-                        *remaining_balance = subtracted;
-                        Ok(())
-                    }
-                    _ => {
-                        Err("not enough left to handle this request from current unstaking funds")
-                    }
-                }
-            })
-            .or_else(|_| {
-                // Withdrawal needs extra collators to unstake to have enough funds to serve withdrawals, do it
-                let reserve = SurplusUnstakingBalance::<T>::get();
-                let mut remaining_to_withdraw = amount - reserve;
+            Self::do_deactivate_funds(&caller, amount)?;
+            if Self::pot_backing() != PotBacking::Staking {
+                // asset deposits are always available, no fee is charged
+                Self::do_withdraw_asset(caller, amount)?;
+                return Ok(().into());
+            }
+            if Self::liquidity_buffer() < amount {
+                log::debug!(
+                    "Liquidity buffer of {:?} can't serve {:?}, queueing withdrawal",
+                    Self::liquidity_buffer(),
+                    amount
+                );
+                Self::do_request_withdraw(caller, amount)?;
+                return Ok(().into());
+            }
 
-                // unstake collators as necessary. This updates `SurplusUnstakingBalance`
-                for collator_to_unstake in Self::calculate_withdrawal_distribution(remaining_to_withdraw){
-                    let our_stake = StakedCollators::<T>::get(collator_to_unstake.clone());
-                    remaining_to_withdraw = remaining_to_withdraw.saturating_sub(our_stake);
-                    // The following call updates `SurplusUnstakingBalance` with newly unstaked funds
-                    Self::do_unstake_collator(now,collator_to_unstake)?;
-                }
-                if !remaining_to_withdraw.is_zero() {
-                    return Err("FATAL: Didn't unstake the full requested balance (or more)");
-                }
-                SurplusUnstakingBalance::<T>::try_mutate(|remaining_balance| {
-                    match (*remaining_balance).checked_sub(&amount){
-                        Some(subtracted) => {
-                            *remaining_balance = subtracted;
-                            Ok(())
-                        }
-                        _ => {
-                            Err("not enough unstaking balance to handle request after unstaking additional collators")
-                        }
-                    }
-                })
+            let fee = Self::instant_withdraw_fee().mul_ceil(amount);
+            let payout = amount.saturating_sub(fee);
+            LiquidityBuffer::<T, I>::try_mutate(|buffer| -> DispatchResult {
+                *buffer = (*buffer)
+                    .checked_sub(&amount)
+                    .ok_or(Error::<T, I>::ArithmeticUnderflow)?;
+                Ok(())
             })?;
-            // END UNSTAKING SECTION
-            Self::deposit_event(Event::ScheduledWithdraw {
+            // The fee stays in the pot as surplus funds and will be paid to the next winner
+            SumOfDeposits::<T, I>::try_mutate(|sum| -> DispatchResult {
+                *sum = (*sum)
+                    .checked_sub(&amount)
+                    .ok_or(Error::<T, I>::ArithmeticUnderflow)?;
+                Ok(())
+            })?;
+            <T as pallet_parachain_staking::Config>::Currency::transfer(
+                &Self::account_id(),
+                &caller,
+                payout,
+                KeepAlive,
+            )?;
+            Self::deposit_event(Event::InstantWithdrawn {
                 account: caller,
-                amount,
+                amount: payout,
+                fee,
             });
-            Ok(())
+            Ok(Some(instant_withdraw_weight).into())
         }
 
         /// Allows the caller to transfer any of the account's previously unclaimed winnings to his their wallet
@@ -633,34 +853,47 @@ pub mod pallet {
         ///
         /// CannotLookup: The caller has no unclaimed winnings.
         #[pallet::call_index(2)]
-        #[pallet::weight(<T as Config>::WeightInfo::claim_my_winnings(pallet_parachain_staking::Pallet::<T>::selected_candidates().len() as u32))]
+        #[pallet::weight(<T as Config<I>>::WeightInfo::claim_my_winnings(pallet_parachain_staking::Pallet::<T>::selected_candidates().len() as u32))]
         pub fn claim_my_winnings(origin: OriginFor<T>) -> DispatchResult {
             let caller = ensure_signed(origin)?;
-            match UnclaimedWinningsByAccount::<T>::take(caller.clone()) {
+            match UnclaimedWinningsByAccount::<T, I>::take(caller.clone()) {
                 Some(winnings) => {
-                    // Never pay out winnings if it would reduce pallet funds below total number of user's deposits
-                    let all_funds_in_pallet =
-                        <T as pallet_parachain_staking::Config>::Currency::total_balance(
-                            &Self::account_id(),
-                        );
-                    ensure!(
-                        // Sanity check: Never pay out funds that would draw on user deposits
-                        all_funds_in_pallet.saturating_sub(winnings) >= Self::sum_of_deposits(),
-                        Error::<T>::PotBalanceTooLow
-                    );
-
-                    TotalUnclaimedWinnings::<T>::try_mutate(|old| {
+                    TotalUnclaimedWinnings::<T, I>::try_mutate(|old| {
                         *old = (*old)
                             .checked_sub(&winnings)
                             .ok_or(ArithmeticError::Underflow)?;
                         Ok::<(), ArithmeticError>(())
                     })?;
-                    <T as pallet_parachain_staking::Config>::Currency::transfer(
-                        &Self::account_id(),
-                        &caller,
-                        winnings,
-                        KeepAlive,
-                    )?; // NOTE: If the transfer fails, the TXN get rolled back and the winnings stay in the map for claiming later
+                    // NOTE: If the transfer fails, the TXN get rolled back and the winnings stay in the map for claiming later
+                    match Self::pot_backing() {
+                        PotBacking::Staking => {
+                            // Never pay out winnings if it would reduce pallet funds below total number of user's deposits
+                            let all_funds_in_pallet =
+                                <T as pallet_parachain_staking::Config>::Currency::total_balance(
+                                    &Self::account_id(),
+                                );
+                            ensure!(
+                                // Sanity check: Never pay out funds that would draw on user deposits
+                                all_funds_in_pallet.saturating_sub(winnings)
+                                    >= Self::sum_of_deposits(),
+                                Error::<T, I>::PotBalanceTooLow
+                            );
+                            <T as pallet_parachain_staking::Config>::Currency::transfer(
+                                &Self::account_id(),
+                                &caller,
+                                winnings,
+                                KeepAlive,
+                            )?;
+                        }
+                        PotBacking::Asset { prize, .. } | PotBacking::Farming { prize, .. } => {
+                            <T as pallet_farming::Config>::MultiCurrency::transfer(
+                                prize,
+                                &Self::account_id(),
+                                &caller,
+                                Self::to_asset_balance(winnings),
+                            )?;
+                        }
+                    }
                     Self::deposit_event(Event::Claimed {
                         account: caller,
                         amount: winnings,
@@ -693,7 +926,7 @@ pub mod pallet {
         pub fn rebalance_stake(origin: OriginFor<T>) -> DispatchResult {
             T::ManageOrigin::ensure_origin(origin.clone())?;
             Err(crate::pallet::DispatchError::Other(
-                Error::<T>::NotImplemented.into(),
+                Error::<T, I>::NotImplemented.into(),
             ))
 
             // withdraw from overallocated collators, wait until funds unlock, re-allocate to underallocated collators
@@ -723,26 +956,26 @@ pub mod pallet {
         ///
         /// You can always learn what block the next drawing - if any - will happen by calling [`Self::next_drawing_at`]
         #[pallet::call_index(4)]
-        #[pallet::weight(<T as Config>::WeightInfo::start_lottery())]
+        #[pallet::weight(<T as Config<I>>::WeightInfo::start_lottery())]
         pub fn start_lottery(origin: OriginFor<T>) -> DispatchResult {
             T::ManageOrigin::ensure_origin(origin.clone())?;
             ensure!(
                 Self::next_drawing_at().is_none(),
-                Error::<T>::LotteryIsRunning
+                Error::<T, I>::LotteryIsRunning
             );
             // Pallet has enough funds to pay gas fees for at least the first drawing
             ensure!(
                 Self::surplus_funds() >= Self::gas_reserve(),
-                Error::<T>::PotBalanceBelowGasReserve
+                Error::<T, I>::PotBalanceBelowGasReserve
             );
             // NOTE: If more than gas_reserve is in the pallet, the full excess will be paid out to the winner of the next drawing! This is intended to dope the winning balance with extra rewards
 
-            let drawing_interval = <T as Config>::DrawingInterval::get();
+            let drawing_interval = <T as Config<I>>::DrawingInterval::get();
             ensure!(
                 drawing_interval > 0u32.into(),
-                Error::<T>::PalletMisconfigured
+                Error::<T, I>::PalletMisconfigured
             );
-            let lottery_drawing_call: CallOf<T> = Call::draw_lottery {}.into();
+            let lottery_drawing_call: CallOf<T, I> = Call::<T, I>::draw_lottery {}.into();
             T::Scheduler::schedule_named(
                 Self::lottery_schedule_id(),
                 DispatchTime::After(drawing_interval),
@@ -751,7 +984,7 @@ pub mod pallet {
                 frame_support::dispatch::RawOrigin::Root.into(),
                 MaybeHashed::Value(lottery_drawing_call),
             )
-            .map_err(|_| Error::<T>::CouldNotSchedule)?;
+            .map_err(|_| Error::<T, I>::CouldNotSchedule)?;
 
             Self::deposit_event(Event::LotteryStarted);
             Ok(())
@@ -769,11 +1002,11 @@ pub mod pallet {
         /// * LotteryNotStarted: Nothing to stop
         ///
         #[pallet::call_index(5)]
-        #[pallet::weight(<T as Config>::WeightInfo::stop_lottery())]
+        #[pallet::weight(<T as Config<I>>::WeightInfo::stop_lottery())]
         pub fn stop_lottery(origin: OriginFor<T>) -> DispatchResult {
             T::ManageOrigin::ensure_origin(origin.clone())?;
             T::Scheduler::cancel_named(Self::lottery_schedule_id())
-                .map_err(|_| Error::<T>::LotteryNotStarted)?;
+                .map_err(|_| Error::<T, I>::LotteryNotStarted)?;
             Self::deposit_event(Event::LotteryStopped);
             Ok(())
         }
@@ -793,12 +1026,28 @@ pub mod pallet {
         /// * PotBalanceTooLow: The balance of the pot is too low.
         /// * NoWinnerFound: Nobody was selected as winner
         #[pallet::call_index(6)]
-        #[pallet::weight(<T as Config>::WeightInfo::draw_lottery(Pallet::<T>::total_users(), pallet_parachain_staking::Pallet::<T>::selected_candidates().len() as u32))]
+        #[pallet::weight(<T as Config<I>>::WeightInfo::draw_lottery(Pallet::<T, I>::total_users(), pallet_parachain_staking::Pallet::<T>::selected_candidates().len() as u32).saturating_add(Pallet::<T, I>::claim_backing_rewards_weight()))]
         pub fn draw_lottery(origin: OriginFor<T>) -> DispatchResult {
             T::ManageOrigin::ensure_origin(origin.clone())?;
             let now = <frame_system::Pallet<T>>::block_number();
             log::trace!("Drawing lottery called at block {:?}", now.clone());
 
+            let backing = Self::pot_backing();
+            match backing {
+                // staking rewards of the pallet's delegations accrue until claimed
+                PotBacking::Staking => {
+                    pallet_parachain_staking::Pallet::<T>::claim_delegator_rewards(
+                        &Self::account_id(),
+                    );
+                }
+                PotBacking::Asset { .. } => {}
+                // move accrued farming rewards into the pot so they can be paid out
+                PotBacking::Farming { pool_id, .. } => {
+                    if !Self::sum_of_deposits().is_zero() {
+                        pallet_farming::Pallet::<T>::claim_rewards(&Self::account_id(), pool_id)?;
+                    }
+                }
+            }
             let total_funds_in_pallet =
                 <T as pallet_parachain_staking::Config>::Currency::total_balance(
                     &Self::account_id(),
@@ -814,13 +1063,15 @@ pub mod pallet {
             );
             // If there's nothing to win or nobody is playing we skip the drawing logic
             if !winning_claim.is_zero() && !participating_funds.is_zero() {
+                // asset prize pools are already net of deposits and prior winnings
                 ensure!(
                     // Sanity check: Prevent allocating funds as winnings to a user that would have to be paid from user deposits
-                    Self::sum_of_deposits()                                 // all users' deposits (staked and unstaking)
+                    backing != PotBacking::Staking
+                        || Self::sum_of_deposits()                                 // all users' deposits (staked and unstaking)
                         .saturating_add(Self::total_unclaimed_winnings())   // all prior winnings
                         .saturating_add(winning_claim)                      // and the current winner's new claim
                         <= total_funds_in_pallet, // don't exceed funds in the pallet
-                    Error::<T>::PotBalanceTooLow
+                    Error::<T, I>::PotBalanceTooLow
                 );
                 Self::select_winner(winning_claim)?;
             } else {
@@ -832,7 +1083,9 @@ pub mod pallet {
             }
             // unstake, pay out tokens due for withdrawals and restake excess funds
             // At this point, all excess funds except for `gas_reserve` have been reserved for the current winner
-            Self::process_matured_withdrawals(origin)?;
+            if backing == PotBacking::Staking {
+                Self::process_matured_withdrawals(origin)?;
+            }
             Ok(())
         }
 
@@ -845,7 +1098,7 @@ pub mod pallet {
         /// * BadOrigin: Caller is not ManageOrigin
         /// * errors defined by the do_process_matured_withdrawals function.
         #[pallet::call_index(7)]
-        #[pallet::weight(<T as Config>::WeightInfo::process_matured_withdrawals())]
+        #[pallet::weight(<T as Config<I>>::WeightInfo::process_matured_withdrawals())]
         pub fn process_matured_withdrawals(origin: OriginFor<T>) -> DispatchResult {
             log::trace!("process_matured_withdrawals");
             T::ManageOrigin::ensure_origin(origin.clone())?;
@@ -873,11 +1126,11 @@ pub mod pallet {
 
             ensure!(
                 Self::next_drawing_at().is_none(),
-                Error::<T>::LotteryIsRunning
+                Error::<T, I>::LotteryIsRunning
             );
 
             Err(crate::pallet::DispatchError::Other(
-                Error::<T>::NotImplemented.into(),
+                Error::<T, I>::NotImplemented.into(),
             ))
 
             // TODO: Unstake all collators, schedule return of all user deposits
@@ -891,35 +1144,35 @@ pub mod pallet {
             // Ok(())
         }
         #[pallet::call_index(9)]
-        #[pallet::weight(<T as Config>::WeightInfo::set_min_deposit())]
+        #[pallet::weight(<T as Config<I>>::WeightInfo::set_min_deposit())]
         pub fn set_min_deposit(origin: OriginFor<T>, min_deposit: BalanceOf<T>) -> DispatchResult {
             T::ManageOrigin::ensure_origin(origin.clone())?;
             ensure!(
                 min_deposit >= Self::min_withdraw(),
-                Error::<T>::PalletMisconfigured
+                Error::<T, I>::PalletMisconfigured
             );
-            MinDeposit::<T>::set(min_deposit);
+            MinDeposit::<T, I>::set(min_deposit);
             Ok(())
         }
         #[pallet::call_index(10)]
-        #[pallet::weight(<T as Config>::WeightInfo::set_min_withdraw())]
+        #[pallet::weight(<T as Config<I>>::WeightInfo::set_min_withdraw())]
         pub fn set_min_withdraw(
             origin: OriginFor<T>,
             min_withdraw: BalanceOf<T>,
         ) -> DispatchResult {
             T::ManageOrigin::ensure_origin(origin.clone())?;
-            MinWithdraw::<T>::set(min_withdraw);
+            MinWithdraw::<T, I>::set(min_withdraw);
             Ok(())
         }
         #[pallet::call_index(11)]
-        #[pallet::weight(<T as Config>::WeightInfo::set_gas_reserve())]
+        #[pallet::weight(<T as Config<I>>::WeightInfo::set_gas_reserve())]
         pub fn set_gas_reserve(origin: OriginFor<T>, gas_reserve: BalanceOf<T>) -> DispatchResult {
             T::ManageOrigin::ensure_origin(origin.clone())?;
-            GasReserve::<T>::set(gas_reserve);
+            GasReserve::<T, I>::set(gas_reserve);
             Ok(())
         }
         #[pallet::call_index(12)]
-        #[pallet::weight(<T as Config>::WeightInfo::set_gas_reserve())]
+        #[pallet::weight(<T as Config<I>>::WeightInfo::set_gas_reserve())]
         pub fn set_farming_params(
             origin: OriginFor<T>,
             mint_farming_token: bool,
@@ -935,24 +1188,194 @@ pub mod pallet {
                 pool_id,
                 currency_id,
             };
-            FarmingParameters::<T>::set(farming_params);
+            FarmingParameters::<T, I>::set(farming_params);
+
+            Ok(())
+        }
+
+        /// Sets the amount of deposits kept unstaked for [`Call::instant_withdraw`] and the fee charged on it
+        ///
+        /// Can only be called by the account set as [`Config::ManageOrigin`]
+        ///
+        /// Raising the target fills the buffer from new deposits and restaked funds,
+        /// lowering it leaves the excess in the buffer until it is drained by instant withdrawals
+        ///
+        /// # Errors
+        ///
+        /// * BadOrigin: Caller is not ManageOrigin
+        /// * InstantWithdrawFeeTooHigh: `instant_withdraw_fee` is above [`Config::MaxInstantWithdrawFee`]
+        #[pallet::call_index(15)]
+        #[pallet::weight(<T as Config<I>>::WeightInfo::set_liquidity_buffer_params())]
+        pub fn set_liquidity_buffer_params(
+            origin: OriginFor<T>,
+            target: BalanceOf<T>,
+            instant_withdraw_fee: Perbill,
+        ) -> DispatchResult {
+            T::ManageOrigin::ensure_origin(origin)?;
+            ensure!(
+                instant_withdraw_fee <= T::MaxInstantWithdrawFee::get(),
+                Error::<T, I>::InstantWithdrawFeeTooHigh
+            );
+            LiquidityBufferTarget::<T, I>::set(target);
+            InstantWithdrawFee::<T, I>::set(instant_withdraw_fee);
+            Self::deposit_event(Event::LiquidityBufferParamsSet {
+                target,
+                instant_withdraw_fee,
+            });
+            Ok(())
+        }
 
+        /// Sets the ordering and parameters of the strategies used to stake and unstake the pot
+        ///
+        /// Can only be called by the account set as [`Config::ManageOrigin`]
+        ///
+        /// # Errors
+        ///
+        /// * BadOrigin: Caller is not ManageOrigin
+        /// * PalletMisconfigured: No deposit or withdraw strategy given, a strategy was given twice
+        ///   or `max_stake_per_collator` is below [`Pallet::min_deposit`]
+        #[pallet::call_index(13)]
+        #[pallet::weight(<T as Config<I>>::WeightInfo::set_staking_strategy(strategy.collator_blocklist.len() as u32))]
+        pub fn set_staking_strategy(
+            origin: OriginFor<T>,
+            strategy: StakingStrategyConfigOf<T, I>,
+        ) -> DispatchResult {
+            T::ManageOrigin::ensure_origin(origin)?;
+            ensure!(
+                !strategy.deposit_strategies.is_empty() && !strategy.withdraw_strategies.is_empty(),
+                Error::<T, I>::PalletMisconfigured
+            );
+            ensure!(
+                strategy
+                    .deposit_strategies
+                    .iter()
+                    .collect::<BTreeSet<_>>()
+                    .len()
+                    == strategy.deposit_strategies.len()
+                    && strategy
+                        .withdraw_strategies
+                        .iter()
+                        .collect::<BTreeSet<_>>()
+                        .len()
+                        == strategy.withdraw_strategies.len(),
+                Error::<T, I>::PalletMisconfigured
+            );
+            ensure!(
+                strategy
+                    .max_stake_per_collator
+                    .map_or(true, |max| max >= Self::min_deposit()),
+                Error::<T, I>::PalletMisconfigured
+            );
+            StakingStrategy::<T, I>::set(strategy);
+            Ok(())
+        }
+
+        /// Selects where deposits of this lottery are kept and what asset its prizes are paid in
+        ///
+        /// Can only be called by the account set as [`Config::ManageOrigin`]
+        ///
+        /// # Errors
+        ///
+        /// * BadOrigin: Caller is not ManageOrigin
+        /// * PotNotEmpty: Users still have deposits or unclaimed winnings in the pot
+        /// * InvalidFarmingPool: The farming pool does not stake exactly the deposit asset or delays withdrawals
+        #[pallet::call_index(16)]
+        #[pallet::weight(<T as Config<I>>::WeightInfo::set_pot_backing())]
+        pub fn set_pot_backing(origin: OriginFor<T>, backing: PotBackingOf<T>) -> DispatchResult {
+            T::ManageOrigin::ensure_origin(origin)?;
+            ensure!(
+                Self::sum_of_deposits().is_zero() && Self::total_unclaimed_winnings().is_zero(),
+                Error::<T, I>::PotNotEmpty
+            );
+            if let PotBacking::Farming {
+                pool_id, deposit, ..
+            } = backing
+            {
+                let pool_info = pallet_farming::Pallet::<T>::pool_infos(pool_id)
+                    .ok_or(Error::<T, I>::InvalidFarmingPool)?;
+                ensure!(
+                    pool_info.basic_token == (deposit, Perbill::one())
+                        && pool_info.tokens_proportion.len() == 1
+                        && pool_info.withdraw_limit_time.is_zero(),
+                    Error::<T, I>::InvalidFarmingPool
+                );
+            }
+            Backing::<T, I>::set(backing);
+            Self::deposit_event(Event::PotBackingSet { backing });
             Ok(())
         }
     }
 
-    impl<T: Config> Pallet<T> {
+    impl<T: Config<I>, I: 'static> Pallet<T, I> {
         /// Get a unique, inaccessible account id from the `PotId`.
         pub(crate) fn account_id() -> T::AccountId {
             T::LotteryPot::get().into_account_truncating()
         }
+        /// Part of a deposit of `amount` that is kept unstaked to fill up the liquidity buffer
+        ///
+        /// Never fills the buffer beyond [`LiquidityBufferTarget`]
+        fn amount_to_buffer(amount: BalanceOf<T>) -> BalanceOf<T> {
+            let missing = Self::liquidity_buffer_target().saturating_sub(Self::liquidity_buffer());
+            let to_buffer = amount.min(missing);
+            let to_stake = amount.saturating_sub(to_buffer);
+            // never leave a remainder that is too small to be staked, stake part of the buffer's share instead
+            if !to_stake.is_zero() && to_stake < Self::min_deposit() {
+                amount.saturating_sub(Self::min_deposit())
+            } else {
+                to_buffer
+            }
+        }
+        fn to_asset_balance(amount: BalanceOf<T>) -> AssetBalanceOf<T> {
+            let converted: T::BalanceConversion = amount.into();
+            converted.into()
+        }
+        fn from_asset_balance(amount: AssetBalanceOf<T>) -> BalanceOf<T> {
+            let converted: T::BalanceConversion = amount.into();
+            converted.into()
+        }
+        /// Pays `amount` of deactivated funds of an asset backed pot back to `caller`
+        fn do_withdraw_asset(caller: T::AccountId, amount: BalanceOf<T>) -> DispatchResult {
+            let asset_amount = Self::to_asset_balance(amount);
+            let deposit = match Self::pot_backing() {
+                PotBacking::Staking => return Err(Error::<T, I>::PalletMisconfigured.into()),
+                PotBacking::Asset { deposit, .. } => deposit,
+                PotBacking::Farming {
+                    pool_id, deposit, ..
+                } => {
+                    // `set_pot_backing` ensures the pool releases withdrawn funds immediately
+                    pallet_farming::Pallet::<T>::withdraw_and_unstake(
+                        Self::account_id(),
+                        pool_id,
+                        Some(asset_amount),
+                    )?;
+                    deposit
+                }
+            };
+            <T as pallet_farming::Config>::MultiCurrency::transfer(
+                deposit,
+                &Self::account_id(),
+                &caller,
+                asset_amount,
+            )?;
+            SumOfDeposits::<T, I>::try_mutate(|sum| -> DispatchResult {
+                *sum = (*sum)
+                    .checked_sub(&amount)
+                    .ok_or(Error::<T, I>::ArithmeticUnderflow)?;
+                Ok(())
+            })?;
+            Self::deposit_event(Event::Withdrawn {
+                account: caller,
+                amount,
+            });
+            Ok(())
+        }
         /// Get an identifier for scheduling drawings from the `PotId`.
         fn lottery_schedule_id() -> Vec<u8> {
             T::LotteryPot::get().0.to_vec()
         }
         fn select_winning_balance(
             max_winning_balance: BalanceOf<T>,
-        ) -> Result<BalanceOf<T>, Error<T>> {
+        ) -> Result<BalanceOf<T>, Error<T, I>> {
             const MAX_NUMBER_OF_RESAMPLES: u8 = 3;
             let mut winning_number = 0; // XXX: This shouldn't need initialization but the compiler doesn't get it
             for n in 0u8..MAX_NUMBER_OF_RESAMPLES {
@@ -981,9 +1404,9 @@ pub mod pallet {
                     //       so this can be done manually on deployment of the pallet
                     // ensure!(
                     //     random.1 = randomness_established_at_block
-                    //         .saturating_add(<T as Config>::DrawingFreezeout::get())
+                    //         .saturating_add(<T as Config<I>>::DrawingFreezeout::get())
                     //         < <frame_system::Pallet<T>>::block_number(),
-                    //     Error::<T>::PalletMisconfigured
+                    //     Error::<T, I>::PalletMisconfigured
                     // );
                 }
                 let random_hash = random.0;
@@ -1027,7 +1450,7 @@ pub mod pallet {
             }
             // no risk of modulo bias here unless we ran out of retries above
             let winning_balance: BalanceOf<T> = BalanceOf::<T>::try_from(winning_number)
-                .map_err(|_| Error::<T>::ArithmeticOverflow)?
+                .map_err(|_| Error::<T, I>::ArithmeticOverflow)?
                 % max_winning_balance;
             log::debug!(
                 "winning_number: {:?}, winning balance: {:?}",
@@ -1038,18 +1461,18 @@ pub mod pallet {
         }
         fn select_winner(payout_for_winner: BalanceOf<T>) -> DispatchResult {
             if payout_for_winner.is_zero() {
-                return Err(Error::<T>::NothingToWin.into());
+                return Err(Error::<T, I>::NothingToWin.into());
             }
             let participating_funds = Self::total_pot();
             if participating_funds.is_zero() {
-                return Err(Error::<T>::NobodyPlaying.into());
+                return Err(Error::<T, I>::NobodyPlaying.into());
             }
             // Match random number to winner. We select a winning **balance** and then just add up accounts in the order they're stored until the sum of balance exceeds the winning amount
             // IMPORTANT: This order and active balances must be locked to modification after the random seed is created (relay BABE randomness, 2 epochs ago)
             let winning_balance = Self::select_winning_balance(participating_funds)?;
             let mut maybe_winner: Option<T::AccountId> = None;
             let mut count: BalanceOf<T> = 0u32.into();
-            for (account, balance) in ActiveBalancePerUser::<T>::iter() {
+            for (account, balance) in ActiveBalancePerUser::<T, I>::iter() {
                 count += balance;
                 if count >= winning_balance {
                     maybe_winner = Some(account);
@@ -1057,17 +1480,17 @@ pub mod pallet {
                 }
             }
             // Should be impossible: If no winner was selected, return Error
-            ensure!(maybe_winner.is_some(), Error::<T>::NoWinnerFound);
+            ensure!(maybe_winner.is_some(), Error::<T, I>::NoWinnerFound);
             let winner = maybe_winner.expect("we checked a winner exists before. qed");
             // Allow winner to manually claim their winnings later
-            UnclaimedWinningsByAccount::<T>::mutate(winner.clone(), |maybe_balance| {
+            UnclaimedWinningsByAccount::<T, I>::mutate(winner.clone(), |maybe_balance| {
                 *maybe_balance = Some(
                     maybe_balance
                         .unwrap_or_else(|| 0u32.into())
                         .saturating_add(payout_for_winner),
                 );
             });
-            TotalUnclaimedWinnings::<T>::try_mutate(|old| {
+            TotalUnclaimedWinnings::<T, I>::try_mutate(|old| {
                 *old = (*old)
                     .checked_add(&payout_for_winner)
                     .ok_or(ArithmeticError::Overflow)?;
@@ -1085,11 +1508,132 @@ pub mod pallet {
             Ok(())
         }
 
+        /// Removes `amount` from the active (win-eligible) funds of `caller`, burning the farming token if configured
+        fn do_deactivate_funds(caller: &T::AccountId, amount: BalanceOf<T>) -> DispatchResult {
+            let farming_params = FarmingParameters::<T, I>::get();
+            if farming_params.destroy_farming_token {
+                let convert_amount: T::BalanceConversion = amount.into();
+                pallet_farming::Pallet::<T>::withdraw_and_unstake(
+                    caller.clone(),
+                    farming_params.pool_id,
+                    Some(convert_amount.into()),
+                )?;
+                <T as pallet_farming::Config>::MultiCurrency::withdraw(
+                    farming_params.currency_id,
+                    caller,
+                    convert_amount.into(),
+                )?;
+            }
+
+            log::debug!("Deactivating {:?} tokens", amount);
+            // Ensure user has enough funds active and mark them as offboarding (remove from `ActiveBalancePerUser`)
+            ActiveBalancePerUser::<T, I>::try_mutate_exists(caller.clone(), |maybe_balance| {
+                match maybe_balance {
+                    None => Err(Error::<T, I>::NoDepositForAccount),
+                    Some(balance) => {
+                        // Withdraw only what's active
+                        ensure!(*balance >= amount, Error::<T, I>::WithdrawAboveDeposit);
+                        // store reduced balance
+                        *maybe_balance = match balance
+                            .checked_sub(&amount)
+                            .ok_or(Error::<T, I>::ArithmeticUnderflow)?
+                        {
+                            new_balance if new_balance.is_zero() => {
+                                // remove user if this was his last remaining funds
+                                TotalUsers::<T, I>::try_mutate(|users| {
+                                    *users = (*users)
+                                        .checked_sub(1u32)
+                                        .ok_or(Error::<T, I>::ArithmeticUnderflow)?;
+                                    Ok(())
+                                })?;
+                                None
+                            }
+                            new_balance => Some(new_balance),
+                        };
+                        TotalPot::<T, I>::try_mutate(|pot| {
+                            *pot = (*pot)
+                                .checked_sub(&amount)
+                                .ok_or(Error::<T, I>::ArithmeticUnderflow)?;
+                            Ok(())
+                        })?;
+                        Ok(())
+                    }
+                }
+            })?;
+
+            Ok(())
+        }
+
+        /// Queues a withdrawal of `amount` deactivated funds to `caller` and unstakes collators as needed to serve it
+        fn do_request_withdraw(caller: T::AccountId, amount: BalanceOf<T>) -> DispatchResult {
+            let now = <frame_system::Pallet<T>>::block_number();
+            log::debug!("Requesting withdraw of {:?} tokens", amount);
+            // Mark funds as offboarding
+            WithdrawalRequestQueue::<T, I>::mutate(|withdraw_vec| {
+                withdraw_vec.push(Request {
+                    user: caller.clone(),
+                    block: now,
+                    balance: amount,
+                })
+            });
+
+            // Unstaking workflow
+            // 1. See if this withdrawal can be serviced with left-over balance from an already unstaking collator, if so deduct remaining balance and schedule the request
+            // 2. If it can't, find the collator with the smallest delegation that is able to handle this withdrawal request and fully unstake it
+            // 3. Add balance overshoot to "remaining balance" to handle further requests from
+
+            // If the withdrawal fits in the currently unstaking funds, do nothing else
+            SurplusUnstakingBalance::<T, I>::try_mutate(|remaining_balance| {
+                match (*remaining_balance).checked_sub(&amount){
+                    Some(subtracted) => {
+                        *remaining_balance = subtracted;
+                        Ok(())
+                    }
+                    _ => {
+                        Err("not enough left to handle this request from current unstaking funds")
+                    }
+                }
+            })
+            .or_else(|_| {
+                // Withdrawal needs extra collators to unstake to have enough funds to serve withdrawals, do it
+                let reserve = SurplusUnstakingBalance::<T, I>::get();
+                let mut remaining_to_withdraw = amount - reserve;
+
+                // unstake collators as necessary. This updates `SurplusUnstakingBalance`
+                for collator_to_unstake in Self::calculate_withdrawal_distribution(remaining_to_withdraw){
+                    let our_stake = StakedCollators::<T, I>::get(collator_to_unstake.clone());
+                    remaining_to_withdraw = remaining_to_withdraw.saturating_sub(our_stake);
+                    // The following call updates `SurplusUnstakingBalance` with newly unstaked funds
+                    Self::do_unstake_collator(now,collator_to_unstake)?;
+                }
+                if !remaining_to_withdraw.is_zero() {
+                    return Err("FATAL: Didn't unstake the full requested balance (or more)");
+                }
+                SurplusUnstakingBalance::<T, I>::try_mutate(|remaining_balance| {
+                    match (*remaining_balance).checked_sub(&amount){
+                        Some(subtracted) => {
+                            *remaining_balance = subtracted;
+                            Ok(())
+                        }
+                        _ => {
+                            Err("not enough unstaking balance to handle request after unstaking additional collators")
+                        }
+                    }
+                })
+            })?;
+            // END UNSTAKING SECTION
+            Self::deposit_event(Event::ScheduledWithdraw {
+                account: caller,
+                amount,
+            });
+            Ok(())
+        }
+
         /// Unstake any collators we can unstake
         /// This is infallible, if any step fails we just leave the collator in the request queue
         fn finish_unstaking_collators() {
             let now = <frame_system::Pallet<T>>::block_number();
-            let mut unstaking = UnstakingCollators::<T>::get();
+            let mut unstaking = UnstakingCollators::<T, I>::get();
             let original_len = unstaking.len();
             if unstaking.is_empty() {
                 return;
@@ -1097,7 +1641,7 @@ pub mod pallet {
             // Unstake what we can (false), leave the rest (true)
             unstaking.retain(|collator|{
                     // Leave collators that are not finished unstaking alone
-                    if collator.since + <T as Config>::UnstakeLockTime::get() > now {
+                    if collator.since + <T as Config<I>>::UnstakeLockTime::get() > now {
                         return true;
                     };
                     // Recover funds locked in the collator
@@ -1134,8 +1678,8 @@ pub mod pallet {
                         Ok(_) => {
                             // collator was unstaked, its funds are now "free balance", we track it so it won't be given to the next winner
                             log::debug!("Unstaked {:?} from collator {:?}",balance_to_unstake,collator.account.clone());
-                            <UnlockedUnstakingFunds<T>>::mutate(|unlocked| *unlocked = (*unlocked).saturating_add(balance_to_unstake));
-                            <StakedCollators<T>>::remove(collator.account.clone());
+                            <UnlockedUnstakingFunds<T, I>>::mutate(|unlocked| *unlocked = (*unlocked).saturating_add(balance_to_unstake));
+                            <StakedCollators<T, I>>::remove(collator.account.clone());
                             // don't retain this collator in the unstaking collators vec
                             false
                         },
@@ -1147,7 +1691,7 @@ pub mod pallet {
                     original_len - unstaking.len(),
                     original_len
                 );
-                UnstakingCollators::<T>::put(unstaking);
+                UnstakingCollators::<T, I>::put(unstaking);
             }
         }
 
@@ -1162,13 +1706,30 @@ pub mod pallet {
             // - is funds that were previously unstaked
             // - is surplus funds (we may have some from `finish_unstaking_collators`)
             // NOTE: Funds tracked in `surplus_unstaking_balance` might still be partially stake locked
-            let outstanding_balance_to_withdraw = <WithdrawalRequestQueue<T>>::get()
+            let outstanding_balance_to_withdraw = <WithdrawalRequestQueue<T, I>>::get()
                 .iter()
                 .map(|request| request.balance)
                 .reduce(|acc, balance| acc + balance)
                 .unwrap_or_else(|| 0u32.into());
-            let restakable_balance =
+            let mut restakable_balance =
                 Self::unlocked_unstaking_funds().saturating_sub(outstanding_balance_to_withdraw);
+            // Top up the liquidity buffer before restaking
+            let to_buffer = restakable_balance
+                .min(Self::liquidity_buffer_target().saturating_sub(Self::liquidity_buffer()));
+            if !to_buffer.is_zero() {
+                SurplusUnstakingBalance::<T, I>::mutate(|bal| {
+                    *bal = (*bal).saturating_sub(to_buffer)
+                });
+                UnlockedUnstakingFunds::<T, I>::try_mutate(|unlocked| -> DispatchResult {
+                    *unlocked = (*unlocked)
+                        .checked_sub(&to_buffer)
+                        .ok_or(Error::<T, I>::ArithmeticUnderflow)?;
+                    Ok(())
+                })?;
+                LiquidityBuffer::<T, I>::mutate(|buffer| *buffer += to_buffer);
+                restakable_balance -= to_buffer;
+                log::debug!("Moved {:?} into the liquidity buffer", to_buffer);
+            }
             if restakable_balance < Self::min_deposit() {
                 log::debug!(
                     "Restakable balance of {:?} is below staking minimum of {:?}. Not restaking",
@@ -1192,16 +1753,16 @@ pub mod pallet {
                     collator
                 );
             }
-            SurplusUnstakingBalance::<T>::try_mutate(|bal| -> DispatchResult {
+            SurplusUnstakingBalance::<T, I>::try_mutate(|bal| -> DispatchResult {
                 *bal = (*bal)
                     .checked_sub(&restakable_balance)
-                    .ok_or(Error::<T>::ArithmeticUnderflow)?;
+                    .ok_or(Error::<T, I>::ArithmeticUnderflow)?;
                 Ok(())
             })?;
-            UnlockedUnstakingFunds::<T>::try_mutate(|unlocked| -> DispatchResult {
+            UnlockedUnstakingFunds::<T, I>::try_mutate(|unlocked| -> DispatchResult {
                 *unlocked = (*unlocked)
                     .checked_sub(&restakable_balance)
-                    .ok_or(Error::<T>::ArithmeticUnderflow)?;
+                    .ok_or(Error::<T, I>::ArithmeticUnderflow)?;
                 Ok(())
             })?;
             Ok(())
@@ -1215,7 +1776,7 @@ pub mod pallet {
         #[named]
         fn do_process_matured_withdrawals() -> DispatchResult {
             log::trace!(function_name!());
-            if <WithdrawalRequestQueue<T>>::get().is_empty() {
+            if <WithdrawalRequestQueue<T, I>>::get().is_empty() {
                 return Ok(()); // nothing to do
             }
             let now = <frame_system::Pallet<T>>::block_number();
@@ -1224,7 +1785,7 @@ pub mod pallet {
                 Self::unlocked_unstaking_funds()
             );
             // Pay down the list from top (oldest) to bottom until we've paid out everyone or run out of available funds
-            <WithdrawalRequestQueue<T>>::mutate(|request_vec| -> Result<(), DispatchError> {
+            <WithdrawalRequestQueue<T, I>>::mutate(|request_vec| -> Result<(), DispatchError> {
                 let mut left_overs: Vec<Request<_, _, _>> = Vec::new();
                 for request in request_vec.iter() {
                     let funds_available_to_withdraw = Self::unlocked_unstaking_funds();
@@ -1234,7 +1795,7 @@ pub mod pallet {
                         continue;
                     }
                     // Don't pay anyone still timelocked
-                    if request.block + <T as Config>::UnstakeLockTime::get() > now {
+                    if request.block + <T as Config<I>>::UnstakeLockTime::get() > now {
                         left_overs.push((*request).clone());
                         continue;
                     }
@@ -1246,7 +1807,9 @@ pub mod pallet {
                         continue;
                     }
                     // we know we can pay this out, do it
-                    <SumOfDeposits<T>>::mutate(|sum| *sum = (*sum).saturating_sub(request.balance));
+                    <SumOfDeposits<T, I>>::mutate(|sum| {
+                        *sum = (*sum).saturating_sub(request.balance)
+                    });
                     log::debug!(
                         "Transferring {:?} to {:?}",
                         request.balance.clone(),
@@ -1258,10 +1821,10 @@ pub mod pallet {
                         request.balance,
                         KeepAlive,
                     )?;
-                    <UnlockedUnstakingFunds<T>>::try_mutate(|funds| -> DispatchResult {
+                    <UnlockedUnstakingFunds<T, I>>::try_mutate(|funds| -> DispatchResult {
                         *funds = (*funds)
                             .checked_sub(&request.balance)
-                            .ok_or(Error::<T>::ArithmeticUnderflow)?;
+                            .ok_or(Error::<T, I>::ArithmeticUnderflow)?;
                         Ok(())
                     })?;
                     Self::deposit_event(Event::Withdrawn {
@@ -1286,7 +1849,7 @@ pub mod pallet {
         }
     }
 
-    impl<T: Config> Pallet<T> {
+    impl<T: Config<I>, I: 'static> Pallet<T, I> {
         // public getters for lottery state
         /// Returns the block the next drawing will execute, if any
         pub fn next_drawing_at() -> Option<T::BlockNumber> {
@@ -1307,15 +1870,96 @@ pub mod pallet {
             // waiting to serve a pending withdrawal in the next cycle.
             // These free funds must not be touched until then, so we don't consider this balance a surplus
             let unlocked = Self::unlocked_unstaking_funds();
+            // The liquidity buffer holds user deposits kept unstaked for instant withdrawals
+            let buffer = Self::liquidity_buffer();
 
             non_staked_funds
                 .saturating_sub(unclaimed)
                 .saturating_sub(unlocked)
+                .saturating_sub(buffer)
+        }
+        /// weight of a deposit into the pot's backing, only staking deposits are spread over collators
+        pub(crate) fn deposit_weight() -> Weight {
+            match Self::pot_backing() {
+                PotBacking::Staking => <T as Config<I>>::WeightInfo::deposit(
+                    Self::total_users(),
+                    pallet_parachain_staking::Pallet::<T>::selected_candidates().len() as u32,
+                ),
+                PotBacking::Asset { .. } => {
+                    <T as Config<I>>::WeightInfo::deposit(Self::total_users(), 0)
+                }
+                PotBacking::Farming { .. } => {
+                    <T as Config<I>>::WeightInfo::deposit(Self::total_users(), 0)
+                        .saturating_add(<T as pallet_farming::Config>::WeightInfo::deposit())
+                }
+            }
+        }
+        /// weight of a withdrawal from the pot's backing, only staking withdrawals unstake collators
+        pub(crate) fn request_withdraw_weight() -> Weight {
+            match Self::pot_backing() {
+                PotBacking::Staking => <T as Config<I>>::WeightInfo::request_withdraw(
+                    Self::total_users(),
+                    pallet_parachain_staking::Pallet::<T>::selected_candidates().len() as u32,
+                ),
+                PotBacking::Asset { .. } => {
+                    <T as Config<I>>::WeightInfo::request_withdraw(Self::total_users(), 0)
+                }
+                PotBacking::Farming { .. } => {
+                    <T as Config<I>>::WeightInfo::request_withdraw(Self::total_users(), 0)
+                        .saturating_add(<T as pallet_farming::Config>::WeightInfo::withdraw())
+                        .saturating_add(<T as pallet_farming::Config>::WeightInfo::claim())
+                }
+            }
+        }
+        /// weight of collecting the rewards of the pot's backing at the start of a drawing
+        pub(crate) fn claim_backing_rewards_weight() -> Weight {
+            match Self::pot_backing() {
+                PotBacking::Staking => {
+                    <T as pallet_parachain_staking::Config>::WeightInfo::claim_rewards(
+                        <T as pallet_parachain_staking::Config>::MaxDelegationsPerDelegator::get(),
+                    )
+                }
+                PotBacking::Asset { .. } => Weight::zero(),
+                PotBacking::Farming { .. } => <T as pallet_farming::Config>::WeightInfo::claim(),
+            }
         }
         /// funds in the lottery pallet that are not needed/reserved for anything and can be paid to the next winner
         pub fn current_prize_pool() -> BalanceOf<T> {
-            // Ensure we keep a gas reserve from the staking rewards to be able to pay tx fees for staking/unstaking and withdrawals
-            Self::surplus_funds().saturating_sub(Self::gas_reserve())
+            match Self::pot_backing() {
+                // Ensure we keep a gas reserve from the staking rewards to be able to pay tx fees for staking/unstaking and withdrawals
+                PotBacking::Staking => Self::surplus_funds().saturating_sub(Self::gas_reserve()),
+                // user deposits sit in the pot account next to the prize
+                PotBacking::Asset { deposit, prize } if deposit == prize => {
+                    Self::asset_surplus_funds(prize).saturating_sub(Self::sum_of_deposits())
+                }
+                PotBacking::Asset { prize, .. } => Self::asset_surplus_funds(prize),
+                // deposits are held by the farming pool, rewards are claimed into the pot right before the drawing
+                PotBacking::Farming { pool_id, prize, .. } => {
+                    let rewards = pallet_farming::Pallet::<T>::get_farming_rewards(
+                        &Self::account_id(),
+                        pool_id,
+                    )
+                    .unwrap_or_default()
+                    .into_iter()
+                    .filter(|(currency, _)| *currency == prize)
+                    .fold(Zero::zero(), |sum: AssetBalanceOf<T>, (_, amount)| {
+                        sum.saturating_add(amount)
+                    });
+                    Self::asset_surplus_funds(prize)
+                        .saturating_add(Self::from_asset_balance(rewards))
+                }
+            }
+        }
+        /// `prize` asset in the pot that is not assigned to previous winners, keeping the pot account alive
+        fn asset_surplus_funds(prize: <T as pallet_farming::Config>::CurrencyId) -> BalanceOf<T> {
+            let pot_balance = <T as pallet_farming::Config>::MultiCurrency::free_balance(
+                prize,
+                &Self::account_id(),
+            );
+            let existential_deposit =
+                <T as pallet_farming::Config>::MultiCurrency::minimum_balance(prize);
+            Self::from_asset_balance(pot_balance.saturating_sub(existential_deposit))
+                .saturating_sub(Self::total_unclaimed_winnings())
         }
         /// Returns if we're within the pre-drawing time where deposits/withdrawals are frozen
         pub fn not_in_drawing_freezeout() -> bool {
@@ -1325,7 +1969,7 @@ pub mod pallet {
                 }
                 Some(drawing) => {
                     let now = <frame_system::Pallet<T>>::block_number();
-                    now < drawing.saturating_sub(<T as Config>::DrawingFreezeout::get())
+                    now < drawing.saturating_sub(<T as Config<I>>::DrawingFreezeout::get())
                 }
             }
         }
@@ -0,0 +1,95 @@
+// Copyright 2020-2024 Manta Network.
+// This file is part of Manta.
+//
+// Manta is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// Manta is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Manta.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Do storage migrations of the lottery:
+//! * initial parameters of a lottery instance that was added to a running chain, which does not
+//!   get the deposit, withdrawal and gas reserve limits of its genesis config.
+
+use super::*;
+use core::marker::PhantomData;
+use frame_support::{
+    dispatch::GetStorageVersion,
+    log,
+    pallet_prelude::Weight,
+    traits::{Get, OnRuntimeUpgrade},
+};
+use pallet_parachain_staking::BalanceOf;
+#[cfg(feature = "try-runtime")]
+use sp_runtime::{traits::Zero, DispatchError};
+#[cfg(feature = "try-runtime")]
+use sp_std::vec::Vec;
+
+/// Storage migration setting the minimum deposit, minimum withdrawal and gas reserve of a new
+/// lottery instance `I`, which would otherwise all be zero.
+pub struct InitializeLotteryParams<T, I, MinDeposit, MinWithdraw, GasReserve>(
+    PhantomData<(T, I, MinDeposit, MinWithdraw, GasReserve)>,
+);
+
+impl<T, I, MinDeposit, MinWithdraw, GasReserve> OnRuntimeUpgrade
+    for InitializeLotteryParams<T, I, MinDeposit, MinWithdraw, GasReserve>
+where
+    T: Config<I>,
+    I: 'static,
+    MinDeposit: Get<BalanceOf<T>>,
+    MinWithdraw: Get<BalanceOf<T>>,
+    GasReserve: Get<BalanceOf<T>>,
+{
+    fn on_runtime_upgrade() -> Weight {
+        // a lottery built from a genesis config is already at the current storage version
+        if Pallet::<T, I>::on_chain_storage_version() == 0 {
+            log::info!(target: "lottery", "Start to initialize the lottery parameters.");
+            pallet::MinDeposit::<T, I>::put(MinDeposit::get());
+            pallet::MinWithdraw::<T, I>::put(MinWithdraw::get());
+            pallet::GasReserve::<T, I>::put(GasReserve::get());
+            // Update storage version.
+            Pallet::<T, I>::current_storage_version().put::<Pallet<T, I>>();
+            T::DbWeight::get().reads_writes(1, 4)
+        } else {
+            log::info!("✅ no migration for lottery.");
+            // only 1 read
+            T::DbWeight::get().reads(1)
+        }
+    }
+
+    #[cfg(feature = "try-runtime")]
+    fn pre_upgrade() -> Result<Vec<u8>, DispatchError> {
+        if Pallet::<T, I>::on_chain_storage_version() != 0 {
+            log::info!(
+                "Lottery parameters are already initialized, the migration won't be executed."
+            );
+        }
+        Ok(Vec::new())
+    }
+
+    #[cfg(feature = "try-runtime")]
+    fn post_upgrade(_state: Vec<u8>) -> Result<(), DispatchError> {
+        if Pallet::<T, I>::on_chain_storage_version() != Pallet::<T, I>::current_storage_version() {
+            return Err(DispatchError::Other(
+                "Storage version of the lottery wasn't updated.",
+            ));
+        }
+        if Pallet::<T, I>::min_deposit().is_zero()
+            || Pallet::<T, I>::min_withdraw().is_zero()
+            || Pallet::<T, I>::gas_reserve().is_zero()
+        {
+            return Err(DispatchError::Other(
+                "Lottery parameters must not be zero after the migration.",
+            ));
+        }
+        log::info!("✅ Storage migration for lottery has been executed successfully.");
+        Ok(())
+    }
+}
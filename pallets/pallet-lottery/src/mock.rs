@@ -21,11 +21,14 @@ use crate as pallet_lottery;
 use crate::{pallet, Config, FarmingParamsOf};
 use calamari_runtime::currency::KMA;
 use frame_support::{
-    assert_ok, construct_runtime, ord_parameter_types,
+    assert_ok, construct_runtime,
+    instances::Instance1,
+    ord_parameter_types,
     pallet_prelude::*,
     parameter_types,
     traits::{
-        AsEnsureOriginWithArg, ConstU128, ConstU32, EitherOfDiverse, Everything, GenesisBuild,
+        fungibles, AsEnsureOriginWithArg, ConstU128, ConstU32, EitherOfDiverse, Everything,
+        GenesisBuild,
     },
     weights::Weight,
 };
@@ -95,6 +98,7 @@ construct_runtime!(
         BlockAuthor: block_author::{Pallet, Storage},
         CollatorSelection: manta_collator_selection::{Pallet, Call, Storage, Config<T>, Event<T>},
         Lottery: pallet_lottery::{Pallet, Call, Storage, Event<T>, Config<T>},
+        AssetLottery: pallet_lottery::<Instance1>::{Pallet, Call, Storage, Event<T>},
         Preimage: pallet_preimage::{Pallet, Call, Storage, Event<T>},
         Assets: pallet_assets::{Pallet, Storage, Config<T>, Event<T>},
         AssetManager: pallet_asset_manager::{Pallet, Call, Storage, Event<T>},
@@ -261,6 +265,7 @@ impl manta_collator_selection::Config for Test {
     type ValidatorRegistration = IsRegistered;
     type WeightInfo = ();
     type CanAuthor = ();
+    type MaxEvictionWindow = ConstU32<4>;
 }
 
 parameter_types! {
@@ -316,6 +321,11 @@ impl pallet_parachain_staking::Config for Test {
     type MinDelegatorStk = ConstU128<{ 5_000 * KMA }>;
     type OnCollatorPayout = ();
     type OnNewRound = ();
+    type OnSlash = ();
+    type SlashDeferDuration = ConstU32<2>;
+    type RedelegationCooldown = ConstU32<2>;
+    type CommissionChangeDelay = ConstU32<2>;
+    type MaxCollatorSelectionMigrationsPerBlock = ConstU32<2>;
     type WeightInfo = calamari_runtime::weights::pallet_parachain_staking::SubstrateWeight<Test>; // XXX: Maybe use the actual calamari weights?
 }
 
@@ -412,6 +422,14 @@ impl AssetRegistry for MantaAssetRegistry {
             metadata.is_frozen,
         )
     }
+
+    fn destroy_asset(asset_id: &CalamariAssetId) -> DispatchResult {
+        let limit = <Test as pallet_assets::Config>::RemoveItemsLimit::get();
+        <Assets as fungibles::Destroy<AccountId>>::start_destroy(*asset_id, None)?;
+        <Assets as fungibles::Destroy<AccountId>>::destroy_accounts(*asset_id, limit)?;
+        <Assets as fungibles::Destroy<AccountId>>::destroy_approvals(*asset_id, limit)?;
+        <Assets as fungibles::Destroy<AccountId>>::finish_destroy(*asset_id)
+    }
 }
 
 parameter_types! {
@@ -454,6 +472,10 @@ impl AssetConfig<Test> for MantaAssetConfig {
     type FungibleLedger = NativeAndNonNative<Test, MantaAssetConfig, Balances, Assets>;
 }
 
+parameter_types! {
+    pub const ProposedBounds: (u128, u128) = (1, u128::MAX);
+}
+
 impl pallet_asset_manager::Config for Test {
     type RuntimeEvent = RuntimeEvent;
     type AssetId = CalamariAssetId;
@@ -467,12 +489,24 @@ impl pallet_asset_manager::Config for Test {
     type TokenNameMaxLen = ConstU32<100>;
     type TokenSymbolMaxLen = ConstU32<100>;
     type PermissionlessAssetRegistryCost = ConstU128<1000>;
+    type SiblingAssetOrigin = EnsureNever<(u32, AccountId)>;
+    type VetoOrigin = EnsureRoot<AccountId>;
+    type AssetChallengePeriod = ConstU32<10>;
+    type MaxActivationsPerBlock = ConstU32<10>;
+    type AssetProposalDeposit = ConstU128<1_000>;
+    type ProposedMinBalanceBounds = ProposedBounds;
+    type ProposedUnitsPerSecondBounds = ProposedBounds;
+    type NativePrice = ();
+    type NativeUnitsPerSecond = ConstU128<1_000_000_000>;
+    type SpotPriceAveragingPeriod = ConstU32<10>;
+    type MaxDynamicPricedAssets = ConstU32<10>;
 }
 
 parameter_types! {
     pub const FarmingKeeperPalletId: PalletId = PalletId(*b"bf/fmkpr");
     pub const FarmingRewardIssuerPalletId: PalletId = PalletId(*b"bf/fmrir");
     pub const TreasuryAccount: AccountId = TREASURY_ACCOUNT;
+    pub const FarmingMaxBoost: Perbill = Perbill::from_percent(100);
 }
 
 ord_parameter_types! {
@@ -489,6 +523,18 @@ impl pallet_farming::Config for Test {
     type TreasuryAccount = TreasuryAccount;
     type Keeper = FarmingKeeperPalletId;
     type RewardIssuer = FarmingRewardIssuerPalletId;
+    type Currency = Balances;
+    type PoolCreationDeposit = ConstU128<1000>;
+    type LpAssets = AssetManager;
+    type MaxBasicRewards = ConstU32<4>;
+    type MaxRewardSteps = ConstU32<4>;
+    type MaxWithdrawLimitTime = ConstU32<100>;
+    type MaxClaimLimitTime = ConstU32<100>;
+    type VeEpochLength = ConstU32<10>;
+    type MaxLockEpochs = ConstU32<10>;
+    type MaxBoost = FarmingMaxBoost;
+    type MaxVotes = ConstU32<4>;
+    type Liquidity = ();
     type WeightInfo = ();
 }
 
@@ -496,15 +542,18 @@ impl block_author::Config for Test {}
 
 use frame_support::PalletId;
 use frame_system::EnsureRoot;
-use manta_primitives::constants::LOTTERY_PALLET_ID;
+use manta_primitives::constants::{ASSET_LOTTERY_PALLET_ID, LOTTERY_PALLET_ID};
 parameter_types! {
     pub const LotteryPotId: PalletId = LOTTERY_PALLET_ID; // ensure we don't deposit/withdraw in the drawing block
+    pub const AssetLotteryPotId: PalletId = ASSET_LOTTERY_PALLET_ID;
     /// Time in blocks between lottery drawings
     pub DrawingInterval: BlockNumber = DefaultBlocksPerRound::get();
     /// Time in blocks *before* a drawing in which modifications of the win-eligble pool are prevented
     pub DrawingFreezeout: BlockNumber = 5;
     /// Time in blocks until a collator is done unstaking
     pub UnstakeLockTime: BlockNumber = LeaveDelayRounds::get() * DefaultBlocksPerRound::get();
+    /// Highest fee governance can charge on instant withdrawals
+    pub const MaxInstantWithdrawFee: Perbill = Perbill::from_percent(5);
 }
 
 use frame_support::traits::Currency;
@@ -532,6 +581,16 @@ impl frame_support::traits::EstimateCallFee<pallet::Call<Test>, BalanceOf<Test>>
         3 * KMA
     }
 }
+impl frame_support::traits::EstimateCallFee<pallet::Call<Test, Instance1>, BalanceOf<Test>>
+    for MockEstimateFee
+{
+    fn estimate_call_fee(
+        _call: &pallet::Call<Test, Instance1>,
+        _post_info: frame_support::dispatch::PostDispatchInfo,
+    ) -> BalanceOf<Test> {
+        3 * KMA
+    }
+}
 impl Config for Test {
     type RuntimeCall = RuntimeCall;
     type RuntimeEvent = RuntimeEvent;
@@ -544,6 +603,29 @@ impl Config for Test {
     type DrawingInterval = DrawingInterval;
     type DrawingFreezeout = DrawingFreezeout;
     type UnstakeLockTime = UnstakeLockTime;
+    type MaxInstantWithdrawFee = MaxInstantWithdrawFee;
+    type MaxDepositStrategies = ConstU32<4>;
+    type MaxWithdrawStrategies = ConstU32<3>;
+    type MaxCollatorBlocklistLen = ConstU32<100>;
+    type BalanceConversion = Balance;
+    type WeightInfo = ();
+}
+impl Config<Instance1> for Test {
+    type RuntimeCall = RuntimeCall;
+    type RuntimeEvent = RuntimeEvent;
+    type Scheduler = Scheduler;
+    type EstimateCallFee = MockEstimateFee;
+    type RandomnessSource = TestRandomness<Test>;
+    type ManageOrigin = frame_system::EnsureRoot<AccountId>;
+    type PalletsOrigin = OriginCaller;
+    type LotteryPot = AssetLotteryPotId;
+    type DrawingInterval = DrawingInterval;
+    type DrawingFreezeout = DrawingFreezeout;
+    type UnstakeLockTime = UnstakeLockTime;
+    type MaxInstantWithdrawFee = MaxInstantWithdrawFee;
+    type MaxDepositStrategies = ConstU32<4>;
+    type MaxWithdrawStrategies = ConstU32<3>;
+    type MaxCollatorBlocklistLen = ConstU32<100>;
     type BalanceConversion = Balance;
     type WeightInfo = ();
 }
@@ -663,6 +745,7 @@ impl ExtBuilder {
             min_withdraw: 5_000 * KMA,
             gas_reserve: 10_000 * KMA,
             farming_pool_params: farming_params,
+            phantom: PhantomData,
         }
         .assimilate_storage(&mut t)
         .expect("pallet_lottery's storage can be assimilated");
@@ -25,7 +25,7 @@ use sp_runtime::{
 use sp_std::{vec, vec::Vec};
 
 #[named]
-pub(super) fn reactivate_bottom_collators<T: Config>(
+pub(super) fn reactivate_bottom_collators<T: Config<I>, I: 'static>(
     active_collators: &[T::AccountId],
     new_deposit: BalanceOf<T>,
 ) -> Vec<(T::AccountId, BalanceOf<T>)> {
@@ -35,16 +35,17 @@ pub(super) fn reactivate_bottom_collators<T: Config>(
     let mut remaining_deposit = new_deposit;
 
     // We only consider collators we're already staked to that are also currently active (and not being unstaked)
-    for collator in StakedCollators::<T>::iter_keys().filter(|coll| active_collators.contains(coll))
+    for collator in
+        StakedCollators::<T, I>::iter_keys().filter(|coll| active_collators.contains(coll))
     {
-        let staked = StakedCollators::<T>::get(collator.clone());
+        let staked = StakedCollators::<T, I>::get(collator.clone());
         let info = pallet_parachain_staking::Pallet::<T>::candidate_info(collator.clone())
             .expect("is active collator, therefore it has collator info. qed");
 
         // If collator not exist in delegatorState(PotAccount).delegations, ignore
-        if let Some(state) =
-            pallet_parachain_staking::Pallet::<T>::delegator_state(crate::Pallet::<T>::account_id())
-        {
+        if let Some(state) = pallet_parachain_staking::Pallet::<T>::delegator_state(
+            crate::Pallet::<T, I>::account_id(),
+        ) {
             let mut is_kick = true;
             for x in &state.delegations.0 {
                 if x.owner == collator {
@@ -80,7 +81,8 @@ pub(super) fn reactivate_bottom_collators<T: Config>(
                 remaining_deposit - this_deposit
             );
             // Ensure we don't try to stake a smaller than allowed delegation to a collator
-            if remaining_deposit.saturating_sub(this_deposit) < crate::Pallet::<T>::min_deposit() {
+            if remaining_deposit.saturating_sub(this_deposit) < crate::Pallet::<T, I>::min_deposit()
+            {
                 deposits.push((collator, remaining_deposit)); // put the full remaining balance in this collator
                 break;
             } else {
@@ -89,11 +91,35 @@ pub(super) fn reactivate_bottom_collators<T: Config>(
             }
         }
     }
+    // If we have re-activated any collators and have leftover funds, we just distribute all surplus tokens to them evenly and call it a day
+    let mut remaining_deposit = new_deposit.saturating_sub(
+        deposits
+            .iter()
+            .map(|deposit| deposit.1)
+            .reduce(|sum, elem| sum + elem)
+            .unwrap_or_else(|| 0u32.into()),
+    );
+    if !deposits.is_empty() && !remaining_deposit.is_zero() {
+        let deposit_per_collator = remaining_deposit / BalanceOf::<T>::from(deposits.len() as u32);
+        for deposit in &mut deposits {
+            deposit.1 += deposit_per_collator;
+            remaining_deposit -= deposit_per_collator;
+        }
+        // the remainder of the division goes to the last collator
+        if let Some(last_deposit) = deposits.last_mut() {
+            last_deposit.1 += remaining_deposit;
+        }
+        log::debug!(
+            "spread surplus over {:?} reactivated collators: {:?}",
+            deposits.len(),
+            deposits
+        );
+    }
     deposits
 }
 
 /// second concern: We want to maximize staking APY earned, so we want to balance the staking pools with our deposits while conserving gas
-pub(super) fn split_to_underallocated_collators<T: Config>(
+pub(super) fn split_to_underallocated_collators<T: Config<I>, I: 'static>(
     active_collators: &[T::AccountId],
     new_deposit: BalanceOf<T>,
 ) -> Vec<(T::AccountId, BalanceOf<T>)> {
@@ -206,8 +232,8 @@ pub(super) fn split_to_underallocated_collators<T: Config>(
                 Percent::from_rational(tokens_to_reach_median, total_underallocation);
             let to_reach_mean = collator_proportion.mul_ceil(new_deposit);
             let to_deposit = to_reach_mean.min(remaining_deposit);
-            let our_stake = StakedCollators::<T>::get(account.clone());
-            if to_deposit > crate::Pallet::<T>::min_deposit()
+            let our_stake = StakedCollators::<T, I>::get(account.clone());
+            if to_deposit > crate::Pallet::<T, I>::min_deposit()
                 && to_deposit + our_stake > info.lowest_top_delegation_amount
             {
                 let this_deposit = core::cmp::min(to_deposit, remaining_deposit);
@@ -219,7 +245,7 @@ pub(super) fn split_to_underallocated_collators<T: Config>(
                     to_deposit
                 );
             };
-            if remaining_deposit < crate::Pallet::<T>::min_deposit() {
+            if remaining_deposit < crate::Pallet::<T, I>::min_deposit() {
                 break;
             }
         }
@@ -259,8 +285,87 @@ pub(super) fn split_to_underallocated_collators<T: Config>(
     deposits
 }
 
+/// alternative to `split_to_underallocated_collators`: spread the deposit over all collators weighted by their estimated APY
+/// APY is estimated as points awarded last round per token counted towards the collator, so low stake and high block production both attract funds
+#[named]
+pub(super) fn split_apy_weighted<T: Config<I>, I: 'static>(
+    active_collators: &[T::AccountId],
+    new_deposit: BalanceOf<T>,
+) -> Vec<(T::AccountId, BalanceOf<T>)> {
+    use sp_arithmetic::{helpers_128bit::multiply_by_rational_with_rounding, Rounding};
+    use sp_runtime::traits::SaturatedConversion;
+    log::trace!(function_name!());
+    // keeps the weights of collators with large stake distinguishable from zero
+    const WEIGHT_SCALE: u128 = 1_000_000_000_000_000_000_000_000;
+
+    let mut deposits: Vec<(T::AccountId, BalanceOf<T>)> = vec![];
+    if active_collators.len().is_zero() || new_deposit.is_zero() {
+        return deposits;
+    }
+    let round_info = pallet_parachain_staking::Pallet::<T>::round();
+    let mut weighted_collators: Vec<_> = active_collators
+        .iter()
+        .filter_map(|collator| {
+            let info = pallet_parachain_staking::Pallet::<T>::candidate_info(collator.clone())?;
+            let points = if round_info.current <= 1 {
+                1u128
+            } else {
+                pallet_parachain_staking::Pallet::<T>::awarded_pts(round_info.current - 1, collator)
+                    as u128
+            };
+            let weight = multiply_by_rational_with_rounding(
+                points,
+                WEIGHT_SCALE,
+                info.total_counted.saturated_into::<u128>().max(1),
+                Rounding::Down,
+            )
+            .unwrap_or(u128::MAX);
+            Some((collator.clone(), weight, info.lowest_top_delegation_amount))
+        })
+        .filter(|(_, weight, _)| !weight.is_zero())
+        .collect();
+    if weighted_collators.is_empty() {
+        return deposits;
+    }
+    // sort descending by weight, so the highest APY collator is first
+    weighted_collators.sort_by(|a, b| b.1.cmp(&a.1));
+    let total_weight = weighted_collators
+        .iter()
+        .fold(0u128, |acc, (_, weight, _)| acc.saturating_add(*weight));
+
+    let mut remaining_deposit = new_deposit;
+    for (collator, weight, lowest_top_delegation_amount) in weighted_collators.iter() {
+        let to_deposit: BalanceOf<T> = multiply_by_rational_with_rounding(
+            new_deposit.saturated_into::<u128>(),
+            *weight,
+            total_weight,
+            Rounding::Down,
+        )
+        .unwrap_or_default()
+        .saturated_into();
+        let to_deposit = to_deposit.min(remaining_deposit);
+        let our_stake = StakedCollators::<T, I>::get(collator.clone());
+        // skip shares that are too small to stake or would not get us into the top delegations
+        if to_deposit >= crate::Pallet::<T, I>::min_deposit()
+            && to_deposit + our_stake > *lowest_top_delegation_amount
+        {
+            deposits.push((collator.clone(), to_deposit));
+            remaining_deposit -= to_deposit;
+        }
+    }
+    // skipped shares and rounding dust go to the highest APY collator
+    if !remaining_deposit.is_zero() {
+        match deposits.first_mut() {
+            Some(first_deposit) => first_deposit.1 += remaining_deposit,
+            None => deposits.push((weighted_collators[0].0.clone(), remaining_deposit)),
+        }
+    }
+    log::debug!("APY weighted deposits: {:?}", deposits);
+    deposits
+}
+
 /// fallback: just assign to a random active collator ( choose a different collator for each invocation )
-pub(crate) fn stake_to_random_collator<T: Config>(
+pub(crate) fn stake_to_random_collator<T: Config<I>, I: 'static>(
     active_collators: &[T::AccountId],
     new_deposit: BalanceOf<T>,
 ) -> Option<(T::AccountId, BalanceOf<T>)> {
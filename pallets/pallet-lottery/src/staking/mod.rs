@@ -22,14 +22,108 @@ use frame_support::{dispatch::RawOrigin, ensure, traits::EstimateCallFee};
 use pallet_parachain_staking::BalanceOf;
 use sp_runtime::{
     traits::{Saturating, Zero},
-    DispatchResult, Percent,
+    DispatchResult,
 };
 use sp_std::{vec, vec::Vec};
 
-impl<T: Config> Pallet<T> {
+/// A strategy to assign (part of) a deposit to collators for staking
+pub(crate) trait DepositStrategy<T: Config<I>, I: 'static> {
+    /// Returns collator/amount pairs covering up to `new_deposit` tokens, using only `eligible_collators`
+    fn distribute(
+        &self,
+        eligible_collators: &[T::AccountId],
+        new_deposit: BalanceOf<T>,
+    ) -> Vec<(T::AccountId, BalanceOf<T>)>;
+}
+
+/// A strategy to select collators to fully unstake in order to serve a withdrawal
+pub(crate) trait WithdrawStrategy<T: Config<I>, I: 'static> {
+    /// Returns the selected collators and the sum of our stake with them
+    fn select(
+        &self,
+        eligible_collators: &[T::AccountId],
+        withdrawal_amount: BalanceOf<T>,
+    ) -> (Vec<T::AccountId>, BalanceOf<T>);
+}
+
+impl<T: Config<I>, I: 'static> DepositStrategy<T, I> for DepositStrategyKind {
+    fn distribute(
+        &self,
+        eligible_collators: &[T::AccountId],
+        new_deposit: BalanceOf<T>,
+    ) -> Vec<(T::AccountId, BalanceOf<T>)> {
+        match self {
+            DepositStrategyKind::ReactivateBottomCollators => {
+                deposit_strategies::reactivate_bottom_collators::<T, I>(
+                    eligible_collators,
+                    new_deposit,
+                )
+            }
+            DepositStrategyKind::SplitToUnderallocatedCollators => {
+                deposit_strategies::split_to_underallocated_collators::<T, I>(
+                    eligible_collators,
+                    new_deposit,
+                )
+            }
+            DepositStrategyKind::SplitApyWeighted => {
+                deposit_strategies::split_apy_weighted::<T, I>(eligible_collators, new_deposit)
+            }
+            DepositStrategyKind::StakeToRandomCollator => {
+                deposit_strategies::stake_to_random_collator::<T, I>(
+                    eligible_collators,
+                    new_deposit,
+                )
+                .into_iter()
+                .collect()
+            }
+        }
+    }
+}
+
+impl<T: Config<I>, I: 'static> WithdrawStrategy<T, I> for WithdrawStrategyKind {
+    fn select(
+        &self,
+        eligible_collators: &[T::AccountId],
+        withdrawal_amount: BalanceOf<T>,
+    ) -> (Vec<T::AccountId>, BalanceOf<T>) {
+        match self {
+            WithdrawStrategyKind::UnstakeBlocklistedCollators => {
+                withdraw_strategies::unstake_blocklisted_collators::<T, I>(
+                    eligible_collators,
+                    withdrawal_amount,
+                )
+            }
+            WithdrawStrategyKind::UnstakeInactiveCollators => {
+                withdraw_strategies::unstake_inactive_collators::<T, I>(
+                    eligible_collators,
+                    withdrawal_amount,
+                )
+            }
+            WithdrawStrategyKind::UnstakeLeastApyCollators => {
+                withdraw_strategies::unstake_least_apy_collators::<T, I>(
+                    eligible_collators,
+                    withdrawal_amount,
+                )
+            }
+        }
+    }
+}
+
+fn sum_of<T: Config<I>, I: 'static>(deposits: &[(T::AccountId, BalanceOf<T>)]) -> BalanceOf<T> {
+    deposits
+        .iter()
+        .map(|deposit| deposit.1)
+        .reduce(|sum, elem| sum + elem)
+        .unwrap_or_else(|| 0u32.into())
+}
+
+impl<T: Config<I>, I: 'static> Pallet<T, I> {
     #[named]
     /// distributes a given amount of tokens to zero or more collators for staking
     /// if it can't distribute all tokens for some reason, it returns an empty vec
+    ///
+    /// The strategies configured in [`StakingStrategy`] are tried in order, each one receiving
+    /// the part of the deposit the previous strategies could not assign
     pub(crate) fn calculate_deposit_distribution(
         new_deposit: BalanceOf<T>,
     ) -> Vec<(T::AccountId, BalanceOf<T>)> {
@@ -46,6 +140,7 @@ impl<T: Config> Pallet<T> {
             );
             return vec![];
         }
+        let strategy = Self::staking_strategy();
         let mut deposits: Vec<(T::AccountId, BalanceOf<T>)> = vec![];
         let mut remaining_deposit = new_deposit;
 
@@ -55,7 +150,7 @@ impl<T: Config> Pallet<T> {
             log::error!("FATAL: ParachainStaking returned no active collators"); // NOTE: guaranteed by ParachainStaking to not happen
             return vec![];
         }
-        let collators_we_are_unstaking_from = UnstakingCollators::<T>::get()
+        let collators_we_are_unstaking_from = UnstakingCollators::<T, I>::get()
             .iter()
             .cloned()
             .map(|uc| uc.account)
@@ -67,6 +162,10 @@ impl<T: Config> Pallet<T> {
             .iter()
             .filter(|account| {
                 !collators_we_are_unstaking_from.contains(account)
+                    && !strategy.collator_blocklist.contains(account)
+                    && strategy
+                        .max_stake_per_collator
+                        .map_or(true, |max| StakedCollators::<T, I>::get(account) < max)
                     && (round_info.current <= 1
                         || !pallet_parachain_staking::Pallet::<T>::awarded_pts(
                             round_info.current - 1,
@@ -81,91 +180,27 @@ impl<T: Config> Pallet<T> {
             "deposit_eligible_collators size: {:?}",
             deposit_eligible_collators.len()
         );
-        // first concern: If we fell out of the active set on one or more collators, we need to get back into it
-        deposits.append(&mut deposit_strategies::reactivate_bottom_collators::<T>(
-            deposit_eligible_collators.as_slice(),
-            new_deposit,
-        ));
-        // `reactivate_bottom_collators` has only distributed the funds needed for reactivation, we can have some left over
-        remaining_deposit -= deposits
-            .iter()
-            .map(|deposit| deposit.1)
-            .reduce(|sum, elem| sum + elem)
-            .unwrap_or_else(|| 0u32.into());
-
-        log::debug!(
-            "after reactivate_bottom_collators deposits: {:?}, remaining: ${:?}",
-            deposits.len(),
-            remaining_deposit
-        );
-        // If we have re-activated any collators and have leftover funds, we just distribute all surplus tokens to them evenly and call it a day
-        if !deposits.is_empty() {
-            if !remaining_deposit.is_zero() {
-                log::debug!(
-                    "deposits:{:?} not null and remaining:{:?} not zero",
-                    deposits,
-                    remaining_deposit
-                );
-                let deposit_per_collator =
-                    Percent::from_rational(1, deposits.len() as u32).mul_ceil(remaining_deposit); // this overshoots the amount if there's a remainder
-                for deposit in &mut deposits {
-                    let add = remaining_deposit.saturating_sub(deposit_per_collator); // we correct the overshoot here
-                    log::debug!(
-                        "deposit_per_collator:{:?}, add:{:?}",
-                        deposit_per_collator,
-                        add
-                    );
-                    deposit.1 += add;
-                    remaining_deposit -= add;
-                }
+        for deposit_strategy in strategy.deposit_strategies.iter() {
+            if remaining_deposit.is_zero() {
+                break;
             }
-            log::debug!(
-                "deposits:{:?} not null and remaining:{:?}",
-                deposits,
-                remaining_deposit
+            let mut new_deposits = Self::apply_stake_cap(
+                &deposits,
+                DepositStrategy::<T, I>::distribute(
+                    deposit_strategy,
+                    deposit_eligible_collators.as_slice(),
+                    remaining_deposit,
+                ),
+                strategy.max_stake_per_collator,
             );
-            return deposits;
-        }
-
-        // second concern: We want to maximize staking APY earned, so we want to balance the staking pools with our deposits while conserving gas
-        log::debug!(
-            "deposit_eligible_collators size:{:?}, remaining_deposit:{:?}",
-            deposit_eligible_collators.len(),
-            remaining_deposit
-        );
-        deposits.append(
-            &mut deposit_strategies::split_to_underallocated_collators::<T>(
-                deposit_eligible_collators.as_slice(),
-                remaining_deposit,
-            ),
-        );
-        remaining_deposit -= deposits
-            .iter()
-            .map(|deposit| deposit.1)
-            .reduce(|sum, elem| sum + elem)
-            .unwrap_or_else(|| 0u32.into());
-        log::debug!(
-            "after split_to_underallocated_collators, remain: ${:?}",
-            remaining_deposit
-        );
-        // fallback: just assign to a random active collator ( choose a different collator for each invocation )
-        if !remaining_deposit.is_zero() {
-            log::warn!(
-                "Failed to distribute {:?} tokens by strategy",
+            remaining_deposit = remaining_deposit.saturating_sub(sum_of::<T, I>(&new_deposits));
+            log::debug!(
+                "after {:?} deposits: {:?}, remaining: {:?}",
+                deposit_strategy,
+                new_deposits,
                 remaining_deposit
             );
-            if let Some(deposit) = deposit_strategies::stake_to_random_collator::<T>(
-                deposit_eligible_collators.as_slice(),
-                remaining_deposit,
-            ) {
-                deposits.push(deposit);
-                remaining_deposit = new_deposit
-                    - deposits
-                        .iter()
-                        .map(|deposit| deposit.1)
-                        .reduce(|sum, elem| sum + elem)
-                        .unwrap_or_else(|| 0u32.into());
-            }
+            deposits.append(&mut new_deposits);
         }
         if deposits.is_empty() {
             log::error!("FATAL: Could not find any collator to stake to");
@@ -181,7 +216,47 @@ impl<T: Config> Pallet<T> {
         deposits
     }
 
+    /// Trims `new_deposits` so that no collator ends up with more than `max_stake_per_collator`
+    /// including our existing stake and the `planned_deposits` not yet executed.
+    /// Deposits that would be trimmed below [`Pallet::min_deposit`] are dropped entirely
+    fn apply_stake_cap(
+        planned_deposits: &[(T::AccountId, BalanceOf<T>)],
+        new_deposits: Vec<(T::AccountId, BalanceOf<T>)>,
+        max_stake_per_collator: Option<BalanceOf<T>>,
+    ) -> Vec<(T::AccountId, BalanceOf<T>)> {
+        let max = match max_stake_per_collator {
+            None => return new_deposits,
+            Some(max) => max,
+        };
+        let mut capped: Vec<(T::AccountId, BalanceOf<T>)> = vec![];
+        for (collator, amount) in new_deposits {
+            let already_staked = planned_deposits
+                .iter()
+                .chain(capped.iter())
+                .filter(|(account, _)| *account == collator)
+                .fold(
+                    StakedCollators::<T, I>::get(&collator),
+                    |acc, (_, planned)| acc.saturating_add(*planned),
+                );
+            let amount = amount.min(max.saturating_sub(already_staked));
+            if amount < Self::min_deposit() {
+                log::debug!(
+                    "Collator {:?} is at its stake cap of {:?}, skipping",
+                    collator,
+                    max
+                );
+                continue;
+            }
+            capped.push((collator, amount));
+        }
+        capped
+    }
+
     #[named]
+    /// selects collators to fully unstake so that at least `withdrawal_amount` tokens are unstaked
+    /// if it can't cover the full amount, it returns an empty vec
+    ///
+    /// The strategies configured in [`StakingStrategy`] are tried in order until the request is covered
     pub(crate) fn calculate_withdrawal_distribution(
         withdrawal_amount: BalanceOf<T>,
     ) -> Vec<T::AccountId> {
@@ -189,12 +264,12 @@ impl<T: Config> Pallet<T> {
         if withdrawal_amount.is_zero() {
             return vec![];
         }
-        let mut withdrawals = vec![];
+        let mut withdrawals: Vec<T::AccountId> = vec![];
         let mut remaining_balance = withdrawal_amount;
 
         // Only unstake collators we're staked to **and not already unstaking from**
-        let staked_collators: Vec<_> = StakedCollators::<T>::iter_keys().collect();
-        let collators_we_are_unstaking_from: Vec<_> = UnstakingCollators::<T>::get()
+        let staked_collators: Vec<_> = StakedCollators::<T, I>::iter_keys().collect();
+        let collators_we_are_unstaking_from: Vec<_> = UnstakingCollators::<T, I>::get()
             .iter()
             .cloned()
             .map(|uc| uc.account)
@@ -208,27 +283,22 @@ impl<T: Config> Pallet<T> {
         if withdrawal_eligible_collators.is_empty() {
             return vec![];
         }
-        // first concern: If there are inactive collators we are staked with, prefer these
-        let (mut collators, balance_unstaked) = withdraw_strategies::unstake_inactive_collators::<T>(
-            &withdrawal_eligible_collators,
-            remaining_balance,
-        );
-        withdrawals.append(&mut collators);
-        remaining_balance = remaining_balance.saturating_sub(balance_unstaked);
-        if remaining_balance.is_zero() && !withdrawals.is_empty() {
-            return withdrawals;
+        for withdraw_strategy in Self::staking_strategy().withdraw_strategies.iter() {
+            let (mut collators, balance_unstaked) = WithdrawStrategy::<T, I>::select(
+                withdraw_strategy,
+                &withdrawal_eligible_collators
+                    .iter()
+                    .filter(|collator| !withdrawals.contains(collator))
+                    .cloned()
+                    .collect::<Vec<_>>(),
+                remaining_balance,
+            );
+            withdrawals.append(&mut collators);
+            remaining_balance = remaining_balance.saturating_sub(balance_unstaked);
+            if remaining_balance.is_zero() && !withdrawals.is_empty() {
+                return withdrawals;
+            }
         }
-        // If we have balance to withdraw left over, we have to unstake some healthy collator.
-        // Unstake starting from the highest overallocated collator ( since that yields the lowest APY ) going down until request is satisfied
-        let (mut collators, balance_unstaked) = withdraw_strategies::unstake_least_apy_collators::<T>(
-            &withdrawal_eligible_collators
-                .into_iter()
-                .filter(|collator| !withdrawals.contains(collator))
-                .collect(),
-            remaining_balance,
-        );
-        withdrawals.append(&mut collators);
-        remaining_balance = remaining_balance.saturating_sub(balance_unstaked);
 
         if !remaining_balance.is_zero() {
             log::error!(
@@ -256,23 +326,23 @@ impl<T: Config> Pallet<T> {
         // - newly deposited funds > min_deposit
         // - unstaked-but-not-needed-for-withdrawals funds > min_deposit
         if amount < Self::min_deposit() {
-            return Err(Error::<T>::DepositBelowMinAmount.into());
+            return Err(Error::<T, I>::DepositBelowMinAmount.into());
         }
         if amount > Self::surplus_funds() && amount > Self::unlocked_unstaking_funds() {
             // we can't handle this withdrawal from new deposits or unstaked funds
-            return Err(Error::<T>::PotBalanceTooLowToStake.into());
+            return Err(Error::<T, I>::PotBalanceTooLowToStake.into());
         }
         // collator exists
         let candidate_delegation_count;
         if let Some(info) = pallet_parachain_staking::Pallet::<T>::candidate_info(&collator) {
             candidate_delegation_count = info.delegation_count;
         } else {
-            return Err(Error::<T>::NoCollatorForStake.into());
+            return Err(Error::<T, I>::NoCollatorForStake.into());
         };
-        let delegation_count = StakedCollators::<T>::iter_keys().count() as u32;
+        let delegation_count = StakedCollators::<T, I>::iter_keys().count() as u32;
 
         // If we're already delegated to this collator, we must call `delegator_bond_more`.
-        if StakedCollators::<T>::get(&collator).is_zero() {
+        if StakedCollators::<T, I>::get(&collator).is_zero() {
             log::debug!(
                 "delegator not staked on collator:{:?}, use delegate",
                 collator
@@ -289,7 +359,7 @@ impl<T: Config> Pallet<T> {
             );
             ensure!(
                 Self::surplus_funds() > fee_estimate,
-                Error::<T>::PotBalanceTooLowToPayTxFee
+                Error::<T, I>::PotBalanceTooLowToPayTxFee
             );
             pallet_parachain_staking::Pallet::<T>::delegate(
                 RawOrigin::Signed(Self::account_id()).into(),
@@ -322,7 +392,7 @@ impl<T: Config> Pallet<T> {
             );
             ensure!(
                 Self::surplus_funds() > fee_estimate,
-                Error::<T>::PotBalanceTooLowToPayTxFee
+                Error::<T, I>::PotBalanceTooLowToPayTxFee
             );
             pallet_parachain_staking::Pallet::<T>::delegator_bond_more(
                 RawOrigin::Signed(Self::account_id()).into(),
@@ -339,7 +409,7 @@ impl<T: Config> Pallet<T> {
                 e.error
             })?;
         }
-        StakedCollators::<T>::mutate(&collator, |balance| *balance += amount);
+        StakedCollators::<T, I>::mutate(&collator, |balance| *balance += amount);
 
         log::debug!("Delegated {:?} tokens to {:?}", amount, collator);
         Ok(())
@@ -351,10 +421,10 @@ impl<T: Config> Pallet<T> {
         some_collator: T::AccountId,
     ) -> DispatchResult {
         log::trace!(function_name!());
-        let delegated_amount_to_be_unstaked = StakedCollators::<T>::get(some_collator.clone());
+        let delegated_amount_to_be_unstaked = StakedCollators::<T, I>::get(some_collator.clone());
         if delegated_amount_to_be_unstaked.is_zero() {
             log::error!("requested to unstake a collator that isn't staked");
-            return Err(Error::<T>::NoCollatorForWithdrawal.into());
+            return Err(Error::<T, I>::NoCollatorForWithdrawal.into());
         };
         log::debug!(
             "Unstaking collator {:?} with balance {:?}",
@@ -370,7 +440,7 @@ impl<T: Config> Pallet<T> {
         );
         ensure!(
             Self::surplus_funds() > fee_estimate,
-            Error::<T>::PotBalanceTooLowToPayTxFee
+            Error::<T, I>::PotBalanceTooLowToPayTxFee
         );
         // unstake from parachain staking
         // NOTE: All funds that were delegated here will no longer produce staking rewards
@@ -381,10 +451,10 @@ impl<T: Config> Pallet<T> {
         .map_err(|e| e.error)?;
 
         // Update bookkeeping
-        SurplusUnstakingBalance::<T>::mutate(|bal| {
+        SurplusUnstakingBalance::<T, I>::mutate(|bal| {
             *bal = (*bal).saturating_add(delegated_amount_to_be_unstaked);
         });
-        UnstakingCollators::<T>::mutate(|collators| {
+        UnstakingCollators::<T, I>::mutate(|collators| {
             collators.push(UnstakingCollator {
                 account: some_collator.clone(),
                 since: now,
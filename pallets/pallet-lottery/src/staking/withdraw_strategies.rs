@@ -19,8 +19,35 @@ use pallet_parachain_staking::BalanceOf;
 use sp_runtime::traits::{Saturating, Zero};
 use sp_std::{vec, vec::Vec};
 
-pub(super) fn unstake_inactive_collators<T: Config>(
-    eligible_collators: &Vec<T::AccountId>,
+pub(super) fn unstake_blocklisted_collators<T: Config<I>, I: 'static>(
+    eligible_collators: &[T::AccountId],
+    withdrawal_amount: BalanceOf<T>,
+) -> (Vec<T::AccountId>, BalanceOf<T>) {
+    let mut withdrawals = vec![];
+    let mut unstaked = 0u32.into();
+
+    if eligible_collators.len().is_zero() || withdrawal_amount.is_zero() {
+        return (withdrawals, unstaked);
+    }
+    // governance no longer wants us staked with these, so get rid of them in any order
+    let blocklist = crate::Pallet::<T, I>::staking_strategy().collator_blocklist;
+    for collator in eligible_collators
+        .iter()
+        .filter(|collator| blocklist.contains(collator))
+    {
+        let our_stake = StakedCollators::<T, I>::get(collator);
+        log::debug!("Unstaking {:?} from blocklisted {:?}", our_stake, collator);
+        unstaked += our_stake;
+        withdrawals.push(collator.clone());
+        if unstaked >= withdrawal_amount {
+            break;
+        }
+    }
+    (withdrawals, unstaked)
+}
+
+pub(super) fn unstake_inactive_collators<T: Config<I>, I: 'static>(
+    eligible_collators: &[T::AccountId],
     withdrawal_amount: BalanceOf<T>,
 ) -> (Vec<T::AccountId>, BalanceOf<T>) {
     let mut withdrawals = vec![];
@@ -41,7 +68,7 @@ pub(super) fn unstake_inactive_collators<T: Config>(
             });
     // since these collators are inactive, we just unstake in any order until we have satisfied the withdrawal request
     for collator in inactive_eligible_collators {
-        let our_stake = StakedCollators::<T>::get(collator);
+        let our_stake = StakedCollators::<T, I>::get(collator);
         log::debug!("Unstaking {:?} from inactive {:?}", our_stake, collator);
         unstaked += our_stake;
         withdrawals.push(collator.clone());
@@ -56,8 +83,8 @@ pub(super) fn unstake_inactive_collators<T: Config>(
     (withdrawals, unstaked)
 }
 
-pub(super) fn unstake_least_apy_collators<T: Config>(
-    eligible_collators: &Vec<T::AccountId>,
+pub(super) fn unstake_least_apy_collators<T: Config<I>, I: 'static>(
+    eligible_collators: &[T::AccountId],
     withdrawal_amount: BalanceOf<T>,
 ) -> (Vec<T::AccountId>, BalanceOf<T>) {
     // If we have balance to withdraw left over, we have to unstake some healthy collator.
@@ -87,7 +114,7 @@ pub(super) fn unstake_least_apy_collators<T: Config>(
         apy_ordered_active_collators_we_are_staked_with.len()
     );
     for c in apy_ordered_active_collators_we_are_staked_with {
-        let our_stake = StakedCollators::<T>::get(c.clone());
+        let our_stake = StakedCollators::<T, I>::get(c.clone());
         log::debug!("Unstaking {:?} from active {:?}", our_stake, c);
         withdrawals.push(c);
         unstaked += our_stake;
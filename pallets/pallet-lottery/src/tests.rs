@@ -17,22 +17,26 @@
 use crate::{
     assert_last_event,
     mock::{
-        roll_one_block, roll_to, roll_to_round_begin, roll_to_round_end, AccountId, Assets,
-        Balance, Balances, ExtBuilder, Farming, Lottery, ParachainStaking, RuntimeOrigin as Origin,
-        System, Test, ALICE, BOB, CHARLIE, DAVE, DELEGATOR1, DELEGATOR2, DELEGATOR3, DELEGATOR4,
-        DELEGATOR5, DELEGATOR6, DELEGATOR7, DELEGATOR8, EVE, INIT_JUMBO_AMOUNT,
-        INIT_V_MANTA_AMOUNT, JUMBO_ID, POOL_ID, V_MANTA_ID,
+        roll_one_block, roll_to, roll_to_round_begin, roll_to_round_end, AccountId, AssetLottery,
+        Assets, Balance, Balances, ExtBuilder, Farming, Lottery, ParachainStaking,
+        RuntimeOrigin as Origin, System, Test, ALICE, BOB, CHARLIE, DAVE, DELEGATOR1, DELEGATOR2,
+        DELEGATOR3, DELEGATOR4, DELEGATOR5, DELEGATOR6, DELEGATOR7, DELEGATOR8, EVE,
+        INIT_JUMBO_AMOUNT, INIT_V_MANTA_AMOUNT, JUMBO_ID, POOL_ID, V_MANTA_ID,
     },
-    Config, Error, FarmingParameters,
+    Config, DepositStrategyKind, Error, FarmingParameters, PotBacking, StakingStrategyConfig,
+    StakingStrategyConfigOf, WithdrawStrategyKind,
 };
 
-use frame_support::{assert_noop, assert_ok, traits::Currency};
+use frame_support::{assert_noop, assert_ok, instances::Instance1, traits::Currency};
 use frame_system::RawOrigin;
-use sp_runtime::TokenError;
+use orml_traits::MultiCurrency;
+use sp_runtime::{Perbill, TokenError};
 
 const UNIT: Balance = 1_000_000_000_000;
 const HIGH_BALANCE: Balance = 1_000_000_000 * UNIT;
 
+type Currencies = <Test as pallet_farming::Config>::MultiCurrency;
+
 #[test]
 fn call_manager_extrinsics_as_normal_user_should_not_work() {
     ExtBuilder::default().build().execute_with(|| {
@@ -1900,3 +1904,478 @@ fn fails_withdrawing_more_than_vmanta() {
             assert_eq!(0, Assets::balance(V_MANTA_ID, CHARLIE));
         });
 }
+
+#[test]
+fn set_staking_strategy_works() {
+    ExtBuilder::default().build().execute_with(|| {
+        assert_eq!(
+            Lottery::staking_strategy(),
+            StakingStrategyConfig::default()
+        );
+        let strategy: StakingStrategyConfigOf<Test> = StakingStrategyConfig {
+            deposit_strategies: vec![DepositStrategyKind::SplitApyWeighted]
+                .try_into()
+                .unwrap(),
+            withdraw_strategies: vec![
+                WithdrawStrategyKind::UnstakeBlocklistedCollators,
+                WithdrawStrategyKind::UnstakeLeastApyCollators,
+            ]
+            .try_into()
+            .unwrap(),
+            max_stake_per_collator: Some(30_000_000 * UNIT),
+            collator_blocklist: vec![ALICE].try_into().unwrap(),
+        };
+        assert_noop!(
+            Lottery::set_staking_strategy(Origin::signed(BOB), strategy.clone()),
+            sp_runtime::DispatchError::BadOrigin
+        );
+        assert_noop!(
+            Lottery::set_staking_strategy(
+                Origin::root(),
+                StakingStrategyConfig {
+                    deposit_strategies: Default::default(),
+                    ..strategy.clone()
+                }
+            ),
+            Error::<Test>::PalletMisconfigured
+        );
+        assert_noop!(
+            Lottery::set_staking_strategy(
+                Origin::root(),
+                StakingStrategyConfig {
+                    deposit_strategies: vec![
+                        DepositStrategyKind::SplitApyWeighted,
+                        DepositStrategyKind::SplitApyWeighted
+                    ]
+                    .try_into()
+                    .unwrap(),
+                    ..strategy.clone()
+                }
+            ),
+            Error::<Test>::PalletMisconfigured
+        );
+        assert_noop!(
+            Lottery::set_staking_strategy(
+                Origin::root(),
+                StakingStrategyConfig {
+                    max_stake_per_collator: Some(1),
+                    ..strategy.clone()
+                }
+            ),
+            Error::<Test>::PalletMisconfigured
+        );
+        assert_ok!(Lottery::set_staking_strategy(
+            Origin::root(),
+            strategy.clone()
+        ));
+        assert_eq!(Lottery::staking_strategy(), strategy);
+    });
+}
+
+#[test]
+fn deposit_skips_blocklisted_collators() {
+    let balance = 50_000_000 * UNIT;
+    let balance1 = 20_000_000 * UNIT;
+    ExtBuilder::default()
+        .with_balances(vec![(ALICE, HIGH_BALANCE), (BOB, HIGH_BALANCE)])
+        .with_candidates(vec![(ALICE, balance1), (BOB, balance)])
+        .with_funded_lottery_account(HIGH_BALANCE)
+        .build()
+        .execute_with(|| {
+            assert_ok!(Lottery::set_staking_strategy(
+                Origin::root(),
+                StakingStrategyConfig {
+                    collator_blocklist: vec![ALICE].try_into().unwrap(),
+                    ..Default::default()
+                }
+            ));
+            // ALICE is underallocated but blocklisted, so everything goes to BOB
+            assert_ok!(Lottery::deposit(Origin::signed(ALICE), balance1));
+            assert_eq!(Lottery::staked_collators(ALICE), 0);
+            assert_eq!(Lottery::staked_collators(BOB), balance1);
+        });
+}
+
+#[test]
+fn deposit_respects_max_stake_per_collator() {
+    let balance = 50_000_000 * UNIT;
+    let balance1 = 20_000_000 * UNIT;
+    let max_stake = 30_000_000 * UNIT;
+    ExtBuilder::default()
+        .with_balances(vec![(ALICE, HIGH_BALANCE), (BOB, HIGH_BALANCE)])
+        .with_candidates(vec![(ALICE, balance1), (BOB, balance)])
+        .with_funded_lottery_account(HIGH_BALANCE)
+        .build()
+        .execute_with(|| {
+            assert_ok!(Lottery::set_staking_strategy(
+                Origin::root(),
+                StakingStrategyConfig {
+                    max_stake_per_collator: Some(max_stake),
+                    ..Default::default()
+                }
+            ));
+            assert_ok!(Lottery::deposit(Origin::signed(ALICE), max_stake));
+            assert_eq!(Lottery::staked_collators(ALICE), max_stake);
+            // ALICE is at the cap, so the next deposit must go to BOB
+            assert_ok!(Lottery::deposit(Origin::signed(ALICE), max_stake));
+            assert_eq!(Lottery::staked_collators(ALICE), max_stake);
+            assert_eq!(Lottery::staked_collators(BOB), max_stake);
+            // Every collator is at the cap
+            assert_noop!(
+                Lottery::deposit(Origin::signed(ALICE), max_stake),
+                Error::<Test>::NoCollatorForDeposit
+            );
+        });
+}
+
+#[test]
+fn apy_weighted_deposit_favors_collators_with_less_stake() {
+    let balance = 50_000_000 * UNIT;
+    let balance1 = 20_000_000 * UNIT;
+    ExtBuilder::default()
+        .with_balances(vec![(ALICE, HIGH_BALANCE), (BOB, HIGH_BALANCE)])
+        .with_candidates(vec![(ALICE, balance1), (BOB, balance)])
+        .with_funded_lottery_account(HIGH_BALANCE)
+        .build()
+        .execute_with(|| {
+            assert_ok!(Lottery::set_staking_strategy(
+                Origin::root(),
+                StakingStrategyConfig {
+                    deposit_strategies: vec![DepositStrategyKind::SplitApyWeighted]
+                        .try_into()
+                        .unwrap(),
+                    ..Default::default()
+                }
+            ));
+            // APY is inversely proportional to stake: ALICE gets 50/70, BOB 20/70
+            assert_ok!(Lottery::deposit(Origin::signed(ALICE), balance + balance1));
+            assert_eq!(Lottery::staked_collators(ALICE), balance);
+            assert_eq!(Lottery::staked_collators(BOB), balance1);
+        });
+}
+
+#[test]
+fn instant_withdraw_from_liquidity_buffer_works() {
+    let balance = 100_000_000 * UNIT;
+    let half_balance = 50_000_000 * UNIT;
+    let fee = Perbill::from_percent(1);
+    ExtBuilder::default()
+        .with_balances(vec![(ALICE, HIGH_BALANCE), (BOB, HIGH_BALANCE)])
+        .with_candidates(vec![(BOB, balance)])
+        .with_funded_lottery_account(HIGH_BALANCE)
+        .build()
+        .execute_with(|| {
+            assert_noop!(
+                Lottery::set_liquidity_buffer_params(Origin::signed(BOB), balance, fee),
+                sp_runtime::DispatchError::BadOrigin
+            );
+            assert_ok!(Lottery::set_liquidity_buffer_params(
+                Origin::root(),
+                balance,
+                fee
+            ));
+            // The whole deposit fits into the buffer and stays unstaked
+            assert_ok!(Lottery::deposit(Origin::signed(ALICE), balance));
+            assert_eq!(Lottery::liquidity_buffer(), balance);
+            assert_eq!(Lottery::staked_collators(BOB), 0);
+            assert_eq!(Lottery::total_pot(), balance);
+
+            let prize_pool_before = Lottery::current_prize_pool();
+            let alice_balance_before = Balances::free_balance(ALICE);
+            assert_ok!(Lottery::instant_withdraw(
+                Origin::signed(ALICE),
+                half_balance
+            ));
+            let expected_fee = fee.mul_ceil(half_balance);
+            assert_last_event!(crate::mock::RuntimeEvent::Lottery(
+                crate::Event::InstantWithdrawn {
+                    account: ALICE,
+                    amount: half_balance - expected_fee,
+                    fee: expected_fee,
+                }
+            ));
+            assert_eq!(
+                Balances::free_balance(ALICE),
+                alice_balance_before + half_balance - expected_fee
+            );
+            assert_eq!(
+                Lottery::current_prize_pool(),
+                prize_pool_before + expected_fee
+            );
+            assert_eq!(Lottery::liquidity_buffer(), half_balance);
+            assert_eq!(Lottery::sum_of_deposits(), half_balance);
+            assert_eq!(Lottery::total_pot(), half_balance);
+            assert!(Lottery::withdrawal_request_queue().is_empty());
+        });
+}
+
+#[test]
+fn instant_withdraw_falls_back_to_queue_when_buffer_exhausted() {
+    let balance = 100_000_000 * UNIT;
+    ExtBuilder::default()
+        .with_balances(vec![(ALICE, HIGH_BALANCE), (BOB, HIGH_BALANCE)])
+        .with_candidates(vec![(BOB, balance)])
+        .with_funded_lottery_account(HIGH_BALANCE)
+        .build()
+        .execute_with(|| {
+            // Buffer is disabled, everything gets staked
+            assert_ok!(Lottery::deposit(Origin::signed(ALICE), balance));
+            assert_eq!(Lottery::liquidity_buffer(), 0);
+            assert_eq!(Lottery::staked_collators(BOB), balance);
+
+            let alice_balance_before = Balances::free_balance(ALICE);
+            assert_ok!(Lottery::instant_withdraw(Origin::signed(ALICE), balance));
+            assert_last_event!(crate::mock::RuntimeEvent::Lottery(
+                crate::Event::ScheduledWithdraw {
+                    account: ALICE,
+                    amount: balance,
+                }
+            ));
+            assert_eq!(Balances::free_balance(ALICE), alice_balance_before);
+            assert_eq!(Lottery::withdrawal_request_queue().len(), 1);
+            assert_eq!(Lottery::total_pot(), 0);
+        });
+}
+
+#[test]
+fn liquidity_buffer_params_are_bounded() {
+    let balance = 100_000_000 * UNIT;
+    ExtBuilder::default().build().execute_with(|| {
+        assert_noop!(
+            Lottery::set_liquidity_buffer_params(Origin::root(), balance, Perbill::from_percent(6)),
+            Error::<Test>::InstantWithdrawFeeTooHigh
+        );
+        assert_ok!(Lottery::set_liquidity_buffer_params(
+            Origin::root(),
+            balance,
+            Perbill::from_percent(5)
+        ));
+        assert_last_event!(crate::mock::RuntimeEvent::Lottery(
+            crate::Event::LiquidityBufferParamsSet {
+                target: balance,
+                instant_withdraw_fee: Perbill::from_percent(5),
+            }
+        ));
+        assert_eq!(Lottery::liquidity_buffer_target(), balance);
+        assert_eq!(Lottery::instant_withdraw_fee(), Perbill::from_percent(5));
+    });
+}
+
+#[test]
+fn deposit_never_fills_liquidity_buffer_beyond_target() {
+    let balance = 100_000_000 * UNIT;
+    ExtBuilder::default()
+        .with_balances(vec![(ALICE, HIGH_BALANCE), (BOB, HIGH_BALANCE)])
+        .with_candidates(vec![(BOB, balance)])
+        .with_funded_lottery_account(HIGH_BALANCE)
+        .build()
+        .execute_with(|| {
+            let min_deposit = Lottery::min_deposit();
+            assert_ok!(Lottery::set_liquidity_buffer_params(
+                Origin::root(),
+                balance,
+                Perbill::zero()
+            ));
+            // The remainder above the target is too small to be staked on its own,
+            // so the minimum deposit is staked and the buffer is filled below its target
+            assert_ok!(Lottery::deposit(Origin::signed(ALICE), balance + UNIT));
+            assert_eq!(Lottery::liquidity_buffer(), balance + UNIT - min_deposit);
+            assert_eq!(Lottery::staked_collators(BOB), min_deposit);
+            assert_eq!(Lottery::total_pot(), balance + UNIT);
+        });
+}
+
+#[test]
+fn asset_backed_lottery_pays_prize_asset_from_separate_pot() {
+    ExtBuilder::default()
+        .with_balances(vec![(ALICE, HIGH_BALANCE), (BOB, HIGH_BALANCE)])
+        .build()
+        .execute_with(|| {
+            let backing = PotBacking::Asset {
+                deposit: V_MANTA_ID,
+                prize: JUMBO_ID,
+            };
+            assert_noop!(
+                AssetLottery::set_pot_backing(Origin::signed(ALICE), backing),
+                sp_runtime::DispatchError::BadOrigin
+            );
+            assert_ok!(AssetLottery::set_pot_backing(
+                RawOrigin::Root.into(),
+                backing
+            ));
+            assert_last_event!(crate::mock::RuntimeEvent::AssetLottery(
+                crate::Event::PotBackingSet { backing }
+            ));
+
+            let pot = AssetLottery::account_id();
+            assert_ne!(pot, Lottery::account_id());
+            assert_ok!(Currencies::deposit(V_MANTA_ID, &BOB, 1_000 * UNIT));
+            assert_ok!(AssetLottery::deposit(Origin::signed(BOB), 500 * UNIT));
+            assert_eq!(AssetLottery::sum_of_deposits(), 500 * UNIT);
+            assert_eq!(AssetLottery::total_pot(), 500 * UNIT);
+            assert_eq!(Currencies::free_balance(V_MANTA_ID, &pot), 500 * UNIT);
+            assert_eq!(Currencies::free_balance(V_MANTA_ID, &BOB), 500 * UNIT);
+            // the native lottery is not affected
+            assert_eq!(Lottery::sum_of_deposits(), 0);
+            assert_eq!(Lottery::active_balance_per_user(BOB), 0);
+
+            // only deposits in the pot, nothing to win
+            assert_eq!(AssetLottery::current_prize_pool(), 0);
+            // sponsor the prize, the existential deposit stays in the pot
+            assert_ok!(Currencies::deposit(JUMBO_ID, &pot, 100 * UNIT + 1));
+            assert_eq!(AssetLottery::current_prize_pool(), 100 * UNIT);
+            assert_ok!(AssetLottery::draw_lottery(RawOrigin::Root.into()));
+            assert_eq!(
+                AssetLottery::unclaimed_winnings_by_account(BOB),
+                Some(100 * UNIT)
+            );
+            assert_eq!(AssetLottery::current_prize_pool(), 0);
+            assert_noop!(
+                AssetLottery::set_pot_backing(RawOrigin::Root.into(), PotBacking::Staking),
+                Error::<Test, Instance1>::PotNotEmpty
+            );
+
+            assert_ok!(AssetLottery::claim_my_winnings(Origin::signed(BOB)));
+            assert_eq!(Currencies::free_balance(JUMBO_ID, &BOB), 100 * UNIT);
+            assert_eq!(AssetLottery::total_unclaimed_winnings(), 0);
+
+            // asset deposits are paid out without timelock
+            assert_ok!(AssetLottery::request_withdraw(
+                Origin::signed(BOB),
+                500 * UNIT
+            ));
+            assert_last_event!(crate::mock::RuntimeEvent::AssetLottery(
+                crate::Event::Withdrawn {
+                    account: BOB,
+                    amount: 500 * UNIT,
+                }
+            ));
+            assert_eq!(Currencies::free_balance(V_MANTA_ID, &BOB), 1_000 * UNIT);
+            assert_eq!(AssetLottery::sum_of_deposits(), 0);
+            assert!(AssetLottery::withdrawal_request_queue().is_empty());
+        });
+}
+
+#[test]
+fn set_pot_backing_validates_farming_pool() {
+    ExtBuilder::default().build().execute_with(|| {
+        // the pool stakes vMANTA only
+        assert_noop!(
+            AssetLottery::set_pot_backing(
+                RawOrigin::Root.into(),
+                PotBacking::Farming {
+                    pool_id: POOL_ID,
+                    deposit: JUMBO_ID,
+                    prize: JUMBO_ID,
+                }
+            ),
+            Error::<Test, Instance1>::InvalidFarmingPool
+        );
+        assert_noop!(
+            AssetLottery::set_pot_backing(
+                RawOrigin::Root.into(),
+                PotBacking::Farming {
+                    pool_id: POOL_ID + 1,
+                    deposit: V_MANTA_ID,
+                    prize: JUMBO_ID,
+                }
+            ),
+            Error::<Test, Instance1>::InvalidFarmingPool
+        );
+        assert_ok!(AssetLottery::set_pot_backing(
+            RawOrigin::Root.into(),
+            PotBacking::Farming {
+                pool_id: POOL_ID,
+                deposit: V_MANTA_ID,
+                prize: JUMBO_ID,
+            }
+        ));
+    });
+}
+
+#[test]
+fn farming_backed_lottery_pays_farming_rewards() {
+    ExtBuilder::default()
+        .with_balances(vec![(ALICE, HIGH_BALANCE), (BOB, HIGH_BALANCE)])
+        .build()
+        .execute_with(|| {
+            assert_ok!(AssetLottery::set_pot_backing(
+                RawOrigin::Root.into(),
+                PotBacking::Farming {
+                    pool_id: POOL_ID,
+                    deposit: V_MANTA_ID,
+                    prize: JUMBO_ID,
+                }
+            ));
+            let pot = AssetLottery::account_id();
+            assert_ok!(Currencies::deposit(V_MANTA_ID, &BOB, 1_000 * UNIT));
+            assert_ok!(AssetLottery::deposit(Origin::signed(BOB), 500 * UNIT));
+            // deposits are staked by the pot
+            assert_eq!(Currencies::free_balance(V_MANTA_ID, &pot), 0);
+            assert_eq!(
+                Farming::shares_and_withdrawn_rewards(POOL_ID, pot)
+                    .unwrap()
+                    .share,
+                500 * UNIT
+            );
+
+            roll_to(10);
+            assert!(AssetLottery::current_prize_pool() > 0);
+            assert_ok!(AssetLottery::draw_lottery(RawOrigin::Root.into()));
+            // rewards were claimed into the pot and assigned to the winner
+            let prize = Currencies::free_balance(JUMBO_ID, &pot) - 1;
+            assert!(prize > 0);
+            assert_eq!(
+                AssetLottery::unclaimed_winnings_by_account(BOB),
+                Some(prize)
+            );
+            assert_ok!(AssetLottery::claim_my_winnings(Origin::signed(BOB)));
+            assert_eq!(Currencies::free_balance(JUMBO_ID, &BOB), prize);
+
+            // withdrawing unstakes from the farming pool
+            assert_ok!(AssetLottery::request_withdraw(
+                Origin::signed(BOB),
+                500 * UNIT
+            ));
+            assert_eq!(Currencies::free_balance(V_MANTA_ID, &BOB), 1_000 * UNIT);
+            assert_eq!(
+                Farming::shares_and_withdrawn_rewards(POOL_ID, pot).map(|share| share.share),
+                Some(0)
+            );
+        });
+}
+
+#[test]
+fn migration_initializes_params_of_new_lottery_instance() {
+    use crate::migrations::InitializeLotteryParams;
+    use frame_support::traits::{ConstU128, GetStorageVersion, OnRuntimeUpgrade};
+
+    type Migration = InitializeLotteryParams<
+        Test,
+        Instance1,
+        ConstU128<{ 5_000 * UNIT }>,
+        ConstU128<{ 1_000 * UNIT }>,
+        ConstU128<{ 10_000 * UNIT }>,
+    >;
+    ExtBuilder::default().build().execute_with(|| {
+        // the asset lottery has no genesis config in the mock, like an instance added by a runtime upgrade
+        assert_eq!(AssetLottery::on_chain_storage_version(), 0);
+        assert_eq!(AssetLottery::min_deposit(), 0);
+        assert_eq!(AssetLottery::min_withdraw(), 0);
+        assert_eq!(AssetLottery::gas_reserve(), 0);
+
+        Migration::on_runtime_upgrade();
+        assert_eq!(
+            AssetLottery::on_chain_storage_version(),
+            AssetLottery::current_storage_version()
+        );
+        assert_eq!(AssetLottery::min_deposit(), 5_000 * UNIT);
+        assert_eq!(AssetLottery::min_withdraw(), 1_000 * UNIT);
+        assert_eq!(AssetLottery::gas_reserve(), 10_000 * UNIT);
+
+        // parameters changed by governance afterwards are not reset by running it again
+        assert_ok!(AssetLottery::set_gas_reserve(Origin::root(), 20_000 * UNIT));
+        Migration::on_runtime_upgrade();
+        assert_eq!(AssetLottery::gas_reserve(), 20_000 * UNIT);
+    });
+}
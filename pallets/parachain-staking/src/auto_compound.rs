@@ -0,0 +1,175 @@
+// Copyright 2020-2024 Manta Network.
+// This file is part of Manta.
+//
+// Manta is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// Manta is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Manta.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Auto-compounding functionality for delegations
+
+use crate::{
+    pallet::{AutoCompoundingDelegations, BalanceOf, Config, DelegatorState, Error, Event, Pallet},
+    types::AddGet,
+};
+use codec::{Decode, Encode};
+use frame_support::{dispatch::DispatchResultWithPostInfo, ensure, BoundedVec, RuntimeDebug};
+use scale_info::TypeInfo;
+use sp_runtime::{traits::Zero, Percent};
+
+/// Represents the auto-compounding amount for a delegation.
+#[derive(Clone, Eq, PartialEq, Encode, Decode, RuntimeDebug, TypeInfo, PartialOrd, Ord)]
+pub struct AutoCompoundConfig<AccountId> {
+    pub delegator: AccountId,
+    pub value: Percent,
+}
+
+impl<T: Config> Pallet<T> {
+    /// Sets the auto-compounding value for a delegation. A value of zero removes the entry.
+    /// `candidate_auto_compounding_delegation_count_hint` and `delegation_count_hint` are
+    /// checked against storage to bound the weight of the call.
+    pub(crate) fn delegation_set_auto_compound(
+        candidate: T::AccountId,
+        delegator: T::AccountId,
+        value: Percent,
+        candidate_auto_compounding_delegation_count_hint: u32,
+        delegation_count_hint: u32,
+    ) -> DispatchResultWithPostInfo {
+        let delegator_state =
+            <DelegatorState<T>>::get(&delegator).ok_or(<Error<T>>::DelegatorDNE)?;
+        ensure!(
+            delegator_state.delegations.0.len() <= delegation_count_hint as usize,
+            <Error<T>>::TooLowDelegationCountToAutoCompound,
+        );
+        ensure!(
+            delegator_state
+                .delegations
+                .0
+                .iter()
+                .any(|b| b.owner == candidate),
+            <Error<T>>::DelegationDNE,
+        );
+
+        let mut auto_compounding_state = <AutoCompoundingDelegations<T>>::get(&candidate);
+        ensure!(
+            auto_compounding_state.len()
+                <= candidate_auto_compounding_delegation_count_hint as usize,
+            <Error<T>>::TooLowCandidateAutoCompoundingDelegationCountToAutoCompound,
+        );
+
+        let maybe_idx = auto_compounding_state
+            .iter()
+            .position(|c| c.delegator == delegator);
+        match (maybe_idx, value.is_zero()) {
+            (Some(idx), true) => {
+                auto_compounding_state.remove(idx);
+            }
+            (Some(idx), false) => {
+                auto_compounding_state[idx].value = value;
+            }
+            (None, false) => {
+                auto_compounding_state
+                    .try_push(AutoCompoundConfig {
+                        delegator: delegator.clone(),
+                        value,
+                    })
+                    .map_err(|_| <Error<T>>::ExceedMaxDelegationsPerDelegator)?;
+            }
+            (None, true) => return Ok(().into()),
+        }
+        Self::put_auto_compounding_state(&candidate, auto_compounding_state);
+
+        Self::deposit_event(Event::AutoCompoundSet {
+            candidate,
+            delegator,
+            value,
+        });
+        Ok(().into())
+    }
+
+    /// Removes the auto-compounding value for a delegation, if it exists.
+    pub(crate) fn delegation_remove_auto_compound(
+        candidate: &T::AccountId,
+        delegator: &T::AccountId,
+    ) {
+        let mut auto_compounding_state = <AutoCompoundingDelegations<T>>::get(candidate);
+        if let Some(idx) = auto_compounding_state
+            .iter()
+            .position(|c| &c.delegator == delegator)
+        {
+            auto_compounding_state.remove(idx);
+            Self::put_auto_compounding_state(candidate, auto_compounding_state);
+        }
+    }
+
+    /// Returns the auto-compounding value of a delegation, zero if none is set.
+    pub fn delegation_auto_compound(candidate: &T::AccountId, delegator: &T::AccountId) -> Percent {
+        <AutoCompoundingDelegations<T>>::get(candidate)
+            .into_iter()
+            .find(|c| &c.delegator == delegator)
+            .map(|c| c.value)
+            .unwrap_or_else(Percent::zero)
+    }
+
+    /// Pays `amt` out of the reward pot to the delegator and re-bonds `auto_compound` of it into
+    /// the delegation towards `candidate`. Failing to compound leaves the reward liquid.
+    pub(crate) fn mint_and_compound(
+        amt: BalanceOf<T>,
+        auto_compound: Percent,
+        candidate: T::AccountId,
+        delegator: T::AccountId,
+    ) {
+        let paid = match Self::pay_delegator_reward(&delegator, amt) {
+            Ok(paid) => paid,
+            Err(_) => return,
+        };
+
+        let compound_amount = auto_compound.mul_ceil(paid);
+        if compound_amount.is_zero() {
+            return;
+        }
+        // a delegation being revoked must not grow any further
+        if Self::delegation_request_revoke_exists(&candidate, &delegator) {
+            return;
+        }
+        let result = frame_support::storage::with_storage_layer(|| {
+            let mut state = <DelegatorState<T>>::get(&delegator).ok_or(<Error<T>>::DelegatorDNE)?;
+            state.increase_delegation::<T>(candidate.clone(), compound_amount)
+        });
+        match result {
+            Ok(()) => Self::deposit_event(Event::Compounded {
+                candidate,
+                delegator,
+                amount: compound_amount,
+            }),
+            Err(error) => log::warn!(
+                "Failed to compound reward for delegator {:?} towards {:?}: {:?}",
+                delegator,
+                candidate,
+                error
+            ),
+        }
+    }
+
+    fn put_auto_compounding_state(
+        candidate: &T::AccountId,
+        state: BoundedVec<
+            AutoCompoundConfig<T::AccountId>,
+            AddGet<T::MaxTopDelegationsPerCandidate, T::MaxBottomDelegationsPerCandidate>,
+        >,
+    ) {
+        if state.is_empty() {
+            <AutoCompoundingDelegations<T>>::remove(candidate);
+        } else {
+            <AutoCompoundingDelegations<T>>::insert(candidate, state);
+        }
+    }
+}
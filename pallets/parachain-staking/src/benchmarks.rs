@@ -18,16 +18,21 @@
 
 //! Benchmarking
 use crate::{
-    AwardedPts, BalanceOf, Call, CandidateBondLessRequest, Config, DelegationAction, Pallet,
-    Points, Range, Round, ScheduledRequest,
+    AtStake, AutoCompoundConfig, AutoCompoundingDelegations, AwardedPts, BalanceOf, Call,
+    CandidateBondLessRequest, CollatorSnapshot, CommissionChangeRequest, Config, DelegationAction,
+    InflationCurve, Pallet, Points, Range, RewardBoundaries, RewardPerShare, Round,
+    ScheduledRequest, SlashingConfig, SlashingInfo, UnappliedSlash, UnappliedSlashes,
 };
 use frame_benchmarking::{account, benchmarks, impl_benchmark_test_suite, vec};
-use frame_support::traits::{
-    tokens::{fungible::Inspect, Fortitude, Preservation},
-    Currency, Get, OnFinalize, OnInitialize,
+use frame_support::{
+    traits::{
+        tokens::{fungible::Inspect, Fortitude, Preservation},
+        Currency, Get, OnFinalize, OnInitialize,
+    },
+    BoundedVec,
 };
 use frame_system::RawOrigin;
-use sp_runtime::{Perbill, Percent};
+use sp_runtime::{FixedPointNumber, FixedU128, Perbill, Percent};
 use sp_std::{collections::btree_map::BTreeMap, vec::Vec};
 
 /// Minimum collator candidate stake
@@ -131,6 +136,18 @@ pub fn roll_to_and_author<T: Config>(round_delay: u32, author: T::AccountId) {
     }
 }
 
+/// A commission within the default bounds which differs from the one of `candidate`
+fn other_commission<T: Config>(candidate: &T::AccountId) -> Perbill {
+    let current = Pallet::<T>::candidate_info(candidate)
+        .map(|state| state.commission)
+        .unwrap_or_default();
+    if current == Perbill::from_percent(50) {
+        Perbill::from_percent(40)
+    } else {
+        Perbill::from_percent(50)
+    }
+}
+
 const USER_SEED: u32 = 999666;
 benchmarks! {
     // MONETARY ORIGIN DISPATCHABLES
@@ -979,8 +996,8 @@ benchmarks! {
     }
 
     pay_one_collator_reward {
-        // y controls number of delegations, its maximum per collator is the max top delegations
-        let y in 0..<<T as Config>::MaxTopDelegationsPerCandidate as Get<u32>>::get();
+        // delegators are paid lazily, so the snapshot of the collator is as large as it gets
+        let y = <<T as Config>::MaxTopDelegationsPerCandidate as Get<u32>>::get();
 
         // must come after 'let foo in 0..` statements for macro
         use crate::{
@@ -1042,6 +1059,7 @@ benchmarks! {
             bond: 1_000u32.into(),
             delegations,
             total: 1_000_000u32.into(),
+            commission: Perbill::from_rational(1u32, 100u32),
         });
 
         <Points<T>>::insert(round_for_payout, 100);
@@ -1060,15 +1078,447 @@ benchmarks! {
             <T as Config>::Currency::free_balance(&sole_collator) > initial_stake_amount,
             "collator should have been paid in pay_one_collator_reward"
         );
-        // nominators should have been paid
-        for delegator in &delegators {
-            assert!(
-                <T as Config>::Currency::free_balance(&delegator) > initial_stake_amount,
-                "delegator should have been paid in pay_one_collator_reward"
+        // nominators should have accrued their rewards
+        assert!(
+            !Pallet::<T>::reward_per_share(&sole_collator).is_zero(),
+            "delegators should have accrued rewards in pay_one_collator_reward"
+        );
+    }
+
+    delegate_with_auto_compound {
+        let x in 3..<<T as Config>::MaxDelegationsPerDelegator as Get<u32>>::get();
+        // leaves room for the caller in the bounded auto-compounding list
+        let y in 0..(<<T as Config>::MaxTopDelegationsPerCandidate as Get<u32>>::get()
+            + <<T as Config>::MaxBottomDelegationsPerCandidate as Get<u32>>::get() - 1);
+        let z in 2..<<T as Config>::MaxTopDelegationsPerCandidate as Get<u32>>::get();
+        // Worst Case is full of delegations before calling `delegate_with_auto_compound`
+        let mut collators: Vec<T::AccountId> = Vec::new();
+        // Initialize MaxDelegationsPerDelegator collator candidates
+        for i in 2..x {
+            let seed = USER_SEED - i;
+            let collator = create_funded_collator::<T>(
+                "collator",
+                seed,
+                0u32.into(),
+                true,
+                collators.len() as u32 + 1u32,
+            )?;
+            collators.push(collator.clone());
+        }
+        let bond = <<T as Config>::MinDelegatorStk as Get<BalanceOf<T>>>::get();
+        let extra = if (bond * (collators.len() as u32 + 1u32).into()) > min_candidate_stk::<T>() {
+            (bond * (collators.len() as u32 + 1u32).into()) - min_candidate_stk::<T>()
+        } else {
+            0u32.into()
+        };
+        let (caller, _) = create_funded_user::<T>("caller", USER_SEED, extra.into());
+        // Delegation count
+        let mut del_del_count = 0u32;
+        // Nominate MaxDelegationsPerDelegators collator candidates
+        for col in collators.clone() {
+            Pallet::<T>::delegate(
+                RawOrigin::Signed(caller.clone()).into(), col, bond, 0u32, del_del_count
+            )?;
+            del_del_count += 1u32;
+        }
+        // Last collator to be delegated
+        let collator: T::AccountId = create_funded_collator::<T>(
+            "collator",
+            USER_SEED,
+            0u32.into(),
+            true,
+            collators.len() as u32 + 1u32,
+        )?;
+        // Worst Case Complexity is insertion into an almost full collator
+        let mut col_del_count = 0u32;
+        for i in 1..z {
+            let seed = USER_SEED + i;
+            let _ = create_funded_delegator::<T>(
+                "delegator",
+                seed,
+                0u32.into(),
+                collator.clone(),
+                true,
+                col_del_count,
+            )?;
+            col_del_count += 1u32;
+        }
+        // Worst Case Complexity is appending to a long auto-compounding list
+        let auto_compounding_state: BoundedVec<_, _> = (0..y)
+            .map(|i| AutoCompoundConfig {
+                delegator: account("auto", i, USER_SEED),
+                value: Percent::from_percent(50),
+            })
+            .collect::<Vec<_>>()
+            .try_into()
+            .expect("y is below the auto-compounding bound, qed");
+        <AutoCompoundingDelegations<T>>::insert(&collator, auto_compounding_state);
+    }: _(
+        RawOrigin::Signed(caller.clone()),
+        collator.clone(),
+        bond,
+        Percent::from_percent(50),
+        col_del_count,
+        y,
+        del_del_count
+    )
+    verify {
+        assert!(Pallet::<T>::is_delegator(&caller));
+        assert_eq!(
+            Pallet::<T>::delegation_auto_compound(&collator, &caller),
+            Percent::from_percent(50),
+        );
+    }
+
+    set_auto_compound {
+        // leaves room for the caller in the bounded auto-compounding list
+        let x in 0..(<<T as Config>::MaxTopDelegationsPerCandidate as Get<u32>>::get()
+            + <<T as Config>::MaxBottomDelegationsPerCandidate as Get<u32>>::get() - 1);
+        let y in 1..<<T as Config>::MaxDelegationsPerDelegator as Get<u32>>::get();
+        let bond = <<T as Config>::MinDelegatorStk as Get<BalanceOf<T>>>::get();
+        let (caller, _) = create_funded_user::<T>("caller", USER_SEED, bond * y.into());
+        // Delegate to y collator candidates, the last one is auto-compounded
+        let mut collators: Vec<T::AccountId> = Vec::new();
+        for i in 0..y {
+            let collator = create_funded_collator::<T>(
+                "collator",
+                USER_SEED - i,
+                0u32.into(),
+                true,
+                i + 1u32,
+            )?;
+            Pallet::<T>::delegate(
+                RawOrigin::Signed(caller.clone()).into(), collator.clone(), bond, 0u32, i
+            )?;
+            collators.push(collator);
+        }
+        let collator = collators.pop().expect("y >= 1, qed");
+        let auto_compounding_state: BoundedVec<_, _> = (0..x)
+            .map(|i| AutoCompoundConfig {
+                delegator: account("auto", i, USER_SEED),
+                value: Percent::from_percent(50),
+            })
+            .collect::<Vec<_>>()
+            .try_into()
+            .expect("x is below the auto-compounding bound, qed");
+        <AutoCompoundingDelegations<T>>::insert(&collator, auto_compounding_state);
+    }: _(RawOrigin::Signed(caller.clone()), collator.clone(), Percent::from_percent(50), x, y)
+    verify {
+        assert_eq!(
+            Pallet::<T>::delegation_auto_compound(&collator, &caller),
+            Percent::from_percent(50),
+        );
+    }
+
+    mint_and_compound {
+        let collator: T::AccountId = create_funded_collator::<T>(
+            "collator",
+            USER_SEED,
+            0u32.into(),
+            true,
+            1u32
+        )?;
+        let bond = <<T as Config>::MinDelegatorStk as Get<BalanceOf<T>>>::get();
+        let delegator = create_funded_delegator::<T>(
+            "delegator",
+            USER_SEED,
+            0u32.into(),
+            collator.clone(),
+            true,
+            0u32,
+        )?;
+        Pallet::<T>::set_auto_compound(
+            RawOrigin::Signed(delegator.clone()).into(),
+            collator.clone(),
+            Percent::from_percent(100),
+            0u32,
+            1u32,
+        )?;
+    }: {
+        Pallet::<T>::mint_and_compound(
+            bond,
+            Percent::from_percent(100),
+            collator.clone(),
+            delegator.clone(),
+        );
+    }
+    verify {
+        assert_eq!(
+            Pallet::<T>::delegator_state(&delegator).expect("delegator was created, qed").total,
+            bond * 2u32.into(),
+        );
+    }
+
+    set_slashing_config {
+        let config = SlashingConfig {
+            min_performance: Perbill::from_percent(50),
+            tolerated_rounds: 3,
+            slash_fraction: Perbill::from_percent(1),
+            slash_delegations: true,
+            equivocation_slash_fraction: Perbill::from_percent(10),
+        };
+    }: _(RawOrigin::Root, config)
+    verify {
+        assert_eq!(Pallet::<T>::slashing_info(), config);
+    }
+
+    cancel_deferred_slash {
+        let x in 1..100;
+        let slashes: Vec<_> = (0..x)
+            .map(|i| UnappliedSlash {
+                collator: account("collator", i, USER_SEED),
+                fraction: Perbill::from_percent(1),
+                own: min_candidate_stk::<T>(),
+                others: Vec::new(),
+            })
+            .collect();
+        <UnappliedSlashes<T>>::insert(1u32, slashes);
+    }: _(RawOrigin::Root, 1u32, (0..x).collect())
+    verify {
+        assert!(Pallet::<T>::unapplied_slashes(1u32).is_empty());
+    }
+
+    apply_slash {
+        let x in 0..<<T as Config>::MaxTopDelegationsPerCandidate as Get<u32>>::get();
+        let collator: T::AccountId = create_funded_collator::<T>(
+            "collator",
+            USER_SEED,
+            0u32.into(),
+            true,
+            1u32
+        )?;
+        for i in 0..x {
+            let _ = create_funded_delegator::<T>(
+                "delegator",
+                USER_SEED + i,
+                0u32.into(),
+                collator.clone(),
+                true,
+                i,
+            )?;
+        }
+        let bond_before = Pallet::<T>::candidate_info(&collator).expect("candidate exists").bond;
+        let slash = UnappliedSlash {
+            collator: collator.clone(),
+            fraction: Perbill::from_percent(10),
+            own: bond_before,
+            others: Pallet::<T>::top_delegations(&collator)
+                .expect("candidate exists")
+                .delegations,
+        };
+    }: {
+        Pallet::<T>::apply_slash(slash);
+    }
+    verify {
+        assert!(
+            Pallet::<T>::candidate_info(&collator).expect("candidate exists").bond < bond_before
+        );
+    }
+
+    redelegate {
+        let from: T::AccountId = create_funded_collator::<T>(
+            "collator",
+            USER_SEED,
+            0u32.into(),
+            true,
+            1u32
+        )?;
+        let to: T::AccountId = create_funded_collator::<T>(
+            "collator",
+            USER_SEED - 1,
+            0u32.into(),
+            true,
+            2u32
+        )?;
+        // Worst Case is insertion into full top delegations, bumping the lowest to the bottom
+        let max_top = <<T as Config>::MaxTopDelegationsPerCandidate as Get<u32>>::get();
+        for i in 0..max_top {
+            let _ = create_funded_delegator::<T>(
+                "delegator",
+                USER_SEED + i,
+                0u32.into(),
+                to.clone(),
+                true,
+                i,
+            )?;
+        }
+        let caller = create_funded_delegator::<T>(
+            "caller",
+            USER_SEED,
+            0u32.into(),
+            from.clone(),
+            false,
+            0u32,
+        )?;
+        let amount = Pallet::<T>::delegator_state(&caller).expect("delegator exists").total();
+    }: _(RawOrigin::Signed(caller.clone()), from.clone(), to.clone(), amount)
+    verify {
+        let state = Pallet::<T>::delegator_state(&caller).expect("delegator exists");
+        assert_eq!(state.get_bond_amount(&to), Some(amount));
+        assert_eq!(state.get_bond_amount(&from), None);
+    }
+
+    set_commission_bounds {}: _(RawOrigin::Root, Perbill::from_percent(1), Perbill::from_percent(50))
+    verify {
+        assert_eq!(
+            Pallet::<T>::commission_bounds(),
+            (Perbill::from_percent(1), Perbill::from_percent(50))
+        );
+    }
+
+    schedule_commission_change {
+        let caller: T::AccountId = create_funded_collator::<T>(
+            "collator",
+            USER_SEED,
+            0u32.into(),
+            true,
+            1u32,
+        )?;
+        let commission = other_commission::<T>(&caller);
+    }: _(RawOrigin::Signed(caller.clone()), commission)
+    verify {
+        let state = Pallet::<T>::candidate_info(&caller).expect("candidate exists");
+        assert_eq!(
+            state.commission_request,
+            Some(CommissionChangeRequest {
+                commission,
+                when_executable: 1 + <<T as Config>::CommissionChangeDelay as Get<u32>>::get(),
+            })
+        );
+    }
+
+    execute_commission_change {
+        let caller: T::AccountId = create_funded_collator::<T>(
+            "collator",
+            USER_SEED,
+            0u32.into(),
+            true,
+            1u32,
+        )?;
+        let commission = other_commission::<T>(&caller);
+        Pallet::<T>::schedule_commission_change(
+            RawOrigin::Signed(caller.clone()).into(),
+            commission
+        )?;
+        roll_to_and_author::<T>(<<T as Config>::CommissionChangeDelay as Get<u32>>::get(), caller.clone());
+    }: _(RawOrigin::Signed(caller.clone()), caller.clone())
+    verify {
+        let state = Pallet::<T>::candidate_info(&caller).expect("candidate exists");
+        assert_eq!(state.commission, commission);
+        assert!(state.commission_request.is_none());
+    }
+
+    cancel_commission_change {
+        let caller: T::AccountId = create_funded_collator::<T>(
+            "collator",
+            USER_SEED,
+            0u32.into(),
+            true,
+            1u32,
+        )?;
+        Pallet::<T>::schedule_commission_change(
+            RawOrigin::Signed(caller.clone()).into(),
+            other_commission::<T>(&caller)
+        )?;
+    }: _(RawOrigin::Signed(caller.clone()))
+    verify {
+        let state = Pallet::<T>::candidate_info(&caller).expect("candidate exists");
+        assert!(state.commission_request.is_none());
+    }
+
+    set_inflation_curve {
+        let curve = InflationCurve {
+            ideal_staked: Perbill::from_percent(50),
+            falloff: Perbill::from_percent(5),
+        };
+    }: _(RawOrigin::Root, Some(curve))
+    verify {
+        assert_eq!(Pallet::<T>::inflation_curve(), Some(curve));
+    }
+
+    claim_rewards {
+        let x in 1..<<T as Config>::MaxDelegationsPerDelegator as Get<u32>>::get();
+        let mut collators: Vec<T::AccountId> = Vec::new();
+        for i in 0..x {
+            let seed = USER_SEED - i;
+            let collator = create_funded_collator::<T>(
+                "collator",
+                seed,
+                0u32.into(),
+                true,
+                collators.len() as u32 + 1u32
+            )?;
+            collators.push(collator.clone());
+        }
+        let bond = <<T as Config>::MinDelegatorStk as Get<BalanceOf<T>>>::get();
+        let need = bond * (collators.len() as u32).into();
+        let default_minted = min_candidate_stk::<T>();
+        let need: BalanceOf<T> = if need > default_minted {
+            need - default_minted
+        } else {
+            0u32.into()
+        };
+        let (caller, _) = create_funded_user::<T>("caller", USER_SEED, need);
+        let mut delegation_count = 0u32;
+        for col in collators.clone() {
+            Pallet::<T>::delegate(
+                RawOrigin::Signed(caller.clone()).into(),
+                col.clone(),
+                bond,
+                0u32,
+                delegation_count
+            )?;
+            delegation_count += 1u32;
+            // every delegation has accrued rewards after its start boundary
+            let round = Pallet::<T>::round().current;
+            <RewardBoundaries<T>>::mutate(&col, round, |boundary| {
+                if let Some(boundary) = boundary {
+                    boundary.per_share = Some(FixedU128::saturating_from_rational(1u32, 10u32));
+                }
+            });
+            <RewardPerShare<T>>::insert(&col, FixedU128::saturating_from_rational(2u32, 10u32));
+        }
+    }: _(RawOrigin::Signed(caller.clone()))
+    verify {
+        for col in collators {
+            assert_eq!(
+                Pallet::<T>::reward_checkpoint(&caller, &col).map(|c| c.per_share),
+                Some(Pallet::<T>::reward_per_share(&col))
             );
         }
     }
 
+    start_collator_selection_migration {}: _(RawOrigin::Root)
+    verify {
+        assert!(Pallet::<T>::collator_selection_migration());
+    }
+
+    report_equivocation {
+        let collator: T::AccountId = create_funded_collator::<T>(
+            "collator",
+            USER_SEED,
+            0u32.into(),
+            true,
+            1u32
+        )?;
+        <SlashingInfo<T>>::put(SlashingConfig {
+            min_performance: Perbill::from_percent(50),
+            tolerated_rounds: 3,
+            slash_fraction: Perbill::from_percent(1),
+            slash_delegations: true,
+            equivocation_slash_fraction: Perbill::from_percent(10),
+        });
+        <AtStake<T>>::insert(1u32, &collator, CollatorSnapshot {
+            bond: min_candidate_stk::<T>(),
+            delegations: Vec::new(),
+            total: min_candidate_stk::<T>(),
+            commission: Perbill::zero(),
+        });
+    }: _(RawOrigin::Root, collator.clone(), 1u32)
+    verify {
+        assert!(Pallet::<T>::reported_equivocations(1u32, &collator).is_some());
+    }
+
     base_on_initialize {
         let collator: T::AccountId = create_funded_collator::<T>(
             "collator",
@@ -1308,6 +1758,111 @@ mod tests {
         });
     }
 
+    #[test]
+    fn bench_delegate_with_auto_compound() {
+        new_test_ext().execute_with(|| {
+            assert_ok!(Pallet::<Test>::test_benchmark_delegate_with_auto_compound());
+        });
+    }
+
+    #[test]
+    fn bench_set_auto_compound() {
+        new_test_ext().execute_with(|| {
+            assert_ok!(Pallet::<Test>::test_benchmark_set_auto_compound());
+        });
+    }
+
+    #[test]
+    fn bench_mint_and_compound() {
+        new_test_ext().execute_with(|| {
+            assert_ok!(Pallet::<Test>::test_benchmark_mint_and_compound());
+        });
+    }
+
+    #[test]
+    fn bench_set_slashing_config() {
+        new_test_ext().execute_with(|| {
+            assert_ok!(Pallet::<Test>::test_benchmark_set_slashing_config());
+        });
+    }
+
+    #[test]
+    fn bench_cancel_deferred_slash() {
+        new_test_ext().execute_with(|| {
+            assert_ok!(Pallet::<Test>::test_benchmark_cancel_deferred_slash());
+        });
+    }
+
+    #[test]
+    fn bench_apply_slash() {
+        new_test_ext().execute_with(|| {
+            assert_ok!(Pallet::<Test>::test_benchmark_apply_slash());
+        });
+    }
+
+    #[test]
+    fn bench_redelegate() {
+        new_test_ext().execute_with(|| {
+            assert_ok!(Pallet::<Test>::test_benchmark_redelegate());
+        });
+    }
+
+    #[test]
+    fn bench_set_commission_bounds() {
+        new_test_ext().execute_with(|| {
+            assert_ok!(Pallet::<Test>::test_benchmark_set_commission_bounds());
+        });
+    }
+
+    #[test]
+    fn bench_schedule_commission_change() {
+        new_test_ext().execute_with(|| {
+            assert_ok!(Pallet::<Test>::test_benchmark_schedule_commission_change());
+        });
+    }
+
+    #[test]
+    fn bench_execute_commission_change() {
+        new_test_ext().execute_with(|| {
+            assert_ok!(Pallet::<Test>::test_benchmark_execute_commission_change());
+        });
+    }
+
+    #[test]
+    fn bench_cancel_commission_change() {
+        new_test_ext().execute_with(|| {
+            assert_ok!(Pallet::<Test>::test_benchmark_cancel_commission_change());
+        });
+    }
+
+    #[test]
+    fn bench_set_inflation_curve() {
+        new_test_ext().execute_with(|| {
+            assert_ok!(Pallet::<Test>::test_benchmark_set_inflation_curve());
+        });
+    }
+
+    #[test]
+    fn bench_claim_rewards() {
+        new_test_ext().execute_with(|| {
+            assert_ok!(Pallet::<Test>::test_benchmark_claim_rewards());
+        });
+    }
+
+    #[test]
+    fn bench_start_collator_selection_migration() {
+        new_test_ext().execute_with(|| {
+            assert_ok!(Pallet::<Test>::test_benchmark_start_collator_selection_migration());
+        });
+    }
+
+    #[test]
+    fn bench_report_equivocation() {
+        new_test_ext().execute_with(|| {
+            assert_ok!(Pallet::<Test>::test_benchmark_report_equivocation());
+        });
+    }
+
     #[test]
     fn bench_base_on_initialize() {
         new_test_ext().execute_with(|| {
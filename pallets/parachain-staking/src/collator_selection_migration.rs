@@ -0,0 +1,96 @@
+// Copyright 2020-2024 Manta Network.
+// This file is part of Manta.
+//
+// Manta is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// Manta is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Manta.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Multi-block migration of the collators of `manta_collator_selection` into the candidate pool
+//!
+//! Once started by governance, up to `MaxCollatorSelectionMigrationsPerBlock` collators are moved
+//! in every block, candidates before invulnerables. A candidate first gets its deposit back, then
+//! every collator joins the candidate pool with a bond of `MinWhitelistCandidateStk`. Collators
+//! which cannot join, e.g. for lack of free balance, are dropped with a `CollatorNotMigrated`
+//! event. Once none are left the storage of `manta_collator_selection` is drained, so block
+//! authors only come from `SelectedCandidates`.
+
+use crate::pallet::{CandidatePool, CollatorSelectionMigration, Config, Error, Event, Pallet};
+use frame_support::{pallet_prelude::Weight, traits::Get};
+use frame_system::RawOrigin;
+use sp_runtime::traits::Saturating;
+use sp_std::cmp::min;
+
+impl<T: Config> Pallet<T> {
+    /// Moves the next batch of `manta_collator_selection` collators into the candidate pool if
+    /// the migration is in progress, returns the weight consumed
+    pub(crate) fn migrate_collator_selection_batch() -> Weight {
+        if !<CollatorSelectionMigration<T>>::get() {
+            return T::DbWeight::get().reads(1);
+        }
+        // read CollatorSelectionMigration, Candidates, Invulnerables
+        let mut weight = T::DbWeight::get().reads(3);
+        let batch = T::MaxCollatorSelectionMigrationsPerBlock::get() as usize;
+
+        let candidates = manta_collator_selection::Pallet::<T>::candidates();
+        let candidates_len = candidates.len();
+        for candidate in candidates.into_iter().take(batch) {
+            // returns the deposit, this can not fail since the account is a candidate
+            let _ = manta_collator_selection::Pallet::<T>::leave_intent(
+                RawOrigin::Signed(candidate.who.clone()).into(),
+            );
+            weight = weight
+                .saturating_add(
+                    <T as manta_collator_selection::Config>::WeightInfo::leave_intent(
+                        candidates_len as u32,
+                    ),
+                )
+                .saturating_add(Self::migrate_collator(candidate.who));
+        }
+
+        let mut invulnerables = manta_collator_selection::Pallet::<T>::invulnerables();
+        let invulnerables_len = invulnerables.len();
+        let take = min(batch.saturating_sub(candidates_len), invulnerables_len);
+        if take > 0 {
+            let remaining = invulnerables.split_off(take);
+            <manta_collator_selection::Invulnerables<T>>::put(remaining);
+            weight = weight.saturating_add(T::DbWeight::get().writes(1));
+            for invulnerable in invulnerables {
+                weight = weight.saturating_add(Self::migrate_collator(invulnerable));
+            }
+        }
+
+        if candidates_len.saturating_add(invulnerables_len) <= batch {
+            weight = weight.saturating_add(manta_collator_selection::Pallet::<T>::drain_storage());
+            <CollatorSelectionMigration<T>>::kill();
+            weight = weight.saturating_add(T::DbWeight::get().writes(1));
+            Self::deposit_event(Event::CollatorSelectionMigrationCompleted);
+        }
+        weight
+    }
+
+    /// Adds `account` to the candidate pool with the whitelisted minimum bond unless it already is
+    /// a candidate, returns the weight consumed
+    fn migrate_collator(account: T::AccountId) -> Weight {
+        if Self::is_candidate(&account) {
+            return T::DbWeight::get().reads(1);
+        }
+        let result = if Self::is_delegator(&account) {
+            Err(Error::<T>::DelegatorExists.into())
+        } else {
+            Self::add_candidate(account.clone(), T::MinWhitelistCandidateStk::get())
+        };
+        if let Err(error) = result {
+            Self::deposit_event(Event::CollatorNotMigrated { account, error });
+        }
+        <T as Config>::WeightInfo::join_candidates(<CandidatePool<T>>::get().0.len() as u32)
+    }
+}
@@ -19,17 +19,20 @@
 use crate::{
     pallet::{
         BalanceOf, CandidateInfo, Config, DelegationScheduledRequests, DelegatorState, Error,
-        Event, Pallet, Round, RoundIndex, Total,
+        Event, LastRedelegation, Pallet, Round, RoundIndex, Total,
     },
     Delegator, DelegatorStatus,
 };
 use codec::{Decode, Encode};
 use frame_support::{dispatch::DispatchResultWithPostInfo, ensure, traits::Get, RuntimeDebug};
 use scale_info::TypeInfo;
+#[cfg(feature = "std")]
+use serde::{Deserialize, Serialize};
 use sp_runtime::traits::Saturating;
 use sp_std::{vec, vec::Vec};
 
 /// An action that can be performed upon a delegation
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 #[derive(Clone, Eq, PartialEq, Encode, Decode, RuntimeDebug, TypeInfo, PartialOrd, Ord)]
 pub enum DelegationAction<Balance> {
     Revoke(Balance),
@@ -48,6 +51,7 @@ impl<Balance: Copy> DelegationAction<Balance> {
 
 /// Represents a scheduled request that define a [DelegationAction]. The request is executable
 /// iff the provided [RoundIndex] is achieved.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 #[derive(Clone, Eq, PartialEq, Encode, Decode, RuntimeDebug, TypeInfo, PartialOrd, Ord)]
 pub struct ScheduledRequest<AccountId, Balance> {
     pub delegator: AccountId,
@@ -92,6 +96,8 @@ impl<T: Config> Pallet<T> {
             .ok_or(<Error<T>>::DelegationDNE)?;
         let now = <Round<T>>::get().current;
         let when = now.saturating_add(T::RevokeDelegationDelay::get());
+        // a delegation being revoked stops earning rewards
+        Self::settle_delegation_rewards(&collator, &delegator, bonded_amount);
         scheduled_requests.push(ScheduledRequest {
             delegator: delegator.clone(),
             action: DelegationAction::Revoke(bonded_amount),
@@ -150,6 +156,8 @@ impl<T: Config> Pallet<T> {
 
         let now = <Round<T>>::get().current;
         let when = now.saturating_add(T::RevokeDelegationDelay::get());
+        // the amount being decreased stops earning rewards
+        Self::settle_delegation_rewards(&collator, &delegator, bonded_amount);
         scheduled_requests.push(ScheduledRequest {
             delegator: delegator.clone(),
             action: DelegationAction::Decrease(decrease_amount),
@@ -176,6 +184,9 @@ impl<T: Config> Pallet<T> {
         let mut state = <DelegatorState<T>>::get(&delegator).ok_or(<Error<T>>::DelegatorDNE)?;
         let mut scheduled_requests = <DelegationScheduledRequests<T>>::get(&collator);
 
+        if let Some(bonded_amount) = state.get_bond_amount(&collator) {
+            Self::settle_delegation_rewards(&collator, &delegator, bonded_amount);
+        }
         let request =
             Self::cancel_request_with_state(&delegator, &mut state, &mut scheduled_requests)
                 .ok_or(<Error<T>>::PendingDelegationRequestDNE)?;
@@ -259,6 +270,7 @@ impl<T: Config> Pallet<T> {
                 <DelegationScheduledRequests<T>>::insert(collator, scheduled_requests);
                 if leaving {
                     <DelegatorState<T>>::remove(&delegator);
+                    <LastRedelegation<T>>::remove(&delegator);
                     Self::deposit_event(Event::DelegatorLeft {
                         delegator,
                         unstaked_amount: amount,
@@ -351,6 +363,7 @@ impl<T: Config> Pallet<T> {
             let collator = bond.owner;
             let bonded_amount = bond.amount;
             let mut scheduled_requests = <DelegationScheduledRequests<T>>::get(&collator);
+            Self::settle_delegation_rewards(&collator, &delegator, bonded_amount);
 
             // cancel any existing requests
             let request =
@@ -425,6 +438,7 @@ impl<T: Config> Pallet<T> {
         for bond in state.delegations.0.clone() {
             let collator = bond.owner;
             let mut scheduled_requests = <DelegationScheduledRequests<T>>::get(&collator);
+            Self::settle_delegation_rewards(&collator, &delegator, bond.amount);
             Self::cancel_request_with_state(&delegator, &mut state, &mut scheduled_requests);
             updated_scheduled_requests.push((collator, scheduled_requests));
         }
@@ -478,6 +492,7 @@ impl<T: Config> Pallet<T> {
                 Self::delegation_remove_request_with_state(&bond.owner, &delegator, &mut state);
             }
             <DelegatorState<T>>::remove(&delegator);
+            <LastRedelegation<T>>::remove(&delegator);
             Self::deposit_event(Event::DelegatorLeft {
                 delegator,
                 unstaked_amount: state.total,
@@ -540,6 +555,7 @@ impl<T: Config> Pallet<T> {
             unstaked_amount,
         });
         <DelegatorState<T>>::remove(&delegator);
+        <LastRedelegation<T>>::remove(&delegator);
 
         Ok(().into())
     }
@@ -28,7 +28,7 @@ const SECONDS_PER_YEAR: u32 = 31557600; // = 365.25 * 24 * 60 * 60, .25 to accom
 const SECONDS_PER_BLOCK: u32 = manta_primitives::constants::time::SECONDS_PER_BLOCK as u32;
 pub const BLOCKS_PER_YEAR: u32 = SECONDS_PER_YEAR / SECONDS_PER_BLOCK;
 
-fn rounds_per_year<T: Config>() -> u32 {
+pub(crate) fn rounds_per_year<T: Config>() -> u32 {
     let blocks_per_round = <Pallet<T>>::round().length;
     BLOCKS_PER_YEAR / blocks_per_round
 }
@@ -134,6 +134,47 @@ impl<Balance> InflationInfo<Balance> {
     }
 }
 
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Eq, PartialEq, Clone, Copy, Encode, Decode, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+/// Inflation curve driven by the staked ratio, similar to the NPoS reward curve.
+/// Round inflation grows linearly from `round.min` with nothing staked to `round.ideal` at
+/// `ideal_staked`, then decays back towards `round.min`, halving the excess every `falloff`.
+pub struct InflationCurve {
+    /// Staked ratio of the total issuance at which inflation peaks
+    pub ideal_staked: Perbill,
+    /// Increase of the staked ratio past `ideal_staked` which halves the excess inflation
+    pub falloff: Perbill,
+}
+
+impl InflationCurve {
+    pub fn is_valid(&self) -> bool {
+        !self.ideal_staked.is_zero() && !self.falloff.is_zero()
+    }
+    /// Round inflation for the `staked` ratio given the round inflation range
+    pub fn round_inflation(&self, round: Range<Perbill>, staked: Perbill) -> Perbill {
+        let excess = round.ideal.saturating_sub(round.min).deconstruct();
+        let due = if staked <= self.ideal_staked {
+            // staked <= ideal_staked so the result fits back into excess
+            (staked.deconstruct() as u64 * excess as u64 / self.ideal_staked.deconstruct() as u64)
+                as u32
+        } else {
+            let over = staked.deconstruct() - self.ideal_staked.deconstruct();
+            let falloff = self.falloff.deconstruct();
+            let halved = excess.checked_shr(over / falloff).unwrap_or_default();
+            if over % falloff == 0 {
+                halved
+            } else {
+                let rest = I64F64::from_num(over % falloff) / I64F64::from_num(falloff);
+                // 2^rest lies in (1, 2) so it cannot overflow
+                let divisor = floatpow(I64F64::from_num(2), rest)
+                    .expect("Cannot overflow since the exponent is below 1; QED");
+                (I64F64::from_num(halved) / divisor).to_num::<u32>()
+            }
+        };
+        round.min.saturating_add(Perbill::from_parts(due))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -210,6 +251,43 @@ mod tests {
         );
     }
     #[test]
+    fn inflation_curve_peaks_at_ideal_staked() {
+        let curve = InflationCurve {
+            ideal_staked: Perbill::from_percent(50),
+            falloff: Perbill::from_percent(10),
+        };
+        let round = Range {
+            min: Perbill::from_parts(1_000),
+            ideal: Perbill::from_parts(5_000),
+            max: Perbill::from_parts(5_000),
+        };
+        assert_eq!(
+            curve.round_inflation(round, Perbill::zero()),
+            Perbill::from_parts(1_000)
+        );
+        assert_eq!(
+            curve.round_inflation(round, Perbill::from_percent(25)),
+            Perbill::from_parts(3_000)
+        );
+        assert_eq!(
+            curve.round_inflation(round, Perbill::from_percent(50)),
+            Perbill::from_parts(5_000)
+        );
+        // excess halves every falloff past the ideal
+        assert_eq!(
+            curve.round_inflation(round, Perbill::from_percent(60)),
+            Perbill::from_parts(3_000)
+        );
+        assert_eq!(
+            curve.round_inflation(round, Perbill::from_percent(70)),
+            Perbill::from_parts(2_000)
+        );
+        assert_eq!(
+            curve.round_inflation(round, Perbill::one()),
+            Perbill::from_parts(1_125)
+        );
+    }
+    #[test]
     fn inflation_does_not_panic_at_round_number_limit() {
         let schedule = Range {
             min: Perbill::from_percent(100),
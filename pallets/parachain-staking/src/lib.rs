@@ -29,8 +29,10 @@
 //! * a new set of collators is chosen from the candidates
 //!
 //! Immediately following a round change, payments are made once-per-block until all payments have
-//! been made. In each such block, one collator is chosen for a rewards payment and is paid along
-//! with each of its top `T::MaxTopDelegationsPerCandidate` delegators.
+//! been made. In each such block, one collator is chosen for a rewards payment and is paid, while
+//! the rewards of its top `T::MaxTopDelegationsPerCandidate` delegators accrue until they call
+//! `claim_rewards` or change their bond. Any signed account may claim them on behalf of a delegator
+//! with `claim_rewards_for`, compounding the rewards of auto-compounding delegations.
 //!
 //! To join the set of candidates, call `join_candidates` with `bond >= MinCandidateStk`.
 //! To leave the set of candidates, call `schedule_leave_candidates`. If the call succeeds,
@@ -49,9 +51,14 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 #![allow(clippy::all)]
 
+mod auto_compound;
+mod collator_selection_migration;
 mod delegation_requests;
 pub mod inflation;
 pub mod migrations;
+#[cfg(feature = "rpc")]
+pub mod rpc;
+pub mod runtime;
 pub mod traits;
 pub mod types;
 pub mod weights;
@@ -62,17 +69,23 @@ pub mod benchmarks;
 #[cfg(test)]
 #[allow(clippy::all)]
 mod mock;
+mod redelegation;
+mod rewards;
 mod set;
+mod slashing;
 #[cfg(test)]
 #[allow(clippy::all)]
 mod tests;
 
 use frame_support::pallet;
-pub use inflation::{InflationInfo, Range};
+pub use inflation::{InflationCurve, InflationInfo, Range};
 pub use weights::WeightInfo;
 
+pub use auto_compound::AutoCompoundConfig;
 pub use delegation_requests::{CancelledScheduledRequest, DelegationAction, ScheduledRequest};
 pub use pallet::*;
+pub use rewards::{RewardBoundary, RewardCheckpoint};
+pub use slashing::{SlashingConfig, UnappliedSlash};
 pub use traits::*;
 pub use types::*;
 pub use RoundIndex;
@@ -80,24 +93,29 @@ pub use RoundIndex;
 #[pallet]
 pub mod pallet {
     use crate::{
+        auto_compound::AutoCompoundConfig,
         delegation_requests::{CancelledScheduledRequest, DelegationAction, ScheduledRequest},
+        rewards::{RewardBoundary, RewardCheckpoint},
         set::OrderedSet,
+        slashing::{SlashingConfig, UnappliedSlash},
         traits::*,
         types::*,
-        InflationInfo, Range, WeightInfo,
+        InflationCurve, InflationInfo, Range, WeightInfo,
     };
     use codec::Decode;
     use frame_support::{
         pallet_prelude::*,
         traits::{
             tokens::{fungible::Inspect, WithdrawReasons},
-            Currency, Get, Imbalance, LockIdentifier, LockableCurrency, ReservableCurrency,
+            Currency, Get, Imbalance, LockIdentifier, LockableCurrency, OnUnbalanced,
+            ReservableCurrency,
         },
+        PalletId,
     };
     use frame_system::pallet_prelude::*;
     use sp_runtime::{
         traits::{Saturating, Zero},
-        Perbill, Percent,
+        FixedPointNumber, FixedU128, Perbill, Percent,
     };
     use sp_std::{collections::btree_map::BTreeMap, prelude::*};
 
@@ -111,11 +129,16 @@ pub mod pallet {
     type RewardPoint = u32;
     pub type BalanceOf<T> =
         <<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
+    pub type NegativeImbalanceOf<T> = <<T as Config>::Currency as Currency<
+        <T as frame_system::Config>::AccountId,
+    >>::NegativeImbalance;
 
     pub const COLLATOR_LOCK_ID: LockIdentifier = *b"stkngcol";
     pub const DELEGATOR_LOCK_ID: LockIdentifier = *b"stkngdel";
+    /// Holds delegator rewards until they are claimed
+    pub const REWARD_POT_ID: PalletId = PalletId(*b"stkngrwd");
 
-    const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+    const STORAGE_VERSION: StorageVersion = StorageVersion::new(3);
 
     /// Configuration trait of this pallet.
     #[pallet::config]
@@ -194,6 +217,21 @@ pub mod pallet {
         /// Handler to notify the runtime when a new round begin.
         /// If you don't need it, you can specify the type `()`.
         type OnNewRound: OnNewRound;
+        /// Handler for the funds slashed from underperforming collators and their delegators
+        type OnSlash: OnUnbalanced<NegativeImbalanceOf<Self>>;
+        /// Number of rounds a slash is deferred for, during which it can be cancelled
+        #[pallet::constant]
+        type SlashDeferDuration: Get<RoundIndex>;
+        /// Minimum number of rounds between two redelegations of a delegator
+        #[pallet::constant]
+        type RedelegationCooldown: Get<RoundIndex>;
+        /// Number of rounds before a commission change can be executed
+        #[pallet::constant]
+        type CommissionChangeDelay: Get<RoundIndex>;
+        /// Maximum number of `manta_collator_selection` collators moved into the candidate pool
+        /// per block while migrating
+        #[pallet::constant]
+        type MaxCollatorSelectionMigrationsPerBlock: Get<u32>;
         /// Weight information for extrinsics in this pallet.
         type WeightInfo: WeightInfo;
     }
@@ -245,6 +283,25 @@ pub mod pallet {
         PalletAlreadyInitialized,
         NotEnoughCollatorsForPalletInit,
         InvalidState,
+        TooLowCandidateAutoCompoundingDelegationCountToDelegate,
+        TooLowCandidateAutoCompoundingDelegationCountToAutoCompound,
+        TooLowDelegationCountToAutoCompound,
+        InvalidSlashFraction,
+        EmptySlashIndices,
+        InvalidSlashIndex,
+        NotSortedAndUnique,
+        CannotRedelegateToSameCandidate,
+        RedelegationCooldownNotElapsed,
+        InvalidCommissionBounds,
+        CommissionOutOfBounds,
+        PendingCommissionChangeAlreadyExists,
+        PendingCommissionChangeDNE,
+        PendingCommissionChangeNotDueYet,
+        InvalidInflationCurve,
+        CollatorSelectionMigrationInProgress,
+        EquivocationSlashingDisabled,
+        CollatorSnapshotDNE,
+        EquivocationAlreadyReported,
     }
 
     #[pallet::event]
@@ -430,6 +487,85 @@ pub mod pallet {
             new_per_round_inflation_ideal: Perbill,
             new_per_round_inflation_max: Perbill,
         },
+        /// Auto-compounding reward percent was set for a delegation.
+        AutoCompoundSet {
+            candidate: T::AccountId,
+            delegator: T::AccountId,
+            value: Percent,
+        },
+        /// Compounded a portion of rewards towards a delegation.
+        Compounded {
+            candidate: T::AccountId,
+            delegator: T::AccountId,
+            amount: BalanceOf<T>,
+        },
+        /// Slashing configuration set.
+        SlashingConfigSet {
+            old: SlashingConfig,
+            new: SlashingConfig,
+        },
+        /// Slash of an underperforming or equivocating collator was scheduled.
+        SlashDeferred {
+            collator: T::AccountId,
+            apply_round: RoundIndex,
+            fraction: Perbill,
+        },
+        /// Deferred slash was cancelled.
+        SlashCancelled {
+            collator: T::AccountId,
+            apply_round: RoundIndex,
+        },
+        /// Account was slashed.
+        Slashed {
+            account: T::AccountId,
+            amount: BalanceOf<T>,
+        },
+        /// Delegator moved bonded stake from one candidate to another.
+        Redelegated {
+            delegator: T::AccountId,
+            from: T::AccountId,
+            to: T::AccountId,
+            amount: BalanceOf<T>,
+        },
+        /// Set the bounds of candidate commissions.
+        CommissionBoundsSet { min: Perbill, max: Perbill },
+        /// Candidate scheduled a change of its commission.
+        CommissionChangeScheduled {
+            candidate: T::AccountId,
+            commission: Perbill,
+            execute_round: RoundIndex,
+        },
+        /// Candidate changed its commission.
+        CommissionChanged {
+            candidate: T::AccountId,
+            old: Perbill,
+            new: Perbill,
+        },
+        /// Candidate cancelled a scheduled commission change.
+        CommissionChangeCancelled {
+            candidate: T::AccountId,
+            commission: Perbill,
+            execute_round: RoundIndex,
+        },
+        /// Inflation curve driven by the staked ratio was set, `None` if disabled.
+        InflationCurveSet {
+            old: Option<InflationCurve>,
+            new: Option<InflationCurve>,
+        },
+        /// Started moving the collators of `manta_collator_selection` into the candidate pool.
+        CollatorSelectionMigrationStarted { candidates: u32, invulnerables: u32 },
+        /// A `manta_collator_selection` collator could not join the candidate pool and was dropped.
+        CollatorNotMigrated {
+            account: T::AccountId,
+            error: DispatchError,
+        },
+        /// All `manta_collator_selection` collators were moved and its storage is drained.
+        CollatorSelectionMigrationCompleted,
+        /// Equivocation of a collator in a round was reported.
+        EquivocationReported {
+            collator: T::AccountId,
+            round: RoundIndex,
+        },
     }
 
     #[pallet::hooks]
@@ -439,10 +575,18 @@ pub mod pallet {
 
             let mut round = <Round<T>>::get();
             if round.should_update(n) {
+                let (ended_round, ended_round_length) = (round.current, round.length);
                 // mutate round
                 round.update(n);
                 // notify that new round begin
                 weight = weight.saturating_add(T::OnNewRound::on_new_round(round.current));
+                // schedule slashes for collators underperforming in the ended round
+                weight = weight.saturating_add(Self::check_collator_performance(
+                    ended_round,
+                    ended_round_length,
+                ));
+                // apply slashes whose deferral window is over
+                weight = weight.saturating_add(Self::apply_deferred_slashes(round.current));
                 // pay all stakers for T::RewardPaymentDelay rounds ago
                 Self::prepare_staking_payouts(round.current);
                 // select top collator candidates for next round
@@ -468,6 +612,8 @@ pub mod pallet {
 
             weight = weight.saturating_add(Self::handle_delayed_payouts(round.current));
 
+            weight = weight.saturating_add(Self::migrate_collator_selection_batch());
+
             // add on_finalize weight
             weight = weight.saturating_add(
                 // read Author, Points, AwardedPts
@@ -485,9 +631,20 @@ pub mod pallet {
 
     #[pallet::storage]
     #[pallet::getter(fn collator_commission)]
-    /// Commission percent taken off of rewards for all collators
+    /// Commission percent taken off of rewards by new candidates
     type CollatorCommission<T: Config> = StorageValue<_, Perbill, ValueQuery>;
 
+    #[pallet::type_value]
+    pub fn DefaultCommissionBounds() -> (Perbill, Perbill) {
+        (Perbill::zero(), Perbill::one())
+    }
+
+    #[pallet::storage]
+    #[pallet::getter(fn commission_bounds)]
+    /// Minimum and maximum commission of candidates
+    pub(crate) type CommissionBounds<T: Config> =
+        StorageValue<_, (Perbill, Perbill), ValueQuery, DefaultCommissionBounds>;
+
     #[pallet::storage]
     #[pallet::getter(fn total_selected)]
     /// The total candidates selected every round
@@ -532,6 +689,20 @@ pub mod pallet {
         ValueQuery,
     >;
 
+    /// Stores auto-compounding configuration per collator.
+    #[pallet::storage]
+    #[pallet::getter(fn auto_compounding_delegations)]
+    pub(crate) type AutoCompoundingDelegations<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        T::AccountId,
+        BoundedVec<
+            AutoCompoundConfig<T::AccountId>,
+            AddGet<T::MaxTopDelegationsPerCandidate, T::MaxBottomDelegationsPerCandidate>,
+        >,
+        ValueQuery,
+    >;
+
     #[pallet::storage]
     #[pallet::getter(fn top_delegations)]
     /// Top delegations for collator candidate
@@ -557,7 +728,7 @@ pub mod pallet {
     #[pallet::storage]
     #[pallet::getter(fn selected_candidates)]
     /// The collator candidates selected for the current round
-    type SelectedCandidates<T: Config> = StorageValue<_, Vec<T::AccountId>, ValueQuery>;
+    pub(crate) type SelectedCandidates<T: Config> = StorageValue<_, Vec<T::AccountId>, ValueQuery>;
 
     #[pallet::storage]
     #[pallet::getter(fn total)]
@@ -599,6 +770,11 @@ pub mod pallet {
     /// Inflation configuration
     pub type InflationConfig<T: Config> = StorageValue<_, InflationInfo<BalanceOf<T>>, ValueQuery>;
 
+    #[pallet::storage]
+    #[pallet::getter(fn inflation_curve)]
+    /// Inflation curve over the staked ratio, replaces the staking expectations if set
+    pub type InflationCurveConfig<T: Config> = StorageValue<_, InflationCurve, OptionQuery>;
+
     #[pallet::storage]
     #[pallet::getter(fn points)]
     /// Total points awarded to collators for block production in the round
@@ -617,6 +793,90 @@ pub mod pallet {
         ValueQuery,
     >;
 
+    #[pallet::storage]
+    #[pallet::getter(fn slashing_info)]
+    /// Slashing configuration for underperforming collators
+    pub(crate) type SlashingInfo<T: Config> = StorageValue<_, SlashingConfig, ValueQuery>;
+
+    #[pallet::storage]
+    #[pallet::getter(fn underperforming_rounds)]
+    /// Consecutive rounds each collator produced fewer blocks than required
+    pub(crate) type UnderperformingRounds<T: Config> =
+        StorageMap<_, Twox64Concat, T::AccountId, u32, ValueQuery>;
+
+    #[pallet::storage]
+    #[pallet::getter(fn unapplied_slashes)]
+    /// Slashes to be applied at the start of each round
+    pub type UnappliedSlashes<T: Config> = StorageMap<
+        _,
+        Twox64Concat,
+        RoundIndex,
+        Vec<UnappliedSlash<T::AccountId, BalanceOf<T>>>,
+        ValueQuery,
+    >;
+
+    #[pallet::storage]
+    #[pallet::getter(fn reported_equivocations)]
+    /// Collators reported for equivocating in a round, each is slashed at most once per round
+    pub(crate) type ReportedEquivocations<T: Config> =
+        StorageDoubleMap<_, Twox64Concat, RoundIndex, Twox64Concat, T::AccountId, (), OptionQuery>;
+
+    #[pallet::storage]
+    #[pallet::getter(fn last_redelegation)]
+    /// Round of the last redelegation of each delegator
+    pub(crate) type LastRedelegation<T: Config> =
+        StorageMap<_, Twox64Concat, T::AccountId, RoundIndex, OptionQuery>;
+
+    #[pallet::storage]
+    #[pallet::getter(fn reward_per_share)]
+    /// Cumulative delegator reward per staked unit of each collator
+    pub type RewardPerShare<T: Config> =
+        StorageMap<_, Twox64Concat, T::AccountId, FixedU128, ValueQuery>;
+
+    #[pallet::storage]
+    #[pallet::getter(fn reward_boundary)]
+    /// `RewardPerShare` of a collator once all payouts up to a round are made, kept while
+    /// delegation checkpoints wait for it
+    pub type RewardBoundaries<T: Config> = StorageDoubleMap<
+        _,
+        Twox64Concat,
+        T::AccountId,
+        Twox64Concat,
+        RoundIndex,
+        RewardBoundary,
+        OptionQuery,
+    >;
+
+    #[pallet::storage]
+    #[pallet::getter(fn unresolved_reward_boundaries)]
+    /// Rounds of the boundaries of a collator which are not paid out yet
+    pub type UnresolvedRewardBoundaries<T: Config> =
+        StorageMap<_, Twox64Concat, T::AccountId, Vec<RoundIndex>, ValueQuery>;
+
+    #[pallet::storage]
+    #[pallet::getter(fn reward_checkpoint)]
+    /// Checkpoint of the rewards of a delegation, by delegator and collator
+    pub type RewardCheckpoints<T: Config> = StorageDoubleMap<
+        _,
+        Twox64Concat,
+        T::AccountId,
+        Twox64Concat,
+        T::AccountId,
+        RewardCheckpoint<BalanceOf<T>>,
+        OptionQuery,
+    >;
+
+    #[pallet::storage]
+    #[pallet::getter(fn unclaimed_rewards)]
+    /// Rewards settled on bond changes which are not claimed yet
+    pub type UnclaimedRewards<T: Config> =
+        StorageMap<_, Twox64Concat, T::AccountId, BalanceOf<T>, ValueQuery>;
+
+    #[pallet::storage]
+    #[pallet::getter(fn collator_selection_migration)]
+    /// Whether the collators of `manta_collator_selection` are being moved into the candidate pool
+    pub type CollatorSelectionMigration<T: Config> = StorageValue<_, bool, ValueQuery>;
+
     #[pallet::genesis_config]
     pub struct GenesisConfig<T: Config> {
         pub candidates: Vec<(T::AccountId, BalanceOf<T>)>,
@@ -640,6 +900,8 @@ pub mod pallet {
     impl<T: Config> GenesisBuild<T> for GenesisConfig<T> {
         fn build(&self) {
             <InflationConfig<T>>::put(self.inflation_config.clone());
+            // Set collator commission to default config, candidates start with it
+            <CollatorCommission<T>>::put(T::DefaultCollatorCommission::get());
             let mut candidate_count = 0u32;
             // Initialize the candidates
             for &(ref candidate, balance) in &self.candidates {
@@ -697,8 +959,6 @@ pub mod pallet {
                     };
                 }
             }
-            // Set collator commission to default config
-            <CollatorCommission<T>>::put(T::DefaultCollatorCommission::get());
             // Set parachain bond config to default config
             <ParachainBondInfo<T>>::put(ParachainBondConfig {
                 // must be set soon; if not => due inflation will be sent to collators/delegators
@@ -730,7 +990,7 @@ pub mod pallet {
         #[pallet::call_index(1)]
         #[pallet::weight(<T as Config>::WeightInfo::set_staking_expectations())]
         /// Set the expectations for total staked. These expectations determine the issuance for
-        /// the round according to logic in `fn compute_issuance`
+        /// the round according to logic in `fn compute_issuance`, unless an inflation curve is set
         pub fn set_staking_expectations(
             origin: OriginFor<T>,
             expectations: Range<BalanceOf<T>>,
@@ -840,7 +1100,7 @@ pub mod pallet {
         }
         #[pallet::call_index(6)]
         #[pallet::weight(<T as Config>::WeightInfo::set_collator_commission())]
-        /// Set the commission for all collators
+        /// Set the commission new candidates start with
         pub fn set_collator_commission(
             origin: OriginFor<T>,
             #[pallet::compact] new: Perbill,
@@ -931,39 +1191,11 @@ pub mod pallet {
                     Error::<T>::CandidateBondBelowMin
                 );
             }
-            let mut candidates = <CandidatePool<T>>::get();
-            let old_count = candidates.0.len() as u32;
             ensure!(
-                candidate_count >= old_count,
+                candidate_count >= <CandidatePool<T>>::get().0.len() as u32,
                 Error::<T>::TooLowCandidateCountWeightHintJoinCandidates
             );
-            ensure!(
-                candidates.insert(Bond {
-                    owner: acc.clone(),
-                    amount: bond
-                }),
-                Error::<T>::CandidateExists
-            );
-            ensure!(
-                Self::get_collator_stakable_free_balance(&acc) >= bond,
-                Error::<T>::InsufficientBalance,
-            );
-            <T as Config>::Currency::set_lock(COLLATOR_LOCK_ID, &acc, bond, WithdrawReasons::all());
-            let candidate = CandidateMetadata::new(bond);
-            <CandidateInfo<T>>::insert(&acc, candidate);
-            let empty_delegations: Delegations<T::AccountId, BalanceOf<T>> = Default::default();
-            // insert empty top delegations
-            <TopDelegations<T>>::insert(&acc, empty_delegations.clone());
-            // insert empty bottom delegations
-            <BottomDelegations<T>>::insert(&acc, empty_delegations);
-            <CandidatePool<T>>::put(candidates);
-            let new_total = <Total<T>>::get().saturating_add(bond);
-            <Total<T>>::put(new_total);
-            Self::deposit_event(Event::JoinedCollatorCandidates {
-                account: acc,
-                amount_locked: bond,
-                new_total_amt_locked: new_total,
-            });
+            Self::add_candidate(acc, bond)?;
             Ok(().into())
         }
         #[pallet::call_index(9)]
@@ -1011,6 +1243,7 @@ pub mod pallet {
             );
             state.can_leave::<T>()?;
             let return_stake = |bond: Bond<T::AccountId, BalanceOf<T>>| -> DispatchResult {
+                Self::end_delegation_rewards(&candidate, &bond.owner, bond.amount);
                 // remove delegation from delegator state
                 let mut delegator =
                     DelegatorState::<T>::get(&bond.owner).ok_or(Error::<T>::InvalidState)?;
@@ -1027,6 +1260,7 @@ pub mod pallet {
                         // since it is assumed that they were removed incrementally before only the
                         // last delegation was left.
                         <DelegatorState<T>>::remove(&bond.owner);
+                        <LastRedelegation<T>>::remove(&bond.owner);
                         <T as Config>::Currency::remove_lock(DELEGATOR_LOCK_ID, &bond.owner);
                     } else {
                         <DelegatorState<T>>::insert(&bond.owner, delegator);
@@ -1041,8 +1275,9 @@ pub mod pallet {
             // total backing stake is at least the candidate self bond
             let mut total_backing = state.bond;
             // return all top delegations
+            // top delegations stay stored until all stakes are returned to settle their rewards
             let top_delegations =
-                <TopDelegations<T>>::take(&candidate).ok_or(Error::<T>::InvalidState)?;
+                <TopDelegations<T>>::get(&candidate).ok_or(Error::<T>::InvalidState)?;
             for bond in top_delegations.delegations {
                 return_stake(bond)?;
             }
@@ -1058,6 +1293,8 @@ pub mod pallet {
             <T as Config>::Currency::remove_lock(COLLATOR_LOCK_ID, &candidate);
             <CandidateInfo<T>>::remove(&candidate);
             <DelegationScheduledRequests<T>>::remove(&candidate);
+            <AutoCompoundingDelegations<T>>::remove(&candidate);
+            <UnderperformingRounds<T>>::remove(&candidate);
             <TopDelegations<T>>::remove(&candidate);
             <BottomDelegations<T>>::remove(&candidate);
             let new_total_staked = <Total<T>>::get().saturating_sub(total_backing);
@@ -1127,6 +1364,11 @@ pub mod pallet {
             let mut state = <CandidateInfo<T>>::get(&collator).ok_or(Error::<T>::CandidateDNE)?;
             ensure!(!state.is_active(), Error::<T>::AlreadyActive);
             ensure!(!state.is_leaving(), Error::<T>::CannotGoOnlineIfLeaving);
+            // a candidate slashed below the minimum bond must bond more first
+            ensure!(
+                state.bond >= T::MinCandidateStk::get(),
+                Error::<T>::CandidateBondBelowMin
+            );
             state.go_online();
             let mut candidates = <CandidatePool<T>>::get();
             ensure!(
@@ -1375,18 +1617,354 @@ pub mod pallet {
             let delegator = ensure_signed(origin)?;
             Self::delegation_cancel_request(candidate, delegator)
         }
+        #[pallet::call_index(27)]
+        #[pallet::weight(
+            <T as Config>::WeightInfo::delegate_with_auto_compound(
+                *candidate_delegation_count,
+                *candidate_auto_compounding_delegation_count,
+                *delegation_count,
+            )
+        )]
+        /// Delegate to a collator candidate and set the percent of rewards to be
+        /// auto-compounded into the delegation.
+        pub fn delegate_with_auto_compound(
+            origin: OriginFor<T>,
+            candidate: T::AccountId,
+            #[pallet::compact] amount: BalanceOf<T>,
+            auto_compound: Percent,
+            #[pallet::compact] candidate_delegation_count: u32,
+            #[pallet::compact] candidate_auto_compounding_delegation_count: u32,
+            #[pallet::compact] delegation_count: u32,
+        ) -> DispatchResultWithPostInfo {
+            let delegator = ensure_signed(origin.clone())?;
+            ensure!(
+                <AutoCompoundingDelegations<T>>::decode_len(&candidate).unwrap_or_default()
+                    <= candidate_auto_compounding_delegation_count as usize,
+                Error::<T>::TooLowCandidateAutoCompoundingDelegationCountToDelegate
+            );
+            Self::delegate(
+                origin,
+                candidate.clone(),
+                amount,
+                candidate_delegation_count,
+                delegation_count,
+            )?;
+            if !auto_compound.is_zero() {
+                Self::delegation_set_auto_compound(
+                    candidate,
+                    delegator,
+                    auto_compound,
+                    candidate_auto_compounding_delegation_count,
+                    delegation_count.saturating_add(1),
+                )?;
+            }
+            Ok(().into())
+        }
+        #[pallet::call_index(28)]
+        #[pallet::weight(
+            <T as Config>::WeightInfo::set_auto_compound(
+                *candidate_auto_compounding_delegation_count_hint,
+                *delegation_count_hint,
+            )
+        )]
+        /// Set the percent of rewards auto-compounded into an existing delegation.
+        /// Setting zero disables auto-compounding for the delegation.
+        pub fn set_auto_compound(
+            origin: OriginFor<T>,
+            candidate: T::AccountId,
+            value: Percent,
+            #[pallet::compact] candidate_auto_compounding_delegation_count_hint: u32,
+            #[pallet::compact] delegation_count_hint: u32,
+        ) -> DispatchResultWithPostInfo {
+            let delegator = ensure_signed(origin)?;
+            Self::delegation_set_auto_compound(
+                candidate,
+                delegator,
+                value,
+                candidate_auto_compounding_delegation_count_hint,
+                delegation_count_hint,
+            )
+        }
+        #[pallet::call_index(29)]
+        #[pallet::weight(<T as Config>::WeightInfo::set_slashing_config())]
+        /// Set the slashing configuration for underperforming collators
+        pub fn set_slashing_config(
+            origin: OriginFor<T>,
+            new: SlashingConfig,
+        ) -> DispatchResultWithPostInfo {
+            T::MonetaryGovernanceOrigin::ensure_origin(origin)?;
+            ensure!(
+                new.slash_fraction < Perbill::one()
+                    && new.equivocation_slash_fraction < Perbill::one(),
+                Error::<T>::InvalidSlashFraction
+            );
+            let old = <SlashingInfo<T>>::get();
+            ensure!(old != new, Error::<T>::NoWritingSameValue);
+            <SlashingInfo<T>>::put(new);
+            Self::deposit_event(Event::SlashingConfigSet { old, new });
+            Ok(().into())
+        }
+        #[pallet::call_index(30)]
+        #[pallet::weight(<T as Config>::WeightInfo::cancel_deferred_slash(slash_indices.len() as u32))]
+        /// Cancel slashes deferred until `apply_round`, indices must be sorted and unique
+        pub fn cancel_deferred_slash(
+            origin: OriginFor<T>,
+            apply_round: RoundIndex,
+            slash_indices: Vec<u32>,
+        ) -> DispatchResultWithPostInfo {
+            T::MonetaryGovernanceOrigin::ensure_origin(origin)?;
+            ensure!(!slash_indices.is_empty(), Error::<T>::EmptySlashIndices);
+            ensure!(
+                slash_indices.windows(2).all(|pair| pair[0] < pair[1]),
+                Error::<T>::NotSortedAndUnique
+            );
+            let mut slashes = <UnappliedSlashes<T>>::get(apply_round);
+            let last = slash_indices.len() - 1;
+            ensure!(
+                (slash_indices[last] as usize) < slashes.len(),
+                Error::<T>::InvalidSlashIndex
+            );
+            for index in slash_indices.into_iter().rev() {
+                let slash = slashes.remove(index as usize);
+                Self::deposit_event(Event::SlashCancelled {
+                    collator: slash.collator,
+                    apply_round,
+                });
+            }
+            if slashes.is_empty() {
+                <UnappliedSlashes<T>>::remove(apply_round);
+            } else {
+                <UnappliedSlashes<T>>::insert(apply_round, slashes);
+            }
+            Ok(().into())
+        }
+        #[pallet::call_index(31)]
+        #[pallet::weight(<T as Config>::WeightInfo::redelegate())]
+        /// Move bonded stake from the delegation towards `from` into a delegation towards `to`
+        /// without waiting for the revoke delay. Allowed once per `RedelegationCooldown` rounds.
+        pub fn redelegate(
+            origin: OriginFor<T>,
+            from: T::AccountId,
+            to: T::AccountId,
+            amount: BalanceOf<T>,
+        ) -> DispatchResultWithPostInfo {
+            let delegator = ensure_signed(origin)?;
+            Self::delegation_redelegate(delegator, from, to, amount)
+        }
+        #[pallet::call_index(32)]
+        #[pallet::weight(<T as Config>::WeightInfo::set_commission_bounds())]
+        /// Set the minimum and maximum commission of candidates. Commissions outside of the
+        /// bounds are clamped when the round snapshot is taken.
+        pub fn set_commission_bounds(
+            origin: OriginFor<T>,
+            min: Perbill,
+            max: Perbill,
+        ) -> DispatchResultWithPostInfo {
+            T::MonetaryGovernanceOrigin::ensure_origin(origin)?;
+            ensure!(min <= max, Error::<T>::InvalidCommissionBounds);
+            ensure!(
+                <CommissionBounds<T>>::get() != (min, max),
+                Error::<T>::NoWritingSameValue
+            );
+            <CommissionBounds<T>>::put((min, max));
+            Self::deposit_event(Event::CommissionBoundsSet { min, max });
+            Ok(().into())
+        }
+        #[pallet::call_index(33)]
+        #[pallet::weight(<T as Config>::WeightInfo::schedule_commission_change())]
+        /// Request to change the commission of the caller, executable after
+        /// `CommissionChangeDelay` rounds
+        pub fn schedule_commission_change(
+            origin: OriginFor<T>,
+            commission: Perbill,
+        ) -> DispatchResultWithPostInfo {
+            let collator = ensure_signed(origin)?;
+            let mut state = <CandidateInfo<T>>::get(&collator).ok_or(Error::<T>::CandidateDNE)?;
+            let when = state.schedule_commission_change::<T>(commission)?;
+            <CandidateInfo<T>>::insert(&collator, state);
+            Self::deposit_event(Event::CommissionChangeScheduled {
+                candidate: collator,
+                commission,
+                execute_round: when,
+            });
+            Ok(().into())
+        }
+        #[pallet::call_index(34)]
+        #[pallet::weight(<T as Config>::WeightInfo::execute_commission_change())]
+        /// Execute pending request to change the commission of a candidate
+        /// - permissionless
+        pub fn execute_commission_change(
+            origin: OriginFor<T>,
+            candidate: T::AccountId,
+        ) -> DispatchResultWithPostInfo {
+            ensure_signed(origin)?;
+            let mut state = <CandidateInfo<T>>::get(&candidate).ok_or(Error::<T>::CandidateDNE)?;
+            state.execute_commission_change::<T>(candidate.clone())?;
+            <CandidateInfo<T>>::insert(&candidate, state);
+            Ok(().into())
+        }
+        #[pallet::call_index(35)]
+        #[pallet::weight(<T as Config>::WeightInfo::cancel_commission_change())]
+        /// Cancel pending request to change the commission of the caller
+        pub fn cancel_commission_change(origin: OriginFor<T>) -> DispatchResultWithPostInfo {
+            let collator = ensure_signed(origin)?;
+            let mut state = <CandidateInfo<T>>::get(&collator).ok_or(Error::<T>::CandidateDNE)?;
+            state.cancel_commission_change::<T>(collator.clone())?;
+            <CandidateInfo<T>>::insert(&collator, state);
+            Ok(().into())
+        }
+        #[pallet::call_index(36)]
+        #[pallet::weight(<T as Config>::WeightInfo::set_inflation_curve())]
+        /// Set the inflation curve computing round issuance from the staked ratio,
+        /// `None` falls back to the staking expectations
+        pub fn set_inflation_curve(
+            origin: OriginFor<T>,
+            curve: Option<InflationCurve>,
+        ) -> DispatchResultWithPostInfo {
+            T::MonetaryGovernanceOrigin::ensure_origin(origin)?;
+            if let Some(curve) = curve {
+                ensure!(curve.is_valid(), Error::<T>::InvalidInflationCurve);
+            }
+            let old = <InflationCurveConfig<T>>::get();
+            ensure!(old != curve, Error::<T>::NoWritingSameValue);
+            <InflationCurveConfig<T>>::set(curve);
+            Self::deposit_event(Event::InflationCurveSet { old, new: curve });
+            Ok(().into())
+        }
+        #[pallet::call_index(37)]
+        #[pallet::weight(
+            <T as Config>::WeightInfo::claim_rewards(T::MaxDelegationsPerDelegator::get())
+                .saturating_add(
+                    <T as Config>::WeightInfo::mint_and_compound()
+                        .saturating_mul(T::MaxDelegationsPerDelegator::get().into())
+                )
+        )]
+        /// Claim the rewards accrued by all delegations of the caller, compounding them where
+        /// configured
+        pub fn claim_rewards(origin: OriginFor<T>) -> DispatchResultWithPostInfo {
+            let delegator = ensure_signed(origin)?;
+            Self::do_claim_rewards(&delegator)
+        }
+        #[pallet::call_index(38)]
+        #[pallet::weight(<T as Config>::WeightInfo::start_collator_selection_migration())]
+        /// Start moving the candidates and invulnerables of `manta_collator_selection` into the
+        /// candidate pool with the whitelisted minimum bond, a batch per block, draining its
+        /// storage once done
+        pub fn start_collator_selection_migration(
+            origin: OriginFor<T>,
+        ) -> DispatchResultWithPostInfo {
+            frame_system::ensure_root(origin)?;
+            ensure!(
+                !<CollatorSelectionMigration<T>>::get(),
+                Error::<T>::CollatorSelectionMigrationInProgress
+            );
+            <CollatorSelectionMigration<T>>::put(true);
+            Self::deposit_event(Event::CollatorSelectionMigrationStarted {
+                candidates: manta_collator_selection::Pallet::<T>::candidates().len() as u32,
+                invulnerables: manta_collator_selection::Pallet::<T>::invulnerables().len() as u32,
+            });
+            Ok(().into())
+        }
+        #[pallet::call_index(39)]
+        #[pallet::weight(<T as Config>::WeightInfo::report_equivocation())]
+        /// Schedule a slash of `collator` for equivocating in `round`, as long as the stake of
+        /// the round is still recorded
+        pub fn report_equivocation(
+            origin: OriginFor<T>,
+            collator: T::AccountId,
+            round: RoundIndex,
+        ) -> DispatchResultWithPostInfo {
+            T::MonetaryGovernanceOrigin::ensure_origin(origin)?;
+            Self::report_collator_equivocation(collator, round)?;
+            Ok(().into())
+        }
+        #[pallet::call_index(40)]
+        #[pallet::weight(
+            <T as Config>::WeightInfo::claim_rewards(T::MaxDelegationsPerDelegator::get())
+                .saturating_add(
+                    <T as Config>::WeightInfo::mint_and_compound()
+                        .saturating_mul(T::MaxDelegationsPerDelegator::get().into())
+                )
+        )]
+        /// Claim the rewards accrued by all delegations of `delegator` on their behalf, so that
+        /// anyone can compound the rewards of auto-compounding delegations
+        pub fn claim_rewards_for(
+            origin: OriginFor<T>,
+            delegator: T::AccountId,
+        ) -> DispatchResultWithPostInfo {
+            ensure_signed(origin)?;
+            Self::do_claim_rewards(&delegator)
+        }
     }
 
     impl<T: Config> Pallet<T> {
+        fn do_claim_rewards(delegator: &T::AccountId) -> DispatchResultWithPostInfo {
+            ensure!(
+                <DelegatorState<T>>::contains_key(delegator)
+                    || <UnclaimedRewards<T>>::contains_key(delegator)
+                    || <RewardCheckpoints<T>>::iter_prefix(delegator)
+                        .next()
+                        .is_some(),
+                Error::<T>::DelegatorDNE
+            );
+            Self::claim_delegator_rewards(delegator);
+            Ok(().into())
+        }
         pub fn is_delegator(acc: &T::AccountId) -> bool {
             <DelegatorState<T>>::get(acc).is_some()
         }
         pub fn is_candidate(acc: &T::AccountId) -> bool {
             <CandidateInfo<T>>::get(acc).is_some()
         }
+        /// Locks `bond` of `acc` and adds it to the candidate pool, the bond is not checked
+        /// against any minimum
+        pub(crate) fn add_candidate(acc: T::AccountId, bond: BalanceOf<T>) -> DispatchResult {
+            let mut candidates = <CandidatePool<T>>::get();
+            ensure!(
+                candidates.insert(Bond {
+                    owner: acc.clone(),
+                    amount: bond
+                }),
+                Error::<T>::CandidateExists
+            );
+            ensure!(
+                Self::get_collator_stakable_free_balance(&acc) >= bond,
+                Error::<T>::InsufficientBalance,
+            );
+            <T as Config>::Currency::set_lock(COLLATOR_LOCK_ID, &acc, bond, WithdrawReasons::all());
+            let candidate = CandidateMetadata::new(
+                bond,
+                Self::bounded_commission(<CollatorCommission<T>>::get()),
+            );
+            <CandidateInfo<T>>::insert(&acc, candidate);
+            let empty_delegations: Delegations<T::AccountId, BalanceOf<T>> = Default::default();
+            // insert empty top delegations
+            <TopDelegations<T>>::insert(&acc, empty_delegations.clone());
+            // insert empty bottom delegations
+            <BottomDelegations<T>>::insert(&acc, empty_delegations);
+            <CandidatePool<T>>::put(candidates);
+            let new_total = <Total<T>>::get().saturating_add(bond);
+            <Total<T>>::put(new_total);
+            Self::deposit_event(Event::JoinedCollatorCandidates {
+                account: acc,
+                amount_locked: bond,
+                new_total_amt_locked: new_total,
+            });
+            Ok(())
+        }
         pub fn is_selected_candidate(acc: &T::AccountId) -> bool {
             <SelectedCandidates<T>>::get().binary_search(acc).is_ok()
         }
+        /// Whether `commission` is within the governance set bounds
+        pub fn commission_within_bounds(commission: Perbill) -> bool {
+            let (min, max) = <CommissionBounds<T>>::get();
+            min <= commission && commission <= max
+        }
+        /// Clamps `commission` into the governance set bounds
+        pub(crate) fn bounded_commission(commission: Perbill) -> Perbill {
+            let (min, max) = <CommissionBounds<T>>::get();
+            commission.max(min).min(max)
+        }
         /// Returns an account's free balance which is not locked in delegation staking
         pub fn get_delegator_stakable_free_balance(acc: &T::AccountId) -> BalanceOf<T> {
             let mut balance = <T as Config>::Currency::free_balance(acc);
@@ -1429,6 +2007,8 @@ pub mod pallet {
             );
 
             <InflationConfig<T>>::put(inflation);
+            // Set collator commission to default config, candidates start with it
+            <CollatorCommission<T>>::put(T::DefaultCollatorCommission::get());
 
             let whitelist_bond = T::MinWhitelistCandidateStk::get();
 
@@ -1451,8 +2031,6 @@ pub mod pallet {
             }
             log::info!("Added {} initial candidates", candidate_count);
 
-            // Set collator commission to default config
-            <CollatorCommission<T>>::put(T::DefaultCollatorCommission::get());
             // Set parachain bond config to default config
             <ParachainBondInfo<T>>::put(ParachainBondConfig {
                 // must be set soon; if not => due inflation will be sent to collators/delegators
@@ -1489,9 +2067,15 @@ pub mod pallet {
             });
             <CandidatePool<T>>::put(candidates);
         }
-        /// Compute round issuance based on total staked for the given round
+        /// Compute round issuance based on total staked for the given round, following the
+        /// inflation curve if one is set
         fn compute_issuance(staked: BalanceOf<T>) -> BalanceOf<T> {
             let config = <InflationConfig<T>>::get();
+            if let Some(curve) = <InflationCurveConfig<T>>::get() {
+                let circulating = <T as Config>::Currency::total_issuance();
+                let staked_ratio = Perbill::from_rational(staked, circulating);
+                return curve.round_inflation(config.round, staked_ratio) * circulating;
+            }
             let round_issuance = crate::inflation::round_issuance_range::<T>(config.round);
             // TODO: consider interpolation instead of bounded range
             if staked < config.expect.min {
@@ -1511,6 +2095,7 @@ pub mod pallet {
         ) -> DispatchResult {
             let mut state = <CandidateInfo<T>>::get(&candidate).ok_or(Error::<T>::CandidateDNE)?;
             state.rm_delegation_if_exists::<T>(&candidate, delegator.clone(), amount)?;
+            Self::delegation_remove_auto_compound(&candidate, &delegator);
             let new_total_locked = <Total<T>>::get().saturating_sub(amount);
             <Total<T>>::put(new_total_locked);
             let new_total = state.total_counted;
@@ -1621,9 +2206,6 @@ pub mod pallet {
                 }
             };
 
-            let collator_fee = payout_info.collator_commission;
-            let collator_issuance = collator_fee * payout_info.round_issuance;
-
             if let Some((collator, pts)) =
                 <AwardedPts<T>>::iter_prefix(paid_for_round).drain().next()
             {
@@ -1633,7 +2215,9 @@ pub mod pallet {
                 let mut amt_due = total_paid;
                 // Take the snapshot of block author and delegations
                 let state = <AtStake<T>>::take(paid_for_round, &collator);
-                let num_delegators = state.delegations.len();
+                // equivocations can no longer be reported without the snapshot
+                <ReportedEquivocations<T>>::remove(paid_for_round, &collator);
+                extra_weight += T::DbWeight::get().writes(1);
                 if state.delegations.is_empty() {
                     // solo collator with no delegators
                     mint(amt_due, collator.clone());
@@ -1645,7 +2229,7 @@ pub mod pallet {
                 } else {
                     // pay collator first; commission + due_portion
                     let collator_pct = Perbill::from_rational(state.bond, state.total);
-                    let commission = pct_due * collator_issuance;
+                    let commission = pct_due * (state.commission * payout_info.round_issuance);
                     amt_due = amt_due.saturating_sub(commission);
                     let collator_reward = (collator_pct * amt_due).saturating_add(commission);
                     mint(collator_reward, collator.clone());
@@ -1654,20 +2238,20 @@ pub mod pallet {
                         collator.clone(),
                         collator_reward,
                     );
-                    // pay delegators due portion
-                    for Bond { owner, amount } in state.delegations {
-                        let percent = Perbill::from_rational(amount, state.total);
-                        let due = percent * amt_due;
-                        if !due.is_zero() {
-                            mint(due, owner.clone());
-                        }
-                    }
+                    // delegators claim their due portion later, keeping the weight independent of
+                    // the number of delegations
+                    Self::accrue_delegation_rewards(
+                        &collator,
+                        paid_for_round,
+                        &state.delegations,
+                        state.total,
+                        amt_due,
+                    );
                 }
 
                 (
                     Some((collator, total_paid)),
-                    <T as Config>::WeightInfo::pay_one_collator_reward(num_delegators as u32)
-                        + extra_weight,
+                    <T as Config>::WeightInfo::pay_one_collator_reward() + extra_weight,
                 )
             } else {
                 // Note that we don't clean up storage here; it is cleaned up in
@@ -1703,6 +2287,88 @@ pub mod pallet {
             collators.sort();
             collators
         }
+        /// Expected annual return of a delegation towards `candidate` under the current
+        /// `InflationConfig`, assuming blocks are evenly split among the selected candidates.
+        /// Returns zero if `candidate` is offline, leaving or not selected, and `None` if it is
+        /// not a candidate.
+        pub fn candidate_expected_apy(candidate: &T::AccountId) -> Option<FixedU128> {
+            let state = <CandidateInfo<T>>::get(candidate)?;
+            let selected = <SelectedCandidates<T>>::get();
+            if !state.is_active()
+                || selected.binary_search(candidate).is_err()
+                || state.total_counted.is_zero()
+            {
+                return Some(FixedU128::zero());
+            }
+            let selected = BalanceOf::<T>::from(selected.len() as u32);
+            let round_issuance = Self::compute_issuance(<Total<T>>::get());
+            let staking_reward = round_issuance
+                .saturating_sub(<ParachainBondInfo<T>>::get().percent * round_issuance);
+            let collator_reward = staking_reward / selected;
+            let commission = Self::bounded_commission(state.commission) * round_issuance / selected;
+            let delegators_reward =
+                collator_reward
+                    .saturating_sub(commission)
+                    .saturating_mul(BalanceOf::<T>::from(
+                        crate::inflation::rounds_per_year::<T>(),
+                    ));
+            Some(FixedU128::saturating_from_rational(
+                delegators_reward,
+                state.total_counted,
+            ))
+        }
+        /// Rewards owed to `delegator` for all rounds whose payouts are not yet complete.
+        /// Rounds which have not been prepared for payout yet are estimated from their staked
+        /// total.
+        pub fn delegator_pending_rewards(delegator: &T::AccountId) -> BalanceOf<T> {
+            let now = <Round<T>>::get().current;
+            let first_round = now.saturating_sub(T::RewardPaymentDelay::get()).max(1);
+            let mut pending = Self::accrued_delegator_rewards(delegator);
+            for round in first_round..now {
+                let total_points = <Points<T>>::get(round);
+                if total_points.is_zero() {
+                    continue;
+                }
+                let payout_info = match <DelayedPayouts<T>>::get(round) {
+                    Some(payout_info) => payout_info,
+                    None => Self::expected_payout(<Staked<T>>::get(round)),
+                };
+                for (collator, pts) in <AwardedPts<T>>::iter_prefix(round) {
+                    let state = <AtStake<T>>::get(round, &collator);
+                    let amount = match state.delegations.iter().find(|b| &b.owner == delegator) {
+                        Some(bond) => bond.amount,
+                        None => continue,
+                    };
+                    let pct_due = Perbill::from_rational(pts, total_points);
+                    let amt_due = (pct_due * payout_info.total_staking_reward)
+                        .saturating_sub(pct_due * (state.commission * payout_info.round_issuance));
+                    let due = Perbill::from_rational(amount, state.total) * amt_due;
+                    pending = pending.saturating_add(due);
+                }
+            }
+            pending
+        }
+        /// Payout which `prepare_staking_payouts` would record for a round with `staked` total
+        fn expected_payout(staked: BalanceOf<T>) -> DelayedPayout<BalanceOf<T>> {
+            let round_issuance = Self::compute_issuance(staked);
+            let bond_config = <ParachainBondInfo<T>>::get();
+            // the reserve is only taken if the bond account can receive it
+            let parachain_bond_reserve =
+                if <<T as Config>::Currency as Currency<T::AccountId>>::total_balance(
+                    &bond_config.account,
+                )
+                .is_zero()
+                {
+                    BalanceOf::<T>::zero()
+                } else {
+                    bond_config.percent * round_issuance
+                };
+            DelayedPayout {
+                round_issuance,
+                total_staking_reward: round_issuance.saturating_sub(parachain_bond_reserve),
+                collator_commission: <CollatorCommission<T>>::get(),
+            }
+        }
         /// Best as in most cumulatively supported in terms of stake
         /// Returns [collator_count, delegation_count, total staked]
         fn select_top_candidates(now: RoundIndex) -> (u32, u32, BalanceOf<T>) {
@@ -1756,6 +2422,7 @@ pub mod pallet {
                     bond: state.bond,
                     delegations: rewardable_delegations,
                     total: total_counted,
+                    commission: Self::bounded_commission(state.commission),
                 };
                 <AtStake<T>>::insert(now, account, snapshot);
                 Self::deposit_event(Event::CollatorChosen {
@@ -25,12 +25,23 @@
 use crate::types::deprecated::{DelegationChange, Delegator as OldDelegator};
 use crate::{
     delegation_requests::{DelegationAction, ScheduledRequest},
-    pallet::{DelegationScheduledRequests, DelegatorState, Total},
+    pallet::{
+        DelegationScheduledRequests, DelegatorState, RewardCheckpoints, RewardPerShare, RoundIndex,
+        Total, UnclaimedRewards,
+    },
+    rewards::RewardCheckpoint,
     types::Delegator,
-    BalanceOf, Bond, BottomDelegations, CandidateInfo, CandidateMetadata, CapacityStatus,
-    CollatorCandidate, Config, Delegations, Event, Pallet, Points, Round, Staked, TopDelegations,
+    AtStake, BalanceOf, Bond, BottomDelegations, CandidateBondLessRequest, CandidateInfo,
+    CandidateMetadata, CapacityStatus, CollatorCandidate, CollatorSnapshot, CollatorStatus, Config,
+    Delegations, Event, Pallet, Points, Round, Staked, TopDelegations,
 };
-use frame_support::Twox64Concat;
+use codec::{Decode, Encode};
+use frame_support::{
+    traits::{GetStorageVersion, StorageVersion},
+    RuntimeDebug, Twox64Concat,
+};
+use scale_info::TypeInfo;
+use sp_runtime::DispatchError;
 extern crate alloc;
 #[cfg(feature = "try-runtime")]
 use alloc::format;
@@ -43,7 +54,253 @@ use frame_support::{
 #[cfg(feature = "try-runtime")]
 use scale_info::prelude::string::String;
 use sp_runtime::traits::{Saturating, Zero};
-use sp_std::{convert::TryInto, vec::Vec};
+use sp_std::{
+    collections::{btree_map::BTreeMap, btree_set::BTreeSet},
+    convert::TryInto,
+    vec::Vec,
+};
+
+/// Candidate metadata before the per-candidate commission was introduced
+#[derive(Encode, Decode, RuntimeDebug, TypeInfo)]
+pub struct CandidateMetadataV1<Balance> {
+    pub bond: Balance,
+    pub delegation_count: u32,
+    pub total_counted: Balance,
+    pub lowest_top_delegation_amount: Balance,
+    pub highest_bottom_delegation_amount: Balance,
+    pub lowest_bottom_delegation_amount: Balance,
+    pub top_capacity: CapacityStatus,
+    pub bottom_capacity: CapacityStatus,
+    pub request: Option<CandidateBondLessRequest<Balance>>,
+    pub status: CollatorStatus,
+}
+
+/// Collator snapshot before the per-candidate commission was introduced
+#[derive(Encode, Decode, RuntimeDebug, TypeInfo)]
+pub struct CollatorSnapshotV1<AccountId, Balance> {
+    pub bond: Balance,
+    pub delegations: Vec<Bond<AccountId, Balance>>,
+    pub total: Balance,
+}
+
+/// Storage migration adding `commission` to `CandidateInfo` and `AtStake`.
+///
+/// Every candidate and every pending round snapshot starts with the global `CollatorCommission`,
+/// so payouts are unchanged until candidates change their commission.
+pub struct CandidateCommissionMigration<T>(PhantomData<T>);
+impl<T: Config> OnRuntimeUpgrade for CandidateCommissionMigration<T> {
+    fn on_runtime_upgrade() -> Weight {
+        // version 0 means the pallet is not initialized yet and starts with the latest layout
+        if Pallet::<T>::on_chain_storage_version() != 1 {
+            log::info!("✅ no commission migration for parachain staking.");
+            return T::DbWeight::get().reads(1);
+        }
+        log::info!("Start to execute commission migration for parachain staking.");
+        let commission = Pallet::<T>::collator_commission();
+        let mut reads: u64 = 2;
+        let mut writes: u64 = 0;
+        <CandidateInfo<T>>::translate::<CandidateMetadataV1<BalanceOf<T>>, _>(|_, old| {
+            reads += 1;
+            writes += 1;
+            Some(CandidateMetadata {
+                bond: old.bond,
+                delegation_count: old.delegation_count,
+                total_counted: old.total_counted,
+                lowest_top_delegation_amount: old.lowest_top_delegation_amount,
+                highest_bottom_delegation_amount: old.highest_bottom_delegation_amount,
+                lowest_bottom_delegation_amount: old.lowest_bottom_delegation_amount,
+                top_capacity: old.top_capacity,
+                bottom_capacity: old.bottom_capacity,
+                request: old.request,
+                status: old.status,
+                commission,
+                commission_request: None,
+            })
+        });
+        <AtStake<T>>::translate::<CollatorSnapshotV1<T::AccountId, BalanceOf<T>>, _>(
+            |_, _, old| {
+                reads += 1;
+                writes += 1;
+                Some(CollatorSnapshot {
+                    bond: old.bond,
+                    delegations: old.delegations,
+                    total: old.total,
+                    commission,
+                })
+            },
+        );
+        StorageVersion::new(2).put::<Pallet<T>>();
+        writes += 1;
+        T::DbWeight::get().reads_writes(reads, writes)
+    }
+
+    #[cfg(feature = "try-runtime")]
+    fn pre_upgrade() -> Result<Vec<u8>, DispatchError> {
+        let candidates = <CandidateInfo<T>>::iter_keys().count() as u32;
+        Ok(candidates.encode())
+    }
+
+    #[cfg(feature = "try-runtime")]
+    fn post_upgrade(state: Vec<u8>) -> Result<(), DispatchError> {
+        if Pallet::<T>::on_chain_storage_version() != 2 {
+            return Err(DispatchError::Other(
+                "Storage version is not 2, the migration wasn't executed.",
+            ));
+        }
+        let candidates_before = u32::decode(&mut &state[..])
+            .map_err(|_| DispatchError::Other("Failed to decode the pre-upgrade state."))?;
+        // decoding every entry checks the new layout
+        let candidates_after = <CandidateInfo<T>>::iter().count() as u32;
+        if candidates_before != candidates_after {
+            return Err(DispatchError::Other(
+                "Failed to migrate the commission of every candidate.",
+            ));
+        }
+        Ok(())
+    }
+}
+
+/// Storage migration switching delegator rewards from being paid with each collator payout to
+/// being claimed.
+///
+/// Delegations keep their layout and every accumulator starts at zero, so delegations accrue
+/// from the first lazy payout on, which includes the rounds whose payout is still pending. A
+/// delegation whose counted amount in one of those snapshots differs from its current rewardable
+/// amount gets a checkpoint waiting for the payouts of the snapshots.
+///
+/// The migration reads the snapshots of the rounds whose payout is pending and the top
+/// delegations of the collators in them, so its weight is bounded by the number of selected
+/// candidates and `MaxTopDelegationsPerCandidate` like a round payout, not by the number of
+/// delegators.
+pub struct LazyRewardsMigration<T>(PhantomData<T>);
+impl<T: Config> OnRuntimeUpgrade for LazyRewardsMigration<T> {
+    fn on_runtime_upgrade() -> Weight {
+        if Pallet::<T>::on_chain_storage_version() != 2 {
+            log::info!("✅ no lazy rewards migration for parachain staking.");
+            return T::DbWeight::get().reads(1);
+        }
+        log::info!("Start to execute lazy rewards migration for parachain staking.");
+        let (mut reads, mut writes) = (2u64, 1u64);
+        let now = <Round<T>>::get().current;
+        let first_round = now.saturating_sub(T::RewardPaymentDelay::get());
+        // rounds of each collator whose payout is pending and the amounts counted in them
+        let mut snapshot_rounds: BTreeMap<T::AccountId, Vec<RoundIndex>> = BTreeMap::new();
+        let mut counted: BTreeMap<
+            (T::AccountId, T::AccountId),
+            BTreeMap<RoundIndex, BalanceOf<T>>,
+        > = BTreeMap::new();
+        for round in first_round..=now {
+            for (collator, snapshot) in <AtStake<T>>::iter_prefix(round) {
+                reads += 1;
+                snapshot_rounds
+                    .entry(collator.clone())
+                    .or_default()
+                    .push(round);
+                for bond in snapshot.delegations {
+                    counted
+                        .entry((bond.owner, collator.clone()))
+                        .or_default()
+                        .insert(round, bond.amount);
+                }
+            }
+        }
+        // only top delegations are rewardable, so the delegations of the snapshotted collators
+        // are read instead of every delegator
+        let mut rewardable: BTreeMap<(T::AccountId, T::AccountId), BalanceOf<T>> = BTreeMap::new();
+        for collator in snapshot_rounds.keys() {
+            reads += 2;
+            let requests = <DelegationScheduledRequests<T>>::get(collator);
+            let top = match <TopDelegations<T>>::get(collator) {
+                Some(top) => top,
+                None => continue,
+            };
+            for bond in top.delegations {
+                let amount = match requests
+                    .iter()
+                    .find(|request| request.delegator == bond.owner)
+                    .map(|request| &request.action)
+                {
+                    Some(DelegationAction::Revoke(_)) => Zero::zero(),
+                    Some(DelegationAction::Decrease(amount)) => bond.amount.saturating_sub(*amount),
+                    None => bond.amount,
+                };
+                rewardable.insert((bond.owner, collator.clone()), amount);
+            }
+        }
+        let delegations: BTreeSet<(T::AccountId, T::AccountId)> =
+            counted.keys().chain(rewardable.keys()).cloned().collect();
+        for (delegator, collator) in delegations {
+            let rounds = match snapshot_rounds.get(&collator) {
+                Some(rounds) => rounds,
+                None => continue,
+            };
+            let current = rewardable
+                .get(&(delegator.clone(), collator.clone()))
+                .cloned()
+                .unwrap_or_else(Zero::zero);
+            let pending: Vec<(RoundIndex, BalanceOf<T>)> = rounds
+                .iter()
+                .map(|round| {
+                    let amount = counted
+                        .get(&(delegator.clone(), collator.clone()))
+                        .and_then(|amounts| amounts.get(round).cloned())
+                        .unwrap_or_else(Zero::zero);
+                    (*round, amount)
+                })
+                .collect();
+            // the current amount applies to all pending payouts unless it changed since
+            if pending.iter().all(|(_, amount)| *amount == current) {
+                continue;
+            }
+            for (round, _) in pending.iter() {
+                reads += 2;
+                writes += 2;
+                Pallet::<T>::add_reward_boundary(&collator, *round);
+            }
+            writes += 1;
+            <RewardCheckpoints<T>>::insert(
+                &delegator,
+                &collator,
+                RewardCheckpoint {
+                    per_share: Default::default(),
+                    pending,
+                },
+            );
+        }
+        StorageVersion::new(3).put::<Pallet<T>>();
+        T::DbWeight::get().reads_writes(reads, writes)
+    }
+
+    #[cfg(feature = "try-runtime")]
+    fn pre_upgrade() -> Result<Vec<u8>, DispatchError> {
+        let delegators = <DelegatorState<T>>::iter_keys().count() as u32;
+        Ok(delegators.encode())
+    }
+
+    #[cfg(feature = "try-runtime")]
+    fn post_upgrade(state: Vec<u8>) -> Result<(), DispatchError> {
+        if Pallet::<T>::on_chain_storage_version() != 3 {
+            return Err(DispatchError::Other(
+                "Storage version is not 3, the migration wasn't executed.",
+            ));
+        }
+        if <RewardPerShare<T>>::iter_keys().next().is_some()
+            || <UnclaimedRewards<T>>::iter_keys().next().is_some()
+        {
+            return Err(DispatchError::Other(
+                "Delegators must not have accrued rewards right after the migration.",
+            ));
+        }
+        let delegators_before = u32::decode(&mut &state[..])
+            .map_err(|_| DispatchError::Other("Failed to decode the pre-upgrade state."))?;
+        if delegators_before != <DelegatorState<T>>::iter_keys().count() as u32 {
+            return Err(DispatchError::Other(
+                "The migration must not change the set of delegators.",
+            ));
+        }
+        Ok(())
+    }
+}
 
 // HISTORIC MOONBEAM MIGRATIONS APPLIED BEFORE OUR FORK BELOW THIS LINE
 
@@ -485,6 +742,8 @@ impl<T: Config> OnRuntimeUpgrade for SplitCandidateStateToDecreasePoV<T> {
                 bottom_capacity,
                 request: state.request,
                 status: state.state,
+                commission: Pallet::<T>::collator_commission(),
+                commission_request: None,
             };
             <CandidateInfo<T>>::insert(&account, metadata);
             let top_delegations = Delegations {
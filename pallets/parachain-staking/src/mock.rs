@@ -20,7 +20,7 @@ use crate::{
     pallet, AwardedPts, Config, InflationInfo, Points, Range, COLLATOR_LOCK_ID, DELEGATOR_LOCK_ID,
 };
 use frame_support::{
-    construct_runtime, parameter_types,
+    assert_ok, construct_runtime, parameter_types,
     traits::{Everything, GenesisBuild, LockIdentifier, OnFinalize, OnInitialize},
 };
 use manta_primitives::types::{BlockNumber, Header};
@@ -153,6 +153,11 @@ impl Config for Test {
     type BlockAuthor = BlockAuthor;
     type OnCollatorPayout = ();
     type OnNewRound = ();
+    type OnSlash = ();
+    type SlashDeferDuration = ConstU32<2>;
+    type RedelegationCooldown = ConstU32<2>;
+    type CommissionChangeDelay = ConstU32<2>;
+    type MaxCollatorSelectionMigrationsPerBlock = ConstU32<2>;
     type WeightInfo = ();
 }
 
@@ -199,6 +204,7 @@ impl manta_collator_selection::Config for Test {
     type ValidatorRegistration = IsRegistered;
     type WeightInfo = ();
     type CanAuthor = ();
+    type MaxEvictionWindow = ConstU32<4>;
 }
 
 use sp_runtime::{traits::OpaqueKeys, RuntimeAppPublic};
@@ -513,6 +519,15 @@ pub(crate) fn set_author(round: u32, acc: u64, pts: u32) {
     <AwardedPts<Test>>::mutate(round, acc, |p| *p += pts);
 }
 
+/// Claims the accrued rewards of `delegators` in order
+pub(crate) fn claim_rewards(delegators: &[u64]) {
+    for delegator in delegators {
+        assert_ok!(ParachainStaking::claim_rewards(RuntimeOrigin::signed(
+            *delegator
+        )));
+    }
+}
+
 /// fn to query the lock amount
 pub(crate) fn query_lock_amount(account_id: u64, id: LockIdentifier) -> Option<Balance> {
     for lock in Balances::locks(&account_id) {
@@ -0,0 +1,143 @@
+// Copyright 2020-2024 Manta Network.
+// This file is part of Manta.
+//
+// Manta is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// Manta is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Manta.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Transfer of bonded stake between candidates without unbonding
+
+use crate::{
+    pallet::{
+        BalanceOf, CandidateInfo, Config, DelegatorState, Error, Event, LastRedelegation, Pallet,
+        Round, Total,
+    },
+    Bond,
+};
+use frame_support::{dispatch::DispatchResultWithPostInfo, ensure, traits::Get};
+use sp_runtime::traits::{Saturating, Zero};
+
+impl<T: Config> Pallet<T> {
+    /// Moves `amount` of the delegation towards `from` into a delegation towards `to`. The bond
+    /// lock is untouched since the delegator's total does not change.
+    pub(crate) fn delegation_redelegate(
+        delegator: T::AccountId,
+        from: T::AccountId,
+        to: T::AccountId,
+        amount: BalanceOf<T>,
+    ) -> DispatchResultWithPostInfo {
+        ensure!(from != to, <Error<T>>::CannotRedelegateToSameCandidate);
+        ensure!(!amount.is_zero(), <Error<T>>::DelegationBelowMin);
+        let mut state = <DelegatorState<T>>::get(&delegator).ok_or(<Error<T>>::DelegatorDNE)?;
+        let now = <Round<T>>::get().current;
+        if let Some(last) = <LastRedelegation<T>>::get(&delegator) {
+            ensure!(
+                now >= last.saturating_add(T::RedelegationCooldown::get()),
+                <Error<T>>::RedelegationCooldownNotElapsed
+            );
+        }
+        // pending requests are accounted against the source delegation
+        ensure!(
+            !Self::delegation_request_exists(&from, &delegator),
+            <Error<T>>::PendingDelegationRequestAlreadyExists
+        );
+        // a revoke of the target delegation is scheduled for its current amount
+        ensure!(
+            !Self::delegation_request_revoke_exists(&to, &delegator),
+            <Error<T>>::PendingDelegationRevoke
+        );
+        let from_bond = state
+            .get_bond_amount(&from)
+            .ok_or(<Error<T>>::DelegationDNE)?;
+        ensure!(amount <= from_bond, <Error<T>>::InsufficientBalance);
+        let remaining = from_bond.saturating_sub(amount);
+        ensure!(
+            remaining.is_zero() || remaining >= T::MinDelegation::get(),
+            <Error<T>>::DelegationBelowMin
+        );
+        let to_bond = state.get_bond_amount(&to);
+        if to_bond.is_none() {
+            ensure!(
+                amount >= T::MinDelegation::get(),
+                <Error<T>>::DelegationBelowMin
+            );
+            // a partial transfer adds a delegation
+            ensure!(
+                remaining.is_zero()
+                    || (state.delegations.0.len() as u32) < T::MaxDelegationsPerDelegator::get(),
+                <Error<T>>::ExceedMaxDelegationsPerDelegator
+            );
+        }
+        let mut to_state = <CandidateInfo<T>>::get(&to).ok_or(<Error<T>>::CandidateDNE)?;
+        let mut from_state = <CandidateInfo<T>>::get(&from).ok_or(<Error<T>>::CandidateDNE)?;
+
+        // leave the source candidate
+        if remaining.is_zero() {
+            from_state.rm_delegation_if_exists::<T>(&from, delegator.clone(), from_bond)?;
+            Self::delegation_remove_auto_compound(&from, &delegator);
+            state.delegations.0.retain(|bond| bond.owner != from);
+        } else {
+            from_state.decrease_delegation::<T>(&from, delegator.clone(), from_bond, amount)?;
+            for bond in &mut state.delegations.0 {
+                if bond.owner == from {
+                    bond.amount = remaining;
+                }
+            }
+        }
+
+        // join the target candidate, which may kick its lowest bottom delegation
+        let mut less_total_staked = BalanceOf::<T>::zero();
+        match to_bond {
+            Some(before) => {
+                let total_before = to_state.total_counted;
+                to_state.increase_delegation::<T>(&to, delegator.clone(), before, amount)?;
+                if to_state.is_active() && total_before != to_state.total_counted {
+                    Self::update_active(to.clone(), to_state.total_counted);
+                }
+                for bond in &mut state.delegations.0 {
+                    if bond.owner == to {
+                        bond.amount = bond.amount.saturating_add(amount);
+                    }
+                }
+            }
+            None => {
+                let (_, less) = to_state.add_delegation::<T>(
+                    &to,
+                    Bond {
+                        owner: delegator.clone(),
+                        amount,
+                    },
+                )?;
+                if let Some(less) = less {
+                    less_total_staked = less;
+                }
+                state.add_delegation(Bond {
+                    owner: to.clone(),
+                    amount,
+                });
+            }
+        }
+
+        <Total<T>>::mutate(|total| *total = total.saturating_sub(less_total_staked));
+        <CandidateInfo<T>>::insert(&from, from_state);
+        <CandidateInfo<T>>::insert(&to, to_state);
+        <DelegatorState<T>>::insert(&delegator, state);
+        <LastRedelegation<T>>::insert(&delegator, now);
+        Self::deposit_event(Event::Redelegated {
+            delegator,
+            from,
+            to,
+            amount,
+        });
+        Ok(().into())
+    }
+}
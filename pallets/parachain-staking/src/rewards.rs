@@ -0,0 +1,428 @@
+// Copyright 2020-2024 Manta Network.
+// This file is part of Manta.
+//
+// Manta is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// Manta is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Manta.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Pull-based delegator rewards
+//!
+//! Paying a collator for a round mints the share of its delegators into the reward pot in one
+//! deposit and adds the reward per counted unit of the round snapshot to the collator's
+//! `RewardPerShare` accumulator, so the payout does not touch any delegator storage.
+//!
+//! A delegation is owed its rewardable amount times the growth of the accumulator since its
+//! checkpoint. Round snapshots are paid `RewardPaymentDelay` rounds after they are taken, so a
+//! change of the rewardable amount during round `r` only applies to rounds after `r`. The amount
+//! before the change is kept in the checkpoint together with `r` until the accumulator is known
+//! once all payouts of rounds up to `r` are made. The first payout of a later round records that
+//! value as a [RewardBoundary] of the collator.
+
+use crate::{
+    pallet::{
+        BalanceOf, Config, DelayedPayouts, DelegationScheduledRequests, DelegatorState, Event,
+        Pallet, RewardBoundaries, RewardCheckpoints, RewardPerShare, Round, RoundIndex,
+        TopDelegations, UnclaimedRewards, UnresolvedRewardBoundaries, REWARD_POT_ID,
+    },
+    Bond, DelegationAction,
+};
+use codec::{Decode, Encode};
+use frame_support::{
+    traits::{Currency, ExistenceRequirement, Get},
+    RuntimeDebug,
+};
+use scale_info::TypeInfo;
+use sp_runtime::{
+    traits::{AccountIdConversion, One, Saturating, Zero},
+    DispatchError, FixedPointNumber, FixedU128,
+};
+use sp_std::vec::Vec;
+
+/// Rewards of a delegation collected up to `per_share` of the collator's accumulator
+#[derive(Clone, Default, Eq, PartialEq, Encode, Decode, RuntimeDebug, TypeInfo)]
+pub struct RewardCheckpoint<Balance> {
+    /// `RewardPerShare` of the collator up to which the rewards were collected
+    pub per_share: FixedU128,
+    /// Rewardable amount of the delegation for all rounds up to the given round, oldest first
+    pub pending: Vec<(RoundIndex, Balance)>,
+}
+
+/// `RewardPerShare` of a collator once all payouts up to a round are made
+#[derive(Clone, Default, Eq, PartialEq, Encode, Decode, RuntimeDebug, TypeInfo)]
+pub struct RewardBoundary {
+    /// Accumulator value, unknown until the payouts are made
+    pub per_share: Option<FixedU128>,
+    /// Number of checkpoints waiting for this boundary
+    pub refs: u32,
+}
+
+impl<T: Config> Pallet<T> {
+    /// Account holding the rewards of delegators until they are claimed
+    pub fn reward_pot() -> T::AccountId {
+        REWARD_POT_ID.into_account_truncating()
+    }
+
+    /// Credits the `delegations` of the snapshot of `collator` for `round` with `reward`, shared
+    /// pro rata over the snapshot `total`
+    pub(crate) fn accrue_delegation_rewards(
+        collator: &T::AccountId,
+        round: RoundIndex,
+        delegations: &[Bond<T::AccountId, BalanceOf<T>>],
+        total: BalanceOf<T>,
+        reward: BalanceOf<T>,
+    ) {
+        let per_share = <RewardPerShare<T>>::get(collator);
+        // all earlier rounds of the collator are paid out
+        <UnresolvedRewardBoundaries<T>>::mutate_exists(collator, |maybe_rounds| {
+            if let Some(rounds) = maybe_rounds {
+                rounds.retain(|boundary| {
+                    if *boundary >= round {
+                        return true;
+                    }
+                    Self::resolve_reward_boundary(collator, *boundary, per_share);
+                    false
+                });
+                if rounds.is_empty() {
+                    *maybe_rounds = None;
+                }
+            }
+        });
+        if reward.is_zero() || total.is_zero() {
+            return;
+        }
+        let reward_per_share = FixedU128::saturating_from_rational(reward, total);
+        // claims round the rewards of several rounds at once, rounding up the rewards of each
+        // round keeps the pot able to pay all of them
+        let due = delegations
+            .iter()
+            .fold(BalanceOf::<T>::zero(), |due, bond| {
+                due.saturating_add(Self::reward_of_rounded_up(reward_per_share, bond.amount))
+            });
+        if due.is_zero() {
+            return;
+        }
+        let pot = Self::reward_pot();
+        // the pot is endowed on its first deposit, a smaller deposit into it would be dropped
+        let endowment = if <T as Config>::Currency::total_balance(&pot).is_zero() {
+            <T as Config>::Currency::minimum_balance()
+        } else {
+            BalanceOf::<T>::zero()
+        };
+        drop(<T as Config>::Currency::deposit_creating(
+            &pot,
+            due.saturating_add(endowment),
+        ));
+        <RewardPerShare<T>>::insert(collator, per_share.saturating_add(reward_per_share));
+    }
+
+    fn resolve_reward_boundary(collator: &T::AccountId, round: RoundIndex, per_share: FixedU128) {
+        <RewardBoundaries<T>>::mutate_exists(collator, round, |maybe_boundary| {
+            if let Some(boundary) = maybe_boundary {
+                boundary.per_share = Some(per_share);
+            }
+        });
+    }
+
+    /// Whether all payouts of `round` are made
+    fn round_paid_out(round: RoundIndex) -> bool {
+        let paid_round = <Round<T>>::get()
+            .current
+            .saturating_sub(T::RewardPaymentDelay::get());
+        round < paid_round || (round == paid_round && !<DelayedPayouts<T>>::contains_key(round))
+    }
+
+    /// `RewardPerShare` of `collator` once all payouts up to `round` are made, if known
+    fn reward_boundary(collator: &T::AccountId, round: RoundIndex) -> Option<FixedU128> {
+        <RewardBoundaries<T>>::get(collator, round)
+            .and_then(|boundary| boundary.per_share)
+            .or_else(|| {
+                // no later round was paid to the collator since
+                Self::round_paid_out(round).then(|| <RewardPerShare<T>>::get(collator))
+            })
+    }
+
+    /// Registers a checkpoint waiting for the boundary of `collator` at `round`
+    pub(crate) fn add_reward_boundary(collator: &T::AccountId, round: RoundIndex) {
+        <RewardBoundaries<T>>::mutate(collator, round, |maybe_boundary| {
+            let boundary = maybe_boundary.get_or_insert_with(Default::default);
+            boundary.refs = boundary.refs.saturating_add(1);
+        });
+        let per_share = <RewardPerShare<T>>::get(collator);
+        <UnresolvedRewardBoundaries<T>>::mutate(collator, |rounds| {
+            // boundaries of rounds paid out are final, which keeps this list short
+            rounds.retain(|boundary| {
+                if !Self::round_paid_out(*boundary) {
+                    return true;
+                }
+                Self::resolve_reward_boundary(collator, *boundary, per_share);
+                false
+            });
+            if !rounds.contains(&round) {
+                rounds.push(round);
+            }
+        });
+    }
+
+    /// Releases a checkpoint waiting for the boundary of `collator` at `round`
+    fn remove_reward_boundary(collator: &T::AccountId, round: RoundIndex) {
+        <RewardBoundaries<T>>::mutate_exists(collator, round, |maybe_boundary| {
+            if let Some(boundary) = maybe_boundary {
+                boundary.refs = boundary.refs.saturating_sub(1);
+                if boundary.refs.is_zero() {
+                    *maybe_boundary = None;
+                }
+            }
+        });
+    }
+
+    /// Part of `bond` which is counted in round snapshots, bottom delegations as well as pending
+    /// revocations and decreases are not
+    pub(crate) fn rewardable_amount(
+        candidate: &T::AccountId,
+        delegator: &T::AccountId,
+        bond: BalanceOf<T>,
+    ) -> BalanceOf<T> {
+        let in_top = <TopDelegations<T>>::get(candidate)
+            .map(|top| top.delegations.iter().any(|d| &d.owner == delegator))
+            .unwrap_or(false);
+        if !in_top {
+            return BalanceOf::<T>::zero();
+        }
+        match <DelegationScheduledRequests<T>>::get(candidate)
+            .into_iter()
+            .find(|request| &request.delegator == delegator)
+            .map(|request| request.action)
+        {
+            Some(DelegationAction::Revoke(_)) => BalanceOf::<T>::zero(),
+            Some(DelegationAction::Decrease(amount)) => bond.saturating_sub(amount),
+            None => bond,
+        }
+    }
+
+    /// Reward of `amount` staked while the accumulator grew by `per_share`, rounded half down
+    /// like the `Perbill` shares of collators are
+    fn reward_of(per_share: FixedU128, amount: BalanceOf<T>) -> BalanceOf<T> {
+        let reward = per_share.saturating_mul(FixedU128::saturating_from_integer(amount));
+        let whole = reward.floor().saturating_mul_int(BalanceOf::<T>::one());
+        if reward.frac() > FixedU128::saturating_from_rational(1u32, 2u32) {
+            whole.saturating_add(One::one())
+        } else {
+            whole
+        }
+    }
+
+    /// Reward of `amount` staked while the accumulator grew by `per_share`, rounded up
+    fn reward_of_rounded_up(per_share: FixedU128, amount: BalanceOf<T>) -> BalanceOf<T> {
+        let reward = per_share.saturating_mul(FixedU128::saturating_from_integer(amount));
+        let whole = reward.floor().saturating_mul_int(BalanceOf::<T>::one());
+        if reward.frac().is_zero() {
+            whole
+        } else {
+            whole.saturating_add(One::one())
+        }
+    }
+
+    /// Collects the rewards owed to the `checkpoint` of a delegation towards `candidate` with the
+    /// rewardable `amount` after all pending rounds. Returns the rewards and the rounds of the
+    /// boundaries which are no longer waited for.
+    fn collect_delegation_rewards(
+        candidate: &T::AccountId,
+        checkpoint: &mut RewardCheckpoint<BalanceOf<T>>,
+        amount: BalanceOf<T>,
+    ) -> (BalanceOf<T>, Vec<RoundIndex>) {
+        let per_share = <RewardPerShare<T>>::get(candidate);
+        let mut due = BalanceOf::<T>::zero();
+        let mut resolved = Vec::new();
+        while let Some((round, pending_amount)) = checkpoint.pending.first().cloned() {
+            match Self::reward_boundary(candidate, round) {
+                Some(boundary) => {
+                    due = due.saturating_add(Self::reward_of(
+                        boundary.saturating_sub(checkpoint.per_share),
+                        pending_amount,
+                    ));
+                    checkpoint.per_share = boundary;
+                    checkpoint.pending.remove(0);
+                    resolved.push(round);
+                }
+                None => {
+                    // every payout since is for rounds up to the pending round
+                    due = due.saturating_add(Self::reward_of(
+                        per_share.saturating_sub(checkpoint.per_share),
+                        pending_amount,
+                    ));
+                    checkpoint.per_share = per_share;
+                    return (due, resolved);
+                }
+            }
+        }
+        due = due.saturating_add(Self::reward_of(
+            per_share.saturating_sub(checkpoint.per_share),
+            amount,
+        ));
+        checkpoint.per_share = per_share;
+        (due, resolved)
+    }
+
+    /// Delegations of `delegator` which may be owed rewards, removed delegations waiting for the
+    /// payouts of rounds they were snapshotted in with a zero bond
+    fn rewarded_delegations(delegator: &T::AccountId) -> Vec<Bond<T::AccountId, BalanceOf<T>>> {
+        let mut delegations = <DelegatorState<T>>::get(delegator)
+            .map(|state| state.delegations.0)
+            .unwrap_or_default();
+        for candidate in <RewardCheckpoints<T>>::iter_key_prefix(delegator) {
+            if !delegations.iter().any(|bond| bond.owner == candidate) {
+                delegations.push(Bond {
+                    owner: candidate,
+                    amount: BalanceOf::<T>::zero(),
+                });
+            }
+        }
+        delegations
+    }
+
+    /// Rewards paid out to `delegator` which are not claimed yet
+    pub(crate) fn accrued_delegator_rewards(delegator: &T::AccountId) -> BalanceOf<T> {
+        Self::rewarded_delegations(delegator).into_iter().fold(
+            <UnclaimedRewards<T>>::get(delegator),
+            |accrued, Bond { owner, amount }| {
+                let mut checkpoint =
+                    <RewardCheckpoints<T>>::get(delegator, &owner).unwrap_or_default();
+                let amount = Self::rewardable_amount(&owner, delegator, amount);
+                accrued.saturating_add(
+                    Self::collect_delegation_rewards(&owner, &mut checkpoint, amount).0,
+                )
+            },
+        )
+    }
+
+    /// Moves rewards owed to the delegation of `bond` towards `candidate` into
+    /// `UnclaimedRewards`. MUST be called before the bond or pending request changes.
+    pub(crate) fn settle_delegation_rewards(
+        candidate: &T::AccountId,
+        delegator: &T::AccountId,
+        bond: BalanceOf<T>,
+    ) {
+        let mut checkpoint = <RewardCheckpoints<T>>::get(delegator, candidate).unwrap_or_default();
+        let amount = Self::rewardable_amount(candidate, delegator, bond);
+        let (due, resolved) = Self::collect_delegation_rewards(candidate, &mut checkpoint, amount);
+        resolved
+            .into_iter()
+            .for_each(|round| Self::remove_reward_boundary(candidate, round));
+        // the snapshot of the current round keeps the amount before the first change, there is
+        // none before the first round starts
+        if <Round<T>>::exists() {
+            let now = <Round<T>>::get().current;
+            if checkpoint.pending.last().map(|(round, _)| *round) != Some(now) {
+                checkpoint.pending.push((now, amount));
+                Self::add_reward_boundary(candidate, now);
+            }
+        }
+        <RewardCheckpoints<T>>::insert(delegator, candidate, checkpoint);
+        if !due.is_zero() {
+            <UnclaimedRewards<T>>::mutate(delegator, |unclaimed| {
+                *unclaimed = unclaimed.saturating_add(due)
+            });
+        }
+    }
+
+    /// Starts accruing rewards for a new delegation
+    pub(crate) fn start_delegation_rewards(candidate: &T::AccountId, delegator: &T::AccountId) {
+        Self::settle_delegation_rewards(candidate, delegator, BalanceOf::<T>::zero());
+    }
+
+    /// Settles the rewards of a delegation which is removed. Rounds it was snapshotted in are
+    /// still rewarded once paid out.
+    pub(crate) fn end_delegation_rewards(
+        candidate: &T::AccountId,
+        delegator: &T::AccountId,
+        bond: BalanceOf<T>,
+    ) {
+        Self::settle_delegation_rewards(candidate, delegator, bond);
+    }
+
+    /// Pays `amount` out of the reward pot, which is kept alive so that the rounding dust of the
+    /// payouts stays in it. Rewards which the pot cannot cover or which cannot be paid are kept
+    /// in `UnclaimedRewards`. Returns the rewards paid.
+    pub(crate) fn pay_delegator_reward(
+        delegator: &T::AccountId,
+        amount: BalanceOf<T>,
+    ) -> Result<BalanceOf<T>, DispatchError> {
+        let pot = Self::reward_pot();
+        let available = <T as Config>::Currency::free_balance(&pot)
+            .saturating_sub(<T as Config>::Currency::minimum_balance());
+        let paid = amount.min(available);
+        let unpaid = amount.saturating_sub(paid);
+        if !unpaid.is_zero() {
+            <UnclaimedRewards<T>>::mutate(delegator, |unclaimed| {
+                *unclaimed = unclaimed.saturating_add(unpaid)
+            });
+        }
+        if paid.is_zero() {
+            return Ok(paid);
+        }
+        let result = <T as Config>::Currency::transfer(
+            &pot,
+            delegator,
+            paid,
+            ExistenceRequirement::KeepAlive,
+        );
+        if let Err(error) = result {
+            log::warn!(
+                "Failed to pay rewards of delegator {:?}: {:?}",
+                delegator,
+                error
+            );
+            <UnclaimedRewards<T>>::mutate(delegator, |unclaimed| {
+                *unclaimed = unclaimed.saturating_add(paid)
+            });
+            return Err(error);
+        }
+        Self::deposit_event(Event::Rewarded {
+            account: delegator.clone(),
+            rewards: paid,
+        });
+        Ok(paid)
+    }
+
+    /// Pays all rewards owed to `delegator`. Rewards of delegations with auto-compounding set are
+    /// compounded, rewards settled on earlier bond changes and of removed delegations are paid
+    /// out liquid.
+    pub fn claim_delegator_rewards(delegator: &T::AccountId) {
+        let mut liquid = <UnclaimedRewards<T>>::take(delegator);
+        for Bond { owner, amount } in Self::rewarded_delegations(delegator) {
+            let mut checkpoint = <RewardCheckpoints<T>>::get(delegator, &owner).unwrap_or_default();
+            let rewardable = Self::rewardable_amount(&owner, delegator, amount);
+            let (due, resolved) =
+                Self::collect_delegation_rewards(&owner, &mut checkpoint, rewardable);
+            resolved
+                .into_iter()
+                .for_each(|round| Self::remove_reward_boundary(&owner, round));
+            if amount.is_zero() && checkpoint.pending.is_empty() {
+                <RewardCheckpoints<T>>::remove(delegator, &owner);
+            } else {
+                <RewardCheckpoints<T>>::insert(delegator, &owner, checkpoint);
+            }
+            if due.is_zero() {
+                continue;
+            }
+            let auto_compound = Self::delegation_auto_compound(&owner, delegator);
+            if amount.is_zero() || auto_compound.is_zero() {
+                liquid = liquid.saturating_add(due);
+            } else {
+                Self::mint_and_compound(due, auto_compound, owner, delegator.clone());
+            }
+        }
+        if !liquid.is_zero() {
+            let _ = Self::pay_delegator_reward(delegator, liquid);
+        }
+    }
+}
@@ -0,0 +1,131 @@
+// Copyright 2020-2024 Manta Network.
+// This file is part of Manta.
+//
+// Manta is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// Manta is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Manta.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Parachain Staking RPC Interfaces
+
+use crate::{runtime::ParachainStakingApi, ScheduledRequest};
+use codec::Codec;
+use core::marker::PhantomData;
+use jsonrpsee::{
+    core::{async_trait, RpcResult},
+    proc_macros::rpc,
+    types::error::{CallError, ErrorObject},
+};
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_runtime::{traits::Block, FixedU128};
+use sp_std::{sync::Arc, vec::Vec};
+
+pub const PARACHAIN_STAKING_ERROR: i32 = 778;
+
+#[rpc(server)]
+pub trait ParachainStakingRpc<AccountId, Balance> {
+    #[method(name = "parachainStaking_candidateExpectedApy", blocking)]
+    fn candidate_expected_apy(&self, candidate: AccountId) -> RpcResult<Option<FixedU128>>;
+
+    #[method(name = "parachainStaking_delegatorPendingRewards", blocking)]
+    fn delegator_pending_rewards(&self, delegator: AccountId) -> RpcResult<Balance>;
+
+    #[method(name = "parachainStaking_candidateScheduledRequests", blocking)]
+    fn candidate_scheduled_requests(
+        &self,
+        candidate: AccountId,
+    ) -> RpcResult<Vec<ScheduledRequest<AccountId, Balance>>>;
+
+    #[method(name = "parachainStaking_projectedSelectedCandidates", blocking)]
+    fn projected_selected_candidates(&self) -> RpcResult<Vec<AccountId>>;
+}
+
+/// Parachain Staking RPC API Implementation
+pub struct ParachainStaking<B, C> {
+    /// Client
+    client: Arc<C>,
+
+    /// Type Parameter Marker
+    __: PhantomData<B>,
+}
+
+impl<B, C> ParachainStaking<B, C> {
+    /// Builds a new [`ParachainStaking`] RPC API implementation.
+    #[inline]
+    pub fn new(client: Arc<C>) -> Self {
+        Self {
+            client,
+            __: PhantomData,
+        }
+    }
+}
+
+/// Converts a runtime API error into an RPC error with the given `message`.
+#[inline]
+fn runtime_error<E>(message: &'static str, err: E) -> jsonrpsee::core::Error
+where
+    E: core::fmt::Debug,
+{
+    CallError::Custom(ErrorObject::owned(
+        PARACHAIN_STAKING_ERROR,
+        message,
+        Some(format!("{err:?}")),
+    ))
+    .into()
+}
+
+#[async_trait]
+impl<B, C, AccountId, Balance> ParachainStakingRpcServer<AccountId, Balance>
+    for ParachainStaking<B, C>
+where
+    B: Block,
+    C: 'static + ProvideRuntimeApi<B> + HeaderBackend<B>,
+    C::Api: ParachainStakingApi<B, AccountId, Balance>,
+    AccountId:
+        'static + Clone + Codec + Send + Sync + serde::de::DeserializeOwned + serde::Serialize,
+    Balance: 'static + Codec + Send + Sync + serde::Serialize,
+{
+    #[inline]
+    fn candidate_expected_apy(&self, candidate: AccountId) -> RpcResult<Option<FixedU128>> {
+        let api = self.client.runtime_api();
+        let at = self.client.info().best_hash;
+        api.candidate_expected_apy(at, candidate)
+            .map_err(|err| runtime_error("Unable to compute expected APY", err))
+    }
+
+    #[inline]
+    fn delegator_pending_rewards(&self, delegator: AccountId) -> RpcResult<Balance> {
+        let api = self.client.runtime_api();
+        let at = self.client.info().best_hash;
+        api.delegator_pending_rewards(at, delegator)
+            .map_err(|err| runtime_error("Unable to compute pending rewards", err))
+    }
+
+    #[inline]
+    fn candidate_scheduled_requests(
+        &self,
+        candidate: AccountId,
+    ) -> RpcResult<Vec<ScheduledRequest<AccountId, Balance>>> {
+        let api = self.client.runtime_api();
+        let at = self.client.info().best_hash;
+        api.candidate_scheduled_requests(at, candidate)
+            .map_err(|err| runtime_error("Unable to fetch scheduled requests", err))
+    }
+
+    #[inline]
+    fn projected_selected_candidates(&self) -> RpcResult<Vec<AccountId>> {
+        let api = self.client.runtime_api();
+        let at = self.client.info().best_hash;
+        api.projected_selected_candidates(at)
+            .map_err(|err| runtime_error("Unable to compute projected selected candidates", err))
+    }
+}
@@ -0,0 +1,33 @@
+// Copyright 2020-2024 Manta Network.
+// This file is part of Manta.
+//
+// Manta is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// Manta is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Manta.  If not, see <http://www.gnu.org/licenses/>.
+
+use crate::ScheduledRequest;
+use codec::Codec;
+use sp_runtime::FixedU128;
+use sp_std::vec::Vec;
+
+sp_api::decl_runtime_apis! {
+    pub trait ParachainStakingApi<AccountId, Balance>
+    where
+        AccountId: Codec,
+        Balance: Codec,
+    {
+        fn candidate_expected_apy(candidate: AccountId) -> Option<FixedU128>;
+        fn delegator_pending_rewards(delegator: AccountId) -> Balance;
+        fn candidate_scheduled_requests(candidate: AccountId) -> Vec<ScheduledRequest<AccountId, Balance>>;
+        fn projected_selected_candidates() -> Vec<AccountId>;
+    }
+}
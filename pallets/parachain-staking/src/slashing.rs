@@ -0,0 +1,322 @@
+// Copyright 2020-2024 Manta Network.
+// This file is part of Manta.
+//
+// Manta is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// Manta is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Manta.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Slashing of underperforming and equivocating collators
+
+use crate::{
+    delegation_requests::DelegationAction,
+    pallet::{
+        AtStake, AwardedPts, BalanceOf, CandidateInfo, CandidatePool, Config,
+        DelegationScheduledRequests, DelegatorState, Error, Event, Pallet, ReportedEquivocations,
+        Round, RoundIndex, SelectedCandidates, SlashingInfo, Total, UnappliedSlashes,
+        UnderperformingRounds, COLLATOR_LOCK_ID,
+    },
+    Bond, CandidateBondLessRequest, CollatorSnapshot,
+};
+use codec::{Decode, Encode};
+use frame_support::{
+    dispatch::DispatchResult,
+    ensure,
+    traits::{Currency, Get, Imbalance, LockableCurrency, OnUnbalanced, WithdrawReasons},
+    weights::Weight,
+    RuntimeDebug,
+};
+use scale_info::TypeInfo;
+use sp_runtime::{
+    traits::{Saturating, Zero},
+    Perbill,
+};
+use sp_std::vec::Vec;
+
+/// Points awarded to a collator for each authored block
+pub(crate) const POINTS_PER_BLOCK: u32 = 20;
+
+/// Slashing parameters set by governance
+#[derive(Clone, Copy, Default, Eq, PartialEq, Encode, Decode, RuntimeDebug, TypeInfo)]
+pub struct SlashingConfig {
+    /// Minimum ratio of authored to expected blocks per round, zero disables slashing
+    pub min_performance: Perbill,
+    /// Number of consecutive underperforming rounds which triggers a slash
+    pub tolerated_rounds: u32,
+    /// Fraction of the stake which is slashed
+    pub slash_fraction: Perbill,
+    /// Whether the delegations backing the collator are slashed as well
+    pub slash_delegations: bool,
+    /// Fraction of the stake which is slashed for an equivocation reported by governance, zero
+    /// disables reports
+    pub equivocation_slash_fraction: Perbill,
+}
+
+/// A slash waiting for its deferral window to pass before being applied
+#[derive(Clone, Eq, PartialEq, Encode, Decode, RuntimeDebug, TypeInfo)]
+pub struct UnappliedSlash<AccountId, Balance> {
+    /// The slashed collator
+    pub collator: AccountId,
+    /// Fraction of the stake to slash
+    pub fraction: Perbill,
+    /// Collator self bond at the time of the offence
+    pub own: Balance,
+    /// Delegations backing the collator at the time of the offence, empty if delegations
+    /// are not slashed
+    pub others: Vec<Bond<AccountId, Balance>>,
+}
+
+impl<T: Config> Pallet<T> {
+    /// Compares the blocks authored by each collator selected for `round` against the blocks
+    /// expected from an even split of the round and schedules a slash for collators who
+    /// underperformed for `tolerated_rounds` consecutive rounds.
+    pub(crate) fn check_collator_performance(round: RoundIndex, round_length: u32) -> Weight {
+        let config = <SlashingInfo<T>>::get();
+        if config.min_performance.is_zero() {
+            return T::DbWeight::get().reads(1);
+        }
+        let collators = <SelectedCandidates<T>>::get();
+        let expected_blocks = round_length / (collators.len() as u32).max(1);
+        if expected_blocks.is_zero() {
+            return T::DbWeight::get().reads(2);
+        }
+        let apply_round = round.saturating_add(T::SlashDeferDuration::get());
+        let mut reads = 2u64;
+        let mut writes = 0u64;
+        for collator in collators {
+            let authored_blocks = <AwardedPts<T>>::get(round, &collator) / POINTS_PER_BLOCK;
+            reads = reads.saturating_add(2);
+            writes = writes.saturating_add(1);
+            if Perbill::from_rational(authored_blocks, expected_blocks) >= config.min_performance {
+                <UnderperformingRounds<T>>::remove(&collator);
+                continue;
+            }
+            let missed_rounds = <UnderperformingRounds<T>>::get(&collator).saturating_add(1);
+            if missed_rounds < config.tolerated_rounds.max(1) {
+                <UnderperformingRounds<T>>::insert(&collator, missed_rounds);
+                continue;
+            }
+            <UnderperformingRounds<T>>::remove(&collator);
+            let snapshot = <AtStake<T>>::get(round, &collator);
+            reads = reads.saturating_add(2);
+            writes = writes.saturating_add(1);
+            Self::defer_slash(
+                collator,
+                config.slash_fraction,
+                snapshot,
+                config.slash_delegations,
+                apply_round,
+            );
+        }
+        T::DbWeight::get().reads_writes(reads, writes)
+    }
+
+    /// Schedules a slash of `collator` for equivocating in `round`, based on its stake in that
+    /// round. The slash is deferred and can be cancelled like the ones of underperformers.
+    pub(crate) fn report_collator_equivocation(
+        collator: T::AccountId,
+        round: RoundIndex,
+    ) -> DispatchResult {
+        let config = <SlashingInfo<T>>::get();
+        ensure!(
+            !config.equivocation_slash_fraction.is_zero(),
+            Error::<T>::EquivocationSlashingDisabled
+        );
+        // the snapshot is kept until the rewards of the round are paid out
+        ensure!(
+            <AtStake<T>>::contains_key(round, &collator),
+            Error::<T>::CollatorSnapshotDNE
+        );
+        ensure!(
+            !<ReportedEquivocations<T>>::contains_key(round, &collator),
+            Error::<T>::EquivocationAlreadyReported
+        );
+        <ReportedEquivocations<T>>::insert(round, &collator, ());
+        Self::deposit_event(Event::EquivocationReported {
+            collator: collator.clone(),
+            round,
+        });
+        let apply_round = <Round<T>>::get()
+            .current
+            .saturating_add(T::SlashDeferDuration::get());
+        Self::defer_slash(
+            collator.clone(),
+            config.equivocation_slash_fraction,
+            <AtStake<T>>::get(round, &collator),
+            config.slash_delegations,
+            apply_round,
+        );
+        Ok(())
+    }
+
+    /// Schedules a slash of `fraction` of the stake in `snapshot` until `apply_round`.
+    fn defer_slash(
+        collator: T::AccountId,
+        fraction: Perbill,
+        snapshot: CollatorSnapshot<T::AccountId, BalanceOf<T>>,
+        slash_delegations: bool,
+        apply_round: RoundIndex,
+    ) {
+        <UnappliedSlashes<T>>::append(
+            apply_round,
+            UnappliedSlash {
+                collator: collator.clone(),
+                fraction,
+                own: snapshot.bond,
+                others: if slash_delegations {
+                    snapshot.delegations
+                } else {
+                    Vec::new()
+                },
+            },
+        );
+        Self::deposit_event(Event::SlashDeferred {
+            collator,
+            apply_round,
+            fraction,
+        });
+    }
+
+    /// Applies all slashes which were deferred until `round`.
+    pub(crate) fn apply_deferred_slashes(round: RoundIndex) -> Weight {
+        let slashes = <UnappliedSlashes<T>>::take(round);
+        let mut weight = T::DbWeight::get().reads_writes(1, 1);
+        for slash in slashes {
+            weight = weight.saturating_add(<T as Config>::WeightInfo::apply_slash(
+                slash.others.len() as u32,
+            ));
+            Self::apply_slash(slash);
+        }
+        weight
+    }
+
+    /// Slashes the collator and, if recorded, its delegators. Stake which was unbonded since the
+    /// offence is not slashed; the slashed funds are handed to `T::OnSlash`. A collator left with
+    /// less than `MinCandidateStk` of self bond goes offline.
+    pub(crate) fn apply_slash(slash: UnappliedSlash<T::AccountId, BalanceOf<T>>) {
+        let collator = slash.collator;
+        let mut state = match <CandidateInfo<T>>::get(&collator) {
+            Some(state) => state,
+            // the candidate already left and its stake was returned
+            None => return,
+        };
+        let mut total_unbonded = BalanceOf::<T>::zero();
+
+        let own = slash.fraction * slash.own.min(state.bond);
+        if !own.is_zero() {
+            state.bond = state.bond.saturating_sub(own);
+            state.total_counted = state.total_counted.saturating_sub(own);
+            <T as Config>::Currency::set_lock(
+                COLLATOR_LOCK_ID,
+                &collator,
+                state.bond,
+                WithdrawReasons::all(),
+            );
+            total_unbonded = total_unbonded.saturating_add(own);
+            Self::slash_account(&collator, own);
+        }
+        // a pending bond decrease may exceed what is left above the minimum
+        if let Some(request) = state.request {
+            let decreasable = state.bond.saturating_sub(T::MinCandidateStk::get());
+            if request.amount > decreasable {
+                if decreasable.is_zero() {
+                    let _ = state.cancel_bond_less::<T>(collator.clone());
+                } else {
+                    state.request = Some(CandidateBondLessRequest {
+                        amount: decreasable,
+                        when_executable: request.when_executable,
+                    });
+                }
+            }
+        }
+
+        for Bond { owner, amount } in slash.others {
+            let mut delegator = match <DelegatorState<T>>::get(&owner) {
+                Some(delegator) => delegator,
+                None => continue,
+            };
+            let bond_before = match delegator.get_bond_amount(&collator) {
+                Some(bond) => bond,
+                None => continue,
+            };
+            let less = slash.fraction * amount.min(bond_before);
+            if less.is_zero() {
+                continue;
+            }
+            if let Err(error) =
+                state.decrease_delegation::<T>(&collator, owner.clone(), bond_before, less)
+            {
+                log::warn!("Failed to slash delegation of {:?}: {:?}", owner, error);
+                continue;
+            }
+            for bond in &mut delegator.delegations.0 {
+                if bond.owner == collator {
+                    bond.amount = bond.amount.saturating_sub(less);
+                }
+            }
+            if delegator.total_sub::<T>(less).is_err() {
+                log::warn!("Failed to adjust the lock of slashed delegator {:?}", owner);
+            }
+            // a pending request may exceed what is left of the delegation
+            let mut scheduled_requests = <DelegationScheduledRequests<T>>::get(&collator);
+            if let Some(idx) = scheduled_requests
+                .iter()
+                .position(|request| request.delegator == owner)
+            {
+                let pending = scheduled_requests[idx].action.amount();
+                delegator.less_total = delegator.less_total.saturating_sub(pending);
+                match scheduled_requests[idx].action {
+                    DelegationAction::Revoke(_) => {
+                        let remaining = bond_before.saturating_sub(less);
+                        scheduled_requests[idx].action = DelegationAction::Revoke(remaining);
+                        delegator.less_total = delegator.less_total.saturating_add(remaining);
+                    }
+                    DelegationAction::Decrease(_) => {
+                        scheduled_requests.remove(idx);
+                    }
+                }
+                <DelegationScheduledRequests<T>>::insert(&collator, scheduled_requests);
+            }
+            <DelegatorState<T>>::insert(&owner, delegator);
+            total_unbonded = total_unbonded.saturating_add(less);
+            Self::slash_account(&owner, less);
+        }
+
+        if state.is_active() {
+            if state.bond < T::MinCandidateStk::get() {
+                // the collator must bond more before going online again
+                state.go_offline();
+                let mut candidates = <CandidatePool<T>>::get();
+                if candidates.remove(&Bond::from_owner(collator.clone())) {
+                    <CandidatePool<T>>::put(candidates);
+                }
+                Self::deposit_event(Event::CandidateWentOffline {
+                    candidate: collator.clone(),
+                });
+            } else {
+                Self::update_active(collator.clone(), state.total_counted);
+            }
+        }
+        <CandidateInfo<T>>::insert(&collator, state);
+        <Total<T>>::mutate(|total| *total = total.saturating_sub(total_unbonded));
+    }
+
+    /// Slashes `amount` from the balance of `who` and hands the funds to `T::OnSlash`.
+    fn slash_account(who: &T::AccountId, amount: BalanceOf<T>) {
+        let (imbalance, _) = <T as Config>::Currency::slash(who, amount);
+        let slashed = imbalance.peek();
+        T::OnSlash::on_unbalanced(imbalance);
+        Self::deposit_event(Event::Slashed {
+            account: who.clone(),
+            amount: slashed,
+        });
+    }
+}
@@ -26,15 +26,19 @@ use crate::{
     assert_tail_eq,
     delegation_requests::{CancelledScheduledRequest, DelegationAction, ScheduledRequest},
     mock::{
-        roll_one_block, roll_to, roll_to_round_begin, roll_to_round_end, set_author, Balances,
-        CollatorSelection, ExtBuilder, ParachainStaking, RuntimeEvent as MetaEvent, RuntimeOrigin,
-        Test,
+        claim_rewards, events, roll_one_block, roll_to, roll_to_round_begin, roll_to_round_end,
+        set_author, Balances, CollatorSelection, ExtBuilder, ParachainStaking,
+        RuntimeEvent as MetaEvent, RuntimeOrigin, Test,
     },
-    AtStake, Bond, CollatorStatus, DelegationScheduledRequests, DelegatorAdded, DelegatorState,
-    DelegatorStatus, Error, Event, Range, DELEGATOR_LOCK_ID,
+    AtStake, AutoCompoundConfig, AutoCompoundingDelegations, Bond, CandidateBondLessRequest,
+    CollatorStatus, DelegationScheduledRequests, DelegatorAdded, DelegatorState, DelegatorStatus,
+    Error, Event, InflationCurve, InflationInfo, Range, SlashingConfig, DELEGATOR_LOCK_ID,
+};
+use frame_support::{assert_noop, assert_ok, BoundedVec};
+use sp_runtime::{
+    traits::{One, Zero},
+    DispatchError, FixedU128, ModuleError, Perbill, Percent,
 };
-use frame_support::{assert_noop, assert_ok};
-use sp_runtime::{traits::Zero, DispatchError, ModuleError, Perbill, Percent};
 
 // ~~ ROOT ~~
 
@@ -1028,6 +1032,139 @@ fn cant_reduce_bond_below_10() {
             );
         });
 }
+#[test]
+fn start_collator_selection_migration_requires_root() {
+    ExtBuilder::default().build().execute_with(|| {
+        assert_noop!(
+            ParachainStaking::start_collator_selection_migration(RuntimeOrigin::signed(1)),
+            sp_runtime::DispatchError::BadOrigin
+        );
+        assert_ok!(ParachainStaking::start_collator_selection_migration(
+            RuntimeOrigin::root()
+        ));
+        assert!(ParachainStaking::collator_selection_migration());
+        assert_last_event!(MetaEvent::ParachainStaking(
+            Event::CollatorSelectionMigrationStarted {
+                candidates: 0,
+                invulnerables: 0,
+            }
+        ));
+        assert_noop!(
+            ParachainStaking::start_collator_selection_migration(RuntimeOrigin::root()),
+            Error::<Test>::CollatorSelectionMigrationInProgress
+        );
+    });
+}
+
+#[test]
+fn collator_selection_migration_moves_collators_in_batches() {
+    ExtBuilder::default()
+        .with_balances(vec![(1, 50), (3, 30), (4, 30), (5, 30), (6, 30)])
+        .with_candidates(vec![(1, 50)])
+        .build()
+        .execute_with(|| {
+            assert_ok!(CollatorSelection::set_desired_candidates(
+                RuntimeOrigin::signed(RootAccount::get()),
+                10
+            ));
+            assert_ok!(CollatorSelection::set_candidacy_bond(
+                RuntimeOrigin::signed(RootAccount::get()),
+                5
+            ));
+            for candidate in [3, 4, 5] {
+                assert_ok!(CollatorSelection::register_candidate(
+                    RuntimeOrigin::signed(RootAccount::get()),
+                    candidate
+                ));
+                assert_eq!(Balances::reserved_balance(candidate), 5);
+            }
+            assert_ok!(CollatorSelection::set_invulnerables(
+                RuntimeOrigin::signed(RootAccount::get()),
+                vec![6, 1]
+            ));
+            assert_ok!(ParachainStaking::start_collator_selection_migration(
+                RuntimeOrigin::root()
+            ));
+            assert_last_event!(MetaEvent::ParachainStaking(
+                Event::CollatorSelectionMigrationStarted {
+                    candidates: 3,
+                    invulnerables: 2,
+                }
+            ));
+
+            // two collators per block, candidates first
+            roll_one_block();
+            assert!(ParachainStaking::is_candidate(&3));
+            assert!(ParachainStaking::is_candidate(&4));
+            assert!(!ParachainStaking::is_candidate(&5));
+            assert_eq!(Balances::reserved_balance(3), 0);
+            assert_eq!(ParachainStaking::candidate_info(3).unwrap().bond, 1);
+            assert_eq!(
+                CollatorSelection::candidates()
+                    .into_iter()
+                    .map(|c| c.who)
+                    .collect::<Vec<_>>(),
+                vec![5]
+            );
+            assert!(ParachainStaking::collator_selection_migration());
+
+            roll_one_block();
+            assert!(ParachainStaking::is_candidate(&5));
+            assert!(ParachainStaking::is_candidate(&6));
+            assert_eq!(ParachainStaking::candidate_info(6).unwrap().bond, 1);
+            assert!(CollatorSelection::candidates().is_empty());
+            assert_eq!(CollatorSelection::invulnerables(), vec![1]);
+            assert!(ParachainStaking::collator_selection_migration());
+
+            // 1 already is a candidate and keeps its bond
+            roll_one_block();
+            assert_eq!(ParachainStaking::candidate_info(1).unwrap().bond, 50);
+            assert_eq!(ParachainStaking::candidate_pool().0.len(), 5);
+            assert!(!ParachainStaking::collator_selection_migration());
+            assert!(CollatorSelection::invulnerables().is_empty());
+            assert_eq!(CollatorSelection::candidacy_bond(), 0);
+            assert_eq!(CollatorSelection::desired_candidates(), 0);
+            assert_last_event!(MetaEvent::ParachainStaking(
+                Event::CollatorSelectionMigrationCompleted
+            ));
+
+            // nothing left to do
+            roll_one_block();
+            assert_eq!(ParachainStaking::candidate_pool().0.len(), 5);
+        });
+}
+
+#[test]
+fn collator_selection_migration_drops_collators_that_cannot_join() {
+    ExtBuilder::default()
+        .with_balances(vec![(1, 50), (2, 20)])
+        .with_candidates(vec![(1, 50)])
+        .with_delegations(vec![(2, 1, 10)])
+        .build()
+        .execute_with(|| {
+            assert_ok!(CollatorSelection::set_invulnerables(
+                RuntimeOrigin::signed(RootAccount::get()),
+                vec![2, 8]
+            ));
+            assert_ok!(ParachainStaking::start_collator_selection_migration(
+                RuntimeOrigin::root()
+            ));
+            roll_one_block();
+            assert_event_emitted!(Event::CollatorNotMigrated {
+                account: 2,
+                error: Error::<Test>::DelegatorExists.into(),
+            });
+            assert_event_emitted!(Event::CollatorNotMigrated {
+                account: 8,
+                error: Error::<Test>::InsufficientBalance.into(),
+            });
+            assert!(!ParachainStaking::is_candidate(&2));
+            assert!(!ParachainStaking::is_candidate(&8));
+            assert!(CollatorSelection::invulnerables().is_empty());
+            assert!(!ParachainStaking::collator_selection_migration());
+            assert_event_emitted!(Event::CollatorSelectionMigrationCompleted);
+        });
+}
 // WHITELIST END SECTION Remove after whitelist period
 
 #[test]
@@ -4298,112 +4435,1759 @@ fn cancel_delegator_bond_less_updates_delegator_state() {
         });
 }
 
-// ~~ PROPERTY-BASED TESTS ~~
+// AUTO-COMPOUND
 
 #[test]
-fn delegator_schedule_revocation_total() {
+fn set_auto_compound_inserts_and_updates_config() {
     ExtBuilder::default()
-        .with_balances(vec![(1, 20), (2, 40), (3, 20), (4, 20), (5, 20)])
-        .with_candidates(vec![(1, 20), (3, 20), (4, 20), (5, 20)])
-        .with_delegations(vec![(2, 1, 10), (2, 3, 10), (2, 4, 10)])
+        .with_balances(vec![(1, 30), (2, 10)])
+        .with_candidates(vec![(1, 30)])
+        .with_delegations(vec![(2, 1, 10)])
         .build()
         .execute_with(|| {
-            assert_ok!(ParachainStaking::schedule_revoke_delegation(
+            assert_ok!(ParachainStaking::set_auto_compound(
                 RuntimeOrigin::signed(2),
+                1,
+                Percent::from_percent(50),
+                0,
                 1
             ));
+            assert_last_event!(MetaEvent::ParachainStaking(Event::AutoCompoundSet {
+                candidate: 1,
+                delegator: 2,
+                value: Percent::from_percent(50),
+            }));
             assert_eq!(
-                ParachainStaking::delegator_state(&2)
-                    .map(|x| x.less_total)
-                    .expect("delegator state must exist"),
-                10
+                ParachainStaking::auto_compounding_delegations(1),
+                vec![AutoCompoundConfig {
+                    delegator: 2,
+                    value: Percent::from_percent(50),
+                }],
             );
-            roll_to(10);
-            assert_ok!(ParachainStaking::execute_delegation_request(
+            assert_ok!(ParachainStaking::set_auto_compound(
                 RuntimeOrigin::signed(2),
-                2,
+                1,
+                Percent::from_percent(100),
+                1,
                 1
             ));
             assert_eq!(
-                ParachainStaking::delegator_state(&2)
-                    .map(|x| x.less_total)
-                    .expect("delegator state must exist"),
-                0
+                ParachainStaking::delegation_auto_compound(&1, &2),
+                Percent::from_percent(100),
             );
-            assert_ok!(ParachainStaking::delegate(
+        });
+}
+
+#[test]
+fn set_auto_compound_to_zero_removes_config() {
+    ExtBuilder::default()
+        .with_balances(vec![(1, 30), (2, 10)])
+        .with_candidates(vec![(1, 30)])
+        .with_delegations(vec![(2, 1, 10)])
+        .build()
+        .execute_with(|| {
+            assert_ok!(ParachainStaking::set_auto_compound(
                 RuntimeOrigin::signed(2),
-                5,
-                10,
+                1,
+                Percent::from_percent(50),
                 0,
-                2
-            ));
-            assert_ok!(ParachainStaking::schedule_revoke_delegation(
-                RuntimeOrigin::signed(2),
-                3
+                1
             ));
-            assert_ok!(ParachainStaking::schedule_revoke_delegation(
+            assert_ok!(ParachainStaking::set_auto_compound(
                 RuntimeOrigin::signed(2),
-                4
+                1,
+                Percent::zero(),
+                1,
+                1
             ));
-            assert_eq!(
-                ParachainStaking::delegator_state(&2)
-                    .map(|x| x.less_total)
-                    .expect("delegator state must exist"),
-                20,
+            assert_last_event!(MetaEvent::ParachainStaking(Event::AutoCompoundSet {
+                candidate: 1,
+                delegator: 2,
+                value: Percent::zero(),
+            }));
+            assert!(ParachainStaking::auto_compounding_delegations(1).is_empty());
+        });
+}
+
+#[test]
+fn set_auto_compound_fails_without_delegation() {
+    ExtBuilder::default()
+        .with_balances(vec![(1, 30), (2, 10), (3, 30)])
+        .with_candidates(vec![(1, 30), (3, 30)])
+        .with_delegations(vec![(2, 1, 10)])
+        .build()
+        .execute_with(|| {
+            assert_noop!(
+                ParachainStaking::set_auto_compound(
+                    RuntimeOrigin::signed(4),
+                    1,
+                    Percent::from_percent(50),
+                    0,
+                    1
+                ),
+                Error::<Test>::DelegatorDNE
             );
-            roll_to(20);
-            assert_ok!(ParachainStaking::execute_delegation_request(
+            assert_noop!(
+                ParachainStaking::set_auto_compound(
+                    RuntimeOrigin::signed(2),
+                    3,
+                    Percent::from_percent(50),
+                    0,
+                    1
+                ),
+                Error::<Test>::DelegationDNE
+            );
+        });
+}
+
+#[test]
+fn set_auto_compound_requires_correct_weight_hints() {
+    ExtBuilder::default()
+        .with_balances(vec![(1, 30), (2, 10), (3, 10)])
+        .with_candidates(vec![(1, 30)])
+        .with_delegations(vec![(2, 1, 10), (3, 1, 10)])
+        .build()
+        .execute_with(|| {
+            assert_noop!(
+                ParachainStaking::set_auto_compound(
+                    RuntimeOrigin::signed(2),
+                    1,
+                    Percent::from_percent(50),
+                    0,
+                    0
+                ),
+                Error::<Test>::TooLowDelegationCountToAutoCompound
+            );
+            assert_ok!(ParachainStaking::set_auto_compound(
                 RuntimeOrigin::signed(2),
-                2,
-                3
+                1,
+                Percent::from_percent(50),
+                0,
+                1
             ));
-            assert_eq!(
-                ParachainStaking::delegator_state(&2)
-                    .map(|x| x.less_total)
-                    .expect("delegator state must exist"),
-                10,
+            assert_noop!(
+                ParachainStaking::set_auto_compound(
+                    RuntimeOrigin::signed(3),
+                    1,
+                    Percent::from_percent(50),
+                    0,
+                    1
+                ),
+                Error::<Test>::TooLowCandidateAutoCompoundingDelegationCountToAutoCompound
             );
-            assert_ok!(ParachainStaking::execute_delegation_request(
+        });
+}
+
+#[test]
+fn set_auto_compound_is_bounded_by_top_and_bottom_delegations() {
+    ExtBuilder::default()
+        .with_balances(vec![(1, 30), (2, 10)])
+        .with_candidates(vec![(1, 30)])
+        .with_delegations(vec![(2, 1, 10)])
+        .build()
+        .execute_with(|| {
+            // fill the list up to MaxTopDelegationsPerCandidate + MaxBottomDelegationsPerCandidate
+            let full: Vec<_> = (100..108)
+                .map(|delegator| AutoCompoundConfig {
+                    delegator,
+                    value: Percent::from_percent(50),
+                })
+                .collect();
+            AutoCompoundingDelegations::<Test>::insert(
+                1,
+                BoundedVec::try_from(full).expect("8 entries fit the bound"),
+            );
+            assert_noop!(
+                ParachainStaking::set_auto_compound(
+                    RuntimeOrigin::signed(2),
+                    1,
+                    Percent::from_percent(50),
+                    8,
+                    1
+                ),
+                Error::<Test>::ExceedMaxDelegationsPerDelegator
+            );
+        });
+}
+
+#[test]
+fn delegate_with_auto_compound_sets_delegation_and_config() {
+    ExtBuilder::default()
+        .with_balances(vec![(1, 30), (2, 10)])
+        .with_candidates(vec![(1, 30)])
+        .build()
+        .execute_with(|| {
+            assert_ok!(ParachainStaking::delegate_with_auto_compound(
                 RuntimeOrigin::signed(2),
-                2,
-                4
+                1,
+                10,
+                Percent::from_percent(50),
+                0,
+                0,
+                0
             ));
+            assert_event_emitted!(Event::Delegation {
+                delegator: 2,
+                locked_amount: 10,
+                candidate: 1,
+                delegator_position: DelegatorAdded::AddedToTop { new_total: 40 },
+            });
+            assert_last_event!(MetaEvent::ParachainStaking(Event::AutoCompoundSet {
+                candidate: 1,
+                delegator: 2,
+                value: Percent::from_percent(50),
+            }));
+            assert!(ParachainStaking::is_delegator(&2));
             assert_eq!(
-                ParachainStaking::delegator_state(&2)
-                    .map(|x| x.less_total)
-                    .expect("delegator state must exist"),
-                0
+                ParachainStaking::delegation_auto_compound(&1, &2),
+                Percent::from_percent(50),
             );
         });
 }
 
 #[test]
-fn parachain_bond_inflation_reserve_matches_config() {
+fn delegate_with_auto_compound_requires_correct_weight_hint() {
     ExtBuilder::default()
-        .with_balances(vec![
-            (1, 100),
-            (2, 100),
-            (3, 100),
-            (4, 100),
-            (5, 100),
-            (6, 100),
-            (7, 100),
-            (8, 100),
-            (9, 100),
-            (10, 100),
-            (11, 1),
-        ])
-        .with_candidates(vec![(1, 20), (2, 20), (3, 20), (4, 20), (5, 10)])
-        .with_delegations(vec![
-            (6, 1, 10),
-            (7, 1, 10),
-            (8, 2, 10),
-            (9, 2, 10),
-            (10, 1, 10),
-        ])
+        .with_balances(vec![(1, 30), (2, 10), (3, 10)])
+        .with_candidates(vec![(1, 30)])
+        .with_delegations(vec![(2, 1, 10)])
         .build()
         .execute_with(|| {
-            assert_eq!(Balances::free_balance(&11), 1);
+            assert_ok!(ParachainStaking::set_auto_compound(
+                RuntimeOrigin::signed(2),
+                1,
+                Percent::from_percent(50),
+                0,
+                1
+            ));
+            assert_noop!(
+                ParachainStaking::delegate_with_auto_compound(
+                    RuntimeOrigin::signed(3),
+                    1,
+                    10,
+                    Percent::from_percent(50),
+                    1,
+                    0,
+                    0
+                ),
+                Error::<Test>::TooLowCandidateAutoCompoundingDelegationCountToDelegate
+            );
+        });
+}
+
+#[test]
+fn delegator_rewards_are_auto_compounded() {
+    ExtBuilder::default()
+        .with_balances(vec![(1, 20), (2, 40), (3, 20), (4, 20)])
+        .with_candidates(vec![(1, 20), (3, 20), (4, 20)])
+        .with_delegations(vec![(2, 1, 10), (2, 3, 10)])
+        .build()
+        .execute_with(|| {
+            // preset rewards for rounds 1, 2 and 3
+            (1..=3).for_each(|round| set_author(round, 1, 1));
+            assert_ok!(ParachainStaking::set_auto_compound(
+                RuntimeOrigin::signed(2),
+                1,
+                Percent::from_percent(100),
+                0,
+                2
+            ));
+            assert_eq!(ParachainStaking::total(), 80);
+
+            roll_to_round_begin(3);
+            assert_last_event!(MetaEvent::ParachainStaking(Event::Rewarded {
+                account: 1,
+                rewards: 4,
+            }));
+            assert_eq!(ParachainStaking::delegator_state(2).unwrap().total(), 20);
+
+            assert_ok!(ParachainStaking::claim_rewards(RuntimeOrigin::signed(2)));
+            assert_eq_last_events!(vec![
+                Event::<Test>::Rewarded {
+                    account: 2,
+                    rewards: 1,
+                },
+                Event::<Test>::DelegationIncreased {
+                    delegator: 2,
+                    candidate: 1,
+                    amount: 1,
+                    in_top: true,
+                },
+                Event::<Test>::Compounded {
+                    candidate: 1,
+                    delegator: 2,
+                    amount: 1,
+                },
+            ]);
+            assert_eq!(
+                ParachainStaking::delegator_state(2)
+                    .expect("exists")
+                    .total(),
+                21
+            );
+            assert_eq!(
+                ParachainStaking::top_delegations(1).unwrap().delegations[0].amount,
+                11
+            );
+            assert_eq!(ParachainStaking::top_delegations(1).unwrap().total, 11);
+            assert_eq!(
+                ParachainStaking::candidate_info(1)
+                    .expect("exists")
+                    .total_counted,
+                31
+            );
+            assert_eq!(ParachainStaking::total(), 81);
+        });
+}
+
+#[test]
+fn anyone_can_compound_delegator_rewards() {
+    ExtBuilder::default()
+        .with_balances(vec![(1, 20), (2, 40), (3, 20), (4, 20), (5, 10)])
+        .with_candidates(vec![(1, 20), (3, 20), (4, 20)])
+        .with_delegations(vec![(2, 1, 10), (2, 3, 10)])
+        .build()
+        .execute_with(|| {
+            (1..=3).for_each(|round| set_author(round, 1, 1));
+            assert_ok!(ParachainStaking::set_auto_compound(
+                RuntimeOrigin::signed(2),
+                1,
+                Percent::from_percent(100),
+                0,
+                2
+            ));
+            roll_to_round_begin(3);
+
+            assert_ok!(ParachainStaking::claim_rewards_for(
+                RuntimeOrigin::signed(5),
+                2
+            ));
+            assert_last_event!(MetaEvent::ParachainStaking(Event::Compounded {
+                candidate: 1,
+                delegator: 2,
+                amount: 1,
+            }));
+            assert_eq!(
+                ParachainStaking::delegator_state(2)
+                    .expect("exists")
+                    .total(),
+                21
+            );
+            assert_eq!(Balances::free_balance(&5), 10);
+            assert_noop!(
+                ParachainStaking::claim_rewards_for(RuntimeOrigin::signed(5), 6),
+                Error::<Test>::DelegatorDNE
+            );
+        });
+}
+
+#[test]
+fn delegator_rewards_are_not_compounded_when_revoke_scheduled() {
+    ExtBuilder::default()
+        .with_balances(vec![(1, 20), (2, 40), (3, 20), (4, 20)])
+        .with_candidates(vec![(1, 20), (3, 20), (4, 20)])
+        .with_delegations(vec![(2, 1, 10), (2, 3, 10)])
+        .build()
+        .execute_with(|| {
+            // preset rewards for rounds 1, 2 and 3
+            (1..=3).for_each(|round| set_author(round, 1, 1));
+            assert_ok!(ParachainStaking::set_auto_compound(
+                RuntimeOrigin::signed(2),
+                1,
+                Percent::from_percent(100),
+                0,
+                2
+            ));
+            assert_ok!(ParachainStaking::schedule_revoke_delegation(
+                RuntimeOrigin::signed(2),
+                1
+            ));
+
+            roll_to_round_begin(3);
+            assert_ok!(ParachainStaking::claim_rewards(RuntimeOrigin::signed(2)));
+            assert_eq_last_events!(vec![
+                Event::<Test>::Rewarded {
+                    account: 1,
+                    rewards: 4,
+                },
+                Event::<Test>::Rewarded {
+                    account: 2,
+                    rewards: 1,
+                },
+            ]);
+            assert_eq!(ParachainStaking::total(), 80);
+
+            assert_ok!(ParachainStaking::execute_delegation_request(
+                RuntimeOrigin::signed(2),
+                2,
+                1
+            ));
+            assert!(ParachainStaking::auto_compounding_delegations(1).is_empty());
+        });
+}
+
+#[test]
+fn execute_leave_candidates_removes_auto_compound_config() {
+    ExtBuilder::default()
+        .with_balances(vec![(1, 30), (2, 10)])
+        .with_candidates(vec![(1, 30)])
+        .with_delegations(vec![(2, 1, 10)])
+        .build()
+        .execute_with(|| {
+            assert_ok!(ParachainStaking::set_auto_compound(
+                RuntimeOrigin::signed(2),
+                1,
+                Percent::from_percent(50),
+                0,
+                1
+            ));
+            assert_ok!(ParachainStaking::schedule_leave_candidates(
+                RuntimeOrigin::signed(1),
+                1u32
+            ));
+            roll_to(10);
+            assert_ok!(ParachainStaking::execute_leave_candidates(
+                RuntimeOrigin::signed(1),
+                1,
+                1
+            ));
+            assert!(ParachainStaking::auto_compounding_delegations(1).is_empty());
+        });
+}
+
+// SLASHING
+
+fn slashing_config(tolerated_rounds: u32) -> SlashingConfig {
+    SlashingConfig {
+        min_performance: Perbill::from_percent(50),
+        tolerated_rounds,
+        slash_fraction: Perbill::from_percent(10),
+        slash_delegations: true,
+        equivocation_slash_fraction: Perbill::zero(),
+    }
+}
+
+#[test]
+fn set_slashing_config_works() {
+    ExtBuilder::default().build().execute_with(|| {
+        assert_noop!(
+            ParachainStaking::set_slashing_config(RuntimeOrigin::signed(45), slashing_config(1)),
+            sp_runtime::DispatchError::BadOrigin
+        );
+        assert_ok!(ParachainStaking::set_slashing_config(
+            RuntimeOrigin::root(),
+            slashing_config(1)
+        ));
+        assert_last_event!(MetaEvent::ParachainStaking(Event::SlashingConfigSet {
+            old: SlashingConfig::default(),
+            new: slashing_config(1),
+        }));
+        assert_eq!(ParachainStaking::slashing_info(), slashing_config(1));
+        assert_noop!(
+            ParachainStaking::set_slashing_config(RuntimeOrigin::root(), slashing_config(1)),
+            Error::<Test>::NoWritingSameValue
+        );
+        assert_noop!(
+            ParachainStaking::set_slashing_config(
+                RuntimeOrigin::root(),
+                SlashingConfig {
+                    slash_fraction: Perbill::one(),
+                    ..slashing_config(1)
+                }
+            ),
+            Error::<Test>::InvalidSlashFraction
+        );
+        assert_noop!(
+            ParachainStaking::set_slashing_config(
+                RuntimeOrigin::root(),
+                SlashingConfig {
+                    equivocation_slash_fraction: Perbill::one(),
+                    ..slashing_config(1)
+                }
+            ),
+            Error::<Test>::InvalidSlashFraction
+        );
+    });
+}
+
+#[test]
+fn underperforming_collator_is_slashed_after_deferral() {
+    ExtBuilder::default()
+        .with_balances(vec![(1, 20), (2, 20), (3, 10)])
+        .with_candidates(vec![(1, 20), (2, 20)])
+        .with_delegations(vec![(3, 1, 10)])
+        .build()
+        .execute_with(|| {
+            assert_ok!(ParachainStaking::set_slashing_config(
+                RuntimeOrigin::root(),
+                slashing_config(1)
+            ));
+            // 2 blocks are expected from each collator, only 2 produces them in round 1
+            set_author(1, 2, 40);
+            (2..=3).for_each(|round| {
+                set_author(round, 1, 40);
+                set_author(round, 2, 40);
+            });
+
+            roll_to_round_begin(2);
+            assert_event_emitted!(Event::SlashDeferred {
+                collator: 1,
+                apply_round: 3,
+                fraction: Perbill::from_percent(10),
+            });
+            assert_eq!(ParachainStaking::unapplied_slashes(3).len(), 1);
+            assert_eq!(ParachainStaking::candidate_info(1).unwrap().bond, 20);
+
+            roll_to_round_begin(3);
+            assert_event_emitted!(Event::Slashed {
+                account: 1,
+                amount: 2,
+            });
+            assert_event_emitted!(Event::Slashed {
+                account: 3,
+                amount: 1,
+            });
+            assert!(ParachainStaking::unapplied_slashes(3).is_empty());
+            let candidate = ParachainStaking::candidate_info(1).unwrap();
+            assert_eq!(candidate.bond, 18);
+            assert_eq!(candidate.total_counted, 27);
+            assert_eq!(ParachainStaking::top_delegations(1).unwrap().total, 9);
+            assert_eq!(
+                ParachainStaking::delegator_state(3)
+                    .expect("exists")
+                    .total(),
+                9
+            );
+            assert_eq!(
+                crate::mock::query_lock_amount(3, DELEGATOR_LOCK_ID),
+                Some(9)
+            );
+            assert_eq!(Balances::free_balance(&1), 18);
+            assert_eq!(Balances::free_balance(&3), 9);
+            assert_eq!(ParachainStaking::total(), 47);
+        });
+}
+
+#[test]
+fn collator_is_not_slashed_within_tolerated_rounds() {
+    ExtBuilder::default()
+        .with_balances(vec![(1, 20), (2, 20)])
+        .with_candidates(vec![(1, 20), (2, 20)])
+        .build()
+        .execute_with(|| {
+            assert_ok!(ParachainStaking::set_slashing_config(
+                RuntimeOrigin::root(),
+                slashing_config(2)
+            ));
+            set_author(1, 2, 40);
+            set_author(2, 1, 40);
+            set_author(2, 2, 40);
+
+            roll_to_round_begin(2);
+            assert_eq!(ParachainStaking::underperforming_rounds(1), 1);
+            assert_eq!(ParachainStaking::underperforming_rounds(2), 0);
+
+            roll_to_round_begin(3);
+            assert_eq!(ParachainStaking::underperforming_rounds(1), 0);
+            assert!(ParachainStaking::unapplied_slashes(3).is_empty());
+            assert!(ParachainStaking::unapplied_slashes(4).is_empty());
+        });
+}
+
+#[test]
+fn cancel_deferred_slash_prevents_slash() {
+    ExtBuilder::default()
+        .with_balances(vec![(1, 20), (2, 20), (3, 10)])
+        .with_candidates(vec![(1, 20), (2, 20)])
+        .with_delegations(vec![(3, 1, 10)])
+        .build()
+        .execute_with(|| {
+            assert_ok!(ParachainStaking::set_slashing_config(
+                RuntimeOrigin::root(),
+                slashing_config(1)
+            ));
+            set_author(1, 2, 40);
+            (2..=3).for_each(|round| {
+                set_author(round, 1, 40);
+                set_author(round, 2, 40);
+            });
+
+            roll_to_round_begin(2);
+            assert_noop!(
+                ParachainStaking::cancel_deferred_slash(RuntimeOrigin::root(), 3, vec![]),
+                Error::<Test>::EmptySlashIndices
+            );
+            assert_noop!(
+                ParachainStaking::cancel_deferred_slash(RuntimeOrigin::root(), 3, vec![0, 0]),
+                Error::<Test>::NotSortedAndUnique
+            );
+            assert_noop!(
+                ParachainStaking::cancel_deferred_slash(RuntimeOrigin::root(), 3, vec![1]),
+                Error::<Test>::InvalidSlashIndex
+            );
+            assert_ok!(ParachainStaking::cancel_deferred_slash(
+                RuntimeOrigin::root(),
+                3,
+                vec![0]
+            ));
+            assert_last_event!(MetaEvent::ParachainStaking(Event::SlashCancelled {
+                collator: 1,
+                apply_round: 3,
+            }));
+
+            roll_to_round_begin(3);
+            assert_eq!(ParachainStaking::candidate_info(1).unwrap().bond, 20);
+            assert_eq!(ParachainStaking::total(), 50);
+        });
+}
+
+#[test]
+fn slash_clamps_or_cancels_candidate_bond_less_request() {
+    ExtBuilder::default()
+        .with_balances(vec![(1, 20), (2, 11), (3, 20)])
+        .with_candidates(vec![(1, 20), (2, 11), (3, 20)])
+        .build()
+        .execute_with(|| {
+            assert_ok!(ParachainStaking::set_slashing_config(
+                RuntimeOrigin::root(),
+                slashing_config(1)
+            ));
+            assert_ok!(ParachainStaking::schedule_candidate_bond_less(
+                RuntimeOrigin::signed(1),
+                10
+            ));
+            assert_ok!(ParachainStaking::schedule_candidate_bond_less(
+                RuntimeOrigin::signed(2),
+                1
+            ));
+            set_author(1, 3, 40);
+            (2..=3).for_each(|round| {
+                (1..=3).for_each(|collator| set_author(round, collator, 40));
+            });
+
+            roll_to_round_begin(3);
+            // 18 are left, 8 of which are above the minimum
+            let candidate = ParachainStaking::candidate_info(1).unwrap();
+            assert_eq!(candidate.bond, 18);
+            assert_eq!(
+                candidate.request,
+                Some(CandidateBondLessRequest {
+                    amount: 8,
+                    when_executable: 3,
+                })
+            );
+            // nothing is left above the minimum
+            assert_event_emitted!(Event::CancelledCandidateBondLess {
+                candidate: 2,
+                amount: 1,
+                execute_round: 3,
+            });
+            let candidate = ParachainStaking::candidate_info(2).unwrap();
+            assert_eq!(candidate.bond, 10);
+            assert_eq!(candidate.request, None);
+            assert!(candidate.is_active());
+
+            assert_ok!(ParachainStaking::execute_candidate_bond_less(
+                RuntimeOrigin::signed(1),
+                1
+            ));
+            assert_eq!(ParachainStaking::candidate_info(1).unwrap().bond, 10);
+        });
+}
+
+#[test]
+fn slash_below_min_candidate_stake_goes_offline() {
+    ExtBuilder::default()
+        .with_balances(vec![(1, 10), (2, 20)])
+        .with_candidates(vec![(1, 10), (2, 20)])
+        .build()
+        .execute_with(|| {
+            assert_ok!(ParachainStaking::set_slashing_config(
+                RuntimeOrigin::root(),
+                slashing_config(1)
+            ));
+            set_author(1, 2, 40);
+            (2..=3).for_each(|round| {
+                set_author(round, 1, 40);
+                set_author(round, 2, 40);
+            });
+
+            roll_to_round_begin(3);
+            assert_event_emitted!(Event::Slashed {
+                account: 1,
+                amount: 1,
+            });
+            assert_event_emitted!(Event::CandidateWentOffline { candidate: 1 });
+            let candidate = ParachainStaking::candidate_info(1).unwrap();
+            assert_eq!(candidate.bond, 9);
+            assert!(!candidate.is_active());
+            assert!(!ParachainStaking::candidate_pool()
+                .0
+                .iter()
+                .any(|bond| bond.owner == 1));
+        });
+}
+
+#[test]
+fn slashed_candidate_must_bond_more_before_going_online() {
+    ExtBuilder::default()
+        .with_balances(vec![(1, 20), (2, 20)])
+        .with_candidates(vec![(1, 10), (2, 20)])
+        .build()
+        .execute_with(|| {
+            assert_ok!(ParachainStaking::set_slashing_config(
+                RuntimeOrigin::root(),
+                slashing_config(1)
+            ));
+            set_author(1, 2, 40);
+            (2..=3).for_each(|round| {
+                set_author(round, 1, 40);
+                set_author(round, 2, 40);
+            });
+
+            roll_to_round_begin(3);
+            assert_eq!(ParachainStaking::candidate_info(1).unwrap().bond, 9);
+            assert_noop!(
+                ParachainStaking::go_online(RuntimeOrigin::signed(1)),
+                Error::<Test>::CandidateBondBelowMin
+            );
+
+            assert_ok!(ParachainStaking::candidate_bond_more(
+                RuntimeOrigin::signed(1),
+                1
+            ));
+            assert_ok!(ParachainStaking::go_online(RuntimeOrigin::signed(1)));
+            assert!(ParachainStaking::candidate_info(1).unwrap().is_active());
+            assert!(ParachainStaking::candidate_pool()
+                .0
+                .iter()
+                .any(|bond| bond.owner == 1));
+        });
+}
+
+#[test]
+fn reported_equivocation_is_slashed_after_deferral() {
+    ExtBuilder::default()
+        .with_balances(vec![(1, 20), (2, 20), (3, 10)])
+        .with_candidates(vec![(1, 20), (2, 20)])
+        .with_delegations(vec![(3, 1, 10)])
+        .build()
+        .execute_with(|| {
+            assert_noop!(
+                ParachainStaking::report_equivocation(RuntimeOrigin::root(), 1, 1),
+                Error::<Test>::EquivocationSlashingDisabled
+            );
+            assert_ok!(ParachainStaking::set_slashing_config(
+                RuntimeOrigin::root(),
+                SlashingConfig {
+                    min_performance: Perbill::zero(),
+                    equivocation_slash_fraction: Perbill::from_percent(10),
+                    ..slashing_config(1)
+                }
+            ));
+            assert_noop!(
+                ParachainStaking::report_equivocation(RuntimeOrigin::signed(2), 1, 1),
+                sp_runtime::DispatchError::BadOrigin
+            );
+            assert_noop!(
+                ParachainStaking::report_equivocation(RuntimeOrigin::root(), 1, 2),
+                Error::<Test>::CollatorSnapshotDNE
+            );
+
+            assert_ok!(ParachainStaking::report_equivocation(
+                RuntimeOrigin::root(),
+                1,
+                1
+            ));
+            assert_event_emitted!(Event::EquivocationReported {
+                collator: 1,
+                round: 1,
+            });
+            assert_last_event!(MetaEvent::ParachainStaking(Event::SlashDeferred {
+                collator: 1,
+                apply_round: 3,
+                fraction: Perbill::from_percent(10),
+            }));
+            assert_noop!(
+                ParachainStaking::report_equivocation(RuntimeOrigin::root(), 1, 1),
+                Error::<Test>::EquivocationAlreadyReported
+            );
+
+            roll_to_round_begin(3);
+            assert_event_emitted!(Event::Slashed {
+                account: 1,
+                amount: 2,
+            });
+            assert_event_emitted!(Event::Slashed {
+                account: 3,
+                amount: 1,
+            });
+            assert_eq!(ParachainStaking::candidate_info(1).unwrap().bond, 18);
+            assert_eq!(ParachainStaking::total(), 47);
+        });
+}
+
+// RUNTIME API
+
+#[test]
+fn delegator_pending_rewards_tracks_unpaid_rounds() {
+    ExtBuilder::default()
+        .with_balances(vec![(1, 20), (2, 40), (3, 20), (4, 20)])
+        .with_candidates(vec![(1, 20), (3, 20), (4, 20)])
+        .with_delegations(vec![(2, 1, 10), (2, 3, 10)])
+        .build()
+        .execute_with(|| {
+            (1..=3).for_each(|round| set_author(round, 1, 1));
+            assert_eq!(ParachainStaking::delegator_pending_rewards(&2), 0);
+
+            roll_to_round_begin(2);
+            assert_eq!(ParachainStaking::delegator_pending_rewards(&2), 1);
+            assert_eq!(ParachainStaking::delegator_pending_rewards(&4), 0);
+
+            // round 1 is paid out but not claimed, round 2 is still pending
+            roll_to_round_begin(3);
+            assert_eq!(ParachainStaking::delegator_pending_rewards(&2), 2);
+            assert_ok!(ParachainStaking::claim_rewards(RuntimeOrigin::signed(2)));
+            assert_last_event!(MetaEvent::ParachainStaking(Event::Rewarded {
+                account: 2,
+                rewards: 1,
+            }));
+            assert_eq!(ParachainStaking::delegator_pending_rewards(&2), 1);
+        });
+}
+
+#[test]
+fn candidate_expected_apy_is_zero_for_inactive_or_unselected_candidates() {
+    ExtBuilder::default()
+        .with_balances(vec![(1, 20), (2, 40), (3, 20), (4, 20), (5, 20)])
+        .with_candidates(vec![(1, 20), (3, 20), (4, 20)])
+        .with_delegations(vec![(2, 1, 10)])
+        .build()
+        .execute_with(|| {
+            // the unrealistic round inflation of the mock yields more than 100% a year
+            assert!(ParachainStaking::candidate_expected_apy(&1).unwrap() > FixedU128::one());
+            assert_eq!(ParachainStaking::candidate_expected_apy(&2), None);
+
+            assert_ok!(ParachainStaking::go_offline(RuntimeOrigin::signed(3)));
+            assert_eq!(
+                ParachainStaking::candidate_expected_apy(&3),
+                Some(FixedU128::zero())
+            );
+            assert_ok!(ParachainStaking::schedule_leave_candidates(
+                RuntimeOrigin::signed(4),
+                2u32
+            ));
+            assert_eq!(
+                ParachainStaking::candidate_expected_apy(&4),
+                Some(FixedU128::zero())
+            );
+            // not selected before the next round
+            assert_ok!(ParachainStaking::join_candidates(
+                RuntimeOrigin::signed(5),
+                20u128,
+                1u32
+            ));
+            assert_eq!(
+                ParachainStaking::candidate_expected_apy(&5),
+                Some(FixedU128::zero())
+            );
+        });
+}
+
+#[test]
+fn candidate_scheduled_requests_and_projected_selection() {
+    ExtBuilder::default()
+        .with_balances(vec![(1, 20), (2, 40), (3, 20), (4, 20)])
+        .with_candidates(vec![(1, 20), (3, 20), (4, 20)])
+        .with_delegations(vec![(2, 1, 10), (2, 3, 10)])
+        .build()
+        .execute_with(|| {
+            assert_ok!(ParachainStaking::schedule_revoke_delegation(
+                RuntimeOrigin::signed(2),
+                1
+            ));
+            assert_eq!(
+                ParachainStaking::delegation_scheduled_requests(&1),
+                vec![ScheduledRequest {
+                    delegator: 2,
+                    when_executable: 3,
+                    action: DelegationAction::Revoke(10),
+                }],
+            );
+            assert_eq!(ParachainStaking::compute_top_candidates(), vec![1, 3, 4]);
+            assert_ok!(ParachainStaking::schedule_leave_candidates(
+                RuntimeOrigin::signed(4),
+                3
+            ));
+            assert_eq!(ParachainStaking::compute_top_candidates(), vec![1, 3]);
+        });
+}
+
+// REDELEGATE
+
+#[test]
+fn redelegate_moves_full_delegation() {
+    ExtBuilder::default()
+        .with_balances(vec![(1, 20), (2, 20), (3, 10)])
+        .with_candidates(vec![(1, 20), (2, 20)])
+        .with_delegations(vec![(3, 1, 10)])
+        .build()
+        .execute_with(|| {
+            assert_ok!(ParachainStaking::redelegate(
+                RuntimeOrigin::signed(3),
+                1,
+                2,
+                10
+            ));
+            assert_last_event!(MetaEvent::ParachainStaking(Event::Redelegated {
+                delegator: 3,
+                from: 1,
+                to: 2,
+                amount: 10,
+            }));
+            let state = ParachainStaking::delegator_state(3).unwrap();
+            assert_eq!(state.get_bond_amount(&1), None);
+            assert_eq!(state.get_bond_amount(&2), Some(10));
+            assert_eq!(state.total(), 10);
+            assert_eq!(
+                ParachainStaking::candidate_info(1).unwrap().total_counted,
+                20
+            );
+            assert_eq!(
+                ParachainStaking::candidate_info(2).unwrap().total_counted,
+                30
+            );
+            assert_eq!(ParachainStaking::total(), 50);
+            assert_eq!(
+                crate::mock::query_lock_amount(3, DELEGATOR_LOCK_ID),
+                Some(10)
+            );
+        });
+}
+
+#[test]
+fn redelegate_moves_partial_delegation_into_existing_one() {
+    ExtBuilder::default()
+        .with_balances(vec![(1, 20), (2, 20), (3, 20)])
+        .with_candidates(vec![(1, 20), (2, 20)])
+        .with_delegations(vec![(3, 1, 10), (3, 2, 5)])
+        .build()
+        .execute_with(|| {
+            assert_ok!(ParachainStaking::redelegate(
+                RuntimeOrigin::signed(3),
+                1,
+                2,
+                4
+            ));
+            let state = ParachainStaking::delegator_state(3).unwrap();
+            assert_eq!(state.get_bond_amount(&1), Some(6));
+            assert_eq!(state.get_bond_amount(&2), Some(9));
+            assert_eq!(state.total(), 15);
+            assert_eq!(
+                ParachainStaking::candidate_info(1).unwrap().total_counted,
+                26
+            );
+            assert_eq!(
+                ParachainStaking::candidate_info(2).unwrap().total_counted,
+                29
+            );
+            assert_eq!(ParachainStaking::total(), 55);
+        });
+}
+
+#[test]
+fn redelegate_respects_cooldown() {
+    ExtBuilder::default()
+        .with_balances(vec![(1, 20), (2, 20), (3, 10)])
+        .with_candidates(vec![(1, 20), (2, 20)])
+        .with_delegations(vec![(3, 1, 10)])
+        .build()
+        .execute_with(|| {
+            assert_ok!(ParachainStaking::redelegate(
+                RuntimeOrigin::signed(3),
+                1,
+                2,
+                10
+            ));
+            assert_eq!(ParachainStaking::last_redelegation(3), Some(1));
+            roll_to_round_begin(2);
+            assert_noop!(
+                ParachainStaking::redelegate(RuntimeOrigin::signed(3), 2, 1, 10),
+                Error::<Test>::RedelegationCooldownNotElapsed
+            );
+            roll_to_round_begin(3);
+            assert_ok!(ParachainStaking::redelegate(
+                RuntimeOrigin::signed(3),
+                2,
+                1,
+                10
+            ));
+        });
+}
+
+#[test]
+fn redelegate_fails_for_invalid_requests() {
+    ExtBuilder::default()
+        .with_balances(vec![(1, 20), (2, 20), (3, 20), (4, 10)])
+        .with_candidates(vec![(1, 20), (2, 20)])
+        .with_delegations(vec![(3, 1, 10), (4, 1, 10)])
+        .build()
+        .execute_with(|| {
+            assert_noop!(
+                ParachainStaking::redelegate(RuntimeOrigin::signed(3), 1, 1, 10),
+                Error::<Test>::CannotRedelegateToSameCandidate
+            );
+            assert_noop!(
+                ParachainStaking::redelegate(RuntimeOrigin::signed(3), 1, 2, 11),
+                Error::<Test>::InsufficientBalance
+            );
+            assert_noop!(
+                ParachainStaking::redelegate(RuntimeOrigin::signed(3), 1, 2, 8),
+                Error::<Test>::DelegationBelowMin
+            );
+            assert_noop!(
+                ParachainStaking::redelegate(RuntimeOrigin::signed(3), 2, 1, 5),
+                Error::<Test>::DelegationDNE
+            );
+            assert_noop!(
+                ParachainStaking::redelegate(RuntimeOrigin::signed(3), 1, 4, 5),
+                Error::<Test>::CandidateDNE
+            );
+            assert_ok!(ParachainStaking::schedule_revoke_delegation(
+                RuntimeOrigin::signed(4),
+                1
+            ));
+            assert_noop!(
+                ParachainStaking::redelegate(RuntimeOrigin::signed(4), 1, 2, 10),
+                Error::<Test>::PendingDelegationRequestAlreadyExists
+            );
+        });
+}
+
+#[test]
+fn redelegate_fails_into_delegation_with_pending_revoke() {
+    ExtBuilder::default()
+        .with_balances(vec![(1, 20), (2, 20), (3, 20)])
+        .with_candidates(vec![(1, 20), (2, 20)])
+        .with_delegations(vec![(3, 1, 10), (3, 2, 10)])
+        .build()
+        .execute_with(|| {
+            assert_ok!(ParachainStaking::schedule_revoke_delegation(
+                RuntimeOrigin::signed(3),
+                2
+            ));
+            assert_noop!(
+                ParachainStaking::redelegate(RuntimeOrigin::signed(3), 1, 2, 10),
+                Error::<Test>::PendingDelegationRevoke
+            );
+            // the revoke still leaves the whole target delegation
+            roll_to(10);
+            assert_ok!(ParachainStaking::execute_delegation_request(
+                RuntimeOrigin::signed(3),
+                3,
+                2
+            ));
+            assert_eq!(ParachainStaking::delegator_state(3).unwrap().total(), 10);
+            assert_eq!(
+                ParachainStaking::candidate_info(2).unwrap().total_counted,
+                20
+            );
+            assert_eq!(ParachainStaking::total(), 50);
+        });
+}
+
+// COMMISSION
+
+#[test]
+fn set_commission_bounds_works() {
+    ExtBuilder::default().build().execute_with(|| {
+        assert_eq!(
+            ParachainStaking::commission_bounds(),
+            (Perbill::zero(), Perbill::one())
+        );
+        assert_noop!(
+            ParachainStaking::set_commission_bounds(
+                RuntimeOrigin::signed(1),
+                Perbill::from_percent(5),
+                Perbill::from_percent(30)
+            ),
+            sp_runtime::DispatchError::BadOrigin
+        );
+        assert_noop!(
+            ParachainStaking::set_commission_bounds(
+                RuntimeOrigin::root(),
+                Perbill::from_percent(30),
+                Perbill::from_percent(5)
+            ),
+            Error::<Test>::InvalidCommissionBounds
+        );
+        assert_ok!(ParachainStaking::set_commission_bounds(
+            RuntimeOrigin::root(),
+            Perbill::from_percent(5),
+            Perbill::from_percent(30)
+        ));
+        assert_last_event!(MetaEvent::ParachainStaking(Event::CommissionBoundsSet {
+            min: Perbill::from_percent(5),
+            max: Perbill::from_percent(30),
+        }));
+        assert_noop!(
+            ParachainStaking::set_commission_bounds(
+                RuntimeOrigin::root(),
+                Perbill::from_percent(5),
+                Perbill::from_percent(30)
+            ),
+            Error::<Test>::NoWritingSameValue
+        );
+    });
+}
+
+#[test]
+fn candidates_join_with_default_commission() {
+    ExtBuilder::default()
+        .with_balances(vec![(1, 20), (2, 20)])
+        .with_candidates(vec![(1, 20)])
+        .build()
+        .execute_with(|| {
+            assert_eq!(
+                ParachainStaking::candidate_info(1).unwrap().commission,
+                Perbill::from_percent(20)
+            );
+            assert_ok!(ParachainStaking::set_commission_bounds(
+                RuntimeOrigin::root(),
+                Perbill::from_percent(5),
+                Perbill::from_percent(10)
+            ));
+            assert_ok!(ParachainStaking::join_candidates(
+                RuntimeOrigin::signed(2),
+                20,
+                1
+            ));
+            assert_eq!(
+                ParachainStaking::candidate_info(2).unwrap().commission,
+                Perbill::from_percent(10)
+            );
+        });
+}
+
+#[test]
+fn commission_change_is_delayed() {
+    ExtBuilder::default()
+        .with_balances(vec![(1, 20)])
+        .with_candidates(vec![(1, 20)])
+        .build()
+        .execute_with(|| {
+            assert_noop!(
+                ParachainStaking::schedule_commission_change(
+                    RuntimeOrigin::signed(1),
+                    Perbill::from_percent(20)
+                ),
+                Error::<Test>::NoWritingSameValue
+            );
+            assert_ok!(ParachainStaking::schedule_commission_change(
+                RuntimeOrigin::signed(1),
+                Perbill::from_percent(10)
+            ));
+            assert_last_event!(MetaEvent::ParachainStaking(
+                Event::CommissionChangeScheduled {
+                    candidate: 1,
+                    commission: Perbill::from_percent(10),
+                    execute_round: 3,
+                }
+            ));
+            assert_noop!(
+                ParachainStaking::schedule_commission_change(
+                    RuntimeOrigin::signed(1),
+                    Perbill::from_percent(15)
+                ),
+                Error::<Test>::PendingCommissionChangeAlreadyExists
+            );
+            assert_noop!(
+                ParachainStaking::execute_commission_change(RuntimeOrigin::signed(2), 1),
+                Error::<Test>::PendingCommissionChangeNotDueYet
+            );
+            roll_to_round_begin(3);
+            assert_ok!(ParachainStaking::execute_commission_change(
+                RuntimeOrigin::signed(2),
+                1
+            ));
+            assert_last_event!(MetaEvent::ParachainStaking(Event::CommissionChanged {
+                candidate: 1,
+                old: Perbill::from_percent(20),
+                new: Perbill::from_percent(10),
+            }));
+            let state = ParachainStaking::candidate_info(1).unwrap();
+            assert_eq!(state.commission, Perbill::from_percent(10));
+            assert_eq!(state.commission_request, None);
+        });
+}
+
+#[test]
+fn commission_change_respects_bounds_and_can_be_cancelled() {
+    ExtBuilder::default()
+        .with_balances(vec![(1, 20)])
+        .with_candidates(vec![(1, 20)])
+        .build()
+        .execute_with(|| {
+            assert_ok!(ParachainStaking::set_commission_bounds(
+                RuntimeOrigin::root(),
+                Perbill::from_percent(5),
+                Perbill::from_percent(30)
+            ));
+            assert_noop!(
+                ParachainStaking::schedule_commission_change(
+                    RuntimeOrigin::signed(1),
+                    Perbill::from_percent(40)
+                ),
+                Error::<Test>::CommissionOutOfBounds
+            );
+            assert_noop!(
+                ParachainStaking::cancel_commission_change(RuntimeOrigin::signed(1)),
+                Error::<Test>::PendingCommissionChangeDNE
+            );
+            assert_ok!(ParachainStaking::schedule_commission_change(
+                RuntimeOrigin::signed(1),
+                Perbill::from_percent(25)
+            ));
+            assert_ok!(ParachainStaking::cancel_commission_change(
+                RuntimeOrigin::signed(1)
+            ));
+            assert_last_event!(MetaEvent::ParachainStaking(
+                Event::CommissionChangeCancelled {
+                    candidate: 1,
+                    commission: Perbill::from_percent(25),
+                    execute_round: 3,
+                }
+            ));
+            assert_ok!(ParachainStaking::schedule_commission_change(
+                RuntimeOrigin::signed(1),
+                Perbill::from_percent(25)
+            ));
+            // bounds tightened after scheduling
+            assert_ok!(ParachainStaking::set_commission_bounds(
+                RuntimeOrigin::root(),
+                Perbill::from_percent(5),
+                Perbill::from_percent(15)
+            ));
+            roll_to_round_begin(3);
+            assert_noop!(
+                ParachainStaking::execute_commission_change(RuntimeOrigin::signed(1), 1),
+                Error::<Test>::CommissionOutOfBounds
+            );
+            // the snapshot clamps the commission into the bounds
+            assert_eq!(
+                ParachainStaking::at_stake(3, 1).commission,
+                Perbill::from_percent(15)
+            );
+        });
+}
+
+#[test]
+fn payouts_use_candidate_commission() {
+    ExtBuilder::default()
+        .with_balances(vec![(1, 20), (2, 40), (3, 20), (4, 20)])
+        .with_candidates(vec![(1, 20), (3, 20), (4, 20)])
+        .with_delegations(vec![(2, 1, 10), (2, 3, 10)])
+        .build()
+        .execute_with(|| {
+            assert_ok!(ParachainStaking::schedule_commission_change(
+                RuntimeOrigin::signed(1),
+                Perbill::one()
+            ));
+            roll_to_round_begin(3);
+            assert_ok!(ParachainStaking::execute_commission_change(
+                RuntimeOrigin::signed(1),
+                1
+            ));
+            set_author(4, 1, 1);
+            roll_to_round_begin(4);
+            assert_eq!(ParachainStaking::at_stake(4, 1).commission, Perbill::one());
+            assert_eq!(
+                ParachainStaking::at_stake(4, 3).commission,
+                Perbill::from_percent(20)
+            );
+
+            // the collator takes the whole reward of the round
+            roll_to_round_begin(6);
+            assert_ok!(ParachainStaking::claim_rewards(RuntimeOrigin::signed(2)));
+            assert!(events()
+                .iter()
+                .any(|e| matches!(e, Event::Rewarded { account: 1, .. })));
+            assert!(!events()
+                .iter()
+                .any(|e| matches!(e, Event::Rewarded { account: 2, .. })));
+        });
+}
+
+// INFLATION CURVE
+
+#[test]
+fn set_inflation_curve_works() {
+    ExtBuilder::default().build().execute_with(|| {
+        let curve = InflationCurve {
+            ideal_staked: Perbill::from_percent(50),
+            falloff: Perbill::from_percent(10),
+        };
+        assert_noop!(
+            ParachainStaking::set_inflation_curve(RuntimeOrigin::signed(1), Some(curve)),
+            sp_runtime::DispatchError::BadOrigin
+        );
+        assert_noop!(
+            ParachainStaking::set_inflation_curve(
+                RuntimeOrigin::root(),
+                Some(InflationCurve {
+                    ideal_staked: Perbill::from_percent(50),
+                    falloff: Perbill::zero(),
+                })
+            ),
+            Error::<Test>::InvalidInflationCurve
+        );
+        assert_noop!(
+            ParachainStaking::set_inflation_curve(RuntimeOrigin::root(), None),
+            Error::<Test>::NoWritingSameValue
+        );
+        assert_ok!(ParachainStaking::set_inflation_curve(
+            RuntimeOrigin::root(),
+            Some(curve)
+        ));
+        assert_last_event!(MetaEvent::ParachainStaking(Event::InflationCurveSet {
+            old: None,
+            new: Some(curve),
+        }));
+        assert_eq!(ParachainStaking::inflation_curve(), Some(curve));
+        assert_ok!(ParachainStaking::set_inflation_curve(
+            RuntimeOrigin::root(),
+            None
+        ));
+        assert_last_event!(MetaEvent::ParachainStaking(Event::InflationCurveSet {
+            old: Some(curve),
+            new: None,
+        }));
+    });
+}
+
+fn round_issuance_with_inflation_curve(curve: Option<InflationCurve>) -> u128 {
+    let round = Range {
+        min: Perbill::from_percent(1),
+        ideal: Perbill::from_percent(5),
+        max: Perbill::from_percent(5),
+    };
+    ExtBuilder::default()
+        .with_balances(vec![(1, 100), (2, 100), (3, 200)])
+        .with_candidates(vec![(1, 100), (2, 100)])
+        .with_inflation(InflationInfo {
+            expect: Range {
+                min: 700,
+                ideal: 700,
+                max: 700,
+            },
+            annual: round,
+            round,
+        })
+        .build()
+        .execute_with(|| {
+            assert_ok!(ParachainStaking::set_inflation_curve(
+                RuntimeOrigin::root(),
+                curve
+            ));
+            set_author(1, 1, 1);
+            set_author(1, 2, 1);
+            roll_to_round_begin(3);
+            ParachainStaking::delayed_payouts(1)
+                .expect("payouts of round 1 are pending")
+                .round_issuance
+        })
+}
+
+#[test]
+fn round_issuance_follows_inflation_curve() {
+    // half of the issuance is staked, below the expectations
+    assert_eq!(round_issuance_with_inflation_curve(None), 4);
+    // peak inflation at the ideal staked ratio
+    assert_eq!(
+        round_issuance_with_inflation_curve(Some(InflationCurve {
+            ideal_staked: Perbill::from_percent(50),
+            falloff: Perbill::from_percent(10),
+        })),
+        20
+    );
+    // one falloff past the ideal halves the excess over the minimum
+    assert_eq!(
+        round_issuance_with_inflation_curve(Some(InflationCurve {
+            ideal_staked: Perbill::from_percent(40),
+            falloff: Perbill::from_percent(10),
+        })),
+        12
+    );
+    // linear growth below the ideal
+    assert_eq!(
+        round_issuance_with_inflation_curve(Some(InflationCurve {
+            ideal_staked: Perbill::from_percent(100),
+            falloff: Perbill::from_percent(10),
+        })),
+        12
+    );
+}
+
+// ~~ PROPERTY-BASED TESTS ~~
+
+// LAZY REWARDS
+
+#[test]
+fn delegator_rewards_accrue_until_claimed() {
+    ExtBuilder::default()
+        .with_balances(vec![(1, 20), (2, 40), (3, 20), (4, 20)])
+        .with_candidates(vec![(1, 20), (3, 20), (4, 20)])
+        .with_delegations(vec![(2, 1, 10), (2, 3, 10)])
+        .build()
+        .execute_with(|| {
+            set_author(1, 1, 1);
+            roll_to_round_begin(3);
+            assert!(!events()
+                .iter()
+                .any(|e| matches!(e, Event::Rewarded { account: 2, .. })));
+            // the pot is endowed with the existential deposit on its first deposit and the
+            // reward of 1.33 is minted rounded up
+            assert_eq!(Balances::free_balance(&ParachainStaking::reward_pot()), 3);
+            assert_eq!(Balances::free_balance(&2), 40);
+
+            assert_ok!(ParachainStaking::claim_rewards(RuntimeOrigin::signed(2)));
+            assert_last_event!(MetaEvent::ParachainStaking(Event::Rewarded {
+                account: 2,
+                rewards: 1,
+            }));
+            assert_eq!(Balances::free_balance(&2), 41);
+            assert_eq!(Balances::free_balance(&ParachainStaking::reward_pot()), 2);
+
+            // nothing is left to claim
+            let num_events = events().len();
+            assert_ok!(ParachainStaking::claim_rewards(RuntimeOrigin::signed(2)));
+            assert_eq!(events().len(), num_events);
+            assert_noop!(
+                ParachainStaking::claim_rewards(RuntimeOrigin::signed(1)),
+                Error::<Test>::DelegatorDNE
+            );
+        });
+}
+
+#[test]
+fn delegator_rewards_are_only_paid_from_the_reward_pot() {
+    ExtBuilder::default()
+        .with_balances(vec![(1, 20), (2, 40), (3, 20), (4, 20)])
+        .with_candidates(vec![(1, 20), (3, 20), (4, 20)])
+        .with_delegations(vec![(2, 1, 10), (2, 3, 10)])
+        .build()
+        .execute_with(|| {
+            set_author(1, 1, 1);
+            roll_to_round_begin(3);
+            crate::UnclaimedRewards::<Test>::insert(2, 5);
+            let issuance = Balances::total_issuance();
+
+            assert_ok!(ParachainStaking::claim_rewards(RuntimeOrigin::signed(2)));
+            assert_last_event!(MetaEvent::ParachainStaking(Event::Rewarded {
+                account: 2,
+                rewards: 2,
+            }));
+            assert_eq!(Balances::free_balance(&2), 42);
+            // nothing is minted and the pot is kept alive
+            assert_eq!(Balances::total_issuance(), issuance);
+            assert_eq!(Balances::free_balance(&ParachainStaking::reward_pot()), 1);
+            assert_eq!(ParachainStaking::unclaimed_rewards(2), 4);
+        });
+}
+
+#[test]
+fn delegators_can_claim_all_rewards_after_many_rounds() {
+    ExtBuilder::default()
+        .with_balances(vec![(1, 20), (2, 40), (3, 40), (4, 40)])
+        .with_candidates(vec![(1, 20)])
+        .with_delegations(vec![(2, 1, 10), (3, 1, 10), (4, 1, 10)])
+        .build()
+        .execute_with(|| {
+            // each delegation is owed about 1.2 per round, rounded once over all rounds when claimed
+            (1..=18).for_each(|round| set_author(round, 1, 1));
+            roll_to_round_begin(22);
+
+            for delegator in [2, 3, 4] {
+                assert!(ParachainStaking::accrued_delegator_rewards(&delegator) > 0);
+                assert_ok!(ParachainStaking::claim_rewards(RuntimeOrigin::signed(
+                    delegator
+                )));
+                assert_eq!(ParachainStaking::unclaimed_rewards(delegator), 0);
+                assert_eq!(ParachainStaking::accrued_delegator_rewards(&delegator), 0);
+                assert!(Balances::free_balance(&delegator) > 40);
+            }
+            // the three delegations are owed the same
+            assert_eq!(Balances::free_balance(&2), Balances::free_balance(&4));
+            assert!(Balances::free_balance(&ParachainStaking::reward_pot()) >= 1);
+        });
+}
+
+#[test]
+fn bond_change_settles_accrued_rewards() {
+    ExtBuilder::default()
+        .with_balances(vec![(1, 20), (2, 40), (3, 20), (4, 20)])
+        .with_candidates(vec![(1, 20), (3, 20), (4, 20)])
+        .with_delegations(vec![(2, 1, 10), (2, 3, 10)])
+        .build()
+        .execute_with(|| {
+            set_author(1, 1, 1);
+            roll_to_round_begin(3);
+            assert_ok!(ParachainStaking::delegator_bond_more(
+                RuntimeOrigin::signed(2),
+                1,
+                10
+            ));
+            assert_eq!(ParachainStaking::unclaimed_rewards(2), 1);
+            assert_eq!(Balances::free_balance(&2), 40);
+
+            assert_ok!(ParachainStaking::claim_rewards(RuntimeOrigin::signed(2)));
+            assert_last_event!(MetaEvent::ParachainStaking(Event::Rewarded {
+                account: 2,
+                rewards: 1,
+            }));
+            assert_eq!(ParachainStaking::unclaimed_rewards(2), 0);
+            assert_eq!(Balances::free_balance(&2), 41);
+        });
+}
+
+#[test]
+fn bond_change_keeps_counted_amount_for_pending_payouts() {
+    ExtBuilder::default()
+        .with_balances(vec![(1, 20), (2, 40), (5, 40)])
+        .with_candidates(vec![(1, 20)])
+        .with_delegations(vec![(2, 1, 10), (5, 1, 10)])
+        .build()
+        .execute_with(|| {
+            (1..=3).for_each(|round| set_author(round, 1, 1));
+            roll_to_round_begin(2);
+            assert_ok!(ParachainStaking::delegator_bond_more(
+                RuntimeOrigin::signed(2),
+                1,
+                10
+            ));
+
+            // the snapshots of rounds 1 and 2 count both delegations equally
+            roll_to_round_begin(4);
+            claim_rewards(&[2, 5]);
+            let (rewards_2, rewards_5) = (Balances::free_balance(&2), Balances::free_balance(&5));
+            assert_eq!(rewards_2, rewards_5);
+            assert!(rewards_2 > 40);
+
+            // the snapshot of round 3 counts the increased delegation
+            roll_to_round_begin(5);
+            claim_rewards(&[2, 5]);
+            assert!(
+                Balances::free_balance(&2) - rewards_2 > Balances::free_balance(&5) - rewards_5
+            );
+        });
+}
+
+#[test]
+fn removed_delegation_is_rewarded_for_rounds_it_was_counted_in() {
+    ExtBuilder::default()
+        .with_balances(vec![(1, 100), (2, 100), (3, 100), (5, 100)])
+        .with_candidates(vec![(1, 100), (3, 100)])
+        .with_delegations(vec![(2, 1, 100), (5, 1, 100)])
+        .build()
+        .execute_with(|| {
+            set_author(1, 1, 1);
+            set_author(1, 3, 1);
+            assert_ok!(ParachainStaking::schedule_revoke_delegation(
+                RuntimeOrigin::signed(2),
+                1
+            ));
+            // the revocation may execute before the collator is paid for round 1
+            roll_to_round_begin(3);
+            assert_ok!(ParachainStaking::execute_delegation_request(
+                RuntimeOrigin::signed(2),
+                2,
+                1
+            ));
+            assert!(ParachainStaking::delegator_state(2).is_none());
+
+            roll_one_block();
+            roll_one_block();
+            claim_rewards(&[2, 5]);
+            assert_eq!(Balances::free_balance(&2), Balances::free_balance(&5));
+            assert!(Balances::free_balance(&2) > 100);
+        });
+}
+
+#[test]
+fn bottom_delegations_accrue_no_rewards() {
+    ExtBuilder::default()
+        .with_balances(vec![(1, 20), (2, 20), (3, 20), (4, 20), (5, 20), (6, 10)])
+        .with_candidates(vec![(1, 20)])
+        .with_delegations(vec![
+            (2, 1, 20),
+            (3, 1, 20),
+            (4, 1, 20),
+            (5, 1, 20),
+            (6, 1, 10),
+        ])
+        .build()
+        .execute_with(|| {
+            set_author(1, 1, 1);
+            roll_to_round_begin(3);
+            claim_rewards(&[2, 6]);
+            assert_event_emitted!(Event::Rewarded {
+                account: 2,
+                rewards: 1,
+            });
+            assert!(!events()
+                .iter()
+                .any(|e| matches!(e, Event::Rewarded { account: 6, .. })));
+            assert_eq!(ParachainStaking::unclaimed_rewards(6), 0);
+        });
+}
+
+#[test]
+fn lazy_rewards_migration_keeps_counted_amounts_of_pending_payouts() {
+    use crate::{migrations::LazyRewardsMigration, RewardCheckpoint};
+    use frame_support::traits::{GetStorageVersion, OnRuntimeUpgrade, StorageVersion};
+
+    ExtBuilder::default()
+        .with_balances(vec![(1, 20), (2, 40), (3, 20), (4, 20)])
+        .with_candidates(vec![(1, 20), (3, 20), (4, 20)])
+        .with_delegations(vec![(2, 1, 10), (2, 3, 10)])
+        .build()
+        .execute_with(|| {
+            (1..=3).for_each(|round| set_author(round, 1, 1));
+            assert_ok!(ParachainStaking::schedule_revoke_delegation(
+                RuntimeOrigin::signed(2),
+                1
+            ));
+            // storage as left by push-based payouts
+            let _ = crate::RewardCheckpoints::<Test>::clear(u32::MAX, None);
+            let _ = crate::RewardBoundaries::<Test>::clear(u32::MAX, None);
+            crate::UnresolvedRewardBoundaries::<Test>::remove(1);
+            StorageVersion::new(2).put::<ParachainStaking>();
+
+            LazyRewardsMigration::<Test>::on_runtime_upgrade();
+            assert_eq!(ParachainStaking::on_chain_storage_version(), 3);
+            // the revoked delegation is still counted in the snapshot of round 1
+            assert_eq!(
+                ParachainStaking::reward_checkpoint(2, 1),
+                Some(RewardCheckpoint {
+                    per_share: Zero::zero(),
+                    pending: vec![(1, 10)],
+                })
+            );
+            assert_eq!(ParachainStaking::reward_checkpoint(2, 3), None);
+
+            roll_to_round_begin(3);
+            assert_ok!(ParachainStaking::claim_rewards(RuntimeOrigin::signed(2)));
+            assert_last_event!(MetaEvent::ParachainStaking(Event::Rewarded {
+                account: 2,
+                rewards: 1,
+            }));
+        });
+}
+
+#[test]
+fn delegator_schedule_revocation_total() {
+    ExtBuilder::default()
+        .with_balances(vec![(1, 20), (2, 40), (3, 20), (4, 20), (5, 20)])
+        .with_candidates(vec![(1, 20), (3, 20), (4, 20), (5, 20)])
+        .with_delegations(vec![(2, 1, 10), (2, 3, 10), (2, 4, 10)])
+        .build()
+        .execute_with(|| {
+            assert_ok!(ParachainStaking::schedule_revoke_delegation(
+                RuntimeOrigin::signed(2),
+                1
+            ));
+            assert_eq!(
+                ParachainStaking::delegator_state(&2)
+                    .map(|x| x.less_total)
+                    .expect("delegator state must exist"),
+                10
+            );
+            roll_to(10);
+            assert_ok!(ParachainStaking::execute_delegation_request(
+                RuntimeOrigin::signed(2),
+                2,
+                1
+            ));
+            assert_eq!(
+                ParachainStaking::delegator_state(&2)
+                    .map(|x| x.less_total)
+                    .expect("delegator state must exist"),
+                0
+            );
+            assert_ok!(ParachainStaking::delegate(
+                RuntimeOrigin::signed(2),
+                5,
+                10,
+                0,
+                2
+            ));
+            assert_ok!(ParachainStaking::schedule_revoke_delegation(
+                RuntimeOrigin::signed(2),
+                3
+            ));
+            assert_ok!(ParachainStaking::schedule_revoke_delegation(
+                RuntimeOrigin::signed(2),
+                4
+            ));
+            assert_eq!(
+                ParachainStaking::delegator_state(&2)
+                    .map(|x| x.less_total)
+                    .expect("delegator state must exist"),
+                20,
+            );
+            roll_to(20);
+            assert_ok!(ParachainStaking::execute_delegation_request(
+                RuntimeOrigin::signed(2),
+                2,
+                3
+            ));
+            assert_eq!(
+                ParachainStaking::delegator_state(&2)
+                    .map(|x| x.less_total)
+                    .expect("delegator state must exist"),
+                10,
+            );
+            assert_ok!(ParachainStaking::execute_delegation_request(
+                RuntimeOrigin::signed(2),
+                2,
+                4
+            ));
+            assert_eq!(
+                ParachainStaking::delegator_state(&2)
+                    .map(|x| x.less_total)
+                    .expect("delegator state must exist"),
+                0
+            );
+        });
+}
+
+#[test]
+fn parachain_bond_inflation_reserve_matches_config() {
+    ExtBuilder::default()
+        .with_balances(vec![
+            (1, 100),
+            (2, 100),
+            (3, 100),
+            (4, 100),
+            (5, 100),
+            (6, 100),
+            (7, 100),
+            (8, 100),
+            (9, 100),
+            (10, 100),
+            (11, 1),
+        ])
+        .with_candidates(vec![(1, 20), (2, 20), (3, 20), (4, 20), (5, 10)])
+        .with_delegations(vec![
+            (6, 1, 10),
+            (7, 1, 10),
+            (8, 2, 10),
+            (9, 2, 10),
+            (10, 1, 10),
+        ])
+        .build()
+        .execute_with(|| {
+            assert_eq!(Balances::free_balance(&11), 1);
             // set parachain bond account so DefaultParachainBondReservePercent = 30% of inflation
             // is allocated to this account hereafter
             assert_ok!(ParachainStaking::set_parachain_bond_account(
@@ -4451,6 +6235,7 @@ fn parachain_bond_inflation_reserve_matches_config() {
             // ~ set block author as 1 for all blocks this round
             set_author(2, 1, 100);
             roll_to(16);
+            claim_rewards(&[6, 7, 10]);
             // distribute total issuance to collator 1 and its delegators 6, 7, 19
             let mut new = vec![
                 Event::CollatorChosen {
@@ -4552,13 +6337,17 @@ fn parachain_bond_inflation_reserve_matches_config() {
                 RuntimeOrigin::signed(6)
             ));
             // fast forward to block in which delegator 6 exit executes
+            roll_to(20);
+            claim_rewards(&[6, 7, 10]);
             roll_to(25);
+            claim_rewards(&[6, 7, 10]);
             assert_ok!(ParachainStaking::execute_leave_delegators(
                 RuntimeOrigin::signed(6),
                 6,
                 10
             ));
             roll_to(30);
+            claim_rewards(&[7, 10]);
             let mut new2 = vec![
                 Event::DelegatorExitScheduled {
                     round: 4,
@@ -4735,6 +6524,7 @@ fn parachain_bond_inflation_reserve_matches_config() {
             // 6 won't be paid for this round because they left already
             set_author(6, 1, 100);
             roll_to(35);
+            claim_rewards(&[7, 10]);
             // keep paying 6
             let mut new3 = vec![
                 Event::ParachainBondReservePercentSet {
@@ -4794,6 +6584,7 @@ fn parachain_bond_inflation_reserve_matches_config() {
             assert_eq!(Balances::free_balance(&11), 95);
             set_author(7, 1, 100);
             roll_to(40);
+            claim_rewards(&[7, 10]);
             // no more paying 6
             let mut new4 = vec![
                 Event::ReservedForParachainBond {
@@ -4856,6 +6647,7 @@ fn parachain_bond_inflation_reserve_matches_config() {
                 10
             ));
             roll_to(45);
+            claim_rewards(&[7, 10]);
             // new delegation is not rewarded yet
             let mut new5 = vec![
                 Event::Delegation {
@@ -4918,6 +6710,7 @@ fn parachain_bond_inflation_reserve_matches_config() {
             set_author(9, 1, 100);
             set_author(10, 1, 100);
             roll_to(50);
+            claim_rewards(&[7, 10]);
             // new delegation is still not rewarded yet
             let mut new6 = vec![
                 Event::ReservedForParachainBond {
@@ -4972,6 +6765,7 @@ fn parachain_bond_inflation_reserve_matches_config() {
             assert_eq_events!(expected.clone());
             assert_eq!(Balances::free_balance(&11), 195);
             roll_to(55);
+            claim_rewards(&[7, 10, 8]);
             // new delegation is rewarded, 2 rounds after joining (`RewardPaymentDelay` is 2)
             let mut new7 = vec![
                 Event::ReservedForParachainBond {
@@ -5131,6 +6925,7 @@ fn paid_collator_commission_matches_config() {
             // only reward author with id 4
             set_author(3, 4, 100);
             roll_to(21);
+            claim_rewards(&[5, 6]);
             // 20% of 10 is commission + due_portion (0) = 2 + 4 = 6
             // all delegator payouts are 10-2 = 8 * stake_pct
             let mut new2 = vec![
@@ -6334,6 +8129,7 @@ fn payouts_follow_delegation_changes() {
             // ~ set block author as 1 for all blocks this round
             set_author(2, 1, 100);
             roll_to(16);
+            claim_rewards(&[6, 7, 10]);
             // distribute total issuance to collator 1 and its delegators 6, 7, 19
             let mut new = vec![
                 Event::CollatorChosen {
@@ -6431,7 +8227,10 @@ fn payouts_follow_delegation_changes() {
                 RuntimeOrigin::signed(6)
             ));
             // fast forward to block in which delegator 6 exit executes
+            roll_to(20);
+            claim_rewards(&[6, 7, 10]);
             roll_to(25);
+            claim_rewards(&[6, 7, 10]);
             assert_ok!(ParachainStaking::execute_leave_delegators(
                 RuntimeOrigin::signed(6),
                 6,
@@ -6553,7 +8352,10 @@ fn payouts_follow_delegation_changes() {
             assert_eq_events!(expected.clone());
             // 6 won't be paid for this round because they left already
             set_author(7, 1, 100);
+            roll_to(30);
+            claim_rewards(&[7, 10]);
             roll_to(35);
+            claim_rewards(&[7, 10]);
             // keep paying 6
             let mut new3 = vec![
                 Event::CollatorChosen {
@@ -6647,6 +8449,7 @@ fn payouts_follow_delegation_changes() {
             assert_eq_events!(expected.clone());
             set_author(8, 1, 100);
             roll_to(40);
+            claim_rewards(&[7, 10]);
             // no more paying 6
             let mut new4 = vec![
                 Event::CollatorChosen {
@@ -6704,6 +8507,7 @@ fn payouts_follow_delegation_changes() {
                 10
             ));
             roll_to(45);
+            claim_rewards(&[7, 10]);
             // new delegation is not rewarded yet
             let mut new5 = vec![
                 Event::Delegation {
@@ -6760,6 +8564,7 @@ fn payouts_follow_delegation_changes() {
             assert_eq_events!(expected.clone());
             set_author(10, 1, 100);
             roll_to(50);
+            claim_rewards(&[7, 10]);
             // new delegation not rewarded yet
             let mut new6 = vec![
                 Event::CollatorChosen {
@@ -6809,6 +8614,7 @@ fn payouts_follow_delegation_changes() {
             expected.append(&mut new6);
             assert_eq_events!(expected.clone());
             roll_to(55);
+            claim_rewards(&[7, 10, 8]);
             // new delegation is rewarded for first time
             // 2 rounds after joining (`RewardPaymentDelay` = 2)
             let mut new7 = vec![
@@ -7660,76 +9466,54 @@ fn deferred_payment_steady_state_event_flow() {
                         account: 3,
                         rewards: 19,
                     },
-                    Event::Rewarded {
-                        account: 22,
-                        rewards: 6,
-                    },
-                    Event::Rewarded {
-                        account: 33,
-                        rewards: 6,
-                    },
                 ];
                 assert_eq_last_events!(expected);
 
                 set_round_points(round);
 
                 roll_one_block();
-                let expected = vec![
-                    Event::Rewarded {
-                        account: 4,
-                        rewards: 19,
-                    },
-                    Event::Rewarded {
-                        account: 33,
-                        rewards: 6,
-                    },
-                    Event::Rewarded {
-                        account: 44,
-                        rewards: 6,
-                    },
-                ];
-                assert_eq_last_events!(expected);
+                assert_last_event!(MetaEvent::ParachainStaking(Event::Rewarded {
+                    account: 4,
+                    rewards: 19,
+                }));
 
                 roll_one_block();
-                let expected = vec![
-                    Event::Rewarded {
-                        account: 1,
-                        rewards: 19,
-                    },
-                    Event::Rewarded {
-                        account: 11,
-                        rewards: 6,
-                    },
-                    Event::Rewarded {
-                        account: 44,
-                        rewards: 6,
-                    },
-                ];
-                assert_eq_last_events!(expected);
+                assert_last_event!(MetaEvent::ParachainStaking(Event::Rewarded {
+                    account: 1,
+                    rewards: 19,
+                }));
+
+                roll_one_block();
+                assert_last_event!(MetaEvent::ParachainStaking(Event::Rewarded {
+                    account: 2,
+                    rewards: 19,
+                }));
 
                 roll_one_block();
+                // we paid everyone out by now, should repeat last event
+                assert_last_event!(MetaEvent::ParachainStaking(Event::Rewarded {
+                    account: 2,
+                    rewards: 19,
+                }));
+
+                // delegators claim their share of both collators they back
+                claim_rewards(&[11, 22, 33, 44]);
                 let expected = vec![
-                    Event::Rewarded {
-                        account: 2,
-                        rewards: 19,
-                    },
                     Event::Rewarded {
                         account: 11,
-                        rewards: 6,
+                        rewards: 12,
                     },
                     Event::Rewarded {
                         account: 22,
-                        rewards: 6,
+                        rewards: 12,
                     },
-                ];
-                assert_eq_last_events!(expected);
-
-                roll_one_block();
-                let expected = vec![
-                    // we paid everyone out by now, should repeat last event
                     Event::Rewarded {
-                        account: 22,
-                        rewards: 6,
+                        account: 33,
+                        rewards: 12,
+                    },
+                    Event::Rewarded {
+                        account: 44,
+                        rewards: 12,
                     },
                 ];
                 assert_eq_last_events!(expected);
@@ -8163,6 +9947,7 @@ fn test_delegator_scheduled_for_revoke_is_rewarded_for_previous_rounds_but_not_f
             );
 
             roll_to_round_begin(3);
+            claim_rewards(&[2]);
             assert_eq_last_events!(
                 vec![
                     Event::<Test>::Rewarded {
@@ -8178,6 +9963,7 @@ fn test_delegator_scheduled_for_revoke_is_rewarded_for_previous_rounds_but_not_f
             );
 
             roll_to_round_begin(4);
+            claim_rewards(&[2]);
             assert_eq_last_events!(
                 vec![Event::<Test>::Rewarded {
                     account: 1,
@@ -8239,6 +10025,7 @@ fn test_delegator_scheduled_for_revoke_is_rewarded_when_request_cancelled() {
             ));
 
             roll_to_round_begin(4);
+            claim_rewards(&[2]);
             assert_eq_last_events!(
                 vec![Event::<Test>::Rewarded {
                     account: 1,
@@ -8259,6 +10046,7 @@ fn test_delegator_scheduled_for_revoke_is_rewarded_when_request_cancelled() {
             );
 
             roll_to_round_begin(5);
+            claim_rewards(&[2]);
             assert_eq_last_events!(
                 vec![
                     Event::<Test>::Rewarded {
@@ -8311,6 +10099,7 @@ fn test_delegator_scheduled_for_bond_decrease_is_rewarded_for_previous_rounds_bu
             );
 
             roll_to_round_begin(3);
+            claim_rewards(&[2]);
             assert_eq_last_events!(
                 vec![
                     Event::<Test>::Rewarded {
@@ -8326,6 +10115,7 @@ fn test_delegator_scheduled_for_bond_decrease_is_rewarded_for_previous_rounds_bu
             );
 
             roll_to_round_begin(4);
+            claim_rewards(&[2]);
             assert_eq_last_events!(
                 vec![
                     Event::<Test>::Rewarded {
@@ -8394,6 +10184,7 @@ fn test_delegator_scheduled_for_bond_decrease_is_rewarded_when_request_cancelled
             ));
 
             roll_to_round_begin(4);
+            claim_rewards(&[2]);
             assert_eq_last_events!(
                 vec![
                     Event::<Test>::Rewarded {
@@ -8420,6 +10211,7 @@ fn test_delegator_scheduled_for_bond_decrease_is_rewarded_when_request_cancelled
             );
 
             roll_to_round_begin(5);
+            claim_rewards(&[2]);
             assert_eq_last_events!(
                 vec![
                     Event::<Test>::Rewarded {
@@ -8466,6 +10258,7 @@ fn test_delegator_scheduled_for_leave_is_rewarded_for_previous_rounds_but_not_fo
             );
 
             roll_to_round_begin(3);
+            claim_rewards(&[2]);
             assert_eq_last_events!(
                 vec![
                     Event::<Test>::Rewarded {
@@ -8481,6 +10274,7 @@ fn test_delegator_scheduled_for_leave_is_rewarded_for_previous_rounds_but_not_fo
             );
 
             roll_to_round_begin(4);
+            claim_rewards(&[2]);
             assert_eq_last_events!(
                 vec![Event::<Test>::Rewarded {
                     account: 1,
@@ -8537,6 +10331,7 @@ fn test_delegator_scheduled_for_leave_is_rewarded_when_request_cancelled() {
             ));
 
             roll_to_round_begin(4);
+            claim_rewards(&[2]);
             assert_eq_last_events!(
                 vec![Event::<Test>::Rewarded {
                     account: 1,
@@ -8557,6 +10352,7 @@ fn test_delegator_scheduled_for_leave_is_rewarded_when_request_cancelled() {
             );
 
             roll_to_round_begin(5);
+            claim_rewards(&[2]);
             assert_eq_last_events!(
                 vec![
                     Event::<Test>::Rewarded {
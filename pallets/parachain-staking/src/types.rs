@@ -18,7 +18,8 @@
 
 use crate::{
     set::OrderedSet, BalanceOf, BottomDelegations, CandidateInfo, Config, DelegatorState, Error,
-    Event, Pallet, Round, RoundIndex, TopDelegations, Total, COLLATOR_LOCK_ID, DELEGATOR_LOCK_ID,
+    Event, LastRedelegation, Pallet, Round, RoundIndex, TopDelegations, Total, COLLATOR_LOCK_ID,
+    DELEGATOR_LOCK_ID,
 };
 use codec::{Decode, Encode};
 use frame_support::{
@@ -112,12 +113,17 @@ pub struct CollatorSnapshot<AccountId, Balance> {
     /// The total counted value locked for the collator, including the self bond + total staked by
     /// top delegators.
     pub total: Balance,
+
+    /// The commission of the collator for the round.
+    pub commission: Perbill,
 }
 
 #[cfg(test)]
 impl<A: PartialEq, B: PartialEq> PartialEq for CollatorSnapshot<A, B> {
     fn eq(&self, other: &Self) -> bool {
-        let must_be_true = self.bond == other.bond && self.total == other.total;
+        let must_be_true = self.bond == other.bond
+            && self.total == other.total
+            && self.commission == other.commission;
         if !must_be_true {
             return false;
         }
@@ -149,6 +155,7 @@ impl<A, B: Default> Default for CollatorSnapshot<A, B> {
             bond: B::default(),
             delegations: Vec::new(),
             total: B::default(),
+            commission: Perbill::zero(),
         }
     }
 }
@@ -209,6 +216,13 @@ pub struct CandidateBondLessRequest<Balance> {
     pub when_executable: RoundIndex,
 }
 
+#[derive(PartialEq, Clone, Copy, Encode, Decode, RuntimeDebug, TypeInfo)]
+/// Request scheduled to change the collator candidate commission
+pub struct CommissionChangeRequest {
+    pub commission: Perbill,
+    pub when_executable: RoundIndex,
+}
+
 #[derive(Encode, Decode, RuntimeDebug, TypeInfo)]
 /// DEPRECATED, replaced by `CandidateMetadata` and two storage instances of `Delegations`
 /// Collator candidate state with self bond + delegations
@@ -359,6 +373,10 @@ pub struct CandidateMetadata<Balance> {
     pub request: Option<CandidateBondLessRequest<Balance>>,
     /// Current status of the collator
     pub status: CollatorStatus,
+    /// Share of the round rewards taken by this candidate before the delegators are paid
+    pub commission: Perbill,
+    /// Maximum 1 pending request to change the commission at any given time
+    pub commission_request: Option<CommissionChangeRequest>,
 }
 
 impl<
@@ -372,7 +390,7 @@ impl<
             + Saturating,
     > CandidateMetadata<Balance>
 {
-    pub fn new(bond: Balance) -> Self {
+    pub fn new(bond: Balance, commission: Perbill) -> Self {
         CandidateMetadata {
             bond,
             delegation_count: 0u32,
@@ -384,6 +402,8 @@ impl<
             bottom_capacity: CapacityStatus::Empty,
             request: None,
             status: CollatorStatus::Active,
+            commission,
+            commission_request: None,
         }
     }
     pub fn is_active(&self) -> bool {
@@ -524,6 +544,69 @@ impl<
         Pallet::<T>::deposit_event(event);
         Ok(())
     }
+    /// Schedule a change of the candidate commission
+    /// Returns the round at which the change can be executed
+    pub fn schedule_commission_change<T: Config>(
+        &mut self,
+        commission: Perbill,
+    ) -> Result<RoundIndex, DispatchError> {
+        ensure!(
+            self.commission_request.is_none(),
+            Error::<T>::PendingCommissionChangeAlreadyExists
+        );
+        ensure!(
+            self.commission != commission,
+            Error::<T>::NoWritingSameValue
+        );
+        ensure!(
+            Pallet::<T>::commission_within_bounds(commission),
+            Error::<T>::CommissionOutOfBounds
+        );
+        let when_executable = <Round<T>>::get().current + T::CommissionChangeDelay::get();
+        self.commission_request = Some(CommissionChangeRequest {
+            commission,
+            when_executable,
+        });
+        Ok(when_executable)
+    }
+    /// Execute pending request to change the candidate commission
+    pub fn execute_commission_change<T: Config>(&mut self, who: T::AccountId) -> DispatchResult {
+        let request = self
+            .commission_request
+            .ok_or(Error::<T>::PendingCommissionChangeDNE)?;
+        ensure!(
+            request.when_executable <= <Round<T>>::get().current,
+            Error::<T>::PendingCommissionChangeNotDueYet
+        );
+        // bounds may have changed since the request was scheduled
+        ensure!(
+            Pallet::<T>::commission_within_bounds(request.commission),
+            Error::<T>::CommissionOutOfBounds
+        );
+        let event = Event::CommissionChanged {
+            candidate: who,
+            old: self.commission,
+            new: request.commission,
+        };
+        self.commission = request.commission;
+        self.commission_request = None;
+        Pallet::<T>::deposit_event(event);
+        Ok(())
+    }
+    /// Cancel candidate commission change request
+    pub fn cancel_commission_change<T: Config>(&mut self, who: T::AccountId) -> DispatchResult {
+        let request = self
+            .commission_request
+            .ok_or(Error::<T>::PendingCommissionChangeDNE)?;
+        let event = Event::CommissionChangeCancelled {
+            candidate: who,
+            commission: request.commission,
+            execute_round: request.when_executable,
+        };
+        self.commission_request = None;
+        Pallet::<T>::deposit_event(event);
+        Ok(())
+    }
     /// Reset top delegations metadata
     pub fn reset_top_data<T: Config>(
         &mut self,
@@ -567,6 +650,7 @@ impl<
     where
         BalanceOf<T>: Into<Balance> + From<Balance>,
     {
+        <Pallet<T>>::start_delegation_rewards(candidate, &delegation.owner);
         let mut less_total_staked = None;
         let delegator_added = match self.top_capacity {
             CapacityStatus::Full => {
@@ -627,6 +711,11 @@ impl<
             if matches!(self.bottom_capacity, CapacityStatus::Full) {
                 less_total_staked = Some(self.lowest_bottom_delegation_amount);
             }
+            <Pallet<T>>::settle_delegation_rewards(
+                candidate,
+                &new_bottom_delegation.owner,
+                new_bottom_delegation.amount,
+            );
             self.add_bottom_delegation::<T>(true, candidate, new_bottom_delegation);
         }
         // insert into top
@@ -669,6 +758,11 @@ impl<
             bottom_delegations.total = bottom_delegations
                 .total
                 .saturating_sub(lowest_bottom_to_be_kicked.amount);
+            <Pallet<T>>::end_delegation_rewards(
+                candidate,
+                &lowest_bottom_to_be_kicked.owner,
+                lowest_bottom_to_be_kicked.amount,
+            );
             // update delegator state
             // total staked is updated via propagation of lowest bottom delegation amount prior
             // to call
@@ -676,6 +770,10 @@ impl<
                 .expect("Delegation existence => DelegatorState existence");
             let leaving = delegator_state.delegations.0.len() == 1usize;
             delegator_state.rm_delegation::<T>(candidate);
+            <Pallet<T>>::delegation_remove_auto_compound(
+                &candidate,
+                &lowest_bottom_to_be_kicked.owner,
+            );
             <Pallet<T>>::delegation_remove_request_with_state(
                 &candidate,
                 &lowest_bottom_to_be_kicked.owner,
@@ -689,6 +787,7 @@ impl<
             });
             if leaving {
                 <DelegatorState<T>>::remove(&lowest_bottom_to_be_kicked.owner);
+                <LastRedelegation<T>>::remove(&lowest_bottom_to_be_kicked.owner);
                 Pallet::<T>::deposit_event(Event::DelegatorLeft {
                     delegator: lowest_bottom_to_be_kicked.owner,
                     unstaked_amount: lowest_bottom_to_be_kicked.amount,
@@ -721,6 +820,7 @@ impl<
     where
         BalanceOf<T>: Into<Balance> + From<Balance>,
     {
+        <Pallet<T>>::end_delegation_rewards(candidate, &delegator, amount.into());
         let amount_geq_lowest_top = amount >= self.lowest_top_delegation_amount;
         let top_is_not_full = !matches!(self.top_capacity, CapacityStatus::Full);
         let lowest_top_eq_highest_bottom =
@@ -778,6 +878,11 @@ impl<
             bottom_delegations.total = bottom_delegations
                 .total
                 .saturating_sub(highest_bottom_delegation.amount);
+            <Pallet<T>>::settle_delegation_rewards(
+                candidate,
+                &highest_bottom_delegation.owner,
+                highest_bottom_delegation.amount,
+            );
             self.reset_bottom_data::<T>(&bottom_delegations);
             <BottomDelegations<T>>::insert(candidate, bottom_delegations);
             // insert highest bottom into top delegations
@@ -836,6 +941,7 @@ impl<
     where
         BalanceOf<T>: Into<Balance> + From<Balance>,
     {
+        <Pallet<T>>::settle_delegation_rewards(candidate, &delegator, bond);
         let lowest_top_eq_highest_bottom =
             self.lowest_top_delegation_amount == self.highest_bottom_delegation_amount;
         let bond_geq_lowest_top = bond.into() >= self.lowest_top_delegation_amount;
@@ -941,6 +1047,11 @@ impl<
                 top_delegations.total = top_delegations
                     .total
                     .saturating_sub(new_bottom_delegation.amount);
+                <Pallet<T>>::settle_delegation_rewards(
+                    candidate,
+                    &new_bottom_delegation.owner,
+                    new_bottom_delegation.amount,
+                );
                 bottom_delegations.insert_sorted_greatest_to_least(new_bottom_delegation);
             }
             // insert into top
@@ -987,6 +1098,7 @@ impl<
     where
         BalanceOf<T>: Into<Balance> + From<Balance>,
     {
+        <Pallet<T>>::settle_delegation_rewards(candidate, &delegator, bond.into());
         let lowest_top_eq_highest_bottom =
             self.lowest_top_delegation_amount == self.highest_bottom_delegation_amount;
         let bond_geq_lowest_top = bond >= self.lowest_top_delegation_amount;
@@ -1056,6 +1168,11 @@ impl<
             bottom_delegations.total = bottom_delegations
                 .total
                 .saturating_sub(highest_bottom_delegation.amount);
+            <Pallet<T>>::settle_delegation_rewards(
+                candidate,
+                &highest_bottom_delegation.owner,
+                highest_bottom_delegation.amount,
+            );
             // insert highest bottom into top
             top_delegations.insert_sorted_greatest_to_least(highest_bottom_delegation);
             // insert previous top into bottom
@@ -1224,6 +1341,7 @@ impl<A: Clone, B: Copy> From<CollatorCandidate<A, B>> for CollatorSnapshot<A, B>
             bond: other.bond,
             delegations: other.top_delegations,
             total: other.total_counted,
+            commission: Perbill::zero(),
         }
     }
 }
@@ -1689,3 +1807,19 @@ pub enum BondAdjust<Balance> {
     Increase(Balance),
     Decrease,
 }
+
+/// Adds two `Get` values, bounding e.g. the auto-compounding delegations of a candidate by its
+/// top and bottom delegations together.
+pub struct AddGet<T, R> {
+    _phantom: PhantomData<(T, R)>,
+}
+
+impl<T, R> Get<u32> for AddGet<T, R>
+where
+    T: Get<u32>,
+    R: Get<u32>,
+{
+    fn get() -> u32 {
+        T::get().saturating_add(R::get())
+    }
+}
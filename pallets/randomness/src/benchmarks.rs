@@ -18,11 +18,43 @@
 
 //! Benchmarking
 use crate::{
-    Call, Config, InherentIncluded, Pallet, RandomnessResult, RandomnessResults, RelayEpoch,
-    RequestType,
+    Call, Config, InherentIncluded, LocalVrfEnforced, LocalVrfOutput, Pallet, RandomnessResult,
+    RandomnessResults, RelayEpoch, RequestCount, RequestType, Requests,
 };
-use frame_benchmarking::{benchmarks, impl_benchmark_test_suite};
+use codec::{Decode, Encode};
+use frame_benchmarking::{account, benchmarks, impl_benchmark_test_suite};
+use frame_support::traits::{Currency, Get, Hooks};
 use frame_system::RawOrigin;
+use nimbus_primitives::{NimbusId, NIMBUS_ENGINE_ID};
+use session_key_primitives::vrf::{KeysLookup, PreDigest, VrfId, VRF_ENGINE_ID};
+use sp_core::crypto::UncheckedFrom;
+use sp_runtime::{traits::Hash, DigestItem};
+
+/// VRF key of the block author in `set_local_vrf_output`, derived from the mini secret `[7; 32]`
+const BENCHMARKING_VRF_ID: &str =
+    "7c0f469d3bd340bae718203fa30ca071a5e37c751e891dbded837b213d45d91d";
+/// VRF output of [`BENCHMARKING_VRF_ID`] signing the transcript of a default last VRF output
+const BENCHMARKING_VRF_OUTPUT: &str =
+    "ca844f2425ce6c5a25be6bd26e833b160d74852b42e20a2bd94605e45bb5201f";
+/// VRF proof of [`BENCHMARKING_VRF_OUTPUT`]
+const BENCHMARKING_VRF_PROOF: &str = "af33d8f15055d77250b7359a18c1f46ed7a749beb7e708a7931eac56e580190f77d3fec14d3f2557c79df7fd61f32530e896cd978e2db5850fea47f6daed6807";
+/// Randomness derived from [`BENCHMARKING_VRF_OUTPUT`]
+const BENCHMARKING_RANDOMNESS: &str =
+    "b2dc63c2d8a930a94fc467f6d92a72c3aef1d6080766c5fc69d1347665593fa4";
+
+/// Decodes the hex encoded test vector `value`
+fn from_hex<const N: usize>(value: &str) -> [u8; N] {
+    let mut bytes = [0u8; N];
+    hex::decode_to_slice(value, &mut bytes).expect("benchmarking test vectors are valid hex");
+    bytes
+}
+
+/// Funds an account with enough balance for a few request deposits
+fn funded_requester<T: Config>() -> T::AccountId {
+    let requester: T::AccountId = account("requester", 0, 0);
+    T::Currency::make_free_balance_be(&requester, T::Deposit::get() * 100u32.into());
+    requester
+}
 
 benchmarks! {
     // Benchmark for inherent included in every block
@@ -51,6 +83,91 @@ benchmarks! {
             benchmarking_new_epoch
         );
     }
+
+    request_randomness {
+        let requester = funded_requester::<T>();
+        let words = T::MaxRandomWords::get();
+    }: _(RawOrigin::Signed(requester), T::Hash::default(), words)
+    verify {
+        assert_eq!(RequestCount::<T>::get(), 1);
+    }
+
+    fulfil_randomness {
+        let x in 1 .. T::MaxRandomWords::get().into();
+        let requester = funded_requester::<T>();
+        Pallet::<T>::do_request_randomness(requester.clone(), T::Hash::default(), x as u8)?;
+        RandomnessResults::<T>::insert(
+            RequestType::BabeEpoch(2),
+            RandomnessResult {
+                randomness: Some(T::Hashing::hash(&[1u8; 32])),
+                request_count: 1,
+            },
+        );
+    }: _(RawOrigin::Signed(requester), 0)
+    verify {
+        assert!(Requests::<T>::get(0).is_none());
+    }
+
+    request_local_randomness {
+        LocalVrfEnforced::<T>::put(true);
+        let requester = funded_requester::<T>();
+        let words = T::MaxRandomWords::get();
+        let delay = T::MinBlockDelay::get();
+    }: _(RawOrigin::Signed(requester), T::Hash::default(), words, delay)
+    verify {
+        assert_eq!(RequestCount::<T>::get(), 1);
+    }
+
+    purge_expired_request {
+        let requester = funded_requester::<T>();
+        Pallet::<T>::do_request_randomness(requester.clone(), T::Hash::default(), 1)?;
+        RelayEpoch::<T>::put(3u64.saturating_add(T::EpochExpirationDelay::get()));
+    }: _(RawOrigin::Signed(requester), 0)
+    verify {
+        assert!(Requests::<T>::get(0).is_none());
+    }
+
+    // Benchmark for the VRF verification in every `on_initialize`, including a pending request
+    set_local_vrf_output {
+        let author = NimbusId::unchecked_from([1u8; 32]);
+        let vrf_id = VrfId::unchecked_from(from_hex::<32>(BENCHMARKING_VRF_ID));
+        T::VrfKeyLookup::set_keys(author.clone(), vrf_id);
+        LocalVrfOutput::<T>::put(T::Hash::default());
+        LocalVrfEnforced::<T>::put(true);
+        let pre_digest = PreDigest {
+            vrf_output: Decode::decode(&mut &from_hex::<32>(BENCHMARKING_VRF_OUTPUT)[..])
+                .expect("VRF outputs have 32 bytes"),
+            vrf_proof: Decode::decode(&mut &from_hex::<64>(BENCHMARKING_VRF_PROOF)[..])
+                .expect("VRF proofs have 64 bytes"),
+        };
+        frame_system::Pallet::<T>::deposit_log(DigestItem::PreRuntime(
+            NIMBUS_ENGINE_ID,
+            author.encode(),
+        ));
+        frame_system::Pallet::<T>::deposit_log(DigestItem::PreRuntime(
+            VRF_ENGINE_ID,
+            pre_digest.encode(),
+        ));
+        let now = frame_system::Pallet::<T>::block_number();
+        RandomnessResults::<T>::insert(RequestType::Local(now), RandomnessResult::new());
+    }: {
+        Pallet::<T>::on_initialize(now);
+    }
+    verify {
+        let randomness = T::Hash::decode(&mut &from_hex::<32>(BENCHMARKING_RANDOMNESS)[..])
+            .expect("randomness has 32 bytes");
+        assert_eq!(LocalVrfOutput::<T>::get(), Some(randomness));
+        assert_eq!(
+            RandomnessResults::<T>::get(RequestType::Local(now)).unwrap().randomness,
+            Some(randomness)
+        );
+    }
+
+    set_local_vrf_enforced {
+    }: _(RawOrigin::Root, true)
+    verify {
+        assert!(LocalVrfEnforced::<T>::get());
+    }
 }
 
 #[cfg(test)]
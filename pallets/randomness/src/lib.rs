@@ -16,12 +16,42 @@
 
 //! # Randomness Pallet
 //!
-//! This pallet provides access to 1 sources of randomness:
+//! This pallet provides access to 2 sources of randomness:
 //! 1. relay chain BABE one epoch ago randomness, produced by the relay chain per relay chain epoch
+//! 2. local VRF randomness, produced by the parachain block author in every block
 //! These options are represented as `type::RequestType`.
 //!
-//! There are no extrinsics for this pallet. Instead, public functions on `Pallet<T: Config>` expose
-//! user actions for the precompile i.e. `request_randomness`.
+//! The [`frame_support::traits::Randomness`] implementation of `Pallet<T>` mixes the subject into the
+//! randomness of the current relay epoch, so it is known ahead of time and the same for every consumer
+//! in the epoch. `LocalRandomness<T>` mixes the subject into the VRF randomness of the current block
+//! instead, consumers pick the source by choosing the implementation.
+//!
+//! ## Randomness Requests
+//! Pallets (via the public functions on `Pallet<T: Config>`) and signed origins (via extrinsics) can
+//! request randomness of a future relay epoch that is unknown at the time of the request:
+//! 1. `request_randomness` reserves `Config::Deposit` and registers a request with a salt for the
+//!    BABE randomness two relay epochs after the current one
+//! 2. Once the inherent filled `RandomnessResults` for that epoch, anyone can `fulfil_randomness`.
+//!    The random words are derived from the BABE randomness, the request id and the salt, emitted in
+//!    `Event::RandomnessFulfilled`, passed to `Config::OnRandomnessFulfilled` and the deposit is returned
+//! 3. Requests not fulfilled within `Config::EpochExpirationDelay` relay epochs can be purged by anyone
+//!    with `purge_expired_request`, which also returns the deposit to the requester
+//!
+//! `request_local_randomness` works the same for the VRF randomness of a parachain block between
+//! `Config::MinBlockDelay` and `Config::MaxBlockDelay` blocks in the future, which expires
+//! `Config::BlockExpirationDelay` blocks after the requested block.
+//!
+//! ## Local VRF Randomness
+//! Every block author includes a VRF pre-runtime digest next to its nimbus digest, signing the VRF
+//! output of the previous block with its `VrfId` session key. `on_initialize` verifies the digest
+//! against the key returned by `Config::VrfKeyLookup` for the author and stores the randomness in
+//! `LocalVrfOutput`. The first block after genesis or after the runtime upgrade introducing this
+//! initializes `LocalVrfOutput` without a digest.
+//!
+//! Blocks without a valid digest are only rejected once `Config::ManageOrigin` enabled
+//! `LocalVrfEnforced` with `set_local_vrf_enforced`, which must wait until every selected collator
+//! has a VRF session key. Until then such blocks keep the output of the previous block, and local
+//! randomness cannot be requested.
 //!
 //! ## Babe Epoch Randomness
 //! Babe epoch randomness is retrieved once every relay chain epoch.
@@ -43,6 +73,7 @@ pub use weights::WeightInfo;
 mod benchmarks;
 pub mod types;
 pub use types::*;
+mod vrf;
 pub mod weights;
 
 #[cfg(test)]
@@ -56,13 +87,36 @@ pub trait GetBabeData<EpochIndex, Randomness> {
     fn get_epoch_randomness() -> Randomness;
 }
 
+/// Callback for pallets consuming requested randomness
+pub trait OnRandomnessFulfilled<AccountId, Hash> {
+    /// Called with the random words of `request_id` once fulfilled
+    fn on_randomness_fulfilled(
+        request_id: RequestId,
+        requester: &AccountId,
+        salt: Hash,
+        randomness: &[Hash],
+    );
+}
+
+impl<AccountId, Hash> OnRandomnessFulfilled<AccountId, Hash> for () {
+    fn on_randomness_fulfilled(_: RequestId, _: &AccountId, _: Hash, _: &[Hash]) {}
+}
+
+type BalanceOf<T> = <<T as Config>::Currency as frame_support::traits::Currency<
+    <T as frame_system::Config>::AccountId,
+>>::Balance;
+
 #[pallet]
 pub mod pallet {
     use super::*;
-    use frame_support::pallet_prelude::*;
+    use frame_support::{pallet_prelude::*, traits::ReservableCurrency};
     use frame_system::pallet_prelude::*;
-    use session_key_primitives::inherent::{InherentError, INHERENT_IDENTIFIER};
-    use sp_runtime::traits::Hash;
+    use nimbus_primitives::NimbusId;
+    use session_key_primitives::{
+        inherent::{InherentError, INHERENT_IDENTIFIER},
+        vrf::{KeysLookup, VrfId},
+    };
+    use sp_runtime::traits::{Hash, Saturating};
     use sp_std::convert::TryInto;
     #[pallet::pallet]
     #[pallet::without_storage_info]
@@ -71,14 +125,86 @@ pub mod pallet {
     /// Configuration trait of this pallet.
     #[pallet::config]
     pub trait Config: frame_system::Config {
+        type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
         /// Get the BABE data from the runtime
         type BabeDataGetter: GetBabeData<u64, Option<Self::Hash>>;
+        /// Currency in which the request deposit is reserved
+        type Currency: ReservableCurrency<Self::AccountId>;
+        /// Amount reserved per randomness request until it is fulfilled or purged
+        #[pallet::constant]
+        type Deposit: Get<BalanceOf<Self>>;
+        /// Maximum number of random words per request
+        #[pallet::constant]
+        type MaxRandomWords: Get<u8>;
+        /// Number of relay epochs after the requested epoch before a request can be purged
+        #[pallet::constant]
+        type EpochExpirationDelay: Get<u64>;
+        /// Notified of fulfilled requests
+        type OnRandomnessFulfilled: OnRandomnessFulfilled<Self::AccountId, Self::Hash>;
+        /// Lookup of the VRF session key of a block author
+        type VrfKeyLookup: KeysLookup<NimbusId, VrfId>;
+        /// Minimum number of blocks before local randomness can be fulfilled, at least 1
+        #[pallet::constant]
+        type MinBlockDelay: Get<BlockNumberFor<Self>>;
+        /// Maximum number of blocks before local randomness can be fulfilled
+        #[pallet::constant]
+        type MaxBlockDelay: Get<BlockNumberFor<Self>>;
+        /// Number of blocks after the requested block before a local request can be purged
+        #[pallet::constant]
+        type BlockExpirationDelay: Get<BlockNumberFor<Self>>;
+        /// Origin allowed to enforce the VRF pre-runtime digest of block authors
+        type ManageOrigin: EnsureOrigin<Self::RuntimeOrigin>;
         type WeightInfo: WeightInfo;
     }
 
+    #[pallet::event]
+    #[pallet::generate_deposit(pub(crate) fn deposit_event)]
+    pub enum Event<T: Config> {
+        RandomnessRequested {
+            id: RequestId,
+            requester: T::AccountId,
+            salt: T::Hash,
+            num_words: u8,
+            epoch_index: u64,
+        },
+        LocalRandomnessRequested {
+            id: RequestId,
+            requester: T::AccountId,
+            salt: T::Hash,
+            num_words: u8,
+            block_number: BlockNumberFor<T>,
+        },
+        RandomnessFulfilled {
+            id: RequestId,
+            randomness: Vec<T::Hash>,
+        },
+        RequestExpired {
+            id: RequestId,
+        },
+        LocalVrfEnforcementSet {
+            enforced: bool,
+        },
+    }
+
     #[pallet::error]
     pub enum Error<T> {
         CannotRequestRandomnessAfterMaxDelay,
+        /// Local randomness must be requested at least `Config::MinBlockDelay` blocks in advance
+        CannotRequestRandomnessBeforeMinDelay,
+        /// Requests must ask for at least one random word
+        MustRequestAtLeastOneWord,
+        /// Requested more than `Config::MaxRandomWords` random words
+        CannotRequestMoreWordsThanMax,
+        /// No pending request with this id
+        RequestDoesNotExist,
+        /// Randomness of the requested epoch is not available yet
+        RequestCannotYetBeFulfilled,
+        /// The request can still be fulfilled
+        RequestHasNotExpired,
+        /// No more request ids available
+        RequestCounterOverflowed,
+        /// Local randomness is only available once the VRF digest of block authors is enforced
+        LocalVrfNotEnforced,
     }
 
     /// Relay epoch
@@ -96,7 +222,33 @@ pub mod pallet {
     #[pallet::storage]
     #[pallet::getter(fn randomness_results)]
     pub type RandomnessResults<T: Config> =
-        StorageMap<_, Twox64Concat, RequestType, RandomnessResult<T::Hash>>;
+        StorageMap<_, Twox64Concat, RequestType<BlockNumberFor<T>>, RandomnessResult<T::Hash>>;
+
+    /// Id of the next randomness request
+    #[pallet::storage]
+    #[pallet::getter(fn request_count)]
+    pub type RequestCount<T: Config> = StorageValue<_, RequestId, ValueQuery>;
+
+    /// Pending randomness requests
+    #[pallet::storage]
+    #[pallet::getter(fn requests)]
+    pub type Requests<T: Config> = StorageMap<
+        _,
+        Twox64Concat,
+        RequestId,
+        Request<T::AccountId, BalanceOf<T>, T::Hash, BlockNumberFor<T>>,
+    >;
+
+    /// VRF randomness of the current block, verified against the VRF key of its author.
+    /// Input of the VRF signature of the next block author
+    #[pallet::storage]
+    #[pallet::getter(fn local_vrf_output)]
+    pub type LocalVrfOutput<T: Config> = StorageValue<_, T::Hash>;
+
+    /// Whether blocks without a valid VRF pre-runtime digest of their author are rejected
+    #[pallet::storage]
+    #[pallet::getter(fn local_vrf_enforced)]
+    pub type LocalVrfEnforced<T: Config> = StorageValue<_, bool, ValueQuery>;
 
     #[pallet::call]
     impl<T: Config> Pallet<T> {
@@ -114,11 +266,14 @@ pub mod pallet {
                 // NOTE: Whether n = 1 or 2 depends on the trait implementation of BabeDataGetter
                 let babe_n_epochs_ago_this_block = RequestType::BabeEpoch(relay_epoch_index);
                 if let Some(randomness) = T::BabeDataGetter::get_epoch_randomness() {
-                    let result = RandomnessResult {
-                        request_count: 1,
-                        randomness: Some(randomness),
-                    };
-                    <RandomnessResults<T>>::insert(babe_n_epochs_ago_this_block, result);
+                    // keep the count of requests already waiting for this epoch
+                    <RandomnessResults<T>>::mutate(babe_n_epochs_ago_this_block, |result| {
+                        let result = result.get_or_insert_with(|| RandomnessResult {
+                            randomness: None,
+                            request_count: 0,
+                        });
+                        result.randomness = Some(randomness);
+                    });
                 } else {
                     log::warn!(
                         "Failed to fill BABE epoch randomness results \
@@ -128,9 +283,243 @@ pub mod pallet {
                 }
             }
             <RelayEpoch<T>>::put(relay_epoch_index);
+            if relay_epoch_index > last_relay_epoch_index {
+                // the previous epoch is no longer used by `Randomness::random`
+                Self::remove_result_if_unused(RequestType::BabeEpoch(last_relay_epoch_index));
+            }
             <InherentIncluded<T>>::put(());
             Ok(Pays::No.into())
         }
+
+        /// Requests `num_words` random words derived from the BABE randomness two relay epochs from now
+        /// and `salt`, reserving `Config::Deposit` from the caller until fulfilled or purged
+        #[pallet::call_index(1)]
+        #[pallet::weight(<T as Config>::WeightInfo::request_randomness())]
+        pub fn request_randomness(
+            origin: OriginFor<T>,
+            salt: T::Hash,
+            num_words: u8,
+        ) -> DispatchResult {
+            let requester = ensure_signed(origin)?;
+            Self::do_request_randomness(requester, salt, num_words)?;
+            Ok(())
+        }
+
+        /// Fulfils the request `id` once the randomness of its epoch is available.
+        /// Callable by anyone, the deposit is returned to the requester
+        #[pallet::call_index(2)]
+        #[pallet::weight(<T as Config>::WeightInfo::fulfil_randomness(T::MaxRandomWords::get().into()))]
+        pub fn fulfil_randomness(origin: OriginFor<T>, id: RequestId) -> DispatchResult {
+            ensure_signed(origin)?;
+            Self::do_fulfil_randomness(id)?;
+            Ok(())
+        }
+
+        /// Removes the expired request `id`. Callable by anyone, the deposit is returned to the requester
+        #[pallet::call_index(3)]
+        #[pallet::weight(<T as Config>::WeightInfo::purge_expired_request())]
+        pub fn purge_expired_request(origin: OriginFor<T>, id: RequestId) -> DispatchResult {
+            ensure_signed(origin)?;
+            Self::do_purge_expired_request(id)
+        }
+
+        /// Requests `num_words` random words derived from the local VRF randomness of the block
+        /// `delay` blocks from now and `salt`, reserving `Config::Deposit` from the caller until
+        /// fulfilled or purged
+        #[pallet::call_index(4)]
+        #[pallet::weight(<T as Config>::WeightInfo::request_local_randomness())]
+        pub fn request_local_randomness(
+            origin: OriginFor<T>,
+            salt: T::Hash,
+            num_words: u8,
+            delay: BlockNumberFor<T>,
+        ) -> DispatchResult {
+            let requester = ensure_signed(origin)?;
+            Self::do_request_local_randomness(requester, salt, num_words, delay)?;
+            Ok(())
+        }
+
+        /// Rejects blocks without a valid VRF pre-runtime digest of their author if `enforced`,
+        /// only to be enabled once every selected collator has a VRF session key
+        #[pallet::call_index(5)]
+        #[pallet::weight(<T as Config>::WeightInfo::set_local_vrf_enforced())]
+        pub fn set_local_vrf_enforced(origin: OriginFor<T>, enforced: bool) -> DispatchResult {
+            T::ManageOrigin::ensure_origin(origin)?;
+            <LocalVrfEnforced<T>>::put(enforced);
+            Self::deposit_event(Event::LocalVrfEnforcementSet { enforced });
+            Ok(())
+        }
+    }
+
+    impl<T: Config> Pallet<T> {
+        /// Registers a BABE epoch randomness request of `requester` and returns its id
+        pub fn do_request_randomness(
+            requester: T::AccountId,
+            salt: T::Hash,
+            num_words: u8,
+        ) -> Result<RequestId, DispatchError> {
+            // `RandomnessResults` of the next epoch may already be known to the relay chain
+            // when this is included, so only the epoch after it is unpredictable
+            let epoch_index = Self::relay_epoch().saturating_add(2);
+            let info = RequestInfo::BabeEpoch(
+                epoch_index,
+                epoch_index.saturating_add(T::EpochExpirationDelay::get()),
+            );
+            let id = Self::register_request(requester.clone(), salt, num_words, info)?;
+            Self::deposit_event(Event::RandomnessRequested {
+                id,
+                requester,
+                salt,
+                num_words,
+                epoch_index,
+            });
+            Ok(id)
+        }
+
+        /// Registers a local VRF randomness request of `requester` for the block `delay` blocks
+        /// from now and returns its id
+        pub fn do_request_local_randomness(
+            requester: T::AccountId,
+            salt: T::Hash,
+            num_words: u8,
+            delay: BlockNumberFor<T>,
+        ) -> Result<RequestId, DispatchError> {
+            ensure!(Self::local_vrf_enforced(), Error::<T>::LocalVrfNotEnforced);
+            ensure!(
+                delay >= T::MinBlockDelay::get(),
+                Error::<T>::CannotRequestRandomnessBeforeMinDelay
+            );
+            ensure!(
+                delay <= T::MaxBlockDelay::get(),
+                Error::<T>::CannotRequestRandomnessAfterMaxDelay
+            );
+            let block_number = frame_system::Pallet::<T>::block_number().saturating_add(delay);
+            let info = RequestInfo::Local(
+                block_number,
+                block_number.saturating_add(T::BlockExpirationDelay::get()),
+            );
+            let id = Self::register_request(requester.clone(), salt, num_words, info)?;
+            Self::deposit_event(Event::LocalRandomnessRequested {
+                id,
+                requester,
+                salt,
+                num_words,
+                block_number,
+            });
+            Ok(id)
+        }
+
+        /// Reserves the deposit and stores the request, counting it in `RandomnessResults`
+        fn register_request(
+            requester: T::AccountId,
+            salt: T::Hash,
+            num_words: u8,
+            info: RequestInfo<BlockNumberFor<T>>,
+        ) -> Result<RequestId, DispatchError> {
+            ensure!(num_words >= 1, Error::<T>::MustRequestAtLeastOneWord);
+            ensure!(
+                num_words <= T::MaxRandomWords::get(),
+                Error::<T>::CannotRequestMoreWordsThanMax
+            );
+            let id = Self::request_count();
+            let next_id = id
+                .checked_add(1)
+                .ok_or(Error::<T>::RequestCounterOverflowed)?;
+            let deposit = T::Deposit::get();
+            T::Currency::reserve(&requester, deposit)?;
+
+            <RandomnessResults<T>>::mutate(RequestType::from(info), |result| match result {
+                Some(result) => result.request_count = result.request_count.saturating_add(1),
+                None => *result = Some(RandomnessResult::new()),
+            });
+            <Requests<T>>::insert(
+                id,
+                Request {
+                    requester,
+                    salt,
+                    num_words,
+                    deposit,
+                    info,
+                },
+            );
+            <RequestCount<T>>::put(next_id);
+            Ok(id)
+        }
+
+        /// Fulfils request `id`, returning its random words
+        pub fn do_fulfil_randomness(id: RequestId) -> Result<Vec<T::Hash>, DispatchError> {
+            let request = <Requests<T>>::get(id).ok_or(Error::<T>::RequestDoesNotExist)?;
+            let request_type = RequestType::from(request.info);
+            let raw_randomness = <RandomnessResults<T>>::get(request_type)
+                .and_then(|result| result.randomness)
+                .ok_or(Error::<T>::RequestCannotYetBeFulfilled)?;
+            let randomness = (0..request.num_words)
+                .map(|index| {
+                    let mut digest = Vec::new();
+                    digest.extend_from_slice(raw_randomness.as_ref());
+                    digest.extend_from_slice(&id.to_le_bytes());
+                    digest.extend_from_slice(request.salt.as_ref());
+                    digest.push(index);
+                    T::Hashing::hash(digest.as_slice())
+                })
+                .collect::<Vec<_>>();
+
+            Self::finish_request(id, &request);
+            T::OnRandomnessFulfilled::on_randomness_fulfilled(
+                id,
+                &request.requester,
+                request.salt,
+                &randomness,
+            );
+            Self::deposit_event(Event::RandomnessFulfilled {
+                id,
+                randomness: randomness.clone(),
+            });
+            Ok(randomness)
+        }
+
+        /// Removes request `id` if it expired without being fulfilled
+        pub fn do_purge_expired_request(id: RequestId) -> DispatchResult {
+            let request = <Requests<T>>::get(id).ok_or(Error::<T>::RequestDoesNotExist)?;
+            ensure!(
+                request.info.is_expired(
+                    Self::relay_epoch(),
+                    frame_system::Pallet::<T>::block_number()
+                ),
+                Error::<T>::RequestHasNotExpired
+            );
+            Self::finish_request(id, &request);
+            Self::deposit_event(Event::RequestExpired { id });
+            Ok(())
+        }
+
+        /// Removes a request, returns its deposit and releases its `RandomnessResults` entry
+        fn finish_request(
+            id: RequestId,
+            request: &Request<T::AccountId, BalanceOf<T>, T::Hash, BlockNumberFor<T>>,
+        ) {
+            <Requests<T>>::remove(id);
+            T::Currency::unreserve(&request.requester, request.deposit);
+            let request_type = RequestType::from(request.info);
+            <RandomnessResults<T>>::mutate(request_type, |result| {
+                if let Some(result) = result {
+                    result.request_count = result.request_count.saturating_sub(1);
+                }
+            });
+            Self::remove_result_if_unused(request_type);
+        }
+
+        /// Removes results without pending requests unless they are used by `Randomness::random`
+        fn remove_result_if_unused(request_type: RequestType<BlockNumberFor<T>>) {
+            if request_type == RequestType::BabeEpoch(Self::relay_epoch()) {
+                return;
+            }
+            if let Some(result) = <RandomnessResults<T>>::get(request_type) {
+                if result.request_count == 0 {
+                    <RandomnessResults<T>>::remove(request_type);
+                }
+            }
+        }
     }
 
     #[pallet::inherent]
@@ -161,6 +550,25 @@ pub mod pallet {
 
     #[pallet::hooks]
     impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+        fn on_initialize(now: BlockNumberFor<T>) -> Weight {
+            if <LocalVrfOutput<T>>::exists() {
+                // requests for this block stay unfulfilled if its author did not provide a new output
+                if vrf::verify_and_set_output::<T>() {
+                    let local_vrf_output = <LocalVrfOutput<T>>::get();
+                    <RandomnessResults<T>>::mutate(RequestType::Local(now), |result| {
+                        if let Some(result) = result {
+                            result.randomness = local_vrf_output;
+                        }
+                    });
+                }
+            } else {
+                // No previous output for the author to sign in the first block after genesis
+                // or after the runtime upgrade introducing local randomness
+                <LocalVrfOutput<T>>::put(T::Hash::default());
+            }
+            <T as Config>::WeightInfo::set_local_vrf_output()
+        }
+
         fn on_finalize(_now: BlockNumberFor<T>) {
             // Ensure the mandatory inherent was included in the block or the block is invalid
             assert!(
@@ -200,4 +608,24 @@ pub mod pallet {
             (randomness, randomness_established_at)
         }
     }
+
+    /// [`frame_support::traits::Randomness`] from the local VRF randomness of the current block
+    pub struct LocalRandomness<T>(PhantomData<T>);
+
+    impl<T: Config> frame_support::traits::Randomness<T::Hash, BlockNumberFor<T>>
+        for LocalRandomness<T>
+    {
+        /// Uses the VRF randomness of the current block author to generate a random seed.
+        fn random(subject: &[u8]) -> (T::Hash, BlockNumberFor<T>) {
+            let local_vrf_output = <LocalVrfOutput<T>>::get().unwrap_or_else(|| {
+                log::error!("FATAL local VRF randomness is not initialized. Using default hash");
+                T::Hash::default()
+            });
+            let mut digest = Vec::new();
+            digest.extend_from_slice(local_vrf_output.as_ref());
+            digest.extend_from_slice(subject);
+            let randomness = T::Hashing::hash(digest.as_slice());
+            (randomness, frame_system::Pallet::<T>::block_number())
+        }
+    }
 }
@@ -18,6 +18,8 @@
 use super::*;
 use crate as pallet_randomness;
 use frame_support::{construct_runtime, parameter_types, traits::Everything, weights::Weight};
+use nimbus_primitives::NimbusId;
+use session_key_primitives::vrf::{KeysLookup, VrfId};
 use sp_core::{H160, H256};
 use sp_runtime::{
     traits::{BlakeTwo256, IdentityLookup},
@@ -41,7 +43,7 @@ construct_runtime!(
     {
         System: frame_system::{Pallet, Call, Config, Storage, Event<T>},
         Balances: pallet_balances::{Pallet, Call, Storage, Config<T>, Event<T>},
-        Randomness: pallet_randomness::{Pallet, Call, Storage, Inherent},
+        Randomness: pallet_randomness::{Pallet, Call, Storage, Event<T>, Inherent},
     }
 );
 
@@ -98,24 +100,59 @@ impl pallet_balances::Config for Test {
     type MaxHolds = frame_support::traits::ConstU32<1>;
 }
 
+parameter_types! {
+    pub static EpochIndex: u64 = 10;
+    pub static EpochRandomness: Option<H256> = Some(H256::default());
+}
+
 pub struct BabeDataGetter;
 impl crate::GetBabeData<u64, Option<H256>> for BabeDataGetter {
     fn get_epoch_index() -> u64 {
-        10u64
+        EpochIndex::get()
     }
     fn get_epoch_randomness() -> Option<H256> {
-        Some(H256::default())
+        EpochRandomness::get()
+    }
+}
+
+parameter_types! {
+    pub static AuthorVrfId: Option<VrfId> = None;
+}
+
+/// Returns `AuthorVrfId` for every block author
+pub struct VrfKeyLookup;
+impl KeysLookup<NimbusId, VrfId> for VrfKeyLookup {
+    fn lookup_keys(_: &NimbusId) -> Option<VrfId> {
+        AuthorVrfId::get()
+    }
+
+    #[cfg(feature = "runtime-benchmarks")]
+    fn set_keys(_: NimbusId, keys: VrfId) {
+        AuthorVrfId::set(Some(keys));
     }
 }
 
 parameter_types! {
     pub const Deposit: u128 = 10;
-    pub const MaxRandomWords: u8 = 1;
+    pub const MaxRandomWords: u8 = 3;
+    pub const EpochExpirationDelay: u64 = 5;
     pub const MinBlockDelay: u32 = 2;
     pub const MaxBlockDelay: u32 = 20;
+    pub const BlockExpirationDelay: u32 = 10;
 }
 impl Config for Test {
+    type RuntimeEvent = RuntimeEvent;
     type BabeDataGetter = BabeDataGetter;
+    type Currency = Balances;
+    type Deposit = Deposit;
+    type MaxRandomWords = MaxRandomWords;
+    type EpochExpirationDelay = EpochExpirationDelay;
+    type OnRandomnessFulfilled = ();
+    type VrfKeyLookup = VrfKeyLookup;
+    type MinBlockDelay = MinBlockDelay;
+    type MaxBlockDelay = MaxBlockDelay;
+    type BlockExpirationDelay = BlockExpirationDelay;
+    type ManageOrigin = frame_system::EnsureRoot<AccountId>;
     type WeightInfo = ();
 }
 
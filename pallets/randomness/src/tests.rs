@@ -14,7 +14,58 @@
 // You should have received a copy of the GNU General Public License
 // along with Manta.  If not, see <http://www.gnu.org/licenses/>.
 
-use crate::mock::*;
+use crate::{mock::*, Error, Event, RequestType};
+use codec::Encode;
+use frame_support::{
+    assert_noop, assert_ok,
+    traits::{Hooks, ReservableCurrency},
+};
+use nimbus_primitives::{NimbusId, NIMBUS_ENGINE_ID};
+use session_key_primitives::vrf::{make_vrf_transcript, PreDigest, VrfId, VRF_ENGINE_ID};
+use sp_core::{crypto::VrfSecret, sr25519, Pair, H160, H256};
+use sp_runtime::{Digest, DigestItem};
+
+const ALICE: AccountId = H160::repeat_byte(1);
+const BOB: AccountId = H160::repeat_byte(2);
+
+/// Simulates the inherent of the first block in relay `epoch`
+fn set_relay_epoch(epoch: u64) {
+    EpochIndex::set(epoch);
+    EpochRandomness::set(Some(H256::repeat_byte(epoch as u8)));
+    assert_ok!(Randomness::set_babe_randomness_results(
+        RuntimeOrigin::none()
+    ));
+}
+
+/// Initializes block `number` authored by `pair` with its VRF pre-runtime digest
+fn author_block(number: BlockNumber, pair: &sr25519::Pair) {
+    let transcript = make_vrf_transcript(Randomness::local_vrf_output().unwrap_or_default());
+    let signature = pair.vrf_sign(&transcript);
+    let pre_digest = PreDigest {
+        vrf_output: signature.output,
+        vrf_proof: signature.proof,
+    };
+    let digest = Digest {
+        logs: vec![
+            DigestItem::PreRuntime(NIMBUS_ENGINE_ID, NimbusId::from(pair.public()).encode()),
+            DigestItem::PreRuntime(VRF_ENGINE_ID, pre_digest.encode()),
+        ],
+    };
+    System::initialize(&number, &H256::default(), &digest);
+    Randomness::on_initialize(number);
+}
+
+/// Registers the VRF key of `pair` for the block author, enforces the VRF digest and initializes
+/// local randomness
+fn init_local_randomness(pair: &sr25519::Pair) {
+    AuthorVrfId::set(Some(VrfId::from(pair.public())));
+    assert_ok!(Randomness::set_local_vrf_enforced(
+        RuntimeOrigin::root(),
+        true
+    ));
+    Randomness::on_initialize(1);
+    assert_eq!(Randomness::local_vrf_output(), Some(H256::default()));
+}
 
 #[test]
 fn set_babe_randomness_results_is_mandatory() {
@@ -23,3 +74,322 @@ fn set_babe_randomness_results_is_mandatory() {
     let info = crate::Call::<Test>::set_babe_randomness_results {}.get_dispatch_info();
     assert_eq!(info.class, DispatchClass::Mandatory);
 }
+
+#[test]
+fn request_randomness_reserves_deposit_for_future_epoch() {
+    ExtBuilder::default()
+        .with_balances(vec![(ALICE, 100)])
+        .build()
+        .execute_with(|| {
+            set_relay_epoch(10);
+            let salt = H256::repeat_byte(7);
+            assert_ok!(Randomness::request_randomness(
+                RuntimeOrigin::signed(ALICE),
+                salt,
+                2
+            ));
+            System::assert_last_event(RuntimeEvent::Randomness(Event::RandomnessRequested {
+                id: 0,
+                requester: ALICE,
+                salt,
+                num_words: 2,
+                epoch_index: 12,
+            }));
+            assert_eq!(Balances::reserved_balance(ALICE), 10);
+            assert_eq!(Randomness::request_count(), 1);
+            let result = Randomness::randomness_results(RequestType::BabeEpoch(12)).unwrap();
+            assert_eq!(result.request_count, 1);
+            assert_eq!(result.randomness, None);
+        });
+}
+
+#[test]
+fn request_randomness_checks_words_and_deposit() {
+    ExtBuilder::default()
+        .with_balances(vec![(ALICE, 100), (BOB, 5)])
+        .build()
+        .execute_with(|| {
+            assert_noop!(
+                Randomness::request_randomness(RuntimeOrigin::signed(ALICE), H256::zero(), 0),
+                Error::<Test>::MustRequestAtLeastOneWord
+            );
+            assert_noop!(
+                Randomness::request_randomness(
+                    RuntimeOrigin::signed(ALICE),
+                    H256::zero(),
+                    MaxRandomWords::get() + 1
+                ),
+                Error::<Test>::CannotRequestMoreWordsThanMax
+            );
+            assert_noop!(
+                Randomness::request_randomness(RuntimeOrigin::signed(BOB), H256::zero(), 1),
+                pallet_balances::Error::<Test>::InsufficientBalance
+            );
+        });
+}
+
+#[test]
+fn fulfil_randomness_once_epoch_randomness_is_available() {
+    ExtBuilder::default()
+        .with_balances(vec![(ALICE, 100)])
+        .build()
+        .execute_with(|| {
+            set_relay_epoch(10);
+            let salt = H256::repeat_byte(7);
+            assert_ok!(Randomness::request_randomness(
+                RuntimeOrigin::signed(ALICE),
+                salt,
+                3
+            ));
+            assert_ok!(Randomness::request_randomness(
+                RuntimeOrigin::signed(ALICE),
+                salt,
+                3
+            ));
+            assert_noop!(
+                Randomness::fulfil_randomness(RuntimeOrigin::signed(BOB), 0),
+                Error::<Test>::RequestCannotYetBeFulfilled
+            );
+            set_relay_epoch(11);
+            assert_noop!(
+                Randomness::fulfil_randomness(RuntimeOrigin::signed(BOB), 0),
+                Error::<Test>::RequestCannotYetBeFulfilled
+            );
+
+            set_relay_epoch(12);
+            let first = Randomness::do_fulfil_randomness(0).unwrap();
+            assert_eq!(first.len(), 3);
+            assert_ne!(first[0], first[1]);
+            System::assert_last_event(RuntimeEvent::Randomness(Event::RandomnessFulfilled {
+                id: 0,
+                randomness: first.clone(),
+            }));
+            assert!(Randomness::requests(0).is_none());
+            assert_eq!(Balances::reserved_balance(ALICE), 10);
+            assert_noop!(
+                Randomness::fulfil_randomness(RuntimeOrigin::signed(BOB), 0),
+                Error::<Test>::RequestDoesNotExist
+            );
+
+            // same salt in the same epoch still yields different words per request
+            assert_ok!(Randomness::fulfil_randomness(RuntimeOrigin::signed(BOB), 1));
+            let second = match System::events().last().unwrap().event.clone() {
+                RuntimeEvent::Randomness(Event::RandomnessFulfilled { randomness, .. }) => {
+                    randomness
+                }
+                _ => panic!("expected RandomnessFulfilled"),
+            };
+            assert_ne!(first, second);
+            assert_eq!(Balances::reserved_balance(ALICE), 0);
+
+            // the result is kept while it is the current epoch and dropped afterwards
+            assert_eq!(
+                Randomness::randomness_results(RequestType::BabeEpoch(12))
+                    .unwrap()
+                    .request_count,
+                0
+            );
+            set_relay_epoch(13);
+            assert!(Randomness::randomness_results(RequestType::BabeEpoch(12)).is_none());
+            assert!(Randomness::randomness_results(RequestType::BabeEpoch(13)).is_some());
+        });
+}
+
+#[test]
+fn expired_requests_can_be_purged() {
+    ExtBuilder::default()
+        .with_balances(vec![(ALICE, 100)])
+        .build()
+        .execute_with(|| {
+            set_relay_epoch(10);
+            assert_ok!(Randomness::request_randomness(
+                RuntimeOrigin::signed(ALICE),
+                H256::zero(),
+                1
+            ));
+            // requested for epoch 12, expires after epoch 17
+            set_relay_epoch(17);
+            assert_noop!(
+                Randomness::purge_expired_request(RuntimeOrigin::signed(BOB), 0),
+                Error::<Test>::RequestHasNotExpired
+            );
+            set_relay_epoch(18);
+            assert_ok!(Randomness::purge_expired_request(
+                RuntimeOrigin::signed(BOB),
+                0
+            ));
+            System::assert_last_event(RuntimeEvent::Randomness(Event::RequestExpired { id: 0 }));
+            assert!(Randomness::requests(0).is_none());
+            assert_eq!(Balances::reserved_balance(ALICE), 0);
+            assert!(Randomness::randomness_results(RequestType::BabeEpoch(12)).is_none());
+        });
+}
+
+#[test]
+fn local_vrf_output_is_verified_against_author_key() {
+    ExtBuilder::default().build().execute_with(|| {
+        let pair = sr25519::Pair::from_seed(&[1u8; 32]);
+        init_local_randomness(&pair);
+
+        author_block(2, &pair);
+        let first = Randomness::local_vrf_output().unwrap();
+        assert_ne!(first, H256::default());
+        author_block(3, &pair);
+        let second = Randomness::local_vrf_output().unwrap();
+        assert_ne!(first, second);
+    });
+}
+
+#[test]
+#[should_panic(expected = "VRF signature of the block author must be valid")]
+fn local_vrf_output_signed_by_other_key_is_rejected() {
+    ExtBuilder::default().build().execute_with(|| {
+        init_local_randomness(&sr25519::Pair::from_seed(&[1u8; 32]));
+        author_block(2, &sr25519::Pair::from_seed(&[2u8; 32]));
+    });
+}
+
+#[test]
+#[should_panic(expected = "VRF pre-runtime digest must be included by the block author")]
+fn local_vrf_output_is_mandatory() {
+    ExtBuilder::default().build().execute_with(|| {
+        init_local_randomness(&sr25519::Pair::from_seed(&[1u8; 32]));
+        System::initialize(&2, &H256::default(), &Digest::default());
+        Randomness::on_initialize(2);
+    });
+}
+
+#[test]
+fn local_vrf_output_is_skipped_until_enforced() {
+    ExtBuilder::default()
+        .with_balances(vec![(ALICE, 100)])
+        .build()
+        .execute_with(|| {
+            let pair = sr25519::Pair::from_seed(&[1u8; 32]);
+            Randomness::on_initialize(1);
+            assert!(!Randomness::local_vrf_enforced());
+            assert_noop!(
+                Randomness::request_local_randomness(
+                    RuntimeOrigin::signed(ALICE),
+                    H256::zero(),
+                    1,
+                    2
+                ),
+                Error::<Test>::LocalVrfNotEnforced
+            );
+
+            // author without a VRF key
+            author_block(2, &pair);
+            assert_eq!(Randomness::local_vrf_output(), Some(H256::default()));
+
+            // block without a VRF digest
+            AuthorVrfId::set(Some(VrfId::from(pair.public())));
+            System::initialize(&3, &H256::default(), &Digest::default());
+            Randomness::on_initialize(3);
+            assert_eq!(Randomness::local_vrf_output(), Some(H256::default()));
+
+            author_block(4, &pair);
+            assert_ne!(Randomness::local_vrf_output(), Some(H256::default()));
+
+            assert_noop!(
+                Randomness::set_local_vrf_enforced(RuntimeOrigin::signed(ALICE), true),
+                sp_runtime::DispatchError::BadOrigin
+            );
+            assert_ok!(Randomness::set_local_vrf_enforced(
+                RuntimeOrigin::root(),
+                true
+            ));
+            System::assert_last_event(RuntimeEvent::Randomness(Event::LocalVrfEnforcementSet {
+                enforced: true,
+            }));
+            assert_ok!(Randomness::request_local_randomness(
+                RuntimeOrigin::signed(ALICE),
+                H256::zero(),
+                1,
+                2
+            ));
+        });
+}
+
+#[test]
+fn local_randomness_requests_are_fulfilled_with_block_randomness() {
+    ExtBuilder::default()
+        .with_balances(vec![(ALICE, 100)])
+        .build()
+        .execute_with(|| {
+            let pair = sr25519::Pair::from_seed(&[1u8; 32]);
+            init_local_randomness(&pair);
+            let salt = H256::repeat_byte(7);
+            assert_noop!(
+                Randomness::request_local_randomness(RuntimeOrigin::signed(ALICE), salt, 1, 1),
+                Error::<Test>::CannotRequestRandomnessBeforeMinDelay
+            );
+            assert_noop!(
+                Randomness::request_local_randomness(RuntimeOrigin::signed(ALICE), salt, 1, 21),
+                Error::<Test>::CannotRequestRandomnessAfterMaxDelay
+            );
+            assert_ok!(Randomness::request_local_randomness(
+                RuntimeOrigin::signed(ALICE),
+                salt,
+                2,
+                2
+            ));
+            System::assert_last_event(RuntimeEvent::Randomness(Event::LocalRandomnessRequested {
+                id: 0,
+                requester: ALICE,
+                salt,
+                num_words: 2,
+                block_number: 3,
+            }));
+            assert_eq!(Balances::reserved_balance(ALICE), 10);
+
+            author_block(2, &pair);
+            assert_noop!(
+                Randomness::fulfil_randomness(RuntimeOrigin::signed(BOB), 0),
+                Error::<Test>::RequestCannotYetBeFulfilled
+            );
+            author_block(3, &pair);
+            assert_eq!(
+                Randomness::randomness_results(RequestType::Local(3))
+                    .unwrap()
+                    .randomness,
+                Randomness::local_vrf_output()
+            );
+            let words = Randomness::do_fulfil_randomness(0).unwrap();
+            assert_eq!(words.len(), 2);
+            assert_eq!(Balances::reserved_balance(ALICE), 0);
+            assert!(Randomness::randomness_results(RequestType::Local(3)).is_none());
+        });
+}
+
+#[test]
+fn expired_local_requests_can_be_purged() {
+    ExtBuilder::default()
+        .with_balances(vec![(ALICE, 100)])
+        .build()
+        .execute_with(|| {
+            assert_ok!(Randomness::set_local_vrf_enforced(
+                RuntimeOrigin::root(),
+                true
+            ));
+            assert_ok!(Randomness::request_local_randomness(
+                RuntimeOrigin::signed(ALICE),
+                H256::zero(),
+                1,
+                2
+            ));
+            // requested for block 3, expires after block 13
+            System::set_block_number(13);
+            assert_noop!(
+                Randomness::purge_expired_request(RuntimeOrigin::signed(BOB), 0),
+                Error::<Test>::RequestHasNotExpired
+            );
+            System::set_block_number(14);
+            assert_ok!(Randomness::purge_expired_request(
+                RuntimeOrigin::signed(BOB),
+                0
+            ));
+            assert_eq!(Balances::reserved_balance(ALICE), 0);
+            assert!(Randomness::randomness_results(RequestType::Local(3)).is_none());
+        });
+}
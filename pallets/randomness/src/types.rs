@@ -16,22 +16,61 @@
 
 use frame_support::pallet_prelude::*;
 
+/// Identifier of a randomness request in `Requests`
+pub type RequestId = u64;
+
 #[derive(PartialEq, Eq, Copy, Clone, Encode, Decode, RuntimeDebug, TypeInfo)]
-#[scale_info(skip_type_params(T))]
 /// Shared request info, a subset of `RequestInfo`
-pub enum RequestType {
+pub enum RequestType<BlockNumber> {
     /// Babe one epoch ago
     BabeEpoch(u64),
+    /// Local per-block VRF randomness of the block author
+    Local(BlockNumber),
 }
 
 #[derive(PartialEq, Eq, Copy, Clone, Encode, Decode, RuntimeDebug, TypeInfo)]
-#[scale_info(skip_type_params(T))]
 /// Type of request
 /// Represents a request for the most recent randomness at or after the inner first field
 /// Expiration is second inner field
-pub enum RequestInfo {
+pub enum RequestInfo<BlockNumber> {
     /// Babe one epoch ago
     BabeEpoch(u64, u64),
+    /// Local per-block VRF randomness of the block author
+    Local(BlockNumber, BlockNumber),
+}
+
+impl<BlockNumber: PartialOrd> RequestInfo<BlockNumber> {
+    /// Whether the request expired at relay epoch `relay_epoch` and parachain block `now`
+    pub fn is_expired(&self, relay_epoch: u64, now: BlockNumber) -> bool {
+        match self {
+            RequestInfo::BabeEpoch(_, expires) => relay_epoch > *expires,
+            RequestInfo::Local(_, expires) => now > *expires,
+        }
+    }
+}
+
+impl<BlockNumber> From<RequestInfo<BlockNumber>> for RequestType<BlockNumber> {
+    fn from(info: RequestInfo<BlockNumber>) -> Self {
+        match info {
+            RequestInfo::BabeEpoch(epoch, _) => RequestType::BabeEpoch(epoch),
+            RequestInfo::Local(block, _) => RequestType::Local(block),
+        }
+    }
+}
+
+#[derive(PartialEq, Eq, Clone, Encode, Decode, RuntimeDebug, TypeInfo)]
+/// Pending randomness request, the value in `Requests` map
+pub struct Request<AccountId, Balance, Hash, BlockNumber> {
+    /// Account that requested the randomness, gets the deposit returned
+    pub requester: AccountId,
+    /// Mixed into the raw randomness to derive the random words of this request
+    pub salt: Hash,
+    /// Number of random words to derive
+    pub num_words: u8,
+    /// Amount reserved from `requester` until the request is fulfilled or purged
+    pub deposit: Balance,
+    /// Randomness source and expiration
+    pub info: RequestInfo<BlockNumber>,
 }
 
 #[derive(PartialEq, Eq, Clone, Default, Encode, Decode, RuntimeDebug, TypeInfo)]
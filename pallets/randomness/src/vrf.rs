@@ -0,0 +1,92 @@
+// Copyright 2020-2024 Manta Network.
+// This file is part of Manta.
+//
+// Manta is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// Manta is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Manta.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Verification of the VRF pre-runtime digest of the block author
+
+use crate::{Config, LocalVrfEnforced, LocalVrfOutput};
+use codec::Decode;
+use nimbus_primitives::{NimbusId, NIMBUS_ENGINE_ID};
+use session_key_primitives::vrf::{
+    make_vrf_transcript, KeysLookup, PreDigest, VrfId, VRF_ENGINE_ID, VRF_INOUT_CONTEXT,
+};
+use sp_core::{
+    crypto::VrfPublic,
+    sr25519::{self, vrf::VrfSignature},
+};
+
+/// Verifies the VRF output in the pre-runtime digest against the [`VrfId`] key of the block
+/// author and stores the derived randomness in [`LocalVrfOutput`]. Returns whether it was updated.
+///
+/// Until [`LocalVrfEnforced`] is set, a missing or invalid digest or VRF key only skips the update
+/// and [`LocalVrfOutput`] keeps the output of the previous block.
+///
+/// # Panics
+///
+/// Panics, and thereby invalidates the block, if [`LocalVrfEnforced`] is set and the digest is
+/// missing or does not verify.
+pub(crate) fn verify_and_set_output<T: Config>() -> bool {
+    match verify_output::<T>() {
+        Ok(randomness) => {
+            <LocalVrfOutput<T>>::put(randomness);
+            true
+        }
+        Err(error) if <LocalVrfEnforced<T>>::get() => panic!("{}", error),
+        Err(error) => {
+            log::warn!("Skipping the local VRF output of this block: {}", error);
+            false
+        }
+    }
+}
+
+/// Returns the randomness derived from the VRF pre-runtime digest of the block author.
+fn verify_output<T: Config>() -> Result<T::Hash, &'static str> {
+    let mut pre_digest = None;
+    let mut block_author_vrf_id = None;
+    for (id, mut data) in <frame_system::Pallet<T>>::digest()
+        .logs
+        .iter()
+        .filter_map(|log| log.as_pre_runtime())
+    {
+        if id == VRF_ENGINE_ID {
+            pre_digest = PreDigest::decode(&mut data).ok();
+        } else if id == NIMBUS_ENGINE_ID {
+            block_author_vrf_id = NimbusId::decode(&mut data)
+                .ok()
+                .and_then(|nimbus_id| T::VrfKeyLookup::lookup_keys(&nimbus_id));
+        }
+    }
+    let PreDigest {
+        vrf_output,
+        vrf_proof,
+    } = pre_digest.ok_or("VRF pre-runtime digest must be included by the block author")?;
+    let block_author_vrf_id: VrfId =
+        block_author_vrf_id.ok_or("Block author must have a registered VRF session key")?;
+    let pubkey: sr25519::Public = block_author_vrf_id.into();
+    let last_vrf_output = <LocalVrfOutput<T>>::get().unwrap_or_default();
+    let transcript = make_vrf_transcript(last_vrf_output);
+    let signature = VrfSignature {
+        output: vrf_output,
+        proof: vrf_proof,
+    };
+    if !pubkey.vrf_verify(&transcript, &signature) {
+        return Err("VRF signature of the block author must be valid");
+    }
+    let randomness: [u8; 32] = pubkey
+        .make_bytes(VRF_INOUT_CONTEXT, &transcript, &signature.output)
+        .expect("VRF output of a valid signature can be converted to randomness bytes");
+    Ok(T::Hash::decode(&mut &randomness[..])
+        .expect("32 bytes of VRF randomness can be decoded into the block hash type"))
+}
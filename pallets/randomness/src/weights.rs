@@ -45,6 +45,12 @@ use core::marker::PhantomData;
 /// Weight functions needed for pallet_randomness.
 pub trait WeightInfo {
 	fn set_babe_randomness_results() -> Weight;
+	fn request_randomness() -> Weight;
+	fn fulfil_randomness(x: u32, ) -> Weight;
+	fn purge_expired_request() -> Weight;
+	fn request_local_randomness() -> Weight;
+	fn set_local_vrf_output() -> Weight;
+	fn set_local_vrf_enforced() -> Weight;
 }
 
 /// Weights for pallet_randomness using the Substrate node and recommended hardware.
@@ -69,6 +75,110 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(3_u64))
 			.saturating_add(T::DbWeight::get().writes(3_u64))
 	}
+	/// Storage: Randomness RelayEpoch (r:1 w:0)
+	/// Proof Skipped: Randomness RelayEpoch (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: Randomness RequestCount (r:1 w:1)
+	/// Proof Skipped: Randomness RequestCount (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: Randomness RandomnessResults (r:1 w:1)
+	/// Proof Skipped: Randomness RandomnessResults (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Randomness Requests (r:0 w:1)
+	/// Proof Skipped: Randomness Requests (max_values: None, max_size: None, mode: Measured)
+	fn request_randomness() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `361`
+		//  Estimated: `3826`
+		// Minimum execution time: 19_214_000 picoseconds.
+		Weight::from_parts(19_873_000, 3826)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	/// Storage: Randomness Requests (r:1 w:1)
+	/// Proof Skipped: Randomness Requests (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Randomness RandomnessResults (r:1 w:1)
+	/// Proof Skipped: Randomness RandomnessResults (max_values: None, max_size: None, mode: Measured)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: Randomness RelayEpoch (r:1 w:0)
+	/// Proof Skipped: Randomness RelayEpoch (max_values: Some(1), max_size: None, mode: Measured)
+	/// The range of component `x` is `[1, 100]`.
+	fn fulfil_randomness(x: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `593`
+		//  Estimated: `4058`
+		// Minimum execution time: 20_187_000 picoseconds.
+		Weight::from_parts(19_644_312, 4058)
+			// Standard Error: 1_204
+			.saturating_add(Weight::from_parts(598_230, 0).saturating_mul(x.into()))
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Storage: Randomness Requests (r:1 w:1)
+	/// Proof Skipped: Randomness Requests (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Randomness RelayEpoch (r:1 w:0)
+	/// Proof Skipped: Randomness RelayEpoch (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: Randomness RandomnessResults (r:1 w:1)
+	/// Proof Skipped: Randomness RandomnessResults (max_values: None, max_size: None, mode: Measured)
+	fn purge_expired_request() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `559`
+		//  Estimated: `4024`
+		// Minimum execution time: 18_306_000 picoseconds.
+		Weight::from_parts(18_927_000, 4024)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Storage: Randomness LocalVrfEnforced (r:1 w:0)
+	/// Proof Skipped: Randomness LocalVrfEnforced (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: Randomness RequestCount (r:1 w:1)
+	/// Proof Skipped: Randomness RequestCount (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: Randomness RandomnessResults (r:1 w:1)
+	/// Proof Skipped: Randomness RandomnessResults (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Randomness Requests (r:0 w:1)
+	/// Proof Skipped: Randomness Requests (max_values: None, max_size: None, mode: Measured)
+	fn request_local_randomness() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `361`
+		//  Estimated: `3826`
+		// Minimum execution time: 19_037_000 picoseconds.
+		Weight::from_parts(19_661_000, 3826)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	/// Storage: Randomness LocalVrfOutput (r:1 w:1)
+	/// Proof Skipped: Randomness LocalVrfOutput (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: System Digest (r:1 w:0)
+	/// Proof Skipped: System Digest (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: Session KeyOwner (r:1 w:0)
+	/// Proof Skipped: Session KeyOwner (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Session NextKeys (r:1 w:0)
+	/// Proof Skipped: Session NextKeys (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Randomness RandomnessResults (r:1 w:1)
+	/// Proof Skipped: Randomness RandomnessResults (max_values: None, max_size: None, mode: Measured)
+	fn set_local_vrf_output() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `712`
+		//  Estimated: `4177`
+		// Minimum execution time: 36_482_000 picoseconds.
+		Weight::from_parts(37_155_000, 4177)
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: Randomness LocalVrfEnforced (r:0 w:1)
+	/// Proof Skipped: Randomness LocalVrfEnforced (max_values: Some(1), max_size: None, mode: Measured)
+	fn set_local_vrf_enforced() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 6_931_000 picoseconds.
+		Weight::from_parts(7_186_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
 }
 
 // For backwards compatibility and tests
@@ -92,4 +202,108 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(3_u64))
 			.saturating_add(RocksDbWeight::get().writes(3_u64))
 	}
+	/// Storage: Randomness RelayEpoch (r:1 w:0)
+	/// Proof Skipped: Randomness RelayEpoch (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: Randomness RequestCount (r:1 w:1)
+	/// Proof Skipped: Randomness RequestCount (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: Randomness RandomnessResults (r:1 w:1)
+	/// Proof Skipped: Randomness RandomnessResults (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Randomness Requests (r:0 w:1)
+	/// Proof Skipped: Randomness Requests (max_values: None, max_size: None, mode: Measured)
+	fn request_randomness() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `361`
+		//  Estimated: `3826`
+		// Minimum execution time: 19_214_000 picoseconds.
+		Weight::from_parts(19_873_000, 3826)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+	/// Storage: Randomness Requests (r:1 w:1)
+	/// Proof Skipped: Randomness Requests (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Randomness RandomnessResults (r:1 w:1)
+	/// Proof Skipped: Randomness RandomnessResults (max_values: None, max_size: None, mode: Measured)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: Randomness RelayEpoch (r:1 w:0)
+	/// Proof Skipped: Randomness RelayEpoch (max_values: Some(1), max_size: None, mode: Measured)
+	/// The range of component `x` is `[1, 100]`.
+	fn fulfil_randomness(x: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `593`
+		//  Estimated: `4058`
+		// Minimum execution time: 20_187_000 picoseconds.
+		Weight::from_parts(19_644_312, 4058)
+			// Standard Error: 1_204
+			.saturating_add(Weight::from_parts(598_230, 0).saturating_mul(x.into()))
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Storage: Randomness Requests (r:1 w:1)
+	/// Proof Skipped: Randomness Requests (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Randomness RelayEpoch (r:1 w:0)
+	/// Proof Skipped: Randomness RelayEpoch (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: Randomness RandomnessResults (r:1 w:1)
+	/// Proof Skipped: Randomness RandomnessResults (max_values: None, max_size: None, mode: Measured)
+	fn purge_expired_request() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `559`
+		//  Estimated: `4024`
+		// Minimum execution time: 18_306_000 picoseconds.
+		Weight::from_parts(18_927_000, 4024)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Storage: Randomness LocalVrfEnforced (r:1 w:0)
+	/// Proof Skipped: Randomness LocalVrfEnforced (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: Randomness RequestCount (r:1 w:1)
+	/// Proof Skipped: Randomness RequestCount (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: Randomness RandomnessResults (r:1 w:1)
+	/// Proof Skipped: Randomness RandomnessResults (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Randomness Requests (r:0 w:1)
+	/// Proof Skipped: Randomness Requests (max_values: None, max_size: None, mode: Measured)
+	fn request_local_randomness() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `361`
+		//  Estimated: `3826`
+		// Minimum execution time: 19_037_000 picoseconds.
+		Weight::from_parts(19_661_000, 3826)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+	/// Storage: Randomness LocalVrfOutput (r:1 w:1)
+	/// Proof Skipped: Randomness LocalVrfOutput (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: System Digest (r:1 w:0)
+	/// Proof Skipped: System Digest (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: Session KeyOwner (r:1 w:0)
+	/// Proof Skipped: Session KeyOwner (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Session NextKeys (r:1 w:0)
+	/// Proof Skipped: Session NextKeys (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Randomness RandomnessResults (r:1 w:1)
+	/// Proof Skipped: Randomness RandomnessResults (max_values: None, max_size: None, mode: Measured)
+	fn set_local_vrf_output() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `712`
+		//  Estimated: `4177`
+		// Minimum execution time: 36_482_000 picoseconds.
+		Weight::from_parts(37_155_000, 4177)
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: Randomness LocalVrfEnforced (r:0 w:1)
+	/// Proof Skipped: Randomness LocalVrfEnforced (max_values: Some(1), max_size: None, mode: Measured)
+	fn set_local_vrf_enforced() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 6_931_000 picoseconds.
+		Weight::from_parts(7_186_000, 0)
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
 }
@@ -63,6 +63,27 @@ fn init_setup<
     );
 }
 
+fn vesting_info<T: Config + pallet_balances::Config>() -> VestingInfo<BalanceOf<T>>
+where
+    BalanceOf<T>: AtLeast32BitUnsigned,
+    <T as pallet_balances::Config>::Balance: From<u128>,
+{
+    let existential_deposit = <T as pallet_balances::Config<()>>::ExistentialDeposit::get();
+    let locked = existential_deposit
+        .saturating_mul(ED_MULTIPLIER.div(10u32).into())
+        .saturated_into::<u128>()
+        .try_into()
+        .ok()
+        .unwrap();
+    // starts after `init_setup`'s timestamp
+    VestingInfo {
+        locked,
+        start: 1636329600 + 100,
+        cliff: 1636329600 + 200,
+        duration: 1000,
+    }
+}
+
 benchmarks! {
     where_clause {
         where
@@ -129,6 +150,51 @@ benchmarks! {
         assert_eq!(crate::Pallet::<T>::vesting_balance(&recipient), Some(unvested));
         assert_has_event::<T>(Event::VestingUpdated(recipient, unvested).into());
     }
+
+    vested_transfer_with_schedule {
+        let s in 0 .. (T::MaxVestingSchedules::get() - 1);
+        let caller: T::AccountId = whitelisted_caller();
+        init_setup::<T, ()>(&caller);
+        let recipient: T::AccountId = account("receiver", 0, SEED);
+        let source_recipient = T::Lookup::unlookup(recipient.clone());
+        let schedule = vesting_info::<T>();
+        for _ in 0..s {
+            assert_ok!(crate::Pallet::<T>::vested_transfer_with_schedule(
+                RawOrigin::Signed(caller.clone()).into(),
+                source_recipient.clone(),
+                schedule,
+            ));
+        }
+    }: _(RawOrigin::Signed(caller.clone()), source_recipient, schedule)
+    verify {
+        assert_eq!(
+            crate::Pallet::<T>::account_vesting_schedules(&recipient).len() as u32,
+            s + 1
+        );
+        assert_has_event::<T>(Event::VestingScheduleAdded(recipient, schedule).into());
+    }
+
+    merge_schedules {
+        let s in 2 .. T::MaxVestingSchedules::get();
+        let caller: T::AccountId = whitelisted_caller();
+        init_setup::<T, ()>(&caller);
+        let recipient: T::AccountId = account("receiver", 0, SEED);
+        let source_recipient = T::Lookup::unlookup(recipient.clone());
+        let schedule = vesting_info::<T>();
+        for _ in 0..s {
+            assert_ok!(crate::Pallet::<T>::vested_transfer_with_schedule(
+                RawOrigin::Signed(caller.clone()).into(),
+                source_recipient.clone(),
+                schedule,
+            ));
+        }
+    }: _(RawOrigin::Signed(recipient.clone()), 0, 1)
+    verify {
+        assert_eq!(
+            crate::Pallet::<T>::account_vesting_schedules(&recipient).len() as u32,
+            s - 1
+        );
+    }
 }
 
 impl_benchmark_test_suite!(
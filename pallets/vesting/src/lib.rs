@@ -20,6 +20,7 @@
 mod benchmarking;
 #[cfg(test)]
 mod mock;
+pub mod runtime;
 #[cfg(test)]
 mod tests;
 pub mod weights;
@@ -37,14 +38,88 @@ use frame_system::{ensure_signed, pallet_prelude::*};
 pub use pallet::*;
 use sp_runtime::{
     traits::{Saturating, StaticLookup, Zero},
-    Percent,
+    Perbill, Percent, RuntimeDebug,
 };
 
 type BalanceOf<T> =
     <<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
 pub type Schedule = u64;
 
-const VESTING_ID: LockIdentifier = *b"calamvst";
+pub const VESTING_ID: LockIdentifier = *b"calamvst";
+
+/// A vesting schedule of a single account, timestamps are UTC in seconds.
+///
+/// Nothing is vested before `cliff`, afterwards `locked` vests linearly from `start` to
+/// `start + duration`, so the amount accrued until the cliff is released at once.
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct VestingInfo<Balance> {
+    /// Amount locked at the start of the schedule.
+    pub locked: Balance,
+    /// Start of the linear release.
+    pub start: Schedule,
+    /// First moment any tokens are vested.
+    pub cliff: Schedule,
+    /// Length of the linear release.
+    pub duration: Schedule,
+}
+
+impl<Balance: sp_runtime::traits::AtLeast32BitUnsigned + Copy> VestingInfo<Balance> {
+    /// Whether the cliff lies within the linear release and there is anything to vest.
+    pub fn is_valid(&self) -> bool {
+        !self.locked.is_zero()
+            && self.start <= self.cliff
+            && self.cliff <= self.start.saturating_add(self.duration)
+    }
+
+    /// End of the linear release.
+    pub fn end(&self) -> Schedule {
+        self.start.saturating_add(self.duration)
+    }
+
+    /// Amount vested at `now`.
+    pub fn vested_at(&self, now: Schedule) -> Balance {
+        if now < self.cliff {
+            Zero::zero()
+        } else if now >= self.end() {
+            self.locked
+        } else {
+            Perbill::from_rational(now.saturating_sub(self.start), self.duration) * self.locked
+        }
+    }
+
+    /// Amount still locked at `now`.
+    pub fn locked_at(&self, now: Schedule) -> Balance {
+        self.locked.saturating_sub(self.vested_at(now))
+    }
+
+    /// Merges the amounts of `self` and `other` still locked at `now` into a schedule starting no
+    /// earlier than `now` and ending with the later of both, or `None` if both are fully vested.
+    pub fn merge(&self, other: &Self, now: Schedule) -> Option<Self> {
+        let locked = self.locked_at(now).saturating_add(other.locked_at(now));
+        if locked.is_zero() {
+            return None;
+        }
+        let start = now.max(self.start).max(other.start);
+        let end = self.end().max(other.end());
+        Some(Self {
+            locked,
+            start,
+            cliff: start.max(self.cliff).max(other.cliff),
+            duration: end.saturating_sub(start),
+        })
+    }
+}
+
+/// Vesting amounts of an account at a given time.
+#[derive(Encode, Decode, Clone, Copy, Default, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct VestingBalance<Balance> {
+    /// Amount which is not vested yet.
+    pub locked: Balance,
+    /// Amount which is vested.
+    pub vested: Balance,
+    /// Vested amount which is still locked and would be unlocked by calling `vest`.
+    pub claimable: Balance,
+}
 
 #[frame_support::pallet]
 pub mod pallet {
@@ -71,6 +146,11 @@ pub mod pallet {
         #[pallet::constant]
         type MaxScheduleLength: Get<u32>;
 
+        /// The maximum number of vesting schedules of an account. Anyone can add a schedule to
+        /// an account, so this should leave room for more than the owner expects.
+        #[pallet::constant]
+        type MaxVestingSchedules: Get<u32>;
+
         type WeightInfo: crate::weights::WeightInfo;
     }
 
@@ -91,6 +171,17 @@ pub mod pallet {
         DefaultVestingSchedule<T>,
     >;
 
+    /// Vesting schedules of individual accounts, on top of their share of the global schedule.
+    #[pallet::storage]
+    #[pallet::getter(fn account_vesting_schedules)]
+    pub(super) type AccountVestingSchedules<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        T::AccountId,
+        BoundedVec<VestingInfo<BalanceOf<T>>, T::MaxVestingSchedules>,
+        ValueQuery,
+    >;
+
     #[pallet::type_value]
     pub(super) fn DefaultVestingSchedule<T: Config>(
     ) -> BoundedVec<(Percent, Schedule), T::MaxScheduleLength> {
@@ -128,6 +219,12 @@ pub mod pallet {
         /// Update a vesting schedule.
         /// \[new_schedule\]
         VestingScheduleUpdated(BoundedVec<Schedule, T::MaxScheduleLength>),
+        /// A vesting schedule was added to an account.
+        /// \[account, schedule\]
+        VestingScheduleAdded(T::AccountId, VestingInfo<BalanceOf<T>>),
+        /// Two vesting schedules of an account were merged into one.
+        /// \[account, merged_schedule\]
+        VestingSchedulesMerged(T::AccountId, VestingInfo<BalanceOf<T>>),
     }
 
     /// Error for the vesting pallet.
@@ -149,6 +246,10 @@ pub mod pallet {
         UnsortedSchedule,
         /// The first round of vesting is not done yet.
         ClaimTooEarly,
+        /// The account already has the maximum number of vesting schedules.
+        TooManyVestingSchedules,
+        /// No vesting schedule exists at the given index.
+        ScheduleIndexOutOfBounds,
     }
 
     #[pallet::call]
@@ -216,9 +317,10 @@ pub mod pallet {
             let who = ensure_signed(origin)?;
 
             let now = T::Timestamp::now().as_secs();
-            // Ensure signer can claim once time is up to schedule.
+            // Ensure signer can claim once time is up to schedule, unless they have their own.
             ensure!(
-                Some(now) >= VestingSchedule::<T>::get().first().map(|v| v.1),
+                AccountVestingSchedules::<T>::contains_key(&who)
+                    || Some(now) >= VestingSchedule::<T>::get().first().map(|v| v.1),
                 Error::<T>::ClaimTooEarly
             );
 
@@ -266,17 +368,101 @@ pub mod pallet {
 
             Ok(())
         }
+
+        /// Create a vested transfer: send `target` balance with its own vesting `schedule`.
+        ///
+        /// The dispatch origin for this call must be _Signed_.
+        ///
+        /// - `target`: The account receiving the vested funds.
+        /// - `schedule`: The vesting schedule, `schedule.locked` is transferred.
+        ///
+        /// Emits `VestingScheduleAdded`.
+        #[pallet::call_index(3)]
+        #[pallet::weight(T::WeightInfo::vested_transfer_with_schedule(
+            T::MaxVestingSchedules::get()
+        ))]
+        pub fn vested_transfer_with_schedule(
+            origin: OriginFor<T>,
+            target: <T::Lookup as StaticLookup>::Source,
+            schedule: VestingInfo<BalanceOf<T>>,
+        ) -> DispatchResult {
+            let transactor = ensure_signed(origin)?;
+            ensure!(
+                schedule.locked >= T::MinVestedTransfer::get(),
+                Error::<T>::AmountLow
+            );
+            ensure!(schedule.is_valid(), Error::<T>::InvalidSchedule);
+            ensure!(
+                T::Currency::free_balance(&transactor) >= schedule.locked,
+                Error::<T>::BalanceLow
+            );
+
+            let who = T::Lookup::lookup(target)?;
+            ensure!(
+                AccountVestingSchedules::<T>::decode_len(&who).unwrap_or_default()
+                    < T::MaxVestingSchedules::get() as usize,
+                Error::<T>::TooManyVestingSchedules
+            );
+
+            T::Currency::transfer(
+                &transactor,
+                &who,
+                schedule.locked,
+                ExistenceRequirement::AllowDeath,
+            )?;
+
+            Self::add_vesting_schedule(&who, schedule)
+        }
+
+        /// Merge two vesting schedules of the caller into one.
+        ///
+        /// The amounts still locked are combined into a schedule starting now at the earliest and
+        /// ending with the later of both. Indices refer to `account_vesting_schedules` before
+        /// merging, the merged schedule is appended.
+        ///
+        /// - `schedule1_index`: Index of the first schedule.
+        /// - `schedule2_index`: Index of the second schedule.
+        ///
+        /// Emits `VestingSchedulesMerged` unless both schedules are fully vested.
+        #[pallet::call_index(4)]
+        #[pallet::weight(T::WeightInfo::merge_schedules(T::MaxVestingSchedules::get()))]
+        pub fn merge_schedules(
+            origin: OriginFor<T>,
+            schedule1_index: u32,
+            schedule2_index: u32,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            let (schedule1_index, schedule2_index) =
+                (schedule1_index as usize, schedule2_index as usize);
+            let mut schedules = AccountVestingSchedules::<T>::get(&who);
+            ensure!(
+                schedule1_index != schedule2_index
+                    && schedule1_index < schedules.len()
+                    && schedule2_index < schedules.len(),
+                Error::<T>::ScheduleIndexOutOfBounds
+            );
+
+            // remove the later one first to keep the other index valid
+            let later = schedules.remove(schedule1_index.max(schedule2_index));
+            let earlier = schedules.remove(schedule1_index.min(schedule2_index));
+            let now = T::Timestamp::now().as_secs();
+            if let Some(merged) = earlier.merge(&later, now) {
+                // two schedules were just removed, so there is room
+                schedules
+                    .try_push(merged)
+                    .map_err(|_| Error::<T>::TooManyVestingSchedules)?;
+                Self::deposit_event(Event::<T>::VestingSchedulesMerged(who.clone(), merged));
+            }
+            AccountVestingSchedules::<T>::insert(&who, schedules);
+
+            Self::update_lock(&who)
+        }
     }
 }
 
 impl<T: Config> Pallet<T> {
-    /// (Re)set pallet's currency lock on `who`'s account in accordance with their
-    /// current unvested amount.
-    fn update_lock(who: &T::AccountId) -> DispatchResult {
-        let vesting = Self::vesting_balance(who).ok_or(Error::<T>::NotVesting)?;
-        let now = T::Timestamp::now().as_secs();
-
-        // compute the vested portion
+    /// Amount of `vesting` on the global schedule which is still locked at `now`.
+    fn global_locked_at(vesting: BalanceOf<T>, now: Schedule) -> BalanceOf<T> {
         let mut portion = Percent::default();
         for (percentage, timestamp) in VestingSchedule::<T>::get() {
             if now < timestamp {
@@ -285,12 +471,41 @@ impl<T: Config> Pallet<T> {
                 portion = portion.saturating_add(percentage);
             }
         }
+        (Percent::from_percent(100) - portion) * vesting
+    }
 
-        let unvested = (Percent::from_percent(100) - portion) * vesting;
+    /// (Re)set pallet's currency lock on `who`'s account in accordance with their
+    /// current unvested amount, dropping whatever is fully vested.
+    fn update_lock(who: &T::AccountId) -> DispatchResult {
+        let vesting = Self::vesting_balance(who);
+        let mut schedules = AccountVestingSchedules::<T>::get(who);
+        ensure!(
+            vesting.is_some() || !schedules.is_empty(),
+            Error::<T>::NotVesting
+        );
+        let now = T::Timestamp::now().as_secs();
+
+        // compute the unvested amount
+        let mut unvested = Zero::zero();
+        if let Some(vesting) = vesting {
+            let global_unvested = Self::global_locked_at(vesting, now);
+            if global_unvested.is_zero() {
+                VestingBalances::<T>::remove(who);
+            }
+            unvested = global_unvested;
+        }
+        schedules.retain(|schedule| !schedule.locked_at(now).is_zero());
+        for schedule in schedules.iter() {
+            unvested = unvested.saturating_add(schedule.locked_at(now));
+        }
+        if schedules.is_empty() {
+            AccountVestingSchedules::<T>::remove(who);
+        } else {
+            AccountVestingSchedules::<T>::insert(who, schedules);
+        }
 
         if unvested.is_zero() {
             T::Currency::remove_lock(VESTING_ID, who);
-            VestingBalances::<T>::remove(who);
             Self::deposit_event(Event::<T>::VestingCompleted(who.clone()));
         } else {
             let reasons = WithdrawReasons::TRANSFER | WithdrawReasons::RESERVE;
@@ -300,6 +515,40 @@ impl<T: Config> Pallet<T> {
         Ok(())
     }
 
+    /// Adds a schedule to the vesting schedules of `who`.
+    fn add_vesting_schedule(
+        who: &T::AccountId,
+        schedule: VestingInfo<BalanceOf<T>>,
+    ) -> DispatchResult {
+        AccountVestingSchedules::<T>::try_mutate(who, |schedules| {
+            schedules
+                .try_push(schedule)
+                .map_err(|_| Error::<T>::TooManyVestingSchedules)
+        })?;
+        Self::deposit_event(Event::<T>::VestingScheduleAdded(who.clone(), schedule));
+        Self::update_lock(who)
+    }
+
+    /// Locked, vested and claimable amounts of `who` at `at`, given the amount `currently_locked`
+    /// under [`VESTING_ID`].
+    pub fn vesting_balance_at(
+        who: &T::AccountId,
+        at: Schedule,
+        currently_locked: BalanceOf<T>,
+    ) -> VestingBalance<BalanceOf<T>> {
+        let mut balance = VestingBalance::<BalanceOf<T>>::default();
+        if let Some(vesting) = Self::vesting_balance(who) {
+            balance.locked = Self::global_locked_at(vesting, at);
+            balance.vested = vesting.saturating_sub(balance.locked);
+        }
+        for schedule in Self::account_vesting_schedules(who) {
+            balance.locked = balance.locked.saturating_add(schedule.locked_at(at));
+            balance.vested = balance.vested.saturating_add(schedule.vested_at(at));
+        }
+        balance.claimable = currently_locked.saturating_sub(balance.locked);
+        balance
+    }
+
     /// Adds a vesting schedule to a given account.
     fn new_vesting_account(who: &T::AccountId, locked: BalanceOf<T>) -> DispatchResult {
         if locked.is_zero() {
@@ -105,6 +105,7 @@ parameter_types! {
     pub const MinVestedTransfer: Balance = 2;
     pub static ExistentialDeposit: Balance = 1;
     pub const MaxScheduleLength: u32 = 6;
+    pub const MaxVestingSchedules: u32 = 8;
 }
 impl Config for Test {
     type Currency = Balances;
@@ -112,6 +113,7 @@ impl Config for Test {
     type Timestamp = Timestamp;
     type MinVestedTransfer = MinVestedTransfer;
     type MaxScheduleLength = MaxScheduleLength;
+    type MaxVestingSchedules = MaxVestingSchedules;
     type WeightInfo = ();
 }
 
@@ -561,3 +561,321 @@ fn check_vesting_schedule() {
             }
         });
 }
+
+fn now_secs() -> Schedule {
+    <Test as Config>::Timestamp::now().as_secs()
+}
+
+fn set_now_secs(now: Schedule) {
+    Timestamp::set_timestamp(now * 1000);
+}
+
+#[test]
+fn vested_transfer_with_schedule_should_work() {
+    ExtBuilder::default()
+        .existential_deposit(1)
+        .build()
+        .execute_with(|| {
+            let start = now_secs() + 100;
+            let schedule = VestingInfo {
+                locked: 1000,
+                start,
+                cliff: start + 100,
+                duration: 1000,
+            };
+
+            assert_noop!(
+                CalamariVesting::vested_transfer_with_schedule(
+                    RuntimeOrigin::signed(ALICE),
+                    BOB,
+                    VestingInfo {
+                        locked: MinVestedTransfer::get() - 1,
+                        ..schedule
+                    }
+                ),
+                Error::<Test>::AmountLow
+            );
+            // The cliff cannot be before the start or after the end.
+            for cliff in [start - 1, start + 1001] {
+                assert_noop!(
+                    CalamariVesting::vested_transfer_with_schedule(
+                        RuntimeOrigin::signed(ALICE),
+                        BOB,
+                        VestingInfo { cliff, ..schedule }
+                    ),
+                    Error::<Test>::InvalidSchedule
+                );
+            }
+            assert_noop!(
+                CalamariVesting::vested_transfer_with_schedule(
+                    RuntimeOrigin::signed(ALICE),
+                    BOB,
+                    VestingInfo {
+                        locked: ALICE_DEPOSIT + 1,
+                        ..schedule
+                    }
+                ),
+                Error::<Test>::BalanceLow
+            );
+
+            assert_ok!(CalamariVesting::vested_transfer_with_schedule(
+                RuntimeOrigin::signed(ALICE),
+                BOB,
+                schedule
+            ));
+            System::assert_has_event(MockEvent::CalamariVesting(
+                PalletEvent::VestingScheduleAdded(BOB, schedule),
+            ));
+            assert_eq!(Balances::free_balance(BOB), 1000);
+            assert_eq!(
+                CalamariVesting::account_vesting_schedules(BOB).into_inner(),
+                vec![schedule]
+            );
+            // BOB is not on the global schedule.
+            assert_eq!(VestingBalances::<Test>::get(BOB), None);
+            assert_noop!(
+                Balances::transfer(RuntimeOrigin::signed(BOB), ALICE, 1),
+                TokenError::Frozen,
+            );
+
+            // Nothing is vested before the cliff, even though the release started.
+            set_now_secs(start + 50);
+            assert_ok!(CalamariVesting::vest(RuntimeOrigin::signed(BOB)));
+            System::assert_last_event(MockEvent::CalamariVesting(PalletEvent::VestingUpdated(
+                BOB, 1000,
+            )));
+
+            // What accrued since the start is released at the cliff.
+            set_now_secs(start + 100);
+            assert_ok!(CalamariVesting::vest(RuntimeOrigin::signed(BOB)));
+            System::assert_last_event(MockEvent::CalamariVesting(PalletEvent::VestingUpdated(
+                BOB, 900,
+            )));
+            assert_noop!(
+                Balances::transfer(RuntimeOrigin::signed(BOB), ALICE, 101),
+                TokenError::Frozen,
+            );
+
+            // Linear release afterwards.
+            set_now_secs(start + 600);
+            assert_ok!(CalamariVesting::vest(RuntimeOrigin::signed(BOB)));
+            System::assert_last_event(MockEvent::CalamariVesting(PalletEvent::VestingUpdated(
+                BOB, 400,
+            )));
+
+            set_now_secs(start + 1000);
+            assert_ok!(CalamariVesting::vest(RuntimeOrigin::signed(BOB)));
+            System::assert_last_event(MockEvent::CalamariVesting(PalletEvent::VestingCompleted(
+                BOB,
+            )));
+            assert!(!AccountVestingSchedules::<Test>::contains_key(BOB));
+            assert_ok!(Balances::transfer(RuntimeOrigin::signed(BOB), ALICE, 1000));
+
+            assert_noop!(
+                CalamariVesting::vest(RuntimeOrigin::signed(BOB)),
+                Error::<Test>::NotVesting,
+            );
+        });
+}
+
+#[test]
+fn multiple_vesting_schedules_should_work() {
+    ExtBuilder::default()
+        .existential_deposit(1)
+        .build()
+        .execute_with(|| {
+            let start = now_secs() + 100;
+            let schedule = VestingInfo {
+                locked: 100,
+                start,
+                cliff: start,
+                duration: 1000,
+            };
+            // The global schedule is combined with the account's own schedules.
+            assert_ok!(CalamariVesting::vested_transfer(
+                RuntimeOrigin::signed(ALICE),
+                BOB,
+                1000
+            ));
+            // One schedule per duration, as many as allowed.
+            let durations = [1000, 2000, 4000, 5000, 10000, 20000, 40000, 50000];
+            assert_eq!(
+                durations.len() as u32,
+                <Test as Config>::MaxVestingSchedules::get()
+            );
+            for duration in durations {
+                assert_ok!(CalamariVesting::vested_transfer_with_schedule(
+                    RuntimeOrigin::signed(ALICE),
+                    BOB,
+                    VestingInfo {
+                        duration,
+                        ..schedule
+                    }
+                ));
+            }
+            assert_noop!(
+                CalamariVesting::vested_transfer_with_schedule(
+                    RuntimeOrigin::signed(ALICE),
+                    BOB,
+                    schedule
+                ),
+                Error::<Test>::TooManyVestingSchedules
+            );
+            assert_eq!(Balances::free_balance(BOB), 1800);
+            assert_noop!(
+                Balances::transfer(RuntimeOrigin::signed(BOB), ALICE, 1),
+                TokenError::Frozen,
+            );
+
+            // The shortest schedule completes and is dropped, the others vested
+            // 50, 25, 20, 10, 5, 2 and 2.
+            set_now_secs(start + 1000);
+            assert_ok!(CalamariVesting::vest(RuntimeOrigin::signed(BOB)));
+            assert_eq!(CalamariVesting::account_vesting_schedules(BOB).len(), 7);
+            // 45% of the global schedule vested on its first round.
+            System::assert_last_event(MockEvent::CalamariVesting(PalletEvent::VestingUpdated(
+                BOB,
+                550 + 50 + 75 + 80 + 90 + 95 + 98 + 98,
+            )));
+
+            // Both are over at the end of the global schedule.
+            let last_round = 5;
+            set_now_secs(VestingSchedule::<Test>::get()[last_round].1);
+            assert_ok!(CalamariVesting::vest(RuntimeOrigin::signed(BOB)));
+            System::assert_last_event(MockEvent::CalamariVesting(PalletEvent::VestingCompleted(
+                BOB,
+            )));
+            assert_eq!(VestingBalances::<Test>::get(BOB), None);
+            assert!(!AccountVestingSchedules::<Test>::contains_key(BOB));
+        });
+}
+
+#[test]
+fn merge_schedules_should_work() {
+    ExtBuilder::default()
+        .existential_deposit(1)
+        .build()
+        .execute_with(|| {
+            let start = now_secs() + 100;
+            let first = VestingInfo {
+                locked: 1000,
+                start,
+                cliff: start,
+                duration: 1000,
+            };
+            let second = VestingInfo {
+                locked: 500,
+                start: start + 500,
+                cliff: start + 500,
+                duration: 500,
+            };
+            let third = VestingInfo {
+                locked: 200,
+                start,
+                cliff: start,
+                duration: 200,
+            };
+            for schedule in [first, second, third] {
+                assert_ok!(CalamariVesting::vested_transfer_with_schedule(
+                    RuntimeOrigin::signed(ALICE),
+                    BOB,
+                    schedule
+                ));
+            }
+
+            for (index1, index2) in [(0, 0), (0, 3)] {
+                assert_noop!(
+                    CalamariVesting::merge_schedules(RuntimeOrigin::signed(BOB), index1, index2),
+                    Error::<Test>::ScheduleIndexOutOfBounds
+                );
+            }
+
+            set_now_secs(start + 250);
+            assert_ok!(CalamariVesting::merge_schedules(
+                RuntimeOrigin::signed(BOB),
+                1,
+                0
+            ));
+            // 750 of the first and all of the second are merged into the release of the second.
+            let merged = VestingInfo {
+                locked: 1250,
+                start: start + 500,
+                cliff: start + 500,
+                duration: 500,
+            };
+            System::assert_has_event(MockEvent::CalamariVesting(
+                PalletEvent::VestingSchedulesMerged(BOB, merged),
+            ));
+            // The third one is done and dropped.
+            assert_eq!(
+                CalamariVesting::account_vesting_schedules(BOB).into_inner(),
+                vec![merged]
+            );
+            System::assert_last_event(MockEvent::CalamariVesting(PalletEvent::VestingUpdated(
+                BOB, 1250,
+            )));
+
+            set_now_secs(start + 750);
+            assert_ok!(CalamariVesting::vest(RuntimeOrigin::signed(BOB)));
+            System::assert_last_event(MockEvent::CalamariVesting(PalletEvent::VestingUpdated(
+                BOB, 625,
+            )));
+        });
+}
+
+#[test]
+fn vesting_balance_at_should_work() {
+    ExtBuilder::default()
+        .existential_deposit(1)
+        .build()
+        .execute_with(|| {
+            let start = now_secs() + 100;
+            let schedule = VestingInfo {
+                locked: 1000,
+                start,
+                cliff: start + 100,
+                duration: 1000,
+            };
+            assert_ok!(CalamariVesting::vested_transfer_with_schedule(
+                RuntimeOrigin::signed(ALICE),
+                BOB,
+                schedule
+            ));
+
+            assert_eq!(
+                CalamariVesting::vesting_balance_at(&BOB, start + 50, 1000),
+                VestingBalance {
+                    locked: 1000,
+                    vested: 0,
+                    claimable: 0,
+                }
+            );
+            assert_eq!(
+                CalamariVesting::vesting_balance_at(&BOB, start + 400, 1000),
+                VestingBalance {
+                    locked: 600,
+                    vested: 400,
+                    claimable: 400,
+                }
+            );
+            // Vesting at that time unlocks what was claimable.
+            set_now_secs(start + 400);
+            assert_ok!(CalamariVesting::vest(RuntimeOrigin::signed(BOB)));
+            assert_eq!(
+                CalamariVesting::vesting_balance_at(&BOB, start + 400, 600),
+                VestingBalance {
+                    locked: 600,
+                    vested: 400,
+                    claimable: 0,
+                }
+            );
+            assert_eq!(
+                CalamariVesting::vesting_balance_at(&BOB, start + 2000, 600),
+                VestingBalance {
+                    locked: 0,
+                    vested: 1000,
+                    claimable: 600,
+                }
+            );
+        });
+}
@@ -112,6 +112,11 @@ pub trait AssetRegistry: AssetIdType + BalanceType {
         asset_id: &Self::AssetId,
         metadata: Self::Metadata,
     ) -> Result<(), Self::Error>;
+
+    /// Destroys an asset by `AssetId`, which must not have any supply left.
+    ///
+    /// * `asset_id`: the asset id to be destroyed.
+    fn destroy_asset(asset_id: &Self::AssetId) -> Result<(), Self::Error>;
 }
 
 /// Asset Configuration
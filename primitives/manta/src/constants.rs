@@ -73,6 +73,9 @@ pub const ASSET_STRING_LIMIT: u32 = 50;
 /// Staking Pallet Identifier
 pub const LOTTERY_PALLET_ID: PalletId = PalletId(*b"LotryPot");
 
+/// Asset Lottery Pallet Identifier
+pub const ASSET_LOTTERY_PALLET_ID: PalletId = PalletId(*b"AsLotPot");
+
 /// Staking Pallet Identifier
 pub const STAKING_PALLET_ID: PalletId = PalletId(*b"PotStake");
 
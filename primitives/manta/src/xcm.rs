@@ -22,7 +22,7 @@ use super::{
 };
 
 use sp_runtime::traits::{CheckedConversion, Convert, Zero};
-use sp_std::marker::PhantomData;
+use sp_std::{marker::PhantomData, vec::Vec};
 
 use crate::assets::{AssetIdLocationMap, UnitsPerSecond};
 use frame_support::{
@@ -116,25 +116,60 @@ where
     }
 }
 
+/// Multi-Asset Trader
 ///
-/// This trader defines how to charge a XCM call.
-/// This takes the first fungible asset, and takes UnitPerSecondGetter that implements
-/// UnitToWeightRatio trait.
-pub struct FirstAssetTrader<M, R>
+/// Charges XCM execution in the first fungible asset of the payment that is registered in `M`
+/// with a units-per-second and covers the weight, trying the assets already charged first, then
+/// the others in the order of the holding register. Refunds are tracked per asset, and the fees
+/// of every charged asset are deposited as revenue through `R` when the trader is dropped.
+pub struct MultiAssetTrader<M, R>
 where
     R: TakeRevenue,
 {
-    /// Weight
-    weight: XcmWeight,
+    /// Charged Assets, with the amount and weight bought with each and their units-per-second
+    charged: Vec<ChargedAsset>,
 
-    /// Refund Cache
-    refund_cache: Option<(MultiLocation, u128, u128)>,
+    /// Surplus weight bought with earlier charged assets which is not refunded yet
+    unrefunded: XcmWeight,
 
     /// Type Parameter Marker
     __: PhantomData<(M, R)>,
 }
 
-impl<M, R> WeightTrader for FirstAssetTrader<M, R>
+/// Asset Charged by the [`MultiAssetTrader`]
+#[derive(Clone, Debug, Eq, PartialEq)]
+struct ChargedAsset {
+    /// Asset Location
+    location: MultiLocation,
+
+    /// Amount Charged
+    amount: u128,
+
+    /// Weight Bought
+    weight: XcmWeight,
+
+    /// Units per Second at the First Purchase
+    units_per_second: u128,
+}
+
+impl<M, R> MultiAssetTrader<M, R>
+where
+    M: AssetIdLocationMap + UnitsPerSecond,
+    M::Location: From<MultiLocation>,
+    R: TakeRevenue,
+{
+    /// Returns the units-per-second of the asset at `location`, reusing the rate of an asset
+    /// already charged so that its refunds stay consistent.
+    #[inline]
+    fn units_per_second(&self, location: &MultiLocation) -> Option<u128> {
+        match self.charged.iter().find(|c| c.location == *location) {
+            Some(charged) => Some(charged.units_per_second),
+            None => M::asset_id(&(*location).into()).and_then(|id| M::units_per_second(&id)),
+        }
+    }
+}
+
+impl<M, R> WeightTrader for MultiAssetTrader<M, R>
 where
     M: AssetIdLocationMap + UnitsPerSecond,
     M::Location: From<MultiLocation>,
@@ -143,8 +178,8 @@ where
     #[inline]
     fn new() -> Self {
         Self {
-            weight: XcmWeight::zero(),
-            refund_cache: None,
+            charged: Vec::new(),
+            unrefunded: XcmWeight::zero(),
             __: PhantomData,
         }
     }
@@ -154,140 +189,127 @@ where
     #[inline]
     fn buy_weight(&mut self, weight: XcmWeight, payment: Assets) -> Result<Assets> {
         log::debug!(
-            target: "FirstAssetTrader::buy_weight",
+            target: "MultiAssetTrader::buy_weight",
             "weight: {:?}, payment: {:?}",
             weight,
             payment
         );
 
-        let first_asset = payment.fungible_assets_iter().next().ok_or({
-            log::debug!(
-                target: "FirstAssetTrader::buy_weight",
-                "no assets in payment: {:?}",
-                payment,
-            );
-            XcmError::TooExpensive
-        })?;
-
-        // Check the first asset
-        match (first_asset.id, first_asset.fun) {
-            (XcmAssetId::Concrete(id), Fungibility::Fungible(_)) => {
-                let asset_id = match M::asset_id(&id.into()) {
-                    Some(id) => id,
-                    None => {
-                        log::debug!(
-                            target: "FirstAssetTrader::buy_weight",
-                            "asset_id missing for asset location with id: {:?}",
-                            id,
-                        );
-                        return Err(XcmError::TooExpensive);
-                    }
-                };
-                let units_per_second = match M::units_per_second(&asset_id) {
-                    Some(units) => units,
-                    None => {
-                        log::debug!(
-                            target: "FirstAssetTrader::buy_weight",
-                            "units_per_second missing for asset with id: {:?}",
-                            id,
-                        );
-                        return Err(XcmError::TooExpensive);
-                    }
-                };
-
-                let amount = (units_per_second.saturating_mul(weight.ref_time() as u128))
-                    / (WEIGHT_PER_SECOND as u128);
-
-                // we don't need to proceed if amount is zero.
-                // This is very useful in tests.
-                if amount.is_zero() {
-                    return Ok(payment);
-                }
-                let required = MultiAsset {
-                    fun: Fungibility::Fungible(amount),
-                    id: XcmAssetId::Concrete(id),
-                };
-
-                log::debug!(
-                    target: "FirstAssetTrader::buy_weight",
-                    "payment: {:?}, required: {:?}",
-                    payment,
-                    required,
-                );
-                let unused = payment.checked_sub(required).map_err(|_| {
+        let mut candidates = payment
+            .fungible_assets_iter()
+            .filter_map(|asset| match asset.id {
+                XcmAssetId::Concrete(location) => Some(location),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+        // Assets already charged come first, keeping the fees in as few assets as possible.
+        candidates.sort_by_key(|location| !self.charged.iter().any(|c| c.location == *location));
+
+        for location in candidates {
+            let units_per_second = match self.units_per_second(&location) {
+                Some(units) => units,
+                None => {
                     log::debug!(
-                        target: "FirstAssetTrader::buy_weight",
-                        "not enough required assets in payment",
+                        target: "MultiAssetTrader::buy_weight",
+                        "no units_per_second for asset location: {:?}",
+                        location,
                     );
-                    XcmError::TooExpensive
-                })?;
-                self.weight = self.weight.saturating_add(weight);
-
-                // In case the asset matches the one the trader already stored before, add
-                // to later refund
-
-                // Else we are always going to subtract the weight if we can, but we latter do
-                // not refund it
-
-                // In short, we only refund on the asset the trader first successfully was able
-                // to pay for an execution
-                let new_asset = match &self.refund_cache {
-                    Some((prev_id, prev_amount, units_per_second)) => {
-                        if prev_id == &id {
-                            Some((id, prev_amount.saturating_add(amount), *units_per_second))
-                        } else {
-                            None
-                        }
-                    }
-                    None => Some((id, amount, units_per_second)),
-                };
-
-                // Due to the trait bound, we can only refund one asset.
-                if let Some(new_asset) = new_asset {
-                    self.weight = self.weight.saturating_add(weight);
-                    self.refund_cache = Some(new_asset);
-                };
-                Ok(unused)
+                    continue;
+                }
+            };
+            let amount = (units_per_second.saturating_mul(weight.ref_time() as u128))
+                / (WEIGHT_PER_SECOND as u128);
+
+            // we don't need to proceed if amount is zero.
+            // This is very useful in tests.
+            if amount.is_zero() {
+                return Ok(payment);
             }
-            _ => {
-                log::debug!(
-                    target: "FirstAssetTrader::buy_weight",
-                    "no matching XcmAssetId for first_asset in payment: {:?}",
-                    payment,
-                );
-                Err(XcmError::TooExpensive)
+            let required = MultiAsset {
+                fun: Fungibility::Fungible(amount),
+                id: XcmAssetId::Concrete(location),
+            };
+            let unused = match payment.clone().checked_sub(required) {
+                Ok(unused) => unused,
+                Err(_) => {
+                    log::debug!(
+                        target: "MultiAssetTrader::buy_weight",
+                        "not enough of asset location {:?} in payment",
+                        location,
+                    );
+                    continue;
+                }
+            };
+
+            match self.charged.iter_mut().find(|c| c.location == location) {
+                Some(charged) => {
+                    charged.amount = charged.amount.saturating_add(amount);
+                    charged.weight = charged.weight.saturating_add(weight);
+                }
+                None => self.charged.push(ChargedAsset {
+                    location,
+                    amount,
+                    weight,
+                    units_per_second,
+                }),
             }
+            return Ok(unused);
         }
+
+        log::debug!(
+            target: "MultiAssetTrader::buy_weight",
+            "no asset in payment can pay for weight: {:?}",
+            weight,
+        );
+        Err(XcmError::TooExpensive)
     }
 
-    ///
+    /// Refunds `weight` across the charged assets in reverse order, starting with the last
+    /// charged asset which still has weight bought with it. Only one asset can be refunded per
+    /// call, so the surplus beyond the weight bought with it is refunded in the earlier assets by
+    /// the following calls.
     #[inline]
     fn refund_weight(&mut self, weight: XcmWeight) -> Option<MultiAsset> {
-        if let Some((id, prev_amount, units_per_second)) = &mut self.refund_cache {
-            let weight = weight.min(self.weight);
-            self.weight = self.weight.saturating_sub(weight);
-            let amount = ((*units_per_second).saturating_mul(weight.ref_time() as u128))
-                / (WEIGHT_PER_SECOND as u128);
-            *prev_amount = prev_amount.saturating_sub(amount);
-            Some(MultiAsset {
-                fun: Fungibility::Fungible(amount),
-                id: XcmAssetId::Concrete(*id),
-            })
-        } else {
-            None
+        let surplus = self.unrefunded.saturating_add(weight);
+        let charged = self
+            .charged
+            .iter_mut()
+            .rev()
+            .find(|c| !c.weight.ref_time().is_zero())?;
+        let weight = surplus.min(charged.weight);
+        let amount = ((charged
+            .units_per_second
+            .saturating_mul(weight.ref_time() as u128))
+            / (WEIGHT_PER_SECOND as u128))
+            .min(charged.amount);
+        charged.weight = charged.weight.saturating_sub(weight);
+        charged.amount = charged.amount.saturating_sub(amount);
+        let location = charged.location;
+        let bought = self.charged.iter().fold(XcmWeight::zero(), |bought, c| {
+            bought.saturating_add(c.weight)
+        });
+        self.unrefunded = surplus.saturating_sub(weight).min(bought);
+        if amount.is_zero() {
+            return None;
         }
+        Some(MultiAsset {
+            fun: Fungibility::Fungible(amount),
+            id: XcmAssetId::Concrete(location),
+        })
     }
 }
 
-impl<M, R> Drop for FirstAssetTrader<M, R>
+impl<M, R> Drop for MultiAssetTrader<M, R>
 where
     R: TakeRevenue,
 {
-    /// Handles spent fees, depositing them as defined by `R`.
+    /// Handles spent fees of every charged asset, depositing them as defined by `R`.
     #[inline]
     fn drop(&mut self) {
-        if let Some((id, amount, _)) = &self.refund_cache {
-            R::take_revenue((*id, *amount).into());
+        for charged in &self.charged {
+            if !charged.amount.is_zero() {
+                R::take_revenue((charged.location, charged.amount).into());
+            }
         }
     }
 }
@@ -544,3 +566,194 @@ impl<T: Contains<MultiLocation>> ShouldExecute for AllowTopLevelPaidExecutionDes
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod xcm_tests {
+    use super::*;
+    use crate::assets::{AssetIdType, LocationType};
+    use core::cell::RefCell;
+
+    fn sibling() -> MultiLocation {
+        MultiLocation::new(1, X1(Parachain(2000)))
+    }
+
+    fn unpriced() -> MultiLocation {
+        MultiLocation::new(1, X1(Parachain(2001)))
+    }
+
+    fn unknown() -> MultiLocation {
+        MultiLocation::new(1, X1(Parachain(2002)))
+    }
+
+    struct MockAssets;
+
+    impl AssetIdType for MockAssets {
+        type AssetId = u32;
+    }
+
+    impl LocationType for MockAssets {
+        type Location = MultiLocation;
+    }
+
+    impl AssetIdLocationMap for MockAssets {
+        fn location(_: &u32) -> Option<MultiLocation> {
+            None
+        }
+
+        fn asset_id(location: &MultiLocation) -> Option<u32> {
+            if *location == MultiLocation::parent() {
+                Some(0)
+            } else if *location == sibling() {
+                Some(1)
+            } else if *location == unpriced() {
+                Some(2)
+            } else {
+                None
+            }
+        }
+    }
+
+    impl UnitsPerSecond for MockAssets {
+        fn units_per_second(asset_id: &u32) -> Option<u128> {
+            match asset_id {
+                0 => Some(1_000),
+                1 => Some(2_000),
+                _ => None,
+            }
+        }
+    }
+
+    thread_local! {
+        static REVENUE: RefCell<Vec<MultiAsset>> = RefCell::new(Vec::new());
+    }
+
+    struct MockRevenue;
+
+    impl TakeRevenue for MockRevenue {
+        fn take_revenue(revenue: MultiAsset) {
+            REVENUE.with(|r| r.borrow_mut().push(revenue));
+        }
+    }
+
+    type Trader = MultiAssetTrader<MockAssets, MockRevenue>;
+
+    fn seconds(numerator: u64, denominator: u64) -> XcmWeight {
+        XcmWeight::from_parts(WEIGHT_PER_SECOND * numerator / denominator, 0)
+    }
+
+    fn payment(assets: &[(MultiLocation, u128)]) -> Assets {
+        assets
+            .iter()
+            .map(|(location, amount)| MultiAsset::from((*location, *amount)))
+            .collect::<Vec<_>>()
+            .into()
+    }
+
+    fn fungible(assets: &Assets, location: MultiLocation) -> u128 {
+        assets
+            .fungible
+            .get(&XcmAssetId::Concrete(location))
+            .copied()
+            .unwrap_or_default()
+    }
+
+    #[test]
+    fn multi_asset_trader_skips_assets_that_cannot_pay() {
+        let mut trader = Trader::new();
+        let unused = trader
+            .buy_weight(
+                seconds(1, 1),
+                payment(&[
+                    (unknown(), 10_000),
+                    (unpriced(), 10_000),
+                    (MultiLocation::parent(), 500),
+                    (sibling(), 5_000),
+                ]),
+            )
+            .unwrap();
+        assert_eq!(fungible(&unused, sibling()), 3_000);
+        assert_eq!(fungible(&unused, MultiLocation::parent()), 500);
+        assert_eq!(fungible(&unused, unknown()), 10_000);
+        assert_eq!(fungible(&unused, unpriced()), 10_000);
+
+        assert!(matches!(
+            trader.buy_weight(
+                seconds(1, 1),
+                payment(&[(MultiLocation::parent(), 500), (unpriced(), 10_000)])
+            ),
+            Err(XcmError::TooExpensive)
+        ));
+    }
+
+    #[test]
+    fn multi_asset_trader_refunds_and_takes_revenue_per_asset() {
+        REVENUE.with(|r| r.borrow_mut().clear());
+        let mut trader = Trader::new();
+        trader
+            .buy_weight(seconds(1, 1), payment(&[(MultiLocation::parent(), 1_500)]))
+            .unwrap();
+        // The relay asset is tried first but is not enough anymore.
+        trader
+            .buy_weight(
+                seconds(1, 1),
+                payment(&[(MultiLocation::parent(), 500), (sibling(), 5_000)]),
+            )
+            .unwrap();
+
+        // Refunds come from the last charged asset first.
+        assert_eq!(
+            trader.refund_weight(seconds(1, 4)),
+            Some((sibling(), 500u128).into())
+        );
+        assert_eq!(
+            trader.refund_weight(seconds(1, 2)),
+            Some((sibling(), 1_000u128).into())
+        );
+
+        trader
+            .buy_weight(seconds(1, 2), payment(&[(sibling(), 1_000)]))
+            .unwrap();
+        drop(trader);
+        REVENUE.with(|r| {
+            assert_eq!(
+                *r.borrow(),
+                vec![
+                    (MultiLocation::parent(), 1_000u128).into(),
+                    (sibling(), 1_500u128).into()
+                ]
+            )
+        });
+    }
+
+    #[test]
+    fn multi_asset_trader_refunds_surplus_across_charged_assets() {
+        REVENUE.with(|r| r.borrow_mut().clear());
+        let mut trader = Trader::new();
+        trader
+            .buy_weight(seconds(1, 1), payment(&[(MultiLocation::parent(), 1_000)]))
+            .unwrap();
+        trader
+            .buy_weight(seconds(1, 1), payment(&[(sibling(), 2_000)]))
+            .unwrap();
+
+        // The surplus exceeds the weight bought with the sibling asset, the rest of it is
+        // refunded in the relay asset by the next call.
+        assert_eq!(
+            trader.refund_weight(seconds(3, 2)),
+            Some((sibling(), 2_000u128).into())
+        );
+        assert_eq!(
+            trader.refund_weight(seconds(1, 4)),
+            Some((MultiLocation::parent(), 750u128).into())
+        );
+        // Nothing is refunded beyond the weight bought.
+        assert_eq!(
+            trader.refund_weight(seconds(1, 1)),
+            Some((MultiLocation::parent(), 250u128).into())
+        );
+        assert_eq!(trader.refund_weight(seconds(1, 1)), None);
+
+        drop(trader);
+        REVENUE.with(|r| assert!(r.borrow().is_empty()));
+    }
+}
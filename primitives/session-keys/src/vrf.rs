@@ -16,8 +16,12 @@
 
 //! VRF Keys
 
+use crate::NimbusId;
+use codec::{Decode, Encode};
+use scale_info::TypeInfo;
 use sp_application_crypto::KeyTypeId;
-use sp_runtime::{BoundToRuntimeAppPublic, ConsensusEngineId};
+use sp_core::sr25519::vrf::{VrfOutput, VrfProof, VrfTranscript};
+use sp_runtime::{BoundToRuntimeAppPublic, ConsensusEngineId, RuntimeDebug};
 
 /// Implementation of [`BoundToRuntimeAppPublic`] with the public key set to [`VrfId`]
 #[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
@@ -50,3 +54,67 @@ sp_application_crypto::with_pair! {
     /// Key Pair for the [`VrfId`] Key
     pub type VrfPair = vrf_crypto::Pair;
 }
+
+/// Context used to derive the randomness bytes from a VRF output
+pub const VRF_INOUT_CONTEXT: &[u8] = b"VRFInOutContext";
+
+/// Builds the transcript a block author signs with its [`VrfId`] key.
+///
+/// The input is the VRF output of the previous block, so the randomness of a block can only be
+/// computed by its author and only once the previous block is known.
+#[inline]
+pub fn make_vrf_transcript<Hash>(last_vrf_output: Hash) -> VrfTranscript
+where
+    Hash: AsRef<[u8]>,
+{
+    VrfTranscript::new(
+        &VRF_ENGINE_ID,
+        &[(b"last vrf output", last_vrf_output.as_ref())],
+    )
+}
+
+/// VRF Pre-Runtime Digest
+///
+/// Included by the block author next to its nimbus pre-runtime digest under [`VRF_ENGINE_ID`].
+#[derive(Clone, Decode, Encode, RuntimeDebug, TypeInfo)]
+pub struct PreDigest {
+    /// VRF Output of the Block Author
+    pub vrf_output: VrfOutput,
+
+    /// Proof that `vrf_output` was produced by the key of the block author
+    pub vrf_proof: VrfProof,
+}
+
+/// Lookup of the keys registered for a block author
+pub trait KeysLookup<AuthorId, Keys> {
+    /// Returns the `Keys` registered for `author`, if any.
+    fn lookup_keys(author: &AuthorId) -> Option<Keys>;
+
+    /// Registers `keys` for `author`, so that benchmarks can look them up.
+    #[cfg(feature = "runtime-benchmarks")]
+    fn set_keys(author: AuthorId, keys: Keys);
+}
+
+impl<AuthorId, Keys> KeysLookup<AuthorId, Keys> for () {
+    #[inline]
+    fn lookup_keys(_: &AuthorId) -> Option<Keys> {
+        None
+    }
+
+    #[cfg(feature = "runtime-benchmarks")]
+    fn set_keys(_: AuthorId, _: Keys) {}
+}
+
+sp_api::decl_runtime_apis! {
+    /// Runtime API used by block authors to produce the VRF pre-runtime digest
+    pub trait VrfApi {
+        /// Returns the VRF output of the last block, the input of the next VRF signature.
+        ///
+        /// Returns `None` until the runtime has initialized local VRF randomness, in which case
+        /// no VRF digest is expected.
+        fn get_last_vrf_output() -> Option<Block::Hash>;
+
+        /// Returns the [`VrfId`] registered for the block author `nimbus_id`.
+        fn vrf_key_lookup(nimbus_id: NimbusId) -> Option<VrfId>;
+    }
+}
@@ -21,6 +21,7 @@ use codec::{Decode, Encode};
 use frame_support::{
     dispatch::{DispatchInfo, GetDispatchInfo},
     traits::{GetCallMetadata, OriginTrait, StorePreimage},
+    BoundedVec,
 };
 use manta_primitives::assets::{AssetRegistryMetadata, TestingDefault};
 use manta_support::manta_pay::TransferPost;
@@ -1044,20 +1045,53 @@ fn calculate_all_current_extrinsic_tx_fee() -> (
     {
         assert_eq!(
             crate::RuntimeCall::get_call_names("CalamariVesting").len(),
-            3,
+            5,
             "Please update new extrinsic here."
         );
         // vest
         let call = crate::RuntimeCall::CalamariVesting(calamari_vesting::Call::vest {});
         let (dispatch_info, call_len) = get_call_details(&call);
         calamari_runtime_calls.push(("calamari_vesting", "vest", dispatch_info, call_len));
+
+        // vested_transfer_with_schedule
+        let call = crate::RuntimeCall::CalamariVesting(
+            calamari_vesting::Call::vested_transfer_with_schedule {
+                target: ALICE.into(),
+                schedule: calamari_vesting::VestingInfo {
+                    locked: 10,
+                    start: 1_700_000_000,
+                    cliff: 1_700_000_000,
+                    duration: 1_000,
+                },
+            },
+        );
+        let (dispatch_info, call_len) = get_call_details(&call);
+        calamari_runtime_calls.push((
+            "calamari_vesting",
+            "vested_transfer_with_schedule",
+            dispatch_info,
+            call_len,
+        ));
+
+        // merge_schedules
+        let call = crate::RuntimeCall::CalamariVesting(calamari_vesting::Call::merge_schedules {
+            schedule1_index: 0,
+            schedule2_index: 1,
+        });
+        let (dispatch_info, call_len) = get_call_details(&call);
+        calamari_runtime_calls.push((
+            "calamari_vesting",
+            "merge_schedules",
+            dispatch_info,
+            call_len,
+        ));
     }
 
     // manta_collator_selection
     {
         assert_eq!(
             crate::RuntimeCall::get_call_names("CollatorSelection").len(),
-            9,
+            12,
             "Please update new extrinsic here."
         );
         // set_invulnerables
@@ -1172,13 +1206,52 @@ fn calculate_all_current_extrinsic_tx_fee() -> (
             dispatch_info,
             call_len,
         ));
+
+        // set_eviction_window
+        let call = crate::RuntimeCall::CollatorSelection(
+            manta_collator_selection::Call::set_eviction_window { sessions: 1 },
+        );
+        let (dispatch_info, call_len) = get_call_details(&call);
+        calamari_runtime_calls.push((
+            "manta_collator_selection",
+            "set_eviction_window",
+            dispatch_info,
+            call_len,
+        ));
+
+        // whitelist_collator
+        let call = crate::RuntimeCall::CollatorSelection(
+            manta_collator_selection::Call::whitelist_collator {
+                collator: ALICE,
+                sessions: 1,
+            },
+        );
+        let (dispatch_info, call_len) = get_call_details(&call);
+        calamari_runtime_calls.push((
+            "manta_collator_selection",
+            "whitelist_collator",
+            dispatch_info,
+            call_len,
+        ));
+
+        // remove_whitelisted_collator
+        let call = crate::RuntimeCall::CollatorSelection(
+            manta_collator_selection::Call::remove_whitelisted_collator { collator: ALICE },
+        );
+        let (dispatch_info, call_len) = get_call_details(&call);
+        calamari_runtime_calls.push((
+            "manta_collator_selection",
+            "remove_whitelisted_collator",
+            dispatch_info,
+            call_len,
+        ));
     }
 
     // pallet_asset_manager
     {
         assert_eq!(
             crate::RuntimeCall::get_call_names("AssetManager").len(),
-            9,
+            15,
             "Please update new extrinsic here."
         );
         // register_asset
@@ -1309,6 +1382,83 @@ fn calculate_all_current_extrinsic_tx_fee() -> (
             dispatch_info,
             call_len,
         ));
+
+        // propose_asset
+        let call = crate::RuntimeCall::AssetManager(pallet_asset_manager::Call::propose_asset {
+            location: Default::default(),
+            metadata: AssetRegistryMetadata::testing_default(),
+            units_per_second: 1_000,
+        });
+        let (dispatch_info, call_len) = get_call_details(&call);
+        calamari_runtime_calls.push((
+            "pallet_asset_manager",
+            "propose_asset",
+            dispatch_info,
+            call_len,
+        ));
+
+        // veto_pending_asset
+        let call =
+            crate::RuntimeCall::AssetManager(pallet_asset_manager::Call::veto_pending_asset {
+                location: Default::default(),
+            });
+        let (dispatch_info, call_len) = get_call_details(&call);
+        calamari_runtime_calls.push((
+            "pallet_asset_manager",
+            "veto_pending_asset",
+            dispatch_info,
+            call_len,
+        ));
+
+        // set_dynamic_pricing
+        let call =
+            crate::RuntimeCall::AssetManager(pallet_asset_manager::Call::set_dynamic_pricing {
+                asset_id: 1,
+                bounds: Some((1, 1_000_000_000_000)),
+            });
+        let (dispatch_info, call_len) = get_call_details(&call);
+        calamari_runtime_calls.push((
+            "pallet_asset_manager",
+            "set_dynamic_pricing",
+            dispatch_info,
+            call_len,
+        ));
+
+        // freeze_asset
+        let call = crate::RuntimeCall::AssetManager(pallet_asset_manager::Call::freeze_asset {
+            asset_id: 1,
+        });
+        let (dispatch_info, call_len) = get_call_details(&call);
+        calamari_runtime_calls.push((
+            "pallet_asset_manager",
+            "freeze_asset",
+            dispatch_info,
+            call_len,
+        ));
+
+        // unfreeze_asset
+        let call = crate::RuntimeCall::AssetManager(pallet_asset_manager::Call::unfreeze_asset {
+            asset_id: 1,
+        });
+        let (dispatch_info, call_len) = get_call_details(&call);
+        calamari_runtime_calls.push((
+            "pallet_asset_manager",
+            "unfreeze_asset",
+            dispatch_info,
+            call_len,
+        ));
+
+        // deregister_asset
+        let call = crate::RuntimeCall::AssetManager(pallet_asset_manager::Call::deregister_asset {
+            asset_id: 1,
+        });
+        let (dispatch_info, call_len) = get_call_details(&call);
+        calamari_runtime_calls.push((
+            "pallet_asset_manager",
+            "deregister_asset",
+            dispatch_info,
+            call_len,
+        ));
     }
 
     // pallet_assets
@@ -1933,7 +2083,7 @@ fn calculate_all_current_extrinsic_tx_fee() -> (
     {
         assert_eq!(
             crate::RuntimeCall::get_call_names("ParachainStaking").len(),
-            26,
+            40,
             "Please update new extrinsic here."
         );
         // set_staking_expectations
@@ -2312,6 +2462,212 @@ fn calculate_all_current_extrinsic_tx_fee() -> (
             dispatch_info,
             call_len,
         ));
+
+        // delegate_with_auto_compound
+        let call = crate::RuntimeCall::ParachainStaking(
+            pallet_parachain_staking::Call::delegate_with_auto_compound {
+                candidate: ALICE,
+                amount: 1,
+                auto_compound: Percent::from_percent(50),
+                candidate_delegation_count: 10,
+                candidate_auto_compounding_delegation_count: 10,
+                delegation_count: 10,
+            },
+        );
+        let (dispatch_info, call_len) = get_call_details(&call);
+        calamari_runtime_calls.push((
+            "pallet_parachain_staking",
+            "delegate_with_auto_compound",
+            dispatch_info,
+            call_len,
+        ));
+
+        // set_auto_compound
+        let call = crate::RuntimeCall::ParachainStaking(
+            pallet_parachain_staking::Call::set_auto_compound {
+                candidate: ALICE,
+                value: Percent::from_percent(50),
+                candidate_auto_compounding_delegation_count_hint: 10,
+                delegation_count_hint: 10,
+            },
+        );
+        let (dispatch_info, call_len) = get_call_details(&call);
+        calamari_runtime_calls.push((
+            "pallet_parachain_staking",
+            "set_auto_compound",
+            dispatch_info,
+            call_len,
+        ));
+
+        // set_slashing_config
+        let call = crate::RuntimeCall::ParachainStaking(
+            pallet_parachain_staking::Call::set_slashing_config {
+                new: pallet_parachain_staking::SlashingConfig {
+                    min_performance: Perbill::from_percent(50),
+                    tolerated_rounds: 3,
+                    slash_fraction: Perbill::from_percent(1),
+                    slash_delegations: true,
+                    equivocation_slash_fraction: Perbill::from_percent(10),
+                },
+            },
+        );
+        let (dispatch_info, call_len) = get_call_details(&call);
+        calamari_runtime_calls.push((
+            "pallet_parachain_staking",
+            "set_slashing_config",
+            dispatch_info,
+            call_len,
+        ));
+
+        // cancel_deferred_slash
+        let call = crate::RuntimeCall::ParachainStaking(
+            pallet_parachain_staking::Call::cancel_deferred_slash {
+                apply_round: 10,
+                slash_indices: vec![0, 1, 2],
+            },
+        );
+        let (dispatch_info, call_len) = get_call_details(&call);
+        calamari_runtime_calls.push((
+            "pallet_parachain_staking",
+            "cancel_deferred_slash",
+            dispatch_info,
+            call_len,
+        ));
+
+        // redelegate
+        let call =
+            crate::RuntimeCall::ParachainStaking(pallet_parachain_staking::Call::redelegate {
+                from: ALICE,
+                to: ALICE,
+                amount: 10,
+            });
+        let (dispatch_info, call_len) = get_call_details(&call);
+        calamari_runtime_calls.push((
+            "pallet_parachain_staking",
+            "redelegate",
+            dispatch_info,
+            call_len,
+        ));
+
+        // set_commission_bounds
+        let call = crate::RuntimeCall::ParachainStaking(
+            pallet_parachain_staking::Call::set_commission_bounds {
+                min: Perbill::from_percent(1),
+                max: Perbill::from_percent(20),
+            },
+        );
+        let (dispatch_info, call_len) = get_call_details(&call);
+        calamari_runtime_calls.push((
+            "pallet_parachain_staking",
+            "set_commission_bounds",
+            dispatch_info,
+            call_len,
+        ));
+
+        // schedule_commission_change
+        let call = crate::RuntimeCall::ParachainStaking(
+            pallet_parachain_staking::Call::schedule_commission_change {
+                commission: Perbill::from_percent(10),
+            },
+        );
+        let (dispatch_info, call_len) = get_call_details(&call);
+        calamari_runtime_calls.push((
+            "pallet_parachain_staking",
+            "schedule_commission_change",
+            dispatch_info,
+            call_len,
+        ));
+
+        // execute_commission_change
+        let call = crate::RuntimeCall::ParachainStaking(
+            pallet_parachain_staking::Call::execute_commission_change { candidate: ALICE },
+        );
+        let (dispatch_info, call_len) = get_call_details(&call);
+        calamari_runtime_calls.push((
+            "pallet_parachain_staking",
+            "execute_commission_change",
+            dispatch_info,
+            call_len,
+        ));
+
+        // cancel_commission_change
+        let call = crate::RuntimeCall::ParachainStaking(
+            pallet_parachain_staking::Call::cancel_commission_change {},
+        );
+        let (dispatch_info, call_len) = get_call_details(&call);
+        calamari_runtime_calls.push((
+            "pallet_parachain_staking",
+            "cancel_commission_change",
+            dispatch_info,
+            call_len,
+        ));
+
+        // set_inflation_curve
+        let call = crate::RuntimeCall::ParachainStaking(
+            pallet_parachain_staking::Call::set_inflation_curve {
+                curve: Some(pallet_parachain_staking::InflationCurve {
+                    ideal_staked: Perbill::from_percent(50),
+                    falloff: Perbill::from_percent(5),
+                }),
+            },
+        );
+        let (dispatch_info, call_len) = get_call_details(&call);
+        calamari_runtime_calls.push((
+            "pallet_parachain_staking",
+            "set_inflation_curve",
+            dispatch_info,
+            call_len,
+        ));
+
+        // claim_rewards
+        let call =
+            crate::RuntimeCall::ParachainStaking(pallet_parachain_staking::Call::claim_rewards {});
+        let (dispatch_info, call_len) = get_call_details(&call);
+        calamari_runtime_calls.push((
+            "pallet_parachain_staking",
+            "claim_rewards",
+            dispatch_info,
+            call_len,
+        ));
+
+        // start_collator_selection_migration
+        let call = crate::RuntimeCall::ParachainStaking(
+            pallet_parachain_staking::Call::start_collator_selection_migration {},
+        );
+        let (dispatch_info, call_len) = get_call_details(&call);
+        calamari_runtime_calls.push((
+            "pallet_parachain_staking",
+            "start_collator_selection_migration",
+            dispatch_info,
+            call_len,
+        ));
+
+        // report_equivocation
+        let call = crate::RuntimeCall::ParachainStaking(
+            pallet_parachain_staking::Call::report_equivocation {
+                collator: ALICE,
+                round: 10,
+            },
+        );
+        let (dispatch_info, call_len) = get_call_details(&call);
+        calamari_runtime_calls.push((
+            "pallet_parachain_staking",
+            "report_equivocation",
+            dispatch_info,
+            call_len,
+        ));
+
+        // claim_rewards_for
+        let call = crate::RuntimeCall::ParachainStaking(
+            pallet_parachain_staking::Call::claim_rewards_for { delegator: ALICE },
+        );
+        let (dispatch_info, call_len) = get_call_details(&call);
+        calamari_runtime_calls.push((
+            "pallet_parachain_staking",
+            "claim_rewards_for",
+            dispatch_info,
+            call_len,
+        ));
     }
 
     // pallet_scheduler
@@ -2571,7 +2927,7 @@ fn calculate_all_current_extrinsic_tx_fee() -> (
     {
         assert_eq!(
             crate::RuntimeCall::get_call_names("Randomness").len(),
-            1,
+            6,
             "Please update new extrinsic here."
         );
         // set_babe_randomness_results
@@ -2584,6 +2940,70 @@ fn calculate_all_current_extrinsic_tx_fee() -> (
             dispatch_info,
             call_len,
         ));
+
+        // request_randomness
+        let call = crate::RuntimeCall::Randomness(pallet_randomness::Call::request_randomness {
+            salt: Default::default(),
+            num_words: 1,
+        });
+        let (dispatch_info, call_len) = get_call_details(&call);
+        calamari_runtime_calls.push((
+            "pallet_randomness",
+            "request_randomness",
+            dispatch_info,
+            call_len,
+        ));
+
+        // fulfil_randomness
+        let call =
+            crate::RuntimeCall::Randomness(pallet_randomness::Call::fulfil_randomness { id: 0 });
+        let (dispatch_info, call_len) = get_call_details(&call);
+        calamari_runtime_calls.push((
+            "pallet_randomness",
+            "fulfil_randomness",
+            dispatch_info,
+            call_len,
+        ));
+
+        // purge_expired_request
+        let call = crate::RuntimeCall::Randomness(pallet_randomness::Call::purge_expired_request {
+            id: 0,
+        });
+        let (dispatch_info, call_len) = get_call_details(&call);
+        calamari_runtime_calls.push((
+            "pallet_randomness",
+            "purge_expired_request",
+            dispatch_info,
+            call_len,
+        ));
+
+        // request_local_randomness
+        let call =
+            crate::RuntimeCall::Randomness(pallet_randomness::Call::request_local_randomness {
+                salt: Default::default(),
+                num_words: 1,
+                delay: 2,
+            });
+        let (dispatch_info, call_len) = get_call_details(&call);
+        calamari_runtime_calls.push((
+            "pallet_randomness",
+            "request_local_randomness",
+            dispatch_info,
+            call_len,
+        ));
+
+        // set_local_vrf_enforced
+        let call =
+            crate::RuntimeCall::Randomness(pallet_randomness::Call::set_local_vrf_enforced {
+                enforced: true,
+            });
+        let (dispatch_info, call_len) = get_call_details(&call);
+        calamari_runtime_calls.push((
+            "pallet_randomness",
+            "set_local_vrf_enforced",
+            dispatch_info,
+            call_len,
+        ));
     }
 
     // pallet_name_service
@@ -2659,7 +3079,7 @@ fn calculate_all_current_extrinsic_tx_fee() -> (
     {
         assert_eq!(
             crate::RuntimeCall::get_call_names("Farming").len(),
-            14,
+            25,
             "Please update new extrinsic here."
         );
         // create_farming_pool
@@ -2785,13 +3205,148 @@ fn calculate_all_current_extrinsic_tx_fee() -> (
             dispatch_info,
             call_len,
         ));
+
+        // set_reward_schedule
+        let call = crate::RuntimeCall::Farming(pallet_farming::Call::set_reward_schedule {
+            pool_id: 1,
+            end_block: Some(1000),
+            reward_steps: BoundedVec::truncate_from(vec![(
+                100,
+                BoundedVec::truncate_from(vec![(1, 64), (2, 128)]),
+            )]),
+        });
+        let (dispatch_info, call_len) = get_call_details(&call);
+        calamari_runtime_calls.push((
+            "pallet_farming",
+            "set_reward_schedule",
+            dispatch_info,
+            call_len,
+        ));
+
+        // create_incentive_pool
+        let call = crate::RuntimeCall::Farming(pallet_farming::Call::create_incentive_pool {
+            lp_token: 10,
+            basic_rewards: BoundedVec::truncate_from(vec![(1, 64), (2, 128)]),
+            min_deposit_to_start: 0,
+            after_block_to_start: 0,
+            withdraw_limit_time: 0,
+            claim_limit_time: 0,
+            withdraw_limit_count: 5,
+            end_block: Some(1000),
+        });
+        let (dispatch_info, call_len) = get_call_details(&call);
+        calamari_runtime_calls.push((
+            "pallet_farming",
+            "create_incentive_pool",
+            dispatch_info,
+            call_len,
+        ));
+
+        // reclaim_rewards
+        let call =
+            crate::RuntimeCall::Farming(pallet_farming::Call::reclaim_rewards { pool_id: 1 });
+        let (dispatch_info, call_len) = get_call_details(&call);
+        calamari_runtime_calls.push(("pallet_farming", "reclaim_rewards", dispatch_info, call_len));
+
+        // ve_lock
+        let call = crate::RuntimeCall::Farming(pallet_farming::Call::ve_lock {
+            amount: 1000,
+            lock_epochs: 52,
+        });
+        let (dispatch_info, call_len) = get_call_details(&call);
+        calamari_runtime_calls.push(("pallet_farming", "ve_lock", dispatch_info, call_len));
+
+        // ve_unlock
+        let call = crate::RuntimeCall::Farming(pallet_farming::Call::ve_unlock {});
+        let (dispatch_info, call_len) = get_call_details(&call);
+        calamari_runtime_calls.push(("pallet_farming", "ve_unlock", dispatch_info, call_len));
+
+        // update_boost
+        let call = crate::RuntimeCall::Farming(pallet_farming::Call::update_boost {
+            who: ALICE,
+            pool_id: 1,
+        });
+        let (dispatch_info, call_len) = get_call_details(&call);
+        calamari_runtime_calls.push(("pallet_farming", "update_boost", dispatch_info, call_len));
+
+        // vote
+        let call = crate::RuntimeCall::Farming(pallet_farming::Call::vote {
+            votes: BoundedVec::truncate_from(vec![
+                (1, Percent::from_percent(60)),
+                (2, Percent::from_percent(40)),
+            ]),
+        });
+        let (dispatch_info, call_len) = get_call_details(&call);
+        calamari_runtime_calls.push(("pallet_farming", "vote", dispatch_info, call_len));
+
+        // set_vote_rewards
+        let call = crate::RuntimeCall::Farming(pallet_farming::Call::set_vote_rewards {
+            rewards: BoundedVec::truncate_from(vec![(1, 64), (2, 128)]),
+        });
+        let (dispatch_info, call_len) = get_call_details(&call);
+        calamari_runtime_calls.push((
+            "pallet_farming",
+            "set_vote_rewards",
+            dispatch_info,
+            call_len,
+        ));
+
+        // distribute_vote_rewards
+        let call = crate::RuntimeCall::Farming(pallet_farming::Call::distribute_vote_rewards {
+            epoch: 1,
+            pool_id: 1,
+        });
+        let (dispatch_info, call_len) = get_call_details(&call);
+        calamari_runtime_calls.push((
+            "pallet_farming",
+            "distribute_vote_rewards",
+            dispatch_info,
+            call_len,
+        ));
+
+        // add_liquidity_and_deposit
+        let call = crate::RuntimeCall::Farming(pallet_farming::Call::add_liquidity_and_deposit {
+            pool_id: 1,
+            asset_0: 1,
+            asset_1: 8,
+            amount_0_desired: 1000,
+            amount_1_desired: 2000,
+            amount_0_min: 900,
+            amount_1_min: 1800,
+            gauge_info: Some((10, 64)),
+        });
+        let (dispatch_info, call_len) = get_call_details(&call);
+        calamari_runtime_calls.push((
+            "pallet_farming",
+            "add_liquidity_and_deposit",
+            dispatch_info,
+            call_len,
+        ));
+
+        // withdraw_and_remove_liquidity
+        let call =
+            crate::RuntimeCall::Farming(pallet_farming::Call::withdraw_and_remove_liquidity {
+                pool_id: 1,
+                asset_0: 1,
+                asset_1: 8,
+                remove_value: Some(100),
+                amount_0_min: 90,
+                amount_1_min: 180,
+            });
+        let (dispatch_info, call_len) = get_call_details(&call);
+        calamari_runtime_calls.push((
+            "pallet_farming",
+            "withdraw_and_remove_liquidity",
+            dispatch_info,
+            call_len,
+        ));
     }
 
     // pallet_lottery
     {
         assert_eq!(
             crate::RuntimeCall::get_call_names("Lottery").len(),
-            13,
+            17,
             "Please update new extrinsic here."
         );
         t.execute_with(|| {
@@ -2929,6 +3484,55 @@ fn calculate_all_current_extrinsic_tx_fee() -> (
                 dispatch_info,
                 call_len,
             ));
+
+            // set_staking_strategy
+            let call = crate::RuntimeCall::Lottery(pallet_lottery::Call::set_staking_strategy {
+                strategy: Default::default(),
+            });
+            let (dispatch_info, call_len) = get_call_details(&call);
+            calamari_runtime_calls.push((
+                "pallet_lottery",
+                "set_staking_strategy",
+                dispatch_info,
+                call_len,
+            ));
+
+            // instant_withdraw
+            let call =
+                crate::RuntimeCall::Lottery(pallet_lottery::Call::instant_withdraw { amount: 10 });
+            let (dispatch_info, call_len) = get_call_details(&call);
+            calamari_runtime_calls.push((
+                "pallet_lottery",
+                "instant_withdraw",
+                dispatch_info,
+                call_len,
+            ));
+
+            // set_liquidity_buffer_params
+            let call =
+                crate::RuntimeCall::Lottery(pallet_lottery::Call::set_liquidity_buffer_params {
+                    target: 10,
+                    instant_withdraw_fee: Default::default(),
+                });
+            let (dispatch_info, call_len) = get_call_details(&call);
+            calamari_runtime_calls.push((
+                "pallet_lottery",
+                "set_liquidity_buffer_params",
+                dispatch_info,
+                call_len,
+            ));
+
+            // set_pot_backing
+            let call = crate::RuntimeCall::Lottery(pallet_lottery::Call::set_pot_backing {
+                backing: Default::default(),
+            });
+            let (dispatch_info, call_len) = get_call_details(&call);
+            calamari_runtime_calls.push((
+                "pallet_lottery",
+                "set_pot_backing",
+                dispatch_info,
+                call_len,
+            ));
         });
     }
 
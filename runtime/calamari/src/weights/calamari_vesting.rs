@@ -48,6 +48,8 @@ pub trait WeightInfo {
 	fn update_vesting_schedule() -> Weight;
 	fn vest() -> Weight;
 	fn vested_transfer() -> Weight;
+	fn vested_transfer_with_schedule(s: u32, ) -> Weight;
+	fn merge_schedules(s: u32, ) -> Weight;
 }
 
 /// Weights for calamari_vesting using the Substrate node and recommended hardware.
@@ -78,14 +80,16 @@ impl<T: frame_system::Config> calamari_vesting::WeightInfo for SubstrateWeight<T
 	/// Proof: Balances Freezes (max_values: None, max_size: Some(49), added: 2524, mode: MaxEncodedLen)
 	/// Storage: System Account (r:1 w:1)
 	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: CalamariVesting AccountVestingSchedules (r:1 w:1)
+	/// Proof Skipped: CalamariVesting AccountVestingSchedules (max_values: None, max_size: None, mode: Measured)
 	fn vest() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `462`
 		//  Estimated: `4764`
 		// Minimum execution time: 46_572_000 picoseconds.
 		Weight::from_parts(47_606_000, 4764)
-			.saturating_add(T::DbWeight::get().reads(6_u64))
-			.saturating_add(T::DbWeight::get().writes(3_u64))
+			.saturating_add(T::DbWeight::get().reads(7_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
 	}
 	/// Storage: CalamariVesting VestingBalances (r:1 w:1)
 	/// Proof Skipped: CalamariVesting VestingBalances (max_values: None, max_size: None, mode: Measured)
@@ -99,14 +103,66 @@ impl<T: frame_system::Config> calamari_vesting::WeightInfo for SubstrateWeight<T
 	/// Proof: Balances Locks (max_values: None, max_size: Some(1299), added: 3774, mode: MaxEncodedLen)
 	/// Storage: Balances Freezes (r:1 w:0)
 	/// Proof: Balances Freezes (max_values: None, max_size: Some(49), added: 2524, mode: MaxEncodedLen)
+	/// Storage: CalamariVesting AccountVestingSchedules (r:1 w:1)
+	/// Proof Skipped: CalamariVesting AccountVestingSchedules (max_values: None, max_size: None, mode: Measured)
 	fn vested_transfer() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `232`
 		//  Estimated: `4764`
 		// Minimum execution time: 84_231_000 picoseconds.
 		Weight::from_parts(85_418_000, 4764)
+			.saturating_add(T::DbWeight::get().reads(7_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	/// Storage: CalamariVesting AccountVestingSchedules (r:1 w:1)
+	/// Proof Skipped: CalamariVesting AccountVestingSchedules (max_values: None, max_size: None, mode: Measured)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: Timestamp Now (r:1 w:0)
+	/// Proof: Timestamp Now (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: CalamariVesting VestingBalances (r:1 w:0)
+	/// Proof Skipped: CalamariVesting VestingBalances (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Balances Locks (r:1 w:1)
+	/// Proof: Balances Locks (max_values: None, max_size: Some(1299), added: 3774, mode: MaxEncodedLen)
+	/// Storage: Balances Freezes (r:1 w:0)
+	/// Proof: Balances Freezes (max_values: None, max_size: Some(49), added: 2524, mode: MaxEncodedLen)
+	/// The range of component `s` is `[0, 27]`.
+	fn vested_transfer_with_schedule(s: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `268 + s * (36 ±0)`
+		//  Estimated: `4764 + s * (36 ±0)`
+		// Minimum execution time: 33_912_000 picoseconds.
+		Weight::from_parts(35_104_722, 4764)
+			// Standard Error: 6_873
+			.saturating_add(Weight::from_parts(151_408, 0).saturating_mul(s.into()))
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+			.saturating_add(Weight::from_parts(0, 36).saturating_mul(s.into()))
+	}
+	/// Storage: CalamariVesting AccountVestingSchedules (r:1 w:1)
+	/// Proof Skipped: CalamariVesting AccountVestingSchedules (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Timestamp Now (r:1 w:0)
+	/// Proof: Timestamp Now (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: CalamariVesting VestingBalances (r:1 w:0)
+	/// Proof Skipped: CalamariVesting VestingBalances (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Balances Locks (r:1 w:1)
+	/// Proof: Balances Locks (max_values: None, max_size: Some(1299), added: 3774, mode: MaxEncodedLen)
+	/// Storage: Balances Freezes (r:1 w:0)
+	/// Proof: Balances Freezes (max_values: None, max_size: Some(49), added: 2524, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// The range of component `s` is `[2, 28]`.
+	fn merge_schedules(s: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `498 + s * (36 ±0)`
+		//  Estimated: `4764 + s * (36 ±0)`
+		// Minimum execution time: 24_316_000 picoseconds.
+		Weight::from_parts(25_027_519, 4764)
+			// Standard Error: 5_912
+			.saturating_add(Weight::from_parts(173_664, 0).saturating_mul(s.into()))
 			.saturating_add(T::DbWeight::get().reads(6_u64))
 			.saturating_add(T::DbWeight::get().writes(3_u64))
+			.saturating_add(Weight::from_parts(0, 36).saturating_mul(s.into()))
 	}
 }
 
@@ -137,14 +193,16 @@ impl WeightInfo for () {
 	/// Proof: Balances Freezes (max_values: None, max_size: Some(49), added: 2524, mode: MaxEncodedLen)
 	/// Storage: System Account (r:1 w:1)
 	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: CalamariVesting AccountVestingSchedules (r:1 w:1)
+	/// Proof Skipped: CalamariVesting AccountVestingSchedules (max_values: None, max_size: None, mode: Measured)
 	fn vest() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `462`
 		//  Estimated: `4764`
 		// Minimum execution time: 46_572_000 picoseconds.
 		Weight::from_parts(47_606_000, 4764)
-			.saturating_add(RocksDbWeight::get().reads(6_u64))
-			.saturating_add(RocksDbWeight::get().writes(3_u64))
+			.saturating_add(RocksDbWeight::get().reads(7_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
 	}
 	/// Storage: CalamariVesting VestingBalances (r:1 w:1)
 	/// Proof Skipped: CalamariVesting VestingBalances (max_values: None, max_size: None, mode: Measured)
@@ -158,13 +216,65 @@ impl WeightInfo for () {
 	/// Proof: Balances Locks (max_values: None, max_size: Some(1299), added: 3774, mode: MaxEncodedLen)
 	/// Storage: Balances Freezes (r:1 w:0)
 	/// Proof: Balances Freezes (max_values: None, max_size: Some(49), added: 2524, mode: MaxEncodedLen)
+	/// Storage: CalamariVesting AccountVestingSchedules (r:1 w:1)
+	/// Proof Skipped: CalamariVesting AccountVestingSchedules (max_values: None, max_size: None, mode: Measured)
 	fn vested_transfer() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `232`
 		//  Estimated: `4764`
 		// Minimum execution time: 84_231_000 picoseconds.
 		Weight::from_parts(85_418_000, 4764)
+			.saturating_add(RocksDbWeight::get().reads(7_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+	/// Storage: CalamariVesting AccountVestingSchedules (r:1 w:1)
+	/// Proof Skipped: CalamariVesting AccountVestingSchedules (max_values: None, max_size: None, mode: Measured)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: Timestamp Now (r:1 w:0)
+	/// Proof: Timestamp Now (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: CalamariVesting VestingBalances (r:1 w:0)
+	/// Proof Skipped: CalamariVesting VestingBalances (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Balances Locks (r:1 w:1)
+	/// Proof: Balances Locks (max_values: None, max_size: Some(1299), added: 3774, mode: MaxEncodedLen)
+	/// Storage: Balances Freezes (r:1 w:0)
+	/// Proof: Balances Freezes (max_values: None, max_size: Some(49), added: 2524, mode: MaxEncodedLen)
+	/// The range of component `s` is `[0, 27]`.
+	fn vested_transfer_with_schedule(s: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `268 + s * (36 ±0)`
+		//  Estimated: `4764 + s * (36 ±0)`
+		// Minimum execution time: 33_912_000 picoseconds.
+		Weight::from_parts(35_104_722, 4764)
+			// Standard Error: 6_873
+			.saturating_add(Weight::from_parts(151_408, 0).saturating_mul(s.into()))
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+			.saturating_add(Weight::from_parts(0, 36).saturating_mul(s.into()))
+	}
+	/// Storage: CalamariVesting AccountVestingSchedules (r:1 w:1)
+	/// Proof Skipped: CalamariVesting AccountVestingSchedules (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Timestamp Now (r:1 w:0)
+	/// Proof: Timestamp Now (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: CalamariVesting VestingBalances (r:1 w:0)
+	/// Proof Skipped: CalamariVesting VestingBalances (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Balances Locks (r:1 w:1)
+	/// Proof: Balances Locks (max_values: None, max_size: Some(1299), added: 3774, mode: MaxEncodedLen)
+	/// Storage: Balances Freezes (r:1 w:0)
+	/// Proof: Balances Freezes (max_values: None, max_size: Some(49), added: 2524, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// The range of component `s` is `[2, 28]`.
+	fn merge_schedules(s: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `498 + s * (36 ±0)`
+		//  Estimated: `4764 + s * (36 ±0)`
+		// Minimum execution time: 24_316_000 picoseconds.
+		Weight::from_parts(25_027_519, 4764)
+			// Standard Error: 5_912
+			.saturating_add(Weight::from_parts(173_664, 0).saturating_mul(s.into()))
 			.saturating_add(RocksDbWeight::get().reads(6_u64))
 			.saturating_add(RocksDbWeight::get().writes(3_u64))
+			.saturating_add(Weight::from_parts(0, 36).saturating_mul(s.into()))
 	}
 }
\ No newline at end of file
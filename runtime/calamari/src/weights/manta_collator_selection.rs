@@ -50,6 +50,9 @@ pub trait WeightInfo {
 	fn set_candidacy_bond() -> Weight;
 	fn set_eviction_baseline() -> Weight;
 	fn set_eviction_tolerance() -> Weight;
+	fn set_eviction_window() -> Weight;
+	fn whitelist_collator() -> Weight;
+	fn remove_whitelisted_collator() -> Weight;
 	fn register_as_candidate(c: u32, ) -> Weight;
 	fn leave_intent(c: u32, ) -> Weight;
 	fn remove_collator(c: u32, ) -> Weight;
@@ -114,6 +117,40 @@ impl<T: frame_system::Config> manta_collator_selection::WeightInfo for Substrate
 		Weight::from_parts(7_500_000, 0)
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
+	/// Storage: CollatorSelection EvictionWindow (r:0 w:1)
+	/// Proof Skipped: CollatorSelection EvictionWindow (max_values: Some(1), max_size: None, mode: Measured)
+	fn set_eviction_window() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 7_253_000 picoseconds.
+		Weight::from_parts(7_612_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: CollatorSelection Candidates (r:1 w:0)
+	/// Proof Skipped: CollatorSelection Candidates (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: CollatorSelection EvictionWhitelist (r:0 w:1)
+	/// Proof Skipped: CollatorSelection EvictionWhitelist (max_values: None, max_size: None, mode: Measured)
+	fn whitelist_collator() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `3183`
+		//  Estimated: `4668`
+		// Minimum execution time: 16_871_000 picoseconds.
+		Weight::from_parts(17_433_000, 4668)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: CollatorSelection EvictionWhitelist (r:1 w:1)
+	/// Proof Skipped: CollatorSelection EvictionWhitelist (max_values: None, max_size: None, mode: Measured)
+	fn remove_whitelisted_collator() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `198`
+		//  Estimated: `3663`
+		// Minimum execution time: 11_324_000 picoseconds.
+		Weight::from_parts(11_902_000, 3663)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
 	/// Storage: CollatorSelection Candidates (r:1 w:1)
 	/// Proof Skipped: CollatorSelection Candidates (max_values: Some(1), max_size: None, mode: Measured)
 	/// Storage: CollatorSelection DesiredCandidates (r:1 w:0)
@@ -223,6 +260,12 @@ impl<T: frame_system::Config> manta_collator_selection::WeightInfo for Substrate
 	/// Proof: System BlockWeight (max_values: Some(1), max_size: Some(48), added: 543, mode: MaxEncodedLen)
 	/// Storage: Session Validators (r:1 w:0)
 	/// Proof Skipped: Session Validators (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: CollatorSelection EvictionWindow (r:1 w:0)
+	/// Proof Skipped: CollatorSelection EvictionWindow (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: CollatorSelection EvictionWhitelist (r:51 w:0)
+	/// Proof Skipped: CollatorSelection EvictionWhitelist (max_values: None, max_size: None, mode: Measured)
+	/// Storage: CollatorSelection PerformanceHistory (r:50 w:50)
+	/// Proof Skipped: CollatorSelection PerformanceHistory (max_values: None, max_size: None, mode: Measured)
 	/// The range of component `c` is `[1, 50]`.
 	fn new_session(c: u32, ) -> Weight {
 		// Proof Size summary in bytes:
@@ -232,10 +275,10 @@ impl<T: frame_system::Config> manta_collator_selection::WeightInfo for Substrate
 		Weight::from_parts(25_999_753, 3598)
 			// Standard Error: 11_769
 			.saturating_add(Weight::from_parts(26_374_574, 0).saturating_mul(c.into()))
-			.saturating_add(T::DbWeight::get().reads(6_u64))
-			.saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(c.into())))
+			.saturating_add(T::DbWeight::get().reads(8_u64))
+			.saturating_add(T::DbWeight::get().reads((4_u64).saturating_mul(c.into())))
 			.saturating_add(T::DbWeight::get().writes(3_u64))
-			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(c.into())))
+			.saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(c.into())))
 			.saturating_add(Weight::from_parts(0, 2704).saturating_mul(c.into()))
 	}
 }
@@ -295,6 +338,40 @@ impl WeightInfo for () {
 		Weight::from_parts(7_500_000, 0)
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
+	/// Storage: CollatorSelection EvictionWindow (r:0 w:1)
+	/// Proof Skipped: CollatorSelection EvictionWindow (max_values: Some(1), max_size: None, mode: Measured)
+	fn set_eviction_window() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 7_253_000 picoseconds.
+		Weight::from_parts(7_612_000, 0)
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: CollatorSelection Candidates (r:1 w:0)
+	/// Proof Skipped: CollatorSelection Candidates (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: CollatorSelection EvictionWhitelist (r:0 w:1)
+	/// Proof Skipped: CollatorSelection EvictionWhitelist (max_values: None, max_size: None, mode: Measured)
+	fn whitelist_collator() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `3183`
+		//  Estimated: `4668`
+		// Minimum execution time: 16_871_000 picoseconds.
+		Weight::from_parts(17_433_000, 4668)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: CollatorSelection EvictionWhitelist (r:1 w:1)
+	/// Proof Skipped: CollatorSelection EvictionWhitelist (max_values: None, max_size: None, mode: Measured)
+	fn remove_whitelisted_collator() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `198`
+		//  Estimated: `3663`
+		// Minimum execution time: 11_324_000 picoseconds.
+		Weight::from_parts(11_902_000, 3663)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
 	/// Storage: CollatorSelection Candidates (r:1 w:1)
 	/// Proof Skipped: CollatorSelection Candidates (max_values: Some(1), max_size: None, mode: Measured)
 	/// Storage: CollatorSelection DesiredCandidates (r:1 w:0)
@@ -404,6 +481,12 @@ impl WeightInfo for () {
 	/// Proof: System BlockWeight (max_values: Some(1), max_size: Some(48), added: 543, mode: MaxEncodedLen)
 	/// Storage: Session Validators (r:1 w:0)
 	/// Proof Skipped: Session Validators (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: CollatorSelection EvictionWindow (r:1 w:0)
+	/// Proof Skipped: CollatorSelection EvictionWindow (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: CollatorSelection EvictionWhitelist (r:51 w:0)
+	/// Proof Skipped: CollatorSelection EvictionWhitelist (max_values: None, max_size: None, mode: Measured)
+	/// Storage: CollatorSelection PerformanceHistory (r:50 w:50)
+	/// Proof Skipped: CollatorSelection PerformanceHistory (max_values: None, max_size: None, mode: Measured)
 	/// The range of component `c` is `[1, 50]`.
 	fn new_session(c: u32, ) -> Weight {
 		// Proof Size summary in bytes:
@@ -413,10 +496,10 @@ impl WeightInfo for () {
 		Weight::from_parts(25_999_753, 3598)
 			// Standard Error: 11_769
 			.saturating_add(Weight::from_parts(26_374_574, 0).saturating_mul(c.into()))
-			.saturating_add(RocksDbWeight::get().reads(6_u64))
-			.saturating_add(RocksDbWeight::get().reads((2_u64).saturating_mul(c.into())))
+			.saturating_add(RocksDbWeight::get().reads(8_u64))
+			.saturating_add(RocksDbWeight::get().reads((4_u64).saturating_mul(c.into())))
 			.saturating_add(RocksDbWeight::get().writes(3_u64))
-			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(c.into())))
+			.saturating_add(RocksDbWeight::get().writes((2_u64).saturating_mul(c.into())))
 			.saturating_add(Weight::from_parts(0, 2704).saturating_mul(c.into()))
 	}
 }
\ No newline at end of file
@@ -45,30 +45,26 @@ use core::marker::PhantomData;
 
 /// Weight functions needed for pallet_farming.
 pub trait WeightInfo {
-	fn on_initialize() -> Weight;
 	fn create_farming_pool() -> Weight;
 	fn charge() -> Weight;
 	fn deposit() -> Weight;
 	fn withdraw() -> Weight;
 	fn claim() -> Weight;
 	fn gauge_withdraw() -> Weight;
+	fn create_incentive_pool() -> Weight;
+	fn reclaim_rewards() -> Weight;
+	fn ve_lock() -> Weight;
+	fn ve_unlock() -> Weight;
+	fn update_boost() -> Weight;
+	fn vote(v: u32, ) -> Weight;
+	fn distribute_vote_rewards() -> Weight;
+	fn set_reward_schedule(n: u32, ) -> Weight;
+	fn set_vote_rewards(n: u32, ) -> Weight;
 }
 
 /// Weights for pallet_farming using the Substrate node and recommended hardware.
 pub struct SubstrateWeight<T>(PhantomData<T>);
 impl<T: frame_system::Config> pallet_farming::WeightInfo for SubstrateWeight<T> {
-	/// Storage: Farming PoolInfos (r:1 w:0)
-	/// Proof Skipped: Farming PoolInfos (max_values: None, max_size: None, mode: Measured)
-	/// Storage: Farming GaugePoolInfos (r:1 w:0)
-	/// Proof Skipped: Farming GaugePoolInfos (max_values: None, max_size: None, mode: Measured)
-	fn on_initialize() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `2105`
-		//  Estimated: `5570`
-		// Minimum execution time: 12_403_000 picoseconds.
-		Weight::from_parts(12_771_000, 5570)
-			.saturating_add(T::DbWeight::get().reads(2_u64))
-	}
 	/// Storage: Farming PoolNextId (r:1 w:1)
 	/// Proof Skipped: Farming PoolNextId (max_values: Some(1), max_size: None, mode: Measured)
 	/// Storage: Farming GaugePoolNextId (r:1 w:1)
@@ -167,22 +163,180 @@ impl<T: frame_system::Config> pallet_farming::WeightInfo for SubstrateWeight<T>
 			.saturating_add(T::DbWeight::get().reads(4_u64))
 			.saturating_add(T::DbWeight::get().writes(2_u64))
 	}
+	/// Storage: AssetManager LpToAssetIdPair (r:1 w:0)
+	/// Proof Skipped: AssetManager LpToAssetIdPair (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Farming PoolNextId (r:1 w:1)
+	/// Proof Skipped: Farming PoolNextId (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: Farming PoolCreators (r:0 w:1)
+	/// Proof Skipped: Farming PoolCreators (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Farming PoolInfos (r:0 w:1)
+	/// Proof Skipped: Farming PoolInfos (max_values: None, max_size: None, mode: Measured)
+	fn create_incentive_pool() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `421`
+		//  Estimated: `3886`
+		// Minimum execution time: 21_403_000 picoseconds.
+		Weight::from_parts(22_017_000, 3886)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	/// Storage: Farming PoolCreators (r:1 w:0)
+	/// Proof Skipped: Farming PoolCreators (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Farming PoolInfos (r:1 w:1)
+	/// Proof Skipped: Farming PoolInfos (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Assets Asset (r:1 w:1)
+	/// Proof: Assets Asset (max_values: None, max_size: Some(222), added: 2697, mode: MaxEncodedLen)
+	/// Storage: Assets Account (r:2 w:2)
+	/// Proof: Assets Account (max_values: None, max_size: Some(146), added: 2621, mode: MaxEncodedLen)
+	fn reclaim_rewards() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1052`
+		//  Estimated: `6232`
+		// Minimum execution time: 31_118_000 picoseconds.
+		Weight::from_parts(31_904_000, 6232)
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	/// Storage: Farming VeSupply (r:1 w:1)
+	/// Proof Skipped: Farming VeSupply (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: Farming VeLocks (r:1 w:1)
+	/// Proof Skipped: Farming VeLocks (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Farming VeExpiries (r:2 w:2)
+	/// Proof Skipped: Farming VeExpiries (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Balances Locks (r:1 w:1)
+	/// Proof: Balances Locks (max_values: None, max_size: Some(1299), added: 3774, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	fn ve_lock() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `412`
+		//  Estimated: `6352`
+		// Minimum execution time: 36_208_000 picoseconds.
+		Weight::from_parts(37_015_000, 6352)
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().writes(6_u64))
+	}
+	/// Storage: Farming VeLocks (r:1 w:1)
+	/// Proof Skipped: Farming VeLocks (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Farming VeSupply (r:1 w:1)
+	/// Proof Skipped: Farming VeSupply (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: Farming VeExpiries (r:1 w:1)
+	/// Proof Skipped: Farming VeExpiries (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Balances Locks (r:1 w:1)
+	/// Proof: Balances Locks (max_values: None, max_size: Some(1299), added: 3774, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	fn ve_unlock() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `498`
+		//  Estimated: `4764`
+		// Minimum execution time: 31_472_000 picoseconds.
+		Weight::from_parts(32_150_000, 4764)
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
+	}
+	/// Storage: Farming PoolInfos (r:1 w:1)
+	/// Proof Skipped: Farming PoolInfos (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Farming SharesAndWithdrawnRewards (r:1 w:1)
+	/// Proof Skipped: Farming SharesAndWithdrawnRewards (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Farming BoostShares (r:1 w:1)
+	/// Proof Skipped: Farming BoostShares (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Farming PoolBoostShares (r:1 w:1)
+	/// Proof Skipped: Farming PoolBoostShares (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Farming VeSupply (r:1 w:0)
+	/// Proof Skipped: Farming VeSupply (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: Farming VeLocks (r:1 w:0)
+	/// Proof Skipped: Farming VeLocks (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Farming VeExpiries (r:1 w:0)
+	/// Proof Skipped: Farming VeExpiries (max_values: None, max_size: None, mode: Measured)
+	fn update_boost() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1247`
+		//  Estimated: `4712`
+		// Minimum execution time: 34_931_000 picoseconds.
+		Weight::from_parts(35_702_000, 4712)
+			.saturating_add(T::DbWeight::get().reads(7_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	/// Storage: Farming VeLocks (r:1 w:0)
+	/// Proof Skipped: Farming VeLocks (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Farming EpochVotes (r:1 w:1)
+	/// Proof Skipped: Farming EpochVotes (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Farming VoteRewards (r:1 w:0)
+	/// Proof Skipped: Farming VoteRewards (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: Farming UserVotes (r:1 w:1)
+	/// Proof Skipped: Farming UserVotes (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Farming PoolInfos (r:16 w:0)
+	/// Proof Skipped: Farming PoolInfos (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Farming PoolVotes (r:16 w:16)
+	/// Proof Skipped: Farming PoolVotes (max_values: None, max_size: None, mode: Measured)
+	/// The range of component `v` is `[1, 16]`.
+	fn vote(v: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `362 + v * (118 ±0)`
+		//  Estimated: `3827 + v * (2593 ±0)`
+		// Minimum execution time: 22_410_000 picoseconds.
+		Weight::from_parts(20_935_461, 3827)
+			// Standard Error: 4_112
+			.saturating_add(Weight::from_parts(6_802_337, 0).saturating_mul(v.into()))
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(v.into())))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(v.into())))
+			.saturating_add(Weight::from_parts(0, 2593).saturating_mul(v.into()))
+	}
+	/// Storage: Farming PoolVotes (r:1 w:1)
+	/// Proof Skipped: Farming PoolVotes (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Farming EpochVotes (r:1 w:0)
+	/// Proof Skipped: Farming EpochVotes (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Farming PoolInfos (r:1 w:1)
+	/// Proof Skipped: Farming PoolInfos (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Assets Asset (r:1 w:1)
+	/// Proof: Assets Asset (max_values: None, max_size: Some(222), added: 2697, mode: MaxEncodedLen)
+	/// Storage: Assets Account (r:2 w:2)
+	/// Proof: Assets Account (max_values: None, max_size: Some(146), added: 2621, mode: MaxEncodedLen)
+	fn distribute_vote_rewards() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1190`
+		//  Estimated: `6232`
+		// Minimum execution time: 38_004_000 picoseconds.
+		Weight::from_parts(38_851_000, 6232)
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
+	}
+	/// Storage: Farming PoolInfos (r:1 w:1)
+	/// Proof Skipped: Farming PoolInfos (max_values: None, max_size: None, mode: Measured)
+	/// The range of component `n` is `[0, 16]`.
+	fn set_reward_schedule(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `540`
+		//  Estimated: `4005`
+		// Minimum execution time: 17_862_000 picoseconds.
+		Weight::from_parts(18_573_209, 4005)
+			// Standard Error: 3_914
+			.saturating_add(Weight::from_parts(1_284_517, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: Farming VoteRewards (r:0 w:1)
+	/// Proof Skipped: Farming VoteRewards (max_values: Some(1), max_size: None, mode: Measured)
+	/// The range of component `n` is `[0, 8]`.
+	fn set_vote_rewards(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 9_214_000 picoseconds.
+		Weight::from_parts(9_786_342, 0)
+			// Standard Error: 2_106
+			.saturating_add(Weight::from_parts(412_850, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
 }
 
 // For backwards compatibility and tests
 impl WeightInfo for () {
-	/// Storage: Farming PoolInfos (r:1 w:0)
-	/// Proof Skipped: Farming PoolInfos (max_values: None, max_size: None, mode: Measured)
-	/// Storage: Farming GaugePoolInfos (r:1 w:0)
-	/// Proof Skipped: Farming GaugePoolInfos (max_values: None, max_size: None, mode: Measured)
-	fn on_initialize() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `2105`
-		//  Estimated: `5570`
-		// Minimum execution time: 12_403_000 picoseconds.
-		Weight::from_parts(12_771_000, 5570)
-			.saturating_add(RocksDbWeight::get().reads(2_u64))
-	}
 	/// Storage: Farming PoolNextId (r:1 w:1)
 	/// Proof Skipped: Farming PoolNextId (max_values: Some(1), max_size: None, mode: Measured)
 	/// Storage: Farming GaugePoolNextId (r:1 w:1)
@@ -281,4 +435,174 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(4_u64))
 			.saturating_add(RocksDbWeight::get().writes(2_u64))
 	}
+	/// Storage: AssetManager LpToAssetIdPair (r:1 w:0)
+	/// Proof Skipped: AssetManager LpToAssetIdPair (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Farming PoolNextId (r:1 w:1)
+	/// Proof Skipped: Farming PoolNextId (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: Farming PoolCreators (r:0 w:1)
+	/// Proof Skipped: Farming PoolCreators (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Farming PoolInfos (r:0 w:1)
+	/// Proof Skipped: Farming PoolInfos (max_values: None, max_size: None, mode: Measured)
+	fn create_incentive_pool() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `421`
+		//  Estimated: `3886`
+		// Minimum execution time: 21_403_000 picoseconds.
+		Weight::from_parts(22_017_000, 3886)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+	/// Storage: Farming PoolCreators (r:1 w:0)
+	/// Proof Skipped: Farming PoolCreators (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Farming PoolInfos (r:1 w:1)
+	/// Proof Skipped: Farming PoolInfos (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Assets Asset (r:1 w:1)
+	/// Proof: Assets Asset (max_values: None, max_size: Some(222), added: 2697, mode: MaxEncodedLen)
+	/// Storage: Assets Account (r:2 w:2)
+	/// Proof: Assets Account (max_values: None, max_size: Some(146), added: 2621, mode: MaxEncodedLen)
+	fn reclaim_rewards() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1052`
+		//  Estimated: `6232`
+		// Minimum execution time: 31_118_000 picoseconds.
+		Weight::from_parts(31_904_000, 6232)
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+	/// Storage: Farming VeSupply (r:1 w:1)
+	/// Proof Skipped: Farming VeSupply (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: Farming VeLocks (r:1 w:1)
+	/// Proof Skipped: Farming VeLocks (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Farming VeExpiries (r:2 w:2)
+	/// Proof Skipped: Farming VeExpiries (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Balances Locks (r:1 w:1)
+	/// Proof: Balances Locks (max_values: None, max_size: Some(1299), added: 3774, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	fn ve_lock() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `412`
+		//  Estimated: `6352`
+		// Minimum execution time: 36_208_000 picoseconds.
+		Weight::from_parts(37_015_000, 6352)
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
+			.saturating_add(RocksDbWeight::get().writes(6_u64))
+	}
+	/// Storage: Farming VeLocks (r:1 w:1)
+	/// Proof Skipped: Farming VeLocks (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Farming VeSupply (r:1 w:1)
+	/// Proof Skipped: Farming VeSupply (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: Farming VeExpiries (r:1 w:1)
+	/// Proof Skipped: Farming VeExpiries (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Balances Locks (r:1 w:1)
+	/// Proof: Balances Locks (max_values: None, max_size: Some(1299), added: 3774, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	fn ve_unlock() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `498`
+		//  Estimated: `4764`
+		// Minimum execution time: 31_472_000 picoseconds.
+		Weight::from_parts(32_150_000, 4764)
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
+	}
+	/// Storage: Farming PoolInfos (r:1 w:1)
+	/// Proof Skipped: Farming PoolInfos (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Farming SharesAndWithdrawnRewards (r:1 w:1)
+	/// Proof Skipped: Farming SharesAndWithdrawnRewards (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Farming BoostShares (r:1 w:1)
+	/// Proof Skipped: Farming BoostShares (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Farming PoolBoostShares (r:1 w:1)
+	/// Proof Skipped: Farming PoolBoostShares (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Farming VeSupply (r:1 w:0)
+	/// Proof Skipped: Farming VeSupply (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: Farming VeLocks (r:1 w:0)
+	/// Proof Skipped: Farming VeLocks (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Farming VeExpiries (r:1 w:0)
+	/// Proof Skipped: Farming VeExpiries (max_values: None, max_size: None, mode: Measured)
+	fn update_boost() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1247`
+		//  Estimated: `4712`
+		// Minimum execution time: 34_931_000 picoseconds.
+		Weight::from_parts(35_702_000, 4712)
+			.saturating_add(RocksDbWeight::get().reads(7_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+	/// Storage: Farming VeLocks (r:1 w:0)
+	/// Proof Skipped: Farming VeLocks (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Farming EpochVotes (r:1 w:1)
+	/// Proof Skipped: Farming EpochVotes (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Farming VoteRewards (r:1 w:0)
+	/// Proof Skipped: Farming VoteRewards (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: Farming UserVotes (r:1 w:1)
+	/// Proof Skipped: Farming UserVotes (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Farming PoolInfos (r:16 w:0)
+	/// Proof Skipped: Farming PoolInfos (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Farming PoolVotes (r:16 w:16)
+	/// Proof Skipped: Farming PoolVotes (max_values: None, max_size: None, mode: Measured)
+	/// The range of component `v` is `[1, 16]`.
+	fn vote(v: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `362 + v * (118 ±0)`
+		//  Estimated: `3827 + v * (2593 ±0)`
+		// Minimum execution time: 22_410_000 picoseconds.
+		Weight::from_parts(20_935_461, 3827)
+			// Standard Error: 4_112
+			.saturating_add(Weight::from_parts(6_802_337, 0).saturating_mul(v.into()))
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().reads((2_u64).saturating_mul(v.into())))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(v.into())))
+			.saturating_add(Weight::from_parts(0, 2593).saturating_mul(v.into()))
+	}
+	/// Storage: Farming PoolVotes (r:1 w:1)
+	/// Proof Skipped: Farming PoolVotes (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Farming EpochVotes (r:1 w:0)
+	/// Proof Skipped: Farming EpochVotes (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Farming PoolInfos (r:1 w:1)
+	/// Proof Skipped: Farming PoolInfos (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Assets Asset (r:1 w:1)
+	/// Proof: Assets Asset (max_values: None, max_size: Some(222), added: 2697, mode: MaxEncodedLen)
+	/// Storage: Assets Account (r:2 w:2)
+	/// Proof: Assets Account (max_values: None, max_size: Some(146), added: 2621, mode: MaxEncodedLen)
+	fn distribute_vote_rewards() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1190`
+		//  Estimated: `6232`
+		// Minimum execution time: 38_004_000 picoseconds.
+		Weight::from_parts(38_851_000, 6232)
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
+	}
+	/// Storage: Farming PoolInfos (r:1 w:1)
+	/// Proof Skipped: Farming PoolInfos (max_values: None, max_size: None, mode: Measured)
+	/// The range of component `n` is `[0, 16]`.
+	fn set_reward_schedule(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `540`
+		//  Estimated: `4005`
+		// Minimum execution time: 17_862_000 picoseconds.
+		Weight::from_parts(18_573_209, 4005)
+			// Standard Error: 3_914
+			.saturating_add(Weight::from_parts(1_284_517, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: Farming VoteRewards (r:0 w:1)
+	/// Proof Skipped: Farming VoteRewards (max_values: Some(1), max_size: None, mode: Measured)
+	/// The range of component `n` is `[0, 8]`.
+	fn set_vote_rewards(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 9_214_000 picoseconds.
+		Weight::from_parts(9_786_342, 0)
+			// Standard Error: 2_106
+			.saturating_add(Weight::from_parts(412_850, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
 }
\ No newline at end of file
@@ -319,6 +319,84 @@ mod parachain_staking_tests {
                 assert!(ParachainStaking::compute_top_candidates().contains(&FERDIE));
             });
     }
+
+    #[test]
+    fn collator_selection_migration_moves_collators_into_staking() {
+        ExtBuilder::default()
+            .with_balances(vec![
+                (ALICE.clone(), MIN_BOND_TO_BE_CONSIDERED_COLLATOR + 100),
+                (BOB.clone(), MIN_BOND_TO_BE_CONSIDERED_COLLATOR + 100),
+                (CHARLIE.clone(), MIN_BOND_TO_BE_CONSIDERED_COLLATOR + 100),
+                (DAVE.clone(), MIN_BOND_TO_BE_CONSIDERED_COLLATOR + 100),
+                (EVE.clone(), MIN_BOND_TO_BE_CONSIDERED_COLLATOR + 100),
+                (FERDIE.clone(), MIN_BOND_TO_BE_CONSIDERED_COLLATOR + 100),
+                (USER.clone(), MIN_BOND_TO_BE_CONSIDERED_COLLATOR + 100),
+            ])
+            .with_invulnerables(vec![])
+            .with_authorities(vec![
+                (ALICE.clone(), ALICE_SESSION_KEYS.clone()),
+                (BOB.clone(), BOB_SESSION_KEYS.clone()),
+                (CHARLIE.clone(), CHARLIE_SESSION_KEYS.clone()),
+                (DAVE.clone(), DAVE_SESSION_KEYS.clone()),
+                (EVE.clone(), EVE_SESSION_KEYS.clone()),
+                (FERDIE.clone(), FERDIE_SESSION_KEYS.clone()),
+            ])
+            .build()
+            .execute_with(|| {
+                initialize_collators_through_whitelist(vec![
+                    ALICE.clone(),
+                    BOB.clone(),
+                    CHARLIE.clone(),
+                    DAVE.clone(),
+                    EVE.clone(),
+                ]);
+                // FERDIE is left as a candidate and USER as an invulnerable of collator selection
+                System::inc_providers(&FERDIE);
+                assert_ok!(CollatorSelection::register_candidate(
+                    root_origin(),
+                    FERDIE.clone()
+                ));
+                assert_ok!(CollatorSelection::set_invulnerables(
+                    root_origin(),
+                    vec![USER.clone()]
+                ));
+                assert_eq!(
+                    Balances::reserved_balance(FERDIE.clone()),
+                    EARLY_COLLATOR_MINIMUM_STAKE
+                );
+
+                assert_ok!(ParachainStaking::start_collator_selection_migration(
+                    root_origin()
+                ));
+                run_to_block(System::block_number() + 1);
+
+                // both joined with the whitelisted minimum stake, FERDIE got the deposit back
+                for collator in [FERDIE.clone(), USER.clone()] {
+                    assert_eq!(
+                        ParachainStaking::candidate_info(&collator).unwrap().bond,
+                        EARLY_COLLATOR_MINIMUM_STAKE
+                    );
+                    assert_eq!(Balances::reserved_balance(collator.clone()), 0);
+                }
+                assert_eq!(ParachainStaking::candidate_pool().len(), 7);
+                assert!(!ParachainStaking::collator_selection_migration());
+
+                // collator selection is drained
+                assert!(CollatorSelection::candidates().is_empty());
+                assert!(CollatorSelection::invulnerables().is_empty());
+                assert_eq!(CollatorSelection::desired_candidates(), 0);
+                assert_eq!(CollatorSelection::candidacy_bond(), 0);
+
+                // authoring follows the staking selection once the bond is sufficient
+                for collator in [FERDIE.clone(), USER.clone()] {
+                    assert_ok!(ParachainStaking::candidate_bond_more(
+                        RuntimeOrigin::signed(collator.clone()),
+                        MIN_BOND_TO_BE_CONSIDERED_COLLATOR - EARLY_COLLATOR_MINIMUM_STAKE
+                    ));
+                    assert!(ParachainStaking::compute_top_candidates().contains(&collator));
+                }
+            });
+    }
 }
 
 #[test]
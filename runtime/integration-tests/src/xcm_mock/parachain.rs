@@ -24,7 +24,10 @@ use frame_support::{
     assert_ok, construct_runtime, match_types,
     pallet_prelude::DispatchResult,
     parameter_types,
-    traits::{AsEnsureOriginWithArg, ConstU128, ConstU32, Contains, Currency, Everything, Nothing},
+    traits::{
+        fungibles, AsEnsureOriginWithArg, ConstU128, ConstU32, Contains, Currency, Everything,
+        Nothing,
+    },
     weights::Weight,
     PalletId,
 };
@@ -48,7 +51,7 @@ use manta_primitives::{
     types::{BlockNumber, CalamariAssetId, Header},
     xcm::{
         AccountIdToMultiLocation, AllowTopLevelPaidExecutionDescendOriginFirst,
-        AllowTopLevelPaidExecutionFrom, FirstAssetTrader, IsNativeConcrete, MultiAssetAdapter,
+        AllowTopLevelPaidExecutionFrom, IsNativeConcrete, MultiAssetAdapter, MultiAssetTrader,
         MultiNativeAsset, XcmFeesToAccount,
     },
 };
@@ -326,7 +329,7 @@ impl Config for XcmExecutorConfig {
     // i.e. units_per_second in `AssetManager`
     type Trader = (
         FixedRateOfFungible<ParaTokenPerSecond, XcmNativeFeeToTreasury>,
-        FirstAssetTrader<AssetManager, CalamariXcmFeesToAccount>,
+        MultiAssetTrader<AssetManager, CalamariXcmFeesToAccount>,
     );
     type ResponseHandler = PolkadotXcm;
     type AssetTrap = PolkadotXcm;
@@ -608,6 +611,14 @@ impl AssetRegistry for CalamariAssetRegistry {
             metadata.is_frozen,
         )
     }
+
+    fn destroy_asset(asset_id: &CalamariAssetId) -> DispatchResult {
+        let limit = <Runtime as pallet_assets::Config>::RemoveItemsLimit::get();
+        <Assets as fungibles::Destroy<AccountId>>::start_destroy(*asset_id, None)?;
+        <Assets as fungibles::Destroy<AccountId>>::destroy_accounts(*asset_id, limit)?;
+        <Assets as fungibles::Destroy<AccountId>>::destroy_approvals(*asset_id, limit)?;
+        <Assets as fungibles::Destroy<AccountId>>::finish_destroy(*asset_id)
+    }
 }
 
 parameter_types! {
@@ -649,6 +660,10 @@ impl AssetConfig<Runtime> for ParachainAssetConfig {
     type FungibleLedger = NativeAndNonNative<Runtime, ParachainAssetConfig, Balances, Assets>;
 }
 
+parameter_types! {
+    pub const ProposedBounds: (u128, u128) = (1, u128::MAX);
+}
+
 impl pallet_asset_manager::Config for Runtime {
     type RuntimeEvent = RuntimeEvent;
     type AssetId = CalamariAssetId;
@@ -662,6 +677,17 @@ impl pallet_asset_manager::Config for Runtime {
     type TokenNameMaxLen = ConstU32<100>;
     type TokenSymbolMaxLen = ConstU32<100>;
     type PermissionlessAssetRegistryCost = ConstU128<1000>;
+    type SiblingAssetOrigin = EnsureNever<(u32, AccountId)>;
+    type VetoOrigin = EnsureRoot<AccountId>;
+    type AssetChallengePeriod = ConstU32<10>;
+    type MaxActivationsPerBlock = ConstU32<10>;
+    type AssetProposalDeposit = ConstU128<1_000>;
+    type ProposedMinBalanceBounds = ProposedBounds;
+    type ProposedUnitsPerSecondBounds = ProposedBounds;
+    type NativePrice = ();
+    type NativeUnitsPerSecond = ConstU128<1_000_000_000>;
+    type SpotPriceAveragingPeriod = ConstU32<10>;
+    type MaxDynamicPricedAssets = ConstU32<10>;
 }
 
 impl cumulus_pallet_xcm::Config for Runtime {
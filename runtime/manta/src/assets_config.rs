@@ -15,9 +15,11 @@
 // along with Manta.  If not, see <http://www.gnu.org/licenses/>.
 
 use super::{
-    weights, xcm_config::SelfReserve, AssetManager, Assets, Balances, CouncilCollective,
-    NativeTokenExistentialDeposit, Runtime, RuntimeEvent, RuntimeOrigin, TechnicalCollective,
-    Timestamp, MANTA,
+    weights,
+    xcm_config::{NativeUnitsPerSecond, SelfReserve},
+    zenlink::ZenlinkNativePrice,
+    AssetManager, Assets, Balances, CouncilCollective, NativeTokenExistentialDeposit, Runtime,
+    RuntimeEvent, RuntimeOrigin, TechnicalCollective, Timestamp, MANTA,
 };
 
 use manta_primitives::{
@@ -25,17 +27,25 @@ use manta_primitives::{
         AssetConfig, AssetIdType, AssetLocation, AssetRegistry, AssetRegistryMetadata,
         AssetStorageMetadata, BalanceType, LocationType, NativeAndNonNative,
     },
-    constants::{ASSET_MANAGER_PALLET_ID, MANTA_DECIMAL, MANTA_SBT_PALLET_ID},
-    types::{AccountId, Balance, MantaAssetId, Signature, Signer},
+    constants::{
+        time::{DAYS, HOURS},
+        ASSET_MANAGER_PALLET_ID, MANTA_DECIMAL, MANTA_SBT_PALLET_ID,
+    },
+    types::{AccountId, Balance, BlockNumber, MantaAssetId, Signature, Signer},
 };
 
 use frame_support::{
     pallet_prelude::DispatchResult,
     parameter_types,
-    traits::{AsEnsureOriginWithArg, ConstU128, ConstU16, ConstU32, EitherOfDiverse},
+    traits::{
+        fungibles, AsEnsureOriginWithArg, ConstU128, ConstU16, ConstU32, EitherOfDiverse, Get,
+        MapSuccess,
+    },
     PalletId,
 };
 use frame_system::EnsureRoot;
+use polkadot_parachain::primitives::{Id as ParaId, Sibling};
+use sp_runtime::traits::{AccountIdConversion, Morph};
 
 use xcm::VersionedMultiLocation;
 
@@ -124,6 +134,14 @@ impl AssetRegistry for MantaAssetRegistry {
             metadata.is_frozen,
         )
     }
+
+    fn destroy_asset(asset_id: &MantaAssetId) -> DispatchResult {
+        let limit = <Runtime as pallet_assets::Config>::RemoveItemsLimit::get();
+        <Assets as fungibles::Destroy<AccountId>>::start_destroy(*asset_id, None)?;
+        <Assets as fungibles::Destroy<AccountId>>::destroy_accounts(*asset_id, limit)?;
+        <Assets as fungibles::Destroy<AccountId>>::destroy_approvals(*asset_id, limit)?;
+        <Assets as fungibles::Destroy<AccountId>>::finish_destroy(*asset_id)
+    }
 }
 
 parameter_types! {
@@ -168,6 +186,32 @@ impl AssetConfig<Runtime> for MantaAssetConfig {
     type FungibleLedger = MantaConcreteFungibleLedger;
 }
 
+parameter_types! {
+    pub const AssetChallengePeriod: BlockNumber = 7 * DAYS;
+    pub const SpotPriceAveragingPeriod: BlockNumber = 1 * HOURS;
+    pub const AssetProposalDeposit: Balance = 50 * MANTA;
+    /// Proposed assets are never sufficient, their min balance only has to be non-zero and
+    /// holdable.
+    pub const ProposedMinBalanceBounds: (Balance, Balance) = (1, 1_000_000_000_000_000_000);
+    pub const ProposedUnitsPerSecondBounds: (u128, u128) =
+        (1_000_000, 1_000_000_000_000_000_000_000);
+}
+
+/// Maps the para id of a sibling parachain to itself and its sovereign account.
+pub struct SiblingParaIdAndAccount;
+impl Morph<ParaId> for SiblingParaIdAndAccount {
+    type Outcome = (u32, AccountId);
+
+    fn morph(para_id: ParaId) -> Self::Outcome {
+        (para_id.into(), Sibling(para_id).into_account_truncating())
+    }
+}
+
+/// Sibling parachains proposing one of their assets through an XCM `Transact` with
+/// `OriginKind::Native`.
+pub type EnsureSiblingParaId =
+    MapSuccess<cumulus_pallet_xcm::EnsureSiblingParachain<RuntimeOrigin>, SiblingParaIdAndAccount>;
+
 impl pallet_asset_manager::Config for Runtime {
     type PermissionlessStartId = ConstU128<1_000_000_000>;
     type TokenNameMaxLen = StringLimit;
@@ -183,6 +227,20 @@ impl pallet_asset_manager::Config for Runtime {
         pallet_collective::EnsureProportionAtLeast<AccountId, CouncilCollective, 3, 4>,
     >;
     type PalletId = AssetManagerPalletId;
+    type SiblingAssetOrigin = EnsureSiblingParaId;
+    type VetoOrigin = EitherOfDiverse<
+        EnsureRoot<AccountId>,
+        pallet_collective::EnsureProportionAtLeast<AccountId, TechnicalCollective, 2, 3>,
+    >;
+    type AssetChallengePeriod = AssetChallengePeriod;
+    type MaxActivationsPerBlock = ConstU32<10>;
+    type AssetProposalDeposit = AssetProposalDeposit;
+    type ProposedMinBalanceBounds = ProposedMinBalanceBounds;
+    type ProposedUnitsPerSecondBounds = ProposedUnitsPerSecondBounds;
+    type NativePrice = ZenlinkNativePrice;
+    type NativeUnitsPerSecond = NativeUnitsPerSecond;
+    type SpotPriceAveragingPeriod = SpotPriceAveragingPeriod;
+    type MaxDynamicPricedAssets = ConstU32<20>;
     type WeightInfo = weights::pallet_asset_manager::SubstrateWeight<Runtime>;
 }
 
@@ -62,8 +62,8 @@ use frame_system::{
 };
 use manta_primitives::{
     constants::{
-        time::*, RocksDbWeight, LOTTERY_PALLET_ID, NAME_SERVICE_PALLET_ID, STAKING_PALLET_ID,
-        TREASURY_PALLET_ID, WEIGHT_PER_SECOND,
+        time::*, RocksDbWeight, ASSET_LOTTERY_PALLET_ID, LOTTERY_PALLET_ID, NAME_SERVICE_PALLET_ID,
+        STAKING_PALLET_ID, TREASURY_PALLET_ID, WEIGHT_PER_SECOND,
     },
     types::{AccountId, Balance, BlockNumber, Hash, Header, Index, PoolId, Signature},
 };
@@ -239,7 +239,14 @@ impl Contains<RuntimeCall> for MantaFilter {
             | RuntimeCall::CouncilMembership(_)
             | RuntimeCall::TechnicalMembership(_)
             | RuntimeCall::Lottery(_)
-            | RuntimeCall::Randomness(pallet_randomness::Call::set_babe_randomness_results{..})
+            | RuntimeCall::AssetLottery(_)
+            | RuntimeCall::Randomness(
+                pallet_randomness::Call::set_babe_randomness_results{..}
+                | pallet_randomness::Call::request_randomness{..}
+                | pallet_randomness::Call::fulfil_randomness{..}
+                | pallet_randomness::Call::purge_expired_request{..}
+                | pallet_randomness::Call::request_local_randomness{..}
+                | pallet_randomness::Call::set_local_vrf_enforced{..})
             | RuntimeCall::Scheduler(_)
             // Sudo also cannot be filtered because it is used in runtime upgrade.
             | RuntimeCall::Sudo(_)
@@ -251,6 +258,7 @@ impl Contains<RuntimeCall> for MantaFilter {
                 pallet_parachain_staking::Call::join_candidates{..}
                 | pallet_parachain_staking::Call::schedule_leave_candidates{..}
                 | pallet_parachain_staking::Call::set_staking_expectations{..}
+                | pallet_parachain_staking::Call::set_inflation_curve{..}
                 | pallet_parachain_staking::Call::execute_leave_candidates{..}
                 | pallet_parachain_staking::Call::cancel_leave_candidates{..}
                 | pallet_parachain_staking::Call::go_offline{..}
@@ -259,6 +267,9 @@ impl Contains<RuntimeCall> for MantaFilter {
                 | pallet_parachain_staking::Call::schedule_candidate_bond_less{..}
                 | pallet_parachain_staking::Call::execute_candidate_bond_less{..}
                 | pallet_parachain_staking::Call::cancel_candidate_bond_less{..}
+                | pallet_parachain_staking::Call::schedule_commission_change{..}
+                | pallet_parachain_staking::Call::execute_commission_change{..}
+                | pallet_parachain_staking::Call::cancel_commission_change{..}
                 // Delegator extrinsics
                 | pallet_parachain_staking::Call::delegate{..}
                 | pallet_parachain_staking::Call::schedule_leave_delegators{..}
@@ -268,7 +279,12 @@ impl Contains<RuntimeCall> for MantaFilter {
                 | pallet_parachain_staking::Call::delegator_bond_more{..}
                 | pallet_parachain_staking::Call::schedule_delegator_bond_less{..}
                 | pallet_parachain_staking::Call::execute_delegation_request{..}
-                | pallet_parachain_staking::Call::cancel_delegation_request{..})
+                | pallet_parachain_staking::Call::cancel_delegation_request{..}
+                | pallet_parachain_staking::Call::delegate_with_auto_compound{..}
+                | pallet_parachain_staking::Call::set_auto_compound{..}
+                | pallet_parachain_staking::Call::redelegate{..}
+                | pallet_parachain_staking::Call::claim_rewards{..}
+                | pallet_parachain_staking::Call::claim_rewards_for{..})
             | RuntimeCall::XTokens(_)
             | RuntimeCall::Balances(_)
             | RuntimeCall::Preimage(_)
@@ -284,7 +300,10 @@ impl Contains<RuntimeCall> for MantaFilter {
             | RuntimeCall::PolkadotXcm(pallet_xcm::Call::send {..})
             | RuntimeCall::AssetManager(
                 pallet_asset_manager::Call::update_outgoing_filtered_assets {..}
-                | pallet_asset_manager::Call::permissionless_register_asset {..})
+                | pallet_asset_manager::Call::permissionless_register_asset {..}
+                | pallet_asset_manager::Call::propose_asset {..}
+                | pallet_asset_manager::Call::veto_pending_asset {..}
+                | pallet_asset_manager::Call::freeze_asset {..})
             | RuntimeCall::Utility(_) => true,
 
             // DISALLOW anything else
@@ -401,8 +420,54 @@ impl pallet_randomness::GetBabeData<u64, Option<Hash>> for BabeDataGetter {
             .flatten()
     }
 }
+parameter_types! {
+    pub const RandomnessRequestDeposit: Balance = 10 * MANTA;
+    pub const MaxRandomWords: u8 = 100;
+    /// Requests unfulfilled a day (polkadot: 4 days) after their relay epoch can be purged
+    pub const RandomnessEpochExpirationDelay: u64 = 24;
+    pub const RandomnessMinBlockDelay: BlockNumber = 2;
+    pub const RandomnessMaxBlockDelay: BlockNumber = 1 * DAYS;
+    pub const RandomnessBlockExpirationDelay: BlockNumber = 1 * DAYS;
+}
+/// Looks up the VRF session key registered together with the nimbus key of a block author
+pub struct VrfKeyLookup;
+impl session_key_primitives::vrf::KeysLookup<NimbusId, VrfId> for VrfKeyLookup {
+    fn lookup_keys(author: &NimbusId) -> Option<VrfId> {
+        use sp_core::crypto::ByteArray;
+        Session::key_owner(nimbus_primitives::NIMBUS_KEY_ID, &author.to_raw_vec())
+            .and_then(pallet_session::NextKeys::<Runtime>::get)
+            .map(|keys| keys.vrf)
+    }
+
+    #[cfg(feature = "runtime-benchmarks")]
+    fn set_keys(author: NimbusId, keys: VrfId) {
+        use sp_core::crypto::ByteArray;
+        let account = AccountId::from(
+            <[u8; 32]>::try_from(author.as_slice()).expect("nimbus keys have 32 bytes"),
+        );
+        let aura = AuraId::from_slice(author.as_slice()).expect("nimbus keys are sr25519 keys");
+        frame_system::Pallet::<Runtime>::inc_providers(&account);
+        Session::set_keys(
+            RuntimeOrigin::signed(account),
+            opaque::SessionKeys::new((aura, author, keys)),
+            Vec::new(),
+        )
+        .expect("keys of a new account can be registered");
+    }
+}
 impl pallet_randomness::Config for Runtime {
+    type RuntimeEvent = RuntimeEvent;
     type BabeDataGetter = BabeDataGetter;
+    type Currency = Balances;
+    type Deposit = RandomnessRequestDeposit;
+    type MaxRandomWords = MaxRandomWords;
+    type EpochExpirationDelay = RandomnessEpochExpirationDelay;
+    type OnRandomnessFulfilled = ();
+    type VrfKeyLookup = VrfKeyLookup;
+    type MinBlockDelay = RandomnessMinBlockDelay;
+    type MaxBlockDelay = RandomnessMaxBlockDelay;
+    type BlockExpirationDelay = RandomnessBlockExpirationDelay;
+    type ManageOrigin = EnsureRootOrMoreThanHalfCouncil;
     type WeightInfo = weights::pallet_randomness::SubstrateWeight<Runtime>;
 }
 parameter_types! {
@@ -413,6 +478,8 @@ parameter_types! {
     pub DrawingFreezeout: BlockNumber = prod_or_fast!(1 * DAYS, 1 * MINUTES);
     /// Time in blocks until a collator is done unstaking
     pub UnstakeLockTime: BlockNumber = LeaveDelayRounds::get() * DefaultBlocksPerRound::get();
+    /// Highest fee governance can charge on instant withdrawals from the liquidity buffer
+    pub const MaxInstantWithdrawFee: Perbill = Perbill::from_percent(5);
 }
 impl pallet_lottery::Config for Runtime {
     type RuntimeCall = RuntimeCall;
@@ -426,6 +493,41 @@ impl pallet_lottery::Config for Runtime {
     type DrawingInterval = DrawingInterval;
     type DrawingFreezeout = DrawingFreezeout;
     type UnstakeLockTime = UnstakeLockTime;
+    type MaxInstantWithdrawFee = MaxInstantWithdrawFee;
+    type MaxDepositStrategies = ConstU32<4>;
+    type MaxWithdrawStrategies = ConstU32<3>;
+    type MaxCollatorBlocklistLen = ConstU32<100>;
+    type BalanceConversion = Balance;
+    type WeightInfo = weights::pallet_lottery::SubstrateWeight<Runtime>;
+}
+
+parameter_types! {
+    pub const AssetLotteryPotId: PalletId = ASSET_LOTTERY_PALLET_ID;
+    /// Time in blocks between asset lottery drawings
+    pub AssetLotteryDrawingInterval: BlockNumber = prod_or_fast!(14 * DAYS, 5 * MINUTES);
+    /// Limits the asset lottery starts with, at genesis or when added by a runtime upgrade
+    pub const AssetLotteryMinDeposit: Balance = 500 * MANTA;
+    pub const AssetLotteryMinWithdraw: Balance = 10 * MANTA;
+    pub const AssetLotteryGasReserve: Balance = 1_000 * MANTA;
+}
+/// Lottery over a [`pallet_lottery::PotBacking`] other than staking, e.g. an LP token farming pool
+pub type AssetLotteryInstance = pallet_lottery::Instance1;
+impl pallet_lottery::Config<AssetLotteryInstance> for Runtime {
+    type RuntimeCall = RuntimeCall;
+    type RuntimeEvent = RuntimeEvent;
+    type Scheduler = Scheduler;
+    type EstimateCallFee = TransactionPayment;
+    type RandomnessSource = Randomness;
+    type ManageOrigin = EnsureRootOrMoreThanHalfCouncil;
+    type PalletsOrigin = OriginCaller;
+    type LotteryPot = AssetLotteryPotId;
+    type DrawingInterval = AssetLotteryDrawingInterval;
+    type DrawingFreezeout = DrawingFreezeout;
+    type UnstakeLockTime = UnstakeLockTime;
+    type MaxInstantWithdrawFee = MaxInstantWithdrawFee;
+    type MaxDepositStrategies = ConstU32<4>;
+    type MaxWithdrawStrategies = ConstU32<3>;
+    type MaxCollatorBlocklistLen = ConstU32<100>;
     type BalanceConversion = Balance;
     type WeightInfo = weights::pallet_lottery::SubstrateWeight<Runtime>;
 }
@@ -556,6 +658,15 @@ impl pallet_parachain_staking::Config for Runtime {
     type MinDelegatorStk = ConstU128<{ 500 * MANTA }>;
     type OnCollatorPayout = ();
     type OnNewRound = ();
+    /// Slashed funds are sent to the treasury
+    type OnSlash = Treasury;
+    /// Rounds before a slash is applied, it must be shorter than the leave delay
+    type SlashDeferDuration = ConstU32<4>;
+    /// Rounds a delegator must wait between redelegations, 1 day
+    type RedelegationCooldown = ConstU32<4>;
+    /// Delegators can revoke before a commission change takes effect
+    type CommissionChangeDelay = LeaveDelayRounds;
+    type MaxCollatorSelectionMigrationsPerBlock = ConstU32<2>;
     type WeightInfo = weights::pallet_parachain_staking::SubstrateWeight<Runtime>;
 }
 
@@ -688,6 +799,7 @@ impl manta_collator_selection::Config for Runtime {
     /// Nimbus filter pipeline step 2:
     /// Filters collators not part of the current pallet_session::validators()
     type CanAuthor = AuraAuthorFilter;
+    type MaxEvictionWindow = ConstU32<8>; // judge collator performance over 8 sessions at most
 }
 
 parameter_types! {
@@ -868,6 +980,10 @@ parameter_types! {
     pub const FarmingKeeperPalletId: PalletId = PalletId(*b"mt/fmkpr");
     pub const FarmingRewardIssuerPalletId: PalletId = PalletId(*b"mt/fmrir");
     pub TreasuryAccount: AccountId = TreasuryPalletId::get().into_account_truncating();
+    pub const FarmingPoolCreationDeposit: Balance = 50 * MANTA;
+    pub const FarmingVeEpochLength: BlockNumber = 7 * DAYS;
+    pub const FarmingMaxLimitTime: BlockNumber = 28 * DAYS;
+    pub const FarmingMaxBoost: Perbill = Perbill::from_percent(100);
 }
 
 /// Zenlink protocol Asset adaptor for orml_traits::MultiCurrency.
@@ -884,6 +1000,18 @@ impl pallet_farming::Config for Runtime {
     type TreasuryAccount = TreasuryAccount;
     type Keeper = FarmingKeeperPalletId;
     type RewardIssuer = FarmingRewardIssuerPalletId;
+    type Currency = Balances;
+    type PoolCreationDeposit = FarmingPoolCreationDeposit;
+    type LpAssets = AssetManager;
+    type MaxBasicRewards = ConstU32<8>;
+    type MaxRewardSteps = ConstU32<16>;
+    type MaxWithdrawLimitTime = FarmingMaxLimitTime;
+    type MaxClaimLimitTime = FarmingMaxLimitTime;
+    type VeEpochLength = FarmingVeEpochLength;
+    type MaxLockEpochs = ConstU32<104>;
+    type MaxBoost = FarmingMaxBoost;
+    type MaxVotes = ConstU32<16>;
+    type Liquidity = zenlink::ZenlinkFarmingLiquidity;
     type WeightInfo = weights::pallet_farming::SubstrateWeight<Runtime>;
 }
 
@@ -992,8 +1120,9 @@ construct_runtime!(
         Farming: pallet_farming::{Pallet, Call, Storage, Event<T>} = 54,
 
         // Lottery
-        Randomness: pallet_randomness::{Pallet, Call, Storage, Inherent} = 70,
-        Lottery: pallet_lottery::{Pallet, Call, Storage, Event<T>, Config<T>} = 71 // Beware: Lottery depends on Randomness inherent
+        Randomness: pallet_randomness::{Pallet, Call, Storage, Event<T>, Inherent} = 70,
+        Lottery: pallet_lottery::{Pallet, Call, Storage, Event<T>, Config<T>} = 71, // Beware: Lottery depends on Randomness inherent
+        AssetLottery: pallet_lottery::<Instance1>::{Pallet, Call, Storage, Event<T>, Config<T>} = 72,
 
     }
 );
@@ -1024,7 +1153,20 @@ pub type CheckedExtrinsic = generic::CheckedExtrinsic<AccountId, RuntimeCall, Si
 
 /// Types for runtime upgrading.
 /// Each type should implement trait `OnRuntimeUpgrade`.
-pub type OnRuntimeUpgradeHooks = ();
+pub type OnRuntimeUpgradeHooks = (
+    pallet_farming::migrations::LazyRewardAccrualMigration<Runtime>,
+    pallet_farming::migrations::RewardScheduleMigration<Runtime>,
+    pallet_parachain_staking::migrations::CandidateCommissionMigration<Runtime>,
+    pallet_parachain_staking::migrations::LazyRewardsMigration<Runtime>,
+    pallet_asset_manager::migrations::AssetIdToLpMigration<Runtime>,
+    pallet_lottery::migrations::InitializeLotteryParams<
+        Runtime,
+        AssetLotteryInstance,
+        AssetLotteryMinDeposit,
+        AssetLotteryMinWithdraw,
+        AssetLotteryGasReserve,
+    >,
+);
 /// Executive: handles dispatch to the various modules.
 pub type Executive = frame_executive::Executive<
     Runtime,
@@ -1237,12 +1379,38 @@ impl_runtime_apis! {
         }
     }
 
+    impl pallet_parachain_staking::runtime::ParachainStakingApi<Block, AccountId, Balance> for Runtime {
+        fn candidate_expected_apy(candidate: AccountId) -> Option<sp_runtime::FixedU128> {
+            ParachainStaking::candidate_expected_apy(&candidate)
+        }
+        fn delegator_pending_rewards(delegator: AccountId) -> Balance {
+            ParachainStaking::delegator_pending_rewards(&delegator)
+        }
+        fn candidate_scheduled_requests(
+            candidate: AccountId,
+        ) -> Vec<pallet_parachain_staking::ScheduledRequest<AccountId, Balance>> {
+            ParachainStaking::delegation_scheduled_requests(&candidate)
+        }
+        fn projected_selected_candidates() -> Vec<AccountId> {
+            ParachainStaking::compute_top_candidates()
+        }
+    }
+
     impl cumulus_primitives_core::CollectCollationInfo<Block> for Runtime {
         fn collect_collation_info(header: &<Block as BlockT>::Header) -> cumulus_primitives_core::CollationInfo {
             ParachainSystem::collect_collation_info(header)
         }
     }
 
+    impl session_key_primitives::vrf::VrfApi<Block> for Runtime {
+        fn get_last_vrf_output() -> Option<<Block as BlockT>::Hash> {
+            Randomness::local_vrf_output()
+        }
+        fn vrf_key_lookup(nimbus_id: NimbusId) -> Option<VrfId> {
+            <VrfKeyLookup as session_key_primitives::vrf::KeysLookup<_, _>>::lookup_keys(&nimbus_id)
+        }
+    }
+
     impl nimbus_primitives::NimbusApi<Block> for Runtime {
         fn can_author(author: NimbusId, relay_parent: u32, parent_header: &<Block as BlockT>::Header) -> bool {
             let next_block_number = parent_header.number + 1;
@@ -1358,6 +1526,7 @@ impl_runtime_apis! {
         }
     }
 
+    #[api_version(2)]
     impl pallet_farming_rpc_runtime_api::FarmingRuntimeApi<Block, AccountId, MantaAssetId, PoolId> for Runtime {
         fn get_farming_rewards(who: AccountId, pid: PoolId) -> Vec<(MantaAssetId, Balance)> {
             Farming::get_farming_rewards(&who, pid).unwrap_or_default()
@@ -1366,6 +1535,22 @@ impl_runtime_apis! {
         fn get_gauge_rewards(who: AccountId, pid: PoolId) -> Vec<(MantaAssetId, Balance)> {
             Farming::get_gauge_rewards(&who, pid).unwrap_or_default()
         }
+
+        fn get_pool_runway(pid: PoolId) -> Option<BlockNumber> {
+            Farming::pool_runway(pid).ok().flatten()
+        }
+
+        fn get_pools() -> Vec<pallet_farming::PoolSummary<Balance, MantaAssetId, BlockNumber>> {
+            Farming::get_pool_summaries()
+        }
+
+        fn get_pool_apr(pid: PoolId, prices: Vec<(MantaAssetId, sp_runtime::FixedU128)>) -> Option<sp_runtime::FixedU128> {
+            Farming::get_pool_apr(pid, &prices.into_iter().collect(), 365 * DAYS)
+        }
+
+        fn get_user_positions(who: AccountId) -> Vec<pallet_farming::UserPosition<Balance, MantaAssetId, BlockNumber>> {
+            Farming::get_user_positions(&who)
+        }
     }
 
     #[cfg(feature = "try-runtime")]
@@ -54,6 +54,14 @@ pub trait WeightInfo {
 	fn update_outgoing_filtered_assets() -> Weight;
 	fn register_lp_asset() -> Weight;
 	fn permissionless_register_asset() -> Weight;
+	fn propose_asset() -> Weight;
+	fn veto_pending_asset() -> Weight;
+	fn activate_pending_assets(n: u32, ) -> Weight;
+	fn set_dynamic_pricing() -> Weight;
+	fn update_dynamic_prices(n: u32, ) -> Weight;
+	fn freeze_asset() -> Weight;
+	fn unfreeze_asset() -> Weight;
+	fn deregister_asset() -> Weight;
 }
 
 /// Weights for pallet_asset_manager using the Substrate node and recommended hardware.
@@ -174,6 +182,8 @@ impl<T: frame_system::Config> pallet_asset_manager::WeightInfo for SubstrateWeig
 	/// Proof Skipped: AssetManager AssetIdMetadata (max_values: None, max_size: None, mode: Measured)
 	/// Storage: AssetManager LpToAssetIdPair (r:0 w:1)
 	/// Proof Skipped: AssetManager LpToAssetIdPair (max_values: None, max_size: None, mode: Measured)
+	/// Storage: AssetManager AssetIdToLp (r:0 w:2)
+	/// Proof Skipped: AssetManager AssetIdToLp (max_values: None, max_size: None, mode: Measured)
 	fn register_lp_asset() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `545`
@@ -181,7 +191,7 @@ impl<T: frame_system::Config> pallet_asset_manager::WeightInfo for SubstrateWeig
 		// Minimum execution time: 54_735_000 picoseconds.
 		Weight::from_parts(55_742_000, 6485)
 			.saturating_add(T::DbWeight::get().reads(6_u64))
-			.saturating_add(T::DbWeight::get().writes(6_u64))
+			.saturating_add(T::DbWeight::get().writes(8_u64))
 	}
 	/// Storage: System Account (r:1 w:1)
 	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
@@ -204,6 +214,183 @@ impl<T: frame_system::Config> pallet_asset_manager::WeightInfo for SubstrateWeig
 			.saturating_add(T::DbWeight::get().reads(5_u64))
 			.saturating_add(T::DbWeight::get().writes(6_u64))
 	}
+	/// Storage: AssetManager LocationAssetId (r:1 w:0)
+	/// Proof Skipped: AssetManager LocationAssetId (max_values: None, max_size: None, mode: Measured)
+	/// Storage: AssetManager PendingAssets (r:1 w:1)
+	/// Proof Skipped: AssetManager PendingAssets (max_values: None, max_size: None, mode: Measured)
+	/// Storage: AssetManager PendingActivations (r:1 w:1)
+	/// Proof Skipped: AssetManager PendingActivations (max_values: None, max_size: None, mode: Measured)
+	/// Storage: System Account (r:2 w:2)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	fn propose_asset() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1420`
+		//  Estimated: `6196`
+		// Minimum execution time: 71_062_000 picoseconds.
+		Weight::from_parts(73_415_000, 6196)
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	/// Storage: AssetManager PendingAssets (r:1 w:1)
+	/// Proof Skipped: AssetManager PendingAssets (max_values: None, max_size: None, mode: Measured)
+	fn veto_pending_asset() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `412`
+		//  Estimated: `3877`
+		// Minimum execution time: 15_027_000 picoseconds.
+		Weight::from_parts(15_611_000, 3877)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: AssetManager PendingActivations (r:1 w:1)
+	/// Proof Skipped: AssetManager PendingActivations (max_values: None, max_size: None, mode: Measured)
+	/// Storage: AssetManager PendingAssets (r:10 w:10)
+	/// Proof Skipped: AssetManager PendingAssets (max_values: None, max_size: None, mode: Measured)
+	/// Storage: AssetManager LocationAssetId (r:10 w:10)
+	/// Proof Skipped: AssetManager LocationAssetId (max_values: None, max_size: None, mode: Measured)
+	/// Storage: AssetManager NextAssetId (r:1 w:1)
+	/// Proof Skipped: AssetManager NextAssetId (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: Assets Asset (r:10 w:10)
+	/// Proof: Assets Asset (max_values: None, max_size: Some(222), added: 2697, mode: MaxEncodedLen)
+	/// Storage: Assets Metadata (r:10 w:10)
+	/// Proof: Assets Metadata (max_values: None, max_size: Some(152), added: 2627, mode: MaxEncodedLen)
+	/// Storage: AssetManager AllowedDestParaIds (r:1 w:1)
+	/// Proof Skipped: AssetManager AllowedDestParaIds (max_values: None, max_size: None, mode: Measured)
+	/// Storage: AssetManager AssetIdMetadata (r:0 w:10)
+	/// Proof Skipped: AssetManager AssetIdMetadata (max_values: None, max_size: None, mode: Measured)
+	/// Storage: AssetManager AssetIdLocation (r:0 w:10)
+	/// Proof Skipped: AssetManager AssetIdLocation (max_values: None, max_size: None, mode: Measured)
+	/// Storage: AssetManager UnitsPerSecond (r:0 w:10)
+	/// Proof Skipped: AssetManager UnitsPerSecond (max_values: None, max_size: None, mode: Measured)
+	/// Storage: System Account (r:2 w:2)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// The range of component `n` is `[1, 10]`.
+	fn activate_pending_assets(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `504 + n * (228 ±0)`
+		//  Estimated: `6196 + n * (2697 ±0)`
+		// Minimum execution time: 48_211_000 picoseconds.
+		Weight::from_parts(21_637_000, 6196)
+			// Standard Error: 24_871
+			.saturating_add(Weight::from_parts(58_905_000, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().reads((4_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+			.saturating_add(T::DbWeight::get().writes((7_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 2697).saturating_mul(n.into()))
+	}
+	/// Storage: AssetManager AssetIdLocation (r:1 w:0)
+	/// Proof Skipped: AssetManager AssetIdLocation (max_values: None, max_size: None, mode: Measured)
+	/// Storage: AssetManager DynamicPricing (r:1 w:1)
+	/// Proof Skipped: AssetManager DynamicPricing (max_values: None, max_size: None, mode: Measured)
+	/// Storage: AssetManager CounterForDynamicPricing (r:1 w:1)
+	/// Proof: AssetManager CounterForDynamicPricing (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	fn set_dynamic_pricing() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1520`
+		//  Estimated: `4985`
+		// Minimum execution time: 21_633_000 picoseconds.
+		Weight::from_parts(22_404_000, 4985)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: AssetManager DynamicPricing (r:11 w:10)
+	/// Proof Skipped: AssetManager DynamicPricing (max_values: None, max_size: None, mode: Measured)
+	/// Storage: ZenlinkProtocol LiquidityPairs (r:10 w:0)
+	/// Proof Skipped: ZenlinkProtocol LiquidityPairs (max_values: None, max_size: None, mode: Measured)
+	/// Storage: ZenlinkProtocol PairStatuses (r:10 w:0)
+	/// Proof Skipped: ZenlinkProtocol PairStatuses (max_values: None, max_size: None, mode: Measured)
+	/// Storage: System Account (r:1 w:0)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: Assets Account (r:10 w:0)
+	/// Proof: Assets Account (max_values: None, max_size: Some(146), added: 2621, mode: MaxEncodedLen)
+	/// The range of component `n` is `[1, 10]`.
+	fn update_dynamic_prices(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `186 + n * (412 ±0)`
+		//  Estimated: `3593 + n * (2621 ±0)`
+		// Minimum execution time: 12_105_000 picoseconds.
+		Weight::from_parts(8_927_000, 3593)
+			// Standard Error: 18_460
+			.saturating_add(Weight::from_parts(14_512_000, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().reads((4_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 2621).saturating_mul(n.into()))
+	}
+	/// Storage: AssetManager AssetIdMetadata (r:1 w:0)
+	/// Proof Skipped: AssetManager AssetIdMetadata (max_values: None, max_size: None, mode: Measured)
+	/// Storage: AssetManager FrozenAssets (r:1 w:1)
+	/// Proof Skipped: AssetManager FrozenAssets (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Assets Asset (r:1 w:0)
+	/// Proof: Assets Asset (max_values: None, max_size: Some(222), added: 2697, mode: MaxEncodedLen)
+	/// Storage: Assets Metadata (r:1 w:1)
+	/// Proof: Assets Metadata (max_values: None, max_size: Some(152), added: 2627, mode: MaxEncodedLen)
+	fn freeze_asset() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1632`
+		//  Estimated: `5097`
+		// Minimum execution time: 31_842_000 picoseconds.
+		Weight::from_parts(32_519_000, 5097)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: AssetManager FrozenAssets (r:1 w:1)
+	/// Proof Skipped: AssetManager FrozenAssets (max_values: None, max_size: None, mode: Measured)
+	/// Storage: AssetManager AssetIdMetadata (r:1 w:0)
+	/// Proof Skipped: AssetManager AssetIdMetadata (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Assets Asset (r:1 w:0)
+	/// Proof: Assets Asset (max_values: None, max_size: Some(222), added: 2697, mode: MaxEncodedLen)
+	/// Storage: Assets Metadata (r:1 w:1)
+	/// Proof: Assets Metadata (max_values: None, max_size: Some(152), added: 2627, mode: MaxEncodedLen)
+	fn unfreeze_asset() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1667`
+		//  Estimated: `5132`
+		// Minimum execution time: 31_196_000 picoseconds.
+		Weight::from_parts(31_903_000, 5132)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: AssetManager FrozenAssets (r:1 w:1)
+	/// Proof Skipped: AssetManager FrozenAssets (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Assets Asset (r:1 w:1)
+	/// Proof: Assets Asset (max_values: None, max_size: Some(222), added: 2697, mode: MaxEncodedLen)
+	/// Storage: AssetManager AssetIdToLp (r:1 w:2)
+	/// Proof Skipped: AssetManager AssetIdToLp (max_values: None, max_size: None, mode: Measured)
+	/// Storage: AssetManager AssetIdPairToLp (r:0 w:1)
+	/// Proof Skipped: AssetManager AssetIdPairToLp (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Assets Metadata (r:1 w:1)
+	/// Proof: Assets Metadata (max_values: None, max_size: Some(152), added: 2627, mode: MaxEncodedLen)
+	/// Storage: AssetManager LpToAssetIdPair (r:1 w:1)
+	/// Proof Skipped: AssetManager LpToAssetIdPair (max_values: None, max_size: None, mode: Measured)
+	/// Storage: AssetManager AssetIdLocation (r:1 w:1)
+	/// Proof Skipped: AssetManager AssetIdLocation (max_values: None, max_size: None, mode: Measured)
+	/// Storage: AssetManager AllowedDestParaIds (r:1 w:1)
+	/// Proof Skipped: AssetManager AllowedDestParaIds (max_values: None, max_size: None, mode: Measured)
+	/// Storage: AssetManager DynamicPricing (r:1 w:1)
+	/// Proof Skipped: AssetManager DynamicPricing (max_values: None, max_size: None, mode: Measured)
+	/// Storage: AssetManager CounterForDynamicPricing (r:1 w:1)
+	/// Proof Skipped: AssetManager CounterForDynamicPricing (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: AssetManager AssetIdMetadata (r:0 w:1)
+	/// Proof Skipped: AssetManager AssetIdMetadata (max_values: None, max_size: None, mode: Measured)
+	/// Storage: AssetManager UnitsPerSecond (r:0 w:1)
+	/// Proof Skipped: AssetManager UnitsPerSecond (max_values: None, max_size: None, mode: Measured)
+	/// Storage: AssetManager LocationAssetId (r:0 w:1)
+	/// Proof Skipped: AssetManager LocationAssetId (max_values: None, max_size: None, mode: Measured)
+	/// Storage: AssetManager FilteredOutgoingAssetLocations (r:0 w:1)
+	/// Proof Skipped: AssetManager FilteredOutgoingAssetLocations (max_values: None, max_size: None, mode: Measured)
+	/// Storage: AssetManager MinXcmFee (r:0 w:1)
+	/// Proof Skipped: AssetManager MinXcmFee (max_values: None, max_size: None, mode: Measured)
+	fn deregister_asset() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1894`
+		//  Estimated: `5359`
+		// Minimum execution time: 63_408_000 picoseconds.
+		Weight::from_parts(64_951_000, 5359)
+			.saturating_add(T::DbWeight::get().reads(9_u64))
+			.saturating_add(T::DbWeight::get().writes(17_u64))
+	}
 }
 
 // For backwards compatibility and tests
@@ -323,6 +510,8 @@ impl WeightInfo for () {
 	/// Proof Skipped: AssetManager AssetIdMetadata (max_values: None, max_size: None, mode: Measured)
 	/// Storage: AssetManager LpToAssetIdPair (r:0 w:1)
 	/// Proof Skipped: AssetManager LpToAssetIdPair (max_values: None, max_size: None, mode: Measured)
+	/// Storage: AssetManager AssetIdToLp (r:0 w:2)
+	/// Proof Skipped: AssetManager AssetIdToLp (max_values: None, max_size: None, mode: Measured)
 	fn register_lp_asset() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `545`
@@ -330,7 +519,7 @@ impl WeightInfo for () {
 		// Minimum execution time: 54_735_000 picoseconds.
 		Weight::from_parts(55_742_000, 6485)
 			.saturating_add(RocksDbWeight::get().reads(6_u64))
-			.saturating_add(RocksDbWeight::get().writes(6_u64))
+			.saturating_add(RocksDbWeight::get().writes(8_u64))
 	}
 	/// Storage: System Account (r:1 w:1)
 	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
@@ -353,4 +542,181 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(5_u64))
 			.saturating_add(RocksDbWeight::get().writes(6_u64))
 	}
+	/// Storage: AssetManager LocationAssetId (r:1 w:0)
+	/// Proof Skipped: AssetManager LocationAssetId (max_values: None, max_size: None, mode: Measured)
+	/// Storage: AssetManager PendingAssets (r:1 w:1)
+	/// Proof Skipped: AssetManager PendingAssets (max_values: None, max_size: None, mode: Measured)
+	/// Storage: AssetManager PendingActivations (r:1 w:1)
+	/// Proof Skipped: AssetManager PendingActivations (max_values: None, max_size: None, mode: Measured)
+	/// Storage: System Account (r:2 w:2)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	fn propose_asset() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1420`
+		//  Estimated: `6196`
+		// Minimum execution time: 71_062_000 picoseconds.
+		Weight::from_parts(73_415_000, 6196)
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+	/// Storage: AssetManager PendingAssets (r:1 w:1)
+	/// Proof Skipped: AssetManager PendingAssets (max_values: None, max_size: None, mode: Measured)
+	fn veto_pending_asset() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `412`
+		//  Estimated: `3877`
+		// Minimum execution time: 15_027_000 picoseconds.
+		Weight::from_parts(15_611_000, 3877)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: AssetManager PendingActivations (r:1 w:1)
+	/// Proof Skipped: AssetManager PendingActivations (max_values: None, max_size: None, mode: Measured)
+	/// Storage: AssetManager PendingAssets (r:10 w:10)
+	/// Proof Skipped: AssetManager PendingAssets (max_values: None, max_size: None, mode: Measured)
+	/// Storage: AssetManager LocationAssetId (r:10 w:10)
+	/// Proof Skipped: AssetManager LocationAssetId (max_values: None, max_size: None, mode: Measured)
+	/// Storage: AssetManager NextAssetId (r:1 w:1)
+	/// Proof Skipped: AssetManager NextAssetId (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: Assets Asset (r:10 w:10)
+	/// Proof: Assets Asset (max_values: None, max_size: Some(222), added: 2697, mode: MaxEncodedLen)
+	/// Storage: Assets Metadata (r:10 w:10)
+	/// Proof: Assets Metadata (max_values: None, max_size: Some(152), added: 2627, mode: MaxEncodedLen)
+	/// Storage: AssetManager AllowedDestParaIds (r:1 w:1)
+	/// Proof Skipped: AssetManager AllowedDestParaIds (max_values: None, max_size: None, mode: Measured)
+	/// Storage: AssetManager AssetIdMetadata (r:0 w:10)
+	/// Proof Skipped: AssetManager AssetIdMetadata (max_values: None, max_size: None, mode: Measured)
+	/// Storage: AssetManager AssetIdLocation (r:0 w:10)
+	/// Proof Skipped: AssetManager AssetIdLocation (max_values: None, max_size: None, mode: Measured)
+	/// Storage: AssetManager UnitsPerSecond (r:0 w:10)
+	/// Proof Skipped: AssetManager UnitsPerSecond (max_values: None, max_size: None, mode: Measured)
+	/// Storage: System Account (r:2 w:2)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// The range of component `n` is `[1, 10]`.
+	fn activate_pending_assets(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `504 + n * (228 ±0)`
+		//  Estimated: `6196 + n * (2697 ±0)`
+		// Minimum execution time: 48_211_000 picoseconds.
+		Weight::from_parts(21_637_000, 6196)
+			// Standard Error: 24_871
+			.saturating_add(Weight::from_parts(58_905_000, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().reads((4_u64).saturating_mul(n.into())))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+			.saturating_add(RocksDbWeight::get().writes((7_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 2697).saturating_mul(n.into()))
+	}
+	/// Storage: AssetManager AssetIdLocation (r:1 w:0)
+	/// Proof Skipped: AssetManager AssetIdLocation (max_values: None, max_size: None, mode: Measured)
+	/// Storage: AssetManager DynamicPricing (r:1 w:1)
+	/// Proof Skipped: AssetManager DynamicPricing (max_values: None, max_size: None, mode: Measured)
+	/// Storage: AssetManager CounterForDynamicPricing (r:1 w:1)
+	/// Proof: AssetManager CounterForDynamicPricing (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	fn set_dynamic_pricing() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1520`
+		//  Estimated: `4985`
+		// Minimum execution time: 21_633_000 picoseconds.
+		Weight::from_parts(22_404_000, 4985)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: AssetManager DynamicPricing (r:11 w:10)
+	/// Proof Skipped: AssetManager DynamicPricing (max_values: None, max_size: None, mode: Measured)
+	/// Storage: ZenlinkProtocol LiquidityPairs (r:10 w:0)
+	/// Proof Skipped: ZenlinkProtocol LiquidityPairs (max_values: None, max_size: None, mode: Measured)
+	/// Storage: ZenlinkProtocol PairStatuses (r:10 w:0)
+	/// Proof Skipped: ZenlinkProtocol PairStatuses (max_values: None, max_size: None, mode: Measured)
+	/// Storage: System Account (r:1 w:0)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: Assets Account (r:10 w:0)
+	/// Proof: Assets Account (max_values: None, max_size: Some(146), added: 2621, mode: MaxEncodedLen)
+	/// The range of component `n` is `[1, 10]`.
+	fn update_dynamic_prices(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `186 + n * (412 ±0)`
+		//  Estimated: `3593 + n * (2621 ±0)`
+		// Minimum execution time: 12_105_000 picoseconds.
+		Weight::from_parts(8_927_000, 3593)
+			// Standard Error: 18_460
+			.saturating_add(Weight::from_parts(14_512_000, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().reads((4_u64).saturating_mul(n.into())))
+			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 2621).saturating_mul(n.into()))
+	}
+	/// Storage: AssetManager AssetIdMetadata (r:1 w:0)
+	/// Proof Skipped: AssetManager AssetIdMetadata (max_values: None, max_size: None, mode: Measured)
+	/// Storage: AssetManager FrozenAssets (r:1 w:1)
+	/// Proof Skipped: AssetManager FrozenAssets (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Assets Asset (r:1 w:0)
+	/// Proof: Assets Asset (max_values: None, max_size: Some(222), added: 2697, mode: MaxEncodedLen)
+	/// Storage: Assets Metadata (r:1 w:1)
+	/// Proof: Assets Metadata (max_values: None, max_size: Some(152), added: 2627, mode: MaxEncodedLen)
+	fn freeze_asset() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1632`
+		//  Estimated: `5097`
+		// Minimum execution time: 31_842_000 picoseconds.
+		Weight::from_parts(32_519_000, 5097)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: AssetManager FrozenAssets (r:1 w:1)
+	/// Proof Skipped: AssetManager FrozenAssets (max_values: None, max_size: None, mode: Measured)
+	/// Storage: AssetManager AssetIdMetadata (r:1 w:0)
+	/// Proof Skipped: AssetManager AssetIdMetadata (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Assets Asset (r:1 w:0)
+	/// Proof: Assets Asset (max_values: None, max_size: Some(222), added: 2697, mode: MaxEncodedLen)
+	/// Storage: Assets Metadata (r:1 w:1)
+	/// Proof: Assets Metadata (max_values: None, max_size: Some(152), added: 2627, mode: MaxEncodedLen)
+	fn unfreeze_asset() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1667`
+		//  Estimated: `5132`
+		// Minimum execution time: 31_196_000 picoseconds.
+		Weight::from_parts(31_903_000, 5132)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: AssetManager FrozenAssets (r:1 w:1)
+	/// Proof Skipped: AssetManager FrozenAssets (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Assets Asset (r:1 w:1)
+	/// Proof: Assets Asset (max_values: None, max_size: Some(222), added: 2697, mode: MaxEncodedLen)
+	/// Storage: AssetManager AssetIdToLp (r:1 w:2)
+	/// Proof Skipped: AssetManager AssetIdToLp (max_values: None, max_size: None, mode: Measured)
+	/// Storage: AssetManager AssetIdPairToLp (r:0 w:1)
+	/// Proof Skipped: AssetManager AssetIdPairToLp (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Assets Metadata (r:1 w:1)
+	/// Proof: Assets Metadata (max_values: None, max_size: Some(152), added: 2627, mode: MaxEncodedLen)
+	/// Storage: AssetManager LpToAssetIdPair (r:1 w:1)
+	/// Proof Skipped: AssetManager LpToAssetIdPair (max_values: None, max_size: None, mode: Measured)
+	/// Storage: AssetManager AssetIdLocation (r:1 w:1)
+	/// Proof Skipped: AssetManager AssetIdLocation (max_values: None, max_size: None, mode: Measured)
+	/// Storage: AssetManager AllowedDestParaIds (r:1 w:1)
+	/// Proof Skipped: AssetManager AllowedDestParaIds (max_values: None, max_size: None, mode: Measured)
+	/// Storage: AssetManager DynamicPricing (r:1 w:1)
+	/// Proof Skipped: AssetManager DynamicPricing (max_values: None, max_size: None, mode: Measured)
+	/// Storage: AssetManager CounterForDynamicPricing (r:1 w:1)
+	/// Proof Skipped: AssetManager CounterForDynamicPricing (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: AssetManager AssetIdMetadata (r:0 w:1)
+	/// Proof Skipped: AssetManager AssetIdMetadata (max_values: None, max_size: None, mode: Measured)
+	/// Storage: AssetManager UnitsPerSecond (r:0 w:1)
+	/// Proof Skipped: AssetManager UnitsPerSecond (max_values: None, max_size: None, mode: Measured)
+	/// Storage: AssetManager LocationAssetId (r:0 w:1)
+	/// Proof Skipped: AssetManager LocationAssetId (max_values: None, max_size: None, mode: Measured)
+	/// Storage: AssetManager FilteredOutgoingAssetLocations (r:0 w:1)
+	/// Proof Skipped: AssetManager FilteredOutgoingAssetLocations (max_values: None, max_size: None, mode: Measured)
+	/// Storage: AssetManager MinXcmFee (r:0 w:1)
+	/// Proof Skipped: AssetManager MinXcmFee (max_values: None, max_size: None, mode: Measured)
+	fn deregister_asset() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1894`
+		//  Estimated: `5359`
+		// Minimum execution time: 63_408_000 picoseconds.
+		Weight::from_parts(64_951_000, 5359)
+			.saturating_add(RocksDbWeight::get().reads(9_u64))
+			.saturating_add(RocksDbWeight::get().writes(17_u64))
+	}
 }
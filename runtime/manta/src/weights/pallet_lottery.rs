@@ -55,6 +55,10 @@ pub trait WeightInfo {
 	fn set_min_deposit() -> Weight;
 	fn set_min_withdraw() -> Weight;
 	fn set_gas_reserve() -> Weight;
+	fn instant_withdraw(x: u32, y: u32, ) -> Weight;
+	fn set_liquidity_buffer_params() -> Weight;
+	fn set_staking_strategy(x: u32, ) -> Weight;
+	fn set_pot_backing() -> Weight;
 }
 
 /// Weights for pallet_lottery using the Substrate node and recommended hardware.
@@ -320,6 +324,92 @@ impl<T: frame_system::Config> pallet_lottery::WeightInfo for SubstrateWeight<T>
 		Weight::from_parts(3_186_000, 0)
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
+	/// Storage: Lottery MinWithdraw (r:1 w:0)
+	/// Proof Skipped: Lottery MinWithdraw (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: Scheduler Lookup (r:1 w:0)
+	/// Proof: Scheduler Lookup (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// Storage: Lottery FarmingParameters (r:1 w:0)
+	/// Proof Skipped: Lottery FarmingParameters (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: Lottery ActiveBalancePerUser (r:1 w:1)
+	/// Proof Skipped: Lottery ActiveBalancePerUser (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Lottery TotalUsers (r:1 w:1)
+	/// Proof Skipped: Lottery TotalUsers (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: Lottery TotalPot (r:1 w:1)
+	/// Proof Skipped: Lottery TotalPot (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: Lottery Backing (r:1 w:0)
+	/// Proof Skipped: Lottery Backing (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: Lottery LiquidityBuffer (r:1 w:1)
+	/// Proof Skipped: Lottery LiquidityBuffer (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: Lottery InstantWithdrawFee (r:1 w:0)
+	/// Proof Skipped: Lottery InstantWithdrawFee (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: Lottery SumOfDeposits (r:1 w:1)
+	/// Proof Skipped: Lottery SumOfDeposits (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: System Account (r:2 w:2)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: ParachainStaking SelectedCandidates (r:1 w:0)
+	/// Proof Skipped: ParachainStaking SelectedCandidates (max_values: Some(1), max_size: None, mode: Measured)
+	/// The range of component `x` is `[0, 1000]`.
+	/// The range of component `y` is `[0, 63]`.
+	fn instant_withdraw(x: u32, y: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1348 + x * (1 ±0) + y * (5 ±0)`
+		//  Estimated: `6196 + x * (1 ±0) + y * (5 ±0)`
+		// Minimum execution time: 38_412_000 picoseconds.
+		Weight::from_parts(41_873_210, 6196)
+			// Standard Error: 181
+			.saturating_add(Weight::from_parts(12_406, 0).saturating_mul(x.into()))
+			// Standard Error: 2_874
+			.saturating_add(Weight::from_parts(3_187, 0).saturating_mul(y.into()))
+			.saturating_add(T::DbWeight::get().reads(13_u64))
+			.saturating_add(T::DbWeight::get().writes(7_u64))
+			.saturating_add(Weight::from_parts(0, 1).saturating_mul(x.into()))
+			.saturating_add(Weight::from_parts(0, 5).saturating_mul(y.into()))
+	}
+	/// Storage: Lottery LiquidityBufferTarget (r:0 w:1)
+	/// Proof Skipped: Lottery LiquidityBufferTarget (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: Lottery InstantWithdrawFee (r:0 w:1)
+	/// Proof Skipped: Lottery InstantWithdrawFee (max_values: Some(1), max_size: None, mode: Measured)
+	fn set_liquidity_buffer_params() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 6_913_000 picoseconds.
+		Weight::from_parts(7_204_000, 0)
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: Lottery MinDeposit (r:1 w:0)
+	/// Proof Skipped: Lottery MinDeposit (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: Lottery StakingStrategy (r:0 w:1)
+	/// Proof Skipped: Lottery StakingStrategy (max_values: Some(1), max_size: None, mode: Measured)
+	/// The range of component `x` is `[0, 100]`.
+	fn set_staking_strategy(x: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `216`
+		//  Estimated: `1701`
+		// Minimum execution time: 9_412_000 picoseconds.
+		Weight::from_parts(10_038_517, 1701)
+			// Standard Error: 1_204
+			.saturating_add(Weight::from_parts(21_837, 0).saturating_mul(x.into()))
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: Lottery SumOfDeposits (r:1 w:0)
+	/// Proof Skipped: Lottery SumOfDeposits (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: Lottery TotalUnclaimedWinnings (r:1 w:0)
+	/// Proof Skipped: Lottery TotalUnclaimedWinnings (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: Farming PoolInfos (r:1 w:0)
+	/// Proof Skipped: Farming PoolInfos (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Lottery Backing (r:0 w:1)
+	/// Proof Skipped: Lottery Backing (max_values: Some(1), max_size: None, mode: Measured)
+	fn set_pot_backing() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `586`
+		//  Estimated: `4051`
+		// Minimum execution time: 14_027_000 picoseconds.
+		Weight::from_parts(14_512_000, 4051)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
 }
 
 // For backwards compatibility and tests
@@ -584,4 +674,90 @@ impl WeightInfo for () {
 		Weight::from_parts(3_186_000, 0)
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
+	/// Storage: Lottery MinWithdraw (r:1 w:0)
+	/// Proof Skipped: Lottery MinWithdraw (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: Scheduler Lookup (r:1 w:0)
+	/// Proof: Scheduler Lookup (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// Storage: Lottery FarmingParameters (r:1 w:0)
+	/// Proof Skipped: Lottery FarmingParameters (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: Lottery ActiveBalancePerUser (r:1 w:1)
+	/// Proof Skipped: Lottery ActiveBalancePerUser (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Lottery TotalUsers (r:1 w:1)
+	/// Proof Skipped: Lottery TotalUsers (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: Lottery TotalPot (r:1 w:1)
+	/// Proof Skipped: Lottery TotalPot (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: Lottery Backing (r:1 w:0)
+	/// Proof Skipped: Lottery Backing (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: Lottery LiquidityBuffer (r:1 w:1)
+	/// Proof Skipped: Lottery LiquidityBuffer (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: Lottery InstantWithdrawFee (r:1 w:0)
+	/// Proof Skipped: Lottery InstantWithdrawFee (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: Lottery SumOfDeposits (r:1 w:1)
+	/// Proof Skipped: Lottery SumOfDeposits (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: System Account (r:2 w:2)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: ParachainStaking SelectedCandidates (r:1 w:0)
+	/// Proof Skipped: ParachainStaking SelectedCandidates (max_values: Some(1), max_size: None, mode: Measured)
+	/// The range of component `x` is `[0, 1000]`.
+	/// The range of component `y` is `[0, 63]`.
+	fn instant_withdraw(x: u32, y: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1348 + x * (1 ±0) + y * (5 ±0)`
+		//  Estimated: `6196 + x * (1 ±0) + y * (5 ±0)`
+		// Minimum execution time: 38_412_000 picoseconds.
+		Weight::from_parts(41_873_210, 6196)
+			// Standard Error: 181
+			.saturating_add(Weight::from_parts(12_406, 0).saturating_mul(x.into()))
+			// Standard Error: 2_874
+			.saturating_add(Weight::from_parts(3_187, 0).saturating_mul(y.into()))
+			.saturating_add(RocksDbWeight::get().reads(13_u64))
+			.saturating_add(RocksDbWeight::get().writes(7_u64))
+			.saturating_add(Weight::from_parts(0, 1).saturating_mul(x.into()))
+			.saturating_add(Weight::from_parts(0, 5).saturating_mul(y.into()))
+	}
+	/// Storage: Lottery LiquidityBufferTarget (r:0 w:1)
+	/// Proof Skipped: Lottery LiquidityBufferTarget (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: Lottery InstantWithdrawFee (r:0 w:1)
+	/// Proof Skipped: Lottery InstantWithdrawFee (max_values: Some(1), max_size: None, mode: Measured)
+	fn set_liquidity_buffer_params() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 6_913_000 picoseconds.
+		Weight::from_parts(7_204_000, 0)
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: Lottery MinDeposit (r:1 w:0)
+	/// Proof Skipped: Lottery MinDeposit (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: Lottery StakingStrategy (r:0 w:1)
+	/// Proof Skipped: Lottery StakingStrategy (max_values: Some(1), max_size: None, mode: Measured)
+	/// The range of component `x` is `[0, 100]`.
+	fn set_staking_strategy(x: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `216`
+		//  Estimated: `1701`
+		// Minimum execution time: 9_412_000 picoseconds.
+		Weight::from_parts(10_038_517, 1701)
+			// Standard Error: 1_204
+			.saturating_add(Weight::from_parts(21_837, 0).saturating_mul(x.into()))
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: Lottery SumOfDeposits (r:1 w:0)
+	/// Proof Skipped: Lottery SumOfDeposits (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: Lottery TotalUnclaimedWinnings (r:1 w:0)
+	/// Proof Skipped: Lottery TotalUnclaimedWinnings (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: Farming PoolInfos (r:1 w:0)
+	/// Proof Skipped: Farming PoolInfos (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Lottery Backing (r:0 w:1)
+	/// Proof Skipped: Lottery Backing (max_values: Some(1), max_size: None, mode: Measured)
+	fn set_pot_backing() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `586`
+		//  Estimated: `4051`
+		// Minimum execution time: 14_027_000 picoseconds.
+		Weight::from_parts(14_512_000, 4051)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
 }
@@ -74,8 +74,23 @@ pub trait WeightInfo {
 	fn cancel_revoke_delegation() -> Weight;
 	fn cancel_delegator_bond_less() -> Weight;
 	fn round_transition_on_initialize(x: u32, y: u32, ) -> Weight;
-	fn pay_one_collator_reward(y: u32, ) -> Weight;
+	fn pay_one_collator_reward() -> Weight;
 	fn base_on_initialize() -> Weight;
+	fn delegate_with_auto_compound(x: u32, y: u32, z: u32, ) -> Weight;
+	fn set_auto_compound(x: u32, y: u32, ) -> Weight;
+	fn mint_and_compound() -> Weight;
+	fn set_slashing_config() -> Weight;
+	fn cancel_deferred_slash(x: u32, ) -> Weight;
+	fn apply_slash(x: u32, ) -> Weight;
+	fn redelegate() -> Weight;
+	fn set_commission_bounds() -> Weight;
+	fn schedule_commission_change() -> Weight;
+	fn execute_commission_change() -> Weight;
+	fn cancel_commission_change() -> Weight;
+	fn set_inflation_curve() -> Weight;
+	fn claim_rewards(x: u32, ) -> Weight;
+	fn start_collator_selection_migration() -> Weight;
+	fn report_equivocation() -> Weight;
 }
 
 /// Weights for pallet_parachain_staking using the Substrate node and recommended hardware.
@@ -629,22 +644,18 @@ impl<T: frame_system::Config> pallet_parachain_staking::WeightInfo for Substrate
 	/// Proof Skipped: ParachainStaking AwardedPts (max_values: None, max_size: None, mode: Measured)
 	/// Storage: ParachainStaking AtStake (r:1 w:1)
 	/// Proof Skipped: ParachainStaking AtStake (max_values: None, max_size: None, mode: Measured)
-	/// Storage: System Account (r:101 w:101)
+	/// Storage: ParachainStaking RewardPerShare (r:1 w:1)
+	/// Proof Skipped: ParachainStaking RewardPerShare (max_values: None, max_size: None, mode: Measured)
+	/// Storage: System Account (r:2 w:2)
 	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
-	/// The range of component `y` is `[0, 100]`.
-	fn pay_one_collator_reward(y: u32, ) -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `211 + y * (179 ±0)`
-		//  Estimated: `6168 + y * (2603 ±0)`
-		// Minimum execution time: 43_801_000 picoseconds.
-		Weight::from_parts(48_242_418, 6168)
-			// Standard Error: 5_529
-			.saturating_add(Weight::from_parts(17_062_072, 0).saturating_mul(y.into()))
-			.saturating_add(T::DbWeight::get().reads(6_u64))
-			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(y.into())))
-			.saturating_add(T::DbWeight::get().writes(3_u64))
-			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(y.into())))
-			.saturating_add(Weight::from_parts(0, 2603).saturating_mul(y.into()))
+	fn pay_one_collator_reward() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `18111`
+		//  Estimated: `26826`
+		// Minimum execution time: 50_783_000 picoseconds.
+		Weight::from_parts(51_700_000, 26826)
+			.saturating_add(T::DbWeight::get().reads(8_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
 	}
 	fn base_on_initialize() -> Weight {
 		// Proof Size summary in bytes:
@@ -653,6 +664,301 @@ impl<T: frame_system::Config> pallet_parachain_staking::WeightInfo for Substrate
 		// Minimum execution time: 1_305_000 picoseconds.
 		Weight::from_parts(1_404_000, 0)
 	}
+	/// Storage: ParachainStaking AutoCompoundingDelegations (r:1 w:1)
+	/// Proof Skipped: ParachainStaking AutoCompoundingDelegations (max_values: None, max_size: None, mode: Measured)
+	/// Storage: System Account (r:1 w:0)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: ParachainStaking DelegatorState (r:1 w:1)
+	/// Proof Skipped: ParachainStaking DelegatorState (max_values: None, max_size: None, mode: Measured)
+	/// Storage: ParachainStaking CandidateInfo (r:1 w:1)
+	/// Proof Skipped: ParachainStaking CandidateInfo (max_values: None, max_size: None, mode: Measured)
+	/// Storage: ParachainStaking TopDelegations (r:1 w:1)
+	/// Proof Skipped: ParachainStaking TopDelegations (max_values: None, max_size: None, mode: Measured)
+	/// Storage: ParachainStaking CandidatePool (r:1 w:1)
+	/// Proof Skipped: ParachainStaking CandidatePool (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: Balances Locks (r:1 w:1)
+	/// Proof: Balances Locks (max_values: None, max_size: Some(1299), added: 3774, mode: MaxEncodedLen)
+	/// Storage: ParachainStaking Total (r:1 w:1)
+	/// Proof Skipped: ParachainStaking Total (max_values: Some(1), max_size: None, mode: Measured)
+	/// The range of component `x` is `[3, 25]`.
+	/// The range of component `y` is `[0, 100]`.
+	/// The range of component `z` is `[2, 100]`.
+	fn delegate_with_auto_compound(x: u32, y: u32, z: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1254 + x * (129 ±0) + y * (41 ±0) + z * (58 ±0)`
+		//  Estimated: `4827 + x * (130 ±0) + y * (42 ±0) + z * (59 ±0)`
+		// Minimum execution time: 46_212_000 picoseconds.
+		Weight::from_parts(40_118_532, 4827)
+			// Standard Error: 3_512
+			.saturating_add(Weight::from_parts(231_604, 0).saturating_mul(x.into()))
+			// Standard Error: 802
+			.saturating_add(Weight::from_parts(38_921, 0).saturating_mul(y.into()))
+			// Standard Error: 802
+			.saturating_add(Weight::from_parts(73_215, 0).saturating_mul(z.into()))
+			.saturating_add(T::DbWeight::get().reads(9_u64))
+			.saturating_add(T::DbWeight::get().writes(8_u64))
+			.saturating_add(Weight::from_parts(0, 130).saturating_mul(x.into()))
+			.saturating_add(Weight::from_parts(0, 42).saturating_mul(y.into()))
+			.saturating_add(Weight::from_parts(0, 59).saturating_mul(z.into()))
+	}
+	/// Storage: ParachainStaking DelegatorState (r:1 w:0)
+	/// Proof Skipped: ParachainStaking DelegatorState (max_values: None, max_size: None, mode: Measured)
+	/// Storage: ParachainStaking AutoCompoundingDelegations (r:1 w:1)
+	/// Proof Skipped: ParachainStaking AutoCompoundingDelegations (max_values: None, max_size: None, mode: Measured)
+	/// The range of component `x` is `[0, 300]`.
+	/// The range of component `y` is `[0, 100]`.
+	fn set_auto_compound(x: u32, y: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `412 + x * (41 ±0) + y * (42 ±0)`
+		//  Estimated: `3877 + x * (42 ±0) + y * (43 ±0)`
+		// Minimum execution time: 12_804_000 picoseconds.
+		Weight::from_parts(11_937_416, 3877)
+			// Standard Error: 418
+			.saturating_add(Weight::from_parts(41_302, 0).saturating_mul(x.into()))
+			// Standard Error: 1_254
+			.saturating_add(Weight::from_parts(52_118, 0).saturating_mul(y.into()))
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+			.saturating_add(Weight::from_parts(0, 42).saturating_mul(x.into()))
+			.saturating_add(Weight::from_parts(0, 43).saturating_mul(y.into()))
+	}
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: ParachainStaking DelegationScheduledRequests (r:1 w:0)
+	/// Proof Skipped: ParachainStaking DelegationScheduledRequests (max_values: None, max_size: None, mode: Measured)
+	/// Storage: ParachainStaking DelegatorState (r:1 w:1)
+	/// Proof Skipped: ParachainStaking DelegatorState (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Balances Locks (r:1 w:1)
+	/// Proof: Balances Locks (max_values: None, max_size: Some(1299), added: 3774, mode: MaxEncodedLen)
+	/// Storage: ParachainStaking CandidateInfo (r:1 w:1)
+	/// Proof Skipped: ParachainStaking CandidateInfo (max_values: None, max_size: None, mode: Measured)
+	/// Storage: ParachainStaking TopDelegations (r:1 w:1)
+	/// Proof Skipped: ParachainStaking TopDelegations (max_values: None, max_size: None, mode: Measured)
+	/// Storage: ParachainStaking CandidatePool (r:1 w:1)
+	/// Proof Skipped: ParachainStaking CandidatePool (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: ParachainStaking Total (r:1 w:1)
+	/// Proof Skipped: ParachainStaking Total (max_values: Some(1), max_size: None, mode: Measured)
+	fn mint_and_compound() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `2381`
+		//  Estimated: `5846`
+		// Minimum execution time: 48_607_000 picoseconds.
+		Weight::from_parts(49_412_000, 5846)
+			.saturating_add(T::DbWeight::get().reads(8_u64))
+			.saturating_add(T::DbWeight::get().writes(7_u64))
+	}
+	/// Storage: ParachainStaking SlashingInfo (r:1 w:1)
+	/// Proof Skipped: ParachainStaking SlashingInfo (max_values: Some(1), max_size: None, mode: Measured)
+	fn set_slashing_config() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `88`
+		//  Estimated: `1573`
+		// Minimum execution time: 6_104_000 picoseconds.
+		Weight::from_parts(6_388_000, 1573)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: ParachainStaking UnappliedSlashes (r:1 w:1)
+	/// Proof Skipped: ParachainStaking UnappliedSlashes (max_values: None, max_size: None, mode: Measured)
+	/// The range of component `x` is `[1, 100]`.
+	fn cancel_deferred_slash(x: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `129 + x * (52 ±0)`
+		//  Estimated: `3594 + x * (53 ±0)`
+		// Minimum execution time: 8_917_000 picoseconds.
+		Weight::from_parts(8_561_204, 3594)
+			// Standard Error: 1_072
+			.saturating_add(Weight::from_parts(412_538, 0).saturating_mul(x.into()))
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+			.saturating_add(Weight::from_parts(0, 53).saturating_mul(x.into()))
+	}
+	/// Storage: ParachainStaking CandidateInfo (r:1 w:1)
+	/// Proof Skipped: ParachainStaking CandidateInfo (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Balances Locks (r:101 w:101)
+	/// Proof: Balances Locks (max_values: None, max_size: Some(1299), added: 3774, mode: MaxEncodedLen)
+	/// Storage: System Account (r:101 w:101)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: ParachainStaking DelegatorState (r:100 w:100)
+	/// Proof Skipped: ParachainStaking DelegatorState (max_values: None, max_size: None, mode: Measured)
+	/// Storage: ParachainStaking TopDelegations (r:1 w:1)
+	/// Proof Skipped: ParachainStaking TopDelegations (max_values: None, max_size: None, mode: Measured)
+	/// Storage: ParachainStaking DelegationScheduledRequests (r:1 w:0)
+	/// Proof Skipped: ParachainStaking DelegationScheduledRequests (max_values: None, max_size: None, mode: Measured)
+	/// Storage: ParachainStaking CandidatePool (r:1 w:1)
+	/// Proof Skipped: ParachainStaking CandidatePool (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: ParachainStaking Total (r:1 w:1)
+	/// Proof Skipped: ParachainStaking Total (max_values: Some(1), max_size: None, mode: Measured)
+	/// The range of component `x` is `[0, 100]`.
+	fn apply_slash(x: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1703 + x * (407 ±0)`
+		//  Estimated: `5168 + x * (3774 ±0)`
+		// Minimum execution time: 52_390_000 picoseconds.
+		Weight::from_parts(48_214_672, 5168)
+			// Standard Error: 21_843
+			.saturating_add(Weight::from_parts(31_506_118, 0).saturating_mul(x.into()))
+			.saturating_add(T::DbWeight::get().reads(7_u64))
+			.saturating_add(T::DbWeight::get().reads((4_u64).saturating_mul(x.into())))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
+			.saturating_add(T::DbWeight::get().writes((3_u64).saturating_mul(x.into())))
+			.saturating_add(Weight::from_parts(0, 3774).saturating_mul(x.into()))
+	}
+	/// Storage: ParachainStaking DelegatorState (r:2 w:2)
+	/// Proof Skipped: ParachainStaking DelegatorState (max_values: None, max_size: None, mode: Measured)
+	/// Storage: ParachainStaking LastRedelegation (r:1 w:1)
+	/// Proof Skipped: ParachainStaking LastRedelegation (max_values: None, max_size: None, mode: Measured)
+	/// Storage: ParachainStaking DelegationScheduledRequests (r:1 w:0)
+	/// Proof Skipped: ParachainStaking DelegationScheduledRequests (max_values: None, max_size: None, mode: Measured)
+	/// Storage: ParachainStaking CandidateInfo (r:2 w:2)
+	/// Proof Skipped: ParachainStaking CandidateInfo (max_values: None, max_size: None, mode: Measured)
+	/// Storage: ParachainStaking TopDelegations (r:2 w:2)
+	/// Proof Skipped: ParachainStaking TopDelegations (max_values: None, max_size: None, mode: Measured)
+	/// Storage: ParachainStaking BottomDelegations (r:2 w:2)
+	/// Proof Skipped: ParachainStaking BottomDelegations (max_values: None, max_size: None, mode: Measured)
+	/// Storage: ParachainStaking AutoCompoundingDelegations (r:1 w:0)
+	/// Proof Skipped: ParachainStaking AutoCompoundingDelegations (max_values: None, max_size: None, mode: Measured)
+	/// Storage: ParachainStaking CandidatePool (r:1 w:1)
+	/// Proof Skipped: ParachainStaking CandidatePool (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: Balances Locks (r:1 w:1)
+	/// Proof: Balances Locks (max_values: None, max_size: Some(1299), added: 3774, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: ParachainStaking Total (r:1 w:1)
+	/// Proof Skipped: ParachainStaking Total (max_values: Some(1), max_size: None, mode: Measured)
+	fn redelegate() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `38164`
+		//  Estimated: `84454`
+		// Minimum execution time: 182_315_000 picoseconds.
+		Weight::from_parts(186_422_000, 84454)
+			.saturating_add(T::DbWeight::get().reads(15_u64))
+			.saturating_add(T::DbWeight::get().writes(13_u64))
+	}
+	/// Storage: ParachainStaking CommissionBounds (r:1 w:1)
+	/// Proof Skipped: ParachainStaking CommissionBounds (max_values: Some(1), max_size: None, mode: Measured)
+	fn set_commission_bounds() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `88`
+		//  Estimated: `1573`
+		// Minimum execution time: 6_217_000 picoseconds.
+		Weight::from_parts(6_493_000, 1573)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: ParachainStaking CandidateInfo (r:1 w:1)
+	/// Proof Skipped: ParachainStaking CandidateInfo (max_values: None, max_size: None, mode: Measured)
+	/// Storage: ParachainStaking CommissionBounds (r:1 w:0)
+	/// Proof Skipped: ParachainStaking CommissionBounds (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: ParachainStaking Round (r:1 w:0)
+	/// Proof Skipped: ParachainStaking Round (max_values: Some(1), max_size: None, mode: Measured)
+	fn schedule_commission_change() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `601`
+		//  Estimated: `4066`
+		// Minimum execution time: 13_826_000 picoseconds.
+		Weight::from_parts(14_302_000, 4066)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: ParachainStaking CandidateInfo (r:1 w:1)
+	/// Proof Skipped: ParachainStaking CandidateInfo (max_values: None, max_size: None, mode: Measured)
+	/// Storage: ParachainStaking Round (r:1 w:0)
+	/// Proof Skipped: ParachainStaking Round (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: ParachainStaking CommissionBounds (r:1 w:0)
+	/// Proof Skipped: ParachainStaking CommissionBounds (max_values: Some(1), max_size: None, mode: Measured)
+	fn execute_commission_change() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `640`
+		//  Estimated: `4105`
+		// Minimum execution time: 14_512_000 picoseconds.
+		Weight::from_parts(15_017_000, 4105)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: ParachainStaking CandidateInfo (r:1 w:1)
+	/// Proof Skipped: ParachainStaking CandidateInfo (max_values: None, max_size: None, mode: Measured)
+	fn cancel_commission_change() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `629`
+		//  Estimated: `4094`
+		// Minimum execution time: 11_244_000 picoseconds.
+		Weight::from_parts(11_683_000, 4094)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: ParachainStaking InflationCurveConfig (r:1 w:1)
+	/// Proof Skipped: ParachainStaking InflationCurveConfig (max_values: Some(1), max_size: None, mode: Measured)
+	fn set_inflation_curve() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `6`
+		//  Estimated: `1491`
+		// Minimum execution time: 6_084_000 picoseconds.
+		Weight::from_parts(6_312_000, 1491)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: ParachainStaking UnclaimedRewards (r:1 w:1)
+	/// Proof Skipped: ParachainStaking UnclaimedRewards (max_values: None, max_size: None, mode: Measured)
+	/// Storage: ParachainStaking DelegatorState (r:1 w:0)
+	/// Proof Skipped: ParachainStaking DelegatorState (max_values: None, max_size: None, mode: Measured)
+	/// Storage: ParachainStaking RewardPerShare (r:100 w:0)
+	/// Proof Skipped: ParachainStaking RewardPerShare (max_values: None, max_size: None, mode: Measured)
+	/// Storage: ParachainStaking RewardCheckpoints (r:100 w:100)
+	/// Proof Skipped: ParachainStaking RewardCheckpoints (max_values: None, max_size: None, mode: Measured)
+	/// Storage: ParachainStaking AutoCompoundingDelegations (r:100 w:0)
+	/// Proof Skipped: ParachainStaking AutoCompoundingDelegations (max_values: None, max_size: None, mode: Measured)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// The range of component `x` is `[1, 100]`.
+	fn claim_rewards(x: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `512 + x * (161 ±0)`
+		//  Estimated: `3977 + x * (2637 ±0)`
+		// Minimum execution time: 21_644_000 picoseconds.
+		Weight::from_parts(14_930_112, 3977)
+			// Standard Error: 9_318
+			.saturating_add(Weight::from_parts(7_846_270, 0).saturating_mul(x.into()))
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().reads((3_u64).saturating_mul(x.into())))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(x.into())))
+			.saturating_add(Weight::from_parts(0, 2637).saturating_mul(x.into()))
+	}
+	/// Storage: ParachainStaking CollatorSelectionMigration (r:1 w:1)
+	/// Proof Skipped: ParachainStaking CollatorSelectionMigration (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: CollatorSelection Candidates (r:1 w:0)
+	/// Proof Skipped: CollatorSelection Candidates (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: CollatorSelection Invulnerables (r:1 w:0)
+	/// Proof Skipped: CollatorSelection Invulnerables (max_values: Some(1), max_size: None, mode: Measured)
+	fn start_collator_selection_migration() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `233`
+		//  Estimated: `1718`
+		// Minimum execution time: 10_428_000 picoseconds.
+		Weight::from_parts(10_851_000, 1718)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: ParachainStaking SlashingInfo (r:1 w:0)
+	/// Proof Skipped: ParachainStaking SlashingInfo (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: ParachainStaking AtStake (r:1 w:0)
+	/// Proof Skipped: ParachainStaking AtStake (max_values: None, max_size: None, mode: Measured)
+	/// Storage: ParachainStaking ReportedEquivocations (r:1 w:1)
+	/// Proof Skipped: ParachainStaking ReportedEquivocations (max_values: None, max_size: None, mode: Measured)
+	/// Storage: ParachainStaking Round (r:1 w:0)
+	/// Proof Skipped: ParachainStaking Round (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: ParachainStaking UnappliedSlashes (r:1 w:1)
+	/// Proof Skipped: ParachainStaking UnappliedSlashes (max_values: None, max_size: None, mode: Measured)
+	fn report_equivocation() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `512`
+		//  Estimated: `3977`
+		// Minimum execution time: 24_316_000 picoseconds.
+		Weight::from_parts(25_003_000, 3977)
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
 }
 
 // For backwards compatibility and tests
@@ -1205,22 +1511,18 @@ impl WeightInfo for () {
 	/// Proof Skipped: ParachainStaking AwardedPts (max_values: None, max_size: None, mode: Measured)
 	/// Storage: ParachainStaking AtStake (r:1 w:1)
 	/// Proof Skipped: ParachainStaking AtStake (max_values: None, max_size: None, mode: Measured)
-	/// Storage: System Account (r:101 w:101)
+	/// Storage: ParachainStaking RewardPerShare (r:1 w:1)
+	/// Proof Skipped: ParachainStaking RewardPerShare (max_values: None, max_size: None, mode: Measured)
+	/// Storage: System Account (r:2 w:2)
 	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
-	/// The range of component `y` is `[0, 100]`.
-	fn pay_one_collator_reward(y: u32, ) -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `211 + y * (179 ±0)`
-		//  Estimated: `6168 + y * (2603 ±0)`
-		// Minimum execution time: 43_801_000 picoseconds.
-		Weight::from_parts(48_242_418, 6168)
-			// Standard Error: 5_529
-			.saturating_add(Weight::from_parts(17_062_072, 0).saturating_mul(y.into()))
-			.saturating_add(RocksDbWeight::get().reads(6_u64))
-			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(y.into())))
-			.saturating_add(RocksDbWeight::get().writes(3_u64))
-			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(y.into())))
-			.saturating_add(Weight::from_parts(0, 2603).saturating_mul(y.into()))
+	fn pay_one_collator_reward() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `18111`
+		//  Estimated: `26826`
+		// Minimum execution time: 50_783_000 picoseconds.
+		Weight::from_parts(51_700_000, 26826)
+			.saturating_add(RocksDbWeight::get().reads(8_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
 	}
 	fn base_on_initialize() -> Weight {
 		// Proof Size summary in bytes:
@@ -1229,4 +1531,299 @@ impl WeightInfo for () {
 		// Minimum execution time: 1_305_000 picoseconds.
 		Weight::from_parts(1_404_000, 0)
 	}
+	/// Storage: ParachainStaking AutoCompoundingDelegations (r:1 w:1)
+	/// Proof Skipped: ParachainStaking AutoCompoundingDelegations (max_values: None, max_size: None, mode: Measured)
+	/// Storage: System Account (r:1 w:0)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: ParachainStaking DelegatorState (r:1 w:1)
+	/// Proof Skipped: ParachainStaking DelegatorState (max_values: None, max_size: None, mode: Measured)
+	/// Storage: ParachainStaking CandidateInfo (r:1 w:1)
+	/// Proof Skipped: ParachainStaking CandidateInfo (max_values: None, max_size: None, mode: Measured)
+	/// Storage: ParachainStaking TopDelegations (r:1 w:1)
+	/// Proof Skipped: ParachainStaking TopDelegations (max_values: None, max_size: None, mode: Measured)
+	/// Storage: ParachainStaking CandidatePool (r:1 w:1)
+	/// Proof Skipped: ParachainStaking CandidatePool (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: Balances Locks (r:1 w:1)
+	/// Proof: Balances Locks (max_values: None, max_size: Some(1299), added: 3774, mode: MaxEncodedLen)
+	/// Storage: ParachainStaking Total (r:1 w:1)
+	/// Proof Skipped: ParachainStaking Total (max_values: Some(1), max_size: None, mode: Measured)
+	/// The range of component `x` is `[3, 25]`.
+	/// The range of component `y` is `[0, 100]`.
+	/// The range of component `z` is `[2, 100]`.
+	fn delegate_with_auto_compound(x: u32, y: u32, z: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1254 + x * (129 ±0) + y * (41 ±0) + z * (58 ±0)`
+		//  Estimated: `4827 + x * (130 ±0) + y * (42 ±0) + z * (59 ±0)`
+		// Minimum execution time: 46_212_000 picoseconds.
+		Weight::from_parts(40_118_532, 4827)
+			// Standard Error: 3_512
+			.saturating_add(Weight::from_parts(231_604, 0).saturating_mul(x.into()))
+			// Standard Error: 802
+			.saturating_add(Weight::from_parts(38_921, 0).saturating_mul(y.into()))
+			// Standard Error: 802
+			.saturating_add(Weight::from_parts(73_215, 0).saturating_mul(z.into()))
+			.saturating_add(RocksDbWeight::get().reads(9_u64))
+			.saturating_add(RocksDbWeight::get().writes(8_u64))
+			.saturating_add(Weight::from_parts(0, 130).saturating_mul(x.into()))
+			.saturating_add(Weight::from_parts(0, 42).saturating_mul(y.into()))
+			.saturating_add(Weight::from_parts(0, 59).saturating_mul(z.into()))
+	}
+	/// Storage: ParachainStaking DelegatorState (r:1 w:0)
+	/// Proof Skipped: ParachainStaking DelegatorState (max_values: None, max_size: None, mode: Measured)
+	/// Storage: ParachainStaking AutoCompoundingDelegations (r:1 w:1)
+	/// Proof Skipped: ParachainStaking AutoCompoundingDelegations (max_values: None, max_size: None, mode: Measured)
+	/// The range of component `x` is `[0, 300]`.
+	/// The range of component `y` is `[0, 100]`.
+	fn set_auto_compound(x: u32, y: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `412 + x * (41 ±0) + y * (42 ±0)`
+		//  Estimated: `3877 + x * (42 ±0) + y * (43 ±0)`
+		// Minimum execution time: 12_804_000 picoseconds.
+		Weight::from_parts(11_937_416, 3877)
+			// Standard Error: 418
+			.saturating_add(Weight::from_parts(41_302, 0).saturating_mul(x.into()))
+			// Standard Error: 1_254
+			.saturating_add(Weight::from_parts(52_118, 0).saturating_mul(y.into()))
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+			.saturating_add(Weight::from_parts(0, 42).saturating_mul(x.into()))
+			.saturating_add(Weight::from_parts(0, 43).saturating_mul(y.into()))
+	}
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: ParachainStaking DelegationScheduledRequests (r:1 w:0)
+	/// Proof Skipped: ParachainStaking DelegationScheduledRequests (max_values: None, max_size: None, mode: Measured)
+	/// Storage: ParachainStaking DelegatorState (r:1 w:1)
+	/// Proof Skipped: ParachainStaking DelegatorState (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Balances Locks (r:1 w:1)
+	/// Proof: Balances Locks (max_values: None, max_size: Some(1299), added: 3774, mode: MaxEncodedLen)
+	/// Storage: ParachainStaking CandidateInfo (r:1 w:1)
+	/// Proof Skipped: ParachainStaking CandidateInfo (max_values: None, max_size: None, mode: Measured)
+	/// Storage: ParachainStaking TopDelegations (r:1 w:1)
+	/// Proof Skipped: ParachainStaking TopDelegations (max_values: None, max_size: None, mode: Measured)
+	/// Storage: ParachainStaking CandidatePool (r:1 w:1)
+	/// Proof Skipped: ParachainStaking CandidatePool (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: ParachainStaking Total (r:1 w:1)
+	/// Proof Skipped: ParachainStaking Total (max_values: Some(1), max_size: None, mode: Measured)
+	fn mint_and_compound() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `2381`
+		//  Estimated: `5846`
+		// Minimum execution time: 48_607_000 picoseconds.
+		Weight::from_parts(49_412_000, 5846)
+			.saturating_add(RocksDbWeight::get().reads(8_u64))
+			.saturating_add(RocksDbWeight::get().writes(7_u64))
+	}
+	/// Storage: ParachainStaking SlashingInfo (r:1 w:1)
+	/// Proof Skipped: ParachainStaking SlashingInfo (max_values: Some(1), max_size: None, mode: Measured)
+	fn set_slashing_config() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `88`
+		//  Estimated: `1573`
+		// Minimum execution time: 6_104_000 picoseconds.
+		Weight::from_parts(6_388_000, 1573)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: ParachainStaking UnappliedSlashes (r:1 w:1)
+	/// Proof Skipped: ParachainStaking UnappliedSlashes (max_values: None, max_size: None, mode: Measured)
+	/// The range of component `x` is `[1, 100]`.
+	fn cancel_deferred_slash(x: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `129 + x * (52 ±0)`
+		//  Estimated: `3594 + x * (53 ±0)`
+		// Minimum execution time: 8_917_000 picoseconds.
+		Weight::from_parts(8_561_204, 3594)
+			// Standard Error: 1_072
+			.saturating_add(Weight::from_parts(412_538, 0).saturating_mul(x.into()))
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+			.saturating_add(Weight::from_parts(0, 53).saturating_mul(x.into()))
+	}
+	/// Storage: ParachainStaking CandidateInfo (r:1 w:1)
+	/// Proof Skipped: ParachainStaking CandidateInfo (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Balances Locks (r:101 w:101)
+	/// Proof: Balances Locks (max_values: None, max_size: Some(1299), added: 3774, mode: MaxEncodedLen)
+	/// Storage: System Account (r:101 w:101)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: ParachainStaking DelegatorState (r:100 w:100)
+	/// Proof Skipped: ParachainStaking DelegatorState (max_values: None, max_size: None, mode: Measured)
+	/// Storage: ParachainStaking TopDelegations (r:1 w:1)
+	/// Proof Skipped: ParachainStaking TopDelegations (max_values: None, max_size: None, mode: Measured)
+	/// Storage: ParachainStaking DelegationScheduledRequests (r:1 w:0)
+	/// Proof Skipped: ParachainStaking DelegationScheduledRequests (max_values: None, max_size: None, mode: Measured)
+	/// Storage: ParachainStaking CandidatePool (r:1 w:1)
+	/// Proof Skipped: ParachainStaking CandidatePool (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: ParachainStaking Total (r:1 w:1)
+	/// Proof Skipped: ParachainStaking Total (max_values: Some(1), max_size: None, mode: Measured)
+	/// The range of component `x` is `[0, 100]`.
+	fn apply_slash(x: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1703 + x * (407 ±0)`
+		//  Estimated: `5168 + x * (3774 ±0)`
+		// Minimum execution time: 52_390_000 picoseconds.
+		Weight::from_parts(48_214_672, 5168)
+			// Standard Error: 21_843
+			.saturating_add(Weight::from_parts(31_506_118, 0).saturating_mul(x.into()))
+			.saturating_add(RocksDbWeight::get().reads(7_u64))
+			.saturating_add(RocksDbWeight::get().reads((4_u64).saturating_mul(x.into())))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
+			.saturating_add(RocksDbWeight::get().writes((3_u64).saturating_mul(x.into())))
+			.saturating_add(Weight::from_parts(0, 3774).saturating_mul(x.into()))
+	}
+	/// Storage: ParachainStaking DelegatorState (r:2 w:2)
+	/// Proof Skipped: ParachainStaking DelegatorState (max_values: None, max_size: None, mode: Measured)
+	/// Storage: ParachainStaking LastRedelegation (r:1 w:1)
+	/// Proof Skipped: ParachainStaking LastRedelegation (max_values: None, max_size: None, mode: Measured)
+	/// Storage: ParachainStaking DelegationScheduledRequests (r:1 w:0)
+	/// Proof Skipped: ParachainStaking DelegationScheduledRequests (max_values: None, max_size: None, mode: Measured)
+	/// Storage: ParachainStaking CandidateInfo (r:2 w:2)
+	/// Proof Skipped: ParachainStaking CandidateInfo (max_values: None, max_size: None, mode: Measured)
+	/// Storage: ParachainStaking TopDelegations (r:2 w:2)
+	/// Proof Skipped: ParachainStaking TopDelegations (max_values: None, max_size: None, mode: Measured)
+	/// Storage: ParachainStaking BottomDelegations (r:2 w:2)
+	/// Proof Skipped: ParachainStaking BottomDelegations (max_values: None, max_size: None, mode: Measured)
+	/// Storage: ParachainStaking AutoCompoundingDelegations (r:1 w:0)
+	/// Proof Skipped: ParachainStaking AutoCompoundingDelegations (max_values: None, max_size: None, mode: Measured)
+	/// Storage: ParachainStaking CandidatePool (r:1 w:1)
+	/// Proof Skipped: ParachainStaking CandidatePool (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: Balances Locks (r:1 w:1)
+	/// Proof: Balances Locks (max_values: None, max_size: Some(1299), added: 3774, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: ParachainStaking Total (r:1 w:1)
+	/// Proof Skipped: ParachainStaking Total (max_values: Some(1), max_size: None, mode: Measured)
+	fn redelegate() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `38164`
+		//  Estimated: `84454`
+		// Minimum execution time: 182_315_000 picoseconds.
+		Weight::from_parts(186_422_000, 84454)
+			.saturating_add(RocksDbWeight::get().reads(15_u64))
+			.saturating_add(RocksDbWeight::get().writes(13_u64))
+	}
+	/// Storage: ParachainStaking CommissionBounds (r:1 w:1)
+	/// Proof Skipped: ParachainStaking CommissionBounds (max_values: Some(1), max_size: None, mode: Measured)
+	fn set_commission_bounds() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `88`
+		//  Estimated: `1573`
+		// Minimum execution time: 6_217_000 picoseconds.
+		Weight::from_parts(6_493_000, 1573)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: ParachainStaking CandidateInfo (r:1 w:1)
+	/// Proof Skipped: ParachainStaking CandidateInfo (max_values: None, max_size: None, mode: Measured)
+	/// Storage: ParachainStaking CommissionBounds (r:1 w:0)
+	/// Proof Skipped: ParachainStaking CommissionBounds (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: ParachainStaking Round (r:1 w:0)
+	/// Proof Skipped: ParachainStaking Round (max_values: Some(1), max_size: None, mode: Measured)
+	fn schedule_commission_change() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `601`
+		//  Estimated: `4066`
+		// Minimum execution time: 13_826_000 picoseconds.
+		Weight::from_parts(14_302_000, 4066)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: ParachainStaking CandidateInfo (r:1 w:1)
+	/// Proof Skipped: ParachainStaking CandidateInfo (max_values: None, max_size: None, mode: Measured)
+	/// Storage: ParachainStaking Round (r:1 w:0)
+	/// Proof Skipped: ParachainStaking Round (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: ParachainStaking CommissionBounds (r:1 w:0)
+	/// Proof Skipped: ParachainStaking CommissionBounds (max_values: Some(1), max_size: None, mode: Measured)
+	fn execute_commission_change() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `640`
+		//  Estimated: `4105`
+		// Minimum execution time: 14_512_000 picoseconds.
+		Weight::from_parts(15_017_000, 4105)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: ParachainStaking CandidateInfo (r:1 w:1)
+	/// Proof Skipped: ParachainStaking CandidateInfo (max_values: None, max_size: None, mode: Measured)
+	fn cancel_commission_change() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `629`
+		//  Estimated: `4094`
+		// Minimum execution time: 11_244_000 picoseconds.
+		Weight::from_parts(11_683_000, 4094)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: ParachainStaking InflationCurveConfig (r:1 w:1)
+	/// Proof Skipped: ParachainStaking InflationCurveConfig (max_values: Some(1), max_size: None, mode: Measured)
+	fn set_inflation_curve() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `6`
+		//  Estimated: `1491`
+		// Minimum execution time: 6_084_000 picoseconds.
+		Weight::from_parts(6_312_000, 1491)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: ParachainStaking UnclaimedRewards (r:1 w:1)
+	/// Proof Skipped: ParachainStaking UnclaimedRewards (max_values: None, max_size: None, mode: Measured)
+	/// Storage: ParachainStaking DelegatorState (r:1 w:0)
+	/// Proof Skipped: ParachainStaking DelegatorState (max_values: None, max_size: None, mode: Measured)
+	/// Storage: ParachainStaking RewardPerShare (r:100 w:0)
+	/// Proof Skipped: ParachainStaking RewardPerShare (max_values: None, max_size: None, mode: Measured)
+	/// Storage: ParachainStaking RewardCheckpoints (r:100 w:100)
+	/// Proof Skipped: ParachainStaking RewardCheckpoints (max_values: None, max_size: None, mode: Measured)
+	/// Storage: ParachainStaking AutoCompoundingDelegations (r:100 w:0)
+	/// Proof Skipped: ParachainStaking AutoCompoundingDelegations (max_values: None, max_size: None, mode: Measured)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// The range of component `x` is `[1, 100]`.
+	fn claim_rewards(x: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `512 + x * (161 ±0)`
+		//  Estimated: `3977 + x * (2637 ±0)`
+		// Minimum execution time: 21_644_000 picoseconds.
+		Weight::from_parts(14_930_112, 3977)
+			// Standard Error: 9_318
+			.saturating_add(Weight::from_parts(7_846_270, 0).saturating_mul(x.into()))
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().reads((3_u64).saturating_mul(x.into())))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(x.into())))
+			.saturating_add(Weight::from_parts(0, 2637).saturating_mul(x.into()))
+	}
+	/// Storage: ParachainStaking CollatorSelectionMigration (r:1 w:1)
+	/// Proof Skipped: ParachainStaking CollatorSelectionMigration (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: CollatorSelection Candidates (r:1 w:0)
+	/// Proof Skipped: CollatorSelection Candidates (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: CollatorSelection Invulnerables (r:1 w:0)
+	/// Proof Skipped: CollatorSelection Invulnerables (max_values: Some(1), max_size: None, mode: Measured)
+	fn start_collator_selection_migration() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `233`
+		//  Estimated: `1718`
+		// Minimum execution time: 10_428_000 picoseconds.
+		Weight::from_parts(10_851_000, 1718)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: ParachainStaking SlashingInfo (r:1 w:0)
+	/// Proof Skipped: ParachainStaking SlashingInfo (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: ParachainStaking AtStake (r:1 w:0)
+	/// Proof Skipped: ParachainStaking AtStake (max_values: None, max_size: None, mode: Measured)
+	/// Storage: ParachainStaking ReportedEquivocations (r:1 w:1)
+	/// Proof Skipped: ParachainStaking ReportedEquivocations (max_values: None, max_size: None, mode: Measured)
+	/// Storage: ParachainStaking Round (r:1 w:0)
+	/// Proof Skipped: ParachainStaking Round (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: ParachainStaking UnappliedSlashes (r:1 w:1)
+	/// Proof Skipped: ParachainStaking UnappliedSlashes (max_values: None, max_size: None, mode: Measured)
+	fn report_equivocation() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `512`
+		//  Estimated: `3977`
+		// Minimum execution time: 24_316_000 picoseconds.
+		Weight::from_parts(25_003_000, 3977)
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
 }
@@ -33,7 +33,7 @@ use manta_primitives::{
     types::{AccountId, MantaAssetId},
     xcm::{
         AccountIdToMultiLocation, AllowTopLevelPaidExecutionDescendOriginFirst,
-        AllowTopLevelPaidExecutionFrom, FirstAssetTrader, IsNativeConcrete, MultiAssetAdapter,
+        AllowTopLevelPaidExecutionFrom, IsNativeConcrete, MultiAssetAdapter, MultiAssetTrader,
         MultiNativeAsset, XcmFeesToAccount,
     },
 };
@@ -122,7 +122,9 @@ parameter_types! {
     /// Used in native traders
     /// This might be able to skipped.
     /// We have to use `here()` because of reanchoring logic
-    pub ParaTokenPerSecond: (cumulus_primitives_core::AssetId, u128, u128) = (Concrete(MultiLocation::here()), 1_000_000_000, 0);
+    pub ParaTokenPerSecond: (cumulus_primitives_core::AssetId, u128, u128) = (Concrete(MultiLocation::here()), NativeUnitsPerSecond::get(), 0);
+    /// Units of the native token charged per second of XCM execution
+    pub const NativeUnitsPerSecond: u128 = 1_000_000_000;
     pub const MaxInstructions: u32 = 100;
 }
 
@@ -225,7 +227,7 @@ impl Config for XcmExecutorConfig {
     // i.e. units_per_second in `AssetManager`
     type Trader = (
         FixedRateOfFungible<ParaTokenPerSecond, XcmNativeFeeToTreasury>,
-        FirstAssetTrader<AssetManager, MantaXcmFeesToAccount>,
+        MultiAssetTrader<AssetManager, MantaXcmFeesToAccount>,
     );
     type ResponseHandler = PolkadotXcm;
     type AssetTrap = PolkadotXcm;
@@ -16,15 +16,15 @@
 
 use super::{AssetManager, Balances, ParachainInfo, Runtime, RuntimeEvent, ZenlinkProtocol};
 use crate::assets_config::MantaConcreteFungibleLedger;
-use frame_support::{parameter_types, traits::ExistenceRequirement, PalletId};
+use frame_support::{parameter_types, traits::ExistenceRequirement, weights::Weight, PalletId};
 use manta_primitives::{
     assets::{AssetIdLpMap, FungibleLedger},
-    types::MantaAssetId,
+    types::{Balance, MantaAssetId},
 };
-use sp_runtime::{traits::Zero, DispatchError};
+use sp_runtime::{traits::Zero, DispatchError, DispatchResult};
 use zenlink_protocol::{
-    AssetBalance, AssetId as ZenlinkAssetId, GenerateLpAssetId, LocalAssetHandler,
-    ZenlinkMultiAssets, LOCAL,
+    AssetBalance, AssetId as ZenlinkAssetId, ExportZenlink, GenerateLpAssetId, LocalAssetHandler,
+    WeightInfo as ZenlinkWeightInfo, ZenlinkMultiAssets, LOCAL, NATIVE,
 };
 
 // Normal Coin AMM
@@ -78,15 +78,100 @@ impl GenerateLpAssetId<ZenlinkAssetId> for AssetManagerLpGenerate {
     }
 }
 
+/// Adds and removes the liquidity of the LP tokens staked in farming pools on Zenlink.
+pub struct ZenlinkFarmingLiquidity;
+impl pallet_farming::LiquidityProvider<sp_runtime::AccountId32, MantaAssetId, Balance>
+    for ZenlinkFarmingLiquidity
+{
+    fn add_liquidity(
+        who: &sp_runtime::AccountId32,
+        asset_0: MantaAssetId,
+        asset_1: MantaAssetId,
+        amount_0_desired: Balance,
+        amount_1_desired: Balance,
+        amount_0_min: Balance,
+        amount_1_min: Balance,
+    ) -> DispatchResult {
+        <ZenlinkProtocol as ExportZenlink<_, _>>::inner_add_liquidity(
+            who,
+            LocalAssetAdaptor::zenlink_asset_id(asset_0),
+            LocalAssetAdaptor::zenlink_asset_id(asset_1),
+            amount_0_desired,
+            amount_1_desired,
+            amount_0_min,
+            amount_1_min,
+        )
+    }
+
+    fn remove_liquidity(
+        who: &sp_runtime::AccountId32,
+        asset_0: MantaAssetId,
+        asset_1: MantaAssetId,
+        liquidity: Balance,
+        amount_0_min: Balance,
+        amount_1_min: Balance,
+    ) -> DispatchResult {
+        <ZenlinkProtocol as ExportZenlink<_, _>>::inner_remove_liquidity(
+            who,
+            LocalAssetAdaptor::zenlink_asset_id(asset_0),
+            LocalAssetAdaptor::zenlink_asset_id(asset_1),
+            liquidity,
+            amount_0_min,
+            amount_1_min,
+            who,
+        )
+    }
+
+    fn add_liquidity_weight() -> Weight {
+        <Runtime as zenlink_protocol::Config>::WeightInfo::add_liquidity()
+    }
+
+    fn remove_liquidity_weight() -> Weight {
+        <Runtime as zenlink_protocol::Config>::WeightInfo::remove_liquidity()
+    }
+}
+
+/// Prices assets against the native token with the reserves of their Zenlink pair.
+pub struct ZenlinkNativePrice;
+impl pallet_asset_manager::NativePriceProvider<MantaAssetId> for ZenlinkNativePrice {
+    fn reserves(asset_id: &MantaAssetId) -> Option<(Balance, Balance)> {
+        let native = LocalAssetAdaptor::zenlink_asset_id(MantaNativeAssetId::get());
+        let asset = LocalAssetAdaptor::zenlink_asset_id(*asset_id);
+        let pair = ZenlinkProtocol::get_pair_by_asset_id(asset, native)?;
+        if pair.asset_0 == asset {
+            Some((pair.reserve_0, pair.reserve_1))
+        } else {
+            Some((pair.reserve_1, pair.reserve_0))
+        }
+    }
+}
+
 pub struct LocalAssetAdaptor;
 
 impl LocalAssetAdaptor {
+    /// Returns the Zenlink asset id of a local asset, the reverse of `asset_id_convert`.
+    fn zenlink_asset_id(asset_id: MantaAssetId) -> ZenlinkAssetId {
+        if asset_id == MantaNativeAssetId::get() {
+            ZenlinkAssetId {
+                chain_id: SelfParaId::get(),
+                asset_type: NATIVE,
+                asset_index: ZenlinkNativeAssetId::get(),
+            }
+        } else {
+            ZenlinkAssetId {
+                chain_id: SelfParaId::get(),
+                asset_type: LOCAL,
+                asset_index: asset_id as u64,
+            }
+        }
+    }
+
     #[cfg(not(feature = "runtime-benchmarks"))]
     fn asset_id_convert(asset_id: ZenlinkAssetId) -> Option<MantaAssetId> {
         // Notice: Manta native asset id is 1, but Zenlink native asset id is 0.
         if asset_id.asset_index == ZenlinkNativeAssetId::get() {
             // When Zenlink asset index is 0, the asset type need to be NATIVE(0).
-            return if asset_id.asset_type != zenlink_protocol::NATIVE {
+            return if asset_id.asset_type != NATIVE {
                 None
             } else {
                 Some(MantaNativeAssetId::get())